@@ -4,5 +4,165 @@
 //! crate previously held a byte-equivalent copy; consolidated to keep one
 //! source of truth for accrued-interest math (standard, ex-dividend, ICMA
 //! irregular periods, year-fraction).
+//!
+//! [`accrued_interest`] and [`accrued_interest_ex_dividend`] are for callers
+//! that hold a coupon schedule rather than a bond. They use the same
+//! schedule-based calculation as `FixedRateBond::accrued_interest`.
+
+use rust_decimal::Decimal;
+
+use convex_bonds::types::CalendarId;
+use convex_core::daycounts::DayCountConvention;
+use convex_core::types::{Date, Frequency};
 
 pub use convex_bonds::cashflows::AccruedInterestCalculator;
+
+/// Accrued interest for a settlement date, given the bond's accrual schedule.
+///
+/// `coupon_dates` are the accrual period boundaries in ascending order,
+/// starting with the issue (dated) date and ending with maturity. Irregular
+/// first and last periods accrue ICMA-style; see
+/// [`AccruedInterestCalculator::from_schedule`].
+///
+/// Returns zero if settlement falls outside the schedule or the frequency is
+/// zero.
+#[must_use]
+pub fn accrued_interest(
+    coupon_dates: &[Date],
+    settlement: Date,
+    coupon_rate: Decimal,
+    face_value: Decimal,
+    day_count: DayCountConvention,
+    frequency: Frequency,
+) -> Decimal {
+    AccruedInterestCalculator::from_schedule(
+        coupon_dates,
+        settlement,
+        coupon_rate,
+        face_value,
+        day_count,
+        frequency,
+    )
+}
+
+/// [`accrued_interest`] with an ex-dividend period (UK gilts and similar).
+///
+/// Settlement on or after the date `ex_div_days` business days before the
+/// next coupon gives negative accrued: minus the interest from settlement to
+/// the coupon date, which the buyer does not receive.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn accrued_interest_ex_dividend(
+    coupon_dates: &[Date],
+    settlement: Date,
+    coupon_rate: Decimal,
+    face_value: Decimal,
+    day_count: DayCountConvention,
+    frequency: Frequency,
+    ex_div_days: u32,
+    calendar: &CalendarId,
+) -> Decimal {
+    let calendar = calendar.to_calendar();
+    AccruedInterestCalculator::from_schedule_ex_dividend(
+        coupon_dates,
+        settlement,
+        coupon_rate,
+        face_value,
+        day_count,
+        frequency,
+        |coupon| calendar.add_business_days(coupon, -(ex_div_days as i32)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    /// Boeing 7.5% 06/15/2025: dated 31 May 2005, then 15 Dec / 15 Jun.
+    fn boeing_dates() -> Vec<Date> {
+        let mut dates = vec![date(2005, 5, 31)];
+        for year in 2005..=2025 {
+            if year > 2005 {
+                dates.push(date(year, 6, 15));
+            }
+            if year < 2025 {
+                dates.push(date(year, 12, 15));
+            }
+        }
+        dates
+    }
+
+    #[test]
+    fn test_boeing_accrued_matches_validation() {
+        // Dec 15 to Apr 29 is 134 days of a 180-day 30/360 period:
+        // 3.75 × 134/180 per 100, 27,916.67 per 1mm.
+        let accrued = accrued_interest(
+            &boeing_dates(),
+            date(2020, 4, 29),
+            dec!(0.075),
+            dec!(1_000_000),
+            DayCountConvention::Thirty360US,
+            Frequency::SemiAnnual,
+        );
+        assert_eq!(accrued.round_dp(2), dec!(27916.67));
+    }
+
+    #[test]
+    fn test_long_first_stub_accrues_against_notional_periods() {
+        // Dated 31 May 2005, first coupon 15 Dec 2005: a long first period.
+        // To 15 Jul it accrues 15 days of the Dec-Jun notional period and 30
+        // of the Jun-Dec one, each out of 180 at 30/360.
+        let accrued = accrued_interest(
+            &boeing_dates(),
+            date(2005, 7, 15),
+            dec!(0.075),
+            dec!(100),
+            DayCountConvention::Thirty360US,
+            Frequency::SemiAnnual,
+        );
+        assert_eq!(accrued, dec!(3.75) * dec!(45) / dec!(180));
+    }
+
+    #[test]
+    fn test_long_last_stub_spans_two_notional_periods() {
+        let dates = [date(2024, 1, 15), date(2024, 7, 15), date(2025, 3, 15)];
+        let accrued = accrued_interest(
+            &dates,
+            date(2025, 2, 15),
+            dec!(0.06),
+            dec!(100),
+            DayCountConvention::Thirty360US,
+            Frequency::SemiAnnual,
+        );
+        // One full notional period (Jul-Jan) plus 30 of 180 days of the next.
+        assert_eq!(accrued, dec!(3) + dec!(3) * dec!(30) / dec!(180));
+    }
+
+    #[test]
+    fn test_ex_dividend_accrued_is_negative() {
+        let dates = [date(2024, 12, 7), date(2025, 6, 7), date(2025, 12, 7)];
+        let calendar = CalendarId::uk();
+        let args = |settlement| {
+            accrued_interest_ex_dividend(
+                &dates,
+                settlement,
+                dec!(0.0425),
+                dec!(100),
+                DayCountConvention::ActActIcma,
+                Frequency::SemiAnnual,
+                7,
+                &calendar,
+            )
+        };
+        // 5 days before the 7 Jun coupon of 2.125 in a 182-day period.
+        assert_eq!(args(date(2025, 6, 2)), -dec!(2.125) * dec!(5) / dec!(182));
+        assert!(args(date(2025, 5, 1)) > Decimal::ZERO);
+        // Outside the schedule there is nothing to accrue.
+        assert_eq!(args(date(2026, 1, 5)), Decimal::ZERO);
+    }
+}
@@ -5,7 +5,7 @@ pub mod irregular;
 pub mod schedule;
 pub mod settlement;
 
-pub use accrued::AccruedInterestCalculator;
+pub use accrued::{accrued_interest, accrued_interest_ex_dividend, AccruedInterestCalculator};
 pub use irregular::{IrregularPeriodHandler, IrregularStubType, ReferenceMethod};
 pub use schedule::{Schedule, ScheduleConfig, StubType};
 pub use settlement::{
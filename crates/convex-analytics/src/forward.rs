@@ -0,0 +1,292 @@
+//! Forward bond prices for repo and forward-settling trades.
+//!
+//! The forward dirty price is the spot dirty price financed at the repo rate
+//! to the forward date, less the cash the bond pays in between (reinvested at
+//! repo to the forward date):
+//!
+//! ```text
+//! F_dirty = P_dirty · (1 + r·T) − Σ CF_i · (1 + r·T_i)
+//! ```
+//!
+//! where `T` and `T_i` are ACT/360 year fractions to the forward date. The
+//! forward clean price strips the accrued interest at the forward date.
+//!
+//! The relation is linear in `r`, so [`implied_repo`] inverts it in closed
+//! form: the repo rate at which a given forward is a fair carry from spot.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use convex_bonds::traits::Bond;
+use convex_core::types::Date;
+
+use crate::error::{AnalyticsError, AnalyticsResult};
+use crate::functions::yield_to_maturity;
+
+/// Output of [`forward_price`]. Prices, accrued and coupons are per 100 face.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForwardPriceResult {
+    /// Forward clean price per 100.
+    pub forward_clean_per_100: f64,
+    /// Forward dirty price per 100.
+    pub forward_dirty_per_100: f64,
+    /// Accrued interest per 100 at the forward date.
+    pub forward_accrued_per_100: f64,
+    /// Cash paid between spot and forward settlement, with repo
+    /// reinvestment to the forward date, per 100.
+    pub interim_cash_per_100: f64,
+    /// Yield to maturity implied by the forward clean price at the forward
+    /// date, as decimal (0.05 = 5%), compounded at the bond's frequency.
+    pub forward_yield_decimal: f64,
+}
+
+impl ForwardPriceResult {
+    /// Forward drop: spot clean minus forward clean. Positive when carry
+    /// (coupon income over financing cost) is positive.
+    #[must_use]
+    pub fn drop_from(&self, spot_clean_per_100: f64) -> f64 {
+        spot_clean_per_100 - self.forward_clean_per_100
+    }
+}
+
+/// ACT/360 money-market year fraction, the repo market convention.
+fn repo_year_fraction(start: Date, end: Date) -> f64 {
+    start.days_between(&end) as f64 / 360.0
+}
+
+fn to_f64(d: Decimal) -> f64 {
+    d.to_f64().unwrap_or(0.0)
+}
+
+/// Checks the dates and returns the cash paid after spot settlement and on
+/// or before the forward date, as `(amount, ACT/360 years to forward date)`.
+fn interim_flows(
+    bond: &dyn Bond,
+    spot_settlement: Date,
+    forward_date: Date,
+) -> AnalyticsResult<Vec<(f64, f64)>> {
+    if forward_date <= spot_settlement {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "forward date {forward_date} must be after spot settlement {spot_settlement}"
+        )));
+    }
+    let maturity = bond
+        .maturity()
+        .ok_or_else(|| AnalyticsError::InvalidInput("bond has no maturity (perpetual)".into()))?;
+    if forward_date >= maturity {
+        return Err(AnalyticsError::InvalidSettlement {
+            settlement: forward_date.to_string(),
+            maturity: maturity.to_string(),
+        });
+    }
+    Ok(bond
+        .cash_flows(spot_settlement)
+        .iter()
+        .take_while(|cf| cf.date <= forward_date)
+        .map(|cf| (to_f64(cf.amount), repo_year_fraction(cf.date, forward_date)))
+        .collect())
+}
+
+/// Forward clean and dirty price of `bond` for settlement on `forward_date`,
+/// given its clean price for `spot_settlement` and a term repo rate.
+///
+/// Coupons (and any principal) paid after spot settlement and on or before
+/// the forward date go to the holder and are deducted, reinvested at the
+/// repo rate. The implied forward yield is the street-convention yield of
+/// the forward clean price at the forward date.
+///
+/// # Arguments
+///
+/// * `bond` - Bond to price forward
+/// * `spot_settlement` - Spot settlement date
+/// * `forward_date` - Forward settlement date
+/// * `repo_rate_decimal` - Term repo rate, ACT/360 simple (0.04 = 4%)
+/// * `spot_clean_price` - Clean price per 100 for spot settlement
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` if the forward date is not after
+/// spot settlement or the repo rate is not finite, and
+/// `AnalyticsError::InvalidSettlement` if the bond matures on or before the
+/// forward date.
+pub fn forward_price(
+    bond: &dyn Bond,
+    spot_settlement: Date,
+    forward_date: Date,
+    repo_rate_decimal: f64,
+    spot_clean_price: Decimal,
+) -> AnalyticsResult<ForwardPriceResult> {
+    if !repo_rate_decimal.is_finite() {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "repo rate not finite ({repo_rate_decimal})"
+        )));
+    }
+    let flows = interim_flows(bond, spot_settlement, forward_date)?;
+
+    let spot_dirty = to_f64(spot_clean_price + bond.accrued_interest(spot_settlement));
+    let financed =
+        spot_dirty * (1.0 + repo_rate_decimal * repo_year_fraction(spot_settlement, forward_date));
+    let interim_cash: f64 = flows
+        .iter()
+        .map(|(amount, t)| amount * (1.0 + repo_rate_decimal * t))
+        .sum();
+
+    let forward_dirty = financed - interim_cash;
+    let forward_accrued = to_f64(bond.accrued_interest(forward_date));
+    let forward_clean = forward_dirty - forward_accrued;
+
+    let forward_clean_dec = Decimal::from_f64_retain(forward_clean).ok_or_else(|| {
+        AnalyticsError::InvalidInput(format!("forward clean price not finite ({forward_clean})"))
+    })?;
+    let forward_yield =
+        yield_to_maturity(bond, forward_date, forward_clean_dec, bond.frequency())?.yield_value;
+
+    Ok(ForwardPriceResult {
+        forward_clean_per_100: forward_clean,
+        forward_dirty_per_100: forward_dirty,
+        forward_accrued_per_100: forward_accrued,
+        interim_cash_per_100: interim_cash,
+        forward_yield_decimal: forward_yield,
+    })
+}
+
+/// Repo rate (ACT/360 simple, as decimal) at which `forward_clean` is the
+/// fair forward of `spot_clean`, allowing for cash paid in between.
+///
+/// Inverts [`forward_price`]: the implied repo of the forward it returns is
+/// the repo rate it was given. Applied to a futures price times conversion
+/// factor, this is the implied repo of a deliverable.
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` if the forward date is not after
+/// spot settlement or the carry has no sensitivity to the repo rate, and
+/// `AnalyticsError::InvalidSettlement` if the bond matures on or before the
+/// forward date.
+pub fn implied_repo(
+    bond: &dyn Bond,
+    spot_clean: Decimal,
+    forward_clean: Decimal,
+    spot_settlement: Date,
+    forward_date: Date,
+) -> AnalyticsResult<f64> {
+    let flows = interim_flows(bond, spot_settlement, forward_date)?;
+
+    let spot_dirty = to_f64(spot_clean + bond.accrued_interest(spot_settlement));
+    let forward_dirty = to_f64(forward_clean + bond.accrued_interest(forward_date));
+    let cash: f64 = flows.iter().map(|(amount, _)| amount).sum();
+    let cash_time: f64 = flows.iter().map(|(amount, t)| amount * t).sum();
+
+    // F = P·(1 + r·T) − Σ CF·(1 + r·T_i)  ⇒  r = (F − P + Σ CF) / (P·T − Σ CF·T_i)
+    let sensitivity = spot_dirty * repo_year_fraction(spot_settlement, forward_date) - cash_time;
+    if sensitivity.abs() < f64::EPSILON {
+        return Err(AnalyticsError::InvalidInput(
+            "carry is insensitive to the repo rate".into(),
+        ));
+    }
+    Ok((forward_dirty - spot_dirty + cash) / sensitivity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use convex_bonds::FixedRateBond;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn bond() -> FixedRateBond {
+        FixedRateBond::builder()
+            .cusip_unchecked("912828XX1")
+            .coupon_percent(4.0)
+            .maturity(date(2030, 2, 15))
+            .issue_date(date(2020, 2, 15))
+            .us_treasury()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_zero_repo_no_coupon_forward_clean_is_spot_less_accrual() {
+        let bond = bond();
+        let spot = date(2025, 3, 3);
+        let forward = date(2025, 4, 2);
+        let result = forward_price(&bond, spot, forward, 0.0, dec!(99.5)).unwrap();
+
+        // Dirty price carries unchanged; the clean price drops by the
+        // coupon accrued over the period.
+        let accrued_spot = bond.accrued_interest(spot).to_f64().unwrap();
+        let accrual = result.forward_accrued_per_100 - accrued_spot;
+        assert!(accrual > 0.0);
+        assert_eq!(result.interim_cash_per_100, 0.0);
+        assert_relative_eq!(
+            result.forward_dirty_per_100,
+            99.5 + accrued_spot,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            result.forward_clean_per_100,
+            99.5 - accrual,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(result.drop_from(99.5), accrual, epsilon = 1e-12);
+        assert!(result.forward_yield_decimal > 0.04);
+    }
+
+    #[test]
+    fn test_interim_coupon_is_deducted_with_reinvestment() {
+        let bond = bond();
+        let spot = date(2025, 7, 1);
+        let forward = date(2025, 9, 2);
+        let repo = 0.043;
+        let result = forward_price(&bond, spot, forward, repo, dec!(99.5)).unwrap();
+
+        // The 15 Aug coupon of 2.0 is reinvested for 18 days.
+        let coupon_fv = 2.0 * (1.0 + repo * 18.0 / 360.0);
+        assert_relative_eq!(result.interim_cash_per_100, coupon_fv, epsilon = 1e-12);
+
+        let spot_dirty = 99.5 + bond.accrued_interest(spot).to_f64().unwrap();
+        let expected_dirty = spot_dirty * (1.0 + repo * 63.0 / 360.0) - coupon_fv;
+        assert_relative_eq!(
+            result.forward_dirty_per_100,
+            expected_dirty,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            result.forward_clean_per_100,
+            expected_dirty - result.forward_accrued_per_100,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_implied_repo_round_trips_forward_price() {
+        let bond = bond();
+        let spot_clean = dec!(99.5);
+        // Without and with a coupon between spot and forward settlement.
+        for (spot, forward) in [
+            (date(2025, 3, 3), date(2025, 4, 2)),
+            (date(2025, 7, 1), date(2025, 9, 2)),
+        ] {
+            for repo in [0.0, 0.043, -0.005] {
+                let fwd = forward_price(&bond, spot, forward, repo, spot_clean).unwrap();
+                let forward_clean = Decimal::from_f64_retain(fwd.forward_clean_per_100).unwrap();
+                let implied =
+                    implied_repo(&bond, spot_clean, forward_clean, spot, forward).unwrap();
+                assert_relative_eq!(implied, repo, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_forward_date_must_follow_spot() {
+        let bond = bond();
+        let spot = date(2025, 3, 3);
+        assert!(forward_price(&bond, spot, spot, 0.04, dec!(99.5)).is_err());
+        assert!(forward_price(&bond, spot, date(2030, 2, 15), 0.04, dec!(99.5)).is_err());
+        assert!(implied_repo(&bond, dec!(99.5), dec!(99.4), spot, spot).is_err());
+    }
+}
@@ -49,6 +49,23 @@ pub fn yield_to_maturity_with_convention(
         .map_err(bond_err)
 }
 
+/// Yield to average life: the yield of a bullet maturing at the bond's
+/// weighted average life. Equals YTM for bullet bonds.
+pub fn yield_to_average_life(
+    bond: &dyn Bond,
+    settlement: Date,
+    clean_price: Decimal,
+    frequency: Frequency,
+) -> AnalyticsResult<YieldResult> {
+    bond.yield_to_average_life(settlement, clean_price, frequency)
+        .map_err(bond_err)
+}
+
+/// Weighted average life of the remaining principal (years).
+pub fn average_life(bond: &dyn Bond, settlement: Date) -> AnalyticsResult<f64> {
+    bond.average_life(settlement).map_err(bond_err)
+}
+
 // ============================================================================
 // PRICE
 // ============================================================================
@@ -238,6 +255,17 @@ mod tests {
         assert!((back - 105.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_yield_to_average_life_matches_ytm_for_bullet() {
+        let bond = create_test_bond();
+        let settlement = date(2021, 1, 15);
+        let ytm = yield_to_maturity(&bond, settlement, dec!(102), Frequency::SemiAnnual).unwrap();
+        let ytal =
+            yield_to_average_life(&bond, settlement, dec!(102), Frequency::SemiAnnual).unwrap();
+        assert!((ytm.yield_value - ytal.yield_value).abs() < 1e-9);
+        assert!(average_life(&bond, settlement).unwrap() > 4.0);
+    }
+
     #[test]
     fn test_modified_duration_range() {
         let bond = create_test_bond();
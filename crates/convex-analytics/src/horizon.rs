@@ -0,0 +1,308 @@
+//! Horizon (scenario) total return for a bond.
+//!
+//! The bond is bought at its dirty price for the beginning yield, collects
+//! its coupons up to the horizon and reinvests them, then is sold at its
+//! dirty price for the ending yield:
+//!
+//! ```text
+//! R = (Σ CF_i + reinvestment income + P_end − P_begin) / P_begin
+//! ```
+//!
+//! Coupons are reinvested to the horizon at the reinvestment rate,
+//! compounded at the bond's coupon frequency over ACT/365 time.
+//! [`breakeven_yield_shift`] solves for the yield move that leaves the
+//! return at zero.
+
+use std::cell::RefCell;
+
+use rust_decimal::prelude::ToPrimitive;
+
+use convex_bonds::traits::Bond;
+use convex_core::types::Date;
+use convex_math::error::MathError;
+use convex_math::solvers::{brent, SolverConfig};
+
+use crate::error::{AnalyticsError, AnalyticsResult};
+use crate::functions::dirty_price_from_yield;
+
+/// Output of [`horizon_return`]. Prices and cash are per 100 face.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizonReturnResult {
+    /// Dirty price per 100 at settlement, at the beginning yield.
+    pub begin_dirty_per_100: f64,
+    /// Dirty price per 100 at the horizon, at the ending yield.
+    pub end_dirty_per_100: f64,
+    /// Cash paid after settlement and on or before the horizon, per 100.
+    pub coupons_per_100: f64,
+    /// Interest earned reinvesting that cash to the horizon, per 100.
+    pub reinvestment_income_per_100: f64,
+    /// Total return over the holding period, as decimal (0.05 = 5%).
+    pub total_return_decimal: f64,
+}
+
+impl HorizonReturnResult {
+    /// Part of the total return from the change in dirty price.
+    #[must_use]
+    pub fn price_return_decimal(&self) -> f64 {
+        (self.end_dirty_per_100 - self.begin_dirty_per_100) / self.begin_dirty_per_100
+    }
+
+    /// Part of the total return from coupons and their reinvestment.
+    #[must_use]
+    pub fn income_return_decimal(&self) -> f64 {
+        (self.coupons_per_100 + self.reinvestment_income_per_100) / self.begin_dirty_per_100
+    }
+}
+
+/// Total return from holding `bond` from `settlement` to `horizon_date`.
+///
+/// The bond is priced at `begin_yield` on settlement and repriced at
+/// `end_yield` on the horizon. Yields are decimals compounded at the bond's
+/// frequency (street convention); the reinvestment rate is a decimal
+/// compounded at the same frequency.
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` if the horizon is not after
+/// settlement or a rate is not finite, and
+/// `AnalyticsError::InvalidSettlement` if the bond matures on or before the
+/// horizon.
+pub fn horizon_return(
+    bond: &dyn Bond,
+    settlement: Date,
+    horizon_date: Date,
+    begin_yield: f64,
+    end_yield: f64,
+    reinvestment_rate: f64,
+) -> AnalyticsResult<HorizonReturnResult> {
+    if !end_yield.is_finite() {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "end yield not finite ({end_yield})"
+        )));
+    }
+    let (begin_dirty, coupons, reinvestment_income) = begin_and_income(
+        bond,
+        settlement,
+        horizon_date,
+        begin_yield,
+        reinvestment_rate,
+    )?;
+    let end_dirty = dirty_price_from_yield(bond, horizon_date, end_yield, bond.frequency())?;
+
+    Ok(HorizonReturnResult {
+        begin_dirty_per_100: begin_dirty,
+        end_dirty_per_100: end_dirty,
+        coupons_per_100: coupons,
+        reinvestment_income_per_100: reinvestment_income,
+        total_return_decimal: (coupons + reinvestment_income + end_dirty - begin_dirty)
+            / begin_dirty,
+    })
+}
+
+/// Change in yield over the horizon at which [`horizon_return`] is zero.
+///
+/// Solves for the ending yield whose dirty price at the horizon, plus the
+/// coupons and reinvestment income collected on the way, just recovers the
+/// dirty price paid at `begin_yield`. Returns `end_yield − begin_yield` as
+/// a decimal (0.0025 = 25bp); positive when carry can absorb a sell-off.
+///
+/// # Errors
+///
+/// Returns the errors of [`horizon_return`], and
+/// `AnalyticsError::SolverConvergenceFailed` if no shift within ±50
+/// percentage points breaks even.
+pub fn breakeven_yield_shift(
+    bond: &dyn Bond,
+    settlement: Date,
+    horizon_date: Date,
+    begin_yield: f64,
+    reinvestment_rate: f64,
+) -> AnalyticsResult<f64> {
+    let (begin_dirty, coupons, reinvestment_income) = begin_and_income(
+        bond,
+        settlement,
+        horizon_date,
+        begin_yield,
+        reinvestment_rate,
+    )?;
+    let target = begin_dirty - coupons - reinvestment_income;
+
+    // Price at the horizon exactly as `horizon_return` does; the first
+    // pricing error, if any, is reported instead of the solver's.
+    let frequency = bond.frequency();
+    let pricing_error = RefCell::new(None);
+    let objective = |shift: f64| match dirty_price_from_yield(
+        bond,
+        horizon_date,
+        begin_yield + shift,
+        frequency,
+    ) {
+        Ok(price) => price - target,
+        Err(e) => {
+            pricing_error.borrow_mut().get_or_insert(e);
+            f64::NAN
+        }
+    };
+
+    let config = SolverConfig::new(1e-12, 100);
+    let result = brent(objective, -0.5, 0.5, &config);
+    if let Some(e) = pricing_error.into_inner() {
+        return Err(e);
+    }
+    let solver = "breakeven yield Brent".to_string();
+    match result {
+        Ok(result) => Ok(result.root),
+        Err(MathError::ConvergenceFailed {
+            iterations,
+            residual,
+        }) => Err(AnalyticsError::SolverConvergenceFailed {
+            solver,
+            iterations,
+            residual,
+        }),
+        Err(MathError::InvalidBracket { fa, fb, .. }) => {
+            Err(AnalyticsError::SolverConvergenceFailed {
+                solver,
+                iterations: 0,
+                residual: fa.abs().min(fb.abs()),
+            })
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Checks the inputs and returns the dirty price at settlement and the cash
+/// (and its reinvestment income) received up to the horizon, per 100.
+fn begin_and_income(
+    bond: &dyn Bond,
+    settlement: Date,
+    horizon_date: Date,
+    begin_yield: f64,
+    reinvestment_rate: f64,
+) -> AnalyticsResult<(f64, f64, f64)> {
+    if !(begin_yield.is_finite() && reinvestment_rate.is_finite()) {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "rates not finite (begin {begin_yield}, reinvestment {reinvestment_rate})"
+        )));
+    }
+    if horizon_date <= settlement {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "horizon {horizon_date} must be after settlement {settlement}"
+        )));
+    }
+    let maturity = bond
+        .maturity()
+        .ok_or_else(|| AnalyticsError::InvalidInput("bond has no maturity (perpetual)".into()))?;
+    if horizon_date >= maturity {
+        return Err(AnalyticsError::InvalidSettlement {
+            settlement: horizon_date.to_string(),
+            maturity: maturity.to_string(),
+        });
+    }
+
+    let frequency = bond.frequency();
+    let periods = f64::from(frequency.periods_per_year().max(1));
+
+    let begin_dirty = dirty_price_from_yield(bond, settlement, begin_yield, frequency)?;
+    if begin_dirty <= 0.0 {
+        return Err(AnalyticsError::CalculationFailed(
+            "non-positive dirty price at settlement".to_string(),
+        ));
+    }
+
+    let mut coupons = 0.0;
+    let mut reinvestment_income = 0.0;
+    for cf in bond
+        .cash_flows(settlement)
+        .iter()
+        .take_while(|cf| cf.date <= horizon_date)
+    {
+        let amount = cf.amount.to_f64().unwrap_or(0.0);
+        let years = cf.date.days_between(&horizon_date) as f64 / 365.0;
+        coupons += amount;
+        reinvestment_income +=
+            amount * ((1.0 + reinvestment_rate / periods).powf(periods * years) - 1.0);
+    }
+    Ok((begin_dirty, coupons, reinvestment_income))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use convex_bonds::FixedRateBond;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn bond() -> FixedRateBond {
+        FixedRateBond::builder()
+            .cusip_unchecked("912828XX1")
+            .coupon_percent(4.0)
+            .maturity(date(2030, 2, 15))
+            .issue_date(date(2020, 2, 15))
+            .us_treasury()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_unchanged_yield_earns_the_yield() {
+        let bond = bond();
+        let settlement = date(2025, 3, 3);
+        let horizon = date(2026, 3, 3);
+        let y = 0.045;
+        let result = horizon_return(&bond, settlement, horizon, y, y, y).unwrap();
+
+        // Two coupons (Aug 2025, Feb 2026) are collected and reinvested.
+        assert_relative_eq!(result.coupons_per_100, 4.0, epsilon = 1e-12);
+        assert!(result.reinvestment_income_per_100 > 0.0);
+
+        // Reinvesting at the yield and selling at the yield earns the yield,
+        // compounded semi-annually over the year.
+        let expected = (1.0 + y / 2.0).powi(2) - 1.0;
+        assert_relative_eq!(result.total_return_decimal, expected, epsilon = 2e-4);
+        assert_relative_eq!(
+            result.price_return_decimal() + result.income_return_decimal(),
+            result.total_return_decimal,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_higher_end_yield_lowers_return() {
+        let bond = bond();
+        let settlement = date(2025, 3, 3);
+        let horizon = date(2026, 3, 3);
+        let flat = horizon_return(&bond, settlement, horizon, 0.045, 0.045, 0.045).unwrap();
+        let selloff = horizon_return(&bond, settlement, horizon, 0.045, 0.055, 0.045).unwrap();
+
+        assert!(selloff.total_return_decimal < flat.total_return_decimal);
+        assert_relative_eq!(selloff.begin_dirty_per_100, flat.begin_dirty_per_100);
+    }
+
+    #[test]
+    fn test_breakeven_shift_for_positive_carry_bond() {
+        let bond = bond();
+        let settlement = date(2025, 3, 3);
+        let horizon = date(2025, 9, 3);
+        let y = 0.045;
+        let shift = breakeven_yield_shift(&bond, settlement, horizon, y, y).unwrap();
+
+        // Six months of carry on a ~4.5-year bond absorbs a rise of roughly
+        // y/2 / duration ≈ 2.25% / 4.1 ≈ 55bp.
+        assert!(shift > 0.004 && shift < 0.007, "breakeven shift {shift}");
+
+        let result = horizon_return(&bond, settlement, horizon, y, y + shift, y).unwrap();
+        assert_relative_eq!(result.total_return_decimal, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_rejects_horizon_outside_life() {
+        let bond = bond();
+        let settlement = date(2025, 3, 3);
+        assert!(horizon_return(&bond, settlement, settlement, 0.04, 0.04, 0.04).is_err());
+        assert!(horizon_return(&bond, settlement, date(2030, 3, 1), 0.04, 0.04, 0.04).is_err());
+    }
+}
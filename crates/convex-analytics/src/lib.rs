@@ -70,6 +70,12 @@ pub mod functions;
 // Mark-driven pricing (the trader-mark sovereignty entry point)
 pub mod pricing;
 
+// Forward prices for repo and forward-settling trades
+pub mod forward;
+
+// Scenario total return over a holding period
+pub mod horizon;
+
 // JSON-wire DTOs for FFI / MCP / WASM boundaries.
 pub mod dto;
 
@@ -92,38 +98,44 @@ pub mod prelude {
 
     // Yields
     pub use crate::yields::{
-        current_yield, simple_yield, street_convention_yield, RollForwardMethod,
-        ShortDateCalculator, StandardYieldEngine, YieldEngine, YieldEngineResult, YieldResult,
-        YieldSolver,
+        current_yield, flat_pv, jgb_simple_yield, simple_yield, street_convention_yield, xirr,
+        RollForwardMethod, ShortDateCalculator, StandardYieldEngine, YieldEngine,
+        YieldEngineResult, YieldResult, YieldSolver,
     };
 
     // Risk
     pub use crate::risk::{
-        aggregate_portfolio_risk, analytical_convexity, compute_position_risk,
-        duration_hedge_ratio, dv01_from_duration, dv01_from_prices, dv01_hedge_ratio,
-        dv01_per_100_face, historical_var, key_rate_duration_at_tenor, modified_from_macaulay,
-        notional_from_dv01, parametric_var, parametric_var_from_dv01, price_change_from_duration,
-        price_change_with_convexity, residual_from, spread_duration, BondFuture,
-        BondRiskCalculator, BondRiskMetrics, ComparisonReport, ComparisonRow, Constraints,
-        Convexity, Duration, EffectiveDurationCalculator, HedgeInstrument, HedgeProposal,
-        HedgeTrade, InterestRateSwap, KeyRateBucket, KeyRateDuration, KeyRateDurationCalculator,
-        KeyRateDurations, PortfolioRisk, Position, Provenance, Recommendation,
-        RecommendationReason, ResidualRisk, RiskProfile, SwapSide, TradeoffNotes, VaRMethod,
-        VaRResult, ADVISOR_KEY_RATE_TENORS, DEFAULT_BUMP_SIZE, DV01, SMALL_BUMP_SIZE,
-        STANDARD_KEY_RATE_TENORS,
+        aggregate_portfolio_risk, analytical_convexity, batch_risk, bond_risk,
+        compute_position_risk, duration_hedge_ratio, dv01_from_duration, dv01_from_prices,
+        dv01_hedge_ratio, dv01_per_100_face, historical_var, key_rate_duration_at_tenor,
+        modified_from_macaulay, notional_from_dv01, parametric_var, parametric_var_from_dv01,
+        price_change_from_duration, price_change_with_convexity, residual_from, spread_duration,
+        BondFuture, BondRiskCalculator, BondRiskMetrics, BondRiskRecord, ComparisonReport,
+        ComparisonRow, Constraints, Convexity, Duration, EffectiveDurationCalculator,
+        HedgeInstrument, HedgeProposal, HedgeTrade, InterestRateSwap, KeyRateBucket,
+        KeyRateDuration, KeyRateDurationCalculator, KeyRateDurations, PortfolioRisk, Position,
+        Provenance, Recommendation, RecommendationReason, ResidualRisk, RiskContext, RiskProfile,
+        SwapSide, TradeoffNotes, VaRMethod, VaRResult, ADVISOR_KEY_RATE_TENORS, DEFAULT_BUMP_SIZE,
+        DV01, SMALL_BUMP_SIZE, STANDARD_KEY_RATE_TENORS,
     };
 
     // Spreads
     pub use crate::spreads::{
+        // Z-spread
+        effective_convexity_from_curve,
+        effective_duration_from_curve,
         // G-spread
         g_spread,
         g_spread_with_benchmark,
         // I-spread
         i_spread,
+        partial_spread_durations,
+        // Survival-weighted PV
+        risky_present_value,
         // Discount Margin
         simple_margin,
+        spread_duration_from_curve,
         z_discount_margin,
-        // Z-spread
         z_spread,
         z_spread_from_curve,
         // Types
@@ -135,10 +147,12 @@ pub mod prelude {
         GovernmentCurve,
         ISpreadCalculator,
         // OAS
+        LatticeType,
         OASCalculator,
         // ASW
         ParParAssetSwap,
         ProceedsAssetSwap,
+        RateDynamics,
         SecurityId,
         Sovereign,
         SupranationalIssuer,
@@ -149,17 +163,25 @@ pub mod prelude {
     pub use crate::yas::{
         calculate_accrued_amount, calculate_proceeds, calculate_settlement_date,
         BatchYASCalculator, BloombergReference, SettlementInvoice, SettlementInvoiceBuilder,
-        ValidationFailure, YASCalculator, YASResult, YasAnalysis, YasAnalysisBuilder,
+        ValidationFailure, YASCalculator, YASRecord, YASResult, YasAnalysis, YasAnalysisBuilder,
     };
 
     // Options (callable/puttable bonds)
     pub use crate::options::{BinomialTree, HullWhite, ModelError, ShortRateModel};
 
     // Mark-driven pricing
-    pub use crate::pricing::{price_from_mark, PricingResult};
+    pub use crate::pricing::{price_from_mark, price_with_spread, PricingResult};
+
+    // Forward pricing
+    pub use crate::forward::{forward_price, implied_repo, ForwardPriceResult};
+
+    // Horizon total return
+    pub use crate::horizon::{breakeven_yield_shift, horizon_return, HorizonReturnResult};
 
     // Standalone bond analytics functions (replacing BondAnalytics trait)
     pub use crate::functions::{
+        // Average life
+        average_life,
         clean_price_from_yield,
         // Convexity calculations
         convexity,
@@ -178,6 +200,7 @@ pub mod prelude {
         // Helper
         parse_day_count,
         // Yield calculations
+        yield_to_average_life,
         yield_to_maturity,
         yield_to_maturity_with_convention,
     };
@@ -3,6 +3,9 @@
 //! `price_from_mark` accepts a trader [`Mark`] (price, yield, or spread) and
 //! returns the canonical bond quote: clean, dirty, accrued, derived YTM, and
 //! — when the mark itself was a spread — the spread in basis points.
+//!
+//! `price_with_spread` prices a bond at a curve plus a flat credit spread,
+//! the inverse of the Z-spread solver.
 
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
@@ -146,6 +149,57 @@ where
     })
 }
 
+/// Price a bond off `curve` plus a flat spread.
+///
+/// Each cash flow is discounted at the curve zero rate plus `spread_bps`
+/// (continuously compounded), the same discounting [`ZSpreadCalculator`]
+/// solves, so the Z-spread of the returned dirty price is `spread_bps`. The
+/// YTM is derived in the bond's own coupon frequency.
+///
+/// # Errors
+///
+/// Returns an error if the bond is perpetual or expired at settlement, the
+/// curve cannot discount to settlement, or the yield does not solve.
+pub fn price_with_spread<B>(
+    bond: &B,
+    curve: &dyn RateCurveDyn,
+    spread_bps: f64,
+    settlement: Date,
+) -> AnalyticsResult<PricingResult>
+where
+    B: Bond + FixedCouponBond,
+{
+    if !spread_bps.is_finite() {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "spread must be finite (got {spread_bps})"
+        )));
+    }
+    let dirty = ZSpreadCalculator::new(curve).try_price_with_spread(
+        bond,
+        spread_bps / 10_000.0,
+        settlement,
+    )?;
+
+    let accrued = dec_to_f64(bond.accrued_interest(settlement), "accrued")?;
+    let clean = dirty - accrued;
+    let ytm_decimal = yield_to_maturity(
+        bond,
+        settlement,
+        f64_to_dec(clean, "clean price")?,
+        bond.frequency(),
+    )?
+    .yield_value;
+
+    Ok(PricingResult {
+        clean_price_per_100: clean,
+        dirty_price_per_100: dirty,
+        accrued_per_100: accrued,
+        ytm_decimal,
+        z_spread_bps: Some(spread_bps),
+        oas_bps: None,
+    })
+}
+
 /// Price a callable bond against a trader [`Mark`], including OAS marks.
 ///
 /// Non-OAS marks (price/yield/Z/I/G-spread) are forwarded to [`price_from_mark`]
@@ -345,6 +399,39 @@ mod tests {
         assert!(r.clean_price_per_100 > 0.0);
     }
 
+    #[test]
+    fn price_with_spread_inverts_z_spread() {
+        let bond = bond_5pct_10y();
+        let curve = flat_curve(0.04);
+        let settle = d(2025, 4, 15);
+
+        let r = price_with_spread(&bond, &curve, 125.0, settle).unwrap();
+        assert!((r.dirty_price_per_100 - r.clean_price_per_100 - r.accrued_per_100).abs() < 1e-9);
+        assert_eq!(r.z_spread_bps, Some(125.0));
+
+        let z = ZSpreadCalculator::new(&curve)
+            .calculate(
+                &bond,
+                f64_to_dec(r.dirty_price_per_100, "dirty").unwrap(),
+                settle,
+            )
+            .unwrap();
+        assert_eq!(z.as_bps(), dec!(125));
+
+        // A wider spread discounts harder
+        let wider = price_with_spread(&bond, &curve, 200.0, settle).unwrap();
+        assert!(wider.clean_price_per_100 < r.clean_price_per_100);
+        assert!(wider.ytm_decimal > r.ytm_decimal);
+    }
+
+    #[test]
+    fn price_with_spread_rejects_expired_bond() {
+        let bond = bond_5pct_10y();
+        let curve = flat_curve(0.04);
+        let err = price_with_spread(&bond, &curve, 50.0, d(2035, 1, 15)).unwrap_err();
+        assert!(matches!(err, AnalyticsError::InvalidSettlement { .. }));
+    }
+
     #[test]
     fn spread_mark_without_curve_errors() {
         let bond = bond_5pct_10y();
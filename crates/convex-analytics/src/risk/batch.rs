@@ -0,0 +1,196 @@
+//! Batch risk over a bond universe.
+//!
+//! [`batch_risk`] prices each bond off a curve and computes its
+//! [`BondRiskMetrics`]. Bonds are independent, so with the `parallel`
+//! feature the universe is spread across rayon's thread pool; without it
+//! the bonds are processed in turn. Either way results come back in input
+//! order and match [`bond_risk`] on each bond.
+
+use rust_decimal::prelude::*;
+
+use convex_bonds::traits::Bond;
+use convex_core::types::{Compounding, Date};
+use convex_curves::{RateCurve, TermStructure};
+
+use crate::error::{AnalyticsError, AnalyticsResult};
+use crate::functions::yield_to_maturity;
+use crate::risk::calculator::{BondRiskCalculator, BondRiskMetrics};
+use crate::risk::context::RiskContext;
+
+/// Risk metrics for one bond priced off `curve`.
+///
+/// The dirty price is the present value of the bond's flows off the curve,
+/// per 100 face; the yield is the bond's yield to maturity at that price in
+/// its own coupon frequency.
+///
+/// # Errors
+///
+/// Returns an error if the bond has no flows after settlement, a discount
+/// factor cannot be computed or the yield does not solve.
+pub fn bond_risk<T: TermStructure>(
+    bond: &dyn Bond,
+    settlement: Date,
+    curve: &RateCurve<T>,
+) -> AnalyticsResult<BondRiskMetrics> {
+    let face = bond.face_value().to_f64().unwrap_or(0.0);
+    if face <= 0.0 {
+        return Err(AnalyticsError::InvalidInput(
+            "face value must be positive".to_string(),
+        ));
+    }
+    let dirty = RiskContext::new(bond, settlement).price(curve)? / face * 100.0;
+    let accrued = bond.accrued_interest(settlement).to_f64().unwrap_or(0.0) / face * 100.0;
+    let clean = Decimal::from_f64(dirty - accrued)
+        .ok_or_else(|| AnalyticsError::CalculationFailed(format!("invalid curve price {dirty}")))?;
+
+    let frequency = bond.frequency();
+    let ytm = yield_to_maturity(bond, settlement, clean, frequency)?.yield_value;
+    BondRiskCalculator::from_bond(bond, settlement, dirty, ytm, Compounding::from(frequency))?
+        .all_metrics()
+}
+
+/// Risk metrics for each bond in `bonds`, in input order.
+///
+/// Runs across rayon's thread pool with the `parallel` feature and
+/// sequentially otherwise; each result equals [`bond_risk`] on that bond.
+#[cfg(feature = "parallel")]
+pub fn batch_risk<T: TermStructure>(
+    bonds: &[&(dyn Bond + Sync)],
+    settlement: Date,
+    curve: &RateCurve<T>,
+) -> Vec<AnalyticsResult<BondRiskMetrics>> {
+    use rayon::prelude::*;
+
+    bonds
+        .par_iter()
+        .map(|bond| bond_risk(*bond, settlement, curve))
+        .collect()
+}
+
+/// Risk metrics for each bond in `bonds`, in input order (non-parallel
+/// fallback).
+#[cfg(not(feature = "parallel"))]
+pub fn batch_risk<T: TermStructure>(
+    bonds: &[&(dyn Bond + Sync)],
+    settlement: Date,
+    curve: &RateCurve<T>,
+) -> Vec<AnalyticsResult<BondRiskMetrics>> {
+    bonds
+        .iter()
+        .map(|bond| bond_risk(*bond, settlement, curve))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use convex_bonds::FixedRateBond;
+    use convex_core::daycounts::DayCountConvention;
+    use convex_curves::{DiscreteCurve, InterpolationMethod, ValueType};
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_batch_matches_individual_in_order() {
+        let settlement = date(2025, 1, 15);
+        let curve = RateCurve::new(
+            DiscreteCurve::new(
+                settlement,
+                vec![0.25, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0],
+                vec![0.030, 0.032, 0.035, 0.041, 0.047, 0.053, 0.055],
+                ValueType::ZeroRate {
+                    compounding: Compounding::Continuous,
+                    day_count: DayCountConvention::Act365Fixed,
+                },
+                InterpolationMethod::Linear,
+            )
+            .unwrap(),
+        );
+        let bonds: Vec<FixedRateBond> = (1..=24_i32)
+            .map(|i| {
+                FixedRateBond::builder()
+                    .cusip_unchecked(&format!("BATCH{i:03}"))
+                    .coupon_rate(Decimal::new(20 + 2 * i64::from(i), 3))
+                    .maturity(date(2025 + i, 11, 15))
+                    .issue_date(date(2024, 11, 15))
+                    .us_treasury()
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        let universe: Vec<&(dyn Bond + Sync)> =
+            bonds.iter().map(|b| b as &(dyn Bond + Sync)).collect();
+
+        let batch = batch_risk(&universe, settlement, &curve);
+
+        assert_eq!(batch.len(), bonds.len());
+        let mut previous = 0.0;
+        for (bond, result) in bonds.iter().zip(&batch) {
+            let batched = result.as_ref().unwrap();
+            let single = bond_risk(bond, settlement, &curve).unwrap();
+            assert_eq!(
+                batched.modified_duration.as_f64(),
+                single.modified_duration.as_f64()
+            );
+            assert_eq!(
+                batched.macaulay_duration.as_f64(),
+                single.macaulay_duration.as_f64()
+            );
+            assert_eq!(batched.convexity.as_f64(), single.convexity.as_f64());
+            assert_eq!(batched.dv01.as_f64(), single.dv01.as_f64());
+            assert_eq!(batched.dv01_per_100.as_f64(), single.dv01_per_100.as_f64());
+            // Input order: maturities lengthen, so duration rises.
+            assert!(batched.modified_duration.as_f64() > previous);
+            previous = batched.modified_duration.as_f64();
+        }
+    }
+
+    #[test]
+    fn test_bimonthly_bond_compounds_six_times_a_year() {
+        use convex_core::types::{Frequency, MonthStep};
+
+        let settlement = date(2025, 1, 15);
+        let curve = RateCurve::new(
+            DiscreteCurve::new(
+                settlement,
+                vec![1.0, 5.0, 10.0],
+                vec![0.040, 0.042, 0.045],
+                ValueType::ZeroRate {
+                    compounding: Compounding::Continuous,
+                    day_count: DayCountConvention::Act365Fixed,
+                },
+                InterpolationMethod::Linear,
+            )
+            .unwrap(),
+        );
+        let frequency = Frequency::CustomMonths(MonthStep::new(2).unwrap());
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("BIMONTH01")
+            .coupon_rate(Decimal::new(5, 2))
+            .maturity(date(2030, 3, 15))
+            .issue_date(date(2024, 11, 15))
+            .frequency(frequency)
+            .day_count(DayCountConvention::Thirty360US)
+            .build()
+            .unwrap();
+
+        let risk = bond_risk(&bond, settlement, &curve).unwrap();
+        let dirty = RiskContext::new(&bond, settlement).price(&curve).unwrap();
+        let accrued = bond.accrued_interest(settlement).to_f64().unwrap();
+        let clean = Decimal::from_f64(dirty - accrued).unwrap();
+        let ytm = yield_to_maturity(&bond, settlement, clean, frequency)
+            .unwrap()
+            .yield_value;
+
+        assert_eq!(
+            Compounding::from(frequency),
+            Compounding::from_periods_per_year(6)
+        );
+        let expected = risk.macaulay_duration.as_f64() / (1.0 + ytm / 6.0);
+        assert!((risk.modified_duration.as_f64() - expected).abs() < 1e-12);
+        // Not the continuous-compounding duration, which equals Macaulay.
+        assert!(risk.macaulay_duration.as_f64() - risk.modified_duration.as_f64() > 1e-3);
+    }
+}
@@ -1,11 +1,14 @@
 //! `BondRiskCalculator`: duration, convexity, DV01 for a single bond.
 
 use rust_decimal::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use convex_bonds::traits::Bond;
 use convex_core::types::{Compounding, Date};
+use convex_curves::{RateCurve, TermStructure};
 
 use crate::error::{AnalyticsError, AnalyticsResult};
+use crate::risk::context::RiskContext;
 use crate::risk::convexity::{analytical_convexity, effective_convexity, Convexity};
 use crate::risk::duration::{
     effective_duration, key_rate_duration_at_tenor, macaulay_duration, modified_duration,
@@ -14,7 +17,10 @@ use crate::risk::duration::{
 use crate::risk::dv01::{dv01_from_duration, DV01};
 
 /// Complete risk metrics for a bond.
-#[derive(Debug, Clone)]
+///
+/// Serializes as a flat [`BondRiskRecord`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "BondRiskRecord", from = "BondRiskRecord")]
 pub struct BondRiskMetrics {
     /// Macaulay duration (weighted average time to cash flows).
     pub macaulay_duration: Duration,
@@ -47,6 +53,61 @@ impl BondRiskMetrics {
     }
 }
 
+impl BondRiskMetrics {
+    /// Flat record of the metrics with explicit units, for analytics stores.
+    #[must_use]
+    pub fn to_record(&self) -> BondRiskRecord {
+        BondRiskRecord {
+            macaulay_duration_years: self.macaulay_duration.years(),
+            modified_duration_years: self.modified_duration.years(),
+            convexity_years2: self.convexity.value(),
+            dv01_per_100: self.dv01_per_100.value(),
+            dv01: self.dv01.value(),
+        }
+    }
+}
+
+/// Flat, stable schema for [`BondRiskMetrics`].
+///
+/// Values serialize as decimal strings so nothing is lost to float
+/// conversion on the way to storage or CSV.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BondRiskRecord {
+    /// Macaulay duration, years.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub macaulay_duration_years: Decimal,
+    /// Modified duration, years.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub modified_duration_years: Decimal,
+    /// Convexity, years squared.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub convexity_years2: Decimal,
+    /// DV01 per 100 face, price points per basis point.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub dv01_per_100: Decimal,
+    /// DV01 of the position, currency units per basis point.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub dv01: Decimal,
+}
+
+impl From<BondRiskMetrics> for BondRiskRecord {
+    fn from(metrics: BondRiskMetrics) -> Self {
+        metrics.to_record()
+    }
+}
+
+impl From<BondRiskRecord> for BondRiskMetrics {
+    fn from(record: BondRiskRecord) -> Self {
+        Self {
+            macaulay_duration: Duration::new(record.macaulay_duration_years),
+            modified_duration: Duration::new(record.modified_duration_years),
+            convexity: Convexity::new(record.convexity_years2),
+            dv01_per_100: DV01::new(record.dv01_per_100),
+            dv01: DV01::new(record.dv01),
+        }
+    }
+}
+
 /// Calculator for bond risk metrics.
 pub struct BondRiskCalculator {
     times: Vec<f64>,
@@ -334,10 +395,40 @@ impl KeyRateDurationCalculator {
         Ok(KeyRateDurations::new(durations?))
     }
 
+    /// Calculates key rate durations by bumping the discount curve itself.
+    ///
+    /// Each tenor gets a triangular
+    /// [`KeyRateBump`](convex_curves::KeyRateBump) of ± the bump size,
+    /// falling to zero at the neighbouring tenors of this calculator's
+    /// ladder, and the bond's remaining cash flows are repriced off the
+    /// bumped curve, generating the flows once (see [`RiskContext`]). The
+    /// triangles sum to one everywhere, so the buckets add
+    /// up to the effective duration from a parallel curve shift. Use
+    /// `with_tenors(STANDARD_KEY_TENORS.to_vec())` for the curve crate's
+    /// standard ladder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a discount factor cannot be computed or the bond
+    /// has no value on the curve.
+    pub fn from_curve<T: TermStructure>(
+        &self,
+        bond: &dyn Bond,
+        curve: &RateCurve<T>,
+        settlement: Date,
+    ) -> AnalyticsResult<KeyRateDurations> {
+        RiskContext::new(bond, settlement).key_rate_durations(curve, self)
+    }
+
     /// Returns the tenors being analyzed.
     pub fn tenors(&self) -> &[f64] {
         &self.tenors
     }
+
+    /// Returns the bump size as a decimal.
+    pub fn bump_size(&self) -> f64 {
+        self.bump_size
+    }
 }
 
 #[cfg(test)]
@@ -367,6 +458,33 @@ mod tests {
         assert_relative_eq!(metrics.dv01_per_100.as_f64(), 0.0188, epsilon = 0.001);
     }
 
+    #[test]
+    fn test_risk_metrics_serialize_round_trip() {
+        let metrics = BondRiskCalculator::from_cash_flows(
+            vec![0.5, 1.0, 1.5, 2.0],
+            vec![2.5, 2.5, 2.5, 102.5],
+            0.05,
+            Compounding::SemiAnnual,
+            100.0,
+            1_000_000.0,
+        )
+        .unwrap()
+        .all_metrics()
+        .unwrap();
+
+        let json = serde_json::to_value(&metrics).unwrap();
+        let fields = json.as_object().unwrap();
+        assert_eq!(fields.len(), 5);
+        assert!(fields.values().all(serde_json::Value::is_string));
+        assert_eq!(
+            json["modified_duration_years"],
+            metrics.modified_duration.years().to_string()
+        );
+
+        let back: BondRiskMetrics = serde_json::from_value(json).unwrap();
+        assert_eq!(back.to_record(), metrics.to_record());
+    }
+
     #[test]
     fn test_bond_risk_calculator_zero_coupon() {
         let calc = BondRiskCalculator::from_cash_flows(
@@ -459,6 +577,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_curve_key_rate_durations_sum_to_parallel_duration() {
+        use convex_bonds::FixedRateBond;
+        use convex_core::daycounts::DayCountConvention;
+        use convex_curves::bumping::ParallelBump;
+        use convex_curves::{DiscreteCurve, InterpolationMethod, ValueType, STANDARD_KEY_TENORS};
+        use rust_decimal_macros::dec;
+
+        let date = |y, m, d| Date::from_ymd(y, m, d).unwrap();
+        let settlement = date(2025, 1, 15);
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("TEST12Y4")
+            .coupon_rate(dec!(0.045))
+            .maturity(date(2037, 3, 1))
+            .issue_date(date(2024, 3, 1))
+            .us_treasury()
+            .build()
+            .unwrap();
+        // Steep curve: 3% at the short end to 5.5% at 30Y.
+        let curve = RateCurve::new(
+            DiscreteCurve::new(
+                settlement,
+                vec![0.25, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0],
+                vec![0.030, 0.032, 0.035, 0.041, 0.047, 0.053, 0.055],
+                ValueType::ZeroRate {
+                    compounding: Compounding::Continuous,
+                    day_count: DayCountConvention::Act365Fixed,
+                },
+                InterpolationMethod::Linear,
+            )
+            .unwrap(),
+        );
+
+        let calc = KeyRateDurationCalculator::with_tenors(STANDARD_KEY_TENORS.to_vec());
+        let krds = calc.from_curve(&bond, &curve, settlement).unwrap();
+        assert_eq!(krds.durations.len(), STANDARD_KEY_TENORS.len());
+
+        let bump = 0.0001;
+        let context = RiskContext::new(&bond, settlement);
+        let base = context.price(&curve).unwrap();
+        let up = RateCurve::new(ParallelBump::new(1.0).apply(curve.inner()));
+        let down = RateCurve::new(ParallelBump::new(-1.0).apply(curve.inner()));
+        let effective = effective_duration(
+            context.price(&up).unwrap(),
+            context.price(&down).unwrap(),
+            base,
+            bump,
+        )
+        .unwrap();
+
+        assert_relative_eq!(
+            krds.total_duration().as_f64(),
+            effective.as_f64(),
+            epsilon = 1e-5
+        );
+        // Cash flows end at ~12Y: buckets beyond 15Y carry nothing.
+        assert!(krds.at_tenor(10.0).unwrap().duration.as_f64() > 1.0);
+        assert_relative_eq!(krds.at_tenor(20.0).unwrap().duration.as_f64(), 0.0);
+    }
+
     #[test]
     fn test_price_change_estimation() {
         let times = vec![0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0];
@@ -0,0 +1,310 @@
+//! `RiskContext`: a bond's cash flows cached for bump-and-reprice risk.
+//!
+//! Effective duration, effective convexity and curve key rate durations
+//! reprice the same bond off many bumped curves. The flows of a fixed bond
+//! do not depend on the curve, so the context generates them once and each
+//! reprice only rediscounts.
+
+use rust_decimal::prelude::*;
+
+use convex_bonds::traits::Bond;
+use convex_core::types::Date;
+use convex_curves::bumping::{KeyRateBump, ParallelBump};
+use convex_curves::{RateCurve, TermStructure};
+
+use crate::error::{AnalyticsError, AnalyticsResult};
+use crate::risk::calculator::KeyRateDurationCalculator;
+use crate::risk::convexity::{effective_convexity, Convexity};
+use crate::risk::duration::{effective_duration, Duration, KeyRateDurations};
+
+/// A bond's remaining cash flows, generated once for repeated repricing.
+///
+/// Flows are taken as of construction; bonds whose flows depend on the
+/// curve (floaters projected off it, callables) need their own repricing.
+#[derive(Debug, Clone)]
+pub struct RiskContext {
+    settlement: Date,
+    cash_flows: Vec<(Date, f64)>,
+}
+
+impl RiskContext {
+    /// Caches the cash flows of `bond` paid after `settlement`.
+    pub fn new(bond: &dyn Bond, settlement: Date) -> Self {
+        let cash_flows = bond
+            .cash_flows(settlement)
+            .iter()
+            .map(|cf| (cf.date, cf.amount.to_f64().unwrap_or(0.0)))
+            .collect();
+        Self {
+            settlement,
+            cash_flows,
+        }
+    }
+
+    /// Returns the settlement date.
+    pub fn settlement(&self) -> Date {
+        self.settlement
+    }
+
+    /// Returns the cached `(payment date, amount)` flows.
+    pub fn cash_flows(&self) -> &[(Date, f64)] {
+        &self.cash_flows
+    }
+
+    /// Present value of the cached flows off `curve`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError::CurveError` if a discount factor cannot be
+    /// computed.
+    pub fn price<T: TermStructure>(&self, curve: &RateCurve<T>) -> AnalyticsResult<f64> {
+        self.cash_flows
+            .iter()
+            .map(|(date, amount)| {
+                let df = curve
+                    .discount_factor(*date)
+                    .map_err(|e| AnalyticsError::CurveError(e.to_string()))?;
+                Ok(amount * df)
+            })
+            .sum()
+    }
+
+    /// Prices off the curve and off parallel shifts of it by ± `bump_bps`:
+    /// `(base, up, down)`.
+    fn parallel_prices<T: TermStructure>(
+        &self,
+        curve: &RateCurve<T>,
+        bump_bps: f64,
+    ) -> AnalyticsResult<(f64, f64, f64)> {
+        let shifted = |bps| RateCurve::new(ParallelBump::new(bps).apply(curve.inner()));
+        Ok((
+            self.price(curve)?,
+            self.price(&shifted(bump_bps))?,
+            self.price(&shifted(-bump_bps))?,
+        ))
+    }
+
+    /// Effective duration from parallel shifts of the curve by ± `bump_bps`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a discount factor cannot be computed, the flows
+    /// have no value on the curve or the bump is zero.
+    pub fn effective_duration<T: TermStructure>(
+        &self,
+        curve: &RateCurve<T>,
+        bump_bps: f64,
+    ) -> AnalyticsResult<Duration> {
+        let (base, up, down) = self.parallel_prices(curve, bump_bps)?;
+        effective_duration(up, down, base, bump_bps / 10_000.0)
+    }
+
+    /// Effective convexity from parallel shifts of the curve by ± `bump_bps`.
+    ///
+    /// # Errors
+    ///
+    /// As for [`effective_duration`](Self::effective_duration).
+    pub fn effective_convexity<T: TermStructure>(
+        &self,
+        curve: &RateCurve<T>,
+        bump_bps: f64,
+    ) -> AnalyticsResult<Convexity> {
+        let (base, up, down) = self.parallel_prices(curve, bump_bps)?;
+        effective_convexity(up, down, base, bump_bps / 10_000.0)
+    }
+
+    /// Key rate durations for the tenors and bump size of `calculator`; see
+    /// [`KeyRateDurationCalculator::from_curve`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a discount factor cannot be computed or the flows
+    /// have no value on the curve.
+    pub fn key_rate_durations<T: TermStructure>(
+        &self,
+        curve: &RateCurve<T>,
+        calculator: &KeyRateDurationCalculator,
+    ) -> AnalyticsResult<KeyRateDurations> {
+        let base_price = self.price(curve)?;
+        let bump_bps = calculator.bump_size() * 10_000.0;
+        let ups = KeyRateBump::custom_profile(calculator.tenors(), bump_bps);
+        let downs = KeyRateBump::custom_profile(calculator.tenors(), -bump_bps);
+
+        let tenor_prices = ups
+            .iter()
+            .zip(&downs)
+            .map(|(up, down)| {
+                let price_up = self.price(&RateCurve::new(up.apply(curve.inner())))?;
+                let price_down = self.price(&RateCurve::new(down.apply(curve.inner())))?;
+                Ok((up.key_tenor(), price_up, price_down))
+            })
+            .collect::<AnalyticsResult<Vec<_>>>()?;
+
+        calculator.calculate(base_price, &tenor_prices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    use convex_bonds::traits::BondCashFlow;
+    use convex_bonds::types::{BondIdentifiers, BondType, CalendarId};
+    use convex_bonds::FixedRateBond;
+    use convex_core::daycounts::DayCountConvention;
+    use convex_core::types::Compounding;
+    use convex_core::types::Frequency;
+    use convex_core::Currency;
+    use convex_curves::{DiscreteCurve, InterpolationMethod, ValueType, STANDARD_KEY_TENORS};
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    /// Counts how often its cash flows are generated.
+    struct CountingBond {
+        inner: FixedRateBond,
+        cash_flow_calls: Cell<usize>,
+    }
+
+    impl Bond for CountingBond {
+        fn identifiers(&self) -> &BondIdentifiers {
+            self.inner.identifiers()
+        }
+        fn bond_type(&self) -> BondType {
+            self.inner.bond_type()
+        }
+        fn currency(&self) -> Currency {
+            self.inner.currency()
+        }
+        fn maturity(&self) -> Option<Date> {
+            self.inner.maturity()
+        }
+        fn issue_date(&self) -> Date {
+            self.inner.issue_date()
+        }
+        fn first_settlement_date(&self) -> Date {
+            self.inner.first_settlement_date()
+        }
+        fn dated_date(&self) -> Date {
+            self.inner.dated_date()
+        }
+        fn face_value(&self) -> Decimal {
+            self.inner.face_value()
+        }
+        fn frequency(&self) -> Frequency {
+            self.inner.frequency()
+        }
+        fn cash_flows(&self, from: Date) -> Vec<BondCashFlow> {
+            self.cash_flow_calls.set(self.cash_flow_calls.get() + 1);
+            self.inner.cash_flows(from)
+        }
+        fn next_coupon_date(&self, after: Date) -> Option<Date> {
+            self.inner.next_coupon_date(after)
+        }
+        fn previous_coupon_date(&self, before: Date) -> Option<Date> {
+            self.inner.previous_coupon_date(before)
+        }
+        fn accrued_interest(&self, settlement: Date) -> Decimal {
+            self.inner.accrued_interest(settlement)
+        }
+        fn day_count_convention(&self) -> &str {
+            self.inner.day_count_convention()
+        }
+        fn calendar(&self) -> &CalendarId {
+            self.inner.calendar()
+        }
+    }
+
+    #[test]
+    fn test_cached_reprices_match_regenerated_flows() {
+        let settlement = date(2025, 1, 15);
+        let bond = CountingBond {
+            inner: FixedRateBond::builder()
+                .cusip_unchecked("TEST30Y1")
+                .coupon_rate(dec!(0.045))
+                .maturity(date(2054, 11, 15))
+                .issue_date(date(2024, 11, 15))
+                .us_treasury()
+                .build()
+                .unwrap(),
+            cash_flow_calls: Cell::new(0),
+        };
+        let curve = RateCurve::new(
+            DiscreteCurve::new(
+                settlement,
+                vec![0.25, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0],
+                vec![0.030, 0.032, 0.035, 0.041, 0.047, 0.053, 0.055],
+                ValueType::ZeroRate {
+                    compounding: Compounding::Continuous,
+                    day_count: DayCountConvention::Act365Fixed,
+                },
+                InterpolationMethod::Linear,
+            )
+            .unwrap(),
+        );
+
+        // Uncached: cash flows regenerated and discounted for every reprice,
+        // then fed straight to the duration, convexity and KRD calculators.
+        fn regenerated<T: TermStructure>(
+            bond: &CountingBond,
+            settlement: Date,
+            curve: &RateCurve<T>,
+        ) -> f64 {
+            bond.cash_flows(settlement)
+                .iter()
+                .map(|cf| cf.amount.to_f64().unwrap() * curve.discount_factor(cf.date).unwrap())
+                .sum()
+        }
+        let base = regenerated(&bond, settlement, &curve);
+        let up = regenerated(
+            &bond,
+            settlement,
+            &RateCurve::new(ParallelBump::new(10.0).apply(curve.inner())),
+        );
+        let down = regenerated(
+            &bond,
+            settlement,
+            &RateCurve::new(ParallelBump::new(-10.0).apply(curve.inner())),
+        );
+        let duration = effective_duration(up, down, base, 0.001).unwrap();
+        let convexity = effective_convexity(up, down, base, 0.001).unwrap();
+        let calculator = KeyRateDurationCalculator::with_tenors(STANDARD_KEY_TENORS.to_vec());
+        let tenor_prices: Vec<_> = KeyRateBump::custom_profile(STANDARD_KEY_TENORS, 1.0)
+            .iter()
+            .zip(&KeyRateBump::custom_profile(STANDARD_KEY_TENORS, -1.0))
+            .map(|(up, down)| {
+                (
+                    up.key_tenor(),
+                    regenerated(&bond, settlement, &RateCurve::new(up.apply(curve.inner()))),
+                    regenerated(
+                        &bond,
+                        settlement,
+                        &RateCurve::new(down.apply(curve.inner())),
+                    ),
+                )
+            })
+            .collect();
+        let krds = calculator.calculate(base, &tenor_prices).unwrap();
+        let uncached_calls = bond.cash_flow_calls.replace(0);
+        assert_eq!(uncached_calls, 3 + 2 * STANDARD_KEY_TENORS.len());
+
+        // Cached: flows generated once for every reprice.
+        let context = RiskContext::new(&bond, settlement);
+        let cached_duration = context.effective_duration(&curve, 10.0).unwrap();
+        let cached_convexity = context.effective_convexity(&curve, 10.0).unwrap();
+        let cached_krds = context.key_rate_durations(&curve, &calculator).unwrap();
+        assert_eq!(bond.cash_flow_calls.get(), 1);
+
+        assert_eq!(context.cash_flows().len(), 60);
+        assert_eq!(cached_duration.as_f64(), duration.as_f64());
+        assert_eq!(cached_convexity.as_f64(), convexity.as_f64());
+        for (cached, uncached) in cached_krds.durations.iter().zip(&krds.durations) {
+            assert_eq!(cached.tenor, uncached.tenor);
+            assert_eq!(cached.duration.as_f64(), uncached.duration.as_f64());
+        }
+        assert!(cached_duration.as_f64() > 15.0);
+    }
+}
@@ -8,8 +8,24 @@
 //! ```text
 //! DV01 = Modified Duration × Dirty Price × Face Value × 0.0001
 //! ```
+//!
+//! ## Curve PV01
+//!
+//! [`pv01_from_curve`] instead reprices the cash flows off the discount curve
+//! shifted in parallel by ±1bp. On a flat curve quoted at the bond's yield
+//! compounding the two agree. On a sloped curve they differ slightly: the
+//! curve discounts early cash flows at their own zero rates rather than the
+//! bond's yield, which shifts PV weight between short and long flows. On an
+//! upward-sloping curve the early flows weigh more and PV01 comes out a
+//! fraction of a percent below the yield DV01.
 
+use crate::error::{AnalyticsError, AnalyticsResult};
 use crate::risk::duration::Duration;
+use convex_bonds::traits::BondCashFlow;
+use convex_core::types::Date;
+use convex_curves::bumping::ParallelBump;
+use convex_curves::{RateCurve, TermStructure};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -115,6 +131,47 @@ pub fn notional_from_dv01(target_dv01: DV01, modified_duration: Duration, dirty_
     target_dv01.as_f64() / (modified_duration.as_f64() * (dirty_price / 100.0) * 0.0001)
 }
 
+/// Calculate PV01 by shifting the discount curve.
+///
+/// Discounts the cash flows paid after `settlement` off the curve shifted by
+/// +1bp and -1bp (a [`ParallelBump`] on the curve's own rates), valued at
+/// settlement, and returns half the difference scaled to `face_value`.
+///
+/// # Arguments
+///
+/// * `cash_flows` - Bond cash flows per 100 face
+/// * `curve` - Discount curve
+/// * `settlement` - Settlement date the PV is measured at
+/// * `face_value` - Face value of the position
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::CurveError` if a discount factor cannot be
+/// computed.
+pub fn pv01_from_curve<T: TermStructure>(
+    cash_flows: &[BondCashFlow],
+    curve: &RateCurve<T>,
+    settlement: Date,
+    face_value: f64,
+) -> AnalyticsResult<DV01> {
+    let pv = |bps: f64| -> AnalyticsResult<f64> {
+        let bumped = RateCurve::new(ParallelBump::new(bps).apply(curve.inner()));
+        let df = |date: Date| {
+            bumped
+                .discount_factor(date)
+                .map_err(|e| AnalyticsError::CurveError(e.to_string()))
+        };
+        let df_settle = df(settlement)?;
+        cash_flows
+            .iter()
+            .filter(|cf| cf.date > settlement)
+            .map(|cf| Ok(cf.amount.to_f64().unwrap_or(0.0) * df(cf.date)? / df_settle))
+            .sum()
+    };
+    let per_100 = dv01_from_prices(pv(1.0)?, pv(-1.0)?).as_f64();
+    Ok(DV01::from(per_100 * face_value / 100.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,4 +222,100 @@ mod tests {
         // 1000 / (5 × 1.0 × 0.0001) = 2,000,000
         assert_relative_eq!(notional, 2_000_000.0, epsilon = 1.0);
     }
+
+    mod curve {
+        use super::*;
+        use crate::functions::yield_to_maturity;
+        use crate::risk::BondRiskCalculator;
+        use convex_bonds::traits::Bond;
+        use convex_bonds::FixedRateBond;
+        use convex_core::daycounts::DayCountConvention;
+        use convex_core::types::{Compounding, Frequency};
+        use convex_curves::{DiscreteCurve, InterpolationMethod, ValueType};
+        use rust_decimal_macros::dec;
+
+        fn date(y: i32, m: u32, d: u32) -> Date {
+            Date::from_ymd(y, m, d).unwrap()
+        }
+
+        fn bond() -> FixedRateBond {
+            FixedRateBond::builder()
+                .cusip_unchecked("TEST10Y45")
+                .coupon_rate(dec!(0.045))
+                .maturity(date(2035, 1, 15))
+                .issue_date(date(2025, 1, 15))
+                .us_treasury()
+                .build()
+                .unwrap()
+        }
+
+        fn semi_annual_curve(rates: Vec<f64>) -> RateCurve<DiscreteCurve> {
+            RateCurve::new(
+                DiscreteCurve::new(
+                    date(2025, 1, 15),
+                    vec![0.5, 1.0, 2.0, 5.0, 10.0, 30.0],
+                    rates,
+                    ValueType::ZeroRate {
+                        compounding: Compounding::SemiAnnual,
+                        day_count: DayCountConvention::Act365Fixed,
+                    },
+                    InterpolationMethod::Linear,
+                )
+                .unwrap(),
+            )
+        }
+
+        /// (curve PV01, yield DV01) per 100 face, with the yield solved from
+        /// the curve price.
+        fn pv01_and_dv01(curve: &RateCurve<DiscreteCurve>) -> (f64, f64) {
+            let bond = bond();
+            let settlement = date(2025, 1, 15);
+            let flows = bond.cash_flows(settlement);
+            let dirty: f64 = flows
+                .iter()
+                .map(|cf| cf.amount.to_f64().unwrap() * curve.discount_factor(cf.date).unwrap())
+                .sum();
+            let clean = dirty - bond.accrued_interest(settlement).to_f64().unwrap();
+            let ytm = yield_to_maturity(
+                &bond,
+                settlement,
+                Decimal::from_f64_retain(clean).unwrap(),
+                Frequency::SemiAnnual,
+            )
+            .unwrap()
+            .yield_value;
+            let dv01 = BondRiskCalculator::from_bond(
+                &bond,
+                settlement,
+                dirty,
+                ytm,
+                Compounding::SemiAnnual,
+            )
+            .unwrap()
+            .all_metrics()
+            .unwrap()
+            .dv01_per_100
+            .as_f64();
+            let pv01 = pv01_from_curve(&flows, curve, settlement, 100.0)
+                .unwrap()
+                .as_f64();
+            (pv01, dv01)
+        }
+
+        #[test]
+        fn test_pv01_matches_dv01_on_flat_curve() {
+            let (pv01, dv01) = pv01_and_dv01(&semi_annual_curve(vec![0.045; 6]));
+            assert_relative_eq!(pv01, dv01, max_relative = 1e-3);
+        }
+
+        #[test]
+        fn test_pv01_differs_slightly_on_steep_curve() {
+            let (pv01, dv01) = pv01_and_dv01(&semi_annual_curve(vec![
+                0.03, 0.032, 0.035, 0.041, 0.047, 0.055,
+            ]));
+            // 3% at 6M rising to 5.5% at 30Y: PV01 is ~0.45% below DV01.
+            let gap = (dv01 - pv01) / dv01;
+            assert!(gap > 1e-3 && gap < 1e-2, "relative gap {gap}");
+        }
+    }
 }
@@ -1,17 +1,23 @@
-//! Risk analytics: duration, convexity, DV01, VaR, KRD profiles, and the
-//! hedge advisor surface.
+//! Risk analytics: duration, convexity, DV01, VaR, KRD profiles, roll-down,
+//! and the hedge advisor surface.
 
+pub mod batch;
 pub mod calculator;
+pub mod context;
 pub mod convexity;
 pub mod duration;
 pub mod dv01;
 pub mod hedging;
 pub mod profile;
+pub mod roll_down;
 pub mod var;
 
+pub use batch::{batch_risk, bond_risk};
 pub use calculator::{
-    BondRiskCalculator, BondRiskMetrics, EffectiveDurationCalculator, KeyRateDurationCalculator,
+    BondRiskCalculator, BondRiskMetrics, BondRiskRecord, EffectiveDurationCalculator,
+    KeyRateDurationCalculator,
 };
+pub use context::RiskContext;
 pub use convexity::{
     analytical_convexity, effective_convexity, price_change_with_convexity, Convexity,
 };
@@ -20,7 +26,10 @@ pub use duration::{
     modified_from_macaulay, price_change_from_duration, spread_duration, Duration, KeyRateDuration,
     KeyRateDurations, DEFAULT_BUMP_SIZE, SMALL_BUMP_SIZE, STANDARD_KEY_RATE_TENORS,
 };
-pub use dv01::{dv01_from_duration, dv01_from_prices, dv01_per_100_face, notional_from_dv01, DV01};
+pub use dv01::{
+    dv01_from_duration, dv01_from_prices, dv01_per_100_face, notional_from_dv01, pv01_from_curve,
+    DV01,
+};
 pub use hedging::{
     aggregate_portfolio_risk, barbell_futures, bond_future_risk, cash_bond_pair, cash_bond_risk,
     compare_hedges, duration_futures, duration_hedge_ratio, dv01_hedge_ratio, hedge_cost_bps,
@@ -35,11 +44,14 @@ pub use profile::{
     aggregate_risk_profiles, compute_callable_position_risk, compute_position_risk, KeyRateBucket,
     Provenance, RiskProfile, ADVISOR_KEY_RATE_TENORS,
 };
+pub use roll_down::roll_down_profile;
 pub use var::{historical_var, parametric_var, parametric_var_from_dv01, VaRMethod, VaRResult};
 
 /// Glob-importable re-exports.
 pub mod prelude {
+    pub use super::batch::*;
     pub use super::calculator::*;
+    pub use super::context::*;
     pub use super::convexity::*;
     pub use super::duration::*;
     pub use super::dv01::*;
@@ -0,0 +1,149 @@
+//! Yield-curve roll-down over a horizon grid.
+//!
+//! The curve is held static in tenor space: at each horizon the bond's
+//! remaining cash flows are discounted at the curve's rate for their
+//! time-to-payment *from the horizon*, so on an upward-sloping curve the
+//! bond rolls down to lower yields as it ages.
+
+use rust_decimal::prelude::*;
+
+use convex_bonds::traits::Bond;
+use convex_core::types::Date;
+use convex_curves::RateCurveDyn;
+
+use crate::error::{AnalyticsError, AnalyticsResult};
+
+/// Price return from roll-down at each horizon, holding the curve static.
+///
+/// For every horizon the bond is aged, repriced off the re-sliced curve and
+/// compared with its clean price at settlement. Returns
+/// `(horizon, clean_h / clean_0 - 1)` pairs in the order given; coupons
+/// received before the horizon are excluded.
+///
+/// # Errors
+///
+/// Returns `AnalyticsError` if a horizon is not strictly between settlement
+/// and maturity, or the curve cannot produce a discount factor.
+pub fn roll_down_profile(
+    bond: &dyn Bond,
+    curve: &dyn RateCurveDyn,
+    settlement: Date,
+    horizons: &[Date],
+) -> AnalyticsResult<Vec<(Date, f64)>> {
+    let maturity = bond.maturity().ok_or_else(|| {
+        AnalyticsError::InvalidInput("Bond has no maturity (perpetual)".to_string())
+    })?;
+    if settlement >= maturity {
+        return Err(AnalyticsError::InvalidSettlement {
+            settlement: settlement.to_string(),
+            maturity: maturity.to_string(),
+        });
+    }
+
+    let base = clean_price_at(bond, curve, settlement)?;
+    if base <= 0.0 {
+        return Err(AnalyticsError::CalculationFailed(
+            "non-positive clean price at settlement".to_string(),
+        ));
+    }
+
+    horizons
+        .iter()
+        .map(|&horizon| {
+            if horizon <= settlement || horizon >= maturity {
+                return Err(AnalyticsError::InvalidInput(format!(
+                    "horizon {horizon} must fall between settlement {settlement} and maturity {maturity}"
+                )));
+            }
+            let price = clean_price_at(bond, curve, horizon)?;
+            Ok((horizon, price / base - 1.0))
+        })
+        .collect()
+}
+
+/// Clean price at `as_of`, discounting each remaining flow at the curve's
+/// rate for its time-to-payment measured from `as_of`.
+fn clean_price_at(bond: &dyn Bond, curve: &dyn RateCurveDyn, as_of: Date) -> AnalyticsResult<f64> {
+    let t0 = curve.date_to_tenor(as_of);
+    let mut dirty = 0.0;
+    for cf in bond.cash_flows(as_of) {
+        if cf.date <= as_of {
+            continue;
+        }
+        let tau = curve.date_to_tenor(cf.date) - t0;
+        let df = curve
+            .discount_factor(tau)
+            .map_err(|e| AnalyticsError::CurveError(e.to_string()))?;
+        dirty += cf.amount.to_f64().unwrap_or(0.0) * df;
+    }
+    Ok(dirty - bond.accrued_interest(as_of).to_f64().unwrap_or(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use convex_bonds::instruments::FixedRateBond;
+    use convex_curves::curves::DiscountCurveBuilder;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    /// Upward-sloping curve: 3% at the short end rising 20bp per year.
+    fn create_upward_curve() -> impl RateCurveDyn {
+        let mut builder = DiscountCurveBuilder::new(date(2024, 1, 15));
+        for t in [0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.0, 10.0] {
+            let rate = 0.03 + 0.002 * t;
+            builder = builder.add_pillar(t, (-rate * t).exp());
+        }
+        builder.with_extrapolation().build().unwrap()
+    }
+
+    fn create_bond() -> FixedRateBond {
+        FixedRateBond::builder()
+            .cusip_unchecked("123456AB1")
+            .coupon_percent(4.0)
+            .issue_date(date(2024, 1, 15))
+            .maturity(date(2034, 1, 15))
+            .us_corporate()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_roll_down_monotonic_on_upward_curve() {
+        let curve = create_upward_curve();
+        let bond = create_bond();
+        let settlement = date(2024, 1, 16);
+        let horizons = [
+            date(2024, 7, 16),
+            date(2025, 1, 16),
+            date(2026, 1, 16),
+            date(2027, 1, 19),
+        ];
+
+        let profile = roll_down_profile(&bond, &curve, settlement, &horizons).unwrap();
+        assert_eq!(profile.len(), horizons.len());
+        assert_eq!(profile[0].0, horizons[0]);
+
+        assert!(profile[0].1 > 0.0, "first horizon roll {}", profile[0].1);
+        for pair in profile.windows(2) {
+            assert!(
+                pair[1].1 > pair[0].1,
+                "roll not increasing: {:?} -> {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_roll_down_rejects_horizon_outside_life() {
+        let curve = create_upward_curve();
+        let bond = create_bond();
+        let settlement = date(2024, 1, 16);
+
+        assert!(roll_down_profile(&bond, &curve, settlement, &[settlement]).is_err());
+        assert!(roll_down_profile(&bond, &curve, settlement, &[date(2035, 1, 15)]).is_err());
+    }
+}
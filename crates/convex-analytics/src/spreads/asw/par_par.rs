@@ -6,21 +6,55 @@ use rust_decimal::Decimal;
 use convex_bonds::traits::{Bond, FixedCouponBond};
 use convex_core::types::{Date, Price, Spread, SpreadType};
 use convex_curves::curves::ZeroCurve;
+use convex_curves::multicurve::{Currency, MultiCurveEnvironment};
 
 use crate::error::{AnalyticsError, AnalyticsResult};
 use crate::spreads::asw::{coupon_year_fraction, day_counter};
 
 /// Par-par asset swap spread calculator.
-#[derive(Debug, Clone)]
+///
+/// By default the swap legs are projected and discounted on `swap_curve`,
+/// the bond-currency swap curve. [`with_funding`](Self::with_funding) keeps
+/// the projection there but discounts the legs with the cross-currency basis
+/// between the bond currency and the funding currency added.
+#[derive(Clone)]
 pub struct ParParAssetSwap<'a> {
     swap_curve: &'a ZeroCurve,
+    funding: Option<(&'a MultiCurveEnvironment, Currency)>,
 }
 
+impl std::fmt::Debug for ParParAssetSwap<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParParAssetSwap")
+            .field("swap_curve", self.swap_curve)
+            .field("funding", &self.funding.map(|(_, ccy)| ccy))
+            .finish()
+    }
+}
+
+/// Basis lookup for one bond: environment, bond currency, funding currency.
+type FundingBasis<'e> = Option<(&'e MultiCurveEnvironment, Currency, Currency)>;
+
 impl<'a> ParParAssetSwap<'a> {
     /// Creates a new par-par asset swap calculator.
     #[must_use]
     pub fn new(swap_curve: &'a ZeroCurve) -> Self {
-        Self { swap_curve }
+        Self {
+            swap_curve,
+            funding: None,
+        }
+    }
+
+    /// Funds the swap in `funding`, discounting the legs with the
+    /// cross-currency basis from `env` (see
+    /// [`MultiCurveEnvironment::xccy_basis_factor`]).
+    ///
+    /// When `funding` is the bond's currency the result is the
+    /// single-currency ASW.
+    #[must_use]
+    pub fn with_funding(mut self, env: &'a MultiCurveEnvironment, funding: Currency) -> Self {
+        self.funding = Some((env, funding));
+        self
     }
 
     /// Returns the reference date from the swap curve.
@@ -120,6 +154,7 @@ impl<'a> ParParAssetSwap<'a> {
 
         let coupon = bond.coupon_rate().to_f64().unwrap();
         let day_count = day_counter(bond.day_count_convention());
+        let basis = self.funding_basis(bond)?;
 
         let mut annuity = 0.0;
         let mut mismatch = 0.0;
@@ -133,16 +168,19 @@ impl<'a> ParParAssetSwap<'a> {
                 continue;
             }
 
+            // Forwards come from the bond-currency curve; only discounting
+            // picks up the basis.
             let df = self
                 .swap_curve
                 .discount_factor(cf.date)
                 .map_err(|e| AnalyticsError::CurveError(e.to_string()))?;
+            let leg_df = df * Self::basis_factor(basis, cf.date)?;
 
             let tau = coupon_year_fraction(day_count.as_deref(), cf, payments_per_year);
 
             let fwd = (prev_df / df - 1.0) / tau;
-            annuity += tau * df;
-            mismatch += df * tau * (coupon - fwd) * 100.0;
+            annuity += tau * leg_df;
+            mismatch += leg_df * tau * (coupon - fwd) * 100.0;
             prev_df = df;
         }
 
@@ -172,6 +210,7 @@ impl<'a> ParParAssetSwap<'a> {
         }
 
         let year_fraction = Decimal::ONE / Decimal::from(payments_per_year);
+        let basis = self.funding_basis(bond)?;
         let mut annuity = Decimal::ZERO;
 
         for cf in &cash_flows {
@@ -182,7 +221,8 @@ impl<'a> ParParAssetSwap<'a> {
             let df_f64 = self
                 .swap_curve
                 .discount_factor(cf.date)
-                .map_err(|e| AnalyticsError::CurveError(e.to_string()))?;
+                .map_err(|e| AnalyticsError::CurveError(e.to_string()))?
+                * Self::basis_factor(basis, cf.date)?;
             let df = Decimal::from_f64_retain(df_f64).unwrap_or(Decimal::ZERO);
             annuity += df * year_fraction;
         }
@@ -190,6 +230,30 @@ impl<'a> ParParAssetSwap<'a> {
         Ok(annuity)
     }
 
+    /// Resolves the funding basis for `bond`, if a funding currency is set.
+    fn funding_basis<B: Bond>(&self, bond: &B) -> AnalyticsResult<FundingBasis<'a>> {
+        let Some((env, funding)) = self.funding else {
+            return Ok(None);
+        };
+        let code = bond.currency().code();
+        let bond_currency = Currency::from_code(code).ok_or_else(|| {
+            AnalyticsError::InvalidInput(format!(
+                "no cross-currency basis support for bond currency {code}"
+            ))
+        })?;
+        Ok(Some((env, bond_currency, funding)))
+    }
+
+    /// Discount-factor adjustment for the basis on `date` (1 without funding).
+    fn basis_factor(basis: FundingBasis<'_>, date: Date) -> AnalyticsResult<f64> {
+        match basis {
+            Some((env, currency, funding)) => env
+                .xccy_basis_factor(currency, funding, date)
+                .map_err(|e| AnalyticsError::CurveError(e.to_string())),
+            None => Ok(1.0),
+        }
+    }
+
     /// Returns the swap annuity for a given bond.
     pub fn annuity<B: Bond + FixedCouponBond>(
         &self,
@@ -453,6 +517,75 @@ mod tests {
         assert!(diff < dec!(0.05), "round-trip drift: {diff}");
     }
 
+    fn funding_env(basis: f64) -> MultiCurveEnvironment {
+        use convex_curves::multicurve::{CurrencyPair, RateIndex};
+        use convex_curves::{DiscreteCurve, InterpolationMethod, ValueType};
+
+        let flat = |rate: f64| {
+            DiscreteCurve::new(
+                date(2024, 1, 15),
+                vec![1.0, 30.0],
+                vec![rate, rate],
+                ValueType::continuous_zero(convex_core::daycounts::DayCountConvention::Act365Fixed),
+                InterpolationMethod::Linear,
+            )
+            .unwrap()
+        };
+        MultiCurveEnvironment::builder(date(2024, 1, 15))
+            .ois_curve(RateIndex::Estr, flat(0.03))
+            .xccy_basis(CurrencyPair::new(Currency::Usd, Currency::Eur), flat(basis))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_cross_currency_funding() {
+        let curve = create_flat_curve(dec!(0.05));
+        let bond = MockBond::new(date(2029, 1, 15), dec!(0.05), 2);
+        let clean_price = Price::new(dec!(95.0), convex_core::Currency::USD);
+        let settlement = date(2024, 1, 17);
+
+        let single = ParParAssetSwap::new(&curve)
+            .calculate(&bond, clean_price, settlement)
+            .unwrap();
+
+        // Funding in the bond's own currency ignores the basis entirely.
+        let env = funding_env(0.0050);
+        let own = ParParAssetSwap::new(&curve)
+            .with_funding(&env, Currency::Usd)
+            .calculate(&bond, clean_price, settlement)
+            .unwrap();
+        assert_eq!(own.as_bps(), single.as_bps());
+
+        let flat_env = funding_env(0.0);
+        let zero_basis = ParParAssetSwap::new(&curve)
+            .with_funding(&flat_env, Currency::Eur)
+            .calculate(&bond, clean_price, settlement)
+            .unwrap();
+        assert_eq!(zero_basis.as_bps(), single.as_bps());
+
+        // A positive basis shrinks the annuity, spreading the discount over
+        // less PV01 and widening the ASW.
+        let calc = ParParAssetSwap::new(&curve).with_funding(&env, Currency::Eur);
+        let xccy = calc.calculate(&bond, clean_price, settlement).unwrap();
+        assert!(
+            xccy.as_bps() > single.as_bps(),
+            "xccy {} vs single {}",
+            xccy.as_bps(),
+            single.as_bps()
+        );
+        assert!(
+            calc.annuity(&bond, settlement).unwrap()
+                < ParParAssetSwap::new(&curve)
+                    .annuity(&bond, settlement)
+                    .unwrap()
+        );
+
+        // Missing basis for the pair is an error.
+        let gbp = ParParAssetSwap::new(&curve).with_funding(&env, Currency::Gbp);
+        assert!(gbp.calculate(&bond, clean_price, settlement).is_err());
+    }
+
     #[test]
     fn test_settlement_after_maturity() {
         let curve = create_flat_curve(dec!(0.05));
@@ -552,6 +552,60 @@ mod tests {
         assert!(price_50bps < price_zero_dm, "Price with DM should be lower");
     }
 
+    #[test]
+    fn test_deep_in_the_money_floor_prices_as_fixed_bond() {
+        // A 10% floor sits far above every SOFR forward on the sample curve,
+        // so each projected coupon clamps to the floor and the FRN is a
+        // fixed 10% bond on the FRN's schedule and day count.
+        let discount = create_sample_discount_curve();
+        let discount_arc: Arc<dyn RateCurveDyn> = Arc::new(discount);
+        let forward = create_sample_forward_curve(discount_arc.clone());
+        let settlement = date(2025, 6, 15);
+        let floor = dec!(0.10);
+        let frn = FloatingRateNote::builder()
+            .cusip_unchecked("FLOORTEST")
+            .index(RateIndex::Sofr)
+            .spread_bps(50)
+            .face_value(dec!(100))
+            .maturity(date(2027, 6, 15))
+            .issue_date(settlement)
+            .corporate_sofr()
+            .floor(floor)
+            .build()
+            .unwrap();
+
+        let day_count = frn.day_count().to_day_count();
+        let fixed_pv: f64 = frn
+            .cash_flows(settlement)
+            .iter()
+            .map(|cf| {
+                let (start, end) = (cf.accrual_start.unwrap(), cf.accrual_end.unwrap());
+                let yf = day_count
+                    .period_year_fraction(start, end, start, end)
+                    .to_f64()
+                    .unwrap();
+                let principal = if cf.is_principal() { 100.0 } else { 0.0 };
+                let t = settlement.days_between(&cf.date) as f64 / 365.0;
+                (100.0 * 0.10 * yf + principal) * discount_arc.discount_factor(t).unwrap()
+            })
+            .sum();
+
+        let calc = DiscountMarginCalculator::new(&forward, discount_arc.as_ref());
+        let floored = calc.price_with_dm(&frn, 0.0, settlement);
+        assert!(
+            (floored - fixed_pv).abs() < 1e-9,
+            "floored FRN {floored} vs fixed bond {fixed_pv}"
+        );
+
+        let unfloored = calc.price_with_dm(&create_sample_frn(), 0.0, settlement);
+        assert!(floored > unfloored + 5.0);
+
+        // Solving back from the fixed-bond price recovers a zero DM.
+        let dirty = Decimal::from_f64_retain(fixed_pv).unwrap();
+        let dm = calc.calculate(&frn, dirty, settlement).unwrap();
+        assert!(dm.as_bps().abs() < dec!(0.01));
+    }
+
     #[test]
     fn test_callable_frn_dm_to_worst_premium_bond() {
         // Premium-priced callable FRN: investor's worst case is being called
@@ -1,18 +1,29 @@
 //! G-spread (government spread) calculator.
 //!
 //! The G-spread is the yield spread of a bond over the interpolated
-//! government benchmark yield at the same maturity.
+//! government benchmark yield at the same maturity. The duration-matched
+//! variant reads the benchmark at the bond's modified duration instead.
 
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 
-use convex_bonds::traits::{Bond, FixedCouponBond};
-use convex_core::types::{Date, Spread, SpreadType, Yield};
+use convex_bonds::traits::{Bond, BondAnalytics, FixedCouponBond};
+use convex_core::types::{Compounding, Date, Spread, SpreadType, Yield};
 
 use crate::error::{AnalyticsError, AnalyticsResult};
 
 use super::benchmark::BenchmarkSpec;
 use super::government_curve::GovernmentCurve;
 
+/// G-spread read off the benchmark at the bond's duration point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationMatchedSpread {
+    /// The G-spread in basis points.
+    pub spread: Spread,
+    /// Point on the benchmark curve (years), equal to the bond's modified duration.
+    pub matching_years: f64,
+}
+
 /// G-spread calculator for fixed rate bonds.
 ///
 /// Calculates the spread over a government benchmark yield.
@@ -80,19 +91,69 @@ impl<'a> GSpreadCalculator<'a> {
         }
 
         let benchmark_yield = self.get_benchmark_yield(settlement, maturity)?;
+        Ok(Self::spread_over(bond_yield, benchmark_yield))
+    }
+
+    /// Calculates the G-spread against the benchmark at the bond's
+    /// modified-duration point rather than its maturity.
+    ///
+    /// On a steep curve a coupon bond's risk sits well inside its maturity,
+    /// so the maturity-matched benchmark overstates (upward slope) or
+    /// understates (inverted) the comparable government yield.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError` if settlement is at or after maturity, the
+    /// duration cannot be computed, or the benchmark yield cannot be determined.
+    pub fn calculate_duration_matched<B: Bond + FixedCouponBond>(
+        &self,
+        bond: &B,
+        bond_yield: Yield,
+        settlement: Date,
+    ) -> AnalyticsResult<DurationMatchedSpread> {
+        let maturity = bond.maturity().ok_or_else(|| {
+            AnalyticsError::InvalidInput("Bond has no maturity (perpetual)".to_string())
+        })?;
+
+        if settlement >= maturity {
+            return Err(AnalyticsError::InvalidSettlement {
+                settlement: settlement.to_string(),
+                maturity: maturity.to_string(),
+            });
+        }
+
+        let frequency = bond.frequency();
+        let ytm = bond_yield
+            .convert_to(Compounding::from(frequency))
+            .value()
+            .to_f64()
+            .unwrap_or(0.0);
+        let matching_years = bond.modified_duration(settlement, ytm, frequency)?;
+
+        let benchmark_yield = self.benchmark_yield_at(matching_years)?;
+        Ok(DurationMatchedSpread {
+            spread: Self::spread_over(bond_yield, benchmark_yield),
+            matching_years,
+        })
+    }
+
+    fn spread_over(bond_yield: Yield, benchmark_yield: Yield) -> Spread {
         // Align compounding before subtracting; mismatched conventions leak
         // ~5 bp on a 10Y bond.
         let bond_yield = bond_yield.convert_to(benchmark_yield.compounding());
 
         let spread_bps =
             ((bond_yield.value() - benchmark_yield.value()) * Decimal::from(10_000)).round();
-        Ok(Spread::new(spread_bps, SpreadType::GSpread))
+        Spread::new(spread_bps, SpreadType::GSpread)
     }
 
     /// Gets the benchmark yield based on the specification.
     fn get_benchmark_yield(&self, settlement: Date, maturity: Date) -> AnalyticsResult<Yield> {
-        let years_to_maturity = settlement.days_between(&maturity) as f64 / 365.0;
+        self.benchmark_yield_at(settlement.days_between(&maturity) as f64 / 365.0)
+    }
 
+    /// Gets the benchmark yield for a point on the curve (years).
+    fn benchmark_yield_at(&self, years_to_maturity: f64) -> AnalyticsResult<Yield> {
         match &self.benchmark_spec {
             BenchmarkSpec::Interpolated => Ok(self.gov_curve.interpolated_yield(years_to_maturity)),
             BenchmarkSpec::OnTheRunTenor(tenor) => {
@@ -147,6 +208,19 @@ pub fn g_spread<B: Bond + FixedCouponBond>(
     GSpreadCalculator::new(gov_curve).calculate(bond, bond_yield, settlement)
 }
 
+/// Convenience function for the duration-matched G-spread with interpolated
+/// benchmark.
+///
+/// Returns the spread and the curve point (the bond's modified duration).
+pub fn g_spread_duration_matched<B: Bond + FixedCouponBond>(
+    bond: &B,
+    bond_yield: Yield,
+    gov_curve: &GovernmentCurve,
+    settlement: Date,
+) -> AnalyticsResult<DurationMatchedSpread> {
+    GSpreadCalculator::new(gov_curve).calculate_duration_matched(bond, bond_yield, settlement)
+}
+
 /// Calculate G-spread with explicit benchmark specification.
 pub fn g_spread_with_benchmark<B: Bond + FixedCouponBond>(
     bond: &B,
@@ -397,6 +471,41 @@ mod tests {
         assert!((semi_spread.as_bps() - cont_spread.as_bps()).abs() <= dec!(1));
     }
 
+    #[test]
+    fn test_g_spread_duration_matched_on_steep_curve() {
+        use convex_bonds::instruments::FixedRateBond;
+
+        // Steep upward-sloping curve: 2% at 1Y rising to 5% at 30Y.
+        let gov_curve = GovernmentCurve::us_treasury(date(2024, 1, 15))
+            .with_point(1.0, 0.02)
+            .with_point(5.0, 0.03)
+            .with_point(10.0, 0.04)
+            .with_point(30.0, 0.05);
+
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("123456AB1")
+            .coupon_percent(6.0)
+            .issue_date(date(2024, 1, 15))
+            .maturity(date(2044, 1, 15))
+            .us_corporate()
+            .build()
+            .unwrap();
+        let settlement = date(2024, 1, 17);
+        let bond_yield = Yield::new(dec!(0.06), Compounding::SemiAnnual);
+
+        let maturity_matched = g_spread(&bond, bond_yield, &gov_curve, settlement).unwrap();
+        let matched = g_spread_duration_matched(&bond, bond_yield, &gov_curve, settlement).unwrap();
+
+        // A 20Y 6% coupon bond has a duration of roughly 11-12 years.
+        assert!(
+            matched.matching_years > 10.0 && matched.matching_years < 13.0,
+            "duration point {}",
+            matched.matching_years
+        );
+        // Reading the benchmark at a shorter, lower-yielding point widens the spread.
+        assert!(matched.spread.as_bps() > maturity_matched.as_bps() + dec!(20));
+    }
+
     #[test]
     fn test_benchmark_not_found() {
         let gov_curve = create_test_curve();
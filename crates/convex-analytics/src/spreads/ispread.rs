@@ -8,14 +8,26 @@ use rust_decimal::Decimal;
 
 use convex_bonds::traits::{Bond, FixedCouponBond};
 use convex_core::types::{Date, Spread, SpreadType, Yield};
-use convex_curves::RateCurveDyn;
+use convex_curves::{DiscreteCurve, InterpolationMethod, RateCurve, RateCurveDyn, ValueType};
 
 use crate::error::{AnalyticsError, AnalyticsResult};
 
+/// Benchmark swap tenors (years) at which the swap curve is sampled when an
+/// explicit interpolation method is selected.
+const SWAP_BENCHMARK_TENORS: [f64; 12] = [
+    0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 15.0, 20.0, 30.0, 50.0,
+];
+
 /// I-spread calculator for fixed rate bonds.
 ///
 /// Calculates the spread over the interpolated swap rate at maturity.
 ///
+/// By default the par swap rate is read directly off the swap curve. With
+/// [`with_interpolation`](Self::with_interpolation) the curve's discount
+/// factors are instead sampled at the benchmark swap tenors and
+/// re-interpolated with the chosen method, so the reference rate matches how
+/// the swap curve was built (e.g. log-linear or monotone convex).
+///
 /// # Formula
 ///
 /// I-spread = Bond YTM - Swap Rate at Maturity
@@ -25,11 +37,15 @@ use crate::error::{AnalyticsError, AnalyticsResult};
 pub struct ISpreadCalculator<'a> {
     /// Reference to the swap curve.
     swap_curve: &'a dyn RateCurveDyn,
+    /// Interpolation between benchmark tenors (`None` reads the curve as-is).
+    interpolation: Option<InterpolationMethod>,
 }
 
 impl std::fmt::Debug for ISpreadCalculator<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ISpreadCalculator").finish_non_exhaustive()
+        f.debug_struct("ISpreadCalculator")
+            .field("interpolation", &self.interpolation)
+            .finish_non_exhaustive()
     }
 }
 
@@ -41,7 +57,18 @@ impl<'a> ISpreadCalculator<'a> {
     /// * `swap_curve` - The swap rate curve
     #[must_use]
     pub fn new(swap_curve: &'a dyn RateCurveDyn) -> Self {
-        Self { swap_curve }
+        Self {
+            swap_curve,
+            interpolation: None,
+        }
+    }
+
+    /// Reads the swap rate off discount factors sampled at the benchmark
+    /// swap tenors and interpolated with `method`.
+    #[must_use]
+    pub fn with_interpolation(mut self, method: InterpolationMethod) -> Self {
+        self.interpolation = Some(method);
+        self
     }
 
     /// Calculates the I-spread for a bond.
@@ -80,19 +107,10 @@ impl<'a> ISpreadCalculator<'a> {
 
         let years_to_maturity = self.swap_curve.date_to_tenor(maturity);
         let frequency = bond.coupon_frequency().max(1);
-        let swap_rate = self
-            .swap_curve
-            .par_swap_rate(years_to_maturity, frequency)
-            .map_err(|e| AnalyticsError::CurveError(e.to_string()))?;
+        let swap_rate = self.par_swap_rate(years_to_maturity, frequency)?;
 
-        // Align bond yield to the par rate's compounding. Reject exotic
-        // coupon frequencies rather than panicking inside `from_periods_per_year`.
-        let target = convex_core::types::Compounding::try_from_periods_per_year(frequency)
-            .ok_or_else(|| {
-                AnalyticsError::InvalidInput(format!(
-                    "unsupported coupon frequency for I-spread: {frequency}"
-                ))
-            })?;
+        // Align bond yield to the par rate's compounding.
+        let target = convex_core::types::Compounding::from_periods_per_year(frequency);
         let bond_yield_f64 = bond_yield
             .convert_to(target)
             .value()
@@ -110,9 +128,54 @@ impl<'a> ISpreadCalculator<'a> {
     ///
     /// Useful for debugging or displaying the benchmark rate.
     pub fn swap_rate_at_maturity(&self, maturity: Date) -> AnalyticsResult<f64> {
-        self.swap_curve
-            .par_swap_rate(self.swap_curve.date_to_tenor(maturity), 2)
-            .map_err(|e| AnalyticsError::CurveError(e.to_string()))
+        self.par_swap_rate(self.swap_curve.date_to_tenor(maturity), 2)
+    }
+
+    /// Par swap rate at `t_maturity`, honouring the selected interpolation.
+    fn par_swap_rate(&self, t_maturity: f64, frequency: u32) -> AnalyticsResult<f64> {
+        let rate = match self.interpolation {
+            None => self.swap_curve.par_swap_rate(t_maturity, frequency),
+            Some(method) => {
+                let curve = self.resampled_curve(t_maturity, method)?;
+                RateCurveDyn::par_swap_rate(&curve, t_maturity, frequency)
+            }
+        };
+        rate.map_err(|e| AnalyticsError::CurveError(e.to_string()))
+    }
+
+    /// Rebuilds the swap curve from its discount factors at the benchmark
+    /// tenors, extending the grid to `t_maturity` if it lies beyond them.
+    fn resampled_curve(
+        &self,
+        t_maturity: f64,
+        method: InterpolationMethod,
+    ) -> AnalyticsResult<RateCurve<DiscreteCurve>> {
+        let max_tenor = self
+            .swap_curve
+            .date_to_tenor(self.swap_curve.max_date())
+            .max(t_maturity);
+        let mut tenors: Vec<f64> = SWAP_BENCHMARK_TENORS
+            .iter()
+            .copied()
+            .filter(|&t| t < max_tenor)
+            .collect();
+        tenors.push(max_tenor);
+
+        let dfs = tenors
+            .iter()
+            .map(|&t| self.swap_curve.discount_factor(t))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AnalyticsError::CurveError(e.to_string()))?;
+
+        let curve = DiscreteCurve::new(
+            self.swap_curve.reference_date(),
+            tenors,
+            dfs,
+            ValueType::DiscountFactor,
+            method,
+        )
+        .map_err(|e| AnalyticsError::CurveError(e.to_string()))?;
+        Ok(RateCurve::new(curve))
     }
 
     /// Calculates implied yield from I-spread.
@@ -375,6 +438,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_i_spread_depends_on_interpolation() {
+        // Humped curve: short rates rise to 6% around 7Y then fall back.
+        let ref_date = date(2024, 1, 15);
+        let mut builder = DiscountCurveBuilder::new(ref_date);
+        for (t, r) in [
+            (0.25, 0.020),
+            (0.5, 0.022),
+            (1.0, 0.025),
+            (2.0, 0.032),
+            (3.0, 0.040),
+            (5.0, 0.052),
+            (7.0, 0.060),
+            (10.0, 0.055),
+            (15.0, 0.048),
+            (20.0, 0.045),
+            (30.0, 0.044),
+        ] {
+            builder = builder.add_pillar(t, (-r * t).exp());
+        }
+        let curve = builder.with_extrapolation().build().unwrap();
+
+        // 8.5Y maturity sits between the 7Y and 10Y benchmarks.
+        let bond = MockBond::new(date(2032, 7, 15));
+        let settlement = date(2024, 1, 17);
+        let bond_yield = Yield::new(dec!(0.065), Compounding::SemiAnnual);
+
+        let linear = ISpreadCalculator::new(&curve)
+            .with_interpolation(InterpolationMethod::Linear)
+            .calculate(&bond, bond_yield, settlement)
+            .unwrap();
+        let log_linear = ISpreadCalculator::new(&curve)
+            .with_interpolation(InterpolationMethod::LogLinear)
+            .calculate(&bond, bond_yield, settlement)
+            .unwrap();
+
+        let diff = (linear.as_bps() - log_linear.as_bps()).abs();
+        assert!(
+            diff >= dec!(2),
+            "linear {} vs log-linear {}",
+            linear.as_bps(),
+            log_linear.as_bps()
+        );
+
+        // Log-linear on discount factors reproduces the curve at a benchmark.
+        let at_pillar = MockBond::new(date(2034, 1, 15));
+        let direct = ISpreadCalculator::new(&curve)
+            .calculate(&at_pillar, bond_yield, settlement)
+            .unwrap();
+        let resampled = ISpreadCalculator::new(&curve)
+            .with_interpolation(InterpolationMethod::LogLinear)
+            .calculate(&at_pillar, bond_yield, settlement)
+            .unwrap();
+        assert!((direct.as_bps() - resampled.as_bps()).abs() <= dec!(1));
+    }
+
     #[test]
     fn test_i_spread_tracks_par_rate_on_rising_curve() {
         use convex_curves::curves::DiscountCurveBuilder;
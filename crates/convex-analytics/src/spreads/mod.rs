@@ -8,6 +8,7 @@
 //! - **OAS**: Option-adjusted spread for callable bonds
 //! - **Discount Margin**: Spread for floating rate notes
 //! - **ASW**: Asset swap spreads (par-par and proceeds)
+//! - **Risky PV**: Survival-weighted pricing off a credit curve
 //!
 //! # Overview
 //!
@@ -28,6 +29,7 @@ mod government_curve;
 mod gspread;
 mod ispread;
 mod oas;
+mod risky;
 mod sovereign;
 mod zspread;
 
@@ -37,11 +39,18 @@ pub mod asw;
 pub use benchmark::{BenchmarkSpec, SecurityId};
 pub use discount_margin::{simple_margin, z_discount_margin, DiscountMarginCalculator};
 pub use government_curve::{GovernmentBenchmark, GovernmentCurve};
-pub use gspread::{g_spread, g_spread_with_benchmark, GSpreadCalculator};
+pub use gspread::{
+    g_spread, g_spread_duration_matched, g_spread_with_benchmark, DurationMatchedSpread,
+    GSpreadCalculator,
+};
 pub use ispread::{i_spread, ISpreadCalculator};
-pub use oas::OASCalculator;
+pub use oas::{LatticeType, OASCalculator, OptionGreeks, RateDynamics};
+pub use risky::risky_present_value;
 pub use sovereign::{Sovereign, SupranationalIssuer};
-pub use zspread::{z_spread, z_spread_from_curve, ZSpreadCalculator};
+pub use zspread::{
+    effective_convexity_from_curve, effective_duration_from_curve, partial_spread_durations,
+    spread_duration_from_curve, z_spread, z_spread_from_curve, ZSpreadCalculator,
+};
 
 // Re-export ASW types
 pub use asw::{ASWType, ParParAssetSwap, ProceedsAssetSwap};
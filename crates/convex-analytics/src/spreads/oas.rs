@@ -9,24 +9,48 @@ use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 
 use convex_bonds::instruments::CallableBond;
-use convex_bonds::options::{build_event_grid, HullWhite, ShortRateModel, TrinomialTree};
+use convex_bonds::options::{
+    build_event_grid, BinomialTree, HullWhite, ShortRateModel, TrinomialTree,
+};
 use convex_bonds::traits::{Bond, CashFlowType, EmbeddedOptionBond};
 use convex_core::types::{Date, Spread, SpreadType};
+use convex_curves::bumping::KeyRateBump;
 use convex_curves::RateCurveDyn;
-use convex_curves::{Compounding, CurveResult};
+use convex_curves::{Compounding, CurveResult, Tenor};
 use convex_math::solvers::{brent, SolverConfig};
 
 use crate::error::{AnalyticsError, AnalyticsResult};
 
-/// A wrapper curve that applies a parallel shift to all rates.
-struct ShiftedCurve<'a> {
+/// A wrapper curve that shifts zero rates, either in parallel or with a
+/// key-rate (triangular) weight profile.
+pub(crate) struct ShiftedCurve<'a> {
     base: &'a dyn RateCurveDyn,
     shift: f64,
+    profile: Option<KeyRateBump>,
 }
 
 impl<'a> ShiftedCurve<'a> {
-    fn new(base: &'a dyn RateCurveDyn, shift: f64) -> Self {
-        Self { base, shift }
+    pub(crate) fn new(base: &'a dyn RateCurveDyn, shift: f64) -> Self {
+        Self {
+            base,
+            shift,
+            profile: None,
+        }
+    }
+
+    /// Shift of `shift` at the bump's key tenor, fading to zero at its
+    /// neighbours.
+    fn key_rate(base: &'a dyn RateCurveDyn, shift: f64, bump: KeyRateBump) -> Self {
+        Self {
+            base,
+            shift,
+            profile: Some(bump),
+        }
+    }
+
+    fn shift_at(&self, t: f64) -> f64 {
+        self.profile
+            .map_or(self.shift, |bump| self.shift * bump.weight_at(t))
     }
 }
 
@@ -39,7 +63,7 @@ impl RateCurveDyn for ShiftedCurve<'_> {
         }
 
         let base_rate = -base_df.ln() / t;
-        let shifted_rate = base_rate + self.shift;
+        let shifted_rate = base_rate + self.shift_at(t);
         let shifted_df = (-shifted_rate * t).exp();
 
         Ok(shifted_df)
@@ -55,32 +79,147 @@ impl RateCurveDyn for ShiftedCurve<'_> {
 
     fn zero_rate(&self, t: f64, compounding: Compounding) -> CurveResult<f64> {
         let base_rate = self.base.zero_rate(t, compounding)?;
-        Ok(base_rate + self.shift)
+        Ok(base_rate + self.shift_at(t))
     }
 
     fn forward_rate(&self, t1: f64, t2: f64) -> CurveResult<f64> {
         let base_fwd = self.base.forward_rate(t1, t2)?;
-        Ok(base_fwd + self.shift)
+        if self.profile.is_none() || t2 <= t1 {
+            return Ok(base_fwd + self.shift);
+        }
+        let shift_fwd = (self.shift_at(t2) * t2 - self.shift_at(t1) * t1) / (t2 - t1);
+        Ok(base_fwd + shift_fwd)
     }
 
     fn instantaneous_forward(&self, t: f64) -> CurveResult<f64> {
         let base_inst_fwd = self.base.instantaneous_forward(t)?;
-        Ok(base_inst_fwd + self.shift)
+        if self.profile.is_none() {
+            return Ok(base_inst_fwd + self.shift);
+        }
+        // d/dt [s(t)·t] across the piecewise-linear weight profile.
+        let h = 1e-6;
+        let lo = (t - h).max(0.0);
+        let hi = t + h;
+        let shift_fwd = (self.shift_at(hi) * hi - self.shift_at(lo) * lo) / (hi - lo);
+        Ok(base_inst_fwd + shift_fwd)
     }
 }
 
+/// Volatility standing in for zero when valuing intrinsic option value
+/// (the Hull-White lattice needs a strictly positive sigma).
+const INTRINSIC_VOLATILITY: f64 = 1e-6;
+
+/// Embedded option value and its sensitivities, per 100 face.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptionGreeks {
+    /// Bullet price minus callable price at the held OAS.
+    pub option_value: f64,
+    /// Option value with exercise decided on the forward curve alone.
+    pub intrinsic_value: f64,
+    /// `option_value - intrinsic_value`.
+    pub time_value: f64,
+    /// Change in option value for a +1 point (0.01) volatility bump.
+    pub vega: f64,
+    /// Change in option value from rolling settlement forward one month.
+    pub theta: f64,
+}
+
+/// Upper bound on the step count tried by [`OASCalculator::converged_steps`].
+const MAX_TREE_STEPS: usize = 6400;
+
+/// Short-rate lattice used to price the embedded option.
+///
+/// Both are Hull-White lattices fitted exactly to the discount curve; they
+/// differ in how events are placed and how fast they converge.
+///
+/// - **Trinomial** (default): Hagan-Brace tree on an event-aligned grid, so
+///   coupon and call dates fall exactly on layers. Converges quickly and
+///   smoothly; 100-200 steps are usually within a cent.
+/// - **Binomial**: recombining tree on a uniform grid with mean reversion
+///   carried in the branch probabilities. Each layer is cheaper (two
+///   branches, `i + 1` nodes), but events are snapped to the nearest layer,
+///   so the error is first order in the step size and oscillates; expect to
+///   need several times the steps for the same accuracy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LatticeType {
+    /// Event-aligned Hull-White trinomial tree.
+    #[default]
+    Trinomial,
+    /// Uniform-grid Hull-White binomial tree.
+    Binomial,
+}
+
+/// Short-rate dynamics on the lattice.
+///
+/// - **Normal** (default): Hull-White; rates are Gaussian and can go
+///   arbitrarily negative. The model volatility is absolute (0.01 = 100bp).
+/// - **Shifted lognormal**: displaced Black-Karasinski; `ln(r + shift)` is
+///   Gaussian, so rates stay above `-shift`. The model volatility is then a
+///   lognormal volatility of `r + shift` (0.20 = 20%). A plain lognormal
+///   model cannot take the negative rates seen in EUR and JPY; the shift
+///   makes room for them. Trinomial lattice only.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RateDynamics {
+    /// Hull-White normal short rate.
+    #[default]
+    Normal,
+    /// Lognormal short rate displaced by `shift` (0.03 = 3%).
+    ShiftedLognormal {
+        /// Displacement added to the rate before taking logs.
+        shift: f64,
+    },
+}
+
 /// OAS Calculator for callable/puttable bonds.
 ///
-/// Uses binomial tree pricing to determine the spread that makes the
-/// model price equal to the market price.
+/// Prices the bond on a short-rate lattice (see [`LatticeType`] and
+/// [`RateDynamics`]) and solves for the spread that makes the model price
+/// equal to the market price.
 pub struct OASCalculator {
     model: Box<dyn ShortRateModel>,
     tree_steps: usize,
+    lattice: LatticeType,
+    dynamics: RateDynamics,
+}
+
+/// A fitted lattice of either type.
+enum Lattice {
+    Trinomial(TrinomialTree),
+    Binomial(BinomialTree),
+}
+
+impl Lattice {
+    fn steps(&self) -> usize {
+        match self {
+            Self::Trinomial(tree) => tree.steps,
+            Self::Binomial(tree) => tree.steps,
+        }
+    }
+
+    /// Layer for an event at `t`: exact for the trinomial grid, nearest for
+    /// the uniform binomial one.
+    fn step_at_time(&self, t: f64) -> Option<usize> {
+        match self {
+            Self::Trinomial(tree) => tree.step_at_time(t),
+            Self::Binomial(tree) => Some(tree.step_at_time(t)),
+        }
+    }
+
+    fn price<C, K>(&self, oas: f64, cashflow_at: C, call_price_at: K) -> f64
+    where
+        C: FnMut(usize) -> f64,
+        K: FnMut(usize) -> Option<f64>,
+    {
+        match self {
+            Self::Trinomial(tree) => tree.price(oas, cashflow_at, call_price_at),
+            Self::Binomial(tree) => tree.price(oas, cashflow_at, call_price_at),
+        }
+    }
 }
 
 /// Cached lattice + per-layer payoffs. Reused across OAS evaluations.
 struct TreeContext {
-    tree: TrinomialTree,
+    tree: Lattice,
     step_amount: Vec<f64>,
     step_call: Vec<Option<f64>>,
 }
@@ -96,9 +235,32 @@ impl OASCalculator {
         Self {
             model: Box::new(model),
             tree_steps: tree_steps.max(10),
+            lattice: LatticeType::default(),
+            dynamics: RateDynamics::default(),
         }
     }
 
+    /// Sets the lattice type (trinomial by default).
+    #[must_use]
+    pub fn with_lattice(mut self, lattice: LatticeType) -> Self {
+        self.lattice = lattice;
+        self
+    }
+
+    /// Sets the short-rate dynamics (normal by default).
+    #[must_use]
+    pub fn with_dynamics(mut self, dynamics: RateDynamics) -> Self {
+        self.dynamics = dynamics;
+        self
+    }
+
+    /// Sets the number of tree steps (at least 10).
+    #[must_use]
+    pub fn with_tree_steps(mut self, tree_steps: usize) -> Self {
+        self.tree_steps = tree_steps.max(10);
+        self
+    }
+
     /// Default Hull-White: 3% mean reversion, 200 tree steps.
     #[must_use]
     pub fn default_hull_white(volatility: f64) -> Self {
@@ -117,6 +279,69 @@ impl OASCalculator {
         self.tree_steps
     }
 
+    /// Returns the lattice type.
+    #[must_use]
+    pub fn lattice(&self) -> LatticeType {
+        self.lattice
+    }
+
+    /// Returns the short-rate dynamics.
+    #[must_use]
+    pub fn dynamics(&self) -> RateDynamics {
+        self.dynamics
+    }
+
+    /// Finds a step count at which the price at `oas` has stabilised.
+    ///
+    /// Starting from the configured step count, doubles the steps until two
+    /// successive prices differ by at most `tolerance` (per 100 face).
+    /// Returns the larger step count and its price; pass the steps to
+    /// [`with_tree_steps`](Self::with_tree_steps) to reuse them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError::InvalidInput` if `tolerance` is not positive,
+    /// `AnalyticsError::SolverConvergenceFailed` if the price has not settled
+    /// by 6400 steps, or any pricing error.
+    pub fn converged_steps(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn RateCurveDyn,
+        oas: f64,
+        settlement: Date,
+        tolerance: f64,
+    ) -> AnalyticsResult<(usize, f64)> {
+        if !tolerance.is_finite() || tolerance <= 0.0 {
+            return Err(AnalyticsError::InvalidInput(format!(
+                "step convergence tolerance must be positive, got {tolerance}"
+            )));
+        }
+
+        let sigma = self.model.volatility(0.0);
+        let price_at = |steps: usize| -> AnalyticsResult<f64> {
+            let ctx = self.build_tree_context_with(bond, curve, settlement, sigma, steps)?;
+            self.price_on_tree(&ctx, oas)
+        };
+
+        let mut steps = self.tree_steps;
+        let mut price = price_at(steps)?;
+        let mut change = f64::INFINITY;
+        while steps < MAX_TREE_STEPS {
+            let refined = price_at(steps * 2)?;
+            change = (refined - price).abs();
+            steps *= 2;
+            price = refined;
+            if change <= tolerance {
+                return Ok((steps, price));
+            }
+        }
+        Err(AnalyticsError::SolverConvergenceFailed {
+            solver: format!("{:?} tree step refinement", self.lattice),
+            iterations: u32::try_from(steps).unwrap_or(u32::MAX),
+            residual: change,
+        })
+    }
+
     /// Calculates OAS for a callable bond.
     ///
     /// # Arguments
@@ -182,8 +407,8 @@ impl OASCalculator {
         ))
     }
 
-    /// Price on an event-aligned HW1F trinomial tree. Rebuilds the tree
-    /// per call; root-solving should go through `calculate`.
+    /// Price on the configured HW1F lattice. Rebuilds the tree per call;
+    /// root-solving should go through `calculate`.
     pub fn price_with_oas(
         &self,
         bond: &CallableBond,
@@ -195,11 +420,59 @@ impl OASCalculator {
         self.price_on_tree(&ctx, oas)
     }
 
+    /// Model dirty price of a callable bond at a given OAS.
+    ///
+    /// The inverse of [`Self::calculate`]: values the bond once on the tree
+    /// with the curve shifted by `oas`, so a price quoted as an OAS can be
+    /// repriced on scenario curves.
+    ///
+    /// # Arguments
+    ///
+    /// * `bond` - The callable bond
+    /// * `curve` - Interest rate curve
+    /// * `oas` - Option-adjusted spread
+    /// * `settlement` - Settlement date
+    ///
+    /// # Returns
+    ///
+    /// Dirty price per 100 face.
+    pub fn price_from_oas(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn RateCurveDyn,
+        oas: Spread,
+        settlement: Date,
+    ) -> AnalyticsResult<Decimal> {
+        let oas_decimal = oas.as_decimal().to_f64().unwrap_or(0.0);
+        let price = self.price_with_oas(bond, curve, oas_decimal, settlement)?;
+        Decimal::from_f64_retain(price).ok_or_else(|| {
+            AnalyticsError::CalculationFailed(format!("model price not finite ({price})"))
+        })
+    }
+
     fn build_tree_context(
         &self,
         bond: &CallableBond,
         curve: &dyn RateCurveDyn,
         settlement: Date,
+    ) -> AnalyticsResult<TreeContext> {
+        self.build_tree_context_with(
+            bond,
+            curve,
+            settlement,
+            self.model.volatility(0.0),
+            self.tree_steps,
+        )
+    }
+
+    /// Tree context with the model volatility and step count overridden.
+    fn build_tree_context_with(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn RateCurveDyn,
+        settlement: Date,
+        sigma: f64,
+        steps: usize,
     ) -> AnalyticsResult<TreeContext> {
         let maturity = bond.maturity().ok_or_else(|| {
             AnalyticsError::InvalidInput("Bond has no maturity (perpetual)".to_string())
@@ -240,11 +513,18 @@ impl OASCalculator {
             }
         }
 
-        let mandatory_times: Vec<f64> = mandatory_pairs.iter().map(|p| p.0).collect();
-        let times = build_event_grid(maturity_years, &mandatory_times, self.tree_steps);
+        let times = match self.lattice {
+            LatticeType::Trinomial => {
+                let mandatory_times: Vec<f64> = mandatory_pairs.iter().map(|p| p.0).collect();
+                build_event_grid(maturity_years, &mandatory_times, steps)
+            }
+            LatticeType::Binomial => (0..=steps)
+                .map(|i| maturity_years * i as f64 / steps as f64)
+                .collect(),
+        };
 
         // Pre-evaluate at every grid point so curve errors surface here, not
-        // silently inside the tree. The HW1F builder only queries `times[i]`
+        // silently inside the tree. The HW1F builders only query `times[i]`
         // values from this same slice, so an exact-match lookup is enough.
         let mut zero_at_times: Vec<f64> = Vec::with_capacity(times.len());
         for &t in &times {
@@ -267,10 +547,34 @@ impl OASCalculator {
         };
 
         let a = self.model.mean_reversion();
-        let sigma = self.model.volatility(0.0);
-        let tree = TrinomialTree::build_hull_white_on_grid(zero_lookup, a, sigma, &times);
+        let tree = match (self.lattice, self.dynamics) {
+            (LatticeType::Trinomial, RateDynamics::Normal) => Lattice::Trinomial(
+                TrinomialTree::build_hull_white_on_grid(zero_lookup, a, sigma, &times),
+            ),
+            (LatticeType::Trinomial, RateDynamics::ShiftedLognormal { shift }) => {
+                Lattice::Trinomial(
+                    TrinomialTree::build_shifted_lognormal_on_grid(
+                        zero_lookup,
+                        a,
+                        sigma,
+                        shift,
+                        &times,
+                    )
+                    .map_err(|e| AnalyticsError::InvalidInput(e.to_string()))?,
+                )
+            }
+            (LatticeType::Binomial, RateDynamics::Normal) => Lattice::Binomial(
+                BinomialTree::build_hull_white(zero_lookup, a, sigma, maturity_years, steps)
+                    .map_err(|e| AnalyticsError::InvalidInput(e.to_string()))?,
+            ),
+            (LatticeType::Binomial, RateDynamics::ShiftedLognormal { .. }) => {
+                return Err(AnalyticsError::InvalidInput(
+                    "shifted-lognormal dynamics need the trinomial lattice".to_string(),
+                ));
+            }
+        };
 
-        let n = tree.steps;
+        let n = tree.steps();
         let mut step_amount = vec![0.0_f64; n + 1];
         let mut step_call: Vec<Option<f64>> = vec![None; n + 1];
 
@@ -287,7 +591,22 @@ impl OASCalculator {
             }
             // BDC-adjusted final flows past maturity bucket into the maturity layer.
             let i = tree.step_at_time(cf_t).unwrap_or(n).min(n);
-            step_amount[i] += cf.amount.to_f64().unwrap_or(0.0);
+            let mut amount = cf.amount.to_f64().unwrap_or(0.0);
+            // The binomial grid snaps flows to the nearest layer; carry them
+            // there at the forward discount factor to remove the first-order
+            // timing error.
+            if let Lattice::Binomial(binomial) = &tree {
+                let t_layer = binomial.time_at_step(i);
+                if (t_layer - cf_t).abs() > 1e-12 {
+                    let df = |t: f64| {
+                        curve.discount_factor(t).map_err(|e| {
+                            AnalyticsError::InvalidInput(format!("curve DF at t={t}: {e}"))
+                        })
+                    };
+                    amount *= df(cf_t)? / df(t_layer)?;
+                }
+            }
+            step_amount[i] += amount;
         }
 
         if step_amount[n] < face_value * 0.5 {
@@ -376,6 +695,52 @@ impl OASCalculator {
         Ok((price_down + price_up - 2.0 * price) / (price * shift * shift))
     }
 
+    /// Effective key-rate durations at a held OAS.
+    ///
+    /// Each key tenor of `curve` is bumped ±1bp with a triangular weight
+    /// (fading to zero at the neighbouring tenors, flat beyond the first and
+    /// last) and the tree re-solved at `oas`. Returns `(tenor, duration)`
+    /// pairs in the order given. The weights sum to one at every tenor, so
+    /// the buckets approximately add up to [`Self::effective_duration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError::InvalidInput` if `tenors` is empty or not
+    /// strictly increasing, or if pricing fails.
+    pub fn effective_key_rate_durations(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn RateCurveDyn,
+        oas: f64,
+        settlement: Date,
+        tenors: &[Tenor],
+    ) -> AnalyticsResult<Vec<(Tenor, f64)>> {
+        let years: Vec<f64> = tenors.iter().map(Tenor::years).collect();
+        if years.is_empty() || years.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(AnalyticsError::InvalidInput(
+                "key rate tenors must be non-empty and strictly increasing".to_string(),
+            ));
+        }
+
+        let shift = 0.0001;
+        let price = self.price_with_oas(bond, curve, oas, settlement)?;
+        if price.abs() < 1e-10 {
+            return Err(AnalyticsError::InvalidInput("Price is zero".to_string()));
+        }
+
+        KeyRateBump::custom_profile(&years, 1.0)
+            .into_iter()
+            .zip(tenors)
+            .map(|(bump, &tenor)| {
+                let curve_up = ShiftedCurve::key_rate(curve, shift, bump);
+                let curve_down = ShiftedCurve::key_rate(curve, -shift, bump);
+                let price_up = self.price_with_oas(bond, &curve_up, oas, settlement)?;
+                let price_down = self.price_with_oas(bond, &curve_down, oas, settlement)?;
+                Ok((tenor, (price_down - price_up) / (2.0 * price * shift)))
+            })
+            .collect()
+    }
+
     /// Bullet PV at the same OAS minus the callable model price.
     pub fn option_value(
         &self,
@@ -384,7 +749,63 @@ impl OASCalculator {
         oas: f64,
         settlement: Date,
     ) -> AnalyticsResult<f64> {
-        let callable_price = self.price_with_oas(bond, curve, oas, settlement)?;
+        self.option_value_with_vol(bond, curve, oas, settlement, self.model.volatility(0.0))
+    }
+
+    /// Option value, intrinsic/time split, vega and theta at a held OAS.
+    ///
+    /// - Intrinsic value is the option value at (near-)zero volatility, i.e.
+    ///   exercised deterministically along the forward curve; the rest is
+    ///   time value.
+    /// - Vega bumps the model volatility by one point (0.01).
+    /// - Theta rolls settlement forward one month, holding curve and OAS.
+    ///
+    /// All values are per 100 face.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError` if pricing fails, or if settlement rolled one
+    /// month forward is at or after maturity.
+    pub fn option_greeks(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn RateCurveDyn,
+        oas: f64,
+        settlement: Date,
+    ) -> AnalyticsResult<OptionGreeks> {
+        let sigma = self.model.volatility(0.0);
+        let option_value = self.option_value_with_vol(bond, curve, oas, settlement, sigma)?;
+        let intrinsic =
+            self.option_value_with_vol(bond, curve, oas, settlement, INTRINSIC_VOLATILITY)?;
+        let bumped = self.option_value_with_vol(bond, curve, oas, settlement, sigma + 0.01)?;
+
+        let rolled = settlement
+            .add_months(1)
+            .map_err(|e| AnalyticsError::InvalidInput(format!("theta roll date: {e}")))?;
+        let rolled_value = self.option_value_with_vol(bond, curve, oas, rolled, sigma)?;
+
+        // Deterministic exercise can't be worth more than the optional one;
+        // tree noise at the kink can make it marginally so.
+        let intrinsic_value = intrinsic.max(0.0).min(option_value.max(0.0));
+        Ok(OptionGreeks {
+            option_value,
+            intrinsic_value,
+            time_value: option_value - intrinsic_value,
+            vega: bumped - option_value,
+            theta: rolled_value - option_value,
+        })
+    }
+
+    fn option_value_with_vol(
+        &self,
+        bond: &CallableBond,
+        curve: &dyn RateCurveDyn,
+        oas: f64,
+        settlement: Date,
+        sigma: f64,
+    ) -> AnalyticsResult<f64> {
+        let ctx = self.build_tree_context_with(bond, curve, settlement, sigma, self.tree_steps)?;
+        let callable_price = self.price_on_tree(&ctx, oas)?;
 
         let base_bond = bond.base_bond();
         let cash_flows = base_bond.cash_flows(settlement);
@@ -489,6 +910,99 @@ mod tests {
         CallableBond::new(base, call_schedule)
     }
 
+    fn create_european_callable() -> CallableBond {
+        let base = FixedRateBond::builder()
+            .cusip_unchecked("123456789")
+            .coupon_percent(5.0)
+            .maturity(date(2029, 1, 15))
+            .issue_date(date(2020, 1, 15))
+            .us_corporate()
+            .build()
+            .unwrap();
+
+        let call_schedule = CallSchedule::new(CallType::European)
+            .with_entry(CallEntry::new(date(2027, 1, 15), 100.0));
+
+        CallableBond::new(base, call_schedule)
+    }
+
+    #[test]
+    fn test_binomial_and_trinomial_agree_on_european_call() {
+        let bond = create_european_callable();
+        let curve = create_flat_curve(0.05);
+        let settlement = date(2024, 1, 17);
+
+        let diff_at = |steps: usize| {
+            let tri = OASCalculator::new(HullWhite::new(0.03, 0.01), steps);
+            let bin = OASCalculator::new(HullWhite::new(0.03, 0.01), steps)
+                .with_lattice(LatticeType::Binomial);
+            assert_eq!(bin.lattice(), LatticeType::Binomial);
+            let p_tri = tri.price_with_oas(&bond, &curve, 0.0, settlement).unwrap();
+            let p_bin = bin.price_with_oas(&bond, &curve, 0.0, settlement).unwrap();
+            (p_tri - p_bin).abs()
+        };
+
+        // Coarse binomial lattices miss badly; refined ones agree to within
+        // half a cent per 100.
+        assert!(diff_at(25) > 0.05, "coarse diff {}", diff_at(25));
+        for steps in [200, 800] {
+            let diff = diff_at(steps);
+            assert!(diff < 0.005, "{steps} steps: diff {diff}");
+        }
+    }
+
+    #[test]
+    fn test_binomial_and_trinomial_agree_on_american_callable() {
+        // Mid-period settlement puts coupon and call dates off the uniform
+        // grid, so the trinomial lattice mixes short and long steps.
+        let bond = create_callable_bond();
+        let curve = create_flat_curve(0.045);
+        let settlement = date(2024, 3, 4);
+
+        let tri = OASCalculator::new(HullWhite::new(0.03, 0.01), 400);
+        let bin =
+            OASCalculator::new(HullWhite::new(0.03, 0.01), 800).with_lattice(LatticeType::Binomial);
+        let p_tri = tri.price_with_oas(&bond, &curve, 0.0, settlement).unwrap();
+        let p_bin = bin.price_with_oas(&bond, &curve, 0.0, settlement).unwrap();
+        assert!((p_tri - p_bin).abs() < 0.01, "trinomial {p_tri} vs binomial {p_bin}");
+
+        // The call caps the price below the bullet.
+        let option = tri.option_value(&bond, &curve, 0.0, settlement).unwrap();
+        assert!(option > 0.1, "option value {option}");
+    }
+
+    #[test]
+    fn test_binomial_zero_volatility_is_an_error() {
+        let bond = create_callable_bond();
+        let curve = create_flat_curve(0.045);
+        let calc =
+            OASCalculator::new(HullWhite::new(0.03, 0.0), 100).with_lattice(LatticeType::Binomial);
+        let result = calc.price_with_oas(&bond, &curve, 0.0, date(2024, 1, 17));
+        assert!(matches!(result, Err(AnalyticsError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_converged_steps() {
+        let bond = create_european_callable();
+        let curve = create_flat_curve(0.05);
+        let settlement = date(2024, 1, 17);
+        let calc = OASCalculator::new(HullWhite::new(0.03, 0.01), 25);
+
+        let (steps, price) = calc
+            .converged_steps(&bond, &curve, 0.0, settlement, 1e-3)
+            .unwrap();
+        assert!(steps > 25);
+        let coarser = calc
+            .with_tree_steps(steps / 2)
+            .price_with_oas(&bond, &curve, 0.0, settlement)
+            .unwrap();
+        assert!((price - coarser).abs() <= 1e-3);
+
+        assert!(OASCalculator::new(HullWhite::new(0.03, 0.01), 25)
+            .converged_steps(&bond, &curve, 0.0, settlement, 0.0)
+            .is_err());
+    }
+
     #[test]
     fn test_calculator_creation() {
         let calc = OASCalculator::default_hull_white(0.01);
@@ -519,6 +1033,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_price_from_oas_inverts_calculate() {
+        let calc = OASCalculator::new(HullWhite::new(0.03, 0.01), 50);
+        let bond = create_callable_bond();
+        let curve = create_flat_curve(0.05);
+        let settlement = date(2024, 1, 17);
+
+        // OAS comes back in whole basis points, so a market price is
+        // recovered to within half a basis point of spread.
+        let market = dec!(99.25);
+        let oas = calc.calculate(&bond, market, &curve, settlement).unwrap();
+        let price = calc.price_from_oas(&bond, &curve, oas, settlement).unwrap();
+        assert!((price - market).abs() < dec!(0.03));
+
+        // A price generated at a whole-bp OAS round-trips exactly.
+        let oas = Spread::new(dec!(85), SpreadType::OAS);
+        let price = calc.price_from_oas(&bond, &curve, oas, settlement).unwrap();
+        let solved = calc.calculate(&bond, price, &curve, settlement).unwrap();
+        assert_eq!(solved, oas);
+        let reprice = calc
+            .price_from_oas(&bond, &curve, solved, settlement)
+            .unwrap();
+        assert_eq!(reprice, price);
+    }
+
+    #[test]
+    fn test_negative_rate_discounting_prices_above_par() {
+        let bond = create_callable_bond();
+        let curve = create_flat_curve(-0.005);
+        let settlement = date(2024, 1, 17);
+
+        // Discount factors exceed one, so a positive-coupon bond is worth
+        // more than par.
+        let pv: f64 = bond
+            .base_bond()
+            .cash_flows(settlement)
+            .iter()
+            .map(|cf| {
+                let t = settlement.days_between(&cf.date) as f64 / 365.0;
+                cf.amount.to_f64().unwrap() * curve.discount_factor(t).unwrap()
+            })
+            .sum();
+        assert!(pv > 100.0);
+        assert!(curve.discount_factor(5.0).unwrap() > 1.0);
+    }
+
+    #[test]
+    fn test_shifted_lognormal_prices_callable_with_negative_rates() {
+        let bond = create_callable_bond();
+        let curve = create_flat_curve(-0.005);
+        let settlement = date(2024, 1, 17);
+        let calc = OASCalculator::new(HullWhite::new(0.03, 0.20), 100)
+            .with_dynamics(RateDynamics::ShiftedLognormal { shift: 0.03 });
+
+        let callable = calc.price_with_oas(&bond, &curve, 0.0, settlement).unwrap();
+        assert!(callable.is_finite());
+        // Deep in the money at negative rates: worth about the first call
+        // price, and no more than the bullet.
+        let bullet = calc
+            .option_value(&bond, &curve, 0.0, settlement)
+            .map(|option| callable + option)
+            .unwrap();
+        assert!(bullet.is_finite());
+        assert!(callable <= bullet);
+        assert!(callable > 100.0);
+
+        let dirty = Decimal::from_f64_retain(callable).unwrap();
+        let oas = calc.calculate(&bond, dirty, &curve, settlement).unwrap();
+        assert_eq!(oas.as_bps(), Decimal::ZERO);
+
+        // A shift that does not cover the negative forwards is rejected.
+        let too_small = OASCalculator::new(HullWhite::new(0.03, 0.20), 100)
+            .with_dynamics(RateDynamics::ShiftedLognormal { shift: 0.001 });
+        assert!(too_small
+            .price_with_oas(&bond, &curve, 0.0, settlement)
+            .is_err());
+    }
+
     #[test]
     fn test_price_increases_with_negative_oas() {
         let calc = OASCalculator::new(HullWhite::new(0.03, 0.01), 50);
@@ -551,6 +1143,92 @@ mod tests {
         assert!(dur > 0.0 && dur < 15.0, "Duration {} is out of range", dur);
     }
 
+    #[test]
+    fn test_key_rate_durations_shift_short_as_call_nears_money() {
+        let calc = OASCalculator::new(HullWhite::new(0.03, 0.01), 60);
+        let settlement = date(2024, 1, 17);
+        let tenors = [
+            Tenor::Y1,
+            Tenor::Y2,
+            Tenor::Y3,
+            Tenor::Y5,
+            Tenor::Y7,
+            Tenor::Y10,
+        ];
+
+        let base = FixedRateBond::builder()
+            .cusip_unchecked("KRDCALL01")
+            .coupon_percent(5.0)
+            .maturity(date(2034, 1, 15))
+            .issue_date(date(2024, 1, 15))
+            .us_corporate()
+            .build()
+            .unwrap();
+        let bond = CallableBond::new(
+            base,
+            CallSchedule::new(CallType::American)
+                .with_entry(CallEntry::new(date(2026, 1, 15), 100.0)),
+        );
+
+        // Share of the total carried by the ≤ 2Y buckets.
+        let short_share = |rate: f64| {
+            let curve = create_flat_curve(rate);
+            let krds = calc
+                .effective_key_rate_durations(&bond, &curve, 0.0, settlement, &tenors)
+                .unwrap();
+            let total: f64 = krds.iter().map(|(_, d)| d).sum();
+            let effective = calc
+                .effective_duration(&bond, &curve, 0.0, settlement)
+                .unwrap();
+            assert!(
+                (total - effective).abs() < 0.05 * effective,
+                "KRD sum {total} vs effective {effective} at {rate}"
+            );
+            let short: f64 = krds.iter().filter(|(t, _)| t.years() <= 2.0).map(|(_, d)| d).sum();
+            short / total
+        };
+
+        // Far out-of-the-money (8%) vs near-the-money (5%) vs in (4%).
+        let otm = short_share(0.08);
+        let atm = short_share(0.05);
+        let itm = short_share(0.04);
+        assert!(atm > otm, "near-the-money {atm} vs OTM {otm}");
+        assert!(itm > atm, "ITM {itm} vs near-the-money {atm}");
+    }
+
+    #[test]
+    fn test_option_greeks() {
+        let calc = OASCalculator::new(HullWhite::new(0.03, 0.01), 60);
+        let bond = create_callable_bond();
+        let curve = create_flat_curve(0.05);
+        let settlement = date(2024, 1, 17);
+
+        let greeks = calc.option_greeks(&bond, &curve, 0.0, settlement).unwrap();
+        let option_value = calc.option_value(&bond, &curve, 0.0, settlement).unwrap();
+
+        assert!((greeks.option_value - option_value).abs() < 1e-12);
+        assert!(greeks.vega > 0.0, "vega {}", greeks.vega);
+        assert!(greeks.time_value > 0.0, "time value {}", greeks.time_value);
+        assert!((greeks.intrinsic_value + greeks.time_value - greeks.option_value).abs() < 1e-12);
+        // Less time to expiry leaves less optionality.
+        assert!(greeks.theta < 0.0, "theta {}", greeks.theta);
+    }
+
+    #[test]
+    fn test_key_rate_tenors_validated() {
+        let calc = OASCalculator::new(HullWhite::new(0.03, 0.01), 30);
+        let bond = create_callable_bond();
+        let curve = create_flat_curve(0.05);
+        let settlement = date(2024, 1, 17);
+
+        assert!(calc
+            .effective_key_rate_durations(&bond, &curve, 0.0, settlement, &[])
+            .is_err());
+        assert!(calc
+            .effective_key_rate_durations(&bond, &curve, 0.0, settlement, &[Tenor::Y5, Tenor::Y2])
+            .is_err());
+    }
+
     #[test]
     fn test_first_call_on_coupon_date_does_not_double_pay() {
         // Deeply ITM call on a coupon date forces exercise; PV must collapse
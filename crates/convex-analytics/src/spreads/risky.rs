@@ -0,0 +1,287 @@
+//! Survival-weighted (intensity-model) bond pricing.
+//!
+//! Prices default-risky cash flows off a risk-free discount curve and a
+//! credit curve instead of a single Z-spread:
+//!
+//! ```text
+//! PV = Σ CF_i × DF(t_i) × Q(t_i)
+//!    + R × Σ_i ∫[t_{i-1}, t_i] N(u) × DF(u) × (−dQ(u))
+//! ```
+//!
+//! where `Q` is the survival probability, `R` the recovery rate and `N(u)` the
+//! principal outstanding when default happens at `u` (recovery of face).
+
+use rust_decimal::prelude::*;
+
+use convex_bonds::traits::{principal_amounts, BondCashFlow};
+use convex_core::types::Date;
+use convex_curves::{CreditCurve, RateCurveDyn, TermStructure};
+
+use crate::error::{AnalyticsError, AnalyticsResult};
+
+/// Default-time integration steps per year within each cash-flow period.
+const DEFAULT_STEPS_PER_YEAR: f64 = 12.0;
+
+/// Present value at `settlement` of `cash_flows` under an intensity model.
+///
+/// Each flow is discounted off `discount_curve` and weighted by its survival
+/// probability from `credit_curve`; on default within a period the holder
+/// receives `recovery` times the principal still outstanding, integrated over
+/// the period on a monthly grid. Discount factors and survival
+/// probabilities are taken forward from settlement, so the price is
+/// conditional on no default before settlement.
+///
+/// `face` is the notional the flows are scaled to (100 for flows per 100
+/// face). Principal is taken from flow types and factors as in
+/// [`principal_amounts`]: a combined coupon-and-principal flow repays `face`
+/// times its drop in factor, never its coupon.
+///
+/// With zero hazard this is the risk-free PV; with zero recovery and a flat
+/// hazard `h` it equals the PV at a continuous Z-spread of `h`.
+///
+/// # Errors
+///
+/// Returns `AnalyticsError` if `recovery` is outside `[0, 1]`, no cash flows
+/// fall after settlement, or either curve cannot be evaluated.
+pub fn risky_present_value<T: TermStructure>(
+    cash_flows: &[BondCashFlow],
+    face: f64,
+    discount_curve: &dyn RateCurveDyn,
+    credit_curve: &CreditCurve<T>,
+    recovery: f64,
+    settlement: Date,
+) -> AnalyticsResult<f64> {
+    if !(0.0..=1.0).contains(&recovery) {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "recovery rate {recovery} must be in [0, 1]"
+        )));
+    }
+
+    let future: Vec<BondCashFlow> = cash_flows
+        .iter()
+        .filter(|cf| cf.date > settlement)
+        .cloned()
+        .collect();
+    if future.is_empty() {
+        return Err(AnalyticsError::InvalidInput(
+            "No cash flows after settlement".to_string(),
+        ));
+    }
+
+    let df_settle = discount_curve.discount_factor(discount_curve.date_to_tenor(settlement))?;
+    let q_settle = credit_curve.survival_probability(settlement)?;
+    if df_settle <= 0.0 || q_settle <= 0.0 {
+        return Err(AnalyticsError::InvalidInput(
+            "discount factor or survival probability at settle is non-positive".to_string(),
+        ));
+    }
+    let df = |date: Date| -> AnalyticsResult<f64> {
+        Ok(discount_curve.discount_factor(discount_curve.date_to_tenor(date))? / df_settle)
+    };
+    let survival = |date: Date| -> AnalyticsResult<f64> {
+        Ok(credit_curve.survival_probability(date)? / q_settle)
+    };
+
+    let face = Decimal::from_f64(face)
+        .filter(|f| *f > Decimal::ZERO)
+        .ok_or_else(|| AnalyticsError::InvalidInput(format!("face {face} must be positive")))?;
+    let principals: Vec<f64> = principal_amounts(&future, face)
+        .iter()
+        .map(|p| p.to_f64().unwrap_or(0.0))
+        .collect();
+    let mut outstanding: f64 = principals.iter().sum();
+
+    let mut pv = 0.0;
+    let mut period_start = settlement;
+    let mut q_start = 1.0;
+    for (cf, principal) in future.iter().zip(&principals) {
+        let q_end = survival(cf.date)?;
+        pv += cf.amount.to_f64().unwrap_or(0.0) * df(cf.date)? * q_end;
+
+        if recovery > 0.0 && outstanding > 0.0 {
+            let days = period_start.days_between(&cf.date);
+            let steps = (days as f64 / 365.0 * DEFAULT_STEPS_PER_YEAR)
+                .ceil()
+                .max(1.0) as i64;
+            let mut step_start = period_start;
+            let mut q_prev = q_start;
+            for k in 1..=steps {
+                let step_end = if k == steps {
+                    cf.date
+                } else {
+                    period_start.add_days(days * k / steps)
+                };
+                let q_next = survival(step_end)?;
+                let mid = step_start.add_days(step_start.days_between(&step_end) / 2);
+                pv += recovery * outstanding * df(mid)? * (q_prev - q_next);
+                step_start = step_end;
+                q_prev = q_next;
+            }
+        }
+
+        outstanding -= principal;
+        period_start = cf.date;
+        q_start = q_end;
+    }
+
+    Ok(pv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use convex_bonds::traits::CashFlowType;
+    use convex_curves::curves::{DiscountCurveBuilder, DiscreteCurve};
+    use convex_curves::{InterpolationMethod, ValueType};
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn flat_discount_curve(rate: f64) -> impl RateCurveDyn {
+        DiscountCurveBuilder::new(date(2024, 1, 15))
+            .add_pillar(0.5, (-rate * 0.5).exp())
+            .add_pillar(1.0, (-rate * 1.0).exp())
+            .add_pillar(2.0, (-rate * 2.0).exp())
+            .add_pillar(5.0, (-rate * 5.0).exp())
+            .add_pillar(10.0, (-rate * 10.0).exp())
+            .with_extrapolation()
+            .build()
+            .unwrap()
+    }
+
+    fn flat_credit_curve(hazard: f64) -> CreditCurve<DiscreteCurve> {
+        let tenors = vec![0.0, 1.0, 2.0, 5.0, 10.0];
+        let survivals = tenors.iter().map(|&t| (-hazard * t).exp()).collect();
+        let curve = DiscreteCurve::new(
+            date(2024, 1, 15),
+            tenors,
+            survivals,
+            ValueType::SurvivalProbability,
+            InterpolationMethod::LogLinear,
+        )
+        .unwrap();
+        CreditCurve::new(curve, 0.40)
+    }
+
+    /// 5% semi-annual 5Y bullet per 100 face.
+    fn bullet_flows() -> Vec<BondCashFlow> {
+        let mut flows = Vec::new();
+        let mut cf_date = date(2024, 7, 15);
+        for i in 1..=10 {
+            if i == 10 {
+                flows.push(BondCashFlow::coupon_and_principal(
+                    cf_date,
+                    dec!(2.5),
+                    dec!(100),
+                ));
+            } else {
+                flows.push(BondCashFlow::coupon(cf_date, dec!(2.5)));
+            }
+            cf_date = cf_date.add_months(6).unwrap();
+        }
+        flows
+    }
+
+    fn risk_free_pv(flows: &[BondCashFlow], curve: &dyn RateCurveDyn, spread: f64) -> f64 {
+        flows
+            .iter()
+            .map(|cf| {
+                let t = curve.date_to_tenor(cf.date);
+                cf.amount.to_f64().unwrap()
+                    * curve.discount_factor(t).unwrap()
+                    * (-spread * t).exp()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_zero_hazard_is_risk_free() {
+        let discount = flat_discount_curve(0.04);
+        let flows = bullet_flows();
+
+        let risky = risky_present_value(
+            &flows,
+            100.0,
+            &discount,
+            &flat_credit_curve(0.0),
+            0.40,
+            date(2024, 1, 15),
+        )
+        .unwrap();
+
+        assert_relative_eq!(risky, risk_free_pv(&flows, &discount, 0.0), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_zero_recovery_matches_spread_pricing() {
+        let discount = flat_discount_curve(0.04);
+        let flows = bullet_flows();
+        let hazard = 0.02;
+
+        let risky = risky_present_value(
+            &flows,
+            100.0,
+            &discount,
+            &flat_credit_curve(hazard),
+            0.0,
+            date(2024, 1, 15),
+        )
+        .unwrap();
+
+        assert_relative_eq!(
+            risky,
+            risk_free_pv(&flows, &discount, hazard),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn test_recovery_adds_value() {
+        let discount = flat_discount_curve(0.04);
+        let flows = bullet_flows();
+        let credit = flat_credit_curve(0.02);
+        let settle = date(2024, 1, 15);
+
+        let no_recovery =
+            risky_present_value(&flows, 100.0, &discount, &credit, 0.0, settle).unwrap();
+        let with_recovery =
+            risky_present_value(&flows, 100.0, &discount, &credit, 0.40, settle).unwrap();
+        let risk_free = risk_free_pv(&flows, &discount, 0.0);
+
+        assert!(no_recovery < with_recovery && with_recovery < risk_free);
+        // Recovery value ≈ R × 100 × P(default before 5Y), discounted.
+        let default_prob = 1.0 - (-0.02_f64 * 5.0).exp();
+        let recovery_value = with_recovery - no_recovery;
+        assert!(recovery_value > 0.40 * 100.0 * default_prob * (-0.04_f64 * 5.0).exp());
+        assert!(recovery_value < 0.40 * 100.0 * default_prob);
+
+        assert!(risky_present_value(&flows, 100.0, &discount, &credit, 1.5, settle).is_err());
+    }
+
+    #[test]
+    fn test_final_coupon_is_not_recovered() {
+        // Only the final coupon-and-principal flow remains: recovery is on
+        // the 100 face, not the 102.5 payment.
+        let discount = flat_discount_curve(0.04);
+        let credit = flat_credit_curve(0.02);
+        let flows = bullet_flows();
+        let settle = date(2028, 8, 1);
+        let remaining: Vec<_> = flows.iter().filter(|cf| cf.date > settle).collect();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].flow_type, CashFlowType::CouponAndPrincipal);
+
+        let no_recovery =
+            risky_present_value(&flows, 100.0, &discount, &credit, 0.0, settle).unwrap();
+        let with_recovery =
+            risky_present_value(&flows, 100.0, &discount, &credit, 0.40, settle).unwrap();
+
+        // R × N × ∫₀^τ e^{−ru} h e^{−hu} du with flat r and h.
+        let (r, h) = (0.04, 0.02);
+        let tau = settle.days_between(&remaining[0].date) as f64 / 365.0;
+        let expected = 0.40 * 100.0 * h / (r + h) * (1.0 - (-(r + h) * tau).exp());
+        assert_relative_eq!(with_recovery - no_recovery, expected, max_relative = 2e-3);
+    }
+}
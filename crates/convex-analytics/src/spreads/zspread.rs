@@ -8,11 +8,15 @@ use rust_decimal::Decimal;
 
 use convex_bonds::traits::{Bond, FixedCouponBond};
 use convex_core::types::{Date, Spread, SpreadType};
-use convex_curves::RateCurveDyn;
+use convex_curves::{RateCurveDyn, Tenor};
 use convex_math::solvers::{brent, SolverConfig};
 
+use super::oas::ShiftedCurve;
 use crate::error::{AnalyticsError, AnalyticsResult};
 
+/// Spread bump used for spread-duration finite differences (1bp).
+const SPREAD_BUMP: f64 = 0.0001;
+
 /// (years_from_settle, fwd_df_from_settle, amount) — Z-spread anchored at settle.
 fn forward_cashflows(
     curve: &dyn RateCurveDyn,
@@ -54,13 +58,15 @@ fn forward_cashflows(
 ///
 /// The Z-spread is found by solving:
 /// ```text
-/// Dirty Price = Σ CF_i × DF(t_i) × exp(-Z × t_i)
+/// Dirty Price = Σ CF_i × DF(t_i) × D_Z(t_i)
 /// ```
 ///
 /// Where:
 /// - CF_i = cash flow at time t_i
 /// - DF(t_i) = discount factor from the spot curve at time t_i
 /// - Z = Z-spread (constant)
+/// - D_Z(t) = spread discount under the calculator's compounding: `exp(-Z × t)`
+///   for continuous (the default), `(1 + Z/k)^(-k × t)` for `k` periods a year
 pub struct ZSpreadCalculator<'a> {
     /// Reference to the spot/zero curve.
     curve: &'a dyn RateCurveDyn,
@@ -74,6 +80,7 @@ impl std::fmt::Debug for ZSpreadCalculator<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ZSpreadCalculator")
             .field("config", &self.config)
+            .field("compounding", &self.compounding)
             .finish_non_exhaustive()
     }
 }
@@ -215,30 +222,59 @@ impl<'a> ZSpreadCalculator<'a> {
     ///
     /// # Returns
     ///
-    /// The dirty price as a percentage of par.
+    /// The dirty price as a percentage of par, or 0 if the bond cannot be
+    /// priced (see [`try_price_with_spread`](Self::try_price_with_spread)).
     pub fn price_with_spread<B: Bond + FixedCouponBond>(
         &self,
         bond: &B,
         z_spread: f64,
         settlement: Date,
     ) -> f64 {
-        let Some(maturity) = bond.maturity() else {
-            return 0.0;
-        };
+        self.try_price_with_spread(bond, z_spread, settlement)
+            .unwrap_or(0.0)
+    }
+
+    /// Prices a bond given a Z-spread, reporting why it cannot be priced.
+    ///
+    /// Each cash flow is discounted at the curve forward from settlement
+    /// times the spread discount factor, exactly as [`calculate`](Self::calculate)
+    /// does, so the two invert each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError` if:
+    /// - The bond is perpetual or settlement is at or after maturity
+    /// - No future cash flows exist
+    /// - The curve cannot discount to settlement
+    pub fn try_price_with_spread<B: Bond + FixedCouponBond>(
+        &self,
+        bond: &B,
+        z_spread: f64,
+        settlement: Date,
+    ) -> AnalyticsResult<f64> {
+        let maturity = bond.maturity().ok_or_else(|| {
+            AnalyticsError::InvalidInput("Bond has no maturity (perpetual)".to_string())
+        })?;
         if settlement >= maturity {
-            return 0.0;
+            return Err(AnalyticsError::InvalidSettlement {
+                settlement: settlement.to_string(),
+                maturity: maturity.to_string(),
+            });
         }
 
         let cash_flows = bond.cash_flows(settlement);
-        let Ok(cf_data) = forward_cashflows(self.curve, &cash_flows, settlement) else {
-            return 0.0;
-        };
+        if cash_flows.is_empty() {
+            return Err(AnalyticsError::InvalidInput(
+                "No cash flows after settlement".to_string(),
+            ));
+        }
+        let cf_data = forward_cashflows(self.curve, &cash_flows, settlement)?;
         let face = bond.face_value().to_f64().unwrap_or(100.0);
         let pv: f64 = cf_data
             .iter()
             .map(|(dt, fwd_df, amt)| amt * fwd_df * self.spread_df(z_spread, *dt))
             .sum();
-        pv / face * 100.0
+        Ok(pv / face * 100.0)
     }
 
     /// Calculates the spread DV01 (price sensitivity to 1bp spread change).
@@ -325,6 +361,97 @@ impl<'a> ZSpreadCalculator<'a> {
             SpreadType::ZSpread,
         ))
     }
+
+    /// Spread duration of pre-computed cash flows at a given Z-spread.
+    ///
+    /// Central difference over a 1bp parallel bump of the spread, holding
+    /// the curve fixed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError` if no cash flows fall after settlement or
+    /// the curve cannot be evaluated.
+    pub fn spread_duration_from_cash_flows(
+        &self,
+        cash_flows: &[convex_bonds::traits::BondCashFlow],
+        z_spread: f64,
+        settlement: Date,
+    ) -> AnalyticsResult<f64> {
+        let cf_data = forward_cashflows(self.curve, cash_flows, settlement)?;
+        let base = self.bucket_pv(&cf_data, z_spread, |_| true);
+        if base.abs() < 1e-12 {
+            return Err(AnalyticsError::InvalidInput(
+                "No cash flows after settlement".to_string(),
+            ));
+        }
+        let up = self.bucket_pv(&cf_data, z_spread + SPREAD_BUMP, |_| true);
+        let down = self.bucket_pv(&cf_data, z_spread - SPREAD_BUMP, |_| true);
+        Ok((down - up) / (2.0 * base * SPREAD_BUMP))
+    }
+
+    /// Partial spread durations bucketed to `tenors`.
+    ///
+    /// Each bucket bumps the Z-spread only for cash flows whose time from
+    /// settlement falls in `(previous tenor, tenor]`; flows beyond the last
+    /// tenor are assigned to the last bucket. Sensitivities are measured
+    /// against the full price, so the buckets sum to the total spread
+    /// duration. Returns `(tenor, duration)` pairs in the order given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsError` if `tenors` is empty or not strictly
+    /// increasing, no cash flows fall after settlement, or the curve cannot
+    /// be evaluated.
+    pub fn partial_spread_durations(
+        &self,
+        cash_flows: &[convex_bonds::traits::BondCashFlow],
+        z_spread: f64,
+        settlement: Date,
+        tenors: &[Tenor],
+    ) -> AnalyticsResult<Vec<(Tenor, f64)>> {
+        let years: Vec<f64> = tenors.iter().map(Tenor::years).collect();
+        if years.is_empty() || years.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(AnalyticsError::InvalidInput(
+                "tenors must be non-empty and strictly increasing".to_string(),
+            ));
+        }
+
+        let cf_data = forward_cashflows(self.curve, cash_flows, settlement)?;
+        let base = self.bucket_pv(&cf_data, z_spread, |_| true);
+        if base.abs() < 1e-12 {
+            return Err(AnalyticsError::InvalidInput(
+                "No cash flows after settlement".to_string(),
+            ));
+        }
+
+        let last = years.len() - 1;
+        let bucket_of = |dt: f64| years.iter().position(|&t| dt <= t).unwrap_or(last);
+
+        Ok(tenors
+            .iter()
+            .enumerate()
+            .map(|(i, &tenor)| {
+                let in_bucket = |dt: f64| bucket_of(dt) == i;
+                let up = self.bucket_pv(&cf_data, z_spread + SPREAD_BUMP, in_bucket);
+                let down = self.bucket_pv(&cf_data, z_spread - SPREAD_BUMP, in_bucket);
+                (tenor, (down - up) / (2.0 * base * SPREAD_BUMP))
+            })
+            .collect())
+    }
+
+    /// PV of the flows selected by `in_bucket` at spread `z`.
+    fn bucket_pv(
+        &self,
+        cf_data: &[(f64, f64, f64)],
+        z: f64,
+        in_bucket: impl Fn(f64) -> bool,
+    ) -> f64 {
+        cf_data
+            .iter()
+            .filter(|(dt, _, _)| in_bucket(*dt))
+            .map(|(dt, fwd_df, amt)| amt * fwd_df * self.spread_df(z, *dt))
+            .sum()
+    }
 }
 
 /// Convenience function to calculate Z-spread.
@@ -346,8 +473,7 @@ pub fn z_spread<B: Bond + FixedCouponBond>(
     settlement: Date,
 ) -> AnalyticsResult<Spread> {
     let compounding =
-        convex_core::types::Compounding::try_from_periods_per_year(bond.coupon_frequency())
-            .unwrap_or(convex_core::types::Compounding::Continuous);
+        convex_core::types::Compounding::from_periods_per_year(bond.coupon_frequency());
 
     ZSpreadCalculator::new(curve)
         .with_compounding(compounding)
@@ -366,9 +492,93 @@ pub fn z_spread_from_curve<B: Bond + FixedCouponBond>(
     z_spread(bond, dirty_price, curve, settlement)
 }
 
+/// Spread duration of cash flows priced off `curve` at `z_spread`
+/// (continuous compounding).
+pub fn spread_duration_from_curve(
+    cash_flows: &[convex_bonds::traits::BondCashFlow],
+    curve: &dyn RateCurveDyn,
+    z_spread: f64,
+    settlement: Date,
+) -> AnalyticsResult<f64> {
+    ZSpreadCalculator::new(curve).spread_duration_from_cash_flows(cash_flows, z_spread, settlement)
+}
+
+/// Partial spread durations bucketed to `tenors`; see
+/// [`ZSpreadCalculator::partial_spread_durations`].
+pub fn partial_spread_durations(
+    cash_flows: &[convex_bonds::traits::BondCashFlow],
+    curve: &dyn RateCurveDyn,
+    z_spread: f64,
+    settlement: Date,
+    tenors: &[Tenor],
+) -> AnalyticsResult<Vec<(Tenor, f64)>> {
+    ZSpreadCalculator::new(curve).partial_spread_durations(cash_flows, z_spread, settlement, tenors)
+}
+
+/// Effective duration of `bond` from ±`bump_bps` parallel shifts of
+/// `curve`, holding the (continuous) Z-spread at `z_spread`.
+///
+/// The same curve-bump measure as [`OASCalculator::effective_duration`], so
+/// bullets and callables in one book share a definition of duration.
+///
+/// [`OASCalculator::effective_duration`]: super::OASCalculator::effective_duration
+pub fn effective_duration_from_curve<B: Bond + FixedCouponBond>(
+    bond: &B,
+    curve: &dyn RateCurveDyn,
+    z_spread: f64,
+    settlement: Date,
+    bump_bps: f64,
+) -> AnalyticsResult<f64> {
+    let (price, up, down, shift) =
+        curve_bumped_prices(bond, curve, z_spread, settlement, bump_bps)?;
+    Ok((down - up) / (2.0 * price * shift))
+}
+
+/// Effective convexity of `bond` from ±`bump_bps` parallel shifts of
+/// `curve`, holding the Z-spread; see [`effective_duration_from_curve`].
+pub fn effective_convexity_from_curve<B: Bond + FixedCouponBond>(
+    bond: &B,
+    curve: &dyn RateCurveDyn,
+    z_spread: f64,
+    settlement: Date,
+    bump_bps: f64,
+) -> AnalyticsResult<f64> {
+    let (price, up, down, shift) =
+        curve_bumped_prices(bond, curve, z_spread, settlement, bump_bps)?;
+    Ok((down + up - 2.0 * price) / (price * shift * shift))
+}
+
+/// `(base, up, down, shift)` prices at `z_spread` off `curve` and the
+/// curve shifted by ±`bump_bps`.
+fn curve_bumped_prices<B: Bond + FixedCouponBond>(
+    bond: &B,
+    curve: &dyn RateCurveDyn,
+    z_spread: f64,
+    settlement: Date,
+    bump_bps: f64,
+) -> AnalyticsResult<(f64, f64, f64, f64)> {
+    let shift = bump_bps / 10_000.0;
+    if shift <= 0.0 {
+        return Err(AnalyticsError::InvalidInput(
+            "bump_bps must be positive".to_string(),
+        ));
+    }
+    let price = ZSpreadCalculator::new(curve).try_price_with_spread(bond, z_spread, settlement)?;
+    if price.abs() < 1e-10 {
+        return Err(AnalyticsError::InvalidInput("Price is zero".to_string()));
+    }
+    let curve_up = ShiftedCurve::new(curve, shift);
+    let curve_down = ShiftedCurve::new(curve, -shift);
+    let up = ZSpreadCalculator::new(&curve_up).try_price_with_spread(bond, z_spread, settlement)?;
+    let down =
+        ZSpreadCalculator::new(&curve_down).try_price_with_spread(bond, z_spread, settlement)?;
+    Ok((price, up, down, shift))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
     use convex_curves::curves::DiscountCurveBuilder;
     use rust_decimal_macros::dec;
 
@@ -529,6 +739,45 @@ mod tests {
         let _ = calc.with_tolerance(1e-8).with_max_iterations(50);
     }
 
+    #[test]
+    fn test_curve_effective_duration_matches_yield_duration_for_bullet() {
+        use convex_bonds::traits::BondAnalytics;
+        use convex_core::types::Frequency;
+
+        let curve = create_flat_curve(0.05);
+        let bond = MockBond::new(date(2029, 1, 15), dec!(0.05));
+        let settlement = date(2024, 1, 15);
+        let z = 0.01;
+
+        let price = ZSpreadCalculator::new(&curve).price_with_spread(&bond, z, settlement);
+        let ytm = bond
+            .yield_to_maturity(
+                settlement,
+                Decimal::from_f64_retain(price).unwrap(),
+                Frequency::SemiAnnual,
+            )
+            .unwrap()
+            .yield_value;
+
+        let curve_duration =
+            effective_duration_from_curve(&bond, &curve, z, settlement, 1.0).unwrap();
+        let yield_duration = bond
+            .effective_duration(settlement, ytm, Frequency::SemiAnnual, 1.0)
+            .unwrap();
+        // The curve shifts continuously-compounded zero rates; the yield bump
+        // is semi-annual, so the two differ by the usual (1 + y/2).
+        assert_relative_eq!(
+            curve_duration,
+            yield_duration * (1.0 + ytm / 2.0),
+            max_relative = 2e-3
+        );
+
+        let curve_convexity =
+            effective_convexity_from_curve(&bond, &curve, z, settlement, 1.0).unwrap();
+        assert!(curve_convexity > 0.0);
+        assert!(effective_duration_from_curve(&bond, &curve, z, settlement, 0.0).is_err());
+    }
+
     #[test]
     fn test_z_spread_at_par() {
         let curve = create_flat_curve(0.05);
@@ -610,6 +859,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_z_spread_depends_on_compounding() {
+        use convex_core::types::Compounding;
+
+        let curve = create_flat_curve(0.05);
+        let continuous = ZSpreadCalculator::new(&curve);
+        let semiannual = ZSpreadCalculator::new(&curve).with_compounding(Compounding::SemiAnnual);
+
+        let bond = MockBond::new(date(2029, 1, 15), dec!(0.05));
+        let settlement = date(2024, 1, 17);
+        let price = Decimal::from_f64_retain(continuous.price_with_spread(&bond, 0.03, settlement))
+            .unwrap();
+
+        let z_cont = continuous.calculate(&bond, price, settlement).unwrap();
+        let z_semi = semiannual.calculate(&bond, price, settlement).unwrap();
+
+        // 300bp continuous is 2 × (e^0.015 − 1) ≈ 302.3bp semi-annual.
+        assert_eq!(z_cont.as_bps(), dec!(300));
+        assert_eq!(z_semi.as_bps(), dec!(302));
+
+        for (calc, z) in [(&continuous, z_cont), (&semiannual, z_semi)] {
+            let z = z.as_bps().to_f64().unwrap() / 10_000.0;
+            let repriced = calc.price_with_spread(&bond, z, settlement);
+            assert!(
+                (repriced - price.to_f64().unwrap()).abs() < 0.02,
+                "round-trip drift: {repriced} vs {price}"
+            );
+        }
+    }
+
     #[test]
     fn test_spread_dv01() {
         let curve = create_flat_curve(0.05);
@@ -625,6 +904,72 @@ mod tests {
         assert!(dv01 > Decimal::ZERO, "DV01 should be positive");
     }
 
+    #[test]
+    fn test_partial_spread_durations_sum_to_parallel_duration() {
+        let curve = create_flat_curve(0.05);
+        let bond = MockBond::new(date(2033, 7, 15), dec!(0.05));
+        let settlement = date(2024, 1, 17);
+        let flows = bond.cash_flows(settlement);
+        let tenors = [
+            Tenor::M3,
+            Tenor::M6,
+            Tenor::Y1,
+            Tenor::Y2,
+            Tenor::Y3,
+            Tenor::Y5,
+            Tenor::Y7,
+            Tenor::Y10,
+            Tenor::Y20,
+            Tenor::Y30,
+        ];
+        let z = 0.01;
+
+        let buckets = partial_spread_durations(&flows, &curve, z, settlement, &tenors).unwrap();
+        assert_eq!(
+            buckets.iter().map(|(t, _)| *t).collect::<Vec<_>>(),
+            tenors.to_vec()
+        );
+
+        // On a flat 5% continuous curve a parallel continuous spread shift
+        // has duration Σ t·PV / Σ PV, with t from settlement.
+        let (weighted, pv) = flows
+            .iter()
+            .filter(|cf| cf.date > settlement)
+            .map(|cf| {
+                let t = settlement.days_between(&cf.date) as f64 / 365.0;
+                let pv = cf.amount.to_f64().unwrap() * (-(0.05 + z) * t).exp();
+                (t * pv, pv)
+            })
+            .fold((0.0, 0.0), |(a, b), (c, d)| (a + c, b + d));
+        let parallel = weighted / pv;
+        let sum: f64 = buckets.iter().map(|(_, d)| d).sum();
+        assert!(
+            (sum - parallel).abs() < 1e-4,
+            "sum {sum} vs parallel {parallel}"
+        );
+
+        // A 9.5Y bullet carries most of its spread risk in the 10Y bucket and
+        // none beyond maturity.
+        let at = |t: Tenor| buckets.iter().find(|(b, _)| *b == t).unwrap().1;
+        assert!(at(Tenor::Y10) > at(Tenor::Y7));
+        assert_eq!(at(Tenor::Y20), 0.0);
+        assert_eq!(at(Tenor::Y30), 0.0);
+    }
+
+    #[test]
+    fn test_partial_spread_durations_rejects_bad_tenors() {
+        let curve = create_flat_curve(0.05);
+        let bond = MockBond::new(date(2029, 1, 15), dec!(0.05));
+        let settlement = date(2024, 1, 17);
+        let flows = bond.cash_flows(settlement);
+
+        assert!(partial_spread_durations(&flows, &curve, 0.0, settlement, &[]).is_err());
+        assert!(
+            partial_spread_durations(&flows, &curve, 0.0, settlement, &[Tenor::Y5, Tenor::Y2])
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_settlement_after_maturity() {
         let curve = create_flat_curve(0.05);
@@ -16,6 +16,11 @@
 //! | Z-Spread        | 444.7 bps | +/-1.0 bps       |
 //! | Mod Duration    | 4.209     | +/-0.001         |
 //! | Convexity       | 0.219     | +/-0.001         |
+//!
+//! Open gap: at 110.503, street convention (`Σ CF / (1 + y/2)^(n + 46/180)`
+//! on 30/360) solves to 5.141769%, 23.6 bps above Bloomberg's YTM. No first
+//! period or day count treatment closes it, so the YTM (and the spreads and
+//! risk derived from it) do not yet validate against this reference.
 
 use super::invoice::SettlementInvoice;
 use crate::error::{AnalyticsError, AnalyticsResult};
@@ -34,19 +39,22 @@ use convex_core::types::{Date, Spread, SpreadType};
 use convex_curves::curves::ZeroCurve;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 /// Complete YAS result matching Bloomberg YAS output.
 ///
 /// This struct provides all metrics that would be displayed on a
 /// Bloomberg YAS screen, using proper typed values for spreads and risk metrics.
-#[derive(Debug, Clone)]
+/// It serializes as a flat [`YASRecord`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(into = "YASRecord")]
 pub struct YASResult {
     // ===== Yield Metrics =====
     /// Street convention yield-to-maturity (standard market quote)
     pub ytm: Decimal,
 
-    /// True yield (accounts for actual settlement)
+    /// True yield: discounts to the business-day-adjusted payment dates
     pub true_yield: Decimal,
 
     /// Current yield (annual coupon / clean price)
@@ -171,6 +179,120 @@ impl YASResult {
     }
 }
 
+impl YASResult {
+    /// Flat record of the result with explicit units, for analytics stores.
+    #[must_use]
+    pub fn to_record(&self) -> YASRecord {
+        let risk = self.risk.to_record();
+        YASRecord {
+            settlement_date: self.invoice.settlement_date,
+            ytm_pct: self.ytm,
+            true_yield_pct: self.true_yield,
+            current_yield_pct: self.current_yield,
+            simple_yield_pct: self.simple_yield,
+            money_market_yield_pct: self.money_market_yield,
+            g_spread_bps: self.g_spread.as_bps(),
+            z_spread_bps: self.z_spread.as_bps(),
+            benchmark_spread_bps: self.benchmark_spread.as_bps(),
+            benchmark_tenor: self.benchmark_tenor.clone(),
+            asw_spread_bps: self.asw_spread.map(|s| s.as_bps()),
+            oas_bps: self.oas.map(|s| s.as_bps()),
+            macaulay_duration_years: risk.macaulay_duration_years,
+            modified_duration_years: risk.modified_duration_years,
+            convexity_years2: risk.convexity_years2,
+            dv01_per_100: risk.dv01_per_100,
+            dv01: risk.dv01,
+            clean_price_pct: self.invoice.clean_price,
+            accrued_interest_pct: self.invoice.accrued_interest,
+            dirty_price_pct: self.invoice.dirty_price,
+            accrued_days: self.invoice.accrued_days,
+            face_value: self.invoice.face_value,
+            settlement_amount: self.invoice.settlement_amount,
+        }
+    }
+}
+
+/// Flat, stable schema for [`YASResult`]: one column per metric, spreads in
+/// basis points, yields and prices in percent of par.
+///
+/// Decimal values serialize as strings so nothing is lost to float
+/// conversion on the way to storage or CSV.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YASRecord {
+    /// Settlement date.
+    pub settlement_date: NaiveDate,
+    /// Street convention yield, percent.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub ytm_pct: Decimal,
+    /// True yield, percent.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub true_yield_pct: Decimal,
+    /// Current yield, percent.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub current_yield_pct: Decimal,
+    /// Simple yield, percent.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub simple_yield_pct: Decimal,
+    /// Money market equivalent yield, percent.
+    #[serde(with = "rust_decimal::serde::str_option")]
+    pub money_market_yield_pct: Option<Decimal>,
+    /// G-spread, basis points.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub g_spread_bps: Decimal,
+    /// Z-spread, basis points.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub z_spread_bps: Decimal,
+    /// Benchmark spread, basis points.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub benchmark_spread_bps: Decimal,
+    /// Benchmark tenor of the benchmark spread (e.g., "5Y").
+    pub benchmark_tenor: String,
+    /// Par-par asset swap spread, basis points.
+    #[serde(with = "rust_decimal::serde::str_option")]
+    pub asw_spread_bps: Option<Decimal>,
+    /// Option-adjusted spread, basis points.
+    #[serde(with = "rust_decimal::serde::str_option")]
+    pub oas_bps: Option<Decimal>,
+    /// Macaulay duration, years.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub macaulay_duration_years: Decimal,
+    /// Modified duration, years.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub modified_duration_years: Decimal,
+    /// Convexity, years squared.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub convexity_years2: Decimal,
+    /// DV01 per 100 face, price points per basis point.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub dv01_per_100: Decimal,
+    /// DV01 of the position, currency units per basis point.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub dv01: Decimal,
+    /// Clean price, percent of par.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub clean_price_pct: Decimal,
+    /// Accrued interest, percent of par.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub accrued_interest_pct: Decimal,
+    /// Dirty price, percent of par.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub dirty_price_pct: Decimal,
+    /// Days of accrued interest.
+    pub accrued_days: i32,
+    /// Face value of the position, currency units.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub face_value: Decimal,
+    /// Total settlement amount, currency units.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub settlement_amount: Decimal,
+}
+
+impl From<YASResult> for YASRecord {
+    fn from(result: YASResult) -> Self {
+        result.to_record()
+    }
+}
+
 impl std::fmt::Display for YASResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(
@@ -281,6 +403,8 @@ impl std::fmt::Display for YASResult {
 /// Bloomberg reference values for validation.
 #[derive(Debug, Clone)]
 pub struct BloombergReference {
+    /// Clean price the reference values are quoted at
+    pub clean_price: Decimal,
     /// Expected YTM
     pub ytm: Decimal,
     /// YTM tolerance (e.g., 0.0001 for +/-0.0001%)
@@ -308,6 +432,7 @@ impl BloombergReference {
     #[must_use]
     pub fn boeing_2025() -> Self {
         Self {
+            clean_price: dec!(110.503),
             ytm: dec!(4.905895),
             ytm_tolerance: dec!(0.0001),
             g_spread_bps: dec!(448.5),
@@ -549,13 +674,18 @@ impl<'a> YASCalculator<'a> {
             .parse::<f64>()
             .map_err(|_| AnalyticsError::InvalidInput("invalid dirty price".to_string()))?;
 
+        // Street convention discounts to the nominal (unadjusted) coupon dates;
+        // true yield to the business-day-adjusted dates the cash is paid on.
         let mut times: Vec<f64> = Vec::new();
+        let mut payment_times: Vec<f64> = Vec::new();
         let mut cf_values: Vec<f64> = Vec::new();
 
         for cf in &cash_flows {
-            let t = settlement_date.days_between(&cf.date) as f64 / 365.25;
+            let nominal_date = cf.accrual_end.unwrap_or(cf.date);
+            let t = settlement_date.days_between(&nominal_date) as f64 / 365.25;
             if t > 0.0 {
                 times.push(t);
+                payment_times.push(settlement_date.days_between(&cf.date) as f64 / 365.25);
                 cf_values.push(cf.amount.to_string().parse::<f64>().unwrap_or(0.0));
             }
         }
@@ -563,6 +693,13 @@ impl<'a> YASCalculator<'a> {
         let ytm_decimal =
             street_convention_yield(dirty_price_f64, &cf_values, &times, bond_frequency, 0.05)?;
         let ytm = ytm_decimal * Decimal::ONE_HUNDRED;
+        let true_yield = street_convention_yield(
+            dirty_price_f64,
+            &cf_values,
+            &payment_times,
+            bond_frequency,
+            ytm_decimal.to_f64().unwrap_or(0.05),
+        )? * Decimal::ONE_HUNDRED;
 
         use convex_bonds::traits::CashFlowType;
 
@@ -679,7 +816,7 @@ impl<'a> YASCalculator<'a> {
 
         Ok(YASResult {
             ytm,
-            true_yield: ytm,
+            true_yield,
             current_yield: current,
             simple_yield: simple,
             money_market_yield: mmy_result,
@@ -977,6 +1114,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_yas_result_serializes_flat() {
+        let curve = create_test_curve();
+        let bond = create_test_bond();
+        let settlement = NaiveDate::from_ymd_opt(2020, 4, 29).unwrap();
+        let yas = YASCalculator::new(&curve)
+            .analyze(&bond, settlement, dec!(110.503))
+            .unwrap();
+
+        let json = serde_json::to_value(&yas).unwrap();
+        let fields = json.as_object().unwrap();
+        assert!(fields
+            .values()
+            .all(|v| !v.is_object() && !v.is_array() && !v.is_f64()));
+        assert_eq!(json["g_spread_bps"], yas.g_spread.as_bps().to_string());
+        assert_eq!(json["settlement_date"], "2020-04-29");
+
+        let record: YASRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(record, yas.to_record());
+    }
+
     #[test]
     fn test_yas_result_display() {
         let curve = create_test_curve();
@@ -1000,13 +1158,51 @@ mod tests {
     fn test_bloomberg_reference() {
         let reference = BloombergReference::boeing_2025();
 
+        assert_eq!(reference.clean_price, dec!(110.503));
         assert_eq!(reference.ytm, dec!(4.905895));
+        assert_eq!(reference.ytm_tolerance, dec!(0.0001));
         assert_eq!(reference.g_spread_bps, dec!(448.5));
         assert_eq!(reference.z_spread_bps, dec!(444.7));
         assert_eq!(reference.modified_duration, dec!(4.209));
         assert_eq!(reference.convexity, dec!(0.219));
     }
 
+    #[test]
+    fn test_bloomberg_reference_ytm_gap() {
+        use crate::yields::{StandardYieldEngine, YieldEngine};
+        use convex_bonds::types::YieldCalculationRules;
+
+        let reference = BloombergReference::boeing_2025();
+        let bond = create_test_bond();
+        let settlement = date(2020, 4, 29);
+        // Street convention discounts to the nominal coupon dates.
+        let flows: Vec<_> = bond
+            .cash_flows(settlement)
+            .into_iter()
+            .map(|mut cf| {
+                cf.date = cf.accrual_end.unwrap_or(cf.date);
+                cf
+            })
+            .collect();
+
+        let ytm = StandardYieldEngine::new()
+            .yield_from_price(
+                &flows,
+                reference.clean_price,
+                bond.accrued_interest(settlement),
+                settlement,
+                &YieldCalculationRules::us_corporate(),
+            )
+            .unwrap()
+            .yield_decimal()
+            * dec!(100);
+        // Open gap (see module docs): street convention misses Bloomberg's
+        // YTM at the reference price by 23.6 bps. Update the reference check
+        // here once the gap is closed.
+        assert!((ytm - dec!(5.141769)).abs() <= reference.ytm_tolerance);
+        assert!((ytm - reference.ytm - dec!(0.235874)).abs() <= reference.ytm_tolerance);
+    }
+
     #[test]
     fn test_validation_failure_display() {
         let failure = ValidationFailure {
@@ -1053,6 +1249,33 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_bimonthly_bond() {
+        use convex_core::types::{Frequency, MonthStep};
+
+        let curve = create_test_curve();
+        let bond = convex_bonds::FixedRateBond::builder()
+            .cusip_unchecked("TEST00006")
+            .face_value(dec!(100))
+            .coupon_rate(dec!(0.06))
+            .maturity(date(2025, 6, 15))
+            .issue_date(date(2019, 6, 15))
+            .day_count(convex_core::daycounts::DayCountConvention::Thirty360US)
+            .frequency(Frequency::CustomMonths(MonthStep::new(2).unwrap()))
+            .build()
+            .unwrap();
+        let settlement = NaiveDate::from_ymd_opt(2020, 4, 29).unwrap();
+
+        let result = YASCalculator::new(&curve)
+            .analyze(&bond, settlement, dec!(102))
+            .unwrap();
+
+        // Street yield and risk both compound six times a year.
+        let ytm = result.ytm.to_f64().unwrap() / 100.0;
+        let expected = result.risk.macaulay_duration.as_f64() / (1.0 + ytm / 6.0);
+        assert!((result.risk.modified_duration.as_f64() - expected).abs() < 1e-12);
+    }
+
     #[test]
     fn test_invoice_calculation() {
         let curve = create_test_curve();
@@ -1095,4 +1318,44 @@ mod tests {
         );
         assert_eq!(asw.spread_type(), SpreadType::AssetSwapPar);
     }
+
+    #[test]
+    fn test_true_yield_discounts_to_adjusted_payment_dates() {
+        let curve = create_test_curve();
+        let calculator = YASCalculator::new(&curve);
+        let settlement = NaiveDate::from_ymd_opt(2020, 4, 29).unwrap();
+
+        // 15 Jun 2025 is a Sunday: maturity is paid on Monday 16 Jun. Street
+        // convention still discounts it to the 15th.
+        let bond = create_test_bond();
+        let flows = bond.cash_flows(settlement.into());
+        let last = flows.last().unwrap();
+        assert_eq!(last.accrual_end, Some(date(2025, 6, 15)));
+        assert_eq!(last.date, date(2025, 6, 16));
+
+        let result = calculator
+            .analyze(&bond, settlement, dec!(110.503))
+            .unwrap();
+        // Paying later is worth less, so the same price implies a lower
+        // yield; a few shifted days over five years is a fraction of a bp.
+        let gap_bps = (result.ytm - result.true_yield) * dec!(100);
+        assert!(gap_bps > dec!(0.1));
+        assert!(gap_bps < dec!(0.5));
+
+        // With every coupon on a business day the two coincide.
+        let weekday_bond = convex_bonds::FixedRateBond::builder()
+            .cusip_unchecked("TEST00002")
+            .face_value(dec!(100))
+            .coupon_rate(dec!(0.05))
+            .maturity(date(2023, 7, 13))
+            .issue_date(date(2020, 1, 13))
+            .day_count(convex_core::daycounts::DayCountConvention::Thirty360US)
+            .frequency(convex_core::types::Frequency::SemiAnnual)
+            .build()
+            .unwrap();
+        let result = calculator
+            .analyze(&weekday_bond, settlement, dec!(101))
+            .unwrap();
+        assert!((result.true_yield - result.ytm).abs() < dec!(1e-10));
+    }
 }
@@ -38,15 +38,17 @@
 mod analysis;
 mod calculator;
 mod invoice;
+mod rounding;
 
 pub use analysis::{YasAnalysis, YasAnalysisBuilder};
 pub use calculator::{
-    BatchYASCalculator, BloombergReference, ValidationFailure, YASCalculator, YASResult,
+    BatchYASCalculator, BloombergReference, ValidationFailure, YASCalculator, YASRecord, YASResult,
 };
 pub use invoice::{
     calculate_accrued_amount, calculate_proceeds, calculate_settlement_date, SettlementInvoice,
     SettlementInvoiceBuilder,
 };
+pub use rounding::{MarketRounding, RoundedYAS};
 
 #[cfg(test)]
 mod tests {
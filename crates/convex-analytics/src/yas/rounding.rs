@@ -0,0 +1,176 @@
+//! Market rounding of YAS output.
+//!
+//! [`YASResult`] carries full-precision figures. Markets report them rounded:
+//! US Treasuries quote prices in 32nds, gilt yields are published to six
+//! decimals. [`MarketRounding`] captures those rules, normally looked up from
+//! the convention registry, and [`YASResult::rounded`] applies them to the
+//! reported values without touching the underlying calculation.
+
+use convex_bonds::conventions::{ConventionKey, ConventionRegistry};
+use convex_bonds::types::{PriceQuoteConvention, RoundingConvention};
+use rust_decimal::Decimal;
+
+use super::calculator::YASResult;
+
+/// Price and yield rounding rules for a market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MarketRounding {
+    /// How prices are quoted (decimal, 32nds, ...).
+    pub price_quote: PriceQuoteConvention,
+    /// How yields are rounded.
+    pub yield_rounding: RoundingConvention,
+}
+
+impl MarketRounding {
+    /// Creates rounding rules from a price quote and yield rounding convention.
+    #[must_use]
+    pub const fn new(
+        price_quote: PriceQuoteConvention,
+        yield_rounding: RoundingConvention,
+    ) -> Self {
+        Self {
+            price_quote,
+            yield_rounding,
+        }
+    }
+
+    /// Looks up the rounding rules registered for a market.
+    ///
+    /// Returns `None` if the global registry has no conventions for the key.
+    /// A market with conventions but no yield rules rounds no yields.
+    #[must_use]
+    pub fn from_registry(key: &ConventionKey) -> Option<Self> {
+        let registry = ConventionRegistry::global();
+        let conventions = registry.get(key)?;
+        let yield_rounding = registry
+            .rules(key)
+            .map(|rules| rules.rounding)
+            .unwrap_or_default();
+        Some(Self::new(conventions.price_quote(), yield_rounding))
+    }
+}
+
+/// YAS figures as the market reports them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundedYAS {
+    /// Clean price rounded to the market's price tick.
+    pub clean_price: Decimal,
+    /// Clean price in the market's quote format (e.g. `"99-16"`).
+    pub clean_price_display: String,
+    /// Accrued interest per 100 face (unrounded).
+    pub accrued_interest: Decimal,
+    /// Rounded clean price plus accrued interest.
+    pub dirty_price: Decimal,
+    /// Street convention yield (%).
+    pub ytm: Decimal,
+    /// True yield (%).
+    pub true_yield: Decimal,
+    /// Current yield (%).
+    pub current_yield: Decimal,
+    /// Simple yield (%).
+    pub simple_yield: Decimal,
+    /// Money market equivalent yield (%).
+    pub money_market_yield: Option<Decimal>,
+}
+
+impl YASResult {
+    /// Returns the price and yield figures rounded per `rounding`.
+    #[must_use]
+    pub fn rounded(&self, rounding: &MarketRounding) -> RoundedYAS {
+        let clean_price = rounding.price_quote.round_price(self.invoice.clean_price);
+        let accrued_interest = self.invoice.accrued_interest;
+        let round_yield = |y: Decimal| rounding.yield_rounding.round_percent(y);
+        RoundedYAS {
+            clean_price,
+            clean_price_display: rounding.price_quote.format_price(self.invoice.clean_price),
+            accrued_interest,
+            dirty_price: clean_price + accrued_interest,
+            ytm: round_yield(self.ytm),
+            true_yield: round_yield(self.true_yield),
+            current_yield: round_yield(self.current_yield),
+            simple_yield: round_yield(self.simple_yield),
+            money_market_yield: self.money_market_yield.map(round_yield),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yas::YASCalculator;
+    use chrono::NaiveDate;
+    use convex_bonds::FixedRateBond;
+    use convex_core::daycounts::DayCountConvention;
+    use convex_core::types::{Date, Frequency};
+    use convex_curves::curves::{ZeroCurve, ZeroCurveBuilder};
+    use convex_curves::InterpolationMethod;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn curve() -> ZeroCurve {
+        ZeroCurveBuilder::new()
+            .reference_date(date(2025, 1, 15))
+            .add_rate(date(2026, 1, 15), dec!(0.04))
+            .add_rate(date(2030, 1, 15), dec!(0.042))
+            .add_rate(date(2035, 1, 15), dec!(0.045))
+            .interpolation(InterpolationMethod::Linear)
+            .build()
+            .unwrap()
+    }
+
+    fn analyze(clean_price: Decimal) -> YASResult {
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("TEST00001")
+            .face_value(dec!(100))
+            .coupon_rate(dec!(0.04))
+            .maturity(date(2030, 2, 15))
+            .issue_date(date(2020, 2, 15))
+            .day_count(DayCountConvention::ActActIcma)
+            .frequency(Frequency::SemiAnnual)
+            .build()
+            .unwrap();
+        let curve = curve();
+        YASCalculator::new(&curve)
+            .analyze(
+                &bond,
+                NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+                clean_price,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_us_treasury_price_in_32nds() {
+        let rounding = MarketRounding::from_registry(&ConventionKey::us_treasury()).unwrap();
+        assert_eq!(rounding.price_quote, PriceQuoteConvention::ThirtySeconds);
+
+        let yas = analyze(dec!(99.51));
+        let rounded = yas.rounded(&rounding);
+
+        assert_eq!(rounded.clean_price_display, "99-16");
+        assert_eq!(rounded.clean_price, dec!(99.5));
+        assert_eq!(
+            rounded.dirty_price,
+            dec!(99.5) + yas.invoice.accrued_interest
+        );
+        // The calculation itself stays at the input price.
+        assert_eq!(yas.invoice.clean_price, dec!(99.51));
+    }
+
+    #[test]
+    fn test_gilt_yield_six_decimals() {
+        let rounding = MarketRounding::from_registry(&ConventionKey::uk_gilt()).unwrap();
+        assert_eq!(rounding.yield_rounding, RoundingConvention::SixDecimals);
+
+        let yas = analyze(dec!(98.765));
+        let rounded = yas.rounded(&rounding);
+
+        assert!(yas.ytm.scale() > 6);
+        assert!(rounded.ytm.scale() <= 6);
+        assert!((rounded.ytm - yas.ytm).abs() <= dec!(0.0000005));
+        assert_eq!(rounded.clean_price_display, "98.765");
+    }
+}
@@ -32,6 +32,7 @@ use convex_core::types::Date;
 use convex_math::solvers::{brent, newton_raphson, SolverConfig};
 
 use crate::error::{AnalyticsError, AnalyticsResult};
+use crate::yields::simple::jgb_simple_yield_to;
 
 /// Result of a yield calculation.
 #[derive(Debug, Clone, Copy)]
@@ -77,7 +78,9 @@ pub trait YieldEngine: Send + Sync {
     ///
     /// # Returns
     ///
-    /// The yield that makes PV(cash flows) = dirty price.
+    /// The yield that makes PV(cash flows) = dirty price. Simple-yield
+    /// rules are rejected, since the cash flows do not carry the coupon
+    /// rate; see [`StandardYieldEngine::simple_yield`].
     fn yield_from_price(
         &self,
         cash_flows: &[BondCashFlow],
@@ -177,6 +180,36 @@ impl StandardYieldEngine {
         self
     }
 
+    /// Japanese simple yield of a bond paying `coupon_rate` on `face_value`,
+    /// quoted at `clean_price` per 100 face, through the same formula as
+    /// [`jgb_simple_yield`](crate::yields::jgb_simple_yield).
+    ///
+    /// The annual coupon is the rate times the face rather than a cash flow
+    /// amount, so a stub period or a non-100 face does not skew it. Closed
+    /// form, so there is nothing to iterate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face value or clean price is not positive,
+    /// or if `maturity` is not after `settlement`.
+    pub fn simple_yield(
+        &self,
+        coupon_rate: Decimal,
+        face_value: Decimal,
+        clean_price: Decimal,
+        settlement: Date,
+        maturity: Date,
+    ) -> AnalyticsResult<YieldEngineResult> {
+        let simple =
+            jgb_simple_yield_to(coupon_rate, face_value, clean_price, settlement, maturity)?;
+        Ok(YieldEngineResult {
+            yield_value: (simple / Decimal::ONE_HUNDRED).to_f64().unwrap_or(0.0),
+            iterations: 0,
+            residual: 0.0,
+            convention: YieldConvention::SimpleYield,
+        })
+    }
+
     /// Prepares cash flow data for calculation.
     fn prepare_cash_flows(
         &self,
@@ -222,17 +255,18 @@ impl StandardYieldEngine {
         }
     }
 
-    /// Calculates present value at a given yield using the specified compounding.
+    /// Calculates present value at a given yield under the rules' convention.
     fn pv_at_yield(
         &self,
         cf_data: &[(f64, f64)],
         yield_rate: f64,
         rules: &YieldCalculationRules,
     ) -> f64 {
+        let final_time = cf_data.last().map_or(0.0, |(years, _)| *years);
         cf_data
             .iter()
             .map(|(years, amount)| {
-                let df = rules.compounding.discount_factor(yield_rate, *years);
+                let df = rules.discount_factor(yield_rate, *years, final_time);
                 amount * df
             })
             .sum()
@@ -245,12 +279,11 @@ impl StandardYieldEngine {
         yield_rate: f64,
         rules: &YieldCalculationRules,
     ) -> f64 {
+        let final_time = cf_data.last().map_or(0.0, |(years, _)| *years);
         cf_data
             .iter()
             .map(|(years, amount)| {
-                let ddf_dy = rules
-                    .compounding
-                    .discount_factor_derivative(yield_rate, *years);
+                let ddf_dy = rules.discount_factor_derivative(yield_rate, *years, final_time);
                 amount * ddf_dy
             })
             .sum()
@@ -315,6 +348,12 @@ impl YieldEngine for StandardYieldEngine {
             ));
         }
 
+        if rules.convention == YieldConvention::SimpleYield {
+            return Err(AnalyticsError::InvalidInput(
+                "simple yield needs the coupon rate and face value; use simple_yield".to_string(),
+            ));
+        }
+
         let initial_guess = self.estimate_initial_yield(&cf_data, target, rules);
 
         // Objective function: PV(yield) - target = 0
@@ -502,6 +541,7 @@ pub fn bond_equivalent_yield_simple(discount_yield: f64, days_to_maturity: i64)
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
+    use convex_bonds::types::FirstPeriodDiscounting;
     use rust_decimal_macros::dec;
 
     fn date(y: i32, m: u32, d: u32) -> Date {
@@ -728,6 +768,72 @@ mod tests {
         assert!(result.iterations < 50);
     }
 
+    #[test]
+    fn test_german_yield_conventions_ordering() {
+        let settlement = date(2025, 1, 15);
+        let maturity = date(2030, 6, 15);
+        let engine = StandardYieldEngine::new();
+        let german_yield = |convention, frequency| {
+            let cash_flows =
+                create_coupon_cash_flows(settlement, maturity, dec!(0.03), dec!(100), frequency);
+            let mut rules = YieldCalculationRules::from_convention(convention);
+            rules.frequency = frequency;
+            engine
+                .yield_from_price(&cash_flows, dec!(97), dec!(0), settlement, &rules)
+                .unwrap()
+                .yield_value
+        };
+
+        // Annual coupons: both German methods discount the ~5-month broken
+        // period simply, which costs more than ISMA's (1 + y)^f, so the same
+        // price gives a yield a fraction of a basis point lower. They agree
+        // with each other.
+        let annual = convex_core::types::Frequency::Annual;
+        let isma = german_yield(YieldConvention::ISMA, annual);
+        let moosmuller = german_yield(YieldConvention::Moosmuller, annual);
+        let braess = german_yield(YieldConvention::BraessFangmeyer, annual);
+        assert_relative_eq!(moosmuller, braess, epsilon = 1e-7);
+        assert!(moosmuller < isma);
+        assert!(isma - moosmuller < 1e-4);
+
+        // Semi-annual coupons: Moosmüller compounds at y/2, Braess-Fangmeyer
+        // annually with simple interest within the year, so it quotes higher.
+        let semi = convex_core::types::Frequency::SemiAnnual;
+        let moosmuller = german_yield(YieldConvention::Moosmuller, semi);
+        let braess = german_yield(YieldConvention::BraessFangmeyer, semi);
+        assert!(braess > moosmuller);
+        assert!(braess - moosmuller < 1e-3);
+    }
+
+    #[test]
+    fn test_first_period_discounting_from_rules() {
+        // Boeing 7.5% 15 Jun 2025 at 110.503 for 29 Apr 2020 settlement:
+        // 134 of 180 days accrued, 46 to the next coupon.
+        let settlement = date(2020, 4, 29);
+        let cash_flows = create_coupon_cash_flows(
+            settlement,
+            date(2025, 6, 15),
+            dec!(0.075),
+            dec!(100),
+            convex_core::types::Frequency::SemiAnnual,
+        );
+        let accrued = dec!(3.75) * dec!(134) / dec!(180);
+        let engine = StandardYieldEngine::new();
+        let street = YieldCalculationRules::us_corporate();
+        let simple = street
+            .clone()
+            .with_first_period_discounting(FirstPeriodDiscounting::Simple);
+
+        let solve = |rules: &YieldCalculationRules| {
+            engine
+                .yield_from_price(&cash_flows, dec!(110.503), accrued, settlement, rules)
+                .unwrap()
+                .yield_value
+        };
+        assert_relative_eq!(solve(&street), 0.05141769, epsilon = 1e-8);
+        assert_relative_eq!(solve(&simple), 0.05140297, epsilon = 1e-8);
+    }
+
     #[test]
     fn test_current_yield() {
         let cy = current_yield_simple(5.0, 100.0);
@@ -755,4 +861,56 @@ mod tests {
         assert!(dy > 0.0);
         assert!(dy < 0.05);
     }
+
+    #[test]
+    fn test_japanese_jgb_simple_yield() {
+        let settlement = date(2024, 3, 21);
+        let maturity = date(2029, 3, 20);
+        let engine = StandardYieldEngine::new();
+
+        // Same bond through jgb_simple_yield: both share one formula.
+        let bond = convex_bonds::FixedRateBond::builder()
+            .cusip_unchecked("JP1103541")
+            .coupon_percent(0.1)
+            .maturity(maturity)
+            .issue_date(date(2019, 3, 20))
+            .with_conventions(&convex_bonds::conventions::jgb())
+            .build()
+            .unwrap();
+        let expected = crate::yields::jgb_simple_yield(&bond, settlement, dec!(99.80)).unwrap();
+
+        let result = engine
+            .simple_yield(dec!(0.001), dec!(100), dec!(99.80), settlement, maturity)
+            .unwrap();
+        assert_eq!(result.convention, YieldConvention::SimpleYield);
+        assert_relative_eq!(
+            result.yield_value,
+            (expected / dec!(100)).to_f64().unwrap(),
+            epsilon = 1e-15
+        );
+
+        // The coupon is rate × face, so the face does not move the yield.
+        let large_face = engine
+            .simple_yield(dec!(0.001), dec!(10000), dec!(99.80), settlement, maturity)
+            .unwrap();
+        assert_relative_eq!(large_face.yield_value, result.yield_value, epsilon = 1e-15);
+
+        // Cash flows cannot supply the coupon rate, so the rules are rejected.
+        let cash_flows = create_coupon_cash_flows(
+            settlement,
+            maturity,
+            dec!(0.001),
+            dec!(100),
+            convex_core::types::Frequency::SemiAnnual,
+        );
+        assert!(engine
+            .yield_from_price(
+                &cash_flows,
+                dec!(99.80),
+                dec!(0.0003),
+                settlement,
+                &YieldCalculationRules::japanese_jgb(),
+            )
+            .is_err());
+    }
 }
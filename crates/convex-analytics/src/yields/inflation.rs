@@ -0,0 +1,243 @@
+//! Inflation-linked bond yields and breakeven inflation.
+//!
+//! A linker is modelled as any [`Bond`] whose cash flows are in real
+//! (unadjusted) terms, plus an index-ratio function `IR(date)` giving the
+//! reference index ratio for each date. The ratio at settlement is the
+//! inflation uplift applied to the real price to get the invoice price;
+//! ratios at later dates project the nominal flows.
+
+use rust_decimal::prelude::*;
+
+use convex_bonds::traits::{Bond, BondAnalytics, BondCashFlow};
+use convex_core::types::{Date, Frequency};
+
+use super::solver::YieldSolver;
+use crate::error::{AnalyticsError, AnalyticsResult};
+
+/// Real and nominal yields of an inflation-linked bond.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinkerYield {
+    /// Real yield: solved from real cash flows against the real price.
+    pub real_yield: f64,
+    /// Nominal yield: solved from projected (inflation-adjusted) cash flows
+    /// against the invoice price.
+    pub nominal_yield: f64,
+    /// Reference index ratio at settlement.
+    pub settlement_index_ratio: f64,
+}
+
+/// Solves the real yield of an inflation-linked bond.
+///
+/// Each real cash flow is uplifted by `index_ratio(date)`; the real clean
+/// price and accrued are uplifted by `index_ratio(settlement)`. The real
+/// yield discounts flows uplifted only by the settlement ratio (so the
+/// uplift cancels), while the nominal yield uses the projected ratio at each
+/// payment date. With zero projected inflation the two coincide.
+///
+/// # Arguments
+///
+/// * `linker` - Bond with real (unadjusted) cash flows
+/// * `settlement` - Settlement date
+/// * `real_clean_price` - Real clean price per 100 face
+/// * `index_ratio` - Reference index ratio for a date
+/// * `frequency` - Compounding frequency
+pub fn real_yield<F>(
+    linker: &dyn Bond,
+    settlement: Date,
+    real_clean_price: Decimal,
+    index_ratio: F,
+    frequency: Frequency,
+) -> AnalyticsResult<LinkerYield>
+where
+    F: Fn(Date) -> f64,
+{
+    let settlement_ratio = index_ratio(settlement);
+    if !settlement_ratio.is_finite() || settlement_ratio <= 0.0 {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "index ratio at settlement must be positive, got {settlement_ratio}"
+        )));
+    }
+
+    let cash_flows = linker.cash_flows(settlement);
+    if cash_flows.is_empty() {
+        return Err(AnalyticsError::CashFlowGenerationFailed(
+            "no future cash flows".to_string(),
+        ));
+    }
+
+    let uplift =
+        |amount: Decimal, ratio: f64| amount * Decimal::from_f64(ratio).unwrap_or(Decimal::ONE);
+    let settlement_flows: Vec<BondCashFlow> = cash_flows
+        .iter()
+        .map(|cf| with_amount(cf, uplift(cf.amount, settlement_ratio)))
+        .collect();
+    let projected_flows: Vec<BondCashFlow> = cash_flows
+        .iter()
+        .map(|cf| with_amount(cf, uplift(cf.amount, index_ratio(cf.date))))
+        .collect();
+
+    let invoice_clean = uplift(real_clean_price, settlement_ratio);
+    let invoice_accrued = uplift(linker.accrued_interest(settlement), settlement_ratio);
+    let day_count = linker.parse_day_count()?;
+    let solver = YieldSolver::new();
+
+    let real = solver.solve(
+        &settlement_flows,
+        invoice_clean,
+        invoice_accrued,
+        settlement,
+        day_count,
+        frequency,
+    )?;
+    let nominal = solver.solve(
+        &projected_flows,
+        invoice_clean,
+        invoice_accrued,
+        settlement,
+        day_count,
+        frequency,
+    )?;
+
+    Ok(LinkerYield {
+        real_yield: real.yield_value,
+        nominal_yield: nominal.yield_value,
+        settlement_index_ratio: settlement_ratio,
+    })
+}
+
+/// Breakeven inflation: nominal bond yield minus the linker's real yield.
+///
+/// With `convexity_adjusted`, the Fisher cross-term is removed per period,
+/// `f · ((1 + n/f) / (1 + r/f) − 1)`, instead of the simple difference `n − r`.
+/// Seasonality is assumed to be reflected in `index_ratio` already.
+pub fn breakeven_inflation<F>(
+    linker: &dyn Bond,
+    settlement: Date,
+    real_clean_price: Decimal,
+    index_ratio: F,
+    nominal_bond_yield: f64,
+    frequency: Frequency,
+    convexity_adjusted: bool,
+) -> AnalyticsResult<f64>
+where
+    F: Fn(Date) -> f64,
+{
+    let real = real_yield(linker, settlement, real_clean_price, index_ratio, frequency)?;
+    if !convexity_adjusted {
+        return Ok(nominal_bond_yield - real.real_yield);
+    }
+
+    let f = f64::from(frequency.periods_per_year());
+    Ok(f * ((1.0 + nominal_bond_yield / f) / (1.0 + real.real_yield / f) - 1.0))
+}
+
+fn with_amount(cf: &BondCashFlow, amount: Decimal) -> BondCashFlow {
+    let mut flow = cf.clone();
+    flow.amount = amount;
+    flow
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use convex_bonds::instruments::FixedRateBond;
+    use convex_core::daycounts::DayCountConvention;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn create_linker() -> FixedRateBond {
+        FixedRateBond::builder()
+            .issue_date(date(2024, 1, 15))
+            .maturity(date(2034, 1, 15))
+            .coupon_rate(dec!(0.0175))
+            .face_value(dec!(100))
+            .frequency(Frequency::SemiAnnual)
+            .day_count(DayCountConvention::ActActIcma)
+            .cusip_unchecked("91282CJY8")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_zero_inflation_real_equals_nominal() {
+        let linker = create_linker();
+        let settlement = date(2025, 3, 10);
+
+        // Index has risen 8% since issue but no further inflation is assumed.
+        let result = real_yield(
+            &linker,
+            settlement,
+            dec!(97),
+            |_| 1.08,
+            Frequency::SemiAnnual,
+        )
+        .unwrap();
+        assert!((result.real_yield - result.nominal_yield).abs() < 1e-10);
+        assert!((result.settlement_index_ratio - 1.08).abs() < 1e-12);
+
+        // The settlement uplift cancels: same yield as the real flows alone.
+        let plain = linker
+            .yield_to_maturity(settlement, dec!(97), Frequency::SemiAnnual)
+            .unwrap();
+        assert!((result.real_yield - plain.yield_value).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_projected_inflation_raises_nominal_yield() {
+        let linker = create_linker();
+        let settlement = date(2025, 3, 10);
+        let inflation: f64 = 0.025;
+        let ratio = |d: Date| {
+            let years = settlement.days_between(&d) as f64 / 365.25;
+            1.08 * (1.0 + inflation).powf(years)
+        };
+
+        let result =
+            real_yield(&linker, settlement, dec!(97), ratio, Frequency::SemiAnnual).unwrap();
+        let implied = result.nominal_yield - result.real_yield;
+        assert!((implied - inflation).abs() < 0.002, "implied {implied}");
+    }
+
+    #[test]
+    fn test_breakeven_inflation() {
+        let linker = create_linker();
+        let settlement = date(2025, 3, 10);
+        let real = real_yield(
+            &linker,
+            settlement,
+            dec!(97),
+            |_| 1.08,
+            Frequency::SemiAnnual,
+        )
+        .unwrap()
+        .real_yield;
+
+        let simple = breakeven_inflation(
+            &linker,
+            settlement,
+            dec!(97),
+            |_| 1.08,
+            0.045,
+            Frequency::SemiAnnual,
+            false,
+        )
+        .unwrap();
+        assert!((simple - (0.045 - real)).abs() < 1e-12);
+
+        let adjusted = breakeven_inflation(
+            &linker,
+            settlement,
+            dec!(97),
+            |_| 1.08,
+            0.045,
+            Frequency::SemiAnnual,
+            true,
+        )
+        .unwrap();
+        assert!(adjusted < simple);
+        assert!(adjusted > 0.0);
+    }
+}
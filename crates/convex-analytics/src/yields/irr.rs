@@ -0,0 +1,239 @@
+//! Internal rate of return and flat-rate present value for dated cash flows.
+//!
+//! These work on plain `(date, amount)` vectors, so no bond needs to be
+//! constructed. Both use annual compounding: [`xirr`] on an ACT/365 basis
+//! (spreadsheet `XIRR`), [`flat_pv`] on a caller-supplied day count.
+
+use rust_decimal::prelude::*;
+
+use convex_core::daycounts::DayCountConvention;
+use convex_core::types::Date;
+use convex_math::solvers::{brent, hybrid, newton_raphson, SolverConfig};
+
+use crate::error::{AnalyticsError, AnalyticsResult};
+
+/// Lowest rate considered by the XIRR bracket search (just above -100%).
+const XIRR_MIN_RATE: f64 = -0.9999;
+
+/// Highest rate considered by the XIRR bracket search.
+const XIRR_MAX_RATE: f64 = 1.0e4;
+
+/// Extended internal rate of return of irregularly dated cash flows.
+///
+/// Finds `r` such that `Σ cfᵢ / (1 + r)^((dᵢ - d₀) / 365) = 0`, where `d₀` is
+/// the earliest cash flow date. Matches the spreadsheet `XIRR` function.
+///
+/// Newton's method runs from `guess` first, so when the flows change sign
+/// more than once and have several rates, the one nearest the guess is
+/// returned. If Newton fails, the rate is bracketed between -100% and
+/// 1,000,000% and solved there.
+///
+/// # Arguments
+///
+/// * `cash_flows` - Dated amounts; negative for outflows, in any order
+/// * `guess` - Starting rate for the Newton iteration (e.g. 0.1)
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` if the cash flows are all of one
+/// sign, and `AnalyticsError::SolverConvergenceFailed` if no rate between
+/// -100% and 1,000,000% solves the equation.
+pub fn xirr(cash_flows: &[(Date, Decimal)], guess: f64) -> AnalyticsResult<f64> {
+    let has_inflow = cash_flows
+        .iter()
+        .any(|(_, cf)| cf.is_sign_positive() && !cf.is_zero());
+    let has_outflow = cash_flows
+        .iter()
+        .any(|(_, cf)| cf.is_sign_negative() && !cf.is_zero());
+    if !has_inflow || !has_outflow {
+        return Err(AnalyticsError::InvalidInput(
+            "XIRR requires at least one positive and one negative cash flow".to_string(),
+        ));
+    }
+
+    let start = cash_flows
+        .iter()
+        .map(|(date, _)| *date)
+        .min()
+        .expect("non-empty after sign check");
+    let flows: Vec<(f64, f64)> = cash_flows
+        .iter()
+        .map(|(date, cf)| {
+            (
+                start.days_between(date) as f64 / 365.0,
+                cf.to_f64().unwrap_or(0.0),
+            )
+        })
+        .collect();
+
+    let npv = |r: f64| -> f64 { flows.iter().map(|(t, cf)| cf * (1.0 + r).powf(-t)).sum() };
+    let dnpv = |r: f64| -> f64 {
+        flows
+            .iter()
+            .map(|(t, cf)| -t * cf * (1.0 + r).powf(-t - 1.0))
+            .sum()
+    };
+
+    let config = SolverConfig::new(1e-10, 100);
+    if guess.is_finite() && guess > XIRR_MIN_RATE {
+        if let Ok(result) = newton_raphson(npv, dnpv, guess, &config) {
+            if (XIRR_MIN_RATE..=XIRR_MAX_RATE).contains(&result.root) {
+                return Ok(result.root);
+            }
+        }
+    }
+
+    let (a, b) =
+        find_rate_bracket(&npv).ok_or_else(|| AnalyticsError::SolverConvergenceFailed {
+            solver: "XIRR".to_string(),
+            iterations: 0,
+            residual: npv(guess.max(XIRR_MIN_RATE)),
+        })?;
+
+    let start_guess = if guess.is_finite() {
+        guess.clamp(a, b)
+    } else {
+        0.5 * (a + b)
+    };
+    let result = match hybrid(npv, dnpv, start_guess, Some((a, b)), &config) {
+        Ok(result) if (a..=b).contains(&result.root) => result,
+        _ => brent(npv, a, b, &config).map_err(|e| AnalyticsError::MathError(e.to_string()))?,
+    };
+    Ok(result.root)
+}
+
+/// Present value of dated cash flows at a flat, annually compounded rate.
+///
+/// Each flow after `settlement` is discounted by `(1 + rate)^-t`, with `t`
+/// the year fraction from settlement under `day_count`. Flows on or before
+/// settlement are ignored.
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` if `rate` is not above -100%.
+pub fn flat_pv(
+    cash_flows: &[(Date, Decimal)],
+    rate: f64,
+    day_count: DayCountConvention,
+    settlement: Date,
+) -> AnalyticsResult<f64> {
+    if !rate.is_finite() || rate <= -1.0 {
+        return Err(AnalyticsError::InvalidInput(format!(
+            "flat rate must be above -100%, got {rate}"
+        )));
+    }
+
+    let dc = day_count.to_day_count();
+    Ok(cash_flows
+        .iter()
+        .filter(|(date, _)| *date > settlement)
+        .map(|(date, cf)| {
+            let t = dc.year_fraction(settlement, *date).to_f64().unwrap_or(0.0);
+            cf.to_f64().unwrap_or(0.0) * (1.0 + rate).powf(-t)
+        })
+        .sum())
+}
+
+/// Finds a rate interval over which `npv` changes sign, widening the upper
+/// bound geometrically from 100%.
+fn find_rate_bracket<F>(npv: &F) -> Option<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    let mut lower = XIRR_MIN_RATE;
+    let mut f_lower = npv(lower);
+    let mut upper = 0.0;
+    while upper <= XIRR_MAX_RATE {
+        let f_upper = npv(upper);
+        if f_lower.is_finite() && f_upper.is_finite() && f_lower * f_upper <= 0.0 {
+            return Some((lower, upper));
+        }
+        lower = upper;
+        f_lower = f_upper;
+        upper = if upper == 0.0 { 1.0 } else { upper * 4.0 };
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    /// The worked example from the spreadsheet `XIRR` documentation.
+    fn spreadsheet_flows() -> Vec<(Date, Decimal)> {
+        vec![
+            (date(2008, 1, 1), dec!(-10000)),
+            (date(2008, 3, 1), dec!(2750)),
+            (date(2008, 10, 30), dec!(4250)),
+            (date(2009, 2, 15), dec!(3250)),
+            (date(2009, 4, 1), dec!(2750)),
+        ]
+    }
+
+    #[test]
+    fn test_xirr_matches_spreadsheet() {
+        let rate = xirr(&spreadsheet_flows(), 0.1).unwrap();
+        assert!((rate - 0.373_362_535).abs() < 1e-6, "XIRR {rate}");
+
+        // Same answer from a poor starting guess.
+        let rate_far = xirr(&spreadsheet_flows(), 5.0).unwrap();
+        assert!((rate_far - rate).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_xirr_guess_selects_among_multiple_rates() {
+        // NPV is -100 + 230v - 132v², zero at 10% and at 20%; it is
+        // negative at every other rate, so no sign change brackets either.
+        let flows = vec![
+            (date(2021, 1, 1), dec!(-100)),
+            (date(2022, 1, 1), dec!(230)),
+            (date(2023, 1, 1), dec!(-132)),
+        ];
+        let low = xirr(&flows, 0.05).unwrap();
+        assert!((low - 0.10).abs() < 1e-9, "XIRR {low}");
+
+        let high = xirr(&flows, 0.3).unwrap();
+        assert!((high - 0.20).abs() < 1e-9, "XIRR {high}");
+    }
+
+    #[test]
+    fn test_flat_pv_at_xirr_is_zero() {
+        let flows = spreadsheet_flows();
+        let rate = xirr(&flows, 0.1).unwrap();
+
+        // Settling the day before the first flow puts every flow in the PV.
+        let settlement = date(2007, 12, 31);
+        let pv = flat_pv(&flows, rate, DayCountConvention::Act365Fixed, settlement).unwrap();
+        assert!(pv.abs() < 1e-6, "PV at IRR {pv}");
+
+        // Flows on or before settlement are excluded.
+        let later = flat_pv(
+            &flows,
+            0.0,
+            DayCountConvention::Act365Fixed,
+            date(2008, 10, 30),
+        )
+        .unwrap();
+        assert!((later - 6000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_xirr_rejects_one_sided_flows() {
+        let flows = vec![(date(2024, 1, 1), dec!(100)), (date(2025, 1, 1), dec!(5))];
+        let err = xirr(&flows, 0.1).unwrap_err();
+        assert!(err.to_string().contains("positive and one negative"));
+
+        assert!(flat_pv(
+            &flows,
+            -1.0,
+            DayCountConvention::Act365Fixed,
+            date(2024, 1, 1)
+        )
+        .is_err());
+    }
+}
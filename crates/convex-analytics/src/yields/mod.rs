@@ -20,6 +20,12 @@
 //! - **True Yield**: Adjusts for actual settlement mechanics and reinvestment
 //!   assumptions that differ from street convention.
 //!
+//! - **Real Yield / Breakeven**: Real yield of inflation-linked bonds off
+//!   index-ratio-adjusted cash flows, and breakeven inflation versus a nominal bond.
+//!
+//! - **IRR / XIRR**: Internal rate of return and flat-rate present value of
+//!   arbitrary dated cash flows, without constructing a bond.
+//!
 //! - **Money Market Yields**: Discount yield, bond equivalent yield (BEY),
 //!   CD equivalent yield, and money market equivalent yield (MMY).
 //!
@@ -56,6 +62,8 @@
 
 mod current;
 mod engine;
+mod inflation;
+mod irr;
 mod money_market;
 mod short_date;
 mod simple;
@@ -68,11 +76,13 @@ pub use engine::{
     bond_equivalent_yield_simple, current_yield_simple, discount_yield_simple, simple_yield_f64,
     StandardYieldEngine, YieldEngine, YieldEngineResult,
 };
+pub use inflation::{breakeven_inflation, real_yield, LinkerYield};
+pub use irr::{flat_pv, xirr};
 pub use money_market::{
-    bond_equivalent_yield, cd_equivalent_yield, discount_yield, money_market_yield,
-    money_market_yield_with_horizon,
+    bill_discount_yield, bill_investment_yield, bill_yield, bond_equivalent_yield,
+    cd_equivalent_yield, discount_yield, money_market_yield, money_market_yield_with_horizon,
 };
 pub use short_date::{RollForwardMethod, ShortDateCalculator};
-pub use simple::simple_yield;
+pub use simple::{jgb_simple_yield, simple_yield};
 pub use solver::{current_yield_decimal, current_yield_from_fixed_bond, YieldResult, YieldSolver};
 pub use street::street_convention_yield;
@@ -3,10 +3,12 @@
 //! Provides:
 //! - T-Bill discount yield
 //! - T-Bill bond equivalent yield
+//! - T-Bill discount and investment yields from dates, by `YieldConvention`
 //! - CD equivalent yield
 //! - Money Market Equivalent Yield for coupon bonds
 
 use convex_bonds::traits::BondCashFlow;
+use convex_bonds::types::YieldConvention;
 use convex_core::types::Date;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
@@ -301,23 +303,156 @@ pub fn bond_equivalent_yield(
         let bey = discount / price * (dec!(365) / days) * dec!(100);
         Ok(bey)
     } else {
-        // Complex formula for longer instruments
-        let d = days_to_maturity as f64;
-        let p = price.to_f64().unwrap_or(100.0);
-        let f = face_value.to_f64().unwrap_or(100.0);
-
-        let term = d / 365.0;
-        let price_factor = 1.0 - f / p;
-        let discriminant = term * term + (2.0 * term - 1.0) * price_factor;
-
-        if discriminant < 0.0 {
-            return Err(AnalyticsError::CalculationFailed(
-                "negative discriminant in BEY calculation".to_string(),
-            ));
+        semi_annual_equivalent(price, face_value, days_to_maturity, 365)
+    }
+}
+
+/// Semi-annual bond-equivalent yield (percent) of a discount instrument
+/// held past a half-year, on a `year_days` basis.
+///
+/// Compares it with a bond paying a coupon at six months: the yield `y`
+/// solves `(1 + y/2)(1 + y(t − ½)) = Face/Price` with `t = days/year_days`.
+fn semi_annual_equivalent(
+    price: Decimal,
+    face_value: Decimal,
+    days_to_maturity: u32,
+    year_days: u32,
+) -> AnalyticsResult<Decimal> {
+    let p = price.to_f64().unwrap_or(100.0);
+    let f = face_value.to_f64().unwrap_or(100.0);
+
+    let term = f64::from(days_to_maturity) / f64::from(year_days);
+    let price_factor = 1.0 - f / p;
+    let discriminant = term * term - (2.0 * term - 1.0) * price_factor;
+
+    if discriminant < 0.0 {
+        return Err(AnalyticsError::CalculationFailed(
+            "negative discriminant in BEY calculation".to_string(),
+        ));
+    }
+
+    let bey = (-2.0 * term + 2.0 * discriminant.sqrt()) / (2.0 * term - 1.0);
+    Ok(Decimal::from_f64_retain(bey * 100.0).unwrap_or(Decimal::ZERO))
+}
+
+// ============================================================================
+// Treasury Bill Yields
+// ============================================================================
+
+/// T-bill discount yield (percent): bank-discount basis, ACT/360.
+///
+/// ```text
+/// Discount Yield = (Face - Price) / Face × (360 / Days) × 100
+/// ```
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` if maturity is not after
+/// settlement or face value is not positive.
+pub fn bill_discount_yield(
+    price: Decimal,
+    face_value: Decimal,
+    settlement: Date,
+    maturity: Date,
+) -> AnalyticsResult<Decimal> {
+    discount_yield(price, face_value, bill_days(settlement, maturity)?)
+}
+
+/// T-bill investment yield (percent), the bond-equivalent (coupon-equivalent)
+/// rate US Treasury auctions publish.
+///
+/// The year basis `y` is 366 when the year after settlement includes a
+/// 29 February, 365 otherwise. Up to a half-year,
+///
+/// ```text
+/// Investment Yield = (Face - Price) / Price × (y / Days) × 100
+/// ```
+///
+/// and beyond, the semi-annual equivalent solving
+/// `(1 + i/2)(1 + i(Days/y − ½)) = Face/Price`.
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` if maturity is not after
+/// settlement or price is not positive.
+pub fn bill_investment_yield(
+    price: Decimal,
+    face_value: Decimal,
+    settlement: Date,
+    maturity: Date,
+) -> AnalyticsResult<Decimal> {
+    let days = bill_days(settlement, maturity)?;
+    if price <= Decimal::ZERO {
+        return Err(AnalyticsError::InvalidInput(
+            "price must be positive".to_string(),
+        ));
+    }
+
+    let year_days = bill_year_days(settlement);
+    if 2 * days <= year_days {
+        let discount = face_value - price;
+        Ok(discount / price * (Decimal::from(year_days) / Decimal::from(days)) * dec!(100))
+    } else {
+        semi_annual_equivalent(price, face_value, days, year_days)
+    }
+}
+
+/// T-bill yield (percent) quoted under `convention`.
+///
+/// - `DiscountYield`: [`bill_discount_yield`] (ACT/360 on face)
+/// - `BondEquivalentYield` / `StreetConvention`: [`bill_investment_yield`]
+///   (ACT/365 or 366 on price, semi-annual past a half-year)
+/// - `SimpleYield`: money-market yield, [`cd_equivalent_yield`] (ACT/360 on
+///   price)
+///
+/// # Errors
+///
+/// Returns `AnalyticsError::InvalidInput` for conventions with no bill
+/// quote, or if the dates or price are invalid.
+pub fn bill_yield(
+    price: Decimal,
+    face_value: Decimal,
+    settlement: Date,
+    maturity: Date,
+    convention: YieldConvention,
+) -> AnalyticsResult<Decimal> {
+    match convention {
+        YieldConvention::DiscountYield => {
+            bill_discount_yield(price, face_value, settlement, maturity)
+        }
+        YieldConvention::BondEquivalentYield | YieldConvention::StreetConvention => {
+            bill_investment_yield(price, face_value, settlement, maturity)
+        }
+        YieldConvention::SimpleYield => {
+            cd_equivalent_yield(price, face_value, bill_days(settlement, maturity)?)
         }
+        other => Err(AnalyticsError::InvalidInput(format!(
+            "no T-bill yield under {other:?}"
+        ))),
+    }
+}
 
-        let bey = (-2.0 * term + 2.0 * discriminant.sqrt()) / (2.0 * term - 1.0);
-        Ok(Decimal::from_f64_retain(bey * 100.0).unwrap_or(Decimal::ZERO))
+/// Actual days from settlement to maturity, which must be positive.
+fn bill_days(settlement: Date, maturity: Date) -> AnalyticsResult<u32> {
+    let days = settlement.days_between(&maturity);
+    if days <= 0 {
+        return Err(AnalyticsError::InvalidInput(
+            "maturity must be after settlement".to_string(),
+        ));
+    }
+    Ok(days as u32)
+}
+
+/// 366 if a 29 February falls in the year after `settlement`, else 365.
+fn bill_year_days(settlement: Date) -> u32 {
+    let year_later = settlement.add_months(12).unwrap_or(settlement);
+    let spans_leap_day = (settlement.year()..=year_later.year())
+        .filter_map(|y| Date::from_ymd(y, 2, 29).ok())
+        .any(|leap_day| leap_day > settlement && leap_day <= year_later);
+    if spans_leap_day {
+        366
+    } else {
+        365
     }
 }
 
@@ -541,6 +676,71 @@ mod tests {
         assert!(discount_yield(dec!(98.5), dec!(0), 90).is_err());
     }
 
+    #[test]
+    fn test_bond_equivalent_yield_long() {
+        // 270 days at 97: the semi-annual equivalent reprices the bill.
+        let bey = bond_equivalent_yield(dec!(97), dec!(100), 270)
+            .unwrap()
+            .to_f64()
+            .unwrap()
+            / 100.0;
+        let t = 270.0 / 365.0;
+        assert_relative_eq!((1.0 + bey / 2.0) * (1.0 + bey * (t - 0.5)), 100.0 / 97.0);
+        assert!(bey > 0.0);
+    }
+
+    #[test]
+    fn test_treasury_bill_discount_and_investment_yields() {
+        // Bills priced from their discount rate the way Treasury auction
+        // results are (price to six decimals); expected yields follow the
+        // published discount and investment rate formulas, to three decimals
+        // as announced.
+        let rounded = |y: Decimal| y.round_dp(3);
+
+        // 13-week bill, 91 days; the following year includes 29 Feb 2024.
+        let (settle, maturity) = (date(2024, 1, 4), date(2024, 4, 4));
+        let price = dec!(98.674181);
+        let discount = bill_discount_yield(price, dec!(100), settle, maturity).unwrap();
+        assert_eq!(rounded(discount), dec!(5.245));
+        let investment = bill_investment_yield(price, dec!(100), settle, maturity).unwrap();
+        assert_eq!(rounded(investment), dec!(5.404));
+
+        // 52-week bill, 364 days spanning 29 Feb 2024: semi-annual
+        // equivalent on a 366-day year.
+        let (settle, maturity) = (date(2023, 3, 23), date(2024, 3, 21));
+        let price = dec!(95.429778);
+        let discount = bill_discount_yield(price, dec!(100), settle, maturity).unwrap();
+        assert_eq!(rounded(discount), dec!(4.520));
+        let investment = bill_investment_yield(price, dec!(100), settle, maturity).unwrap();
+        assert_eq!(rounded(investment), dec!(4.759));
+
+        // The convention picks the basis.
+        assert_eq!(
+            bill_yield(
+                price,
+                dec!(100),
+                settle,
+                maturity,
+                YieldConvention::DiscountYield
+            )
+            .unwrap(),
+            discount
+        );
+        assert_eq!(
+            bill_yield(
+                price,
+                dec!(100),
+                settle,
+                maturity,
+                YieldConvention::BondEquivalentYield
+            )
+            .unwrap(),
+            investment
+        );
+        assert!(bill_yield(price, dec!(100), settle, maturity, YieldConvention::ISMA).is_err());
+        assert!(bill_discount_yield(price, dec!(100), maturity, settle).is_err());
+    }
+
     #[test]
     fn test_bey_invalid() {
         assert!(bond_equivalent_yield(dec!(0), dec!(100), 90).is_err());
@@ -1,9 +1,15 @@
 //! Simple yield calculation.
 //!
 //! Simple yield adds the annualized capital gain/loss to the current yield.
+//! It is the quoted yield for Japanese government bonds, see
+//! [`jgb_simple_yield`].
 
 use rust_decimal::Decimal;
 
+use convex_bonds::traits::FixedCouponBond;
+use convex_core::daycounts::DayCountConvention;
+use convex_core::types::Date;
+
 use crate::error::{AnalyticsError, AnalyticsResult};
 
 /// Calculate simple yield.
@@ -49,6 +55,70 @@ pub fn simple_yield(
     Ok(simple)
 }
 
+/// JGB simple yield of a fixed coupon bond.
+///
+/// ```text
+/// Simple Yield = (Coupon + (100 - Price) / Years) / Price
+/// ```
+///
+/// with the coupon and clean price per 100 face and the remaining years
+/// counted ACT/365 (fixed) from settlement to maturity, as JGBs are quoted.
+/// No compounding.
+///
+/// # Arguments
+///
+/// * `bond` - A bond implementing the `FixedCouponBond` trait
+/// * `settlement` - Settlement date
+/// * `clean_price` - Clean price per 100 face
+///
+/// # Returns
+///
+/// Simple yield as percentage
+pub fn jgb_simple_yield(
+    bond: &dyn FixedCouponBond,
+    settlement: Date,
+    clean_price: Decimal,
+) -> AnalyticsResult<Decimal> {
+    let maturity = bond
+        .maturity()
+        .ok_or_else(|| AnalyticsError::InvalidInput("bond has no maturity (perpetual)".into()))?;
+    jgb_simple_yield_to(
+        bond.coupon_rate(),
+        bond.face_value(),
+        clean_price,
+        settlement,
+        maturity,
+    )
+}
+
+/// JGB simple yield of `coupon_rate` paid on `face_value`, with the clean
+/// price per 100 face and the remaining years counted ACT/365 (fixed) from
+/// `settlement` to `maturity`.
+///
+/// Shared by [`jgb_simple_yield`] and the yield engine's Japanese rules.
+pub(crate) fn jgb_simple_yield_to(
+    coupon_rate: Decimal,
+    face_value: Decimal,
+    clean_price: Decimal,
+    settlement: Date,
+    maturity: Date,
+) -> AnalyticsResult<Decimal> {
+    if face_value <= Decimal::ZERO {
+        return Err(AnalyticsError::InvalidInput(
+            "face value must be positive".to_string(),
+        ));
+    }
+    let years = DayCountConvention::Act365Fixed
+        .to_day_count()
+        .year_fraction(settlement, maturity);
+    simple_yield(
+        coupon_rate * face_value,
+        clean_price * face_value / Decimal::ONE_HUNDRED,
+        face_value,
+        years,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,4 +179,28 @@ mod tests {
         let result = simple_yield(dec!(5.0), dec!(100.0), dec!(100.0), dec!(0.0));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_jgb_simple_yield() {
+        use convex_bonds::conventions::jgb;
+        use convex_bonds::FixedRateBond;
+
+        // 0.1% JGB maturing 20 Mar 2029, bought at 99.80 for settlement
+        // 21 Mar 2024: 1,825 days is exactly 5 years on ACT/365, so
+        // (0.1 + 0.20 / 5) / 99.80 = 0.140281%.
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("JP1103541")
+            .coupon_percent(0.1)
+            .maturity(Date::from_ymd(2029, 3, 20).unwrap())
+            .issue_date(Date::from_ymd(2019, 3, 20).unwrap())
+            .with_conventions(&jgb())
+            .build()
+            .unwrap();
+
+        let sy =
+            jgb_simple_yield(&bond, Date::from_ymd(2024, 3, 21).unwrap(), dec!(99.80)).unwrap();
+
+        assert_eq!(sy, dec!(0.14) / dec!(99.80) * dec!(100));
+        assert_eq!(sy.round_dp(6), dec!(0.140281));
+    }
 }
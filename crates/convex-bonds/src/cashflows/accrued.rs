@@ -29,6 +29,7 @@ use rust_decimal::Decimal;
 use convex_core::daycounts::DayCountConvention;
 use convex_core::types::{Date, Frequency};
 
+use crate::cashflows::IrregularPeriodHandler;
 use crate::types::CalendarId;
 
 /// Calculator for accrued interest.
@@ -128,10 +129,42 @@ impl AccruedInterestCalculator {
             return Decimal::ZERO;
         }
 
-        let cal = calendar.to_calendar();
-
         // Calculate ex-dividend date (ex_div_days business days before next coupon)
-        let ex_div_date = cal.add_business_days(next_coupon, -(ex_div_days as i32));
+        let ex_div_date = calendar
+            .to_calendar()
+            .add_business_days(next_coupon, -(ex_div_days as i32));
+
+        Self::ex_dividend_from_date(
+            settlement,
+            last_coupon,
+            next_coupon,
+            coupon_rate,
+            face_value,
+            day_count,
+            frequency,
+            ex_div_date,
+        )
+    }
+
+    /// Calculates accrued interest given the ex-dividend date directly.
+    ///
+    /// Same as [`Self::ex_dividend`] for windows that are not counted in
+    /// business days, such as calendar-day windows.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn ex_dividend_from_date(
+        settlement: Date,
+        last_coupon: Date,
+        next_coupon: Date,
+        coupon_rate: Decimal,
+        face_value: Decimal,
+        day_count: DayCountConvention,
+        frequency: Frequency,
+        ex_div_date: Date,
+    ) -> Decimal {
+        if frequency.is_zero() {
+            return Decimal::ZERO;
+        }
 
         if settlement >= ex_div_date {
             // Ex-dividend: buyer doesn't receive next coupon
@@ -218,6 +251,77 @@ impl AccruedInterestCalculator {
         period_coupon * Decimal::from(actual_days) / Decimal::from(ref_days)
     }
 
+    /// Calculates accrued interest from the bond's accrual schedule.
+    ///
+    /// `coupon_dates` are the unadjusted accrual period boundaries in
+    /// ascending order, starting with the dated date and ending with
+    /// maturity. Regular periods use [`Self::standard`]. A first or last
+    /// period that [`IrregularPeriodHandler`] flags as irregular is a stub and
+    /// accrues ICMA-style against notional regular periods: the first stub
+    /// against periods counted back from its end, the last against periods
+    /// counted forward from its start. ACT/360 and ACT/365 accrue on the year
+    /// fraction whatever the period length.
+    ///
+    /// Returns zero if settlement falls outside the schedule.
+    #[must_use]
+    pub fn from_schedule(
+        coupon_dates: &[Date],
+        settlement: Date,
+        coupon_rate: Decimal,
+        face_value: Decimal,
+        day_count: DayCountConvention,
+        frequency: Frequency,
+    ) -> Decimal {
+        match SchedulePeriod::containing(coupon_dates, settlement, frequency) {
+            Some(period) => {
+                period.accrued(settlement, coupon_rate, face_value, day_count, frequency)
+            }
+            None => Decimal::ZERO,
+        }
+    }
+
+    /// [`Self::from_schedule`] with an ex-dividend period.
+    ///
+    /// `ex_div_date` maps a coupon date to the first date that coupon trades
+    /// ex-dividend. Settlement on or after it gives negative accrued: minus
+    /// the interest from settlement to the coupon date, which the buyer does
+    /// not receive.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_schedule_ex_dividend(
+        coupon_dates: &[Date],
+        settlement: Date,
+        coupon_rate: Decimal,
+        face_value: Decimal,
+        day_count: DayCountConvention,
+        frequency: Frequency,
+        ex_div_date: impl Fn(Date) -> Date,
+    ) -> Decimal {
+        let Some(period) = SchedulePeriod::containing(coupon_dates, settlement, frequency) else {
+            return Decimal::ZERO;
+        };
+        let ex_div_date = ex_div_date(period.end);
+        if period.stub.is_none() {
+            return Self::ex_dividend_from_date(
+                settlement,
+                period.start,
+                period.end,
+                coupon_rate,
+                face_value,
+                day_count,
+                frequency,
+                ex_div_date,
+            );
+        }
+        let accrued =
+            |date| period.accrued(date, coupon_rate, face_value, day_count, frequency);
+        if settlement >= ex_div_date {
+            accrued(settlement) - accrued(period.end)
+        } else {
+            accrued(settlement)
+        }
+    }
+
     /// Calculates accrued interest using year fraction.
     ///
     /// This method uses the day count's year fraction directly,
@@ -249,6 +353,123 @@ impl AccruedInterestCalculator {
     }
 }
 
+/// Which end of the schedule a stub sits at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stub {
+    Front,
+    Back,
+}
+
+/// The accrual period containing a settlement date.
+#[derive(Debug, Clone, Copy)]
+struct SchedulePeriod {
+    start: Date,
+    end: Date,
+    stub: Option<Stub>,
+}
+
+impl SchedulePeriod {
+    /// The period with `start <= settlement < end`, flagged as a stub if it
+    /// is an irregular first or last period.
+    fn containing(coupon_dates: &[Date], settlement: Date, frequency: Frequency) -> Option<Self> {
+        if frequency.is_zero() {
+            return None;
+        }
+        let last = coupon_dates.len().checked_sub(2)?;
+        let (index, window) = coupon_dates
+            .windows(2)
+            .enumerate()
+            .find(|(_, w)| w[0] <= settlement && settlement < w[1])?;
+        let (start, end) = (window[0], window[1]);
+        let stub = if index == 0 && IrregularPeriodHandler::is_first_irregular(start, end, frequency)
+        {
+            Some(Stub::Front)
+        } else if index == last && IrregularPeriodHandler::is_last_irregular(start, end, frequency)
+        {
+            Some(Stub::Back)
+        } else {
+            None
+        };
+        Some(Self { start, end, stub })
+    }
+
+    fn accrued(
+        &self,
+        settlement: Date,
+        coupon_rate: Decimal,
+        face_value: Decimal,
+        day_count: DayCountConvention,
+        frequency: Frequency,
+    ) -> Decimal {
+        let year_fraction_based = matches!(
+            day_count,
+            DayCountConvention::Act360
+                | DayCountConvention::Act365Fixed
+                | DayCountConvention::Act365Leap
+        );
+        let Some(stub) = self.stub.filter(|_| !year_fraction_based) else {
+            return AccruedInterestCalculator::standard(
+                settlement,
+                self.start,
+                self.end,
+                coupon_rate,
+                face_value,
+                day_count,
+                frequency,
+            );
+        };
+
+        // Notional regular periods covering the stub, each accruing its share
+        // of a full coupon over the part of it that lies in [start, settlement].
+        let months = IrregularPeriodHandler::period_months(frequency) as i32;
+        let mut notional = Vec::new();
+        match stub {
+            Stub::Front => {
+                let mut period_end = self.end;
+                while period_end > self.start {
+                    let Ok(period_start) = period_end.add_months(-months) else {
+                        break;
+                    };
+                    notional.push((period_start, period_end));
+                    period_end = period_start;
+                }
+            }
+            Stub::Back => {
+                let mut period_start = self.start;
+                while period_start < self.end {
+                    let Ok(period_end) = period_start.add_months(months) else {
+                        break;
+                    };
+                    notional.push((period_start, period_end));
+                    period_start = period_end;
+                }
+            }
+        }
+
+        notional
+            .into_iter()
+            .map(|(ref_start, ref_end)| {
+                let from = ref_start.max(self.start);
+                let to = ref_end.min(self.end).min(settlement);
+                if to <= from {
+                    return Decimal::ZERO;
+                }
+                AccruedInterestCalculator::irregular_period(
+                    to,
+                    from,
+                    ref_end,
+                    ref_start,
+                    ref_end,
+                    coupon_rate,
+                    face_value,
+                    day_count,
+                    frequency,
+                )
+            })
+            .sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -388,6 +609,38 @@ mod tests {
         assert!(accrued < dec!(2.5)); // Less than full period coupon
     }
 
+    #[test]
+    fn test_schedule_end_of_month_period_is_regular() {
+        // Month-end schedule: Feb 28 to Aug 31 is a full period, not a stub,
+        // even though Feb 28 + 6M is Aug 28.
+        let dates = [
+            Date::from_ymd(2024, 8, 31).unwrap(),
+            Date::from_ymd(2025, 2, 28).unwrap(),
+            Date::from_ymd(2025, 8, 31).unwrap(),
+            Date::from_ymd(2026, 2, 28).unwrap(),
+        ];
+        let settlement = Date::from_ymd(2025, 8, 30).unwrap();
+        let accrued = AccruedInterestCalculator::from_schedule(
+            &dates,
+            settlement,
+            dec!(0.05),
+            dec!(100),
+            DayCountConvention::ActActIcma,
+            Frequency::SemiAnnual,
+        );
+        let standard = AccruedInterestCalculator::standard(
+            settlement,
+            dates[1],
+            dates[2],
+            dec!(0.05),
+            dec!(100),
+            DayCountConvention::ActActIcma,
+            Frequency::SemiAnnual,
+        );
+        assert_eq!(accrued, standard);
+        assert!(accrued < dec!(2.5));
+    }
+
     #[test]
     fn test_using_year_fraction() {
         let settlement = Date::from_ymd(2025, 4, 15).unwrap();
@@ -266,6 +266,7 @@ impl IrregularPeriodHandler {
             Frequency::Quarterly => 91,
             Frequency::Monthly => 30,
             Frequency::Zero => 0,
+            Frequency::CustomMonths(step) => 365 * step.months() as i64 / 12,
         }
     }
 
@@ -278,6 +279,7 @@ impl IrregularPeriodHandler {
             Frequency::Quarterly => 3,
             Frequency::Monthly => 1,
             Frequency::Zero => 0,
+            Frequency::CustomMonths(step) => step.months(),
         }
     }
 
@@ -28,7 +28,7 @@
 use serde::{Deserialize, Serialize};
 
 use convex_core::calendars::{
-    BusinessDayConvention, Calendar, SIFMACalendar, Target2Calendar, WeekendCalendar,
+    BusinessDayConvention, Calendar, CalendarRegistry, JointCalendar, WeekendCalendar,
 };
 use convex_core::types::{Date, Frequency};
 
@@ -357,19 +357,39 @@ impl Schedule {
 
 impl CalendarId {
     /// Converts the calendar ID to a boxed Calendar trait object.
+    ///
+    /// Unknown identifiers fall back to a weekend-only calendar; use
+    /// [`try_to_calendar`](Self::try_to_calendar) to reject them instead.
     #[must_use]
     pub fn to_calendar(&self) -> Box<dyn Calendar> {
-        match self.as_str() {
-            "US_GOVERNMENT" | "US-GOV" | "USGov" | "SIFMA" | "US" => Box::new(SIFMACalendar::new()),
-            "TARGET2" | "EUR" => Box::new(Target2Calendar::new()),
-            _ => Box::new(WeekendCalendar),
+        self.try_to_calendar()
+            .unwrap_or_else(|_| Box::new(WeekendCalendar))
+    }
+
+    /// Resolves the calendar ID to a built-in calendar or one registered in
+    /// the global [`CalendarRegistry`]. Combined IDs (`"SIFMA+TARGET2"`)
+    /// resolve to the joint calendar of their components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the identifier is neither built in nor registered.
+    pub fn try_to_calendar(&self) -> BondResult<Box<dyn Calendar>> {
+        if self.is_combined() {
+            let members = self
+                .components()
+                .map(|id| CalendarId::new(id).try_to_calendar())
+                .collect::<BondResult<Vec<_>>>()?;
+            return Ok(Box::new(JointCalendar::new(members)));
         }
+
+        Ok(Box::new(CalendarRegistry::global().get(self.as_str())?))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use convex_core::types::MonthStep;
 
     #[test]
     fn test_schedule_generation_semiannual() {
@@ -401,6 +421,21 @@ mod tests {
         assert_eq!(schedule.num_periods(), 5);
     }
 
+    #[test]
+    fn test_custom_three_month_schedule_matches_quarterly() {
+        let start = Date::from_ymd(2020, 3, 31).unwrap();
+        let end = Date::from_ymd(2023, 3, 31).unwrap();
+
+        let quarterly =
+            Schedule::generate(ScheduleConfig::new(start, end, Frequency::Quarterly)).unwrap();
+        let three_months = Frequency::CustomMonths(MonthStep::new(3).unwrap());
+        let custom = Schedule::generate(ScheduleConfig::new(start, end, three_months)).unwrap();
+
+        assert_eq!(custom.num_periods(), 12);
+        assert_eq!(custom.unadjusted_dates(), quarterly.unadjusted_dates());
+        assert_eq!(custom.dates(), quarterly.dates());
+    }
+
     #[test]
     fn test_schedule_generation_zero_coupon() {
         let config = ScheduleConfig::new(
@@ -472,4 +507,48 @@ mod tests {
         assert!(!StubType::None.is_front_stub());
         assert!(!StubType::None.is_back_stub());
     }
+
+    #[test]
+    fn test_calendar_id_resolves_registered_calendar() {
+        CalendarRegistry::global()
+            .register_json(r#"{"name": "ScheduleTestMarket", "holidays": ["2025-01-31"]}"#)
+            .unwrap();
+
+        let cal = CalendarId::new("ScheduleTestMarket")
+            .try_to_calendar()
+            .unwrap();
+        let holiday = Date::from_ymd(2025, 1, 31).unwrap();
+        assert_eq!(
+            cal.adjust(holiday, BusinessDayConvention::ModifiedFollowing)
+                .unwrap(),
+            Date::from_ymd(2025, 1, 30).unwrap()
+        );
+
+        let err = CalendarId::new("NoSuchMarket")
+            .try_to_calendar()
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("unknown calendar 'NoSuchMarket'"));
+
+        // Built-in names match case-insensitively, as in the registry.
+        let new_year = Date::from_ymd(2025, 1, 1).unwrap();
+        for id in ["Japan", "JAPAN", "jpy"] {
+            let cal = CalendarId::new(id).try_to_calendar().unwrap();
+            assert!(!cal.is_business_day(new_year), "{id}");
+        }
+    }
+
+    #[test]
+    fn test_combined_calendar_id_is_joint() {
+        let joint = CalendarId::sifma()
+            .combined_with(&CalendarId::target2())
+            .try_to_calendar()
+            .unwrap();
+
+        // TARGET2-only holiday is non-business in the combined calendar
+        assert!(!joint.is_business_day(Date::from_ymd(2025, 5, 1).unwrap()));
+        // SIFMA-only holiday too
+        assert!(!joint.is_business_day(Date::from_ymd(2025, 7, 4).unwrap()));
+        assert!(joint.is_business_day(Date::from_ymd(2025, 5, 2).unwrap()));
+    }
 }
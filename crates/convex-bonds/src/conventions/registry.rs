@@ -23,9 +23,11 @@
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
+use convex_core::types::Date;
+
 use super::market::{InstrumentType, Market};
 use super::BondConventions;
-use crate::types::YieldCalculationRules;
+use crate::types::{CalendarId, YieldCalculationRules};
 
 /// Key for convention lookup combining market and instrument type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -338,6 +340,48 @@ impl ConventionRegistry {
         YieldCalculationRules::default()
     }
 
+    /// Calculates the settlement date for a trade under the conventions for `key`.
+    ///
+    /// The holiday calendar and the business-day vs calendar-day rule come from
+    /// the registered conventions, falling back to the market's government bond
+    /// conventions when `key` is not registered. `settlement_days` overrides the
+    /// registered T+n period when provided.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use convex_bonds::conventions::{ConventionKey, ConventionRegistry};
+    /// use convex_core::types::Date;
+    ///
+    /// let registry = ConventionRegistry::global();
+    /// // Friday before MLK day: T+1 skips the weekend and the holiday
+    /// let trade = Date::from_ymd(2025, 1, 17).unwrap();
+    /// let settle = registry.settlement_date(trade, &ConventionKey::us_treasury(), None);
+    /// assert_eq!(settle, Date::from_ymd(2025, 1, 21).unwrap());
+    /// ```
+    #[must_use]
+    pub fn settlement_date(
+        &self,
+        trade_date: Date,
+        key: &ConventionKey,
+        settlement_days: Option<u32>,
+    ) -> Date {
+        let mut rules = self.rules(key).map_or_else(
+            || self.default_rules_for_market(key.market).settlement_rules,
+            |r| r.settlement_rules,
+        );
+        if let Some(days) = settlement_days {
+            rules.days = days;
+        }
+
+        let calendar = self
+            .get(key)
+            .or_else(|| self.get_by_market(key.market, InstrumentType::GovernmentBond))
+            .map_or_else(CalendarId::weekend_only, |c| c.calendar().clone());
+
+        rules.settlement_date(trade_date, calendar.to_calendar().as_ref())
+    }
+
     // =========================================================================
     // Registration methods
     // =========================================================================
@@ -746,4 +790,22 @@ mod tests {
             assert!(registry.rules(key).is_some(), "Missing rules for {:?}", key);
         }
     }
+
+    #[test]
+    fn test_settlement_date_uses_market_calendar() {
+        let registry = ConventionRegistry::global();
+
+        // Christmas Eve 2025 (Wed): gilts settle T+1 over Christmas and Boxing Day
+        let trade = Date::from_ymd(2025, 12, 24).unwrap();
+        assert_eq!(
+            registry.settlement_date(trade, &ConventionKey::uk_gilt(), None),
+            Date::from_ymd(2025, 12, 29).unwrap()
+        );
+
+        // Explicit settlement days override the registered T+n
+        assert_eq!(
+            registry.settlement_date(trade, &ConventionKey::uk_gilt(), Some(0)),
+            trade
+        );
+    }
 }
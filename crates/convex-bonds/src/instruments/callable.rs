@@ -226,33 +226,54 @@ impl CallableBond {
         clean_price: Decimal,
         settlement: Date,
     ) -> BondResult<(Decimal, Date)> {
-        let maturity = self.base.maturity().unwrap();
-        let mut workout_dates = self.all_workout_dates(settlement, maturity);
-        workout_dates.push(maturity);
-
-        let mut worst_yield = Decimal::new(99999, 2); // Start with large value
-        let mut worst_date = maturity;
-
-        for date in workout_dates {
-            let yield_result = if date == maturity {
-                self.yield_to_maturity(clean_price, settlement)
-            } else {
-                self.yield_to_call_date(clean_price, settlement, date)
-            };
-
-            if let Ok(y) = yield_result {
-                if y < worst_yield {
-                    worst_yield = y;
-                    worst_date = date;
-                }
+        let mut worst: Option<(Decimal, Date)> = None;
+        for (date, _, y) in self.yields_to_all_workouts(clean_price, settlement) {
+            if worst.is_none_or(|(worst_yield, _)| y < worst_yield) {
+                worst = Some((y, date));
             }
         }
 
-        if worst_yield > Decimal::new(999, 1) {
-            return Err(BondError::YieldConvergenceFailed { iterations: 100 });
+        worst.ok_or(BondError::YieldConvergenceFailed { iterations: 100 })
+    }
+
+    /// Calculates the yield to every workout: each call date after
+    /// settlement and maturity.
+    ///
+    /// Returns `(workout_date, workout_price, yield)` sorted by date, where
+    /// the workout price is the call price (or the redemption value at
+    /// maturity) as a percentage of par. Workouts whose yield does not
+    /// converge are left out; [`Self::yield_to_worst_with_date`] is the
+    /// minimum over this table.
+    ///
+    /// # Arguments
+    ///
+    /// * `clean_price` - Market clean price (percentage of par)
+    /// * `settlement` - Settlement date
+    #[must_use]
+    pub fn yields_to_all_workouts(
+        &self,
+        clean_price: Decimal,
+        settlement: Date,
+    ) -> Vec<(Date, Decimal, Decimal)> {
+        let maturity = self.base.maturity().unwrap();
+        let mut workouts: Vec<(Date, Decimal, Decimal)> = self
+            .all_workout_dates(settlement, maturity)
+            .into_iter()
+            .filter(|&date| date < maturity)
+            .filter_map(|date| {
+                let call_price = self.call_price_on(date)?;
+                let y = self
+                    .yield_to_call_date(clean_price, settlement, date)
+                    .ok()?;
+                Some((date, Decimal::from_f64_retain(call_price)?, y))
+            })
+            .collect();
+
+        if let Ok(y) = self.yield_to_maturity(clean_price, settlement) {
+            workouts.push((maturity, self.base.redemption_value(), y));
         }
 
-        Ok((worst_yield, worst_date))
+        workouts
     }
 
     /// Calculates the make-whole call price.
@@ -787,6 +808,52 @@ mod tests {
         assert!(ytw > Decimal::ZERO);
     }
 
+    #[test]
+    fn test_yields_to_all_workouts_min_is_yield_to_worst() {
+        let base = create_base_bond();
+        let call_schedule = CallSchedule::new(CallType::Bermudan)
+            .with_entry(CallEntry::new(date(2025, 6, 15), 102.0))
+            .with_entry(CallEntry::new(date(2027, 6, 15), 101.0))
+            .with_entry(CallEntry::new(date(2028, 6, 15), 100.0));
+        let callable = CallableBond::new(base, call_schedule);
+        let settlement = date(2024, 1, 15);
+        let price = dec!(104);
+
+        let table = callable.yields_to_all_workouts(price, settlement);
+        let dates: Vec<Date> = table.iter().map(|(d, _, _)| *d).collect();
+        assert_eq!(
+            dates,
+            vec![
+                date(2025, 6, 15),
+                date(2027, 6, 15),
+                date(2028, 6, 15),
+                date(2030, 6, 15)
+            ]
+        );
+        let prices: Vec<Decimal> = table.iter().map(|(_, p, _)| *p).collect();
+        assert_eq!(prices, vec![dec!(102), dec!(101), dec!(100), dec!(100)]);
+
+        // Each row matches the single-workout calculation.
+        assert_eq!(
+            table[1].2,
+            callable
+                .yield_to_call_date(price, settlement, date(2027, 6, 15))
+                .unwrap()
+        );
+        assert_eq!(
+            table[3].2,
+            callable.yield_to_maturity(price, settlement).unwrap()
+        );
+
+        let (min_date, _, min_yield) = table.iter().min_by(|a, b| a.2.cmp(&b.2)).copied().unwrap();
+        let (ytw, ytw_date) = callable
+            .yield_to_worst_with_date(price, settlement)
+            .unwrap();
+        assert_eq!(ytw, min_yield);
+        assert_eq!(ytw_date, min_date);
+        assert_eq!(callable.yield_to_worst(price, settlement), Some(ytw));
+    }
+
     #[test]
     fn test_workout_dates() {
         let base = create_base_bond();
@@ -12,7 +12,7 @@ use crate::cashflows::{AccruedInterestCalculator, Schedule, ScheduleConfig, Stub
 use crate::conventions::{self, BondConventions};
 use crate::error::{BondError, BondResult, IdentifierError};
 use crate::traits::{Bond, BondCashFlow, FixedCouponBond};
-use crate::types::{BondIdentifiers, BondType, CalendarId, Cusip};
+use crate::types::{BondIdentifiers, BondType, CalendarId, Cusip, DayType};
 
 /// Fixed-rate bond. Cashflow schedule is built lazily on first use.
 #[derive(Debug, Clone)]
@@ -45,6 +45,7 @@ pub struct FixedRateBond {
 
     // Ex-dividend
     ex_dividend_days: Option<u32>,
+    ex_dividend_day_type: DayType,
 
     // Classification
     bond_type: BondType,
@@ -98,6 +99,7 @@ impl FixedRateBond {
             face_value: Decimal::ONE_HUNDRED,
             redemption_value: Decimal::ONE_HUNDRED,
             ex_dividend_days: conventions.ex_dividend_days(),
+            ex_dividend_day_type: DayType::BusinessDays,
             bond_type: BondType::FixedRateCorporate,
             schedule: OnceCell::new(),
         }
@@ -150,6 +152,25 @@ impl FixedRateBond {
         self.ex_dividend_days
     }
 
+    /// Returns whether the ex-dividend window counts business or calendar days.
+    #[must_use]
+    pub fn ex_dividend_day_type(&self) -> DayType {
+        self.ex_dividend_day_type
+    }
+
+    /// First date the coupon paid on `next_coupon` trades ex-dividend, if the
+    /// bond has an ex-dividend window.
+    fn ex_dividend_date(&self, next_coupon: Date) -> Option<Date> {
+        let days = self.ex_dividend_days?;
+        Some(match self.ex_dividend_day_type {
+            DayType::BusinessDays => self
+                .calendar
+                .to_calendar()
+                .add_business_days(next_coupon, -(days as i32)),
+            DayType::CalendarDays => next_coupon.add_days(-i64::from(days)),
+        })
+    }
+
     /// Gets or generates the payment schedule.
     ///
     /// The schedule is lazily computed and cached for performance.
@@ -190,6 +211,57 @@ impl FixedRateBond {
         })
     }
 
+    /// True when `[start, end]` spans exactly one coupon period (allowing
+    /// for the end-of-month roll).
+    fn is_regular_period(&self, start: Date, end: Date) -> bool {
+        let months = self.frequency.months_per_period() as i32;
+        let rolls_to = |from: Date, by: i32, to: Date| {
+            from.add_months(by)
+                .is_ok_and(|d| d == to || (self.end_of_month && d.end_of_month() == to))
+        };
+        rolls_to(start, months, end) || rolls_to(end, -months, start)
+    }
+
+    /// Coupon for an irregular period.
+    ///
+    /// The period is split into notional regular periods rolled back from
+    /// `end` (front stub) or forward from `start` (back stub); each pays its
+    /// day-count share of the regular coupon (ICMA Rule 251, as in
+    /// QuantLib). A short stub pays less than a full coupon, a long stub one
+    /// full coupon plus the share of the extra notional period. 30/360 rules
+    /// come from the day count's own `day_count()`.
+    fn stub_coupon(&self, start: Date, end: Date, front: bool) -> Decimal {
+        let dc = self.day_count.to_day_count();
+        let months = self.frequency.months_per_period() as i32;
+        let mut fraction = Decimal::ZERO;
+        for k in 0.. {
+            let (notional_start, notional_end) = if front {
+                (
+                    end.add_months(-months * (k + 1)),
+                    end.add_months(-months * k),
+                )
+            } else {
+                (
+                    start.add_months(months * k),
+                    start.add_months(months * (k + 1)),
+                )
+            };
+            let (Ok(notional_start), Ok(notional_end)) = (notional_start, notional_end) else {
+                break;
+            };
+            let (from, to) = (notional_start.max(start), notional_end.min(end));
+            if from >= to {
+                break;
+            }
+            let nominal_days = dc.day_count(notional_start, notional_end).abs();
+            if nominal_days > 0 {
+                fraction +=
+                    Decimal::from(dc.day_count(from, to).abs()) / Decimal::from(nominal_days);
+            }
+        }
+        self.coupon_per_period() * fraction
+    }
+
     /// Finds the previous and next coupon dates for a given settlement date.
     fn coupon_dates_for_settlement(&self, settlement: Date) -> (Date, Date) {
         let schedule = self.schedule();
@@ -212,72 +284,29 @@ impl FixedRateBond {
 
     /// Calculates accrued interest at settlement.
     ///
-    /// Handles both standard accrued and ex-dividend accrued (UK Gilts).
-    /// Also handles irregular (stub) first coupon periods by prorating the coupon.
+    /// Handles both standard accrued and ex-dividend accrued (UK Gilts), and
+    /// irregular first and last periods, through the schedule-based
+    /// [`AccruedInterestCalculator::from_schedule`].
     fn calculate_accrued(&self, settlement: Date) -> Decimal {
-        if self.frequency.is_zero() {
-            return Decimal::ZERO;
-        }
-
-        let (last_coupon, next_coupon) = self.coupon_dates_for_settlement(settlement);
-
-        // Check if we're in an irregular first period (short or long first coupon)
-        // This happens when the first coupon period doesn't match the regular period length
-        let is_first_period = last_coupon == self.dated_date;
-        let is_irregular_period = if is_first_period {
-            // Calculate regular period length in days (approximately)
-            let regular_days = 365 / self.frequency.periods_per_year() as i64;
-            // Calculate actual first period length in days
-            let actual_days = self.dated_date.days_between(&next_coupon).abs();
-            // If the first period differs by more than 30 days from regular, it's irregular
-            (actual_days - regular_days).abs() > 30
-        } else {
-            false
-        };
-
-        if is_irregular_period {
-            // For irregular first period, use the irregular period calculator
-            // which prorates based on reference period length
-            let regular_months = 12 / self.frequency.periods_per_year();
-            let ref_period_start = next_coupon
-                .add_months(-(regular_months as i32))
-                .unwrap_or(next_coupon);
-            let ref_period_end = next_coupon;
-
-            AccruedInterestCalculator::irregular_period(
+        let dates = self.schedule().unadjusted_dates();
+        match self.ex_dividend_days {
+            Some(_) => AccruedInterestCalculator::from_schedule_ex_dividend(
+                dates,
                 settlement,
-                last_coupon, // period_start = dated_date
-                next_coupon, // period_end = first coupon
-                ref_period_start,
-                ref_period_end,
                 self.coupon_rate,
                 self.face_value,
                 self.day_count,
                 self.frequency,
-            )
-        } else {
-            match self.ex_dividend_days {
-                Some(ex_div_days) => AccruedInterestCalculator::ex_dividend(
-                    settlement,
-                    last_coupon,
-                    next_coupon,
-                    self.coupon_rate,
-                    self.face_value,
-                    self.day_count,
-                    self.frequency,
-                    ex_div_days,
-                    &self.calendar,
-                ),
-                None => AccruedInterestCalculator::standard(
-                    settlement,
-                    last_coupon,
-                    next_coupon,
-                    self.coupon_rate,
-                    self.face_value,
-                    self.day_count,
-                    self.frequency,
-                ),
-            }
+                |coupon| self.ex_dividend_date(coupon).unwrap_or(coupon),
+            ),
+            None => AccruedInterestCalculator::from_schedule(
+                dates,
+                settlement,
+                self.coupon_rate,
+                self.face_value,
+                self.day_count,
+                self.frequency,
+            ),
         }
     }
 }
@@ -325,9 +354,12 @@ impl Bond for FixedRateBond {
         let schedule = self.schedule();
         let dates = schedule.dates();
         let unadjusted = schedule.unadjusted_dates();
-
-        // Calculate regular period length for stub detection
-        let regular_days = 365 / self.frequency.periods_per_year() as i64;
+        let last_period = unadjusted.len().saturating_sub(2);
+        // Settling ex-dividend, the next coupon goes to the seller (the
+        // negative accrued pays the buyer back for the days it doesn't own).
+        let ex_dividend_coupon = self
+            .is_ex_dividend(from)
+            .then(|| self.coupon_dates_for_settlement(from).1);
 
         let mut flows = Vec::new();
 
@@ -340,27 +372,15 @@ impl Bond for FixedRateBond {
                 continue;
             }
 
-            // Check if this is the first period and it's a stub
-            let is_first_period = i == 0 && accrual_start == self.dated_date;
-            let actual_days = accrual_start.days_between(&accrual_end).abs();
-            let is_stub_period = is_first_period && (actual_days - regular_days).abs() > 30;
+            // Front stubs roll notional periods back from the first coupon
+            // date, back stubs forward from the last regular date.
+            let is_front_stub = i == 0 && accrual_start == self.dated_date;
+            let is_irregular = !self.is_regular_period(accrual_start, accrual_end);
 
-            let coupon = if is_stub_period {
-                // Prorate the regular coupon by actual_days / nominal_period_days,
-                // matching ISMA/ICMA convention (and QuantLib's stub handling).
-                // Using the DayCount's own day_count() keeps 30/360 rules in sync.
-                let dc = self.day_count.to_day_count();
-                let months_per_period = 12 / self.frequency.periods_per_year() as i32;
-                let nominal_start = accrual_end
-                    .add_months(-months_per_period)
-                    .unwrap_or(accrual_start);
-                let nominal_days = dc.day_count(nominal_start, accrual_end).abs();
-                let actual = dc.day_count(accrual_start, accrual_end).abs();
-                if nominal_days > 0 {
-                    self.coupon_per_period() * Decimal::from(actual) / Decimal::from(nominal_days)
-                } else {
-                    self.coupon_per_period()
-                }
+            let coupon = if is_irregular && is_front_stub {
+                self.stub_coupon(accrual_start, accrual_end, true)
+            } else if is_irregular && i == last_period {
+                self.stub_coupon(accrual_start, accrual_end, false)
             } else if matches!(
                 self.day_count,
                 DayCountConvention::Act360
@@ -377,9 +397,16 @@ impl Bond for FixedRateBond {
                 self.coupon_per_period()
             };
 
-            let is_final = i == unadjusted.len() - 2;
+            let is_final = i == last_period;
 
-            if is_final {
+            if ex_dividend_coupon == Some(accrual_end) {
+                if is_final {
+                    flows.push(
+                        BondCashFlow::principal(payment_date, self.redemption_value)
+                            .with_accrual(accrual_start, accrual_end),
+                    );
+                }
+            } else if is_final {
                 // Final payment: coupon + principal
                 flows.push(
                     BondCashFlow::coupon_and_principal(payment_date, coupon, self.redemption_value)
@@ -427,6 +454,7 @@ impl Bond for FixedRateBond {
             DayCountConvention::Thirty360E => "30E/360",
             DayCountConvention::Thirty360EIsda => "30E/360 ISDA",
             DayCountConvention::Thirty360German => "30/360 German",
+            DayCountConvention::Business252 { .. } => self.day_count.name(),
         }
     }
 
@@ -467,14 +495,9 @@ impl FixedCouponBond for FixedRateBond {
     }
 
     fn is_ex_dividend(&self, settlement: Date) -> bool {
-        if let Some(ex_div_days) = self.ex_dividend_days {
-            let (_, next_coupon) = self.coupon_dates_for_settlement(settlement);
-            let calendar = self.calendar.to_calendar();
-            let ex_div_date = calendar.add_business_days(next_coupon, -(ex_div_days as i32));
-            settlement >= ex_div_date
-        } else {
-            false
-        }
+        let (_, next_coupon) = self.coupon_dates_for_settlement(settlement);
+        self.ex_dividend_date(next_coupon)
+            .is_some_and(|ex_div_date| settlement >= ex_div_date)
     }
 }
 
@@ -501,6 +524,7 @@ pub struct FixedRateBondBuilder {
     face_value: Option<Decimal>,
     redemption_value: Option<Decimal>,
     ex_dividend_days: Option<u32>,
+    ex_dividend_day_type: Option<DayType>,
     bond_type: Option<BondType>,
 }
 
@@ -656,6 +680,14 @@ impl FixedRateBondBuilder {
         self
     }
 
+    /// Sets whether the ex-dividend window counts business days (default)
+    /// or calendar days.
+    #[must_use]
+    pub fn ex_dividend_day_type(mut self, day_type: DayType) -> Self {
+        self.ex_dividend_day_type = Some(day_type);
+        self
+    }
+
     /// Applies US Corporate bond conventions.
     ///
     /// - Day count: 30/360 US
@@ -799,6 +831,7 @@ impl FixedRateBondBuilder {
             face_value: self.face_value.unwrap_or(Decimal::ONE_HUNDRED),
             redemption_value: self.redemption_value.unwrap_or(Decimal::ONE_HUNDRED),
             ex_dividend_days: self.ex_dividend_days,
+            ex_dividend_day_type: self.ex_dividend_day_type.unwrap_or_default(),
             bond_type: self.bond_type.unwrap_or(BondType::FixedRateCorporate),
             schedule: OnceCell::new(),
         })
@@ -818,6 +851,7 @@ fn day_count_to_string(dc: &DayCountConvention) -> &'static str {
         DayCountConvention::Thirty360E => "Thirty360E",
         DayCountConvention::Thirty360EIsda => "Thirty360EIsda",
         DayCountConvention::Thirty360German => "Thirty360German",
+        DayCountConvention::Business252 { .. } => dc.name(),
     }
 }
 
@@ -834,7 +868,8 @@ fn string_to_day_count(s: &str) -> DayCountConvention {
         "Thirty360E" => DayCountConvention::Thirty360E,
         "Thirty360EIsda" => DayCountConvention::Thirty360EIsda,
         "Thirty360German" => DayCountConvention::Thirty360German,
-        _ => DayCountConvention::Thirty360US, // Default fallback
+        // Calendar-bearing conventions serialize under their market name.
+        other => other.parse().unwrap_or(DayCountConvention::Thirty360US), // Default fallback
     }
 }
 
@@ -845,7 +880,7 @@ impl Serialize for FixedRateBond {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("FixedRateBond", 18)?;
+        let mut state = serializer.serialize_struct("FixedRateBond", 19)?;
         state.serialize_field("identifiers", &self.identifiers)?;
         state.serialize_field("coupon_rate", &self.coupon_rate)?;
         state.serialize_field("maturity", &self.maturity)?;
@@ -863,6 +898,7 @@ impl Serialize for FixedRateBond {
         state.serialize_field("face_value", &self.face_value)?;
         state.serialize_field("redemption_value", &self.redemption_value)?;
         state.serialize_field("ex_dividend_days", &self.ex_dividend_days)?;
+        state.serialize_field("ex_dividend_day_type", &self.ex_dividend_day_type)?;
         state.serialize_field("bond_type", &self.bond_type)?;
         state.end()
     }
@@ -893,6 +929,8 @@ impl<'de> Deserialize<'de> for FixedRateBond {
             face_value: Decimal,
             redemption_value: Decimal,
             ex_dividend_days: Option<u32>,
+            #[serde(default)]
+            ex_dividend_day_type: DayType,
             bond_type: BondType,
         }
 
@@ -915,6 +953,7 @@ impl<'de> Deserialize<'de> for FixedRateBond {
             face_value: data.face_value,
             redemption_value: data.redemption_value,
             ex_dividend_days: data.ex_dividend_days,
+            ex_dividend_day_type: data.ex_dividend_day_type,
             bond_type: data.bond_type,
             schedule: OnceCell::new(),
         })
@@ -993,6 +1032,175 @@ mod tests {
         }
     }
 
+    /// 5% semi-annual 30/360 bond; the 30/360 year fraction of the period
+    /// times the annual coupon.
+    fn thirty_360_coupon(start: Date, end: Date) -> Decimal {
+        let dc = DayCountConvention::Thirty360US.to_day_count();
+        dec!(5) * dc.year_fraction(start, end)
+    }
+
+    #[test]
+    fn test_short_first_coupon_pays_day_count_fraction() {
+        // Issued 2025-07-10 into a Jun/Dec 15th schedule: a 155-day (30/360)
+        // first period, within a month of regular but still a stub.
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("SHORTSTUB")
+            .coupon_percent(5.0)
+            .maturity(date(2030, 6, 15))
+            .issue_date(date(2025, 7, 10))
+            .frequency(Frequency::SemiAnnual)
+            .day_count(DayCountConvention::Thirty360US)
+            .build()
+            .unwrap();
+
+        let flows = bond.cash_flows(date(2025, 7, 10));
+        let first = &flows[0];
+        assert_eq!(first.accrual_end, Some(date(2025, 12, 15)));
+        let expected = thirty_360_coupon(date(2025, 7, 10), date(2025, 12, 15));
+        assert!((first.amount - expected).abs() < dec!(0.0000000001));
+        assert!(first.amount < dec!(2.5));
+        assert_eq!(flows[1].amount, dec!(2.5));
+    }
+
+    #[test]
+    fn test_long_first_coupon_pays_day_count_fraction() {
+        // Issued 2025-01-15 with first coupon 2025-09-15: eight months, one
+        // regular period plus a two-month notional stub.
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("LONGSTUB")
+            .coupon_percent(5.0)
+            .maturity(date(2030, 3, 15))
+            .issue_date(date(2025, 1, 15))
+            .first_coupon_date(date(2025, 9, 15))
+            .frequency(Frequency::SemiAnnual)
+            .day_count(DayCountConvention::Thirty360US)
+            .build()
+            .unwrap();
+
+        let flows = bond.cash_flows(date(2025, 1, 15));
+        let first = &flows[0];
+        assert_eq!(first.accrual_end, Some(date(2025, 9, 15)));
+        let expected = thirty_360_coupon(date(2025, 1, 15), date(2025, 9, 15));
+        assert!((first.amount - expected).abs() < dec!(0.0000000001));
+        assert!(first.amount > dec!(2.5));
+        assert_eq!(flows[1].amount, dec!(2.5));
+
+        // Accrued builds up to the stub coupon, one 30/360 day short of it
+        // the day before payment.
+        let accrued = bond.accrued_interest(date(2025, 9, 14));
+        let expected = first.amount - dec!(2.5) / dec!(180);
+        assert!((accrued - expected).abs() < dec!(0.0000000001));
+    }
+
+    #[test]
+    fn test_short_last_coupon_pays_day_count_fraction() {
+        // Coupons roll forward from 2025-09-15, leaving a three-month final
+        // period from 2030-03-15 to the June maturity.
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("BACKSTUB")
+            .coupon_percent(5.0)
+            .maturity(date(2030, 6, 15))
+            .issue_date(date(2025, 3, 15))
+            .first_coupon_date(date(2025, 9, 15))
+            .frequency(Frequency::SemiAnnual)
+            .day_count(DayCountConvention::Thirty360US)
+            .build()
+            .unwrap();
+
+        let flows = bond.cash_flows(date(2025, 3, 15));
+        assert_eq!(flows[0].amount, dec!(2.5));
+        let last = flows.last().unwrap();
+        assert_eq!(last.accrual_start, Some(date(2030, 3, 15)));
+        let expected = thirty_360_coupon(date(2030, 3, 15), date(2030, 6, 15)) + dec!(100);
+        assert!((last.amount - expected).abs() < dec!(0.0000000001));
+    }
+
+    #[test]
+    fn test_ex_dividend_settlement_drops_next_coupon() {
+        use crate::pricing::YieldSolver;
+        use crate::traits::BondAnalytics;
+
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("GILTEXDIV")
+            .coupon_percent(4.0)
+            .maturity(date(2030, 3, 7))
+            .issue_date(date(2020, 3, 7))
+            .uk_gilt()
+            .build()
+            .unwrap();
+
+        // Mar 7 2025 coupon goes ex seven business days earlier, Feb 26.
+        let cum = date(2025, 2, 24);
+        let ex = date(2025, 3, 3);
+        assert!(!bond.is_ex_dividend(cum));
+        assert!(bond.is_ex_dividend(ex));
+
+        assert_eq!(bond.cash_flows(cum)[0].date, date(2025, 3, 7));
+        let ex_flows = bond.cash_flows(ex);
+        assert_eq!(ex_flows[0].accrual_end, Some(date(2025, 9, 7)));
+        assert_eq!(ex_flows.len(), bond.cash_flows(cum).len() - 1);
+        assert!(bond.accrued_interest(ex) < Decimal::ZERO);
+
+        // At the same clean price, the ex-div yield stays in line with the
+        // cum-div one...
+        let clean = dec!(98);
+        let ytm_cum = bond
+            .yield_to_maturity(cum, clean, Frequency::SemiAnnual)
+            .unwrap()
+            .yield_value;
+        let ytm_ex = bond
+            .yield_to_maturity(ex, clean, Frequency::SemiAnnual)
+            .unwrap()
+            .yield_value;
+        assert!((ytm_ex - ytm_cum).abs() < 0.0005, "{ytm_cum} vs {ytm_ex}");
+
+        // ...whereas keeping the seller's coupon overstates it.
+        let mut with_coupon = ex_flows.clone();
+        with_coupon.insert(0, BondCashFlow::coupon(date(2025, 3, 7), dec!(2)));
+        let overstated = YieldSolver::new()
+            .solve(
+                &with_coupon,
+                clean,
+                bond.accrued_interest(ex),
+                ex,
+                DayCountConvention::ActActIcma,
+                Frequency::SemiAnnual,
+            )
+            .unwrap()
+            .yield_value;
+        assert!(overstated - ytm_ex > 0.002, "{overstated} vs {ytm_ex}");
+    }
+
+    #[test]
+    fn test_calendar_day_ex_dividend_window() {
+        let gilt = || {
+            FixedRateBond::builder()
+                .cusip_unchecked("GILTEXDIV")
+                .coupon_percent(4.0)
+                .maturity(date(2030, 3, 7))
+                .issue_date(date(2020, 3, 7))
+                .uk_gilt()
+        };
+        let business = gilt().build().unwrap();
+        let calendar = gilt()
+            .ex_dividend_day_type(DayType::CalendarDays)
+            .build()
+            .unwrap();
+        assert_eq!(calendar.ex_dividend_day_type(), DayType::CalendarDays);
+
+        // Seven calendar days before Mar 7 is Feb 28, two business days
+        // later than the business-day window's Feb 26.
+        let between = date(2025, 2, 27);
+        assert!(business.is_ex_dividend(between));
+        assert!(!calendar.is_ex_dividend(between));
+        assert!(calendar.accrued_interest(between) > Decimal::ZERO);
+
+        let ex = date(2025, 3, 3);
+        assert!(calendar.is_ex_dividend(ex));
+        assert_eq!(calendar.accrued_interest(ex), business.accrued_interest(ex));
+        assert_eq!(calendar.cash_flows(ex).len(), business.cash_flows(ex).len());
+    }
+
     /// Boeing 7.5% 06/15/2025 - Bloomberg YAS validation
     ///
     /// Settlement: 04/29/2020
@@ -1221,6 +1429,52 @@ mod tests {
         );
     }
 
+    /// BUS/252 accrues on Brazilian business days and survives the string
+    /// round-trip used by yield solving and serialization.
+    #[test]
+    fn test_business252_accrued_and_yield() {
+        use crate::traits::BondAnalytics;
+        use convex_core::calendars::MarketCalendar;
+        use convex_core::daycounts::{Business252, DayCount};
+
+        let convention = DayCountConvention::Business252 {
+            calendar: MarketCalendar::Brazil,
+        };
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("NTNF2031")
+            .coupon_percent(10.0)
+            .issue_date(date(2025, 1, 1))
+            .maturity(date(2031, 1, 1))
+            .frequency(Frequency::SemiAnnual)
+            .day_count(convention)
+            .currency(Currency::BRL)
+            .business_day_convention(BusinessDayConvention::Unadjusted)
+            .build()
+            .unwrap();
+
+        // Settlement after Good Friday (Apr 18) and Tiradentes (Apr 21).
+        let settlement = date(2025, 4, 28);
+        let dc = Business252::new(MarketCalendar::Brazil);
+        let accrued_bd = dc.day_count(date(2025, 1, 1), settlement);
+        let period_bd = dc.day_count(date(2025, 1, 1), date(2025, 7, 1));
+        let expected = dec!(5) * Decimal::from(accrued_bd) / Decimal::from(period_bd);
+
+        let accrued = bond.accrued_interest(settlement);
+        assert!((accrued - expected).abs() < dec!(0.0000001), "{accrued}");
+        // Calendar-day proration would give 5 × 117/181.
+        assert!((accrued - dec!(5) * dec!(117) / dec!(181)).abs() > dec!(0.01));
+
+        assert_eq!(bond.parse_day_count().unwrap(), convention);
+        let ytm = bond
+            .yield_to_maturity(settlement, dec!(95), Frequency::SemiAnnual)
+            .unwrap();
+        assert!(ytm.yield_value > 0.10 && ytm.yield_value < 0.13);
+
+        let json = serde_json::to_string(&bond).unwrap();
+        let restored: FixedRateBond = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.day_count(), convention);
+    }
+
     /// Test accrued interest for annual bond with LONG first coupon period.
     ///
     /// Bond: 5% annual coupon
@@ -329,6 +329,7 @@ impl Bond for FloatingRateNote {
             DayCountConvention::Thirty360E => "30E/360",
             DayCountConvention::Thirty360EIsda => "30E/360 ISDA",
             DayCountConvention::Thirty360German => "30/360 German",
+            DayCountConvention::Business252 { .. } => self.day_count.name(),
         }
     }
 
@@ -689,6 +690,7 @@ fn day_count_to_string(dc: &DayCountConvention) -> &'static str {
         DayCountConvention::Thirty360E => "Thirty360E",
         DayCountConvention::Thirty360EIsda => "Thirty360EIsda",
         DayCountConvention::Thirty360German => "Thirty360German",
+        DayCountConvention::Business252 { .. } => dc.name(),
     }
 }
 
@@ -704,7 +706,8 @@ fn string_to_day_count(s: &str) -> DayCountConvention {
         "Thirty360E" => DayCountConvention::Thirty360E,
         "Thirty360EIsda" => DayCountConvention::Thirty360EIsda,
         "Thirty360German" => DayCountConvention::Thirty360German,
-        _ => DayCountConvention::Act360, // Default
+        // Calendar-bearing conventions serialize under their market name.
+        other => other.parse().unwrap_or(DayCountConvention::Act360), // Default
     }
 }
 
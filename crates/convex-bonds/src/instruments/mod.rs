@@ -5,6 +5,7 @@ mod callable_frn;
 mod fixed_rate;
 mod floating_rate;
 mod sinking_fund;
+mod strips;
 mod zero_coupon;
 
 pub use callable::{CallableBond, CallableBondBuilder};
@@ -15,6 +16,7 @@ pub use sinking_fund::{
     AccelerationOption, SinkingFundBond, SinkingFundBondBuilder, SinkingFundPayment,
     SinkingFundSchedule,
 };
+pub use strips::strip;
 pub use zero_coupon::{convert_yield, Compounding, ZeroCouponBond, ZeroCouponBondBuilder};
 
 // Canonical `Bond` trait lives in `crate::traits`; the legacy local trait
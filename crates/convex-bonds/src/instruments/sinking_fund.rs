@@ -10,11 +10,12 @@
 use rust_decimal::Decimal;
 
 use convex_core::types::{Currency, Date, Frequency};
-use convex_math::solvers::{newton_raphson, SolverConfig};
 
 use crate::error::{BondError, BondResult};
 use crate::instruments::FixedRateBond;
-use crate::traits::{AmortizingBond, Bond, BondCashFlow, CashFlowType, FixedCouponBond};
+use crate::traits::{
+    AmortizingBond, Bond, BondAnalytics, BondCashFlow, CashFlowType, FixedCouponBond,
+};
 use crate::types::{AmortizationSchedule, AmortizationType, BondIdentifiers, BondType, CalendarId};
 
 /// A single sinking fund payment.
@@ -430,10 +431,12 @@ impl SinkingFundBond {
     }
 
     /// Returns the average life from settlement.
+    ///
+    /// Same as [`BondAnalytics::average_life`], with zero when no principal
+    /// is outstanding.
     #[must_use]
     pub fn average_life(&self, settlement: Date) -> f64 {
-        let maturity = self.base.maturity().unwrap();
-        self.sinking_schedule.average_life(settlement, maturity)
+        BondAnalytics::average_life(self, settlement).unwrap_or(0.0)
     }
 
     /// Returns the remaining principal at settlement.
@@ -446,7 +449,8 @@ impl SinkingFundBond {
     /// Calculates yield to average life.
     ///
     /// This calculates the yield assuming the bond is retired at its
-    /// average life date.
+    /// average life date, through [`BondAnalytics::yield_to_average_life`]
+    /// at the bond's coupon frequency.
     ///
     /// # Arguments
     ///
@@ -461,18 +465,8 @@ impl SinkingFundBond {
         clean_price: Decimal,
         settlement: Date,
     ) -> BondResult<Decimal> {
-        let avg_life_years = self.average_life(settlement);
-        let avg_life_days = (avg_life_years * 365.0) as i64;
-        let avg_life_date = settlement + avg_life_days;
-
-        // Generate cash flows to average life
-        let flows = self.cash_flows_to_date(settlement, avg_life_date);
-
-        if flows.is_empty() {
-            return Err(BondError::invalid_spec("no cash flows to average life"));
-        }
-
-        self.solve_yield(&flows, clean_price, settlement)
+        BondAnalytics::yield_to_average_life(self, settlement, clean_price, self.base.frequency())
+            .map(|result| result.yield_decimal())
     }
 
     /// Factor-adjusted cash flows. Coupon at sink date D accrues on the
@@ -493,7 +487,13 @@ impl SinkingFundBond {
 
             let pre_paydown_factor = remaining_factor;
 
-            if let Some(sf_payment) = self.sinking_schedule.payment_on(cf.date) {
+            // Sinking dates are unadjusted; match the coupon's accrual end
+            // too so a sink on a weekend still lands on its payment date.
+            let sf_payment = self.sinking_schedule.payment_on(cf.date).or_else(|| {
+                cf.accrual_end
+                    .and_then(|end| self.sinking_schedule.payment_on(end))
+            });
+            if let Some(sf_payment) = sf_payment {
                 let sf_amount = self.original_face
                     * Decimal::try_from(sf_payment.amount_pct / 100.0).unwrap_or(Decimal::ZERO)
                     * Decimal::try_from(sf_payment.price / 100.0).unwrap_or(Decimal::ONE);
@@ -533,7 +533,7 @@ impl SinkingFundBond {
             }
         }
 
-        // YTAL workouts that don't extend to maturity leave residual principal.
+        // Principal not retired by the sinking fund is redeemed at the end date.
         if remaining_factor > 0.0 && end_date <= maturity {
             let final_amount =
                 self.original_face * Decimal::try_from(remaining_factor).unwrap_or(Decimal::ZERO);
@@ -542,69 +542,6 @@ impl SinkingFundBond {
 
         flows
     }
-
-    /// Solves for yield given cash flows and target price.
-    fn solve_yield(
-        &self,
-        flows: &[BondCashFlow],
-        clean_price: Decimal,
-        settlement: Date,
-    ) -> BondResult<Decimal> {
-        let factor = self.current_factor(settlement);
-        let adjusted_price = clean_price * Decimal::try_from(factor).unwrap_or(Decimal::ONE);
-        let accrued = self.accrued_interest(settlement);
-        let target_dirty = adjusted_price + accrued;
-        let target = target_dirty.to_string().parse::<f64>().unwrap_or(100.0);
-
-        let freq = f64::from(self.base.coupon_frequency());
-        let coupon_rate = self
-            .base
-            .coupon_rate()
-            .to_string()
-            .parse::<f64>()
-            .unwrap_or(0.05);
-        let initial_guess = coupon_rate;
-
-        // Collect flow data for closures
-        let flow_data: Vec<(f64, f64)> = flows
-            .iter()
-            .map(|cf| {
-                let t = settlement.days_between(&cf.date) as f64 / 365.0;
-                let amount = cf.amount.to_string().parse::<f64>().unwrap_or(0.0);
-                (t, amount)
-            })
-            .collect();
-
-        if flow_data.is_empty() {
-            return Err(BondError::invalid_spec(
-                "no cash flows for yield calculation",
-            ));
-        }
-
-        let objective = |y: f64| {
-            let mut pv = 0.0;
-            for &(t, amount) in &flow_data {
-                let df = 1.0 / (1.0 + y / freq).powf(freq * t);
-                pv += amount * df;
-            }
-            pv - target
-        };
-
-        let derivative = |y: f64| {
-            let mut dpv = 0.0;
-            for &(t, amount) in &flow_data {
-                let df = 1.0 / (1.0 + y / freq).powf(freq * t);
-                dpv += amount * (-t) * df / (1.0 + y / freq);
-            }
-            dpv
-        };
-
-        let config = SolverConfig::new(1e-10, 100);
-        let result = newton_raphson(objective, derivative, initial_guess, &config)
-            .map_err(|_| BondError::YieldConvergenceFailed { iterations: 100 })?;
-
-        Ok(Decimal::from_f64_retain(result.root).unwrap_or(Decimal::ZERO))
-    }
 }
 
 // Implement Bond trait
@@ -857,6 +794,45 @@ mod tests {
         assert!((schedule.acceleration().unwrap().multiple - 2.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_amortizing_duration_and_convexity_below_bullet() {
+        use crate::traits::BondAnalytics;
+
+        let bullet = create_base_bond();
+        let sinker = SinkingFundBond::new(create_base_bond(), create_sinking_schedule());
+        let settlement = date(2024, 1, 15);
+        let ytm = 0.06;
+
+        // Duration and convexity see every principal repayment, not just a
+        // terminal redemption. The 2025-06-15 sink falls on a Sunday and is
+        // paid with the coupon on the 16th.
+        let principal: Vec<_> = sinker
+            .cash_flows(settlement)
+            .into_iter()
+            .filter(|cf| cf.flow_type == CashFlowType::Principal)
+            .collect();
+        assert_eq!(principal.len(), 5);
+        assert_eq!(principal[0].date, date(2025, 6, 16));
+        let total: Decimal = principal.iter().map(|cf| cf.amount).sum();
+        assert!((total - dec!(100)).abs() < dec!(0.000001));
+
+        let freq = Frequency::SemiAnnual;
+        let bullet_duration = bullet.macaulay_duration(settlement, ytm, freq).unwrap();
+        let sinker_duration = sinker.macaulay_duration(settlement, ytm, freq).unwrap();
+        assert!(
+            sinker_duration < bullet_duration - 1.0,
+            "{sinker_duration} vs {bullet_duration}"
+        );
+        assert!(sinker_duration > sinker.average_life(settlement) * 0.8);
+
+        let bullet_convexity = bullet.convexity(settlement, ytm, freq).unwrap();
+        let sinker_convexity = sinker.convexity(settlement, ytm, freq).unwrap();
+        assert!(
+            sinker_convexity < bullet_convexity,
+            "{sinker_convexity} vs {bullet_convexity}"
+        );
+    }
+
     #[test]
     fn test_sinking_fund_to_amortization() {
         let schedule = create_sinking_schedule();
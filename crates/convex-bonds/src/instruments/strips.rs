@@ -0,0 +1,162 @@
+//! Separation of coupon bonds into principal and interest STRIPS.
+//!
+//! Each remaining coupon becomes an interest strip and the redemption
+//! becomes a principal strip, so the pieces can be priced and compared
+//! individually. Discounting the strips off a curve and summing
+//! reconstitutes the bond's PV on the same curve.
+
+use convex_core::types::Date;
+use rust_decimal::Decimal;
+
+use crate::instruments::ZeroCouponBond;
+use crate::traits::{principal_amounts, Bond, BondAnalytics};
+use crate::types::BondIdentifiers;
+
+/// Strips a coupon bond into one zero-coupon bond per cash flow after
+/// `settlement`.
+///
+/// Each coupon produces an interest strip and each principal payment a
+/// principal strip, with the payment date as maturity and the payment
+/// amount as face value. A combined coupon-and-principal flow yields both,
+/// with the principal taken from the drop in the flow's factor (see
+/// [`BondCashFlow::factor`](crate::traits::BondCashFlow::factor)). Strips are returned in payment date order, interest before
+/// principal on the same date, and carry a ticker of the form
+/// `"<bond id> C <date>"` or `"<bond id> P <date>"`.
+#[must_use]
+pub fn strip<B: Bond + ?Sized>(bond: &B, settlement: Date) -> Vec<ZeroCouponBond> {
+    let id = bond
+        .identifiers()
+        .primary_id()
+        .unwrap_or("STRIP")
+        .to_string();
+    let day_count = bond.parse_day_count().ok();
+
+    let make_strip = |kind: &str, date: Date, amount: Decimal| {
+        let mut builder = ZeroCouponBond::builder()
+            .identifiers(BondIdentifiers::new().with_ticker(format!("{id} {kind} {date}")))
+            .maturity(date)
+            .issue_date(settlement)
+            .currency(bond.currency())
+            .calendar(bond.calendar().clone())
+            .face_value(amount);
+        if let Some(dc) = day_count {
+            builder = builder.day_count(dc);
+        }
+        builder.build()
+    };
+
+    let cash_flows = bond.cash_flows(settlement);
+    let principals = principal_amounts(&cash_flows, bond.redemption_value());
+    cash_flows
+        .into_iter()
+        .zip(principals)
+        .flat_map(|(cf, principal)| {
+            let interest = cf.amount - principal;
+            [("C", interest), ("P", principal)]
+                .into_iter()
+                .filter(|(_, amount)| *amount > Decimal::ZERO)
+                .map(move |(kind, amount)| (kind, cf.date, amount))
+        })
+        // Flows from `cash_flows(settlement)` fall after settlement, so the
+        // builder's maturity check always passes.
+        .filter_map(|(kind, date, amount)| make_strip(kind, date, amount).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::FixedRateBond;
+    use convex_curves::curves::DiscountCurveBuilder;
+    use convex_curves::RateCurve;
+    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_strip_pvs_sum_to_bond_pv() {
+        let settlement = date(2025, 1, 15);
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("912828XX1")
+            .coupon_percent(4.25)
+            .maturity(date(2030, 2, 15))
+            .issue_date(date(2020, 2, 15))
+            .us_treasury()
+            .build()
+            .unwrap();
+
+        let curve = RateCurve::new(
+            DiscountCurveBuilder::new(settlement)
+                .add_zero_rate(0.25, 0.043)
+                .add_zero_rate(1.0, 0.041)
+                .add_zero_rate(2.0, 0.040)
+                .add_zero_rate(5.0, 0.042)
+                .add_zero_rate(10.0, 0.045)
+                .build()
+                .unwrap(),
+        );
+        let pv = |flows: Vec<crate::traits::BondCashFlow>| -> f64 {
+            flows
+                .iter()
+                .map(|cf| cf.amount.to_f64().unwrap() * curve.discount_factor(cf.date).unwrap())
+                .sum()
+        };
+
+        let strips = strip(&bond, settlement);
+        let flows = bond.cash_flows(settlement);
+        // One interest strip per coupon plus the principal strip.
+        assert_eq!(strips.len(), flows.len() + 1);
+
+        let last = strips.last().unwrap();
+        assert_eq!(last.maturity_date(), date(2030, 2, 15));
+        assert_eq!(last.redemption_value(), dec!(100));
+        assert_eq!(last.identifiers().ticker(), Some("912828XX1 P 2030-02-15"));
+        assert_eq!(strips[0].redemption_value(), dec!(2.125));
+
+        let strip_pv: f64 = strips.iter().map(|s| pv(s.cash_flows(settlement))).sum();
+        assert!((strip_pv - pv(flows)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_strip_sinker_principal_strips_retire_face() {
+        use crate::instruments::{SinkingFundBond, SinkingFundPayment, SinkingFundSchedule};
+        use convex_core::calendars::BusinessDayConvention;
+
+        let base = FixedRateBond::builder()
+            .cusip_unchecked("123456789")
+            .coupon_percent(6.0)
+            .issue_date(date(2020, 6, 15))
+            .maturity(date(2025, 6, 15))
+            .us_corporate()
+            .business_day_convention(BusinessDayConvention::Unadjusted)
+            .build()
+            .unwrap();
+        let schedule = (2021..2025).fold(SinkingFundSchedule::new(), |s, year| {
+            s.with_payment(SinkingFundPayment::new(date(year, 6, 15), 20.0))
+        });
+        let bond = SinkingFundBond::new(base, schedule);
+
+        let strips = strip(&bond, date(2020, 6, 15));
+        let principal: Vec<_> = strips
+            .iter()
+            .filter(|s| s.identifiers().ticker().unwrap().contains(" P "))
+            .map(|s| s.redemption_value().round_dp(8))
+            .collect();
+        // One principal strip per sink plus the final redemption, each 20.
+        assert_eq!(principal, vec![dec!(20); 5]);
+        // Coupons shrink with the outstanding balance.
+        let interest = |d: Date| {
+            strips
+                .iter()
+                .find(|s| s.identifiers().ticker() == Some(&format!("123456789 C {d}")))
+                .unwrap()
+                .redemption_value()
+                .round_dp(8)
+        };
+        assert_eq!(interest(date(2020, 12, 15)), dec!(3));
+        assert_eq!(interest(date(2024, 12, 15)), dec!(0.6));
+    }
+}
@@ -99,9 +99,22 @@ impl std::fmt::Display for Compounding {
     }
 }
 
+impl From<Compounding> for convex_core::types::Compounding {
+    fn from(compounding: Compounding) -> Self {
+        match compounding {
+            Compounding::Annual => Self::Annual,
+            Compounding::SemiAnnual => Self::SemiAnnual,
+            Compounding::Quarterly => Self::Quarterly,
+            Compounding::Monthly => Self::Monthly,
+            Compounding::Continuous => Self::Continuous,
+        }
+    }
+}
+
 /// Converts a yield from one compounding convention to another.
 ///
-/// Uses continuous compounding as an intermediate step.
+/// Delegates to [`convex_core::types::convert_yield`], the shared
+/// implementation for all yield bases.
 ///
 /// # Arguments
 ///
@@ -125,29 +138,7 @@ impl std::fmt::Display for Compounding {
 /// ```
 #[must_use]
 pub fn convert_yield(yield_rate: Decimal, from: Compounding, to: Compounding) -> Decimal {
-    if from == to {
-        return yield_rate;
-    }
-
-    let rate = yield_rate.to_string().parse::<f64>().unwrap_or(0.0);
-
-    // First convert to continuous
-    let continuous = if from == Compounding::Continuous {
-        rate
-    } else {
-        let m = f64::from(from.periods_per_year());
-        m * (1.0 + rate / m).ln()
-    };
-
-    // Then convert from continuous to target
-    let result = if to == Compounding::Continuous {
-        continuous
-    } else {
-        let m = f64::from(to.periods_per_year());
-        m * ((continuous / m).exp() - 1.0)
-    };
-
-    Decimal::try_from(result).unwrap_or(Decimal::ZERO)
+    convex_core::types::convert_yield(yield_rate, from.into(), to.into())
 }
 
 /// A zero coupon (discount) bond.
@@ -534,6 +525,7 @@ impl Bond for ZeroCouponBond {
             DayCountConvention::Thirty360E => "30E/360",
             DayCountConvention::Thirty360EIsda => "30E/360 ISDA",
             DayCountConvention::Thirty360German => "30/360 German",
+            DayCountConvention::Business252 { .. } => self.day_count.name(),
         }
     }
 
@@ -559,6 +551,7 @@ fn day_count_to_string(dc: &DayCountConvention) -> &'static str {
         DayCountConvention::Thirty360E => "Thirty360E",
         DayCountConvention::Thirty360EIsda => "Thirty360EIsda",
         DayCountConvention::Thirty360German => "Thirty360German",
+        DayCountConvention::Business252 { .. } => dc.name(),
     }
 }
 
@@ -574,7 +567,8 @@ fn string_to_day_count(s: &str) -> DayCountConvention {
         "Thirty360E" => DayCountConvention::Thirty360E,
         "Thirty360EIsda" => DayCountConvention::Thirty360EIsda,
         "Thirty360German" => DayCountConvention::Thirty360German,
-        _ => DayCountConvention::ActActIsda, // Default
+        // Calendar-bearing conventions serialize under their market name.
+        other => other.parse().unwrap_or(DayCountConvention::ActActIsda), // Default
     }
 }
 
@@ -3,6 +3,8 @@
 //! Provides a recombining binomial tree structure for pricing bonds with
 //! embedded options using backward induction.
 
+use super::models::ModelError;
+
 /// A binomial interest rate tree.
 ///
 /// The tree represents possible short rate paths over time, with each node
@@ -205,12 +207,152 @@ impl BinomialTree {
 
         values[0]
     }
+
+    /// Builds a Hull-White lattice fitted to `zero_rates` on a uniform grid.
+    ///
+    /// Node `(i, j)` sits at `x = (2j - i)·σ√Δt`; mean reversion enters
+    /// through the up probability `½ - a·x·√Δt / (2σ)` (clamped to [0, 1])
+    /// rather than the node spacing, so the tree recombines. The per-step
+    /// shift is fitted by forward induction on Arrow-Debreu prices so that
+    /// zero-coupon bonds at every layer reprice exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `steps` is zero or `a`, `sigma` or `maturity` is
+    /// not positive.
+    pub fn build_hull_white<F>(
+        zero_rates: F,
+        a: f64,
+        sigma: f64,
+        maturity: f64,
+        steps: usize,
+    ) -> Result<Self, ModelError>
+    where
+        F: Fn(f64) -> f64,
+    {
+        if steps == 0 {
+            return Err(ModelError::invalid_parameter("steps", 0.0));
+        }
+        for (name, value) in [("a", a), ("sigma", sigma), ("maturity", maturity)] {
+            if value.is_nan() || value <= 0.0 {
+                return Err(ModelError::invalid_parameter(name, value));
+            }
+        }
+
+        let dt = maturity / steps as f64;
+        let dx = sigma * dt.sqrt();
+        let mut tree = Self::new(steps, dt);
+
+        // Arrow-Debreu prices Q[j] at the current layer. The terminal layer
+        // only carries payoffs, so its rates are left at zero.
+        let mut q = vec![1.0_f64];
+        for i in 0..steps {
+            let x = |j: usize| (2.0 * j as f64 - i as f64) * dx;
+            let numer: f64 = q
+                .iter()
+                .enumerate()
+                .map(|(j, qj)| qj * (-x(j) * dt).exp())
+                .sum();
+            let t_next = (i + 1) as f64 * dt;
+            let d_next = (-zero_rates(t_next) * t_next).exp();
+            let alpha = (numer / d_next).max(1e-300).ln() / dt;
+
+            for j in 0..=i {
+                tree.set_rate(i, j, alpha + x(j));
+            }
+
+            let mut q_next = vec![0.0_f64; i + 2];
+            for (j, qj) in q.iter().enumerate() {
+                let p_up = (0.5 - a * x(j) * dt / (2.0 * dx)).clamp(0.0, 1.0);
+                tree.set_probabilities(i, j, p_up, 1.0 - p_up);
+                let weight = qj * (-tree.rate_at(i, j) * dt).exp();
+                q_next[j + 1] += weight * p_up;
+                q_next[j] += weight * (1.0 - p_up);
+            }
+            q = q_next;
+        }
+
+        Ok(tree)
+    }
+
+    /// Time step nearest to `t` years, clamped to `[0, steps]`.
+    ///
+    /// Events between layers are snapped to the closest one, which is the
+    /// main source of the lattice's first-order discretisation error.
+    #[must_use]
+    pub fn step_at_time(&self, t: f64) -> usize {
+        ((t / self.dt).round().max(0.0) as usize).min(self.steps)
+    }
+
+    /// Backward-induction PV with the same conventions as
+    /// [`TrinomialTree::price`](super::TrinomialTree::price):
+    /// `cashflow_at(i)` is added at layer i, `call_price_at(i)` caps the
+    /// continuation value, and `oas` shifts the short rate.
+    #[must_use]
+    pub fn price<C, K>(&self, oas: f64, mut cashflow_at: C, mut call_price_at: K) -> f64
+    where
+        C: FnMut(usize) -> f64,
+        K: FnMut(usize) -> Option<f64>,
+    {
+        let n = self.steps;
+        let mut values = vec![cashflow_at(n); n + 1];
+
+        for i in (0..n).rev() {
+            let cap_at_i = if i > 0 { call_price_at(i) } else { None };
+            let cf_i = cashflow_at(i);
+            let next: Vec<f64> = (0..=i)
+                .map(|j| {
+                    let df = self.discount_factor(i, j, oas);
+                    let cont = df
+                        * (self.prob_up(i, j) * values[j + 1] + self.prob_down(i, j) * values[j]);
+                    cap_at_i.map_or(cont, |cap| cont.min(cap)) + cf_i
+                })
+                .collect();
+            values = next;
+        }
+
+        values[0]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_hull_white_reprices_zero_coupons() {
+        let zero = |t: f64| 0.03 + 0.004 * t;
+        let tree = BinomialTree::build_hull_white(zero, 0.05, 0.01, 5.0, 100).unwrap();
+
+        for &step in &[20, 60, 100] {
+            let t = tree.time_at_step(step);
+            let pv = tree.price(0.0, |i| if i == step { 100.0 } else { 0.0 }, |_| None);
+            assert!(
+                (pv - 100.0 * (-zero(t) * t).exp()).abs() < 1e-8,
+                "zero at t={t}: {pv}"
+            );
+        }
+        assert_eq!(tree.step_at_time(2.52), 50);
+        assert_eq!(tree.step_at_time(9.0), 100);
+    }
+
+    #[test]
+    fn test_hull_white_rejects_non_positive_parameters() {
+        let zero = |_: f64| 0.03;
+        for (a, sigma, maturity, steps) in [
+            (0.03, 0.0, 5.0, 50),
+            (0.0, 0.01, 5.0, 50),
+            (0.03, f64::NAN, 5.0, 50),
+            (0.03, 0.01, 0.0, 50),
+            (0.03, 0.01, 5.0, 0),
+        ] {
+            assert!(matches!(
+                BinomialTree::build_hull_white(zero, a, sigma, maturity, steps),
+                Err(ModelError::InvalidParameter { .. })
+            ));
+        }
+    }
+
     #[test]
     fn test_tree_creation() {
         let tree = BinomialTree::new(10, 0.5);
@@ -1,6 +1,13 @@
 //! Hagan-Brace trinomial tree for HW1F. Matches QL's
 //! `TreeCallableFixedRateBondEngine` lattice (Brigo-Mercurio §3.7).
 //! Supports non-uniform time grids so events land on layers exactly.
+//!
+//! The same lattice carries shifted-lognormal (displaced Black-Karasinski)
+//! dynamics: the state is `ln(r + shift)` rather than `r`, so rates stay
+//! above `-shift` and the model works with negative rates, where a plain
+//! lognormal model cannot.
+
+use super::models::ModelError;
 
 #[derive(Debug, Clone, Copy)]
 struct Branching {
@@ -11,38 +18,22 @@ struct Branching {
 }
 
 /// Branching from `(j, dx_in)` at layer i to layer i+1 (spacing `dx_out`),
-/// with mean-reversion factor `m = exp(-a·dt[i])`. Boundaries at ±j_max use
-/// the alternative {k+1, k, k-1} layout to keep probabilities non-negative.
+/// with mean-reversion factor `m = exp(-a·dt[i])`.
+///
+/// The branch is centred on the node nearest the conditional mean, as in
+/// QL's `TrinomialTree`, so `|eta| <= 0.5` and every probability lies in
+/// `[0, 1]`. The next layer widens to hold the children rather than
+/// clipping them at a fixed `j_max`.
 #[allow(clippy::manual_midpoint)] // polynomial probability formulas, not midpoints
-fn branching_at(j: i32, j_max: i32, m: f64, dx_in: f64, dx_out: f64) -> Branching {
+fn branching_at(j: i32, m: f64, dx_in: f64, dx_out: f64) -> Branching {
     let target = j as f64 * (dx_in / dx_out) * m;
-    if j == j_max {
-        let k = j_max - 1;
-        let eta = target - k as f64;
-        Branching {
-            k,
-            pu: 7.0 / 6.0 + (eta * eta + 3.0 * eta) / 2.0,
-            pm: -1.0 / 3.0 - eta * eta - 2.0 * eta,
-            pd: 1.0 / 6.0 + (eta * eta + eta) / 2.0,
-        }
-    } else if j == -j_max {
-        let k = -j_max + 1;
-        let eta = target - k as f64;
-        Branching {
-            k,
-            pu: 1.0 / 6.0 + (eta * eta - eta) / 2.0,
-            pm: -1.0 / 3.0 - eta * eta + 2.0 * eta,
-            pd: 7.0 / 6.0 + (eta * eta - 3.0 * eta) / 2.0,
-        }
-    } else {
-        let k = target.round() as i32;
-        let eta = target - k as f64;
-        Branching {
-            k,
-            pu: 1.0 / 6.0 + (eta * eta + eta) / 2.0,
-            pm: 2.0 / 3.0 - eta * eta,
-            pd: 1.0 / 6.0 + (eta * eta - eta) / 2.0,
-        }
+    let k = target.round() as i32;
+    let eta = target - k as f64;
+    Branching {
+        k,
+        pu: 1.0 / 6.0 + (eta * eta + eta) / 2.0,
+        pm: 2.0 / 3.0 - eta * eta,
+        pd: 1.0 / 6.0 + (eta * eta - eta) / 2.0,
     }
 }
 
@@ -92,11 +83,25 @@ pub struct TrinomialTree {
     pub dt: Vec<f64>,
     /// j-spacing at layer i: `dx[i+1] = σ·√(3·dt[i])`. Length steps+1.
     pub dx: Vec<f64>,
-    pub j_max: i32,
+    /// Lowest node index at layer i. Length steps+1.
+    pub j_min: Vec<i32>,
+    /// Highest node index at layer i. Length steps+1.
+    pub j_max: Vec<i32>,
     /// `m[i] = exp(-a·dt[i])`. Length steps.
     m: Vec<f64>,
     /// Arrow-Debreu fitting shift; `alpha[i]` reprices `D(times[i+1])`.
     pub alpha: Vec<f64>,
+    /// Displacement for shifted-lognormal dynamics; `None` for Hull-White.
+    pub shift: Option<f64>,
+}
+
+/// Short rate at fitted level `alpha` and state `x`: `alpha + x` for
+/// Hull-White, `exp(alpha + x) - shift` for shifted lognormal.
+fn short_rate(shift: Option<f64>, alpha: f64, x: f64) -> f64 {
+    match shift {
+        None => alpha + x,
+        Some(shift) => (alpha + x).exp() - shift,
+    }
 }
 
 impl TrinomialTree {
@@ -123,6 +128,58 @@ impl TrinomialTree {
     /// to align cashflow and call dates onto layers exactly.
     #[must_use]
     pub fn build_hull_white_on_grid<F>(zero_rates: F, a: f64, sigma: f64, times: &[f64]) -> Self
+    where
+        F: Fn(f64) -> f64,
+    {
+        Self::build_on_grid(zero_rates, a, sigma, times, None)
+    }
+
+    /// Shifted-lognormal tree on a strictly-increasing grid starting at 0:
+    /// `ln(r + shift)` follows the mean-reverting process with volatility
+    /// `sigma`, so `sigma` is a lognormal volatility of `r + shift`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shift` is negative, or if it does not keep every
+    /// forward rate between grid points above `-shift`.
+    pub fn build_shifted_lognormal_on_grid<F>(
+        zero_rates: F,
+        a: f64,
+        sigma: f64,
+        shift: f64,
+        times: &[f64],
+    ) -> Result<Self, ModelError>
+    where
+        F: Fn(f64) -> f64,
+    {
+        if shift < 0.0 || shift.is_nan() {
+            return Err(ModelError::invalid_parameter("shift", shift));
+        }
+        let lowest = times
+            .windows(2)
+            .map(|w| (zero_rates(w[1]) * w[1] - zero_rates(w[0]) * w[0]) / (w[1] - w[0]))
+            .fold(f64::INFINITY, f64::min);
+        if lowest <= -shift {
+            return Err(ModelError::tree_construction_failed(format!(
+                "lognormal shift {shift} does not cover forward rate {lowest}"
+            )));
+        }
+        Ok(Self::build_on_grid(
+            zero_rates,
+            a,
+            sigma,
+            times,
+            Some(shift),
+        ))
+    }
+
+    fn build_on_grid<F>(
+        zero_rates: F,
+        a: f64,
+        sigma: f64,
+        times: &[f64],
+        shift: Option<f64>,
+    ) -> Self
     where
         F: Fn(f64) -> f64,
     {
@@ -151,53 +208,50 @@ impl TrinomialTree {
             dx.push(sigma * (3.0 * d).sqrt());
         }
 
-        // 0.184/(a·Δt) is the minimum j_max for non-negative boundary
-        // probabilities (Hagan-Brace); take the worst-case dt so every
-        // layer is safe.
-        let min_dt = dt.iter().copied().fold(f64::INFINITY, f64::min);
-        let j_max = ((0.184 / (a * min_dt)).ceil().max(1.0)) as i32;
+        // Node ranges per layer: each layer holds the children of the one
+        // before. Targets rise with j, so the outermost nodes bound them.
+        let mut j_min = vec![0i32; steps + 1];
+        let mut j_max = vec![0i32; steps + 1];
+        for i in 0..steps {
+            j_min[i + 1] = branching_at(j_min[i], m[i], dx[i], dx[i + 1]).k - 1;
+            j_max[i + 1] = branching_at(j_max[i], m[i], dx[i], dx[i + 1]).k + 1;
+        }
 
-        // Forward induction with Arrow-Debreu prices Q[i][j+j_max].
-        let row_len = (2 * j_max + 1) as usize;
+        // Forward induction with Arrow-Debreu prices Q[i][j - j_min[i]].
         let mut alpha = vec![0.0f64; steps];
-        let mut q = vec![0.0f64; row_len];
-        q[j_max as usize] = 1.0;
-        let mut q_next = vec![0.0f64; row_len];
+        let mut q = vec![1.0f64];
 
         for (i, alpha_i) in alpha.iter_mut().enumerate().take(steps) {
-            let band = (i as i32).min(j_max);
             let dx_i = dx[i];
             let dt_i = dt[i];
 
             // exp(-α[i]·dt[i]) · Σ_j Q[i][j] · exp(-x_j·dt[i]) = D(t_{i+1})
             let mut numer = 0.0f64;
-            for j in -band..=band {
-                let qij = q[(j + j_max) as usize];
+            for (j, &qij) in (j_min[i]..=j_max[i]).zip(&q) {
                 if qij > 0.0 {
                     numer += qij * (-(j as f64 * dx_i) * dt_i).exp();
                 }
             }
             let d_next = (-zero_rates(times[i + 1]) * times[i + 1]).exp();
             *alpha_i = (numer / d_next).max(1e-300).ln() / dt_i;
+            if let Some(shift) = shift {
+                *alpha_i = fit_lognormal_alpha(&q, j_min[i], dx_i, dt_i, shift, *alpha_i, d_next);
+            }
 
             // Propagate Q[i] → Q[i+1].
-            q_next.fill(0.0);
-            for j in -band..=band {
-                let qij = q[(j + j_max) as usize];
+            let mut q_next = vec![0.0f64; (j_max[i + 1] - j_min[i + 1] + 1) as usize];
+            for (j, &qij) in (j_min[i]..=j_max[i]).zip(&q) {
                 if qij == 0.0 {
                     continue;
                 }
-                let br = branching_at(j, j_max, m[i], dx_i, dx[i + 1]);
-                let weight = qij * (-(*alpha_i + j as f64 * dx_i) * dt_i).exp();
-                let band_next = ((i + 1) as i32).min(j_max);
+                let br = branching_at(j, m[i], dx_i, dx[i + 1]);
+                let r = short_rate(shift, *alpha_i, j as f64 * dx_i);
+                let weight = qij * (-r * dt_i).exp();
                 for (offset, p) in [(1, br.pu), (0, br.pm), (-1, br.pd)] {
-                    let child = br.k + offset;
-                    if child.abs() <= band_next {
-                        q_next[(child + j_max) as usize] += weight * p;
-                    }
+                    q_next[(br.k + offset - j_min[i + 1]) as usize] += weight * p;
                 }
             }
-            std::mem::swap(&mut q, &mut q_next);
+            q = q_next;
         }
 
         Self {
@@ -205,9 +259,11 @@ impl TrinomialTree {
             times: times.to_vec(),
             dt,
             dx,
+            j_min,
             j_max,
             m,
             alpha,
+            shift,
         }
     }
 
@@ -234,38 +290,75 @@ impl TrinomialTree {
         C: FnMut(usize) -> f64,
         K: FnMut(usize) -> Option<f64>,
     {
-        let row_len = (2 * self.j_max + 1) as usize;
         let n = self.steps;
+        let width = |i: usize| (self.j_max[i] - self.j_min[i] + 1) as usize;
 
         let terminal = cashflow_at(n);
-        let mut values = vec![terminal; row_len];
-        let mut new_values = vec![0.0f64; row_len];
+        let mut values = vec![terminal; width(n)];
 
         for i in (0..n).rev() {
-            let band = (i as i32).min(self.j_max);
             let dx_i = self.dx[i];
             let dx_next = self.dx[i + 1];
             let dt_i = self.dt[i];
             let m_i = self.m[i];
+            let lo_next = self.j_min[i + 1];
             let cap_at_i = if i > 0 { call_price_at(i) } else { None };
             let cf_i = cashflow_at(i);
-            new_values.fill(0.0);
-            for j in -band..=band {
-                let br = branching_at(j, self.j_max, m_i, dx_i, dx_next);
-                let r = self.alpha[i] + j as f64 * dx_i + oas;
-                let df = (-r * dt_i).exp();
-                let v_up = values[(br.k + 1 + self.j_max) as usize];
-                let v_mid = values[(br.k + self.j_max) as usize];
-                let v_down = values[(br.k - 1 + self.j_max) as usize];
-                let cont = df * (br.pu * v_up + br.pm * v_mid + br.pd * v_down);
-                let capped = cap_at_i.map_or(cont, |cap| cont.min(cap));
-                new_values[(j + self.j_max) as usize] = capped + cf_i;
-            }
-            std::mem::swap(&mut values, &mut new_values);
+            let new_values = (self.j_min[i]..=self.j_max[i])
+                .map(|j| {
+                    let br = branching_at(j, m_i, dx_i, dx_next);
+                    let r = short_rate(self.shift, self.alpha[i], j as f64 * dx_i) + oas;
+                    let df = (-r * dt_i).exp();
+                    let v_up = values[(br.k + 1 - lo_next) as usize];
+                    let v_mid = values[(br.k - lo_next) as usize];
+                    let v_down = values[(br.k - 1 - lo_next) as usize];
+                    let cont = df * (br.pu * v_up + br.pm * v_mid + br.pd * v_down);
+                    let capped = cap_at_i.map_or(cont, |cap| cont.min(cap));
+                    capped + cf_i
+                })
+                .collect();
+            values = new_values;
         }
 
-        values[self.j_max as usize]
+        values[0]
+    }
+}
+
+/// Newton solve for the shifted-lognormal level at one layer:
+/// `Σ_j Q[j] · exp(-(exp(α + x_j) - shift)·dt) = D(t_{i+1})`. The left side
+/// falls monotonically in `α`; `hw_alpha` (the normal-model level, roughly
+/// the forward rate) seeds it.
+fn fit_lognormal_alpha(
+    q: &[f64],
+    j_min: i32,
+    dx: f64,
+    dt: f64,
+    shift: f64,
+    hw_alpha: f64,
+    d_next: f64,
+) -> f64 {
+    let mut alpha = (hw_alpha + shift).max(1e-8).ln();
+    for _ in 0..100 {
+        let mut value = -d_next;
+        let mut slope = 0.0;
+        for (j, &qij) in (j_min..).zip(q) {
+            if qij > 0.0 {
+                let level = (alpha + j as f64 * dx).exp();
+                let disc = qij * (-(level - shift) * dt).exp();
+                value += disc;
+                slope -= disc * level * dt;
+            }
+        }
+        if slope == 0.0 {
+            break;
+        }
+        let step = value / slope;
+        alpha -= step;
+        if step.abs() < 1e-14 {
+            break;
+        }
     }
+    alpha
 }
 
 #[cfg(test)]
@@ -293,6 +386,38 @@ mod tests {
         assert!((pv - 100.0_f64 * (-zero(5.0) * 5.0).exp()).abs() < 5e-5);
     }
 
+    #[test]
+    fn shorter_step_stays_on_lattice() {
+        // Once the lattice stops widening, a step shorter than the one
+        // before it shrinks dx and pushes the outer targets past the edge.
+        let mut times: Vec<f64> = (0..=30).map(|i| f64::from(i) * 0.1).collect();
+        times.extend([3.075, 3.5, 4.0]);
+        let tree = TrinomialTree::build_hull_white_on_grid(flat(0.05), 0.1, 0.01, &times);
+        let n = tree.steps;
+        let pv = tree.price(0.0, |i| if i == n { 100.0 } else { 0.0 }, |_| None);
+        assert!((pv - 100.0_f64 * (-0.2_f64).exp()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn branch_probabilities_stay_in_unit_interval() {
+        // A step shorter than the one before it pushes the outer targets
+        // beyond the previous layer's range; the next layer widens rather
+        // than clipping them into negative probabilities.
+        let mut times: Vec<f64> = (0..=30).map(|i| f64::from(i) * 0.1).collect();
+        times.extend([3.01, 3.5, 4.0]);
+        let tree = TrinomialTree::build_hull_white_on_grid(flat(0.05), 0.1, 0.01, &times);
+        for i in 0..tree.steps {
+            for j in tree.j_min[i]..=tree.j_max[i] {
+                let br = branching_at(j, tree.m[i], tree.dx[i], tree.dx[i + 1]);
+                for p in [br.pu, br.pm, br.pd] {
+                    assert!((0.0..=1.0).contains(&p), "layer {i} node {j}: {p}");
+                }
+                assert!(br.k > tree.j_min[i + 1] && br.k < tree.j_max[i + 1]);
+            }
+        }
+        assert!(tree.j_max[31] > tree.j_max[30]);
+    }
+
     #[test]
     fn intermediate_step_recovers_curve() {
         let zero = |t: f64| 0.04 - 0.001 * t;
@@ -390,4 +515,35 @@ mod tests {
         assert_eq!(tree.step_at_time(3.0), Some(tree.steps));
         assert_eq!(tree.step_at_time(0.123_456_789), None);
     }
+
+    #[test]
+    fn shifted_lognormal_recovers_negative_rate_discount() {
+        let zero = |t: f64| -0.005 + 0.001 * t;
+        let times = build_event_grid(5.0, &[1.0, 2.0, 3.0, 4.0], 100);
+        let tree =
+            TrinomialTree::build_shifted_lognormal_on_grid(zero, 0.03, 0.2, 0.03, &times).unwrap();
+        let n = tree.steps;
+        let pv = tree.price(0.0, |i| if i == n { 100.0 } else { 0.0 }, |_| None);
+        assert!((pv - 100.0_f64 * (-zero(5.0) * 5.0).exp()).abs() < 1e-6);
+
+        // Every node rate stays above -shift.
+        for (i, &alpha) in tree.alpha.iter().enumerate() {
+            let x_min = f64::from(tree.j_min[i]) * tree.dx[i];
+            assert!(short_rate(tree.shift, alpha, x_min) > -0.03);
+        }
+    }
+
+    #[test]
+    fn shifted_lognormal_rejects_uncovered_forwards() {
+        let zero = |t: f64| -0.02 - 0.001 * t;
+        let times = build_event_grid(5.0, &[1.0, 2.0], 50);
+        let negative =
+            TrinomialTree::build_shifted_lognormal_on_grid(zero, 0.03, 0.2, -0.01, &times);
+        assert!(matches!(negative, Err(ModelError::InvalidParameter { .. })));
+        let narrow = TrinomialTree::build_shifted_lognormal_on_grid(zero, 0.03, 0.2, 0.01, &times);
+        assert!(matches!(
+            narrow,
+            Err(ModelError::TreeConstructionFailed { .. })
+        ));
+    }
 }
@@ -13,4 +13,6 @@ pub use yield_engine::{
     bond_equivalent_yield, current_yield_simple, discount_yield, simple_yield, StandardYieldEngine,
     YieldEngine, YieldEngineResult,
 };
-pub use yield_solver::{current_yield, current_yield_from_bond, YieldResult, YieldSolver};
+pub use yield_solver::{
+    current_yield, current_yield_from_bond, YieldResult, YieldSearch, YieldSolver,
+};
@@ -228,17 +228,18 @@ impl StandardYieldEngine {
         }
     }
 
-    /// Calculates present value at a given yield using the specified compounding.
+    /// Calculates present value at a given yield under the rules' convention.
     fn pv_at_yield(
         &self,
         cf_data: &[(f64, f64)],
         yield_rate: f64,
         rules: &YieldCalculationRules,
     ) -> f64 {
+        let final_time = cf_data.last().map_or(0.0, |(years, _)| *years);
         cf_data
             .iter()
             .map(|(years, amount)| {
-                let df = rules.compounding.discount_factor(yield_rate, *years);
+                let df = rules.discount_factor(yield_rate, *years, final_time);
                 amount * df
             })
             .sum()
@@ -251,12 +252,11 @@ impl StandardYieldEngine {
         yield_rate: f64,
         rules: &YieldCalculationRules,
     ) -> f64 {
+        let final_time = cf_data.last().map_or(0.0, |(years, _)| *years);
         cf_data
             .iter()
             .map(|(years, amount)| {
-                let ddf_dy = rules
-                    .compounding
-                    .discount_factor_derivative(yield_rate, *years);
+                let ddf_dy = rules.discount_factor_derivative(yield_rate, *years, final_time);
                 amount * ddf_dy
             })
             .sum()
@@ -21,11 +21,11 @@ use rust_decimal::Decimal;
 
 use convex_core::daycounts::DayCountConvention;
 use convex_core::types::{Date, Frequency};
-use convex_math::solvers::{brent, newton_raphson, SolverConfig};
+use convex_math::solvers::{brent, hybrid, newton_raphson, SolverConfig, SolverResult};
 
 use crate::error::{BondError, BondResult};
 use crate::traits::{BondCashFlow, FixedCouponBond};
-use crate::types::YieldConvention;
+use crate::types::{FirstPeriodDiscounting, YieldConvention};
 
 /// Project future cash flows into `(year_fraction_from_settlement, amount)` pairs
 /// suitable for PV / duration / convexity loops.
@@ -149,6 +149,25 @@ pub(crate) fn project_discount_fractions(
         .collect()
 }
 
+/// Yield range searched for a root: −50% to +200%.
+pub const YIELD_SEARCH_RANGE: (f64, f64) = (-0.5, 2.0);
+
+/// Grid step used when bracketing the root within [`YIELD_SEARCH_RANGE`].
+const BRACKET_STEP: f64 = 0.05;
+
+/// How [`YieldSolver`] searches for the yield.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum YieldSearch {
+    /// Newton-Raphson from the initial guess. The root is bracketed only if
+    /// Newton fails or leaves the −50% to +200% search range.
+    #[default]
+    NewtonFirst,
+    /// Bracket the root within −50% to +200% before any Newton
+    /// step. Suits distressed bonds priced far from par, where Newton from
+    /// the guess is likely to diverge.
+    Bracketed,
+}
+
 /// Result of a yield calculation.
 #[derive(Debug, Clone, Copy)]
 pub struct YieldResult {
@@ -158,6 +177,9 @@ pub struct YieldResult {
     pub iterations: u32,
     /// Final residual (should be near zero).
     pub residual: f64,
+    /// Whether the price residual met the solver tolerance. `false` means the
+    /// solver stopped on step size alone.
+    pub converged: bool,
 }
 
 impl YieldResult {
@@ -176,14 +198,21 @@ impl YieldResult {
 
 /// Yield-to-maturity solver.
 ///
-/// Uses Bloomberg YAS methodology with Newton-Raphson iteration
-/// and Brent's method fallback.
+/// Uses Bloomberg YAS methodology. By default Newton-Raphson runs from the
+/// initial guess; only if it fails or leaves the −50% to +200% range is the
+/// root bracketed outward from the guess (see [`YieldSearch`]). Within the
+/// bracket the hybrid solver takes Newton steps and falls back to Brent's
+/// method when they diverge.
 #[derive(Debug, Clone)]
 pub struct YieldSolver {
     /// Solver configuration.
     config: SolverConfig,
     /// Yield convention to use.
     convention: YieldConvention,
+    /// Discounting of the fractional first period (periodic conventions).
+    first_period: FirstPeriodDiscounting,
+    /// Root search strategy.
+    search: YieldSearch,
 }
 
 impl Default for YieldSolver {
@@ -195,14 +224,22 @@ impl Default for YieldSolver {
 impl YieldSolver {
     /// Creates a new yield solver with default settings.
     ///
+    /// Use [`with_tolerance`](Self::with_tolerance) and
+    /// [`with_max_iterations`](Self::with_max_iterations) to trade speed for
+    /// precision.
+    ///
     /// Default tolerance: 1e-10
     /// Default max iterations: 100
     /// Default convention: Street Convention
+    /// Default first period: compound
+    /// Default search: Newton first
     #[must_use]
     pub fn new() -> Self {
         Self {
             config: SolverConfig::new(1e-10, 100),
             convention: YieldConvention::StreetConvention,
+            first_period: FirstPeriodDiscounting::Compound,
+            search: YieldSearch::NewtonFirst,
         }
     }
 
@@ -213,14 +250,31 @@ impl YieldSolver {
         self
     }
 
-    /// Sets the solver tolerance.
+    /// Sets how the fractional first period is discounted under periodic
+    /// conventions. Street convention compounds it; use
+    /// [`FirstPeriodDiscounting::Simple`] for simple interest to the next
+    /// coupon.
+    #[must_use]
+    pub fn with_first_period_discounting(mut self, first_period: FirstPeriodDiscounting) -> Self {
+        self.first_period = first_period;
+        self
+    }
+
+    /// Sets the root search strategy.
+    #[must_use]
+    pub fn with_search(mut self, search: YieldSearch) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Sets the solver tolerance (on the dirty price residual).
     #[must_use]
     pub fn with_tolerance(mut self, tolerance: f64) -> Self {
         self.config = SolverConfig::new(tolerance, self.config.max_iterations);
         self
     }
 
-    /// Sets the maximum iterations.
+    /// Sets the maximum iterations for each Newton and Brent attempt.
     #[must_use]
     pub fn with_max_iterations(mut self, max_iterations: u32) -> Self {
         self.config = SolverConfig::new(self.config.tolerance, max_iterations);
@@ -233,6 +287,18 @@ impl YieldSolver {
         self.convention
     }
 
+    /// Returns the first period discounting.
+    #[must_use]
+    pub const fn first_period_discounting(&self) -> FirstPeriodDiscounting {
+        self.first_period
+    }
+
+    /// Returns the root search strategy.
+    #[must_use]
+    pub const fn search(&self) -> YieldSearch {
+        self.search
+    }
+
     /// Solves for yield given cash flows and price.
     ///
     /// # Arguments
@@ -291,25 +357,15 @@ impl YieldSolver {
         // Analytical derivative for Newton-Raphson
         let derivative = |y: f64| self.pv_derivative(&cf_data, y, periods_per_year);
 
-        // Try Newton-Raphson first
-        match newton_raphson(objective, derivative, initial_guess, &self.config) {
-            Ok(result) => Ok(YieldResult {
-                yield_value: result.root,
-                iterations: result.iterations,
-                residual: result.residual,
-            }),
-            Err(_) => {
-                // Fallback to Brent's method with wider bracket
-                self.solve_with_brent(objective, initial_guess)
-            }
-        }
+        let floor = self.yield_floor(&cf_data, periods_per_year);
+        self.solve_bracketed(objective, derivative, initial_guess, target, floor)
     }
 
     /// Solves for yield from pre-computed (year_fraction, amount) pairs.
     ///
     /// Lower-level companion to [`Self::solve`] for callers that already have flat
     /// arrays of discount times and cash flow amounts (e.g. a Bloomberg YAS
-    /// pipeline). Uses Newton-Raphson with Brent's method as a fallback.
+    /// pipeline). Uses the same Newton/Brent search as [`Self::solve`].
     pub fn solve_primitive(
         &self,
         cash_flows: &[(f64, f64)],
@@ -327,42 +383,103 @@ impl YieldSolver {
             |y: f64| self.pv_at_yield(cash_flows, y, periods_per_year) - target_dirty_price;
         let derivative = |y: f64| self.pv_derivative(cash_flows, y, periods_per_year);
 
-        match newton_raphson(objective, derivative, initial_guess, &self.config) {
-            Ok(result) => Ok(YieldResult {
-                yield_value: result.root,
-                iterations: result.iterations,
-                residual: result.residual,
-            }),
-            Err(_) => self.solve_with_brent(objective, initial_guess),
-        }
+        let floor = self.yield_floor(cash_flows, periods_per_year);
+        self.solve_bracketed(
+            objective,
+            derivative,
+            initial_guess,
+            target_dirty_price,
+            floor,
+        )
     }
 
-    /// Solves using Brent's method when Newton-Raphson fails.
-    fn solve_with_brent<F>(&self, objective: F, initial_guess: f64) -> BondResult<YieldResult>
+    /// Under [`YieldSearch::NewtonFirst`], solves from `initial_guess` with
+    /// Newton-Raphson, keeping the root if it lies within
+    /// [`YIELD_SEARCH_RANGE`] above `floor`.
+    ///
+    /// Otherwise the root is bracketed outward from the guess within that
+    /// range and solved with the hybrid Newton/Brent solver, falling back to
+    /// plain Brent if the hybrid root leaves the bracket.
+    fn solve_bracketed<F, DF>(
+        &self,
+        objective: F,
+        derivative: DF,
+        initial_guess: f64,
+        target: f64,
+        floor: f64,
+    ) -> BondResult<YieldResult>
     where
         F: Fn(f64) -> f64,
+        DF: Fn(f64) -> f64,
     {
-        // Try to find a bracket
-        let brackets = [
-            (initial_guess - 0.1, initial_guess + 0.1),
-            (-0.1, 0.5),
-            (-0.2, 1.0),
-            (-0.5, 2.0),
-        ];
+        let (lo, hi) = YIELD_SEARCH_RANGE;
+        // Stay clear of the pole where discounting breaks down
+        let lo = lo.max(floor + BRACKET_STEP / 100.0);
+        let guess = if initial_guess.is_finite() {
+            initial_guess.clamp(lo, hi)
+        } else {
+            0.05
+        };
 
-        for (a, b) in brackets {
-            if let Ok(result) = brent(&objective, a, b, &self.config) {
-                return Ok(YieldResult {
-                    yield_value: result.root,
-                    iterations: result.iterations,
-                    residual: result.residual,
-                });
+        if self.search == YieldSearch::NewtonFirst {
+            if let Ok(result) = newton_raphson(&objective, &derivative, guess, &self.config) {
+                if (lo..=hi).contains(&result.root)
+                    && result.residual.abs() <= self.config.tolerance
+                {
+                    return Ok(self.to_yield_result(&result));
+                }
             }
         }
 
-        Err(BondError::YieldConvergenceFailed {
-            iterations: self.config.max_iterations,
-        })
+        let (a, b) = find_yield_bracket(&objective, guess, (lo, hi)).ok_or_else(|| {
+            BondError::pricing_failed(format!(
+                "no yield between {:.0}% and {:.0}% reproduces dirty price {target:.6} \
+                 (PV ranges from {:.6} to {:.6})",
+                lo * 100.0,
+                hi * 100.0,
+                objective(hi) + target,
+                objective(lo) + target,
+            ))
+        })?;
+        let guess = guess.clamp(a, b);
+        let result = match hybrid(&objective, &derivative, guess, Some((a, b)), &self.config) {
+            Ok(result) if (a..=b).contains(&result.root) => result,
+            _ => brent(&objective, a, b, &self.config).map_err(|_| {
+                BondError::YieldConvergenceFailed {
+                    iterations: self.config.max_iterations,
+                }
+            })?,
+        };
+        Ok(self.to_yield_result(&result))
+    }
+
+    /// Lowest yield at which every discount factor stays positive and
+    /// finite under the convention.
+    fn yield_floor(&self, cf_data: &[(f64, f64)], periods_per_year: f64) -> f64 {
+        match self.convention {
+            YieldConvention::Continuous => f64::NEG_INFINITY,
+            YieldConvention::TrueYield => -1.0,
+            YieldConvention::SimpleYield => {
+                let last = cf_data.last().map_or(0.0, |(years, _)| *years);
+                if last > 0.0 {
+                    -1.0 / last
+                } else {
+                    f64::NEG_INFINITY
+                }
+            }
+            _ => -periods_per_year.max(1.0),
+        }
+    }
+
+    /// Wraps a raw solver result, flagging whether the residual is within
+    /// tolerance.
+    fn to_yield_result(&self, result: &SolverResult) -> YieldResult {
+        YieldResult {
+            yield_value: result.root,
+            iterations: result.iterations,
+            residual: result.residual,
+            converged: result.residual.abs() <= self.config.tolerance,
+        }
     }
 
     /// Calculates present value at a given yield.
@@ -405,11 +522,18 @@ impl YieldSolver {
             }
             _ => {
                 // Street Convention and others: periodic compounding
+                let first = self.first_period_fraction(cf_data, periods_per_year);
                 cf_data
                     .iter()
                     .map(|(years, amount)| {
                         let periods = years * periods_per_year;
-                        let df = 1.0 / (1.0 + rate_per_period).powf(periods);
+                        let df = match first {
+                            Some(w) => {
+                                1.0 / ((1.0 + rate_per_period * w)
+                                    * (1.0 + rate_per_period).powf(periods - w))
+                            }
+                            None => 1.0 / (1.0 + rate_per_period).powf(periods),
+                        };
                         amount * df
                     })
                     .sum()
@@ -417,6 +541,18 @@ impl YieldSolver {
         }
     }
 
+    /// Fraction of a period to the first cash flow, when it is discounted at
+    /// simple interest; `None` when every period compounds.
+    fn first_period_fraction(&self, cf_data: &[(f64, f64)], periods_per_year: f64) -> Option<f64> {
+        if self.first_period == FirstPeriodDiscounting::Compound {
+            return None;
+        }
+        let periods = cf_data.first()?.0 * periods_per_year;
+        // A long first period: the fraction is the part beyond whole periods
+        let w = periods - (periods.ceil() - 1.0).max(0.0);
+        Some(w.clamp(0.0, 1.0))
+    }
+
     /// Derivative of PV with respect to yield.
     fn pv_derivative(&self, cf_data: &[(f64, f64)], yield_rate: f64, periods_per_year: f64) -> f64 {
         let rate_per_period = yield_rate / periods_per_year;
@@ -446,15 +582,26 @@ impl YieldSolver {
                     amount * ddf_dy
                 })
                 .sum(),
-            _ => cf_data
-                .iter()
-                .map(|(years, amount)| {
-                    let periods = years * periods_per_year;
-                    let df = 1.0 / (1.0 + rate_per_period).powf(periods);
-                    let ddf_dy = -periods * df / (1.0 + rate_per_period) / periods_per_year;
-                    amount * ddf_dy
-                })
-                .sum(),
+            _ => {
+                let first = self.first_period_fraction(cf_data, periods_per_year);
+                cf_data
+                    .iter()
+                    .map(|(years, amount)| {
+                        let periods = years * periods_per_year;
+                        let ddf_dy = if let Some(w) = first {
+                            let n = periods - w;
+                            let df = 1.0
+                                / ((1.0 + rate_per_period * w) * (1.0 + rate_per_period).powf(n));
+                            -df * (w / (1.0 + rate_per_period * w) + n / (1.0 + rate_per_period))
+                                / periods_per_year
+                        } else {
+                            let df = 1.0 / (1.0 + rate_per_period).powf(periods);
+                            -periods * df / (1.0 + rate_per_period) / periods_per_year
+                        };
+                        amount * ddf_dy
+                    })
+                    .sum()
+            }
         }
     }
 
@@ -491,6 +638,51 @@ impl YieldSolver {
     }
 }
 
+/// Finds an interval within `range` where `objective` changes sign,
+/// stepping outward from `guess` in both directions.
+fn find_yield_bracket<F>(objective: &F, guess: f64, range: (f64, f64)) -> Option<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    let (lo, hi) = range;
+    let f_guess = objective(guess);
+    if !f_guess.is_finite() {
+        return None;
+    }
+    if f_guess == 0.0 {
+        return Some((guess, guess));
+    }
+    let (mut lower, mut f_lower) = (guess, f_guess);
+    let (mut upper, mut f_upper) = (guess, f_guess);
+    while lower > lo || upper < hi {
+        if upper < hi {
+            let next = (upper + BRACKET_STEP).min(hi);
+            let f_next = objective(next);
+            if f_next.is_finite() {
+                if f_upper * f_next <= 0.0 {
+                    return Some((upper, next));
+                }
+                (upper, f_upper) = (next, f_next);
+            } else {
+                upper = hi;
+            }
+        }
+        if lower > lo {
+            let next = (lower - BRACKET_STEP).max(lo);
+            let f_next = objective(next);
+            if f_next.is_finite() {
+                if f_lower * f_next <= 0.0 {
+                    return Some((next, lower));
+                }
+                (lower, f_lower) = (next, f_next);
+            } else {
+                lower = lo;
+            }
+        }
+    }
+    None
+}
+
 /// Calculates current yield.
 ///
 /// Current yield = Annual Coupon / Clean Price
@@ -799,6 +991,116 @@ mod tests {
         assert!(diff < 0.0001, "Price roundtrip error: {}", diff);
     }
 
+    #[test]
+    fn test_deep_discount_newton_divergence_recovers() {
+        // 10-year 5% semi-annual bond at 20, settling mid-period: YTM ≈ 32.7%.
+        let cf_data: Vec<(f64, f64)> = (1..=20)
+            .map(|k| {
+                let amount = if k == 20 { 102.5 } else { 2.5 };
+                (f64::from(k) / 2.0 - 0.1, amount)
+            })
+            .collect();
+        let solver = YieldSolver::new();
+        let objective = |y: f64| solver.pv_at_yield(&cf_data, y, 2.0) - 20.0;
+        let derivative = |y: f64| solver.pv_derivative(&cf_data, y, 2.0);
+
+        // Plain Newton from 150% overshoots below -200%, where the periodic
+        // discount factor is undefined, and never recovers.
+        let naive = convex_math::solvers::newton_raphson(
+            objective,
+            derivative,
+            1.5,
+            &SolverConfig::new(1e-10, 100),
+        );
+        assert!(naive.is_err());
+
+        let result = solver.solve_primitive(&cf_data, 20.0, 2.0, 1.5).unwrap();
+        assert!(result.converged);
+        assert!((result.yield_value - 0.3268).abs() < 1e-4);
+        assert!(objective(result.yield_value).abs() < 1e-8);
+
+        // Bracketing first skips the failed Newton run and reaches the same root.
+        let bracketed = solver
+            .clone()
+            .with_search(YieldSearch::Bracketed)
+            .solve_primitive(&cf_data, 20.0, 2.0, 1.5)
+            .unwrap();
+        assert!(bracketed.converged);
+        assert!((bracketed.yield_value - result.yield_value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_newton_from_guess_skips_bracket_scan() {
+        // 5-year 5% semi-annual bond at par: a 5% guess is already the root.
+        let cf_data: Vec<(f64, f64)> = (1..=10)
+            .map(|k| (f64::from(k) / 2.0, if k == 10 { 102.5 } else { 2.5 }))
+            .collect();
+        let solver = YieldSolver::new();
+        let calls = std::cell::Cell::new(0);
+        let objective = |y: f64| {
+            calls.set(calls.get() + 1);
+            solver.pv_at_yield(&cf_data, y, 2.0) - 100.0
+        };
+        let derivative = |y: f64| solver.pv_derivative(&cf_data, y, 2.0);
+
+        let result = solver
+            .solve_bracketed(objective, derivative, 0.045, 100.0, -2.0)
+            .unwrap();
+        assert!((result.yield_value - 0.05).abs() < 1e-10);
+        // A scan of the -50%..200% range alone would take ~50 evaluations.
+        assert!(calls.get() <= 5, "{} objective calls", calls.get());
+    }
+
+    #[test]
+    fn test_no_yield_in_range_is_descriptive() {
+        let cf_data = vec![(0.5, 2.5), (1.0, 102.5)];
+        let err = YieldSolver::new()
+            .solve_primitive(&cf_data, 1_000.0, 2.0, 0.05)
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("no yield between -50% and 200%"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_iteration_budget_on_long_zero() {
+        // 100-year zero at 0.5: the solve starts from 0% and needs several
+        // Newton steps to reach a yield above 5%.
+        let settlement = date(2025, 1, 15);
+        let cash_flows = vec![BondCashFlow::principal(date(2125, 1, 15), dec!(100))];
+        let solve = |solver: &YieldSolver| {
+            solver.solve(
+                &cash_flows,
+                dec!(0.5),
+                dec!(0),
+                settlement,
+                DayCountConvention::Thirty360US,
+                Frequency::SemiAnnual,
+            )
+        };
+
+        let starved = YieldSolver::new().with_max_iterations(3);
+        assert!(matches!(
+            solve(&starved),
+            Err(BondError::YieldConvergenceFailed { iterations: 3 })
+        ));
+
+        let result = solve(&YieldSolver::new().with_max_iterations(200)).unwrap();
+        assert!(result.converged);
+        assert!(result.iterations > 3);
+        assert!(result.residual.abs() <= 1e-10);
+
+        let expected = 2.0 * (200f64.powf(1.0 / 200.0) - 1.0);
+        assert!((result.yield_value - expected).abs() < 1e-9);
+
+        // A looser tolerance trades precision for fewer iterations.
+        let loose = solve(&YieldSolver::new().with_tolerance(1e-4)).unwrap();
+        assert!(loose.converged);
+        assert!(loose.iterations <= result.iterations);
+    }
+
     #[test]
     fn test_current_yield() {
         let annual_coupon = dec!(7.5); // 7.5% coupon on 100 face
@@ -816,7 +1118,8 @@ mod tests {
         // Boeing 7.5% 06/15/2025
         // Settlement: 04/29/2020
         // Clean Price: 110.503
-        // Expected YTM (Street): 4.905895% (approximately)
+        // Expected YTM (Street): 5.141769% (see
+        // `test_boeing_street_convention_price`)
 
         let settlement = date(2020, 4, 29);
         let _maturity = date(2025, 6, 15);
@@ -898,6 +1201,140 @@ mod tests {
         );
     }
 
+    /// Boeing 7.5% 06/15/2025 flows with their 30/360 accrual periods.
+    fn boeing_cash_flows() -> Vec<BondCashFlow> {
+        let mut cash_flows = Vec::new();
+        let mut start = date(2019, 12, 15);
+        for year in 2020..=2025 {
+            for month in [6, 12] {
+                let end = date(year, month, 15);
+                if end > date(2025, 6, 15) {
+                    break;
+                }
+                let flow = if end == date(2025, 6, 15) {
+                    BondCashFlow::coupon_and_principal(end, dec!(3.75), dec!(100))
+                } else {
+                    BondCashFlow::coupon(end, dec!(3.75))
+                };
+                cash_flows.push(flow.with_accrual(start, end));
+                start = end;
+            }
+        }
+        cash_flows
+    }
+
+    #[test]
+    fn test_boeing_street_convention_price() {
+        // Boeing 7.5% 06/15/2025 at 110.503 clean for 04/29/2020 settlement.
+        // Accrued is 134/180 of the 3.75 coupon; 46 of the 180 days in the
+        // current period remain. Street convention compounds that fraction:
+        //   dirty = v^w × (3.75 × (1 − v^11) / (1 − v) + 100 × v^10)
+        // with v = 1 / (1 + y/2) and w = 46/180. Evaluated in closed form
+        // below, independently of the solver, 5.141769% reprices to 110.503.
+        //
+        // Bloomberg quotes 4.905895% at this price. That 23.6 bps gap is
+        // still open (see the YAS calculator's validation target).
+        let settlement = date(2020, 4, 29);
+        let accrued = dec!(3.75) * dec!(134) / dec!(180);
+        let cash_flows = boeing_cash_flows();
+
+        let closed_form_clean = |y: f64| {
+            let v = 1.0 / (1.0 + y / 2.0);
+            let w = 46.0 / 180.0;
+            v.powf(w) * (3.75 * (1.0 - v.powi(11)) / (1.0 - v) + 100.0 * v.powi(10))
+                - 3.75 * 134.0 / 180.0
+        };
+        assert!((closed_form_clean(0.05141769) - 110.503).abs() < 1e-5);
+
+        let street = YieldSolver::new().with_convention(YieldConvention::StreetConvention);
+        assert_eq!(
+            street.first_period_discounting(),
+            FirstPeriodDiscounting::Compound
+        );
+        let ytm = street
+            .solve(
+                &cash_flows,
+                dec!(110.503),
+                accrued,
+                settlement,
+                DayCountConvention::Thirty360US,
+                Frequency::SemiAnnual,
+            )
+            .unwrap();
+        assert!(
+            (ytm.yield_value * 100.0 - 5.141769).abs() < 1e-6,
+            "Boeing street yield: expected 5.141769%, got {:.6}%",
+            ytm.yield_value * 100.0
+        );
+
+        // And the yield prices back to the reference price
+        let clean = street.clean_price_from_yield(
+            &cash_flows,
+            ytm.yield_value,
+            accrued,
+            settlement,
+            DayCountConvention::Thirty360US,
+            Frequency::SemiAnnual,
+        );
+        assert!(
+            (clean - 110.503).abs() < 1e-8,
+            "Boeing street clean price: expected 110.503, got {clean:.10}"
+        );
+    }
+
+    #[test]
+    fn test_simple_first_period_discounting() {
+        let settlement = date(2020, 4, 29);
+        let accrued = dec!(3.75) * dec!(134) / dec!(180);
+        let cash_flows = boeing_cash_flows();
+        let simple = YieldSolver::new()
+            .with_first_period_discounting(FirstPeriodDiscounting::Simple)
+            .with_tolerance(1e-12);
+
+        // Simple interest over 46/180 of a period vs compounding it
+        let y = 0.04905895;
+        let r: f64 = y / 2.0;
+        let w = 46.0 / 180.0;
+        let expected: f64 = (0..11)
+            .map(|n| {
+                let amount = if n == 10 { 103.75 } else { 3.75 };
+                amount / ((1.0 + r * w) * (1.0 + r).powi(n))
+            })
+            .sum();
+        let dirty = simple.dirty_price_from_yield(
+            &cash_flows,
+            y,
+            settlement,
+            DayCountConvention::Thirty360US,
+            Frequency::SemiAnnual,
+        );
+        assert!((dirty - expected).abs() < 1e-9);
+
+        // Simple interest over a fraction of a period discounts more than
+        // compounding it, for positive yields
+        let street = YieldSolver::new().dirty_price_from_yield(
+            &cash_flows,
+            y,
+            settlement,
+            DayCountConvention::Thirty360US,
+            Frequency::SemiAnnual,
+        );
+        assert!(dirty < street);
+
+        // The solver inverts the simple-first-period price
+        let ytm = simple
+            .solve(
+                &cash_flows,
+                Decimal::from_f64(dirty).unwrap() - accrued,
+                accrued,
+                settlement,
+                DayCountConvention::Thirty360US,
+                Frequency::SemiAnnual,
+            )
+            .unwrap();
+        assert!((ytm.yield_value - y).abs() < 1e-9);
+    }
+
     #[test]
     fn test_true_yield_convention() {
         let settlement = date(2025, 1, 15);
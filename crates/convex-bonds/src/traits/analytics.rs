@@ -29,7 +29,7 @@ use convex_core::types::{Date, Frequency};
 
 use crate::error::{BondError, BondResult};
 use crate::pricing::{YieldResult, YieldSolver};
-use crate::traits::Bond;
+use crate::traits::{Bond, BondCashFlow, CashFlowType};
 use crate::types::YieldConvention;
 
 /// Analytics extension trait for bonds.
@@ -115,6 +115,89 @@ pub trait BondAnalytics: Bond {
         )
     }
 
+    // ==================== Average Life ====================
+
+    /// Calculates the weighted average life of the remaining principal.
+    ///
+    /// WAL = `sum(t_i * principal_i) / sum(principal_i)` with `t_i` in
+    /// ACT/365 years from settlement. For a bullet bond this is simply the
+    /// time to maturity.
+    fn average_life(&self, settlement: Date) -> BondResult<f64> {
+        let days = self.average_life_days(settlement)?;
+        Ok(days / 365.0)
+    }
+
+    /// Calculates yield to average life from clean price.
+    ///
+    /// The bond is treated as a bullet maturing on its average-life date: the
+    /// whole outstanding principal is redeemed there, and coupons up to that
+    /// date are paid on the full outstanding balance. `clean_price` is per 100
+    /// of outstanding principal. For a bullet bond this equals the YTM.
+    fn yield_to_average_life(
+        &self,
+        settlement: Date,
+        clean_price: Decimal,
+        frequency: Frequency,
+    ) -> BondResult<YieldResult> {
+        let cash_flows = self.cash_flows(settlement);
+        let days = self.average_life_days(settlement)?;
+        let wal_date = settlement.add_days(days.round() as i64);
+
+        let principals = principal_amounts(&cash_flows, self.redemption_value());
+        let outstanding: Decimal = principals.iter().copied().sum();
+        let scale = Decimal::ONE_HUNDRED / outstanding;
+
+        // A coupon accrues on the balance before any paydown on the same date.
+        let mut balance = outstanding;
+        let mut paid_down = Decimal::ZERO;
+        let mut current_date = settlement;
+        let mut bullet_flows = Vec::new();
+        for (cf, &principal) in cash_flows
+            .iter()
+            .zip(&principals)
+            .filter(|(cf, _)| cf.date <= wal_date)
+        {
+            if cf.date != current_date {
+                balance -= paid_down;
+                paid_down = Decimal::ZERO;
+                current_date = cf.date;
+            }
+            let coupon = if cf.is_coupon() {
+                cf.amount - principal
+            } else {
+                Decimal::ZERO
+            };
+            if coupon > Decimal::ZERO && balance > Decimal::ZERO {
+                // Keep the accrual period so stub coupons discount correctly.
+                let mut flow = cf.clone();
+                flow.amount = coupon * outstanding / balance * scale;
+                flow.flow_type = CashFlowType::Coupon;
+                flow.factor = Decimal::ONE;
+                if cf.date == wal_date {
+                    flow.amount += Decimal::ONE_HUNDRED;
+                    flow.flow_type = CashFlowType::CouponAndPrincipal;
+                }
+                bullet_flows.push(flow);
+            }
+            paid_down += principal;
+        }
+        if bullet_flows.last().map_or(true, |cf| cf.date != wal_date) {
+            bullet_flows.push(BondCashFlow::principal(wal_date, Decimal::ONE_HUNDRED));
+        }
+
+        let accrued = self.accrued_interest(settlement) * scale;
+        let day_count = self.parse_day_count()?;
+        let solver = YieldSolver::new().with_convention(YieldConvention::StreetConvention);
+        solver.solve(
+            &bullet_flows,
+            clean_price,
+            accrued,
+            settlement,
+            day_count,
+            frequency,
+        )
+    }
+
     // ==================== Price Calculations ====================
 
     /// Calculates dirty price from yield.
@@ -412,6 +495,26 @@ pub trait BondAnalytics: Bond {
 
     // ==================== Helper Methods ====================
 
+    /// Principal-weighted average number of days from settlement.
+    fn average_life_days(&self, settlement: Date) -> BondResult<f64> {
+        let cash_flows = self.cash_flows(settlement);
+        let principals = principal_amounts(&cash_flows, self.redemption_value());
+        let mut weighted_days = 0.0;
+        let mut total_principal = 0.0;
+        for (cf, principal) in cash_flows.iter().zip(principals) {
+            let principal = principal.to_f64().unwrap_or(0.0);
+            weighted_days += settlement.days_between(&cf.date) as f64 * principal;
+            total_principal += principal;
+        }
+
+        if total_principal <= 0.0 {
+            return Err(BondError::InvalidSpec {
+                reason: "no outstanding principal".to_string(),
+            });
+        }
+        Ok(weighted_days / total_principal)
+    }
+
     /// Parses the day count convention string to its enum.
     fn parse_day_count(&self) -> BondResult<DayCountConvention> {
         let dcc_str = self.day_count_convention();
@@ -421,6 +524,31 @@ pub trait BondAnalytics: Bond {
     }
 }
 
+/// Principal repaid by each of `cash_flows` (sorted by date).
+///
+/// A `Principal` flow is all principal. A `CouponAndPrincipal` flow repays
+/// `redemption` times the drop in factor to the next later flow, or its whole
+/// remaining factor if it is the last flow, so a bullet redemption repays
+/// `redemption` and an amortizing payment only its paydown.
+#[must_use]
+pub fn principal_amounts(cash_flows: &[BondCashFlow], redemption: Decimal) -> Vec<Decimal> {
+    cash_flows
+        .iter()
+        .enumerate()
+        .map(|(i, cf)| match cf.flow_type {
+            CashFlowType::Principal => cf.amount,
+            CashFlowType::CouponAndPrincipal => {
+                let next_factor = cash_flows[i + 1..]
+                    .iter()
+                    .find(|next| next.date > cf.date)
+                    .map_or(Decimal::ZERO, |next| next.factor);
+                redemption * (cf.factor - next_factor).max(Decimal::ZERO)
+            }
+            _ => Decimal::ZERO,
+        })
+        .collect()
+}
+
 // Blanket implementation for all Bond types
 impl<T: Bond + ?Sized> BondAnalytics for T {}
 
@@ -581,4 +709,79 @@ mod tests {
             change
         );
     }
+
+    #[test]
+    fn test_average_life_bullet_equals_maturity() {
+        let bond = create_test_bond();
+        let settlement = date(2021, 1, 15);
+        // Redemption is paid on the adjusted maturity date.
+        let redemption_date = bond.cash_flows(settlement).last().unwrap().date;
+
+        let wal = bond.average_life(settlement).unwrap();
+        let expected = settlement.days_between(&redemption_date) as f64 / 365.0;
+        assert!((wal - expected).abs() < 1e-12);
+
+        let ytal = bond
+            .yield_to_average_life(settlement, dec!(103), Frequency::SemiAnnual)
+            .unwrap();
+        let ytm = bond
+            .yield_to_maturity(settlement, dec!(103), Frequency::SemiAnnual)
+            .unwrap();
+        assert!((ytal.yield_value - ytm.yield_value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_principal_amounts_follow_factors() {
+        // Amortizer repaying half its face with the first coupon.
+        let flows = [
+            BondCashFlow::coupon_and_principal(date(2025, 6, 15), dec!(3), dec!(50)),
+            BondCashFlow::coupon_and_principal(date(2025, 12, 15), dec!(1.5), dec!(50))
+                .with_factor(dec!(0.5)),
+        ];
+        assert_eq!(
+            principal_amounts(&flows, dec!(100)),
+            vec![dec!(50), dec!(50)]
+        );
+
+        // A bullet's interim coupons repay nothing.
+        let flows = [
+            BondCashFlow::coupon(date(2025, 6, 15), dec!(3)),
+            BondCashFlow::coupon_and_principal(date(2025, 12, 15), dec!(3), dec!(100)),
+        ];
+        assert_eq!(
+            principal_amounts(&flows, dec!(100)),
+            vec![Decimal::ZERO, dec!(100)]
+        );
+    }
+
+    #[test]
+    fn test_yield_to_average_life_linear_amortizer() {
+        use crate::instruments::{SinkingFundBond, SinkingFundPayment, SinkingFundSchedule};
+        use convex_core::calendars::BusinessDayConvention;
+
+        // 10-year bond retiring 10% of face every year. Unadjusted so every
+        // sink lands on a coupon date.
+        let base = FixedRateBond::builder()
+            .cusip_unchecked("123456789")
+            .coupon_percent(6.0)
+            .issue_date(date(2020, 6, 15))
+            .maturity(date(2030, 6, 15))
+            .us_corporate()
+            .business_day_convention(BusinessDayConvention::Unadjusted)
+            .build()
+            .unwrap();
+        let schedule = (2021..2030).fold(SinkingFundSchedule::new(), |s, year| {
+            s.with_payment(SinkingFundPayment::new(date(year, 6, 15), 10.0))
+        });
+        let bond = SinkingFundBond::new(base, schedule);
+        let settlement = date(2020, 6, 15);
+
+        // Principal is paid evenly over years 1..=10, so WAL is ~5.5 years.
+        let wal = bond.average_life(settlement);
+        assert!((wal - 5.5).abs() < 0.02, "WAL {wal}");
+
+        // At par on a coupon date, the bullet-at-WAL yield is the coupon.
+        let ytal = bond.yield_to_average_life(dec!(100), settlement).unwrap();
+        assert!((ytal - dec!(0.06)).abs() < dec!(0.002), "YTAL {ytal}");
+    }
 }
@@ -14,7 +14,7 @@ mod analytics;
 mod bond;
 mod extensions;
 
-pub use analytics::BondAnalytics;
+pub use analytics::{principal_amounts, BondAnalytics};
 pub use bond::{Bond, BondCashFlow, CashFlowType};
 pub use extensions::{
     AmortizingBond, EmbeddedOptionBond, FixedCouponBond, FloatingCouponBond, InflationLinkedBond,
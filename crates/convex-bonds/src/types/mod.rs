@@ -48,5 +48,7 @@ pub use sector::Sector;
 pub use seniority::Seniority;
 pub use settlement_rules::{SettlementAdjustment, SettlementRules, SettlementType};
 pub use stub_rules::{ReferenceMethod, StubPeriodRules, StubPosition, StubType};
-pub use yield_convention::{AccruedConvention, RoundingConvention, YieldConvention};
+pub use yield_convention::{
+    AccruedConvention, FirstPeriodDiscounting, RoundingConvention, YieldConvention,
+};
 pub use yield_rules::YieldCalculationRules;
@@ -7,7 +7,8 @@
 //!
 //! This module provides types and parsing for these conventions.
 
-use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -106,6 +107,47 @@ impl PriceQuoteConvention {
         }
     }
 
+    /// Rounds a decimal price to the nearest fraction this convention quotes
+    /// in (e.g. 1/32 for `ThirtySeconds`). Decimal and rate conventions are
+    /// returned unchanged.
+    #[must_use]
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        let denominator = match self {
+            PriceQuoteConvention::ThirtySecondsPlus => 64,
+            _ => match self.denominator() {
+                Some(d) => d,
+                None => return price,
+            },
+        };
+        let denominator = Decimal::from(denominator);
+        (price * denominator).round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+            / denominator
+    }
+
+    /// Formats a decimal price the way this convention quotes it: `"99-16"`
+    /// for 32nds, `"99-16+"` for 32nds-plus, `"99-33"` for 64ths. Other
+    /// conventions print the decimal price.
+    #[must_use]
+    pub fn format_price(&self, price: Decimal) -> String {
+        let rounded = self.round_price(price);
+        let handle = rounded.trunc();
+        let ticks = |denominator: u32| {
+            ((rounded - handle) * Decimal::from(denominator))
+                .to_u32()
+                .unwrap_or(0)
+        };
+        match self {
+            PriceQuoteConvention::ThirtySeconds => format!("{handle}-{:02}", ticks(32)),
+            PriceQuoteConvention::ThirtySecondsPlus => {
+                let sixty_fourths = ticks(64);
+                let plus = if sixty_fourths % 2 == 1 { "+" } else { "" };
+                format!("{handle}-{:02}{plus}", sixty_fourths / 2)
+            }
+            PriceQuoteConvention::SixtyFourths => format!("{handle}-{:02}", ticks(64)),
+            _ => rounded.normalize().to_string(),
+        }
+    }
+
     /// Returns the minimum tick size for this convention.
     #[must_use]
     pub fn tick_size(&self) -> Decimal {
@@ -593,6 +635,32 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_round_and_format_price() {
+        let c = PriceQuoteConvention::ThirtySeconds;
+        assert_eq!(c.format_price(Decimal::new(995, 1)), "99-16");
+        // 99.51 is closer to 99-16 than 99-17.
+        assert_eq!(c.format_price(Decimal::new(9951, 2)), "99-16");
+        assert_eq!(c.round_price(Decimal::new(9951, 2)), Decimal::new(995, 1));
+        assert_eq!(c.format_price(Decimal::new(10_003_125, 5)), "100-01");
+
+        let plus = PriceQuoteConvention::ThirtySecondsPlus;
+        assert_eq!(plus.format_price(Decimal::new(99515625, 6)), "99-16+");
+        assert_eq!(plus.format_price(Decimal::new(995, 1)), "99-16");
+
+        assert_eq!(
+            PriceQuoteConvention::SixtyFourths.format_price(Decimal::new(99515625, 6)),
+            "99-33"
+        );
+
+        let dec = PriceQuoteConvention::Decimal;
+        assert_eq!(
+            dec.round_price(Decimal::new(9951234, 5)),
+            Decimal::new(9951234, 5)
+        );
+        assert_eq!(dec.format_price(Decimal::new(995, 1)), "99.5");
+    }
+
     #[test]
     fn test_tick_size() {
         assert_eq!(
@@ -5,6 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use convex_core::calendars::{BusinessDayConvention, Calendar};
+use convex_core::types::Date;
+
 /// Rules for settlement date calculation.
 ///
 /// Settlement conventions vary by market:
@@ -184,6 +187,36 @@ impl SettlementRules {
         }
     }
 
+    /// Calculates the settlement date for a trade executed on `trade_date`.
+    ///
+    /// With business-day rules this counts `days` business days on `calendar`.
+    /// With calendar-day rules it adds `days` calendar days and then rolls the
+    /// result off any holiday according to [`SettlementAdjustment`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use convex_bonds::types::SettlementRules;
+    /// use convex_core::calendars::SIFMACalendar;
+    /// use convex_core::types::Date;
+    ///
+    /// // Friday trade, T+1 settles the following Monday
+    /// let trade = Date::from_ymd(2025, 1, 10).unwrap();
+    /// let settle = SettlementRules::us_treasury().settlement_date(trade, &SIFMACalendar::new());
+    /// assert_eq!(settle, Date::from_ymd(2025, 1, 13).unwrap());
+    /// ```
+    #[must_use]
+    pub fn settlement_date(&self, trade_date: Date, calendar: &dyn Calendar) -> Date {
+        if self.use_business_days {
+            calendar.add_business_days(trade_date, self.days as i32)
+        } else {
+            let unadjusted = trade_date.add_days(i64::from(self.days));
+            calendar
+                .adjust(unadjusted, self.adjustment.business_day_convention())
+                .unwrap_or(unadjusted)
+        }
+    }
+
     /// Returns the settlement period notation (e.g., "T+2").
     #[must_use]
     pub fn notation(&self) -> String {
@@ -246,6 +279,18 @@ impl SettlementAdjustment {
             Self::NoAdjustment => "No Adjustment",
         }
     }
+
+    /// Returns the equivalent business day convention.
+    #[must_use]
+    pub const fn business_day_convention(&self) -> BusinessDayConvention {
+        match self {
+            Self::Following => BusinessDayConvention::Following,
+            Self::Preceding => BusinessDayConvention::Preceding,
+            Self::ModifiedFollowing => BusinessDayConvention::ModifiedFollowing,
+            Self::ModifiedPreceding => BusinessDayConvention::ModifiedPreceding,
+            Self::NoAdjustment => BusinessDayConvention::Unadjusted,
+        }
+    }
 }
 
 impl std::fmt::Display for SettlementAdjustment {
@@ -333,4 +378,48 @@ mod tests {
         assert_eq!(SettlementRules::italian_btp().days, 2);
         assert_eq!(SettlementRules::japanese_jgb().days, 2);
     }
+
+    #[test]
+    fn test_settlement_date_spanning_us_holiday() {
+        use convex_core::calendars::SIFMACalendar;
+
+        let cal = SIFMACalendar::new();
+        // Wednesday before Thanksgiving (Thu 2025-11-27)
+        let trade = Date::from_ymd(2025, 11, 26).unwrap();
+
+        // T+2 business days: skips Thanksgiving and the weekend
+        let business = SettlementRules::us_corporate();
+        assert_eq!(
+            business.settlement_date(trade, &cal),
+            Date::from_ymd(2025, 12, 1).unwrap()
+        );
+
+        // T+2 calendar days lands on Friday 2025-11-28, a business day
+        let calendar_days =
+            SettlementRules::custom(2, false, SettlementAdjustment::Following, false);
+        assert_eq!(
+            calendar_days.settlement_date(trade, &cal),
+            Date::from_ymd(2025, 11, 28).unwrap()
+        );
+
+        // T+1 calendar days lands on Thanksgiving and rolls forward
+        let calendar_t1 = SettlementRules::custom(1, false, SettlementAdjustment::Following, false);
+        assert_eq!(
+            calendar_t1.settlement_date(trade, &cal),
+            Date::from_ymd(2025, 11, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_settlement_date_no_adjustment() {
+        use convex_core::calendars::WeekendCalendar;
+
+        // Friday + 1 calendar day = Saturday, left unadjusted
+        let trade = Date::from_ymd(2025, 1, 10).unwrap();
+        let rules = SettlementRules::custom(1, false, SettlementAdjustment::NoAdjustment, false);
+        assert_eq!(
+            rules.settlement_date(trade, &WeekendCalendar),
+            Date::from_ymd(2025, 1, 11).unwrap()
+        );
+    }
 }
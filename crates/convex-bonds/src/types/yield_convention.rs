@@ -3,6 +3,7 @@
 //! This module defines the various conventions used for calculating yields
 //! and accrued interest across different bond markets.
 
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 /// Yield calculation convention.
@@ -289,6 +290,9 @@ pub enum RoundingConvention {
     /// Round to 3 decimal places (0.001%).
     ThreeDecimals,
 
+    /// Round to 6 decimal places (0.000001%), as for UK gilt yields.
+    SixDecimals,
+
     /// Truncate (always round down).
     Truncate,
 }
@@ -302,14 +306,88 @@ impl RoundingConvention {
             RoundingConvention::BasisPoint => Some(4),
             RoundingConvention::HalfBasisPoint => Some(5),
             RoundingConvention::ThreeDecimals => Some(5),
+            RoundingConvention::SixDecimals => Some(8),
             RoundingConvention::Truncate => Some(4),
         }
     }
+
+    /// Rounds a yield quoted in percent (e.g. 4.123456 for 4.123456%).
+    ///
+    /// Midpoints round away from zero.
+    #[must_use]
+    pub fn round_percent(&self, yield_pct: Decimal) -> Decimal {
+        let round = |value: Decimal, dp: u32| {
+            value.round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero)
+        };
+        match self {
+            RoundingConvention::None => yield_pct,
+            RoundingConvention::BasisPoint => round(yield_pct, 2),
+            RoundingConvention::HalfBasisPoint => round(yield_pct * Decimal::TWO, 2) / Decimal::TWO,
+            RoundingConvention::ThreeDecimals => round(yield_pct, 3),
+            RoundingConvention::SixDecimals => round(yield_pct, 6),
+            RoundingConvention::Truncate => {
+                yield_pct.round_dp_with_strategy(2, RoundingStrategy::ToZero)
+            }
+        }
+    }
+}
+
+/// How the fractional period from settlement to the next coupon is
+/// discounted in periodic yield conventions.
+///
+/// With `n` whole periods after the next coupon and `w` the fraction of the
+/// current period still to run, a flow is discounted by
+///
+/// - `Compound`: `(1 + y/f)^-(w + n)`, the US street convention
+/// - `Simple`: `(1 + w·y/f)^-1 × (1 + y/f)^-n`, simple interest over the
+///   first fraction as in the Moosmüller and Japanese conventions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum FirstPeriodDiscounting {
+    /// Compound the fractional first period at the periodic yield.
+    #[default]
+    Compound,
+
+    /// Simple interest over the fractional first period.
+    Simple,
+}
+
+impl std::fmt::Display for FirstPeriodDiscounting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            FirstPeriodDiscounting::Compound => "Compound",
+            FirstPeriodDiscounting::Simple => "Simple",
+        };
+        write!(f, "{s}")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_rounding_convention_round_percent() {
+        let y = dec!(4.12345678);
+        assert_eq!(RoundingConvention::None.round_percent(y), y);
+        assert_eq!(RoundingConvention::BasisPoint.round_percent(y), dec!(4.12));
+        assert_eq!(
+            RoundingConvention::HalfBasisPoint.round_percent(y),
+            dec!(4.125)
+        );
+        assert_eq!(
+            RoundingConvention::ThreeDecimals.round_percent(y),
+            dec!(4.123)
+        );
+        assert_eq!(
+            RoundingConvention::SixDecimals.round_percent(y),
+            dec!(4.123457)
+        );
+        assert_eq!(
+            RoundingConvention::Truncate.round_percent(dec!(4.1299)),
+            dec!(4.12)
+        );
+    }
 
     #[test]
     fn test_yield_convention_default() {
@@ -12,7 +12,9 @@ use super::compounding::CompoundingMethod;
 use super::ex_dividend::ExDividendRules;
 use super::settlement_rules::SettlementRules;
 use super::stub_rules::{ReferenceMethod, StubPeriodRules};
-use super::yield_convention::{AccruedConvention, RoundingConvention, YieldConvention};
+use super::yield_convention::{
+    AccruedConvention, FirstPeriodDiscounting, RoundingConvention, YieldConvention,
+};
 
 /// Complete rules for yield calculation.
 ///
@@ -41,6 +43,10 @@ pub struct YieldCalculationRules {
     /// How interest compounds
     pub compounding: CompoundingMethod,
 
+    /// How the fractional period up to the first cash flow is discounted
+    #[serde(default)]
+    pub first_period: FirstPeriodDiscounting,
+
     /// Day count for accrual period calculations
     pub accrual_day_count: DayCountConvention,
 
@@ -75,6 +81,19 @@ pub struct YieldCalculationRules {
     pub description: String,
 }
 
+/// A cash flow's time split per [`YieldCalculationRules::discount_factor`].
+enum GermanPeriods {
+    /// Simple-interest broken period, then coupon periods compounded.
+    Moosmuller { broken: f64, periods: f64 },
+    /// Simple-interest broken period, whole years compounded annually, then
+    /// simple interest within the final year.
+    BraessFangmeyer {
+        broken: f64,
+        years: f64,
+        within_year: f64,
+    },
+}
+
 impl YieldCalculationRules {
     /// Creates rules for US Treasury Notes/Bonds.
     ///
@@ -87,6 +106,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::StreetConvention,
             compounding: CompoundingMethod::semi_annual(),
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::SemiAnnual,
@@ -111,6 +131,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::DiscountYield,
             compounding: CompoundingMethod::Discount,
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::Act360,
             discount_day_count: DayCountConvention::Act360,
             frequency: Frequency::Zero,
@@ -135,6 +156,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::StreetConvention,
             compounding: CompoundingMethod::semi_annual(),
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::Thirty360US,
             discount_day_count: DayCountConvention::Thirty360US,
             frequency: Frequency::SemiAnnual,
@@ -159,6 +181,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::MunicipalYield,
             compounding: CompoundingMethod::semi_annual(),
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::Thirty360US,
             discount_day_count: DayCountConvention::Thirty360US,
             frequency: Frequency::SemiAnnual,
@@ -182,11 +205,13 @@ impl YieldCalculationRules {
     /// - Compounding: Semi-annual (ISMA)
     /// - Settlement: T+1
     /// - Ex-dividend: 7 business days before coupon
+    /// - Yields reported to 6 decimal places
     #[must_use]
     pub fn uk_gilt() -> Self {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 2 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::SemiAnnual,
@@ -194,7 +219,7 @@ impl YieldCalculationRules {
             stub_rules: StubPeriodRules::icma(),
             ex_dividend_rules: Some(ExDividendRules::uk_gilt()),
             accrued_convention: AccruedConvention::ExDividend,
-            rounding: RoundingConvention::None,
+            rounding: RoundingConvention::SixDecimals,
             use_short_date_method: true,
             short_date_threshold: 1.0,
             description: "UK Gilt".to_string(),
@@ -212,6 +237,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 1 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::Annual,
@@ -236,6 +262,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 1 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::Annual,
@@ -261,6 +288,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 2 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::SemiAnnual,
@@ -285,6 +313,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 1 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::Annual,
@@ -309,6 +338,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::SimpleYield,
             compounding: CompoundingMethod::Simple,
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::Act365Fixed,
             discount_day_count: DayCountConvention::Act365Fixed,
             frequency: Frequency::SemiAnnual,
@@ -336,6 +366,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 1 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::Annual,
@@ -361,6 +392,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 2 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::SemiAnnual,
@@ -385,6 +417,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::StreetConvention,
             compounding: CompoundingMethod::semi_annual(),
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::Act365Fixed,
             discount_day_count: DayCountConvention::Act365Fixed,
             frequency: Frequency::SemiAnnual,
@@ -409,6 +442,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::ISMA,
             compounding: CompoundingMethod::ActualPeriod { frequency: 1 },
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::ActActIcma,
             discount_day_count: DayCountConvention::ActActIcma,
             frequency: Frequency::Annual,
@@ -429,6 +463,7 @@ impl YieldCalculationRules {
         Self {
             convention: YieldConvention::Continuous,
             compounding: CompoundingMethod::Continuous,
+            first_period: FirstPeriodDiscounting::Compound,
             accrual_day_count: DayCountConvention::Act365Fixed,
             discount_day_count: DayCountConvention::Act365Fixed,
             frequency: Frequency::Annual,
@@ -489,6 +524,13 @@ impl YieldCalculationRules {
         }
     }
 
+    /// Sets how the fractional first period is discounted.
+    #[must_use]
+    pub fn with_first_period_discounting(mut self, first_period: FirstPeriodDiscounting) -> Self {
+        self.first_period = first_period;
+        self
+    }
+
     /// Returns true if this convention uses ex-dividend.
     #[must_use]
     pub const fn has_ex_dividend(&self) -> bool {
@@ -507,6 +549,103 @@ impl YieldCalculationRules {
         self.frequency.periods_per_year()
     }
 
+    /// Discount factor at `yield_rate` for a cash flow `time` years after
+    /// settlement, on a bond whose last cash flow is `final_time` years out.
+    ///
+    /// The German conventions discount the broken period up to the first
+    /// coupon with simple interest, the broken period being what is left of
+    /// `final_time` after whole periods counted back from maturity:
+    ///
+    /// - Moosmüller compounds at `yield / frequency` over the coupon periods
+    ///   after it.
+    /// - Braess-Fangmeyer compounds annually over whole years after it and
+    ///   accrues simple interest within each year.
+    ///
+    /// Both coincide for annual coupons. Periodic conventions with
+    /// [`FirstPeriodDiscounting::Simple`] split time as Moosmüller does;
+    /// others discount with [`CompoundingMethod::discount_factor`].
+    #[must_use]
+    pub fn discount_factor(&self, yield_rate: f64, time: f64, final_time: f64) -> f64 {
+        match self.german_periods(time, final_time) {
+            Some(GermanPeriods::Moosmuller { broken, periods }) => {
+                let m = f64::from(self.periods_per_year());
+                1.0 / ((1.0 + yield_rate * broken) * (1.0 + yield_rate / m).powf(periods))
+            }
+            Some(GermanPeriods::BraessFangmeyer {
+                broken,
+                years,
+                within_year,
+            }) => {
+                1.0 / ((1.0 + yield_rate * broken)
+                    * (1.0 + yield_rate).powf(years)
+                    * (1.0 + yield_rate * within_year))
+            }
+            None => self.compounding.discount_factor(yield_rate, time),
+        }
+    }
+
+    /// Derivative of [`Self::discount_factor`] with respect to yield.
+    #[must_use]
+    pub fn discount_factor_derivative(&self, yield_rate: f64, time: f64, final_time: f64) -> f64 {
+        let df = self.discount_factor(yield_rate, time, final_time);
+        match self.german_periods(time, final_time) {
+            Some(GermanPeriods::Moosmuller { broken, periods }) => {
+                let m = f64::from(self.periods_per_year());
+                -df * (broken / (1.0 + yield_rate * broken) + periods / m / (1.0 + yield_rate / m))
+            }
+            Some(GermanPeriods::BraessFangmeyer {
+                broken,
+                years,
+                within_year,
+            }) => {
+                -df * (broken / (1.0 + yield_rate * broken)
+                    + years / (1.0 + yield_rate)
+                    + within_year / (1.0 + yield_rate * within_year))
+            }
+            None => self
+                .compounding
+                .discount_factor_derivative(yield_rate, time),
+        }
+    }
+
+    /// Splits `time` into a simple-interest broken period and the compounded
+    /// remainder, or `None` when the first period is compounded.
+    fn german_periods(&self, time: f64, final_time: f64) -> Option<GermanPeriods> {
+        let moosmuller = match self.convention {
+            YieldConvention::Moosmuller => true,
+            YieldConvention::BraessFangmeyer => false,
+            _ if self.first_period == FirstPeriodDiscounting::Simple
+                && self.compounding.is_periodic() =>
+            {
+                true
+            }
+            _ => return None,
+        };
+        let per_year = if moosmuller {
+            f64::from(self.periods_per_year().max(1))
+        } else {
+            1.0
+        };
+        // Broken period: what precedes the whole periods before maturity.
+        let whole_periods = (final_time * per_year - 1e-9).floor().max(0.0);
+        let broken_end = final_time - whole_periods / per_year;
+        let broken = time.min(broken_end);
+        let after = (time - broken_end).max(0.0);
+        Some(if moosmuller {
+            GermanPeriods::Moosmuller {
+                broken,
+                periods: after * per_year,
+            }
+        } else {
+            let years = (after - 1e-9).floor().max(0.0);
+            GermanPeriods::BraessFangmeyer {
+                broken,
+                years,
+                within_year: after - years,
+            }
+        })
+    }
+
     /// Returns whether the rules are for a discount instrument.
     #[must_use]
     pub const fn is_discount_instrument(&self) -> bool {
@@ -562,6 +701,44 @@ mod tests {
         assert!(!rules.has_ex_dividend());
     }
 
+    #[test]
+    fn test_german_market_discounts_broken_period_simply() {
+        let rules = YieldCalculationRules::from_convention(YieldConvention::for_market("DE"));
+        assert_eq!(rules.convention, YieldConvention::Moosmuller);
+
+        // 2.25 years to maturity on annual coupons: a quarter-year broken
+        // period at simple interest, then whole years compounded.
+        let y = 0.04;
+        let df = rules.discount_factor(y, 2.25, 2.25);
+        assert!((df - 1.0 / ((1.0 + y * 0.25) * 1.04_f64.powi(2))).abs() < 1e-12);
+        assert!((rules.discount_factor(y, 0.25, 2.25) - 1.0 / 1.01).abs() < 1e-12);
+
+        let mut braess = rules.clone();
+        braess.convention = YieldConvention::BraessFangmeyer;
+        assert!((braess.discount_factor(y, 2.25, 2.25) - df).abs() < 1e-12);
+
+        let h = 1e-6;
+        let numeric = (rules.discount_factor(y + h, 2.25, 2.25)
+            - rules.discount_factor(y - h, 2.25, 2.25))
+            / (2.0 * h);
+        assert!((rules.discount_factor_derivative(y, 2.25, 2.25) - numeric).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_simple_first_period_discounting() {
+        let street = YieldCalculationRules::us_corporate();
+        let simple = street
+            .clone()
+            .with_first_period_discounting(FirstPeriodDiscounting::Simple);
+
+        // 2.25 years on semi-annual coupons: half a period, then 4 periods.
+        let y = 0.06;
+        let compound = 1.03_f64.powf(-4.5);
+        assert!((street.discount_factor(y, 2.25, 2.25) - compound).abs() < 1e-12);
+        let expected = 1.0 / ((1.0 + 0.5 * 0.03) * 1.03_f64.powi(4));
+        assert!((simple.discount_factor(y, 2.25, 2.25) - expected).abs() < 1e-12);
+    }
+
     #[test]
     fn test_japanese_jgb_rules() {
         let rules = YieldCalculationRules::japanese_jgb();
@@ -0,0 +1,37 @@
+//! Capture build metadata for [`convex_core::build_info`].
+//!
+//! Sets `CONVEX_GIT_COMMIT` (short hash, or `unknown` outside a git checkout,
+//! e.g. when built from a crates.io tarball) and `CONVEX_BUILD_TIMESTAMP`
+//! (seconds since the Unix epoch) for `env!` at compile time.
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    println!("cargo:rustc-env=CONVEX_GIT_COMMIT={commit}");
+    println!("cargo:rustc-env=CONVEX_BUILD_TIMESTAMP={timestamp}");
+
+    // Only re-run when the checked-out commit moves, not on every source edit.
+    println!("cargo:rerun-if-changed=build.rs");
+    for path in ["../../.git/HEAD", "../../.git/refs/heads"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Build metadata for support and diagnostics.
+//!
+//! Values are captured at compile time by the crate's build script.
+
+use serde::Serialize;
+
+/// Version and provenance of the compiled library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Crate version (e.g. `0.13.0`).
+    pub version: &'static str,
+    /// Short git commit hash, or `unknown` when built outside a git checkout.
+    pub git_commit: &'static str,
+    /// Build time in seconds since the Unix epoch.
+    pub build_timestamp: &'static str,
+}
+
+/// Returns the version, git commit and build timestamp of this build.
+#[must_use]
+pub const fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        git_commit: env!("CONVEX_GIT_COMMIT"),
+        build_timestamp: env!("CONVEX_BUILD_TIMESTAMP"),
+    }
+}
+
+impl std::fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (commit {}, built {})",
+            self.version, self.git_commit, self.build_timestamp
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info_fields() {
+        let info = build_info();
+        assert!(!info.version.is_empty());
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.git_commit.is_empty());
+        assert!(info.build_timestamp.parse::<u64>().is_ok());
+    }
+}
@@ -0,0 +1,118 @@
+//! Brazil national (ANBIMA) holiday calendar.
+//!
+//! Used for BRL fixed income, including the BUS/252 day count on LTN, NTN-F
+//! and DI futures.
+
+use super::bitmap::{HolidayBitmap, HolidayCalendarBuilder, WeekendType, MAX_YEAR, MIN_YEAR};
+use super::Calendar;
+use crate::types::Date;
+use std::sync::OnceLock;
+
+/// Static Brazil calendar instance.
+static BRAZIL_CALENDAR: OnceLock<BrazilCalendar> = OnceLock::new();
+
+/// Brazil national holiday calendar (ANBIMA).
+///
+/// ## Holidays
+///
+/// - New Year's Day (January 1)
+/// - Carnival Monday and Tuesday (48 and 47 days before Easter)
+/// - Good Friday
+/// - Tiradentes (April 21)
+/// - Labour Day (May 1)
+/// - Corpus Christi (60 days after Easter)
+/// - Independence Day (September 7)
+/// - Our Lady of Aparecida (October 12)
+/// - All Souls' Day (November 2)
+/// - Republic Proclamation Day (November 15)
+/// - Black Consciousness Day (November 20, from 2024)
+/// - Christmas Day (December 25)
+///
+/// Holidays falling on a weekend are not moved.
+#[derive(Debug, Clone)]
+pub struct BrazilCalendar {
+    bitmap: HolidayBitmap,
+}
+
+impl BrazilCalendar {
+    /// Create a new Brazil calendar.
+    pub fn new() -> Self {
+        Self {
+            bitmap: build_brazil_holidays(),
+        }
+    }
+
+    /// Get the global Brazil calendar instance.
+    pub fn global() -> &'static BrazilCalendar {
+        BRAZIL_CALENDAR.get_or_init(BrazilCalendar::new)
+    }
+}
+
+impl Default for BrazilCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Calendar for BrazilCalendar {
+    fn name(&self) -> &'static str {
+        "Brazil"
+    }
+
+    fn is_business_day(&self, date: Date) -> bool {
+        self.bitmap.is_business_day(date.as_naive_date())
+    }
+}
+
+/// Build the Brazil holiday bitmap.
+fn build_brazil_holidays() -> HolidayBitmap {
+    HolidayCalendarBuilder::new("Brazil")
+        .weekend(WeekendType::SaturdaySunday)
+        .year_range(MIN_YEAR, MAX_YEAR)
+        .add_fixed_holiday(1, 1, false)
+        // Carnival Monday and Tuesday
+        .add_easter_holiday(-48)
+        .add_easter_holiday(-47)
+        // Good Friday
+        .add_easter_holiday(-2)
+        .add_fixed_holiday(4, 21, false)
+        .add_fixed_holiday(5, 1, false)
+        // Corpus Christi
+        .add_easter_holiday(60)
+        .add_fixed_holiday(9, 7, false)
+        .add_fixed_holiday(10, 12, false)
+        .add_fixed_holiday(11, 2, false)
+        .add_fixed_holiday(11, 15, false)
+        // Black Consciousness Day became a national holiday in 2024
+        .add_fixed_holiday_from(11, 20, 2024, false)
+        .add_fixed_holiday(12, 25, false)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brazil_carnival_and_corpus_christi() {
+        let cal = BrazilCalendar::new();
+
+        // 2025: Easter is April 20
+        assert!(!cal.is_business_day(Date::from_ymd(2025, 3, 3).unwrap()));
+        assert!(!cal.is_business_day(Date::from_ymd(2025, 3, 4).unwrap()));
+        assert!(cal.is_business_day(Date::from_ymd(2025, 3, 5).unwrap())); // Ash Wednesday
+        assert!(!cal.is_business_day(Date::from_ymd(2025, 4, 18).unwrap()));
+        assert!(!cal.is_business_day(Date::from_ymd(2025, 6, 19).unwrap()));
+    }
+
+    #[test]
+    fn test_brazil_fixed_holidays() {
+        let cal = BrazilCalendar::new();
+
+        assert!(!cal.is_business_day(Date::from_ymd(2025, 4, 21).unwrap()));
+        assert!(!cal.is_business_day(Date::from_ymd(2023, 9, 7).unwrap()));
+        assert!(!cal.is_business_day(Date::from_ymd(2025, 11, 20).unwrap()));
+        // Black Consciousness Day was not national before 2024
+        assert!(cal.is_business_day(Date::from_ymd(2023, 11, 20).unwrap()));
+    }
+}
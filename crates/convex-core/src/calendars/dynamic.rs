@@ -21,7 +21,7 @@
 //! ```
 
 use super::bitmap::{HolidayBitmap, WeekendType, MAX_YEAR, MIN_YEAR};
-use super::Calendar;
+use super::{Calendar, JointCalendar};
 use crate::error::{ConvexError, ConvexResult};
 use crate::types::Date;
 use chrono::{Datelike, NaiveDate};
@@ -325,6 +325,29 @@ impl DynamicCalendar {
         }
     }
 
+    /// Joins two calendars into a [`JointCalendar`] whose non-business days
+    /// are the union of theirs: a date that is a holiday or weekend in
+    /// either member is not a business day in the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use convex_core::calendars::{Calendar, DynamicCalendar, SIFMACalendar, Target2Calendar};
+    /// use convex_core::types::Date;
+    ///
+    /// let usd_eur = DynamicCalendar::join(SIFMACalendar::new(), Target2Calendar::new());
+    ///
+    /// // May 1 is a TARGET2 holiday only
+    /// assert!(!usd_eur.is_business_day(Date::from_ymd(2025, 5, 1).unwrap()));
+    /// ```
+    pub fn join<A, B>(first: A, second: B) -> JointCalendar
+    where
+        A: Calendar + 'static,
+        B: Calendar + 'static,
+    {
+        JointCalendar::new(vec![Box::new(first), Box::new(second)])
+    }
+
     /// Count total holidays in this calendar.
     pub fn holiday_count(&self) -> usize {
         self.bitmap.count_holidays()
@@ -860,4 +883,40 @@ mod tests {
         assert_eq!(parsed.weekend, Some(WeekendType::SaturdaySunday));
         assert_eq!(parsed.holidays.len(), 1);
     }
+
+    #[test]
+    fn test_join_unions_holidays() {
+        use crate::calendars::{BusinessDayConvention, SIFMACalendar, Target2Calendar};
+
+        let sifma = SIFMACalendar::new();
+        let target2 = Target2Calendar::new();
+        let joint = DynamicCalendar::join(SIFMACalendar::new(), Target2Calendar::new());
+
+        // Holiday in one market only
+        let labour_day = Date::from_ymd(2025, 5, 1).unwrap();
+        assert!(sifma.is_business_day(labour_day));
+        assert!(!joint.is_business_day(labour_day));
+        let july_4 = Date::from_ymd(2025, 7, 4).unwrap();
+        assert!(target2.is_business_day(july_4));
+        assert!(!joint.is_business_day(july_4));
+
+        // Weekends and ordinary days carry over
+        assert!(!joint.is_business_day(Date::from_ymd(2025, 5, 3).unwrap()));
+        assert!(joint.is_business_day(Date::from_ymd(2025, 5, 2).unwrap()));
+
+        // Rolling skips Boxing Day (TARGET2 only) as well as Christmas
+        let christmas = Date::from_ymd(2025, 12, 25).unwrap();
+        assert_eq!(
+            sifma
+                .adjust(christmas, BusinessDayConvention::Following)
+                .unwrap(),
+            Date::from_ymd(2025, 12, 26).unwrap()
+        );
+        assert_eq!(
+            joint
+                .adjust(christmas, BusinessDayConvention::Following)
+                .unwrap(),
+            Date::from_ymd(2025, 12, 29).unwrap()
+        );
+    }
 }
@@ -14,6 +14,7 @@
 //! | `Target2Calendar` | Eurozone payments | EUR swaps, Bunds |
 //! | `UKCalendar` | UK bank holidays | Gilts |
 //! | `JapanCalendar` | Japan holidays | JGBs |
+//! | `BrazilCalendar` | Brazil national (ANBIMA) holidays | BRL bonds, BUS/252 |
 //! | `WeekendCalendar` | Weekend only (no holidays) | Testing |
 //!
 //! # Performance
@@ -41,9 +42,11 @@
 use chrono::Datelike;
 
 mod bitmap;
+mod brazil;
 mod conventions;
 mod dynamic;
 mod japan;
+mod registry;
 mod sifma;
 mod target2;
 mod uk;
@@ -62,7 +65,9 @@ pub use dynamic::{CalendarData, CustomCalendarBuilder, DynamicCalendar};
 pub use conventions::BusinessDayConvention;
 
 // Re-export calendar implementations
+pub use brazil::BrazilCalendar;
 pub use japan::JapanCalendar;
+pub use registry::CalendarRegistry;
 pub use sifma::{SIFMACalendar, USGovernmentCalendar};
 pub use target2::Target2Calendar;
 pub use uk::UKCalendar;
@@ -165,6 +170,26 @@ pub trait Calendar: Send + Sync {
     }
 }
 
+impl<C: Calendar + ?Sized> Calendar for &C {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn is_business_day(&self, date: Date) -> bool {
+        (**self).is_business_day(date)
+    }
+}
+
+impl<C: Calendar + ?Sized> Calendar for std::sync::Arc<C> {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn is_business_day(&self, date: Date) -> bool {
+        (**self).is_business_day(date)
+    }
+}
+
 /// A simple weekend-only calendar (no holidays).
 ///
 /// Useful for testing or when holiday data is not available.
@@ -233,6 +258,72 @@ impl Calendar for JointCalendar {
     }
 }
 
+/// Identifier for a built-in holiday calendar.
+///
+/// A `Copy` handle that can be embedded in conventions such as
+/// [`DayCountConvention::Business252`](crate::daycounts::DayCountConvention::Business252)
+/// and resolved to a [`Calendar`] on demand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum MarketCalendar {
+    /// Weekends only, no holidays.
+    Weekend,
+    /// SIFMA US bond market.
+    Sifma,
+    /// TARGET2 Eurozone payments.
+    Target2,
+    /// UK bank holidays.
+    Uk,
+    /// Japan holidays.
+    Japan,
+    /// Brazil national (ANBIMA) holidays.
+    Brazil,
+}
+
+impl MarketCalendar {
+    /// Returns the shared calendar instance for this identifier.
+    #[must_use]
+    pub fn calendar(self) -> &'static dyn Calendar {
+        match self {
+            MarketCalendar::Weekend => &WeekendCalendar,
+            MarketCalendar::Sifma => SIFMACalendar::global(),
+            MarketCalendar::Target2 => Target2Calendar::global(),
+            MarketCalendar::Uk => UKCalendar::global(),
+            MarketCalendar::Japan => JapanCalendar::global(),
+            MarketCalendar::Brazil => BrazilCalendar::global(),
+        }
+    }
+
+    /// Returns the calendar name.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            MarketCalendar::Weekend => "Weekend",
+            MarketCalendar::Sifma => "SIFMA",
+            MarketCalendar::Target2 => "TARGET2",
+            MarketCalendar::Uk => "UK",
+            MarketCalendar::Japan => "Japan",
+            MarketCalendar::Brazil => "Brazil",
+        }
+    }
+
+    /// Looks up a calendar by name (case-insensitive), accepting the names
+    /// returned by [`name`](Self::name) and common currency aliases.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let cal = match name.trim().to_uppercase().as_str() {
+            "WEEKEND" => MarketCalendar::Weekend,
+            "SIFMA" | "US" | "USGOV" | "US_GOVERNMENT" | "US-GOV" => MarketCalendar::Sifma,
+            "TARGET2" | "EUR" => MarketCalendar::Target2,
+            "UK" | "GBP" => MarketCalendar::Uk,
+            "JAPAN" | "JPY" => MarketCalendar::Japan,
+            "BRAZIL" | "BRL" | "ANBIMA" => MarketCalendar::Brazil,
+            _ => return None,
+        };
+        Some(cal)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
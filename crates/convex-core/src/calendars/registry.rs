@@ -0,0 +1,184 @@
+//! Registry of named holiday calendars.
+//!
+//! Lets markets be added at runtime (e.g. from JSON holiday files) and then
+//! referenced by name alongside the built-in calendars.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use super::{Calendar, DynamicCalendar, MarketCalendar};
+use crate::error::{ConvexError, ConvexResult};
+
+/// Process-wide registry instance.
+static GLOBAL_REGISTRY: OnceLock<CalendarRegistry> = OnceLock::new();
+
+/// Registry of named calendars.
+///
+/// Lookups check registered calendars first, then fall back to the built-in
+/// [`MarketCalendar`]s, so a registration can also override a built-in
+/// name. Names are case-insensitive.
+///
+/// # Example
+///
+/// ```
+/// use convex_core::calendars::{Calendar, CalendarRegistry};
+/// use convex_core::types::Date;
+///
+/// let registry = CalendarRegistry::new();
+/// registry
+///     .register_json(r#"{"name": "Atlantis", "holidays": ["2025-03-03"]}"#)
+///     .unwrap();
+///
+/// let cal = registry.get("atlantis").unwrap();
+/// assert!(!cal.is_business_day(Date::from_ymd(2025, 3, 3).unwrap()));
+/// assert!(registry.get("Lemuria").is_err());
+/// ```
+#[derive(Default)]
+pub struct CalendarRegistry {
+    calendars: RwLock<HashMap<String, Arc<dyn Calendar>>>,
+}
+
+impl std::fmt::Debug for CalendarRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CalendarRegistry")
+            .field("registered", &self.names())
+            .finish()
+    }
+}
+
+impl CalendarRegistry {
+    /// Creates an empty registry (built-ins are always available).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the global registry instance.
+    pub fn global() -> &'static CalendarRegistry {
+        GLOBAL_REGISTRY.get_or_init(CalendarRegistry::new)
+    }
+
+    /// Registers a calendar under `name`, replacing any previous registration.
+    pub fn register(&self, name: impl AsRef<str>, calendar: impl Calendar + 'static) {
+        self.calendars
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(normalize(name.as_ref()), Arc::new(calendar));
+    }
+
+    /// Loads a [`DynamicCalendar`] from JSON and registers it under the name
+    /// in the data. Returns that name.
+    ///
+    /// See [`DynamicCalendar::from_json`] for the format.
+    pub fn register_json(&self, json: &str) -> ConvexResult<String> {
+        let calendar = DynamicCalendar::from_json(json)?;
+        let name = calendar.calendar_name().to_string();
+        self.register(&name, calendar);
+        Ok(name)
+    }
+
+    /// Looks up a calendar by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConvexError::CalendarError` if the name is neither registered
+    /// nor a built-in calendar.
+    pub fn get(&self, name: &str) -> ConvexResult<Arc<dyn Calendar>> {
+        let registered = self
+            .calendars
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&normalize(name))
+            .cloned();
+        if let Some(calendar) = registered {
+            return Ok(calendar);
+        }
+
+        MarketCalendar::from_name(name)
+            .map(|builtin| Arc::new(builtin.calendar()) as Arc<dyn Calendar>)
+            .ok_or_else(|| ConvexError::CalendarError {
+                reason: format!("unknown calendar '{name}'"),
+            })
+    }
+
+    /// Returns true if `name` resolves to a registered or built-in calendar.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_ok()
+    }
+
+    /// Returns the names of registered (non built-in) calendars, sorted.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .calendars
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendars::{BusinessDayConvention, WeekendType};
+    use crate::types::Date;
+
+    #[test]
+    fn test_registered_calendar_adjusts() {
+        let registry = CalendarRegistry::new();
+        // Fri 2025-01-31 is a holiday, so Modified Following rolls back
+        // into January rather than forward into February.
+        registry.register(
+            "Tiny",
+            DynamicCalendar::from_dates(
+                "Tiny",
+                WeekendType::SaturdaySunday,
+                [Date::from_ymd(2025, 1, 31).unwrap()],
+            ),
+        );
+
+        let cal = registry.get("TINY").unwrap();
+        let holiday = Date::from_ymd(2025, 1, 31).unwrap();
+        assert_eq!(
+            cal.adjust(holiday, BusinessDayConvention::ModifiedFollowing)
+                .unwrap(),
+            Date::from_ymd(2025, 1, 30).unwrap()
+        );
+        assert_eq!(
+            cal.adjust(holiday, BusinessDayConvention::Following)
+                .unwrap(),
+            Date::from_ymd(2025, 2, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_register_json_and_builtins() {
+        let registry = CalendarRegistry::new();
+        let name = registry
+            .register_json(r#"{"name": "Atlantis", "holidays": ["2025-03-03"]}"#)
+            .unwrap();
+        assert_eq!(name, "Atlantis");
+        assert_eq!(registry.names(), vec!["ATLANTIS".to_string()]);
+
+        // Built-ins resolve without registration.
+        let target2 = registry.get("TARGET2").unwrap();
+        assert!(!target2.is_business_day(Date::from_ymd(2025, 5, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_unknown_calendar_errors() {
+        let registry = CalendarRegistry::new();
+        let err = match registry.get("Lemuria") {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e,
+        };
+        assert!(err.to_string().contains("unknown calendar 'Lemuria'"));
+        assert!(!registry.contains("Lemuria"));
+    }
+}
@@ -143,6 +143,10 @@ impl ActActIcma {
     /// Calculates year fraction given the coupon period dates.
     ///
     /// This is the primary method for bond accrued interest calculations.
+    /// If the accrual runs outside the coupon period (a long first or last
+    /// coupon), the excess is measured against the notional quasi-coupon
+    /// periods obtained by stepping the period bounds by `12 / frequency`
+    /// months, as in the ISDA long-stub examples.
     ///
     /// # Arguments
     ///
@@ -167,9 +171,62 @@ impl ActActIcma {
             return Decimal::ZERO;
         }
 
-        let accrued_days = accrual_start.days_between(&accrual_end);
-        Decimal::from(accrued_days)
-            / (Decimal::from(self.frequency) * Decimal::from(days_in_period))
+        if accrual_start >= period_start && accrual_end <= period_end {
+            let accrued_days = accrual_start.days_between(&accrual_end);
+            return Decimal::from(accrued_days)
+                / (Decimal::from(self.frequency) * Decimal::from(days_in_period));
+        }
+
+        let months = 12 / self.frequency.max(1) as i32;
+        let eom = period_start.is_end_of_month() && period_end.is_end_of_month();
+        let step = |date: Date, months: i32| {
+            date.add_months(months)
+                .ok()
+                .map(|d| if eom { d.end_of_month() } else { d })
+        };
+
+        let mut total =
+            self.quasi_period_fraction(accrual_start, accrual_end, period_start, period_end);
+
+        // Quasi-coupon periods before the period (long first coupon)
+        let mut quasi_end = period_start;
+        while accrual_start < quasi_end {
+            let Some(quasi_start) = step(quasi_end, -months) else {
+                break;
+            };
+            total += self.quasi_period_fraction(accrual_start, accrual_end, quasi_start, quasi_end);
+            quasi_end = quasi_start;
+        }
+
+        // Quasi-coupon periods after the period (long last coupon)
+        let mut quasi_start = period_end;
+        while accrual_end > quasi_start {
+            let Some(quasi_end) = step(quasi_start, months) else {
+                break;
+            };
+            total += self.quasi_period_fraction(accrual_start, accrual_end, quasi_start, quasi_end);
+            quasi_start = quasi_end;
+        }
+
+        total
+    }
+
+    /// Fraction of the accrual falling within one (quasi-)coupon period.
+    fn quasi_period_fraction(
+        &self,
+        accrual_start: Date,
+        accrual_end: Date,
+        quasi_start: Date,
+        quasi_end: Date,
+    ) -> Decimal {
+        let overlap = accrual_start
+            .max(quasi_start)
+            .days_between(&accrual_end.min(quasi_end));
+        if overlap <= 0 {
+            return Decimal::ZERO;
+        }
+        Decimal::from(overlap)
+            / (Decimal::from(self.frequency) * Decimal::from(quasi_start.days_between(&quasi_end)))
     }
 
     /// Calculates accrued days for a period.
@@ -230,10 +287,11 @@ impl DayCount for ActActIcma {
 ///
 /// # Rules
 ///
-/// 1. Count actual days between dates
-/// 2. The denominator is 366 if the calculation period includes Feb 29
-///    (determined by looking at the year preceding the end date)
-/// 3. For periods > 1 year, split into full years plus remainder
+/// 1. Count whole years back from the end date; counting back onto Feb 28
+///    of a leap year lands on Feb 29
+/// 2. The remaining stub is actual days over 366 if it includes Feb 29,
+///    otherwise 365
+/// 3. The year fraction is whole years plus the stub
 ///
 /// # Bloomberg
 ///
@@ -242,34 +300,6 @@ impl DayCount for ActActIcma {
 pub struct ActActAfb;
 
 impl ActActAfb {
-    /// Determines if the year basis should be 366.
-    ///
-    /// The basis is 366 if Feb 29 exists in the one-year lookback from end date.
-    fn is_366_basis(start: Date, end: Date) -> bool {
-        // For AFB, we look at whether Feb 29 falls in the relevant period
-        // The "relevant year" is determined by going back one year from the end date
-
-        let days = start.days_between(&end);
-        if days <= 0 {
-            return false;
-        }
-
-        // If period is less than a year, check if Feb 29 is in [start, end]
-        if days <= 366 {
-            return Self::period_contains_feb29(start, end);
-        }
-
-        // For longer periods, we need the rule for the final partial year
-        // Look back one year from end
-        let one_year_back =
-            Date::from_ymd(end.year() - 1, end.month(), end.day()).unwrap_or_else(|_| {
-                // Handle Feb 29 -> Feb 28
-                Date::from_ymd(end.year() - 1, end.month(), 28).unwrap()
-            });
-
-        Self::period_contains_feb29(one_year_back, end)
-    }
-
     /// Checks if Feb 29 is contained in the period [start, end].
     fn period_contains_feb29(start: Date, end: Date) -> bool {
         let start_year = start.year();
@@ -302,35 +332,24 @@ impl DayCount for ActActAfb {
             return Decimal::ZERO;
         }
 
-        let total_days = start.days_between(&end);
-
-        // For periods up to one year
-        if total_days <= 366 {
-            let basis = if Self::is_366_basis(start, end) {
-                366
-            } else {
-                365
-            };
-            return Decimal::from(total_days) / Decimal::from(basis);
-        }
-
-        // For periods > 1 year, count full years plus remainder
+        // Count whole years back from the end date. Counting back onto
+        // Feb 28 of a leap year lands on Feb 29 instead.
         let mut full_years = 0i32;
-        let mut remaining_start = start;
-
-        // Count full years by moving forward
-        loop {
-            let next_year = remaining_start.add_years(1).unwrap_or(remaining_start);
-            if next_year > end {
+        let mut remaining_end = end;
+        while let Ok(mut prior) = remaining_end.add_years(-1) {
+            if prior.month() == 2 && prior.day() == 28 && is_leap_year(prior.year()) {
+                prior = prior.add_days(1);
+            }
+            if prior < start {
                 break;
             }
             full_years += 1;
-            remaining_start = next_year;
+            remaining_end = prior;
         }
 
-        // Calculate fraction for remaining period
-        let remaining_days = remaining_start.days_between(&end);
-        let basis = if Self::period_contains_feb29(remaining_start, end) {
+        // Remaining stub over 366 if it contains Feb 29
+        let remaining_days = start.days_between(&remaining_end);
+        let basis = if Self::period_contains_feb29(start, remaining_end) {
             366
         } else {
             365
@@ -0,0 +1,111 @@
+//! Business/252 day count convention.
+//!
+//! Used for Brazilian fixed income (LTN, NTN-F, DI futures).
+
+use rust_decimal::Decimal;
+
+use super::DayCount;
+use crate::calendars::MarketCalendar;
+use crate::types::Date;
+
+/// Business/252 day count convention.
+///
+/// The day count is the number of business days between dates in the given
+/// holiday calendar, excluding the start date and including the end date.
+/// The year basis is always 252 business days.
+///
+/// # Usage
+///
+/// - Brazilian government bonds (LTN, NTN-F, NTN-B)
+/// - DI futures and other BRL money market instruments
+///
+/// # Formula
+///
+/// $$\text{Year Fraction} = \frac{\text{Business Days}}{252}$$
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Business252 {
+    calendar: MarketCalendar,
+}
+
+impl Business252 {
+    /// Creates a Business/252 day count over the given holiday calendar.
+    #[must_use]
+    pub const fn new(calendar: MarketCalendar) -> Self {
+        Self { calendar }
+    }
+
+    /// Returns the holiday calendar used to count business days.
+    #[must_use]
+    pub const fn calendar(&self) -> MarketCalendar {
+        self.calendar
+    }
+}
+
+impl Default for Business252 {
+    fn default() -> Self {
+        Self::new(MarketCalendar::Brazil)
+    }
+}
+
+impl DayCount for Business252 {
+    fn name(&self) -> &'static str {
+        match self.calendar {
+            MarketCalendar::Weekend => "BUS/252 Weekend",
+            MarketCalendar::Sifma => "BUS/252 SIFMA",
+            MarketCalendar::Target2 => "BUS/252 TARGET2",
+            MarketCalendar::Uk => "BUS/252 UK",
+            MarketCalendar::Japan => "BUS/252 Japan",
+            MarketCalendar::Brazil => "BUS/252",
+        }
+    }
+
+    fn year_fraction(&self, start: Date, end: Date) -> Decimal {
+        Decimal::from(self.day_count(start, end)) / Decimal::from(252)
+    }
+
+    fn day_count(&self, start: Date, end: Date) -> i64 {
+        let calendar = self.calendar.calendar();
+        if end >= start {
+            i64::from(calendar.business_days_between(start, end))
+        } else {
+            -i64::from(calendar.business_days_between(end, start))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daycounts::Act365Fixed;
+
+    #[test]
+    fn test_business252_spans_brazilian_holidays() {
+        let dc = Business252::default();
+
+        // Mon 2025-04-14 to Mon 2025-04-28 spans Good Friday (Apr 18) and
+        // Tiradentes (Mon Apr 21): 14 calendar days, 8 business days.
+        let start = Date::from_ymd(2025, 4, 14).unwrap();
+        let end = Date::from_ymd(2025, 4, 28).unwrap();
+
+        assert_eq!(Act365Fixed.day_count(start, end), 14);
+        assert_eq!(dc.day_count(start, end), 8);
+        assert_eq!(
+            dc.year_fraction(start, end),
+            Decimal::from(8) / Decimal::from(252)
+        );
+
+        // Weekend-only calendar counts the two holidays.
+        let weekend = Business252::new(MarketCalendar::Weekend);
+        assert_eq!(weekend.day_count(start, end), 10);
+    }
+
+    #[test]
+    fn test_business252_reversed_dates_negative() {
+        let dc = Business252::default();
+        let start = Date::from_ymd(2025, 4, 14).unwrap();
+        let end = Date::from_ymd(2025, 4, 28).unwrap();
+
+        assert_eq!(dc.day_count(end, start), -8);
+        assert_eq!(dc.day_count(start, start), 0);
+    }
+}
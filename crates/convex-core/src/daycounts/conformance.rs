@@ -0,0 +1,150 @@
+//! Day count conformance suite.
+//!
+//! Reference date pairs and expected year fractions drawn from the ISDA 2006
+//! Definitions (§4.16) and the ISDA ACT/ACT comparison memo, with extra rows
+//! for the February end-of-month and 31st-to-30th rolls. Every row must match
+//! to 1e-10.
+
+use rust_decimal::prelude::ToPrimitive;
+
+use super::{ActActAfb, ActActIcma, ActActIsda, DayCount, Thirty360E, Thirty360EIsda, Thirty360US};
+use crate::types::Date;
+
+const TOLERANCE: f64 = 1e-10;
+
+/// One reference row. `period` carries the ICMA coupon period; other
+/// conventions ignore it.
+struct Case {
+    label: &'static str,
+    dc: Box<dyn DayCount>,
+    start: Date,
+    end: Date,
+    period: Option<(Date, Date)>,
+    expected: f64,
+}
+
+fn d(y: i32, m: u32, day: u32) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+fn case(
+    label: &'static str,
+    dc: impl DayCount + 'static,
+    start: Date,
+    end: Date,
+    expected: f64,
+) -> Case {
+    Case {
+        label,
+        dc: Box::new(dc),
+        start,
+        end,
+        period: None,
+        expected,
+    }
+}
+
+fn icma(
+    label: &'static str,
+    frequency: u32,
+    start: Date,
+    end: Date,
+    period: (Date, Date),
+    expected: f64,
+) -> Case {
+    Case {
+        label,
+        dc: Box::new(ActActIcma::new(frequency)),
+        start,
+        end,
+        period: Some(period),
+        expected,
+    }
+}
+
+#[rustfmt::skip]
+fn reference_table() -> Vec<Case> {
+    vec![
+        // 30/360 US: D1 Feb EOM → 30; D2 Feb EOM → 30 only if D1 was Feb EOM;
+        // D2 31 → 30 only if D1 ≥ 30.
+        case("30/360 US regular", Thirty360US, d(2006, 8, 20), d(2007, 2, 20), 180.0 / 360.0),
+        case("30/360 US Feb EOM to 31st", Thirty360US, d(2007, 2, 28), d(2007, 8, 31), 180.0 / 360.0),
+        case("30/360 US 31st to leap Feb EOM", Thirty360US, d(2007, 8, 31), d(2008, 2, 29), 179.0 / 360.0),
+        case("30/360 US leap Feb EOM to 31st", Thirty360US, d(2008, 2, 29), d(2008, 8, 31), 180.0 / 360.0),
+        case("30/360 US 31st to Feb EOM", Thirty360US, d(2008, 8, 31), d(2009, 2, 28), 178.0 / 360.0),
+        case("30/360 US Feb EOM to Feb EOM", Thirty360US, d(2007, 2, 28), d(2008, 2, 29), 1.0),
+        case("30/360 US 31st to Feb", Thirty360US, d(2007, 1, 31), d(2007, 2, 28), 28.0 / 360.0),
+        case("30/360 US mid-month to 31st", Thirty360US, d(2007, 1, 15), d(2007, 1, 31), 16.0 / 360.0),
+        case("30/360 US 30th to 31st", Thirty360US, d(2007, 4, 30), d(2007, 5, 31), 30.0 / 360.0),
+
+        // 30E/360 (Eurobond): 31 → 30 on either date, February untouched.
+        case("30E/360 Feb EOM to 31st", Thirty360E, d(2006, 2, 28), d(2006, 8, 31), 182.0 / 360.0),
+        case("30E/360 31st to Feb EOM", Thirty360E, d(2006, 8, 31), d(2007, 2, 28), 178.0 / 360.0),
+        case("30E/360 Feb EOM to 31st (2007)", Thirty360E, d(2007, 2, 28), d(2007, 8, 31), 182.0 / 360.0),
+        case("30E/360 31st to leap Feb EOM", Thirty360E, d(2007, 8, 31), d(2008, 2, 29), 179.0 / 360.0),
+        case("30E/360 leap Feb EOM to 31st", Thirty360E, d(2008, 2, 29), d(2008, 8, 31), 181.0 / 360.0),
+        case("30E/360 31st to Feb EOM (2009)", Thirty360E, d(2008, 8, 31), d(2009, 2, 28), 178.0 / 360.0),
+        case("30E/360 Feb EOM to 31st (2009)", Thirty360E, d(2009, 2, 28), d(2009, 8, 31), 182.0 / 360.0),
+
+        // 30E/360 ISDA, termination 2009-02-28: any EOM D1 → 30; D2 31 → 30;
+        // D2 Feb EOM → 30 unless it is the termination date.
+        case("30E/360 ISDA Feb EOM to 31st", Thirty360EIsda::new(Some(d(2009, 2, 28))), d(2006, 2, 28), d(2006, 8, 31), 180.0 / 360.0),
+        case("30E/360 ISDA 31st to Feb EOM", Thirty360EIsda::new(Some(d(2009, 2, 28))), d(2006, 8, 31), d(2007, 2, 28), 180.0 / 360.0),
+        case("30E/360 ISDA Feb EOM to 31st (2007)", Thirty360EIsda::new(Some(d(2009, 2, 28))), d(2007, 2, 28), d(2007, 8, 31), 180.0 / 360.0),
+        case("30E/360 ISDA 31st to leap Feb EOM", Thirty360EIsda::new(Some(d(2009, 2, 28))), d(2007, 8, 31), d(2008, 2, 29), 180.0 / 360.0),
+        case("30E/360 ISDA leap Feb EOM to 31st", Thirty360EIsda::new(Some(d(2009, 2, 28))), d(2008, 2, 29), d(2008, 8, 31), 180.0 / 360.0),
+        case("30E/360 ISDA to termination Feb EOM", Thirty360EIsda::new(Some(d(2009, 2, 28))), d(2008, 8, 31), d(2009, 2, 28), 178.0 / 360.0),
+        case("30E/360 ISDA Feb EOM to 31st (2009)", Thirty360EIsda::new(Some(d(2009, 2, 28))), d(2009, 2, 28), d(2009, 8, 31), 180.0 / 360.0),
+        case("30E/360 ISDA termination on 31st", Thirty360EIsda::new(Some(d(2009, 8, 31))), d(2009, 2, 28), d(2009, 8, 31), 180.0 / 360.0),
+
+        // ACT/ACT ISDA: days in each calendar year over that year's length.
+        case("ACT/ACT ISDA regular semi-annual", ActActIsda, d(2003, 11, 1), d(2004, 5, 1), 61.0 / 365.0 + 121.0 / 366.0),
+        case("ACT/ACT ISDA short first", ActActIsda, d(1999, 2, 1), d(1999, 7, 1), 150.0 / 365.0),
+        case("ACT/ACT ISDA long first", ActActIsda, d(2002, 8, 15), d(2003, 7, 15), 334.0 / 365.0),
+        case("ACT/ACT ISDA short final", ActActIsda, d(2000, 1, 30), d(2000, 6, 30), 152.0 / 366.0),
+        case("ACT/ACT ISDA long final", ActActIsda, d(1999, 11, 30), d(2000, 4, 30), 32.0 / 365.0 + 120.0 / 366.0),
+        case("ACT/ACT ISDA across Feb 29", ActActIsda, d(2004, 2, 28), d(2004, 3, 1), 2.0 / 366.0),
+
+        // ACT/ACT ICMA: days over frequency × days in the (quasi-)coupon period.
+        icma("ACT/ACT ICMA regular semi-annual", 2, d(2003, 11, 1), d(2004, 5, 1), (d(2003, 11, 1), d(2004, 5, 1)), 0.5),
+        icma("ACT/ACT ICMA short first", 1, d(1999, 2, 1), d(1999, 7, 1), (d(1998, 7, 1), d(1999, 7, 1)), 150.0 / 365.0),
+        icma("ACT/ACT ICMA long first", 2, d(2002, 8, 15), d(2003, 7, 15), (d(2003, 1, 15), d(2003, 7, 15)), 181.0 / 362.0 + 153.0 / 368.0),
+        icma("ACT/ACT ICMA short final", 2, d(2000, 1, 30), d(2000, 6, 30), (d(2000, 1, 30), d(2000, 7, 30)), 152.0 / 364.0),
+        icma("ACT/ACT ICMA long final EOM", 4, d(1999, 11, 30), d(2000, 4, 30), (d(1999, 11, 30), d(2000, 2, 29)), 91.0 / 364.0 + 61.0 / 368.0),
+
+        // ACT/ACT AFB: whole years counted back from the end date, remainder
+        // over 366 if it contains Feb 29.
+        case("ACT/ACT AFB regular semi-annual", ActActAfb, d(2003, 11, 1), d(2004, 5, 1), 182.0 / 366.0),
+        case("ACT/ACT AFB short first", ActActAfb, d(1999, 2, 1), d(1999, 7, 1), 150.0 / 365.0),
+        case("ACT/ACT AFB long first", ActActAfb, d(2002, 8, 15), d(2003, 7, 15), 334.0 / 365.0),
+        case("ACT/ACT AFB short final", ActActAfb, d(2000, 1, 30), d(2000, 6, 30), 152.0 / 366.0),
+        case("ACT/ACT AFB long final", ActActAfb, d(1999, 11, 30), d(2000, 4, 30), 152.0 / 366.0),
+        case("ACT/ACT AFB multi-year", ActActAfb, d(1994, 2, 10), d(1997, 6, 30), 3.0 + 140.0 / 365.0),
+        case("ACT/ACT AFB to Feb 29", ActActAfb, d(2004, 2, 28), d(2008, 2, 29), 4.0 + 1.0 / 366.0),
+        case("ACT/ACT AFB Feb 28 to Feb 29", ActActAfb, d(2003, 2, 28), d(2008, 2, 29), 5.0),
+        case("ACT/ACT AFB year containing Feb 29", ActActAfb, d(2003, 3, 1), d(2004, 2, 29), 365.0 / 366.0),
+        case("ACT/ACT AFB from Feb 29", ActActAfb, d(2004, 2, 29), d(2005, 2, 28), 1.0),
+    ]
+}
+
+#[test]
+fn test_day_count_conformance() {
+    let mut failures = Vec::new();
+    for case in reference_table() {
+        let yf = match case.period {
+            Some((period_start, period_end)) => {
+                case.dc
+                    .period_year_fraction(case.start, case.end, period_start, period_end)
+            }
+            None => case.dc.year_fraction(case.start, case.end),
+        };
+        let actual = yf.to_f64().unwrap();
+        if (actual - case.expected).abs() > TOLERANCE {
+            failures.push(format!(
+                "{} ({} → {}): expected {:.12}, got {:.12}",
+                case.label, case.start, case.end, case.expected, actual
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -21,6 +21,10 @@
 //! - [`Thirty360EIsda`]: 30E/360 ISDA - ISDA swap convention
 //! - [`Thirty360German`]: 30/360 German - German market convention
 //!
+//! ## Business-day Family
+//!
+//! - [`Business252`]: BUS/252 - Brazilian fixed income, over a holiday calendar
+//!
 //! # Usage
 //!
 //! ```rust
@@ -45,13 +49,18 @@
 mod act360;
 mod act365;
 mod actact;
+mod business252;
+#[cfg(test)]
+mod conformance;
 mod thirty360;
 
 pub use act360::Act360;
 pub use act365::{Act365, Act365Fixed, Act365Leap};
 pub use actact::{ActActAfb, ActActIcma, ActActIsda};
+pub use business252::Business252;
 pub use thirty360::{Thirty360, Thirty360E, Thirty360EIsda, Thirty360German, Thirty360US};
 
+use crate::calendars::MarketCalendar;
 use crate::types::Date;
 use rust_decimal::Decimal;
 
@@ -160,6 +169,15 @@ pub enum DayCountConvention {
 
     /// 30/360 German - German market convention
     Thirty360German,
+
+    // =========================================================================
+    // Business-day Family
+    // =========================================================================
+    /// BUS/252 - Business days in `calendar` over 252 (Brazilian bonds)
+    Business252 {
+        /// Holiday calendar used to count business days.
+        calendar: MarketCalendar,
+    },
 }
 
 impl DayCountConvention {
@@ -184,11 +202,15 @@ impl DayCountConvention {
             DayCountConvention::Thirty360E => Box::new(Thirty360E),
             DayCountConvention::Thirty360EIsda => Box::new(Thirty360EIsda::default()),
             DayCountConvention::Thirty360German => Box::new(Thirty360German),
+
+            // Business-day Family
+            DayCountConvention::Business252 { calendar } => Box::new(Business252::new(*calendar)),
         }
     }
 
     /// Nominal year basis used for date↔tenor conversions. ACT/360 → 360,
-    /// ACT/365* → 365, ACT/ACT* → 365, 30/360 family → 360.
+    /// ACT/365* → 365, ACT/ACT* → 365, 30/360 family → 360. BUS/252 → 365,
+    /// since tenors are measured in calendar days.
     #[must_use]
     pub fn nominal_days_per_year(&self) -> u32 {
         match self {
@@ -202,6 +224,7 @@ impl DayCountConvention {
             | DayCountConvention::Thirty360E
             | DayCountConvention::Thirty360EIsda
             | DayCountConvention::Thirty360German => 360,
+            DayCountConvention::Business252 { .. } => 365,
         }
     }
 
@@ -219,6 +242,7 @@ impl DayCountConvention {
             DayCountConvention::Thirty360E => "30E/360",
             DayCountConvention::Thirty360EIsda => "30E/360 ISDA",
             DayCountConvention::Thirty360German => "30/360 German",
+            DayCountConvention::Business252 { calendar } => Business252::new(*calendar).name(),
         }
     }
 
@@ -236,6 +260,9 @@ impl DayCountConvention {
             DayCountConvention::Thirty360E,
             DayCountConvention::Thirty360EIsda,
             DayCountConvention::Thirty360German,
+            DayCountConvention::Business252 {
+                calendar: MarketCalendar::Brazil,
+            },
         ]
     }
 }
@@ -288,7 +315,21 @@ impl std::str::FromStr for DayCountConvention {
             }
             "30E/360ISDA" => DayCountConvention::Thirty360EIsda,
             "30/360GERMAN" => DayCountConvention::Thirty360German,
-            _ => return Err(ParseDayCountError(s.to_string())),
+            other => {
+                // BUS/252 defaults to the Brazilian calendar; a suffix selects
+                // another one, e.g. "BUS/252 TARGET2".
+                let suffix = ["BUS/252", "BD/252", "ACT/252"]
+                    .iter()
+                    .find_map(|prefix| other.strip_prefix(prefix))
+                    .ok_or_else(|| ParseDayCountError(s.to_string()))?;
+                let calendar = if suffix.is_empty() {
+                    MarketCalendar::Brazil
+                } else {
+                    MarketCalendar::from_name(suffix)
+                        .ok_or_else(|| ParseDayCountError(s.to_string()))?
+                };
+                DayCountConvention::Business252 { calendar }
+            }
         };
         Ok(dc)
     }
@@ -344,6 +385,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_business252() {
+        let brazil = DayCountConvention::Business252 {
+            calendar: MarketCalendar::Brazil,
+        };
+        assert_eq!(DayCountConvention::from_str("BUS/252").unwrap(), brazil);
+        assert_eq!(DayCountConvention::from_str("ACT/252").unwrap(), brazil);
+        assert_eq!(
+            DayCountConvention::from_str("BUS/252 TARGET2").unwrap(),
+            DayCountConvention::Business252 {
+                calendar: MarketCalendar::Target2
+            }
+        );
+        assert!(DayCountConvention::from_str("BUS/252 NOWHERE").is_err());
+    }
+
     #[test]
     fn test_parse_unknown_errors() {
         assert!(DayCountConvention::from_str("MADE-UP/360").is_err());
@@ -180,9 +180,11 @@ impl DayCount for Thirty360E {
 /// # Rules
 ///
 /// 1. If D1 is the last day of the month, change D1 to 30
-/// 2. If D2 is the last day of the month (but not the maturity date), change D2 to 30
+/// 2. If D2 is the 31st, or the last day of February but not the maturity
+///    date, change D2 to 30
 ///
-/// The maturity date exception means D2 = 31 stays as 31 on the final payment.
+/// The maturity date exception only keeps a February end-of-month D2 (28/29)
+/// on the final payment, per ISDA 2006 §4.16(h).
 ///
 /// # Bloomberg
 ///
@@ -242,10 +244,10 @@ impl DayCount for Thirty360EIsda {
             d1 = 30;
         }
 
-        // Rule 2: If D2 is the last day of the month AND
+        // Rule 2: If D2 is the 31st, or the last day of February AND
         // (no termination date specified OR end != termination date), change D2 to 30
         let is_maturity = self.termination_date == Some(end);
-        if end.is_end_of_month() && !is_maturity {
+        if d2 == 31 || (is_last_day_of_february(end) && !is_maturity) {
             d2 = 30;
         }
 
@@ -527,16 +529,28 @@ mod tests {
 
     #[test]
     fn test_thirty360e_isda_maturity_exception() {
-        // With termination date
+        // February end-of-month on the termination date is not rolled to 30
+        let termination = Date::from_ymd(2025, 2, 28).unwrap();
+        let dc = Thirty360EIsda::new(Some(termination));
+
+        let start = Date::from_ymd(2024, 11, 30).unwrap();
+
+        // D1 = 30 (EOM), D2 = 28 (Feb EOM, but maturity)
+        // Days = 30 * 3 + (28-30) = 88
+        assert_eq!(dc.day_count(start, termination), 88);
+    }
+
+    #[test]
+    fn test_thirty360e_isda_maturity_on_31st() {
+        // The maturity exception applies to February only; a 31st still rolls
         let termination = Date::from_ymd(2025, 3, 31).unwrap();
         let dc = Thirty360EIsda::new(Some(termination));
 
         let start = Date::from_ymd(2025, 1, 31).unwrap();
-        let end = termination;
 
-        // D1 = 30 (EOM), D2 = 31 (stays because it's maturity)
-        // Days = 30 * 2 + (31-30) = 61
-        assert_eq!(dc.day_count(start, end), 61);
+        // D1 = 30 (EOM), D2 = 30 (31st)
+        // Days = 30 * 2 + (30-30) = 60
+        assert_eq!(dc.day_count(start, termination), 60);
     }
 
     #[test]
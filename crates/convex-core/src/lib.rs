@@ -29,6 +29,7 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+pub mod build_info;
 pub mod calendars;
 pub mod daycounts;
 pub mod error;
@@ -51,6 +52,7 @@ pub mod prelude {
 }
 
 // Re-export commonly used types at crate root
+pub use build_info::{build_info, BuildInfo};
 pub use error::{ConvexError, ConvexResult};
 pub use types::{Currency, Date, Price, Yield};
 pub mod ids;
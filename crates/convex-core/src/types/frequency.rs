@@ -1,5 +1,7 @@
-//! Frequency and compounding types.
+//! Frequency and compounding types, and conversions between yield bases.
 
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -18,6 +20,46 @@ pub enum Frequency {
     Monthly,
     /// Zero coupon (no periodic payments)
     Zero,
+    /// Payments every `n` months outside the standard set (e.g. 2 for
+    /// bi-monthly).
+    CustomMonths(MonthStep),
+}
+
+/// Period length in months for [`Frequency::CustomMonths`].
+///
+/// Always divides 12, so a year holds whole periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MonthStep(u32);
+
+impl MonthStep {
+    /// Step of `months` months, or `None` unless `months` is positive and
+    /// divides 12.
+    #[must_use]
+    pub fn new(months: u32) -> Option<Self> {
+        (months > 0 && 12 % months == 0).then_some(Self(months))
+    }
+
+    /// Number of months per period.
+    #[must_use]
+    pub const fn months(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<u32> for MonthStep {
+    type Error = String;
+
+    fn try_from(months: u32) -> Result<Self, Self::Error> {
+        Self::new(months).ok_or_else(|| format!("{months} months does not divide a year"))
+    }
+}
+
+impl From<MonthStep> for u32 {
+    fn from(step: MonthStep) -> Self {
+        step.0
+    }
 }
 
 impl Frequency {
@@ -30,6 +72,7 @@ impl Frequency {
             Frequency::Quarterly => 4,
             Frequency::Monthly => 12,
             Frequency::Zero => 0,
+            Frequency::CustomMonths(step) => 12 / step.months(),
         }
     }
 
@@ -42,7 +85,33 @@ impl Frequency {
             Frequency::Quarterly => 3,
             Frequency::Monthly => 1,
             Frequency::Zero => 0,
+            Frequency::CustomMonths(step) => step.months(),
+        }
+    }
+
+    /// Frequency paying every `months` months, using the standard variant
+    /// where one exists (3 gives `Quarterly`) and `Zero` for 0. `None`
+    /// unless `months` is 0 or divides 12, so a year holds whole periods.
+    #[must_use]
+    pub fn try_from_months(months: u32) -> Option<Frequency> {
+        Some(match months {
+            0 => Frequency::Zero,
+            1 => Frequency::Monthly,
+            3 => Frequency::Quarterly,
+            6 => Frequency::SemiAnnual,
+            12 => Frequency::Annual,
+            other => Frequency::CustomMonths(MonthStep::new(other)?),
+        })
+    }
+
+    /// Frequency paying `periods` times a year, or `None` unless `periods`
+    /// is positive and divides 12.
+    #[must_use]
+    pub fn try_from_periods_per_year(periods: u32) -> Option<Frequency> {
+        if periods == 0 || 12 % periods != 0 {
+            return None;
         }
+        Self::try_from_months(12 / periods)
     }
 
     /// Returns true if this is a zero coupon (no periodic payments).
@@ -60,6 +129,7 @@ impl fmt::Display for Frequency {
             Frequency::Quarterly => "Quarterly",
             Frequency::Monthly => "Monthly",
             Frequency::Zero => "Zero Coupon",
+            Frequency::CustomMonths(step) => return write!(f, "Every {} Months", step.months()),
         };
         write!(f, "{name}")
     }
@@ -84,6 +154,48 @@ pub enum Compounding {
     Daily,
     /// Continuous compounding
     Continuous,
+    /// Periodic compounding `n` times a year, for counts without a named
+    /// variant (e.g. 6 for a bi-monthly coupon).
+    Periodic(PeriodCount),
+}
+
+/// Periods per year for [`Compounding::Periodic`].
+///
+/// Always positive and never a count with its own named variant, so each
+/// compounding has exactly one representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PeriodCount(u32);
+
+impl PeriodCount {
+    /// Count of `periods` a year, or `None` if `periods` is zero or has a
+    /// named [`Compounding`] variant (1, 2, 4, 12 or 365).
+    #[must_use]
+    pub fn new(periods: u32) -> Option<Self> {
+        (!matches!(periods, 0 | 1 | 2 | 4 | 12 | 365)).then_some(Self(periods))
+    }
+
+    /// Number of periods per year.
+    #[must_use]
+    pub const fn periods(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<u32> for PeriodCount {
+    type Error = String;
+
+    fn try_from(periods: u32) -> Result<Self, Self::Error> {
+        Self::new(periods)
+            .ok_or_else(|| format!("{periods} periods a year is zero or has a named compounding"))
+    }
+}
+
+impl From<PeriodCount> for u32 {
+    fn from(count: PeriodCount) -> Self {
+        count.0
+    }
 }
 
 impl Compounding {
@@ -100,6 +212,7 @@ impl Compounding {
             Compounding::Monthly => 12,
             Compounding::Daily => 365,
             Compounding::Continuous => u32::MAX, // Conceptually infinite
+            Compounding::Periodic(n) => n.periods(),
         }
     }
 
@@ -115,30 +228,28 @@ impl Compounding {
         matches!(self, Compounding::Simple)
     }
 
-    /// Periodic compounding for a periods-per-year count. Returns `None` for
-    /// values outside the supported set; callers that have already validated
-    /// the input can use [`Self::from_periods_per_year`] for the panicking
-    /// flavour.
+    /// Periodic compounding for a periods-per-year count, using the named
+    /// variant where one exists and [`Compounding::Periodic`] otherwise.
+    /// Zero periods (a zero-coupon frequency) gives `Continuous`.
     #[must_use]
-    pub fn try_from_periods_per_year(periods: u32) -> Option<Compounding> {
-        Some(match periods {
+    pub fn from_periods_per_year(periods: u32) -> Compounding {
+        match periods {
             0 => Compounding::Continuous,
             1 => Compounding::Annual,
             2 => Compounding::SemiAnnual,
             4 => Compounding::Quarterly,
             12 => Compounding::Monthly,
             365 => Compounding::Daily,
-            _ => return None,
-        })
+            n => Compounding::Periodic(PeriodCount(n)),
+        }
     }
 
-    /// Like [`Self::try_from_periods_per_year`] but panics on unsupported
-    /// counts. Use only when `periods` came from a closed source like
-    /// `Frequency::periods_per_year`.
+    /// Periodic compounding for a periods-per-year count. Every count now
+    /// has one, so this never returns `None`.
+    #[deprecated(note = "every count maps to a compounding; use `from_periods_per_year`")]
     #[must_use]
-    pub fn from_periods_per_year(periods: u32) -> Compounding {
-        Self::try_from_periods_per_year(periods)
-            .unwrap_or_else(|| panic!("unsupported compounding period count: {periods}"))
+    pub fn try_from_periods_per_year(periods: u32) -> Option<Compounding> {
+        Some(Self::from_periods_per_year(periods))
     }
 }
 
@@ -152,6 +263,7 @@ impl fmt::Display for Compounding {
             Compounding::Monthly => "Monthly",
             Compounding::Daily => "Daily",
             Compounding::Continuous => "Continuous",
+            Compounding::Periodic(n) => return write!(f, "{}x Per Year", n.periods()),
         };
         write!(f, "{name}")
     }
@@ -165,13 +277,96 @@ impl From<Frequency> for Compounding {
             Frequency::Quarterly => Compounding::Quarterly,
             Frequency::Monthly => Compounding::Monthly,
             Frequency::Zero => Compounding::Continuous, // Zero coupon typically uses continuous
+            Frequency::CustomMonths(_) => {
+                Compounding::from_periods_per_year(freq.periods_per_year())
+            }
         }
     }
 }
 
+/// Continuously compounded equivalent of `rate` under `compounding`.
+/// Simple interest is taken over one year, where it equals annual.
+fn to_continuous(rate: f64, compounding: Compounding) -> f64 {
+    match compounding {
+        Compounding::Continuous => rate,
+        Compounding::Simple => rate.ln_1p(),
+        other => {
+            let m = f64::from(other.periods_per_year());
+            m * (rate / m).ln_1p()
+        }
+    }
+}
+
+/// Inverse of [`to_continuous`].
+fn from_continuous(rate: f64, compounding: Compounding) -> f64 {
+    match compounding {
+        Compounding::Continuous => rate,
+        Compounding::Simple => rate.exp_m1(),
+        other => {
+            let m = f64::from(other.periods_per_year());
+            m * (rate / m).exp_m1()
+        }
+    }
+}
+
+/// Converts a yield between compounding bases.
+///
+/// The two rates give the same growth over any horizon:
+/// `(1 + r₁/m₁)^m₁ = (1 + r₂/m₂)^m₂`, with `e^r` for continuous. Simple
+/// interest has no horizon of its own and is converted over one year, where
+/// it equals the annual rate. Rates are decimals (0.05 = 5%).
+///
+/// # Example
+///
+/// ```rust
+/// use convex_core::types::{convert_yield, Compounding};
+/// use rust_decimal_macros::dec;
+///
+/// // 4% semi-annual is 4.04% annual: (1 + 0.04/2)² − 1.
+/// let annual = convert_yield(dec!(0.04), Compounding::SemiAnnual, Compounding::Annual);
+/// assert_eq!(annual.round_dp(10), dec!(0.0404));
+/// ```
+#[must_use]
+pub fn convert_yield(rate: Decimal, from: Compounding, to: Compounding) -> Decimal {
+    let one_year = |c| match c {
+        Compounding::Simple => Compounding::Annual,
+        other => other,
+    };
+    if one_year(from) == one_year(to) {
+        return rate;
+    }
+    let continuous = to_continuous(rate.to_f64().unwrap_or(0.0), from);
+    Decimal::from_f64_retain(from_continuous(continuous, to)).unwrap_or(Decimal::ZERO)
+}
+
+/// Converts a money-market (ACT/360 add-on) yield to a bond-equivalent
+/// (semi-annual, ACT/365) yield for an instrument with `days_to_maturity`
+/// days left.
+///
+/// Up to 182 days the instrument pays once, so the conversion is a day-basis
+/// rescale, `r × 365/360`. Beyond that it is compared with a semi-annual
+/// bond paying a coupon at six months: the bond-equivalent yield `y` solves
+/// `(1 + y/2)(1 + y(t − ½)) = 1 + r·d/360`, with `t = d/365`.
+#[must_use]
+pub fn money_market_to_bond_equivalent(rate: Decimal, days_to_maturity: u32) -> Decimal {
+    let r = rate.to_f64().unwrap_or(0.0);
+    let days = f64::from(days_to_maturity);
+    let bey = if days_to_maturity <= 182 {
+        r * 365.0 / 360.0
+    } else {
+        let growth = 1.0 + r * days / 360.0;
+        let t = days / 365.0;
+        // (t − ½)/2 · y² + t · y + (1 − growth) = 0, taking the positive root.
+        let a = 2.0 * t - 1.0;
+        (-2.0 * t + 2.0 * (t * t + a * (growth - 1.0)).sqrt()) / a
+    };
+    Decimal::from_f64_retain(bey).unwrap_or(Decimal::ZERO)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_frequency_periods() {
@@ -180,6 +375,58 @@ mod tests {
         assert_eq!(Frequency::Quarterly.periods_per_year(), 4);
         assert_eq!(Frequency::Monthly.periods_per_year(), 12);
         assert_eq!(Frequency::Zero.periods_per_year(), 0);
+        let bimonthly = Frequency::CustomMonths(MonthStep::new(2).unwrap());
+        assert_eq!(bimonthly.periods_per_year(), 6);
+    }
+
+    #[test]
+    fn test_checked_frequency_constructors() {
+        assert_eq!(Frequency::try_from_months(3), Some(Frequency::Quarterly));
+        assert_eq!(
+            Frequency::try_from_months(4),
+            Some(Frequency::CustomMonths(MonthStep::new(4).unwrap()))
+        );
+        assert_eq!(Frequency::try_from_months(0), Some(Frequency::Zero));
+        assert_eq!(Frequency::try_from_months(5), None);
+        assert_eq!(Frequency::try_from_months(24), None);
+
+        assert_eq!(
+            Frequency::try_from_periods_per_year(2),
+            Some(Frequency::SemiAnnual)
+        );
+        assert_eq!(
+            Frequency::try_from_periods_per_year(6),
+            Some(Frequency::CustomMonths(MonthStep::new(2).unwrap()))
+        );
+        assert_eq!(Frequency::try_from_periods_per_year(0), None);
+        assert_eq!(Frequency::try_from_periods_per_year(5), None);
+        assert_eq!(
+            Frequency::CustomMonths(MonthStep::new(4).unwrap()).periods_per_year(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_month_step_rejects_uneven_years() {
+        assert_eq!(MonthStep::new(2).map(MonthStep::months), Some(2));
+        assert_eq!(MonthStep::new(0), None);
+        assert_eq!(MonthStep::new(5), None);
+        assert_eq!(MonthStep::new(24), None);
+
+        let step: MonthStep = serde_json::from_str("4").unwrap();
+        assert_eq!(step.months(), 4);
+        assert!(serde_json::from_str::<MonthStep>("5").is_err());
+        let json = serde_json::to_string(&Frequency::CustomMonths(step)).unwrap();
+        assert_eq!(json, r#"{"CustomMonths":4}"#);
+        assert!(serde_json::from_str::<Frequency>(r#"{"CustomMonths":5}"#).is_err());
+    }
+
+    #[test]
+    fn test_frequency_from_months() {
+        assert_eq!(Frequency::try_from_months(3), Some(Frequency::Quarterly));
+        let bimonthly = Frequency::try_from_months(2).unwrap();
+        assert_eq!(bimonthly.months_per_period(), 2);
+        assert_eq!(bimonthly.to_string(), "Every 2 Months");
     }
 
     #[test]
@@ -193,5 +440,100 @@ mod tests {
     fn test_frequency_to_compounding() {
         let comp: Compounding = Frequency::SemiAnnual.into();
         assert_eq!(comp, Compounding::SemiAnnual);
+
+        let bimonthly = Frequency::try_from_months(2).unwrap();
+        let six = Compounding::Periodic(PeriodCount::new(6).unwrap());
+        assert_eq!(Compounding::from(bimonthly), six);
+        assert_eq!(six.periods_per_year(), 6);
+        assert_eq!(six.to_string(), "6x Per Year");
+        assert_eq!(
+            Compounding::from_periods_per_year(3),
+            Compounding::Periodic(PeriodCount::new(3).unwrap())
+        );
+        assert_eq!(
+            Compounding::from_periods_per_year(4),
+            Compounding::Quarterly
+        );
+    }
+
+    #[test]
+    fn test_period_count_is_canonical() {
+        assert_eq!(PeriodCount::new(6).map(PeriodCount::periods), Some(6));
+        for named in [0, 1, 2, 4, 12, 365] {
+            assert_eq!(PeriodCount::new(named), None, "{named}");
+        }
+        assert!(serde_json::from_str::<PeriodCount>("0").is_err());
+        assert!(serde_json::from_str::<Compounding>(r#"{"Periodic":2}"#).is_err());
+        let json = serde_json::to_string(&Compounding::from_periods_per_year(6)).unwrap();
+        assert_eq!(json, r#"{"Periodic":6}"#);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_try_from_periods_per_year() {
+        assert_eq!(
+            Compounding::try_from_periods_per_year(2),
+            Some(Compounding::SemiAnnual)
+        );
+        assert_eq!(
+            Compounding::try_from_periods_per_year(0),
+            Some(Compounding::Continuous)
+        );
+        assert_eq!(
+            Compounding::try_from_periods_per_year(6),
+            Some(Compounding::from_periods_per_year(6))
+        );
+    }
+
+    #[test]
+    fn test_convert_yield_periodic() {
+        // (1 + 0.06/6)⁶ − 1 annual
+        let annual = convert_yield(
+            dec!(0.06),
+            Compounding::from_periods_per_year(6),
+            Compounding::Annual,
+        );
+        let expected = 1.01_f64.powi(6) - 1.0;
+        assert!((annual.to_f64().unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_convert_yield_semi_continuous_round_trip() {
+        for rate in [dec!(0.0001), dec!(0.0425), dec!(0.12)] {
+            let continuous = convert_yield(rate, Compounding::SemiAnnual, Compounding::Continuous);
+            assert!(continuous < rate);
+            let back = convert_yield(continuous, Compounding::Continuous, Compounding::SemiAnnual);
+            assert!((back - rate).abs() < dec!(1e-15), "{rate} -> {back}");
+        }
+    }
+
+    #[test]
+    fn test_convert_yield_semi_to_annual_textbook() {
+        // (1 + 0.05/2)² − 1 = 0.050625
+        let annual = convert_yield(dec!(0.05), Compounding::SemiAnnual, Compounding::Annual);
+        assert!((annual - dec!(0.050625)).abs() < dec!(1e-15));
+        let semi = convert_yield(dec!(0.050625), Compounding::Annual, Compounding::SemiAnnual);
+        assert!((semi - dec!(0.05)).abs() < dec!(1e-15));
+        assert_eq!(
+            convert_yield(dec!(0.05), Compounding::Simple, Compounding::Annual),
+            dec!(0.05)
+        );
+    }
+
+    #[test]
+    fn test_money_market_to_bond_equivalent() {
+        // Short end: day-basis rescale only.
+        let short = money_market_to_bond_equivalent(dec!(0.05), 90);
+        assert!((short - dec!(0.05) * dec!(365) / dec!(360)).abs() < dec!(1e-15));
+
+        // Long end: same growth as a semi-annual bond over the period.
+        let days = 270.0;
+        let bey = money_market_to_bond_equivalent(dec!(0.05), 270)
+            .to_f64()
+            .unwrap();
+        let t = days / 365.0;
+        let bond_growth = (1.0 + bey / 2.0) * (1.0 + bey * (t - 0.5));
+        assert!((bond_growth - (1.0 + 0.05 * days / 360.0)).abs() < 1e-12);
+        assert!(bey > 0.05 * 365.0 / 360.0 - 1e-3 && bey < 0.05 * 365.0 / 360.0);
     }
 }
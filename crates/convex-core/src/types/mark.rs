@@ -102,6 +102,7 @@ impl FromStr for Mark {
     ///                                                        e.g. "+125bps@USD.SOFR",
     ///                                                        "125 OAS@USD.TSY"
     ///   FREQ        := A | SA | Q | M | CONT | ANNUAL | SEMI | SEMI_ANNUAL | QUARTERLY | MONTHLY
+    ///                | BM | BIMONTHLY | <int>'M'        (every n months, n dividing 12, e.g. "2M")
     ///   SPREADTYPE  := Z | G | I | OAS | DM | ASW | ASW_PROC | CREDIT  (default Z)
     ///   BENCH       := any non-whitespace identifier (e.g. USD.SOFR, USD.TSY.10Y)
     /// ```
@@ -214,7 +215,13 @@ fn parse_frequency(token: &str) -> Result<Frequency, MarkParseError> {
         "Q" | "QTR" | "QUARTERLY" => Ok(Frequency::Quarterly),
         "M" | "MO" | "MONTHLY" => Ok(Frequency::Monthly),
         "Z" | "ZERO" => Ok(Frequency::Zero),
-        other => Err(MarkParseError(format!("unknown frequency {other:?}"))),
+        "BM" | "BIMONTHLY" | "BI-MONTHLY" => Ok(Frequency::try_from_months(2).unwrap()),
+        other => other
+            .strip_suffix('M')
+            .and_then(|months| months.parse::<u32>().ok())
+            .filter(|&months| months > 0)
+            .and_then(Frequency::try_from_months)
+            .ok_or_else(|| MarkParseError(format!("unknown frequency {other:?}"))),
     }
 }
 
@@ -397,6 +404,23 @@ mod tests {
                 frequency: Frequency::Quarterly
             }
         );
+        assert_eq!(
+            parse("4.65%@BM"),
+            Mark::Yield {
+                value: dec!(0.0465),
+                frequency: Frequency::try_from_months(2).unwrap()
+            }
+        );
+        assert_eq!(
+            parse("4.65%@3M"),
+            Mark::Yield {
+                value: dec!(0.0465),
+                frequency: Frequency::Quarterly
+            }
+        );
+        // A year must hold whole periods
+        assert!("4.65%@5M".parse::<Mark>().is_err());
+        assert!("4.65%@24M".parse::<Mark>().is_err());
     }
 
     #[test]
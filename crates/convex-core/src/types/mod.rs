@@ -23,7 +23,9 @@ mod yield_type;
 pub use cashflow::{CashFlow, CashFlowSchedule, CashFlowType};
 pub use currency::Currency;
 pub use date::Date;
-pub use frequency::{Compounding, Frequency};
+pub use frequency::{
+    convert_yield, money_market_to_bond_equivalent, Compounding, Frequency, MonthStep, PeriodCount,
+};
 pub use mark::{Mark, PriceKind};
 pub use price::Price;
 pub use spread::{Spread, SpreadType};
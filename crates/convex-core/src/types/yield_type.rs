@@ -4,7 +4,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-use super::Compounding;
+use super::{convert_yield, Compounding};
 use crate::error::{ConvexError, ConvexResult};
 
 /// A yield value with compounding convention.
@@ -99,32 +99,11 @@ impl Yield {
 
     /// Converts the yield to a different compounding convention.
     ///
-    /// Uses the formula for equivalent yields:
-    /// `(1 + r1/n1)^n1 = (1 + r2/n2)^n2`
+    /// See [`convert_yield`] for the equivalence used.
     #[must_use]
     pub fn convert_to(&self, target: Compounding) -> Self {
-        if self.compounding == target {
-            return *self;
-        }
-
-        let n1 = f64::from(self.compounding.periods_per_year());
-        let n2 = f64::from(target.periods_per_year());
-        let r1 = self.value.to_string().parse::<f64>().unwrap_or(0.0);
-
-        // (1 + r1/n1)^n1 = (1 + r2/n2)^n2
-        // r2 = n2 * ((1 + r1/n1)^(n1/n2) - 1)
-        let r2 = if target == Compounding::Continuous {
-            // Convert to continuous: r_c = n * ln(1 + r/n)
-            n1 * (1.0 + r1 / n1).ln()
-        } else if self.compounding == Compounding::Continuous {
-            // Convert from continuous: r = n * (e^(r_c/n) - 1)
-            n2 * ((r1 / n2).exp() - 1.0)
-        } else {
-            n2 * ((1.0 + r1 / n1).powf(n1 / n2) - 1.0)
-        };
-
         Self {
-            value: Decimal::from_f64_retain(r2).unwrap_or(self.value),
+            value: convert_yield(self.value, self.compounding, target),
             compounding: target,
         }
     }
@@ -89,8 +89,13 @@ pub struct CurveBuilder {
     default_interpolation: InterpolationMethod,
     /// Default extrapolation method.
     default_extrapolation: ExtrapolationMethod,
+    /// Fail the build on interpolation-induced negative forwards.
+    require_positive_forwards: bool,
 }
 
+/// Sampling step (years) when scanning a built curve for negative forwards.
+const FORWARD_SCAN_STEP: f64 = 1.0 / 52.0;
+
 /// Configuration for a single segment.
 #[derive(Debug, Clone)]
 struct SegmentConfig {
@@ -202,6 +207,7 @@ impl CurveBuilder {
             segments: Vec::new(),
             default_interpolation: InterpolationMethod::MonotoneConvex,
             default_extrapolation: ExtrapolationMethod::Flat,
+            require_positive_forwards: false,
         }
     }
 
@@ -214,6 +220,7 @@ impl CurveBuilder {
             segments: Vec::new(),
             default_interpolation: InterpolationMethod::PiecewiseConstant,
             default_extrapolation: ExtrapolationMethod::Flat,
+            require_positive_forwards: false,
         }
     }
 
@@ -226,6 +233,7 @@ impl CurveBuilder {
             segments: Vec::new(),
             default_interpolation: InterpolationMethod::Linear,
             default_extrapolation: ExtrapolationMethod::Linear,
+            require_positive_forwards: false,
         }
     }
 
@@ -243,6 +251,22 @@ impl CurveBuilder {
         self
     }
 
+    /// Fails the build if interpolation turns a segment's forward negative
+    /// between two pillars whose discrete forward is positive.
+    ///
+    /// Only segments built from discrete data with an interpolation that does
+    /// not guarantee positive forwards (see
+    /// [`InterpolationMethod::guarantees_positive_forwards`]) are scanned: a
+    /// cubic spline, for example, can overshoot between the pillars of a
+    /// humped curve. The build then returns [`CurveError::NegativeForward`].
+    /// Negative forwards the data itself implies, as in a negative-rate
+    /// market, are not flagged.
+    #[must_use]
+    pub fn require_positive_forwards(mut self) -> Self {
+        self.require_positive_forwards = true;
+        self
+    }
+
     // ========================================================================
     // Simple (single-segment) curve construction
     // ========================================================================
@@ -480,7 +504,11 @@ impl CurveBuilder {
         }
 
         let segment = &self.segments[0];
-        self.build_discrete_from_config(segment)
+        let curve = self.build_discrete_from_config(segment)?;
+        if self.require_positive_forwards {
+            self.check_positive_forwards(&curve, segment)?;
+        }
+        Ok(curve)
     }
 
     /// Builds a segmented curve (single or multiple segments).
@@ -497,6 +525,9 @@ impl CurveBuilder {
         let mut curve_segments = Vec::new();
         for config in &configs {
             let curve = self.build_curve_from_config(config)?;
+            if self.require_positive_forwards {
+                self.check_positive_forwards(&curve, config)?;
+            }
             let source = self.build_segment_source(config);
 
             curve_segments.push(CurveSegment::new(config.start, config.end, source, curve));
@@ -585,6 +616,53 @@ impl CurveBuilder {
         }
     }
 
+    /// Scans a discrete rate segment for negative instantaneous forwards its
+    /// interpolation introduced: those between pillars whose discrete
+    /// forward is not itself negative.
+    fn check_positive_forwards<T: TermStructure>(
+        &self,
+        curve: &T,
+        config: &SegmentConfig,
+    ) -> CurveResult<()> {
+        if config.interpolation.guarantees_positive_forwards()
+            || !matches!(self.curve_family, CurveFamily::Rate)
+        {
+            return Ok(());
+        }
+        let (SegmentDataSource::DiscreteZeros { tenors, .. }
+        | SegmentDataSource::DiscreteDiscountFactors { tenors, .. }
+        | SegmentDataSource::DiscreteForwards { tenors, .. }) = &config.source
+        else {
+            return Ok(());
+        };
+
+        let rate_curve = crate::wrappers::RateCurve::new(curve);
+        for pillars in tenors.windows(2) {
+            let (start, end) = (pillars[0], pillars[1]);
+            let discrete_forward = (rate_curve.discount_factor_at_tenor(start)?
+                / rate_curve.discount_factor_at_tenor(end)?)
+            .ln()
+                / (end - start);
+            if discrete_forward < 0.0 {
+                continue;
+            }
+
+            let mut t = start.max(FORWARD_SCAN_STEP);
+            while t < end {
+                let forward = rate_curve.instantaneous_forward_at_tenor(t)?;
+                if forward < 0.0 {
+                    return Err(CurveError::NegativeForward {
+                        tenor: t,
+                        forward,
+                        interpolation: config.interpolation,
+                    });
+                }
+                t += FORWARD_SCAN_STEP;
+            }
+        }
+        Ok(())
+    }
+
     fn build_curve_from_config(&self, config: &SegmentConfig) -> CurveResult<CurveRef> {
         match &config.source {
             SegmentDataSource::DiscreteZeros { .. }
@@ -1037,6 +1115,69 @@ mod tests {
         assert_relative_eq!(df, expected, epsilon = 1e-6);
     }
 
+    #[test]
+    fn test_require_positive_forwards_rejects_spline_overshoot() {
+        // Humped zeros whose discrete forwards are all positive, but whose
+        // 5y-7y forward is small enough for a cubic spline to dip below zero.
+        let tenors = vec![1.0, 2.0, 3.0, 5.0, 7.0, 10.0];
+        let rates = vec![0.01, 0.05, 0.055, 0.05, 0.038, 0.033];
+
+        let result = CurveBuilder::rate_curve(today())
+            .with_zeros(tenors.clone(), rates.clone(), Compounding::Continuous)
+            .interpolate(InterpolationMethod::CubicSpline)
+            .require_positive_forwards()
+            .build();
+        match result {
+            Err(CurveError::NegativeForward {
+                tenor,
+                forward,
+                interpolation,
+            }) => {
+                assert!(tenor > 5.0 && tenor < 7.0);
+                assert!(forward < 0.0);
+                assert_eq!(interpolation, InterpolationMethod::CubicSpline);
+            }
+            other => panic!("expected NegativeForward, got {other:?}"),
+        }
+
+        // Without the check the spline curve still builds.
+        assert!(CurveBuilder::rate_curve(today())
+            .with_zeros(tenors.clone(), rates.clone(), Compounding::Continuous)
+            .interpolate(InterpolationMethod::CubicSpline)
+            .build()
+            .is_ok());
+
+        let curve = CurveBuilder::rate_curve(today())
+            .with_zeros(tenors, rates, Compounding::Continuous)
+            .interpolate(InterpolationMethod::MonotoneConvex)
+            .require_positive_forwards()
+            .build_rate_curve()
+            .unwrap();
+        for i in 4..40 {
+            let t = f64::from(i) * 0.25;
+            assert!(curve.instantaneous_forward_at_tenor(t).unwrap() >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_require_positive_forwards_accepts_negative_market_forwards() {
+        // Negative-rate zeros: the forwards out to 5y are negative in the
+        // data itself, and linear interpolation keeps the 5y-10y forward
+        // positive.
+        let curve = CurveBuilder::rate_curve(today())
+            .with_zeros(
+                vec![1.0, 2.0, 3.0, 5.0, 10.0],
+                vec![-0.004, -0.005, -0.0055, -0.004, 0.002],
+                Compounding::Continuous,
+            )
+            .interpolate(InterpolationMethod::Linear)
+            .require_positive_forwards()
+            .build_rate_curve()
+            .unwrap();
+        assert!(curve.instantaneous_forward_at_tenor(1.5).unwrap() < 0.0);
+        assert!(curve.instantaneous_forward_at_tenor(7.0).unwrap() > 0.0);
+    }
+
     #[test]
     fn test_empty_builder_error() {
         let result = CurveBuilder::rate_curve(today()).build();
@@ -5,6 +5,10 @@
 //!
 //! The bump profile is typically triangular, centered at the key tenor,
 //! with weights linearly decreasing to zero at adjacent key tenors.
+//!
+//! Bumped curves never copy the base curve's pillars: they hold a reference
+//! (or an `Arc`) to it and apply the weighted shift in `value_at`, so a full
+//! key-rate run shares one base curve across all of its bumps.
 
 use std::sync::Arc;
 
@@ -287,6 +291,9 @@ impl<T: TermStructure> TermStructure for KeyRateBumpedCurve<'_, T> {
 }
 
 /// Arc-owned key-rate bumped curve.
+///
+/// Holds the shared base curve and the bump specification only; cloning it
+/// or bumping the same base again never copies pillar data.
 #[derive(Debug, Clone)]
 pub struct ArcKeyRateBumpedCurve<T: TermStructure> {
     /// The base curve (Arc-owned).
@@ -510,6 +517,39 @@ mod tests {
         assert_relative_eq!(bumped_4y - base_4y, 0.005, epsilon = 1e-10);
     }
 
+    #[test]
+    fn test_arc_bumps_share_base_curve() {
+        let base = Arc::new(sample_zero_curve());
+        let bumps: Vec<_> = (0..50)
+            .map(|i| KeyRateBump::new(5.0, f64::from(i + 1)).apply_arc(base.clone()))
+            .collect();
+
+        // Every bump points at the one base curve rather than a copy.
+        assert_eq!(Arc::strong_count(&base), 51);
+        for bumped in &bumps {
+            assert!(std::ptr::eq(bumped.base(), base.as_ref()));
+        }
+
+        // Base plus shift at the key tenor, base outside the triangle.
+        let bumped = &bumps[9]; // 10bp at 5Y
+        assert_relative_eq!(
+            bumped.value_at(5.0) - base.value_at(5.0),
+            0.001,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            bumped.value_at(6.0) - base.value_at(6.0),
+            0.0005,
+            epsilon = 1e-12
+        );
+        for t in [1.0, 2.0, 3.0, 7.0, 10.0, 30.0] {
+            assert_eq!(bumped.value_at(t), base.value_at(t));
+        }
+
+        drop(bumps);
+        assert_eq!(Arc::strong_count(&base), 1);
+    }
+
     #[test]
     fn test_standard_profile() {
         let profile = KeyRateBump::standard_profile(1.0);
@@ -371,6 +371,51 @@ impl Scenario {
         }
     }
 
+    /// Creates a scenario from level, slope and curvature shocks, the first
+    /// three principal components of curve moves.
+    ///
+    /// Each shock is mapped onto `key_tenors` (sorted, in years) with
+    /// stylized loadings over the position `x` of the tenor across the key
+    /// range, from -1 at the shortest key to +1 at the longest:
+    ///
+    /// - **Level**: `1` — a parallel shift.
+    /// - **Slope**: `x` — short end down and long end up by `slope_bps`,
+    ///   rotating about the middle of the range.
+    /// - **Curvature**: `1 - 2x²` — belly up and wings down by
+    ///   `curvature_bps`.
+    ///
+    /// The per-key shifts become triangular key-rate bumps, so the shift is
+    /// linear between keys and flat beyond the first and last.
+    #[must_use]
+    pub fn pca(level_bps: f64, slope_bps: f64, curvature_bps: f64, key_tenors: &[f64]) -> Self {
+        let (first, last) = match (key_tenors.first(), key_tenors.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => (0.0, 0.0),
+        };
+        let position = |t: f64| {
+            if last > first {
+                (2.0 * t - first - last) / (last - first)
+            } else {
+                0.0
+            }
+        };
+
+        let bumps = key_tenors.iter().enumerate().map(|(i, &tenor)| {
+            let x = position(tenor);
+            ScenarioBump::KeyRate {
+                tenor,
+                shift_bps: level_bps + slope_bps * x + curvature_bps * (1.0 - 2.0 * x * x),
+                left_tenor: i.checked_sub(1).map(|j| key_tenors[j]),
+                right_tenor: key_tenors.get(i + 1).copied(),
+            }
+        });
+
+        Self::new(format!(
+            "PCA level {level_bps:+.0}bp, slope {slope_bps:+.0}bp, curvature {curvature_bps:+.0}bp"
+        ))
+        .with_bumps(bumps)
+    }
+
     /// Adds a bump to the scenario.
     #[must_use]
     pub fn with_bump(mut self, bump: ScenarioBump) -> Self {
@@ -670,6 +715,47 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn test_pca_level_is_parallel() {
+        let curve = sample_zero_curve();
+        let keys = [1.0, 2.0, 5.0, 10.0, 20.0, 30.0];
+        let pca = Scenario::pca(25.0, 0.0, 0.0, &keys);
+        let parallel = Scenario::new("Parallel +25bp").with_bump(ScenarioBump::parallel(25.0));
+
+        let pca_curve = pca.apply(&curve);
+        let parallel_curve = parallel.apply(&curve);
+        for t in [0.5, 1.0, 3.0, 7.5, 15.0, 30.0, 40.0] {
+            assert_relative_eq!(
+                pca_curve.value_at(t),
+                parallel_curve.value_at(t),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_pca_slope_rotates_about_midpoint() {
+        let curve = sample_zero_curve();
+        let keys = [2.0, 5.0, 10.0, 20.0, 30.0];
+        let scenario = Scenario::pca(0.0, 40.0, 0.0, &keys);
+        let bumped = scenario.apply(&curve);
+        let shift = |t: f64| bumped.value_at(t) - curve.value_at(t);
+
+        // Zero at the middle of the key range, antisymmetric about it.
+        assert_relative_eq!(shift(16.0), 0.0, epsilon = 1e-12);
+        for d in [1.0, 4.0, 9.0, 14.0] {
+            assert_relative_eq!(shift(16.0 - d), -shift(16.0 + d), epsilon = 1e-12);
+        }
+        assert_relative_eq!(shift(2.0), -0.004, epsilon = 1e-12);
+        assert_relative_eq!(shift(30.0), 0.004, epsilon = 1e-12);
+
+        // Curvature lifts the belly and lowers the wings.
+        let butterfly = Scenario::pca(0.0, 0.0, 10.0, &keys);
+        assert!(butterfly.total_shift_at(16.0) > 0.0);
+        assert_relative_eq!(butterfly.total_shift_at(2.0), -0.001, epsilon = 1e-12);
+        assert_relative_eq!(butterfly.total_shift_at(30.0), -0.001, epsilon = 1e-12);
+    }
+
     #[test]
     fn test_parallel_bump() {
         let bump = ScenarioBump::parallel(100.0);
@@ -30,13 +30,16 @@
 
 use convex_core::daycounts::DayCountConvention;
 use convex_core::types::{Compounding, Date};
+use convex_math::linear_algebra::condition_number;
+use nalgebra::DMatrix;
 
+use crate::conversion::ValueConverter;
 use crate::curves::DiscreteCurve;
 use crate::error::{CurveError, CurveResult};
 use crate::wrappers::RateCurve;
 use crate::{InterpolationMethod, ValueType};
 
-use super::instruments::{CalibrationInstrument, InstrumentSet};
+use super::instruments::{CalibrationInstrument, FuturesConvexity, InstrumentSet};
 
 /// Configuration for the global fitter.
 #[derive(Debug, Clone, Copy)]
@@ -55,6 +58,13 @@ pub struct FitterConfig {
     pub max_lambda: f64,
     /// Finite difference step for Jacobian.
     pub jacobian_step: f64,
+    /// Convexity adjustment applied to futures, or `None` to fit futures
+    /// with only their own fixed adjustment.
+    pub futures_convexity: Option<FuturesConvexity>,
+    /// Weight of the forward-curve roughness penalty `∫ f''(t)² dt` added to
+    /// the squared pricing errors. Zero fits the instruments exactly; larger
+    /// weights trade fit for a smoother forward curve.
+    pub smoothness_weight: f64,
 }
 
 impl Default for FitterConfig {
@@ -67,6 +77,8 @@ impl Default for FitterConfig {
             min_lambda: 1e-10,
             max_lambda: 1e10,
             jacobian_step: 1e-6,
+            futures_convexity: None,
+            smoothness_weight: 0.0,
         }
     }
 }
@@ -91,6 +103,53 @@ impl FitterConfig {
         self.tolerance = tolerance;
         self
     }
+
+    /// Applies `model`'s convexity adjustment to futures when fitting.
+    #[must_use]
+    pub fn with_futures_convexity(mut self, model: FuturesConvexity) -> Self {
+        self.futures_convexity = Some(model);
+        self
+    }
+
+    /// Sets the weight of the forward-curve roughness penalty.
+    #[must_use]
+    pub fn with_smoothness_weight(mut self, weight: f64) -> Self {
+        self.smoothness_weight = weight;
+        self
+    }
+}
+
+/// Jacobian condition number above which a calibration is flagged as
+/// ill-conditioned.
+///
+/// Liquid deposit and swap sets sit in the tens; two instruments maturing a
+/// day apart push it to around 1e5.
+pub const ILL_CONDITIONED_THRESHOLD: f64 = 1e4;
+
+/// An instrument's market quote against the quote implied by the
+/// calibrated curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepriceError {
+    /// Instrument description.
+    pub instrument: String,
+    /// Quoted market rate.
+    pub market_quote: f64,
+    /// Rate implied by the calibrated curve.
+    pub model_quote: f64,
+}
+
+impl RepriceError {
+    /// Model minus market quote.
+    #[must_use]
+    pub fn error(&self) -> f64 {
+        self.model_quote - self.market_quote
+    }
+
+    /// Model minus market quote in basis points.
+    #[must_use]
+    pub fn error_bps(&self) -> f64 {
+        self.error() * 10_000.0
+    }
 }
 
 /// Result of curve calibration.
@@ -106,9 +165,150 @@ pub struct CalibrationResult {
     pub rms_error: f64,
     /// Whether calibration converged.
     pub converged: bool,
+    /// Market against model quote for each instrument, in maturity order.
+    pub reprice_errors: Vec<RepriceError>,
+    /// Condition number of the Jacobian of pricing errors with respect to
+    /// the curve's pillar values, at the calibrated curve.
+    pub jacobian_condition: f64,
+    /// Whether `jacobian_condition` exceeds [`ILL_CONDITIONED_THRESHOLD`]:
+    /// the instruments barely distinguish some pillars, so small quote
+    /// changes can move the curve a long way.
+    pub ill_conditioned: bool,
+    /// Interpolation the curve was calibrated with.
+    interpolation: InterpolationMethod,
+    /// Configuration the curve was calibrated with.
+    config: FitterConfig,
 }
 
 impl CalibrationResult {
+    /// Builds the result for a curve calibrated with `interpolation` and
+    /// `config`, repricing `instruments` and measuring the conditioning of
+    /// the fit.
+    fn new(
+        curve: DiscreteCurve,
+        interpolation: InterpolationMethod,
+        config: &FitterConfig,
+        instruments: &[&Box<dyn CalibrationInstrument>],
+        iterations: usize,
+        converged: impl FnOnce(f64) -> bool,
+    ) -> CurveResult<Self> {
+        let rate_curve = RateCurve::new(curve.clone());
+        let residuals: Vec<f64> = instruments
+            .iter()
+            .map(|inst| inst.pricing_error(&rate_curve).unwrap_or(f64::NAN))
+            .collect();
+        let rms_error =
+            (residuals.iter().map(|r| r * r).sum::<f64>() / residuals.len() as f64).sqrt();
+
+        let reprice_errors = instruments
+            .iter()
+            .map(|inst| RepriceError {
+                instrument: inst.description(),
+                market_quote: inst.quote(),
+                model_quote: inst.implied_quote(&rate_curve).unwrap_or(f64::NAN),
+            })
+            .collect();
+
+        let jacobian = residual_jacobian(
+            curve.get_reference_date(),
+            curve.tenors(),
+            curve.values(),
+            curve.get_value_type(),
+            interpolation,
+            instruments,
+            config.jacobian_step,
+        )?;
+        let jacobian = DMatrix::from_fn(jacobian.len(), curve.len(), |i, j| jacobian[i][j]);
+        let jacobian_condition = condition_number(&jacobian);
+
+        Ok(Self {
+            converged: converged(rms_error),
+            curve,
+            residuals,
+            iterations,
+            rms_error,
+            reprice_errors,
+            jacobian_condition,
+            ill_conditioned: jacobian_condition > ILL_CONDITIONED_THRESHOLD,
+            interpolation,
+            config: *config,
+        })
+    }
+
+    /// Par sensitivity of the curve's zero rates to the quotes of
+    /// `instruments`, the set the curve was calibrated to.
+    ///
+    /// Entry `[j][i]` is `∂(zero rate at pillar j) / ∂(quote of instrument i)`,
+    /// with pillars in [`DiscreteCurve::tenors`] order and instruments in
+    /// maturity order, as in [`reprice_errors`](Self::reprice_errors). It maps
+    /// key-rate risk on the pillars into risk in the calibration instruments.
+    ///
+    /// Holding each instrument repriced, the pillar moves are the inverse of
+    /// the Jacobian of model quotes to pillar values at the calibrated curve,
+    /// bumped by the calibration's [`FitterConfig::jacobian_step`]. A `t = 0`
+    /// anchor pillar is held fixed and its row is zero. Zero rates are in the
+    /// curve's own convention, or continuously compounded for a
+    /// discount-factor curve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instruments do not each determine one pillar,
+    /// the quote Jacobian is singular, or the curve does not hold zero rates
+    /// or discount factors.
+    pub fn par_jacobian(&self, instruments: &InstrumentSet) -> CurveResult<Vec<Vec<f64>>> {
+        let adjusted = convexity_adjusted(instruments, self.config.futures_convexity);
+        let sorted = by_maturity(instruments, &adjusted);
+        let quote_jacobian = instrument_jacobian(
+            self.curve.get_reference_date(),
+            self.curve.tenors(),
+            self.curve.values(),
+            self.curve.get_value_type(),
+            self.interpolation,
+            &sorted,
+            self.config.jacobian_step,
+            |inst, curve| inst.implied_quote(curve),
+        )?;
+
+        let tenors = self.curve.tenors();
+        let values = self.curve.values();
+        let m = quote_jacobian.len();
+        let offset = match tenors.len().checked_sub(m) {
+            Some(0) => 0,
+            Some(1) if tenors[0] == 0.0 => 1,
+            _ => {
+                return Err(CurveError::calibration_failed(
+                    0,
+                    f64::NAN,
+                    "Par Jacobian needs one pillar per instrument",
+                ))
+            }
+        };
+
+        let quote_jacobian = DMatrix::from_fn(m, m, |i, j| quote_jacobian[i][j + offset]);
+        let inverse = quote_jacobian.try_inverse().ok_or_else(|| {
+            CurveError::calibration_failed(0, f64::NAN, "Quote Jacobian is singular")
+        })?;
+
+        let mut par = vec![vec![0.0; m]; tenors.len()];
+        for (j, row) in par.iter_mut().enumerate().skip(offset) {
+            let zero_per_value = match self.curve.get_value_type() {
+                ValueType::ZeroRate { .. } => 1.0,
+                // z = -ln(P) / t
+                ValueType::DiscountFactor => -1.0 / (tenors[j] * values[j]),
+                other => {
+                    return Err(CurveError::incompatible_value_type(
+                        "DiscountFactor or ZeroRate",
+                        format!("{other:?}"),
+                    ))
+                }
+            };
+            for (i, entry) in row.iter_mut().enumerate() {
+                *entry = zero_per_value * inverse[(j - offset, i)];
+            }
+        }
+        Ok(par)
+    }
+
     /// Returns the maximum absolute error.
     #[must_use]
     pub fn max_error(&self) -> f64 {
@@ -143,7 +343,10 @@ impl CalibrationResult {
 /// Global curve fitter using Levenberg-Marquardt optimization.
 ///
 /// Calibrates a discount curve by minimizing the sum of squared pricing
-/// errors across all instruments simultaneously.
+/// errors across all instruments simultaneously. With a
+/// [`smoothness_weight`](FitterConfig::smoothness_weight) the objective also
+/// penalizes the roughness of the forward curve, so noisy quotes are fitted
+/// approximately by a smoother curve.
 #[derive(Debug, Clone)]
 pub struct GlobalFitter {
     /// Configuration.
@@ -220,9 +423,12 @@ impl GlobalFitter {
             ));
         }
 
+        // With a convexity model configured, futures are fitted to their
+        // adjusted rates.
+        let adjusted = convexity_adjusted(instruments, self.config.futures_convexity);
+
         // Get sorted tenors and initial guesses from quotes
-        let mut sorted_instruments: Vec<_> = instruments.instruments().iter().collect();
-        sorted_instruments.sort_by(|a, b| a.tenor().partial_cmp(&b.tenor()).unwrap());
+        let sorted_instruments = by_maturity(instruments, &adjusted);
 
         let tenors: Vec<f64> = sorted_instruments.iter().map(|i| i.tenor()).collect();
         let quotes: Vec<f64> = sorted_instruments.iter().map(|i| i.quote()).collect();
@@ -248,23 +454,14 @@ impl GlobalFitter {
             self.interpolation,
         )?;
 
-        // Compute final residuals
-        let rate_curve = RateCurve::new(curve.clone());
-        let residuals: Vec<f64> = sorted_instruments
-            .iter()
-            .map(|inst| inst.pricing_error(&rate_curve).unwrap_or(f64::NAN))
-            .collect();
-
-        let rms_error =
-            (residuals.iter().map(|r| r * r).sum::<f64>() / residuals.len() as f64).sqrt();
-
-        Ok(CalibrationResult {
+        CalibrationResult::new(
             curve,
-            residuals,
+            self.interpolation,
+            &self.config,
+            &sorted_instruments,
             iterations,
-            rms_error,
-            converged,
-        })
+            |_| converged,
+        )
     }
 
     /// Runs the Levenberg-Marquardt algorithm.
@@ -281,6 +478,7 @@ impl GlobalFitter {
         let mut values = initial_values.to_vec();
         let mut lambda = self.config.initial_lambda;
         let mut prev_error = f64::MAX;
+        let smoothing = self.config.smoothness_weight > 0.0;
 
         for iteration in 0..self.config.max_iterations {
             // Build curve with current values
@@ -294,10 +492,7 @@ impl GlobalFitter {
             let rate_curve = RateCurve::new(curve);
 
             // Compute residuals
-            let residuals: Vec<f64> = instruments
-                .iter()
-                .map(|inst| inst.pricing_error(&rate_curve).unwrap_or(0.0))
-                .collect();
+            let residuals = self.residuals(&rate_curve, tenors, instruments);
 
             let error = residuals.iter().map(|r| r * r).sum::<f64>();
 
@@ -308,7 +503,11 @@ impl GlobalFitter {
             }
 
             // Compute Jacobian (numerical)
-            let jacobian = self.compute_jacobian(reference_date, tenors, &values, instruments)?;
+            let mut jacobian =
+                self.compute_jacobian(reference_date, tenors, &values, instruments)?;
+            if smoothing {
+                jacobian.extend(self.penalty_jacobian(reference_date, tenors, &values)?);
+            }
 
             // Compute J^T * J and J^T * r
             let jtj = self.matrix_multiply_transpose(&jacobian, &jacobian, n);
@@ -335,12 +534,15 @@ impl GlobalFitter {
             )?;
             let new_rate_curve = RateCurve::new(new_curve);
 
-            let new_residuals: Vec<f64> = instruments
-                .iter()
-                .map(|inst| inst.pricing_error(&new_rate_curve).unwrap_or(0.0))
-                .collect();
+            let new_residuals = self.residuals(&new_rate_curve, tenors, instruments);
             let new_error: f64 = new_residuals.iter().map(|r| r * r).sum();
 
+            // A smoothed fit never reprices exactly; it has converged once
+            // steps stop reducing the penalized objective.
+            if smoothing && new_error <= error && error - new_error <= 1e-14 * error {
+                return Ok((new_values, iteration + 1, true));
+            }
+
             if new_error < error {
                 // Accept step, reduce damping
                 values = new_values;
@@ -352,7 +554,7 @@ impl GlobalFitter {
             }
 
             // Check if stuck
-            if (prev_error - error).abs() < 1e-16 && iteration > 10 {
+            if !smoothing && (prev_error - error).abs() < 1e-16 && iteration > 10 {
                 return Ok((values, iteration + 1, rms < self.config.tolerance * 10.0));
             }
         }
@@ -380,60 +582,85 @@ impl GlobalFitter {
         ))
     }
 
-    /// Computes the Jacobian matrix numerically.
-    fn compute_jacobian(
+    /// Pricing errors of `instruments`, followed by the weighted roughness
+    /// penalty terms when smoothing.
+    fn residuals(
+        &self,
+        curve: &RateCurve<DiscreteCurve>,
+        tenors: &[f64],
+        instruments: &[&Box<dyn CalibrationInstrument>],
+    ) -> Vec<f64> {
+        let mut residuals: Vec<f64> = instruments
+            .iter()
+            .map(|inst| inst.pricing_error(curve).unwrap_or(0.0))
+            .collect();
+        if self.config.smoothness_weight > 0.0 {
+            let scale = self.config.smoothness_weight.sqrt();
+            residuals.extend(
+                forward_roughness(curve, tenors)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|term| scale * term),
+            );
+        }
+        residuals
+    }
+
+    /// Central-difference Jacobian of the weighted roughness penalty terms.
+    fn penalty_jacobian(
         &self,
         reference_date: Date,
         tenors: &[f64],
         values: &[f64],
-        instruments: &[&Box<dyn CalibrationInstrument>],
     ) -> CurveResult<Vec<Vec<f64>>> {
-        let n = tenors.len();
-        let m = instruments.len();
         let h = self.config.jacobian_step;
-
-        let mut jacobian = vec![vec![0.0; n]; m];
-
-        for j in 0..n {
-            // Bump value j up
-            let mut values_up = values.to_vec();
-            values_up[j] += h;
-
-            let curve_up = DiscreteCurve::new(
+        let scale = self.config.smoothness_weight.sqrt();
+        let terms = |values: Vec<f64>| -> CurveResult<Vec<f64>> {
+            let curve = DiscreteCurve::new(
                 reference_date,
                 tenors.to_vec(),
-                values_up,
+                values,
                 self.value_type.clone(),
                 self.interpolation,
             )?;
-            let rate_curve_up = RateCurve::new(curve_up);
+            forward_roughness(&RateCurve::new(curve), tenors)
+        };
 
-            // Bump value j down
+        let rows = tenors.len().saturating_sub(3);
+        let mut jacobian = vec![vec![0.0; tenors.len()]; rows];
+        for j in 0..tenors.len() {
+            let mut values_up = values.to_vec();
+            values_up[j] += h;
             let mut values_down = values.to_vec();
             values_down[j] -= h;
-
-            let curve_down = DiscreteCurve::new(
-                reference_date,
-                tenors.to_vec(),
-                values_down,
-                self.value_type.clone(),
-                self.interpolation,
-            )?;
-            let rate_curve_down = RateCurve::new(curve_down);
-
-            // Central difference for each instrument
-            for i in 0..m {
-                let r_up = instruments[i].pricing_error(&rate_curve_up).unwrap_or(0.0);
-                let r_down = instruments[i]
-                    .pricing_error(&rate_curve_down)
-                    .unwrap_or(0.0);
-                jacobian[i][j] = (r_up - r_down) / (2.0 * h);
+            let up = terms(values_up)?;
+            let down = terms(values_down)?;
+            for (row, (u, d)) in jacobian.iter_mut().zip(up.iter().zip(&down)) {
+                row[j] = scale * (u - d) / (2.0 * h);
             }
         }
-
         Ok(jacobian)
     }
 
+    /// Computes the Jacobian matrix numerically.
+    fn compute_jacobian(
+        &self,
+        reference_date: Date,
+        tenors: &[f64],
+        values: &[f64],
+        instruments: &[&Box<dyn CalibrationInstrument>],
+    ) -> CurveResult<Vec<Vec<f64>>> {
+        residual_jacobian(
+            reference_date,
+            tenors,
+            values,
+            &self.value_type,
+            self.interpolation,
+            instruments,
+            self.config.jacobian_step,
+        )
+    }
+
     /// Computes J^T * J.
     fn matrix_multiply_transpose(
         &self,
@@ -526,9 +753,158 @@ impl GlobalFitter {
     }
 }
 
+/// Roughness of the forward curve between `tenors`, as terms whose squares
+/// sum to approximately `∫ f''(t)² dt`.
+///
+/// Uses the average forward over each interval between consecutive tenors,
+/// placed at the interval's midpoint, and a second difference of those.
+fn forward_roughness(curve: &RateCurve<DiscreteCurve>, tenors: &[f64]) -> CurveResult<Vec<f64>> {
+    let mut forwards = Vec::with_capacity(tenors.len().saturating_sub(1));
+    let mut midpoints = Vec::with_capacity(tenors.len().saturating_sub(1));
+    for pair in tenors.windows(2) {
+        let (t0, t1) = (pair[0], pair[1]);
+        let df0 = curve.discount_factor_at_tenor(t0)?;
+        let df1 = curve.discount_factor_at_tenor(t1)?;
+        forwards.push((df0 / df1).ln() / (t1 - t0));
+        midpoints.push(f64::midpoint(t0, t1));
+    }
+
+    let mut terms = Vec::with_capacity(forwards.len().saturating_sub(2));
+    for k in 1..forwards.len().saturating_sub(1) {
+        let slope_left = (forwards[k] - forwards[k - 1]) / (midpoints[k] - midpoints[k - 1]);
+        let slope_right = (forwards[k + 1] - forwards[k]) / (midpoints[k + 1] - midpoints[k]);
+        let width = (midpoints[k + 1] - midpoints[k - 1]) / 2.0;
+        let second_derivative = (slope_right - slope_left) / width;
+        terms.push(second_derivative * width.sqrt());
+    }
+    Ok(terms)
+}
+
+/// How an instrument is measured against a curve, for [`instrument_jacobian`].
+type InstrumentMeasure =
+    fn(&dyn CalibrationInstrument, &RateCurve<DiscreteCurve>) -> CurveResult<f64>;
+
+/// Central-difference Jacobian of the instruments' pricing errors with
+/// respect to the curve values at each tenor (one row per instrument).
+/// Each instrument's convexity-adjusted replacement under `model`, if any.
+fn convexity_adjusted(
+    instruments: &InstrumentSet,
+    model: Option<FuturesConvexity>,
+) -> Vec<Option<Box<dyn CalibrationInstrument>>> {
+    instruments
+        .instruments()
+        .iter()
+        .map(|inst| model.and_then(|model| inst.with_futures_convexity(model)))
+        .collect()
+}
+
+/// The instruments, with their `adjusted` replacements, in maturity order.
+fn by_maturity<'a>(
+    instruments: &'a InstrumentSet,
+    adjusted: &'a [Option<Box<dyn CalibrationInstrument>>],
+) -> Vec<&'a Box<dyn CalibrationInstrument>> {
+    let mut sorted: Vec<_> = instruments
+        .instruments()
+        .iter()
+        .zip(adjusted)
+        .map(|(inst, adjusted)| adjusted.as_ref().unwrap_or(inst))
+        .collect();
+    sorted.sort_by(|a, b| a.tenor().partial_cmp(&b.tenor()).unwrap());
+    sorted
+}
+
+fn residual_jacobian(
+    reference_date: Date,
+    tenors: &[f64],
+    values: &[f64],
+    value_type: &ValueType,
+    interpolation: InterpolationMethod,
+    instruments: &[&Box<dyn CalibrationInstrument>],
+    h: f64,
+) -> CurveResult<Vec<Vec<f64>>> {
+    instrument_jacobian(
+        reference_date,
+        tenors,
+        values,
+        value_type,
+        interpolation,
+        instruments,
+        h,
+        |inst, curve| inst.pricing_error(curve),
+    )
+}
+
+/// Central-difference Jacobian of `measure` on each instrument with respect
+/// to the curve values at each tenor (one row per instrument).
+#[allow(clippy::too_many_arguments)]
+fn instrument_jacobian(
+    reference_date: Date,
+    tenors: &[f64],
+    values: &[f64],
+    value_type: &ValueType,
+    interpolation: InterpolationMethod,
+    instruments: &[&Box<dyn CalibrationInstrument>],
+    h: f64,
+    measure: InstrumentMeasure,
+) -> CurveResult<Vec<Vec<f64>>> {
+    let n = tenors.len();
+    let m = instruments.len();
+
+    let mut jacobian = vec![vec![0.0; n]; m];
+
+    for j in 0..n {
+        // Bump value j up
+        let mut values_up = values.to_vec();
+        values_up[j] += h;
+
+        let curve_up = DiscreteCurve::new(
+            reference_date,
+            tenors.to_vec(),
+            values_up,
+            value_type.clone(),
+            interpolation,
+        )?;
+        let rate_curve_up = RateCurve::new(curve_up);
+
+        // Bump value j down
+        let mut values_down = values.to_vec();
+        values_down[j] -= h;
+
+        let curve_down = DiscreteCurve::new(
+            reference_date,
+            tenors.to_vec(),
+            values_down,
+            value_type.clone(),
+            interpolation,
+        )?;
+        let rate_curve_down = RateCurve::new(curve_down);
+
+        // Central difference for each instrument
+        for i in 0..m {
+            let r_up = measure(instruments[i].as_ref(), &rate_curve_up).unwrap_or(0.0);
+            let r_down = measure(instruments[i].as_ref(), &rate_curve_down).unwrap_or(0.0);
+            jacobian[i][j] = (r_up - r_down) / (2.0 * h);
+        }
+    }
+
+    Ok(jacobian)
+}
+
 /// Sequential bootstrap for comparison/fallback.
 ///
 /// Bootstraps a curve by solving one instrument at a time in maturity order.
+///
+/// # Front stubs
+///
+/// The front of the curve is placed exactly. Starting from the reference
+/// date, each instrument with a [`front_stub`] whose start is already on the
+/// curve fixes the discount factor at its end: O/N and T/N deposits by ACT/360
+/// simple interest, and a single-period OIS from spot by its daily-compounded
+/// floating leg. The chain stops at the first instrument that is not such a
+/// stub; the rest are placed taking each quote as a continuously compounded
+/// zero rate.
+///
+/// [`front_stub`]: CalibrationInstrument::front_stub
 #[derive(Debug, Clone, Default)]
 pub struct SequentialBootstrapper {
     /// Value type for output curve.
@@ -573,15 +949,37 @@ impl SequentialBootstrapper {
         let mut tenors = Vec::with_capacity(sorted.len());
         let mut values = Vec::with_capacity(sorted.len());
 
-        // Bootstrap each instrument
+        // Chain exact discount factors through the front stubs, placing each
+        // at its end date on the curve's own tenor basis
+        let days_per_year = f64::from(self.value_type.tenor_day_count().nominal_days_per_year());
+        let mut known = vec![(reference_date, 1.0)];
         for inst in &sorted {
+            let Some(stub) = inst.front_stub() else {
+                break;
+            };
+            let Some(&(_, df_start)) = known.iter().find(|(date, _)| *date == stub.start) else {
+                break;
+            };
+            let df = df_start / stub.growth;
+            let tenor = reference_date.days_between(&stub.end) as f64 / days_per_year;
+            let Some(value) = self.value_from_df(df, tenor) else {
+                break;
+            };
+            known.push((stub.end, df));
+            tenors.push(tenor);
+            values.push(value);
+        }
+
+        // Bootstrap the remaining instruments
+        for inst in &sorted[tenors.len()..] {
             let tenor = inst.tenor();
             let quote = inst.quote();
 
             // For simple deposits, zero rate ≈ quote
             // This is a simplified bootstrap - full version would solve for exact DF
+            let df = ValueConverter::zero_to_df(quote, tenor, Compounding::Continuous);
             tenors.push(tenor);
-            values.push(quote);
+            values.push(self.value_from_df(df, tenor).unwrap_or(quote));
         }
 
         let curve = DiscreteCurve::new(
@@ -592,22 +990,26 @@ impl SequentialBootstrapper {
             self.interpolation,
         )?;
 
-        // Compute residuals
-        let rate_curve = RateCurve::new(curve.clone());
-        let residuals: Vec<f64> = sorted
-            .iter()
-            .map(|inst| inst.pricing_error(&rate_curve).unwrap_or(f64::NAN))
-            .collect();
-
-        let rms = (residuals.iter().map(|r| r * r).sum::<f64>() / residuals.len() as f64).sqrt();
-
-        Ok(CalibrationResult {
+        CalibrationResult::new(
             curve,
-            residuals,
-            iterations: 1,
-            rms_error: rms,
-            converged: true,
-        })
+            self.interpolation,
+            &FitterConfig::default(),
+            &sorted,
+            1,
+            |_| true,
+        )
+    }
+
+    /// The curve value for a discount factor at `tenor`, if the output value
+    /// type is one a discount factor converts to.
+    fn value_from_df(&self, df: f64, tenor: f64) -> Option<f64> {
+        match self.value_type {
+            ValueType::DiscountFactor => Some(df),
+            ValueType::ZeroRate { compounding, .. } => {
+                Some(ValueConverter::df_to_zero(df, tenor, compounding))
+            }
+            _ => None,
+        }
     }
 }
 
@@ -777,29 +1179,22 @@ impl PiecewiseBootstrapper {
             self.interpolation,
         )?;
 
-        // Compute final residuals
-        let rate_curve = RateCurve::new(curve.clone());
-        let residuals: Vec<f64> = sorted
-            .iter()
-            .map(|inst| inst.pricing_error(&rate_curve).unwrap_or(f64::NAN))
-            .collect();
-
-        let rms = (residuals.iter().map(|r| r * r).sum::<f64>() / residuals.len() as f64).sqrt();
-
-        Ok(CalibrationResult {
+        // 1bp accuracy is sufficient for converged status
+        CalibrationResult::new(
             curve,
-            residuals,
-            iterations: total_iterations as usize,
-            rms_error: rms,
-            converged: rms < 1e-6, // 1bp accuracy is sufficient for converged status
-        })
+            self.interpolation,
+            &FitterConfig::default(),
+            &sorted,
+            total_iterations as usize,
+            |rms| rms < 1e-6,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::calibration::instruments::{Deposit, Ois, Swap};
+    use crate::calibration::instruments::{Deposit, Future, FuturesConvexity, Ois, Swap};
     use approx::assert_relative_eq;
     use convex_core::types::Frequency;
 
@@ -994,4 +1389,319 @@ mod tests {
             assert!(e.abs() < 100.0); // Less than 100bp
         }
     }
+
+    #[test]
+    fn test_well_specified_swaps_reprice_and_are_well_conditioned() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let swap = |tenor: f64, rate: f64| {
+            Swap::from_tenor(
+                today,
+                tenor,
+                rate,
+                Frequency::SemiAnnual,
+                DayCountConvention::Thirty360US,
+            )
+        };
+        let instruments = InstrumentSet::new()
+            .with(Deposit::from_tenor(
+                today,
+                0.5,
+                0.040,
+                DayCountConvention::Act360,
+            ))
+            .with(swap(1.0, 0.041))
+            .with(swap(2.0, 0.042))
+            .with(swap(5.0, 0.043))
+            .with(swap(10.0, 0.044));
+
+        let result = GlobalFitter::new().fit(today, &instruments).unwrap();
+
+        assert_eq!(result.reprice_errors.len(), 5);
+        for error in &result.reprice_errors {
+            assert!(error.error_bps().abs() < 0.01, "{error:?}");
+        }
+        assert_relative_eq!(result.reprice_errors[3].market_quote, 0.043);
+        assert!(result.jacobian_condition < 100.0);
+        assert!(!result.ill_conditioned);
+    }
+
+    #[test]
+    fn test_jacobian_condition_uses_configured_step() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let instruments = InstrumentSet::new()
+            .with(Deposit::from_tenor(
+                today,
+                0.5,
+                0.040,
+                DayCountConvention::Act360,
+            ))
+            .with(Swap::from_tenor(
+                today,
+                5.0,
+                0.043,
+                Frequency::SemiAnnual,
+                DayCountConvention::Thirty360US,
+            ));
+
+        let fine = GlobalFitter::new().fit(today, &instruments).unwrap();
+        let coarse = GlobalFitter::with_config(FitterConfig {
+            jacobian_step: 0.05,
+            ..FitterConfig::default()
+        })
+        .fit(today, &instruments)
+        .unwrap();
+
+        // A 500bp finite-difference step sees the curvature of discounting.
+        assert!((fine.jacobian_condition - coarse.jacobian_condition).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_near_collinear_instruments_flag_ill_conditioning() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let swap = |tenor: f64, rate: f64| {
+            Swap::from_tenor(
+                today,
+                tenor,
+                rate,
+                Frequency::SemiAnnual,
+                DayCountConvention::Thirty360US,
+            )
+        };
+        // Two 5Y swaps a day apart carry almost the same risk.
+        let instruments = InstrumentSet::new()
+            .with(Deposit::from_tenor(
+                today,
+                0.5,
+                0.040,
+                DayCountConvention::Act360,
+            ))
+            .with(swap(2.0, 0.042))
+            .with(swap(5.0, 0.043))
+            .with(swap(5.0 + 1.0 / 365.0, 0.0431));
+
+        for result in [
+            GlobalFitter::new().fit(today, &instruments).unwrap(),
+            PiecewiseBootstrapper::new()
+                .bootstrap(today, &instruments)
+                .unwrap(),
+        ] {
+            assert!(result.jacobian_condition > ILL_CONDITIONED_THRESHOLD);
+            assert!(result.ill_conditioned);
+        }
+    }
+
+    #[test]
+    fn test_futures_convexity_lowers_fitted_forward() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let imm = today.add_months(48).unwrap();
+        let end = today.add_months(51).unwrap();
+        let deposit =
+            |tenor: f64| Deposit::from_tenor(today, tenor, 0.045, DayCountConvention::Act360);
+        let instruments = InstrumentSet::new()
+            .with(deposit(0.25))
+            .with(deposit(0.5))
+            .with(deposit(1.0))
+            .with(Future::new(
+                today,
+                imm,
+                end,
+                95.0,
+                0.0,
+                DayCountConvention::Act365Fixed,
+            ));
+
+        let fitted_forward = |config: FitterConfig| {
+            let result = GlobalFitter::with_config(config)
+                .fit(today, &instruments)
+                .unwrap();
+            let curve = RateCurve::new(result.curve);
+            let tau = 91.0 / 365.0;
+            (curve.discount_factor(imm).unwrap() / curve.discount_factor(end).unwrap() - 1.0) / tau
+        };
+
+        let model = FuturesConvexity::HoLee { volatility: 0.01 };
+        let unadjusted = fitted_forward(FitterConfig::default());
+        let adjusted = fitted_forward(FitterConfig::default().with_futures_convexity(model));
+
+        assert_relative_eq!(unadjusted, 0.05, epsilon = 1e-8);
+        // Act/365F times to the IMM date and the end of the 91-day period.
+        let t1 = 1461.0 / 365.0;
+        let t2 = 1552.0 / 365.0;
+        let expected = 0.0001 * t1 * t2 / 2.0;
+        assert_relative_eq!(unadjusted - adjusted, expected, epsilon = 1e-8);
+        // A little over 8bp for a four-year contract at 100bp volatility.
+        assert!(expected > 0.0008 && expected < 0.0009);
+    }
+
+    #[test]
+    fn test_sofr_front_stubs_reprice_exactly() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let spot = today.add_days(2);
+        let ois = |months: i32, rate: f64| {
+            Ois::new(
+                today,
+                spot,
+                spot.add_months(months).unwrap(),
+                rate,
+                Frequency::Annual,
+                DayCountConvention::Act360,
+            )
+        };
+        let instruments = InstrumentSet::new()
+            .with(Deposit::overnight(today, 0.0531))
+            .with(Deposit::tom_next(today, 0.0532))
+            .with(ois(1, 0.0533))
+            .with(ois(3, 0.0536))
+            .with(ois(6, 0.0530))
+            .with(ois(12, 0.0505));
+
+        let result = SequentialBootstrapper::new()
+            .output_as(ValueType::continuous_zero(DayCountConvention::Act360))
+            .bootstrap(today, &instruments)
+            .unwrap();
+
+        // The O/N deposit reprices exactly, as does the rest of the chain.
+        assert_eq!(result.reprice_errors[0].instrument, "Deposit 0M @ 5.3100%");
+        assert_relative_eq!(
+            result.reprice_errors[0].model_quote,
+            0.0531,
+            epsilon = 1e-12
+        );
+        for residual in &result.residuals {
+            assert!(residual.abs() < 1e-12, "residual {residual}");
+        }
+
+        // Spot is reached by O/N then T/N simple ACT/360 accrual.
+        let curve = RateCurve::new(result.curve);
+        let df_spot = 1.0 / ((1.0 + 0.0531 / 360.0) * (1.0 + 0.0532 / 360.0));
+        assert_relative_eq!(
+            curve.discount_factor(spot).unwrap(),
+            df_spot,
+            epsilon = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_smoothness_penalty_smooths_noisy_par_rates() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let swap = |tenor: f64, rate: f64| {
+            Swap::from_tenor(
+                today,
+                tenor,
+                rate,
+                Frequency::SemiAnnual,
+                DayCountConvention::Thirty360US,
+            )
+        };
+        // An upward-sloping curve with a few basis points of zig-zag noise.
+        let instruments = InstrumentSet::new()
+            .with(Deposit::from_tenor(
+                today,
+                0.5,
+                0.040,
+                DayCountConvention::Act360,
+            ))
+            .with(Deposit::from_tenor(
+                today,
+                1.0,
+                0.041,
+                DayCountConvention::Act360,
+            ))
+            .with(swap(2.0, 0.0425))
+            .with(swap(3.0, 0.0428))
+            .with(swap(4.0, 0.0441))
+            .with(swap(5.0, 0.0442))
+            .with(swap(7.0, 0.0458))
+            .with(swap(10.0, 0.0461));
+
+        let roughness = |result: &CalibrationResult| -> f64 {
+            let curve = RateCurve::new(result.curve.clone());
+            forward_roughness(&curve, result.curve.tenors())
+                .unwrap()
+                .iter()
+                .map(|term| term * term)
+                .sum()
+        };
+
+        let exact = GlobalFitter::new().fit(today, &instruments).unwrap();
+        let unpenalized =
+            GlobalFitter::with_config(FitterConfig::default().with_smoothness_weight(0.0))
+                .fit(today, &instruments)
+                .unwrap();
+        let smoothed =
+            GlobalFitter::with_config(FitterConfig::default().with_smoothness_weight(1e-2))
+                .fit(today, &instruments)
+                .unwrap();
+
+        // Zero weight is the exact fit.
+        assert_eq!(unpenalized.curve.values(), exact.curve.values());
+        assert!(exact.max_error() < 1e-8);
+
+        // A heavy penalty smooths the forwards at the cost of a few bp of fit.
+        assert!(smoothed.converged);
+        assert!(roughness(&smoothed) < roughness(&exact) / 10.0);
+        assert!(smoothed.max_error() > 1e-5);
+        assert!(smoothed.max_error() < 5e-4);
+    }
+
+    #[test]
+    fn test_par_jacobian_of_piecewise_bootstrap() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        // ACT/365F throughout puts each maturity exactly on its pillar.
+        let instruments = |five_year: f64| {
+            let swap = |tenor: f64, rate: f64| {
+                Swap::from_tenor(
+                    today,
+                    tenor,
+                    rate,
+                    Frequency::SemiAnnual,
+                    DayCountConvention::Act365Fixed,
+                )
+            };
+            InstrumentSet::new()
+                .with(Deposit::from_tenor(
+                    today,
+                    0.5,
+                    0.040,
+                    DayCountConvention::Act365Fixed,
+                ))
+                .with(Deposit::from_tenor(
+                    today,
+                    1.0,
+                    0.041,
+                    DayCountConvention::Act365Fixed,
+                ))
+                .with(swap(2.0, 0.042))
+                .with(swap(3.0, 0.043))
+                .with(swap(5.0, five_year))
+                .with(swap(7.0, 0.045))
+                .with(swap(10.0, 0.046))
+        };
+        let bootstrapper = PiecewiseBootstrapper::new();
+        let base = bootstrapper.bootstrap(today, &instruments(0.044)).unwrap();
+        let bumped = bootstrapper.bootstrap(today, &instruments(0.0441)).unwrap();
+
+        let jacobian = base.par_jacobian(&instruments(0.044)).unwrap();
+        let tenors = base.curve.tenors();
+        assert_eq!(jacobian.len(), tenors.len());
+        assert_eq!(jacobian[0].len(), 7);
+
+        // Column 4 is the 5Y swap: the t=0 anchor and the pillars before
+        // 5Y do not move; the 5Y pillar moves about one for one and the
+        // later pillars move to keep the 7Y and 10Y swaps at par.
+        for (j, &tenor) in tenors.iter().enumerate() {
+            let refit = (bumped.curve.values()[j] - base.curve.values()[j]) / 0.0001;
+            assert!(
+                (jacobian[j][4] - refit).abs() < 1e-3,
+                "pillar {tenor}: {} vs refit {refit}",
+                jacobian[j][4]
+            );
+            if tenor < 4.9 {
+                assert!(jacobian[j][4].abs() < 1e-4);
+            } else {
+                assert!(jacobian[j][4].abs() > 1e-3);
+            }
+        }
+        assert!(jacobian[5][4] > 0.9);
+    }
 }
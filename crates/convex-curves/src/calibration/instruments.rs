@@ -178,11 +178,37 @@ pub trait CalibrationInstrument: Send + Sync + fmt::Debug {
     /// This is the residual that the calibration solver minimizes.
     fn pricing_error(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64>;
 
+    /// The quote implied by the curve, in the units of [`quote`](Self::quote).
+    ///
+    /// The default suits instruments whose pricing error is the implied
+    /// rate less the quoted one.
+    fn implied_quote(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64> {
+        Ok(self.quote() + self.pricing_error(curve)?)
+    }
+
     /// Calculate DV01 (sensitivity to 1bp rate move).
     ///
     /// Used by Newton-based solvers for faster convergence.
     fn dv01(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64>;
 
+    /// The money-market period this instrument fixes exactly, if it is a
+    /// single-period stub at the front of the curve.
+    ///
+    /// Bootstrappers chain these from the reference date to place the
+    /// overnight, tom-next and first OIS points without interpolation.
+    fn front_stub(&self) -> Option<FrontStub> {
+        None
+    }
+
+    /// This instrument with `model`'s futures convexity adjustment applied,
+    /// or `None` if it is not a futures contract.
+    fn with_futures_convexity(
+        &self,
+        _model: FuturesConvexity,
+    ) -> Option<Box<dyn CalibrationInstrument>> {
+        None
+    }
+
     /// Instrument type description.
     fn instrument_type(&self) -> &'static str;
 
@@ -197,6 +223,20 @@ pub trait CalibrationInstrument: Send + Sync + fmt::Debug {
     }
 }
 
+/// A single accrual period at the front of the curve.
+///
+/// The instrument's quote fixes the growth `DF(start) / DF(end)` over the
+/// period, so `DF(end)` follows exactly once `DF(start)` is known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrontStub {
+    /// Start of the period.
+    pub start: Date,
+    /// End of the period.
+    pub end: Date,
+    /// Growth of one unit invested over the period.
+    pub growth: f64,
+}
+
 /// Formats a tenor in years to a readable string.
 fn format_tenor(t: f64) -> String {
     if t < 1.0 {
@@ -211,17 +251,20 @@ fn format_tenor(t: f64) -> String {
 /// Money market deposit.
 ///
 /// A deposit is a simple zero-coupon instrument that pays
-/// principal + interest at maturity.
+/// principal + interest at maturity. It usually starts on the reference
+/// date; a tom-next deposit starts the day after.
 ///
 /// # Pricing
 ///
-/// For a deposit rate r with day count fraction τ:
-/// - Simple interest: PV = 1 / (1 + r × τ) should equal DF(T)
-/// - Pricing error = DF(T) × (1 + r × τ) - 1
+/// For a deposit rate r with day count fraction τ from start S to maturity T:
+/// - Simple interest: DF(S) / (1 + r × τ) should equal DF(T)
+/// - Pricing error = DF(T) / DF(S) × (1 + r × τ) - 1
 #[derive(Debug, Clone)]
 pub struct Deposit {
     /// Reference date.
     reference_date: Date,
+    /// Start date.
+    start_date: Date,
     /// Maturity date.
     maturity: Date,
     /// Deposit rate (as decimal, e.g., 0.05 for 5%).
@@ -240,9 +283,22 @@ impl Deposit {
         maturity: Date,
         rate: f64,
         day_count: DayCountConvention,
+    ) -> Self {
+        Self::starting(reference_date, reference_date, maturity, rate, day_count)
+    }
+
+    /// Creates a deposit starting on `start_date`.
+    #[must_use]
+    pub fn starting(
+        reference_date: Date,
+        start_date: Date,
+        maturity: Date,
+        rate: f64,
+        day_count: DayCountConvention,
     ) -> Self {
         Self {
             reference_date,
+            start_date,
             maturity,
             rate,
             day_count,
@@ -250,6 +306,29 @@ impl Deposit {
         }
     }
 
+    /// Overnight (O/N) deposit: today to tomorrow, ACT/360 simple interest.
+    #[must_use]
+    pub fn overnight(reference_date: Date, rate: f64) -> Self {
+        Self::new(
+            reference_date,
+            reference_date.add_days(1),
+            rate,
+            DayCountConvention::Act360,
+        )
+    }
+
+    /// Tom-next (T/N) deposit: tomorrow to spot, ACT/360 simple interest.
+    #[must_use]
+    pub fn tom_next(reference_date: Date, rate: f64) -> Self {
+        Self::starting(
+            reference_date,
+            reference_date.add_days(1),
+            reference_date.add_days(2),
+            rate,
+            DayCountConvention::Act360,
+        )
+    }
+
     /// Creates a deposit from tenor in years.
     #[must_use]
     pub fn from_tenor(
@@ -262,10 +341,15 @@ impl Deposit {
         Self::new(reference_date, maturity, rate, day_count)
     }
 
-    /// Returns the year fraction using the day count convention.
+    /// Returns the accrual year fraction using the day count convention.
     fn year_fraction(&self) -> f64 {
         self.day_count
-            .year_fraction_f64(self.reference_date, self.maturity)
+            .year_fraction_f64(self.start_date, self.maturity)
+    }
+
+    /// Returns the discount factor from start to maturity.
+    fn period_df(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64> {
+        Ok(curve.discount_factor(self.maturity)? / curve.discount_factor(self.start_date)?)
     }
 }
 
@@ -279,7 +363,8 @@ impl CalibrationInstrument for Deposit {
     }
 
     fn tenor(&self) -> f64 {
-        self.year_fraction()
+        self.day_count
+            .year_fraction_f64(self.reference_date, self.maturity)
     }
 
     fn quote(&self) -> f64 {
@@ -287,28 +372,42 @@ impl CalibrationInstrument for Deposit {
     }
 
     fn pv(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64> {
-        let df = curve.discount_factor(self.maturity)?;
+        let df = self.period_df(curve)?;
         let tau = self.year_fraction();
 
-        // PV of deposit: pay 1 at t=0, receive 1 + r*tau at T
-        // PV = -1 + (1 + r*tau) * DF(T)
+        // PV of deposit: pay 1 at start, receive 1 + r*tau at T
+        // PV = -1 + (1 + r*tau) * DF(T) / DF(S)
         Ok(self.notional * (-1.0 + (1.0 + self.rate * tau) * df))
     }
 
     fn pricing_error(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64> {
-        // Error is (1 + r*tau) * DF(T) - 1, which should be zero
-        let df = curve.discount_factor(self.maturity)?;
+        // Error is (1 + r*tau) * DF(T) / DF(S) - 1, which should be zero
+        let df = self.period_df(curve)?;
         let tau = self.year_fraction();
         Ok((1.0 + self.rate * tau) * df - 1.0)
     }
 
+    fn implied_quote(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64> {
+        // Simple rate that discounts from T back to S
+        let df = self.period_df(curve)?;
+        Ok((1.0 / df - 1.0) / self.year_fraction())
+    }
+
     fn dv01(&self, curve: &RateCurve<DiscreteCurve>) -> CurveResult<f64> {
-        // DV01 ≈ -τ × DF(T) for a deposit
-        let df = curve.discount_factor(self.maturity)?;
+        // DV01 ≈ -τ × DF(T) / DF(S) for a deposit
+        let df = self.period_df(curve)?;
         let tau = self.year_fraction();
         Ok(-tau * df * self.notional)
     }
 
+    fn front_stub(&self) -> Option<FrontStub> {
+        Some(FrontStub {
+            start: self.start_date,
+            end: self.maturity,
+            growth: 1.0 + self.rate * self.year_fraction(),
+        })
+    }
+
     fn instrument_type(&self) -> &'static str {
         "Deposit"
     }
@@ -453,10 +552,61 @@ impl CalibrationInstrument for Fra {
     }
 }
 
+/// Model for the convexity adjustment of an interest rate future.
+///
+/// Daily margining makes a futures rate sit above the forward rate for the
+/// same period. The adjustment subtracted from the futures rate grows with
+/// the short-rate volatility and with the time to the contract.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FuturesConvexity {
+    /// Ho-Lee: `σ²·t₁·t₂/2`.
+    HoLee {
+        /// Normal (absolute) short-rate volatility, e.g. 0.01 for 100bp.
+        volatility: f64,
+    },
+    /// Hull-White: mean reversion damps the Ho-Lee adjustment for distant
+    /// contracts, and tends to it as the mean reversion goes to zero.
+    HullWhite {
+        /// Normal (absolute) short-rate volatility.
+        volatility: f64,
+        /// Mean-reversion speed.
+        mean_reversion: f64,
+    },
+}
+
+impl FuturesConvexity {
+    /// Convexity adjustment (as a decimal rate) for a contract on the period
+    /// from `t1` to `t2` years.
+    #[must_use]
+    pub fn adjustment(&self, t1: f64, t2: f64) -> f64 {
+        match *self {
+            Self::HoLee { volatility } => volatility * volatility * t1 * t2 / 2.0,
+            Self::HullWhite {
+                volatility,
+                mean_reversion,
+            } => {
+                let a = mean_reversion;
+                if a.abs() < 1e-8 {
+                    return Self::HoLee { volatility }.adjustment(t1, t2);
+                }
+                let b = |t: f64| (1.0 - (-a * t).exp()) / a;
+                let b12 = b(t2 - t1);
+                b12 / (t2 - t1)
+                    * (b12 * (1.0 - (-2.0 * a * t1).exp()) + 2.0 * a * b(t1) * b(t1))
+                    * volatility
+                    * volatility
+                    / (4.0 * a)
+            }
+        }
+    }
+}
+
 /// Interest rate future.
 ///
 /// Futures are quoted as price = 100 - rate. We apply a convexity
-/// adjustment to convert from futures rate to forward rate.
+/// adjustment to convert from futures rate to forward rate: a fixed number
+/// of basis points, plus a [`FuturesConvexity`] model's adjustment if one is
+/// set.
 #[derive(Debug, Clone)]
 pub struct Future {
     /// Reference date.
@@ -469,6 +619,8 @@ pub struct Future {
     price: f64,
     /// Convexity adjustment in basis points.
     convexity_adj_bps: f64,
+    /// Model convexity adjustment, on top of `convexity_adj_bps`.
+    convexity_model: Option<FuturesConvexity>,
     /// Day count convention.
     day_count: DayCountConvention,
     /// Notional.
@@ -492,21 +644,41 @@ impl Future {
             end_date,
             price,
             convexity_adj_bps,
+            convexity_model: None,
             day_count,
             notional: 1.0,
         }
     }
 
+    /// Sets a model convexity adjustment, applied on top of the fixed one.
+    #[must_use]
+    pub fn with_convexity_model(mut self, model: FuturesConvexity) -> Self {
+        self.convexity_model = Some(model);
+        self
+    }
+
     /// Returns the futures rate (100 - price) as a decimal.
     #[must_use]
     pub fn futures_rate(&self) -> f64 {
         (100.0 - self.price) / 100.0
     }
 
+    /// Returns the total convexity adjustment as a decimal rate.
+    #[must_use]
+    pub fn convexity_adjustment(&self) -> f64 {
+        let model = self.convexity_model.map_or(0.0, |model| {
+            let t1 = self
+                .day_count
+                .year_fraction_f64(self.reference_date, self.imm_date);
+            model.adjustment(t1, self.tenor())
+        });
+        self.convexity_adj_bps / 10_000.0 + model
+    }
+
     /// Returns the convexity-adjusted forward rate.
     #[must_use]
     pub fn adjusted_rate(&self) -> f64 {
-        self.futures_rate() - self.convexity_adj_bps / 10_000.0
+        self.futures_rate() - self.convexity_adjustment()
     }
 
     /// Returns the forward period year fraction.
@@ -561,6 +733,13 @@ impl CalibrationInstrument for Future {
         Ok(tau * df_end * self.notional)
     }
 
+    fn with_futures_convexity(
+        &self,
+        model: FuturesConvexity,
+    ) -> Option<Box<dyn CalibrationInstrument>> {
+        Some(Box::new(self.clone().with_convexity_model(model)))
+    }
+
     fn instrument_type(&self) -> &'static str {
         "Future"
     }
@@ -903,6 +1082,23 @@ impl CalibrationInstrument for Ois {
         self.annuity(curve)
     }
 
+    fn front_stub(&self) -> Option<FrontStub> {
+        // With a single fixed payment, the floating leg compounds the daily
+        // overnight rate from effective date to maturity, so at par that
+        // compounded growth equals simple interest at the fixed rate.
+        if self.fixed_schedule().len() != 1 {
+            return None;
+        }
+        let tau = self
+            .day_count
+            .year_fraction_f64(self.effective_date, self.maturity);
+        Some(FrontStub {
+            start: self.effective_date,
+            end: self.maturity,
+            growth: 1.0 + self.fixed_rate * tau,
+        })
+    }
+
     fn instrument_type(&self) -> &'static str {
         "OIS"
     }
@@ -1134,6 +1330,48 @@ mod tests {
         assert!(rms < 0.01); // Should be small on reasonable curve
     }
 
+    #[test]
+    fn test_futures_convexity_adjustment() {
+        let ho_lee = FuturesConvexity::HoLee { volatility: 0.01 };
+        // σ²·t₁·t₂/2 = 0.0001 × 4 × 4.25 / 2
+        assert!((ho_lee.adjustment(4.0, 4.25) - 0.00085).abs() < 1e-12);
+
+        // Larger for later contracts and higher volatility.
+        assert!(ho_lee.adjustment(4.0, 4.25) > ho_lee.adjustment(1.0, 1.25));
+        let higher_vol = FuturesConvexity::HoLee { volatility: 0.015 };
+        assert!(higher_vol.adjustment(4.0, 4.25) > ho_lee.adjustment(4.0, 4.25));
+
+        // Mean reversion damps it; without mean reversion it is Ho-Lee.
+        let hull_white = FuturesConvexity::HullWhite {
+            volatility: 0.01,
+            mean_reversion: 0.05,
+        };
+        assert!(hull_white.adjustment(4.0, 4.25) < ho_lee.adjustment(4.0, 4.25));
+        assert!(hull_white.adjustment(4.0, 4.25) > hull_white.adjustment(1.0, 1.25));
+        let no_reversion = FuturesConvexity::HullWhite {
+            volatility: 0.01,
+            mean_reversion: 1e-6,
+        };
+        assert!((no_reversion.adjustment(4.0, 4.25) - 0.00085).abs() < 1e-8);
+
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let future = Future::new(
+            today,
+            today.add_months(48).unwrap(),
+            today.add_months(51).unwrap(),
+            95.0,
+            1.0,
+            DayCountConvention::Act365Fixed,
+        );
+        assert!((future.adjusted_rate() - 0.0499).abs() < 1e-12);
+        let modelled = future.clone().with_convexity_model(ho_lee);
+        assert!(modelled.convexity_adjustment() > 0.0001);
+        assert!(
+            (modelled.adjusted_rate() - (0.0499 - modelled.convexity_adjustment() + 0.0001)).abs()
+                < 1e-12
+        );
+    }
+
     #[test]
     fn test_instrument_descriptions() {
         let today = Date::from_ymd(2024, 1, 2).unwrap();
@@ -47,9 +47,10 @@ mod global_fit;
 mod instruments;
 
 pub use global_fit::{
-    CalibrationResult, FitterConfig, GlobalFitter, PiecewiseBootstrapper, SequentialBootstrapper,
+    CalibrationResult, FitterConfig, GlobalFitter, PiecewiseBootstrapper, RepriceError,
+    SequentialBootstrapper, ILL_CONDITIONED_THRESHOLD,
 };
 pub use instruments::{
-    CalibrationInstrument, CurveInstrument, Deposit, Fra, Future, InstrumentSet, InstrumentType,
-    Ois, Swap,
+    CalibrationInstrument, CurveInstrument, Deposit, Fra, FrontStub, Future, FuturesConvexity,
+    InstrumentSet, InstrumentType, Ois, Swap,
 };
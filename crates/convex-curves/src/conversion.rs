@@ -34,7 +34,9 @@
 //! - `Q(t) = exp(-∫₀ᵗ h(s) ds)`
 //! - `h(t) = -d/dt ln(Q(t))`
 
-use convex_core::types::Compounding;
+use convex_core::types::{convert_yield, Compounding};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 
 /// Value conversion utilities for term structures.
 pub struct ValueConverter;
@@ -76,23 +78,7 @@ impl ValueConverter {
         match compounding {
             Compounding::Continuous => -df.ln() / t,
             Compounding::Simple => (1.0 / df - 1.0) / t,
-            Compounding::Annual => df.powf(-1.0 / t) - 1.0,
-            Compounding::SemiAnnual => {
-                let n = 2.0;
-                n * (df.powf(-1.0 / (n * t)) - 1.0)
-            }
-            Compounding::Quarterly => {
-                let n = 4.0;
-                n * (df.powf(-1.0 / (n * t)) - 1.0)
-            }
-            Compounding::Monthly => {
-                let n = 12.0;
-                n * (df.powf(-1.0 / (n * t)) - 1.0)
-            }
-            Compounding::Daily => {
-                let n = 365.0;
-                n * (df.powf(-1.0 / (n * t)) - 1.0)
-            }
+            periodic => Self::convert_compounding(-df.ln() / t, Compounding::Continuous, periodic),
         }
     }
 
@@ -128,22 +114,8 @@ impl ValueConverter {
         match compounding {
             Compounding::Continuous => (-rate * t).exp(),
             Compounding::Simple => 1.0 / (1.0 + rate * t),
-            Compounding::Annual => (1.0 + rate).powf(-t),
-            Compounding::SemiAnnual => {
-                let n = 2.0;
-                (1.0 + rate / n).powf(-n * t)
-            }
-            Compounding::Quarterly => {
-                let n = 4.0;
-                (1.0 + rate / n).powf(-n * t)
-            }
-            Compounding::Monthly => {
-                let n = 12.0;
-                (1.0 + rate / n).powf(-n * t)
-            }
-            Compounding::Daily => {
-                let n = 365.0;
-                (1.0 + rate / n).powf(-n * t)
+            periodic => {
+                (-Self::convert_compounding(rate, periodic, Compounding::Continuous) * t).exp()
             }
         }
     }
@@ -162,7 +134,9 @@ impl ValueConverter {
     ///
     /// # Returns
     ///
-    /// The equivalent rate under the target compounding convention.
+    /// The equivalent rate under the target compounding convention, as
+    /// given by [`convert_yield`]. Simple interest is converted over one
+    /// year.
     ///
     /// # Example
     ///
@@ -180,44 +154,9 @@ impl ValueConverter {
     /// ```
     #[must_use]
     pub fn convert_compounding(rate: f64, from: Compounding, to: Compounding) -> f64 {
-        if from == to {
-            return rate;
-        }
-
-        // Convert to continuous as intermediate step
-        let continuous = Self::to_continuous(rate, from);
-
-        // Convert from continuous to target
-        Self::from_continuous(continuous, to)
-    }
-
-    /// Converts a rate to continuous compounding.
-    #[must_use]
-    fn to_continuous(rate: f64, compounding: Compounding) -> f64 {
-        match compounding {
-            Compounding::Continuous => rate,
-            Compounding::Simple => {
-                // For simple interest, approximation using 1-year period
-                (1.0 + rate).ln()
-            }
-            Compounding::Annual => (1.0 + rate).ln(),
-            Compounding::SemiAnnual => 2.0 * (1.0 + rate / 2.0).ln(),
-            Compounding::Quarterly => 4.0 * (1.0 + rate / 4.0).ln(),
-            Compounding::Monthly => 12.0 * (1.0 + rate / 12.0).ln(),
-            Compounding::Daily => 365.0 * (1.0 + rate / 365.0).ln(),
-        }
-    }
-
-    /// Converts from continuous compounding to another convention.
-    #[must_use]
-    fn from_continuous(continuous_rate: f64, to: Compounding) -> f64 {
-        match to {
-            Compounding::Continuous => continuous_rate,
-            Compounding::Simple | Compounding::Annual => continuous_rate.exp() - 1.0,
-            Compounding::SemiAnnual => 2.0 * ((continuous_rate / 2.0).exp() - 1.0),
-            Compounding::Quarterly => 4.0 * ((continuous_rate / 4.0).exp() - 1.0),
-            Compounding::Monthly => 12.0 * ((continuous_rate / 12.0).exp() - 1.0),
-            Compounding::Daily => 365.0 * ((continuous_rate / 365.0).exp() - 1.0),
+        match Decimal::from_f64_retain(rate) {
+            Some(rate) => convert_yield(rate, from, to).to_f64().unwrap_or(0.0),
+            None => rate,
         }
     }
 
@@ -303,7 +242,7 @@ impl ValueConverter {
             _ => {
                 // For periodic compounding, convert to continuous then back
                 let cont_fwd = ratio.ln() / dt;
-                Self::from_continuous(cont_fwd, compounding)
+                Self::convert_compounding(cont_fwd, Compounding::Continuous, compounding)
             }
         }
     }
@@ -449,6 +388,31 @@ mod tests {
         assert_relative_eq!(back, continuous, epsilon = 1e-10);
     }
 
+    #[test]
+    fn test_periodic_conversions_match_convert_yield() {
+        use rust_decimal_macros::dec;
+
+        let thrice = Compounding::from_periods_per_year(3);
+        let expected = convert_yield(dec!(0.05), Compounding::Continuous, thrice)
+            .to_f64()
+            .unwrap();
+        let rate = ValueConverter::convert_compounding(0.05, Compounding::Continuous, thrice);
+        assert_relative_eq!(rate, expected, epsilon = 1e-12);
+
+        // A 5% continuous discount factor quoted thrice-yearly.
+        let df = (-0.05_f64 * 2.0).exp();
+        assert_relative_eq!(
+            ValueConverter::df_to_zero(df, 2.0, thrice),
+            expected,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            ValueConverter::zero_to_df(expected, 2.0, thrice),
+            df,
+            epsilon = 1e-12
+        );
+    }
+
     #[test]
     fn test_same_compounding_conversion() {
         let rate = 0.05;
@@ -0,0 +1,310 @@
+//! Blended curve implementation.
+//!
+//! A `BlendedCurve` combines several rate curves with fixed weights, either
+//! in discount-factor space or in zero-rate space.
+
+use std::sync::Arc;
+
+use convex_core::types::{Compounding, Date};
+
+use crate::conversion::ValueConverter;
+use crate::error::{CurveError, CurveResult};
+use crate::wrappers::RateCurveDyn;
+
+/// Tolerance on the weight sum accepted by [`BlendedCurve::new`].
+const WEIGHT_SUM_TOLERANCE: f64 = 1e-9;
+
+/// Space in which component curves are averaged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendSpace {
+    /// `P(t) = Σ wᵢ Pᵢ(t)`.
+    #[default]
+    DiscountFactor,
+    /// `r(t) = Σ wᵢ rᵢ(t)` on continuously compounded zero rates.
+    ZeroRate,
+}
+
+/// A weighted blend of rate curves.
+///
+/// Weights must be finite and non-negative with at least one positive.
+/// [`new`](Self::new) additionally requires them to sum to 1;
+/// [`normalized`](Self::normalized) rescales them instead.
+///
+/// Blending in discount-factor space gives a lower zero rate than blending
+/// the rates themselves (the average of exponentials exceeds the exponential
+/// of the average), but both stay within the range of the components.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_curves::{BlendedCurve, BlendSpace};
+///
+/// let blend = BlendedCurve::normalized(vec![(sofr, 3.0), (ois, 1.0)])?
+///     .with_space(BlendSpace::ZeroRate);
+/// let df = blend.discount_factor(5.0)?;
+/// ```
+#[derive(Clone)]
+pub struct BlendedCurve {
+    /// Component curves and their weights.
+    components: Vec<(Arc<dyn RateCurveDyn>, f64)>,
+    /// Space in which the components are averaged.
+    space: BlendSpace,
+}
+
+impl std::fmt::Debug for BlendedCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let weights: Vec<f64> = self.components.iter().map(|(_, w)| *w).collect();
+        f.debug_struct("BlendedCurve")
+            .field("weights", &weights)
+            .field("space", &self.space)
+            .finish()
+    }
+}
+
+impl BlendedCurve {
+    /// Creates a blend whose weights already sum to 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::InvalidValue` if there are no components, a weight
+    /// is non-finite or negative, all weights are zero, or the weights do not
+    /// sum to 1; `CurveError::ReferenceDateMismatch` if the components have
+    /// different reference dates.
+    pub fn new(components: Vec<(Arc<dyn RateCurveDyn>, f64)>) -> CurveResult<Self> {
+        let total = validate(&components)?;
+        if (total - 1.0).abs() > WEIGHT_SUM_TOLERANCE {
+            return Err(CurveError::invalid_value(format!(
+                "blend weights must sum to 1, got {total}"
+            )));
+        }
+        Ok(Self {
+            components,
+            space: BlendSpace::default(),
+        })
+    }
+
+    /// Creates a blend, rescaling the weights to sum to 1.
+    ///
+    /// # Errors
+    ///
+    /// As [`new`](Self::new), except that any positive weight sum is accepted.
+    pub fn normalized(components: Vec<(Arc<dyn RateCurveDyn>, f64)>) -> CurveResult<Self> {
+        let total = validate(&components)?;
+        let components = components
+            .into_iter()
+            .map(|(curve, weight)| (curve, weight / total))
+            .collect();
+        Ok(Self {
+            components,
+            space: BlendSpace::default(),
+        })
+    }
+
+    /// Sets the space in which the components are averaged.
+    #[must_use]
+    pub fn with_space(mut self, space: BlendSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Returns the blending space.
+    #[must_use]
+    pub fn space(&self) -> BlendSpace {
+        self.space
+    }
+
+    /// Returns the component weights, in order.
+    #[must_use]
+    pub fn weights(&self) -> Vec<f64> {
+        self.components.iter().map(|(_, w)| *w).collect()
+    }
+}
+
+/// Checks the components and returns the weight sum.
+fn validate(components: &[(Arc<dyn RateCurveDyn>, f64)]) -> CurveResult<f64> {
+    let Some((first, _)) = components.first() else {
+        return Err(CurveError::invalid_value(
+            "blended curve needs at least one component",
+        ));
+    };
+    let reference_date = first.reference_date();
+
+    let mut total = 0.0;
+    for (curve, weight) in components {
+        if !weight.is_finite() {
+            return Err(CurveError::invalid_value(format!(
+                "blend weight must be finite, got {weight}"
+            )));
+        }
+        if *weight < 0.0 {
+            return Err(CurveError::invalid_value(format!(
+                "blend weight must be non-negative, got {weight}"
+            )));
+        }
+        if curve.reference_date() != reference_date {
+            return Err(CurveError::reference_date_mismatch(
+                reference_date,
+                curve.reference_date(),
+            ));
+        }
+        total += weight;
+    }
+    if total <= 0.0 {
+        return Err(CurveError::invalid_value("blend weights are all zero"));
+    }
+    Ok(total)
+}
+
+impl RateCurveDyn for BlendedCurve {
+    fn discount_factor(&self, t: f64) -> CurveResult<f64> {
+        if t <= 0.0 {
+            return Ok(1.0);
+        }
+        match self.space {
+            BlendSpace::DiscountFactor => self
+                .components
+                .iter()
+                .map(|(curve, w)| curve.discount_factor(t).map(|df| w * df))
+                .sum(),
+            BlendSpace::ZeroRate => {
+                let rate: f64 = self
+                    .components
+                    .iter()
+                    .map(|(curve, w)| curve.zero_rate(t, Compounding::Continuous).map(|r| w * r))
+                    .sum::<CurveResult<f64>>()?;
+                Ok((-rate * t).exp())
+            }
+        }
+    }
+
+    fn zero_rate(&self, t: f64, compounding: Compounding) -> CurveResult<f64> {
+        if t <= 0.0 {
+            return Ok(0.0);
+        }
+        let df = self.discount_factor(t)?;
+        Ok(ValueConverter::df_to_zero(df, t, compounding))
+    }
+
+    fn forward_rate(&self, t1: f64, t2: f64) -> CurveResult<f64> {
+        if t2 <= t1 {
+            return Err(CurveError::invalid_value(format!(
+                "forward_rate: t2 ({t2}) must be after t1 ({t1})"
+            )));
+        }
+        let df1 = self.discount_factor(t1)?;
+        let df2 = self.discount_factor(t2)?;
+        Ok((df1 / df2).ln() / (t2 - t1))
+    }
+
+    fn instantaneous_forward(&self, t: f64) -> CurveResult<f64> {
+        match self.space {
+            // d/dt of -ln Σ wᵢPᵢ: the DF-weighted average of component forwards.
+            BlendSpace::DiscountFactor => {
+                let mut weighted = 0.0;
+                let mut total = 0.0;
+                for (curve, w) in &self.components {
+                    let df = curve.discount_factor(t)?;
+                    weighted += w * df * curve.instantaneous_forward(t)?;
+                    total += w * df;
+                }
+                Ok(weighted / total)
+            }
+            BlendSpace::ZeroRate => self
+                .components
+                .iter()
+                .map(|(curve, w)| curve.instantaneous_forward(t).map(|f| w * f))
+                .sum(),
+        }
+    }
+
+    fn reference_date(&self) -> Date {
+        self.components[0].0.reference_date()
+    }
+
+    fn max_date(&self) -> Date {
+        self.components
+            .iter()
+            .map(|(curve, _)| curve.max_date())
+            .min()
+            .expect("validated non-empty")
+    }
+
+    fn date_to_tenor(&self, date: Date) -> f64 {
+        self.components[0].0.date_to_tenor(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::DiscreteCurve;
+    use crate::wrappers::RateCurve;
+    use crate::{InterpolationMethod, ValueType};
+    use approx::assert_relative_eq;
+    use convex_core::daycounts::DayCountConvention;
+
+    fn flat(rate: f64) -> Arc<dyn RateCurveDyn> {
+        let curve = DiscreteCurve::new(
+            Date::from_ymd(2024, 1, 1).unwrap(),
+            vec![1.0, 5.0, 30.0],
+            vec![rate; 3],
+            ValueType::ZeroRate {
+                compounding: Compounding::Continuous,
+                day_count: DayCountConvention::Act365Fixed,
+            },
+            InterpolationMethod::Linear,
+        )
+        .unwrap();
+        Arc::new(RateCurve::new(curve))
+    }
+
+    #[test]
+    fn test_rate_space_vs_df_space() {
+        let components = vec![(flat(0.02), 0.5), (flat(0.06), 0.5)];
+        let df_blend = BlendedCurve::new(components.clone()).unwrap();
+        let rate_blend = BlendedCurve::new(components)
+            .unwrap()
+            .with_space(BlendSpace::ZeroRate);
+
+        let t = 10.0;
+        let rate_space = rate_blend.zero_rate(t, Compounding::Continuous).unwrap();
+        let df_space = df_blend.zero_rate(t, Compounding::Continuous).unwrap();
+
+        assert_relative_eq!(rate_space, 0.04, epsilon = 1e-12);
+        assert_relative_eq!(
+            df_blend.discount_factor(t).unwrap(),
+            0.5 * ((-0.2f64).exp() + (-0.6f64).exp()),
+            epsilon = 1e-12
+        );
+
+        // Different, but both inside the component range.
+        assert!(df_space < rate_space - 1e-4, "{df_space} vs {rate_space}");
+        assert!(df_space > 0.02 && df_space < 0.06);
+
+        // The DF-space forward is the DF-weighted average of the components.
+        let fwd = df_blend.instantaneous_forward(t).unwrap();
+        let (p1, p2) = ((-0.2f64).exp(), (-0.6f64).exp());
+        assert_relative_eq!(fwd, (p1 * 0.02 + p2 * 0.06) / (p1 + p2), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_normalized_rescales_weights() {
+        let blend = BlendedCurve::normalized(vec![(flat(0.02), 3.0), (flat(0.06), 1.0)]).unwrap();
+        assert_eq!(blend.weights(), vec![0.75, 0.25]);
+
+        let rate = blend
+            .with_space(BlendSpace::ZeroRate)
+            .zero_rate(5.0, Compounding::Continuous)
+            .unwrap();
+        assert_relative_eq!(rate, 0.03, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_invalid_weights_rejected() {
+        assert!(BlendedCurve::new(vec![(flat(0.02), 0.6), (flat(0.06), 0.6)]).is_err());
+        assert!(BlendedCurve::normalized(vec![(flat(0.02), -1.0), (flat(0.06), 2.0)]).is_err());
+        assert!(BlendedCurve::normalized(vec![(flat(0.02), 0.0), (flat(0.06), 0.0)]).is_err());
+        assert!(BlendedCurve::normalized(vec![(flat(0.02), f64::NAN)]).is_err());
+        assert!(BlendedCurve::normalized(Vec::new()).is_err());
+    }
+}
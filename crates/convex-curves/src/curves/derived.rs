@@ -236,9 +236,16 @@ impl<T: TermStructure + ?Sized> TermStructure for DerivedCurve<T> {
                 // Derivative unchanged, just at different tenor
                 self.base.derivative_at(transformed_t)
             }
-            CurveTransform::Twist { .. } => {
-                // Complex - twist affects derivative
-                None
+            CurveTransform::Twist {
+                short_shift_bps,
+                long_shift_bps,
+                pivot_tenor: _,
+            } => {
+                // The twist shift is linear in t, so it adds its slope
+                let slope = (long_shift_bps - short_shift_bps) / 30.0;
+                self.base
+                    .derivative_at(transformed_t)
+                    .map(|d| d + slope / 10000.0)
             }
         }
     }
@@ -341,6 +348,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_forward_uses_analytic_derivative() {
+        use crate::wrappers::RateCurve;
+
+        let today = Date::from_ymd(2024, 1, 1).unwrap();
+        let base = Arc::new(
+            DiscreteCurve::new(
+                today,
+                vec![1.0, 2.0, 5.0, 10.0, 30.0],
+                vec![0.04, 0.045, 0.05, 0.055, 0.052],
+                ValueType::ZeroRate {
+                    compounding: Compounding::Continuous,
+                    day_count: DayCountConvention::Act365Fixed,
+                },
+                InterpolationMethod::CubicSpline,
+            )
+            .unwrap(),
+        );
+
+        for transform in [
+            CurveTransform::parallel_shift(50.0),
+            CurveTransform::scale(1.1),
+            CurveTransform::twist(-20.0, 40.0, 5.0),
+        ] {
+            let curve = RateCurve::new(DerivedCurve::new(base.clone(), transform.clone()));
+            for t in [1.5, 3.7, 7.25, 18.0] {
+                let analytic = curve.instantaneous_forward_at_tenor(t).unwrap();
+
+                // Central difference of ln P(t)
+                let h = 1e-5;
+                let up = curve.discount_factor_at_tenor(t + h).unwrap();
+                let down = curve.discount_factor_at_tenor(t - h).unwrap();
+                let numeric = -(up.ln() - down.ln()) / (2.0 * h);
+
+                assert_relative_eq!(analytic, numeric, epsilon = 1e-7);
+            }
+        }
+    }
+
     #[test]
     fn test_reference_date() {
         let base = sample_base_curve();
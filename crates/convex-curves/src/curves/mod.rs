@@ -6,6 +6,7 @@
 //! - [`SegmentedCurve`]: Multiple segments with different sources/interpolation
 //! - [`DelegatedCurve`]: Wraps another curve with fallback handling
 //! - [`DerivedCurve`]: Transforms a base curve (shift, spread, scale)
+//! - [`BlendedCurve`]: Weighted blend of rate curves (DF or zero-rate space)
 //! - [`ForwardCurve`]: Forward rate curve derived from discount curve
 //!
 //! # Builders
@@ -13,11 +14,13 @@
 //! - [`DiscountCurveBuilder`]: Simple builder for discount/zero rate curves
 //! - [`ZeroCurveBuilder`]: Date-based builder for zero rate curves
 
+mod blended;
 mod delegated;
 mod derived;
 mod discrete;
 mod segmented;
 
+pub use blended::{BlendSpace, BlendedCurve};
 pub use delegated::{DelegatedCurve, DelegationFallback};
 pub use derived::{CurveTransform, DerivedCurve};
 pub use discrete::DiscreteCurve;
@@ -168,7 +171,7 @@ pub struct DiscountCurveBuilder {
     tenors: Vec<f64>,
     values: Vec<f64>,
     is_zero_rate: bool,
-    interpolation: InterpolationMethod,
+    interpolation: Option<InterpolationMethod>,
     extrapolate: bool,
 }
 
@@ -180,7 +183,7 @@ impl DiscountCurveBuilder {
             tenors: Vec::new(),
             values: Vec::new(),
             is_zero_rate: false,
-            interpolation: InterpolationMethod::LogLinear,
+            interpolation: None,
             extrapolate: false,
         }
     }
@@ -202,8 +205,11 @@ impl DiscountCurveBuilder {
     }
 
     /// Sets the interpolation method.
+    ///
+    /// Without this, the curve interpolates log-linearly, or linearly when
+    /// any zero-rate pillar is zero or negative.
     pub fn with_interpolation(mut self, method: InterpolationMethod) -> Self {
-        self.interpolation = method;
+        self.interpolation = Some(method);
         self
     }
 
@@ -214,7 +220,23 @@ impl DiscountCurveBuilder {
     }
 
     /// Builds the discount curve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if log-linear interpolation was requested for
+    /// zero-rate pillars that include a zero or negative rate.
     pub fn build(self) -> CurveResult<RateCurve<DiscreteCurve>> {
+        let non_positive_rates = self.is_zero_rate && self.values.iter().any(|&rate| rate <= 0.0);
+        let interpolation = match self.interpolation {
+            Some(InterpolationMethod::LogLinear) if non_positive_rates => {
+                return Err(CurveError::builder_error(
+                    "log-linear interpolation needs positive zero rates",
+                ));
+            }
+            Some(method) => method,
+            None if non_positive_rates => InterpolationMethod::Linear,
+            None => InterpolationMethod::LogLinear,
+        };
         let value_type = if self.is_zero_rate {
             ValueType::ZeroRate {
                 compounding: Compounding::Continuous,
@@ -229,7 +251,7 @@ impl DiscountCurveBuilder {
             self.tenors,
             self.values,
             value_type,
-            self.interpolation,
+            interpolation,
         )?;
 
         Ok(RateCurve::new(curve))
@@ -354,6 +376,27 @@ mod tests {
         assert!((df - 0.95).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_discount_curve_builder_negative_zero_rates() {
+        let today = Date::from_ymd(2024, 1, 1).unwrap();
+        let curve = DiscountCurveBuilder::new(today)
+            .add_zero_rate(1.0, -0.006)
+            .add_zero_rate(2.0, -0.004)
+            .build()
+            .unwrap();
+
+        // Linear in the zero rate between pillars; discount factors above one.
+        let df = curve.discount_factor_at_tenor(1.5).unwrap();
+        assert!((df - (0.005_f64 * 1.5).exp()).abs() < 1e-12);
+
+        let log_linear = DiscountCurveBuilder::new(today)
+            .add_zero_rate(1.0, -0.006)
+            .add_zero_rate(2.0, -0.004)
+            .with_interpolation(InterpolationMethod::LogLinear)
+            .build();
+        assert!(log_linear.is_err());
+    }
+
     #[test]
     fn test_discount_curve_builder_zero_rates() {
         let today = Date::from_ymd(2024, 1, 1).unwrap();
@@ -125,6 +125,20 @@ pub enum CurveError {
         reason: String,
     },
 
+    /// The chosen interpolation produces a negative instantaneous forward.
+    #[error(
+        "Negative instantaneous forward {forward:.6} at t={tenor:.4} under {interpolation:?} \
+         interpolation; MonotoneConvex keeps forwards positive"
+    )]
+    NegativeForward {
+        /// Tenor of the negative forward.
+        tenor: f64,
+        /// The forward rate found there.
+        forward: f64,
+        /// Interpolation of the segment that produced it.
+        interpolation: crate::InterpolationMethod,
+    },
+
     /// Curve not found in environment.
     #[error("Curve not found: {name}")]
     CurveNotFound {
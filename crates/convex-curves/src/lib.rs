@@ -43,8 +43,8 @@
 //!
 //! - [`RateCurve<T>`]: `discount_factor()`, `zero_rate()`, `forward_rate()`
 //! - [`CreditCurve<T>`]: `survival_probability()`, `hazard_rate()`
-//! - `InflationCurve<T>`: `index_ratio()`, `real_rate()` (planned)
-//! - `FxCurve<T>`: `forward_rate()`, `forward_points()` (planned)
+//! - [`InflationCurve<T>`]: `index_ratio()`, `real_rate()`
+//! - [`FxCurve<T>`]: `forward_rate()`, `forward_points()`
 //!
 //! ## Curve Types
 //!
@@ -137,12 +137,12 @@ pub use term_structure::{Curve, CurveRef, TermStructure};
 pub use value_type::ValueType;
 
 // Re-export curve types
-pub use curves::{CurveTransform, DelegationFallback, SegmentSource};
-pub use curves::{DelegatedCurve, DerivedCurve, DiscreteCurve, SegmentedCurve};
+pub use curves::{BlendSpace, CurveTransform, DelegationFallback, SegmentSource};
+pub use curves::{BlendedCurve, DelegatedCurve, DerivedCurve, DiscreteCurve, SegmentedCurve};
 pub use curves::{DiscountCurve, DiscountCurveBuilder, ForwardCurve, ZeroCurve, ZeroCurveBuilder};
 
 // Re-export wrappers
-pub use wrappers::{CreditCurve, RateCurve, RateCurveDyn};
+pub use wrappers::{CreditCurve, FxCurve, InflationCurve, RateCurve, RateCurveDyn};
 
 // Re-export builder
 pub use builder::{CurveBuilder, CurveFamily, SegmentBuilder};
@@ -150,7 +150,8 @@ pub use builder::{CurveBuilder, CurveFamily, SegmentBuilder};
 // Re-export calibration types
 pub use calibration::{
     CalibrationInstrument, CalibrationResult, CurveInstrument, Deposit, FitterConfig, Fra, Future,
-    GlobalFitter, InstrumentSet, InstrumentType, Ois, SequentialBootstrapper, Swap,
+    FuturesConvexity, GlobalFitter, InstrumentSet, InstrumentType, Ois, RepriceError,
+    SequentialBootstrapper, Swap,
 };
 
 // Re-export bumping types
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use convex_core::types::Date;
+use rust_decimal::prelude::ToPrimitive;
 
 use crate::curves::DiscreteCurve;
 use crate::error::{CurveError, CurveResult};
@@ -23,6 +24,8 @@ use super::index::{Currency, CurrencyPair, RateIndex};
 /// - **Credit curves**: Issuer-specific survival probability curves
 /// - **Government curves**: Benchmark curves per currency
 /// - **FX curves**: Forward curves for cross-currency pricing
+/// - **Cross-currency basis**: Spreads applied when one currency's flows are
+///   funded in another
 ///
 /// # Example
 ///
@@ -58,6 +61,9 @@ pub struct MultiCurveEnvironment {
 
     /// FX forward curves per currency pair.
     fx_curves: HashMap<CurrencyPair, CurveRef>,
+
+    /// Cross-currency basis spread curves per currency pair.
+    xccy_basis_curves: HashMap<CurrencyPair, CurveRef>,
 }
 
 impl MultiCurveEnvironment {
@@ -118,6 +124,48 @@ impl MultiCurveEnvironment {
             .ok_or_else(|| CurveError::curve_not_found(format!("{} FX", pair)))
     }
 
+    /// Returns the cross-currency basis curve for a currency pair.
+    ///
+    /// `value_at(t)` is the continuously compounded spread (decimal) added to
+    /// `pair.base` discount rates when its flows are funded in `pair.quote`.
+    pub fn xccy_basis(&self, pair: CurrencyPair) -> CurveResult<&dyn TermStructure> {
+        self.xccy_basis_curves
+            .get(&pair)
+            .map(|c| c.as_ref())
+            .ok_or_else(|| CurveError::curve_not_found(format!("{} xccy basis", pair)))
+    }
+
+    /// Returns the discount-factor adjustment `exp(-b(t)·t)` for `currency`
+    /// flows on `date` funded in `funding`.
+    ///
+    /// This is 1 when the currencies match. A basis stored for the inverted
+    /// pair is applied with the opposite sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::CurveNotFound` if no basis curve is stored for
+    /// the pair in either orientation.
+    pub fn xccy_basis_factor(
+        &self,
+        currency: Currency,
+        funding: Currency,
+        date: Date,
+    ) -> CurveResult<f64> {
+        if currency == funding {
+            return Ok(1.0);
+        }
+        let pair = CurrencyPair::new(currency, funding);
+        let (curve, sign) = match self.xccy_basis_curves.get(&pair) {
+            Some(curve) => (curve, 1.0),
+            None => match self.xccy_basis_curves.get(&pair.invert()) {
+                Some(curve) => (curve, -1.0),
+                None => return Err(CurveError::curve_not_found(format!("{} xccy basis", pair))),
+            },
+        };
+        let t = curve.date_to_tenor(date).max(0.0);
+        Ok((-sign * curve.value_at(t) * t).exp())
+    }
+
     /// Returns true if a discount curve exists for the currency.
     #[must_use]
     pub fn has_discount(&self, currency: Currency) -> bool {
@@ -154,13 +202,50 @@ impl MultiCurveEnvironment {
         self.credit_curves.keys().map(|s| s.as_str()).collect()
     }
 
-    /// Returns a discount factor from the appropriate discount curve.
+    /// Returns a discount factor from the currency's OIS discount curve.
+    ///
+    /// This is the discounting side of the multi-curve split: cash flows
+    /// are valued off it whatever index they were projected from (see
+    /// [`projected_forward`](Self::projected_forward)).
     pub fn discount_factor(&self, currency: Currency, date: Date) -> CurveResult<f64> {
         let curve = self.discount(currency)?;
         let rate_curve = RateCurve::new(CurveWrapper(curve));
         rate_curve.discount_factor(date)
     }
 
+    /// Returns the projected fixing of `index` for the accrual period from
+    /// `start` to `end`.
+    ///
+    /// This is the projection side of the multi-curve split: the rate is
+    /// [`forward_rate`](Self::forward_rate) off the index's projection curve,
+    /// restated as the simple rate `(P(start)/P(end) − 1) / τ` with `τ` on the
+    /// index's day count, so it is the coupon rate an FRN on the index
+    /// accrues. Any basis between the index and OIS shows up here and not in
+    /// [`discount_factor`](Self::discount_factor).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::CurveNotFound` if the environment has no
+    /// projection curve for the index (the discount curve is never used in
+    /// its place), and `CurveError::InvalidValue` if `end` is not after
+    /// `start`.
+    pub fn projected_forward(&self, index: RateIndex, start: Date, end: Date) -> CurveResult<f64> {
+        let curve = self.projection(index)?;
+        let tau = index
+            .day_count()
+            .to_day_count()
+            .year_fraction(start, end)
+            .to_f64()
+            .unwrap_or(0.0);
+        if end <= start || tau <= 0.0 {
+            return Err(CurveError::invalid_value(
+                "Accrual end must be after accrual start",
+            ));
+        }
+        let curve_tau = curve.date_to_tenor(end) - curve.date_to_tenor(start);
+        Ok(self.forward_rate(index, start, end)? * curve_tau / tau)
+    }
+
     /// Returns a forward rate from the projection curve.
     pub fn forward_rate(&self, index: RateIndex, start: Date, end: Date) -> CurveResult<f64> {
         let curve = self.projection(index)?;
@@ -196,6 +281,7 @@ pub struct MultiCurveEnvironmentBuilder {
     credit_curves: HashMap<String, Arc<CreditCurve<DiscreteCurve>>>,
     govt_curves: HashMap<Currency, CurveRef>,
     fx_curves: HashMap<CurrencyPair, CurveRef>,
+    xccy_basis_curves: HashMap<CurrencyPair, CurveRef>,
 }
 
 impl MultiCurveEnvironmentBuilder {
@@ -209,6 +295,7 @@ impl MultiCurveEnvironmentBuilder {
             credit_curves: HashMap::new(),
             govt_curves: HashMap::new(),
             fx_curves: HashMap::new(),
+            xccy_basis_curves: HashMap::new(),
         }
     }
 
@@ -276,6 +363,14 @@ impl MultiCurveEnvironmentBuilder {
         self
     }
 
+    /// Adds a cross-currency basis curve; see
+    /// [`MultiCurveEnvironment::xccy_basis`] for the convention.
+    #[must_use]
+    pub fn xccy_basis(mut self, pair: CurrencyPair, curve: impl TermStructure + 'static) -> Self {
+        self.xccy_basis_curves.insert(pair, Arc::new(curve));
+        self
+    }
+
     /// Builds the multi-curve environment.
     ///
     /// # Errors
@@ -297,6 +392,7 @@ impl MultiCurveEnvironmentBuilder {
             credit_curves: self.credit_curves,
             govt_curves: self.govt_curves,
             fx_curves: self.fx_curves,
+            xccy_basis_curves: self.xccy_basis_curves,
         })
     }
 }
@@ -342,6 +438,7 @@ impl<'a> TermStructure for CurveWrapper<'a> {
 mod tests {
     use super::*;
     use crate::{InterpolationMethod, ValueType};
+    use convex_core::daycounts::DayCountConvention;
 
     fn sample_curve(reference_date: Date, rate: f64) -> DiscreteCurve {
         let tenors: Vec<f64> = vec![0.25, 0.5, 1.0, 2.0, 5.0, 10.0];
@@ -490,6 +587,46 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_xccy_basis_factor() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let basis = DiscreteCurve::new(
+            today,
+            vec![1.0, 10.0],
+            vec![0.002, 0.002],
+            ValueType::continuous_zero(convex_core::daycounts::DayCountConvention::Act365Fixed),
+            InterpolationMethod::Linear,
+        )
+        .unwrap();
+
+        let env = MultiCurveEnvironment::builder(today)
+            .ois_curve(RateIndex::Sofr, sample_curve(today, 0.04))
+            .xccy_basis(CurrencyPair::new(Currency::Usd, Currency::Eur), basis)
+            .build()
+            .unwrap();
+
+        let date = today.add_days(730);
+        let t = 730.0 / 365.0;
+        let factor = env
+            .xccy_basis_factor(Currency::Usd, Currency::Eur, date)
+            .unwrap();
+        assert!((factor - (-0.002_f64 * t).exp()).abs() < 1e-12);
+
+        // Inverted pair flips the sign; same currency is a no-op.
+        let inverse = env
+            .xccy_basis_factor(Currency::Eur, Currency::Usd, date)
+            .unwrap();
+        assert!((inverse * factor - 1.0).abs() < 1e-12);
+        assert_eq!(
+            env.xccy_basis_factor(Currency::Usd, Currency::Usd, date)
+                .unwrap(),
+            1.0
+        );
+        assert!(env
+            .xccy_basis_factor(Currency::Usd, Currency::Gbp, date)
+            .is_err());
+    }
+
     #[test]
     fn test_with_methods() {
         let today = Date::from_ymd(2024, 1, 2).unwrap();
@@ -508,4 +645,75 @@ mod tests {
         let env = env.with_discount(Currency::Gbp, Arc::new(sonia_curve));
         assert!(env.has_discount(Currency::Gbp));
     }
+
+    #[test]
+    fn test_projected_coupons_carry_basis_over_ois_discounting() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let quarter_ends: Vec<Date> = (0..=8).map(|q| today.add_months(3 * q).unwrap()).collect();
+        let flat_curve = |rate: f64| {
+            let tenors: Vec<f64> = vec![0.0, 0.5, 1.0, 2.0, 5.0];
+            let dfs: Vec<f64> = tenors.iter().map(|&t| (-rate * t).exp()).collect();
+            DiscreteCurve::new(
+                today,
+                tenors,
+                dfs,
+                ValueType::DiscountFactor,
+                InterpolationMethod::LogLinear,
+            )
+            .unwrap()
+        };
+
+        // Par FRN PV per 100: projected coupons and principal on OIS discounting.
+        let frn_pv = |env: &MultiCurveEnvironment, index: RateIndex| -> f64 {
+            let dc = index.day_count().to_day_count();
+            let coupons: f64 = quarter_ends
+                .windows(2)
+                .map(|w| {
+                    let rate = env.projected_forward(index, w[0], w[1]).unwrap();
+                    let tau = dc.year_fraction(w[0], w[1]).to_f64().unwrap();
+                    100.0 * rate * tau * env.discount_factor(Currency::Eur, w[1]).unwrap()
+                })
+                .sum();
+            let maturity = *quarter_ends.last().unwrap();
+            coupons + 100.0 * env.discount_factor(Currency::Eur, maturity).unwrap()
+        };
+
+        // Projecting and discounting off the same OIS curve prices par.
+        let ois_only = MultiCurveEnvironment::builder(today)
+            .ois_curve(RateIndex::Estr, flat_curve(0.035))
+            .build()
+            .unwrap();
+        assert!((frn_pv(&ois_only, RateIndex::Estr) - 100.0).abs() < 1e-9);
+
+        // A 25bp IBOR-OIS basis lifts every projected coupon above the OIS
+        // forward for its period, so the FRN prices above par.
+        let env = MultiCurveEnvironment::builder(today)
+            .discount(Currency::Eur, flat_curve(0.035))
+            .projection(RateIndex::Euribor3M, flat_curve(0.0375))
+            .build()
+            .unwrap();
+        for w in quarter_ends.windows(2) {
+            let projected = env
+                .projected_forward(RateIndex::Euribor3M, w[0], w[1])
+                .unwrap();
+            let df_start = env.discount_factor(Currency::Eur, w[0]).unwrap();
+            let df_end = env.discount_factor(Currency::Eur, w[1]).unwrap();
+            let tau = DayCountConvention::Act360
+                .to_day_count()
+                .year_fraction(w[0], w[1])
+                .to_f64()
+                .unwrap();
+            let ois_forward = (df_start / df_end - 1.0) / tau;
+            assert!(projected - ois_forward > 0.002);
+        }
+        assert!(frn_pv(&env, RateIndex::Euribor3M) > 100.4);
+
+        // No Euribor curve: projection does not fall back to discounting.
+        assert!(env
+            .projected_forward(RateIndex::Euribor6M, today, quarter_ends[2])
+            .is_err());
+        assert!(env
+            .projected_forward(RateIndex::Euribor3M, quarter_ends[1], quarter_ends[1])
+            .is_err());
+    }
 }
@@ -496,6 +496,23 @@ impl Currency {
         }
     }
 
+    /// Parses an ISO 4217 code (case-insensitive).
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        [
+            Currency::Usd,
+            Currency::Eur,
+            Currency::Gbp,
+            Currency::Jpy,
+            Currency::Chf,
+            Currency::Cad,
+            Currency::Aud,
+            Currency::Hkd,
+        ]
+        .into_iter()
+        .find(|c| c.code().eq_ignore_ascii_case(code.trim()))
+    }
+
     /// Returns the primary overnight rate for this currency.
     #[must_use]
     pub fn overnight_rate(&self) -> RateIndex {
@@ -622,6 +639,13 @@ mod tests {
         assert!(active.contains(&RateIndex::Euribor3M));
     }
 
+    #[test]
+    fn test_currency_from_code() {
+        assert_eq!(Currency::from_code("usd"), Some(Currency::Usd));
+        assert_eq!(Currency::from_code("EUR"), Some(Currency::Eur));
+        assert_eq!(Currency::from_code("BRL"), None);
+    }
+
     #[test]
     fn test_currency_pair() {
         let pair = CurrencyPair::EURUSD;
@@ -29,9 +29,9 @@
 //!     .credit("AAPL", apple_credit)
 //!     .build()?;
 //!
-//! // Use for pricing
-//! let df = env.discount_factor(Currency::Usd, payment_date)?;
-//! let fwd = env.forward_rate(RateIndex::Sofr, start, end)?;
+//! // Project off the index curve, discount off OIS
+//! let fwd = env.projected_forward(RateIndex::Euribor3M, start, end)?;
+//! let df = env.discount_factor(Currency::Eur, end)?;
 //! ```
 //!
 //! # Rate Indices
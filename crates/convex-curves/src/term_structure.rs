@@ -128,12 +128,7 @@ pub trait TermStructure: Send + Sync {
     /// Day count for date↔tenor conversions; defaults to the `ValueType`'s
     /// day count or ACT/365 Fixed.
     fn tenor_day_count(&self) -> DayCountConvention {
-        match self.value_type() {
-            ValueType::ZeroRate { day_count, .. } | ValueType::ParSwapRate { day_count, .. } => {
-                day_count
-            }
-            _ => DayCountConvention::Act365Fixed,
-        }
+        self.value_type().tenor_day_count()
     }
 
     /// Converts a date to a year fraction from the reference date using the
@@ -158,6 +158,18 @@ impl ValueType {
         }
     }
 
+    /// Day count that maps dates to tenors on a curve of this value type:
+    /// the rate's own day count, or ACT/365 Fixed.
+    #[must_use]
+    pub fn tenor_day_count(&self) -> DayCountConvention {
+        match self {
+            ValueType::ZeroRate { day_count, .. } | ValueType::ParSwapRate { day_count, .. } => {
+                *day_count
+            }
+            _ => DayCountConvention::Act365Fixed,
+        }
+    }
+
     /// Creates a zero rate value type with continuous compounding.
     #[must_use]
     pub fn continuous_zero(day_count: DayCountConvention) -> Self {
@@ -4,11 +4,19 @@
 //! for credit operations regardless of the underlying value type.
 
 use convex_core::types::Date;
+use convex_math::solvers::{brent, SolverConfig};
 
+use super::RateCurve;
 use crate::conversion::ValueConverter;
+use crate::curves::DiscreteCurve;
 use crate::error::{CurveError, CurveResult};
+use crate::multicurve::Tenor;
 use crate::term_structure::TermStructure;
 use crate::value_type::ValueType;
+use crate::InterpolationMethod;
+
+/// CDS premium payments per year.
+const CDS_PAYMENTS_PER_YEAR: f64 = 4.0;
 
 /// A wrapper providing credit operations on any term structure.
 ///
@@ -426,6 +434,139 @@ impl<T: TermStructure> CreditCurve<T> {
     }
 }
 
+impl CreditCurve<DiscreteCurve> {
+    /// Bootstraps a credit curve from CDS par spreads.
+    ///
+    /// Solves, pillar by pillar, for the piecewise-constant hazard rate that
+    /// makes each CDS worth zero: the premium leg (quarterly payments with
+    /// half-period accrual on default) against the protection leg paying
+    /// `1 - recovery` on default, both discounted off `discount_curve`. The
+    /// curve stores survival probabilities, log-linear between pillars, so
+    /// its hazard rate is constant between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `par_spreads` - CDS maturities and par spreads (decimal, e.g. 0.01
+    ///   for 100bp), in increasing maturity
+    /// * `recovery` - Recovery rate as decimal
+    /// * `discount_curve` - Risk-free discount curve; its reference date is
+    ///   the credit curve's
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no spreads are given, maturities do not
+    /// increase, a spread is negative, recovery is not below one, or a
+    /// hazard rate cannot be solved.
+    pub fn from_cds<D: TermStructure>(
+        par_spreads: &[(Tenor, f64)],
+        recovery: f64,
+        discount_curve: &RateCurve<D>,
+    ) -> CurveResult<Self> {
+        if par_spreads.is_empty() {
+            return Err(CurveError::invalid_value("No CDS spreads provided"));
+        }
+        if !(0.0..1.0).contains(&recovery) {
+            return Err(CurveError::invalid_value(format!(
+                "Recovery rate {recovery} must be in [0, 1)"
+            )));
+        }
+
+        let mut pillars = vec![0.0];
+        let mut survivals = vec![1.0];
+        let solver_config = SolverConfig {
+            tolerance: 1e-14,
+            max_iterations: 200,
+        };
+
+        for (tenor, spread) in par_spreads {
+            let maturity = tenor.years();
+            let start = *pillars.last().expect("pillars start at zero");
+            if maturity <= start {
+                return Err(CurveError::invalid_value(format!(
+                    "CDS maturities must increase: {tenor} after {start}Y"
+                )));
+            }
+            if *spread < 0.0 {
+                return Err(CurveError::invalid_value(format!(
+                    "Negative CDS spread {spread} at {tenor}"
+                )));
+            }
+
+            let q_start = *survivals.last().expect("survivals start at one");
+            let objective = |hazard: f64| {
+                let survival = |t: f64| {
+                    if t <= start {
+                        piecewise_survival(&pillars, &survivals, t)
+                    } else {
+                        q_start * (-hazard * (t - start)).exp()
+                    }
+                };
+                cds_value(survival, discount_curve, maturity, *spread, recovery).unwrap_or(f64::NAN)
+            };
+
+            let hazard = brent(objective, 0.0, 10.0, &solver_config)
+                .map_err(|e| {
+                    CurveError::calibration_failed(
+                        0,
+                        f64::NAN,
+                        format!("CDS hazard rate at {tenor} did not solve: {e}"),
+                    )
+                })?
+                .root;
+            pillars.push(maturity);
+            survivals.push(q_start * (-hazard * (maturity - start)).exp());
+        }
+
+        let curve = DiscreteCurve::new(
+            discount_curve.reference_date(),
+            pillars,
+            survivals,
+            ValueType::SurvivalProbability,
+            InterpolationMethod::LogLinear,
+        )?;
+        Ok(Self::new(curve, recovery))
+    }
+}
+
+/// Survival probability at `t` with constant hazard between the pillars.
+fn piecewise_survival(pillars: &[f64], survivals: &[f64], t: f64) -> f64 {
+    let i = pillars
+        .partition_point(|&p| p < t)
+        .clamp(1, pillars.len() - 1);
+    let (t0, t1) = (pillars[i - 1], pillars[i]);
+    let (q0, q1) = (survivals[i - 1], survivals[i]);
+    q0 * (q1 / q0).powf((t - t0) / (t1 - t0))
+}
+
+/// Value to the protection buyer of a CDS paying `spread` to `maturity`:
+/// protection leg less premium leg, per unit notional.
+fn cds_value<D: TermStructure>(
+    survival: impl Fn(f64) -> f64,
+    discount_curve: &RateCurve<D>,
+    maturity: f64,
+    spread: f64,
+    recovery: f64,
+) -> CurveResult<f64> {
+    let periods = (maturity * CDS_PAYMENTS_PER_YEAR - 1e-9).ceil().max(1.0) as usize;
+    let mut premium = 0.0;
+    let mut protection = 0.0;
+    let mut prev_t = 0.0;
+    let mut prev_q = 1.0;
+    for i in 1..=periods {
+        let t = (i as f64 / CDS_PAYMENTS_PER_YEAR).min(maturity);
+        let q = survival(t);
+        let df = discount_curve.discount_factor_at_tenor(t)?;
+        let accrual = t - prev_t;
+        // Premium paid if alive at t, plus half the period's accrual if
+        // default happens within it.
+        premium += accrual * df * (q + prev_q) / 2.0;
+        protection += (1.0 - recovery) * df * (prev_q - q);
+        prev_t = t;
+        prev_q = q;
+    }
+    Ok(protection - spread * premium)
+}
+
 // Implement TermStructure for CreditCurve so it can be nested
 impl<T: TermStructure> TermStructure for CreditCurve<T> {
     fn reference_date(&self) -> Date {
@@ -514,6 +655,66 @@ mod tests {
         RateCurve::new(curve)
     }
 
+    #[test]
+    fn test_from_cds_flat_spreads_give_flat_hazard() {
+        let discount = sample_discount_curve();
+        let spread = 0.01;
+        let quotes: Vec<_> = [
+            Tenor::Y1,
+            Tenor::Y2,
+            Tenor::Y3,
+            Tenor::Y5,
+            Tenor::Y7,
+            Tenor::Y10,
+        ]
+        .into_iter()
+        .map(|tenor| (tenor, spread))
+        .collect();
+
+        let curve = CreditCurve::from_cds(&quotes, 0.40, &discount).unwrap();
+
+        // Credit triangle: h ≈ s / (1 - R)
+        let triangle = spread / (1.0 - 0.40);
+        for t in [0.5, 1.5, 2.5, 4.0, 6.0, 8.5] {
+            let hazard = curve.hazard_rate_at_tenor(t).unwrap();
+            assert_relative_eq!(hazard, triangle, max_relative = 0.01);
+        }
+
+        // Every quote reprices.
+        let inner = curve.inner();
+        for (tenor, spread) in &quotes {
+            let value = cds_value(
+                |t| inner.value_at(t),
+                &discount,
+                tenor.years(),
+                *spread,
+                0.40,
+            )
+            .unwrap();
+            assert!(value.abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_from_cds_upward_spreads_raise_hazard() {
+        let discount = sample_discount_curve();
+        let quotes = [(Tenor::Y1, 0.005), (Tenor::Y3, 0.01), (Tenor::Y5, 0.015)];
+
+        let curve = CreditCurve::from_cds(&quotes, 0.40, &discount).unwrap();
+
+        let h1 = curve.hazard_rate_at_tenor(0.5).unwrap();
+        let h3 = curve.hazard_rate_at_tenor(2.0).unwrap();
+        let h5 = curve.hazard_rate_at_tenor(4.0).unwrap();
+        assert!(h1 < h3 && h3 < h5);
+        assert_relative_eq!(curve.recovery_rate(), 0.40);
+
+        assert!(CreditCurve::from_cds(&[], 0.40, &discount).is_err());
+        assert!(
+            CreditCurve::from_cds(&[(Tenor::Y5, 0.01), (Tenor::Y3, 0.01)], 0.40, &discount)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_survival_probability() {
         let curve = sample_survival_curve();
@@ -0,0 +1,191 @@
+//! FX forward curve wrapper providing covered-interest-parity forwards.
+//!
+//! `FxCurve<T>` pairs the rate curves of a currency pair's two currencies
+//! with a spot rate and derives outright forwards and forward points.
+
+use convex_core::types::Date;
+
+use crate::error::{CurveError, CurveResult};
+use crate::term_structure::TermStructure;
+use crate::wrappers::RateCurve;
+
+/// FX forwards for a currency pair from covered interest parity.
+///
+/// Spot is quoted as units of the quote currency per unit of the base
+/// currency (EURUSD 1.0850 is 1.0850 USD per EUR). The forward for
+/// delivery at `T` is
+///
+/// ```text
+/// F(T) = S · P_base(T) · exp(−b·t) / P_quote(T)
+/// ```
+///
+/// where `P` are the currencies' discount factors and `b` is the
+/// cross-currency basis: a continuously compounded spread added to base
+/// currency rates when its flows are funded in the quote currency, the
+/// convention of [`MultiCurveEnvironment::xccy_basis`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_curves::{FxCurve, RateCurve};
+///
+/// // EURUSD: base EUR (ESTR), quote USD (SOFR), -10bp EUR basis
+/// let eurusd = FxCurve::new(RateCurve::new(estr), RateCurve::new(sofr), 1.0850)?
+///     .with_basis_spread(-0.0010);
+///
+/// let outright = eurusd.forward_rate(one_year)?;
+/// let pips = eurusd.forward_points(one_year)? * 10_000.0;
+/// ```
+///
+/// [`MultiCurveEnvironment::xccy_basis`]: crate::multicurve::MultiCurveEnvironment::xccy_basis
+#[derive(Clone, Debug)]
+pub struct FxCurve<T: TermStructure> {
+    /// Discount curve of the base currency.
+    base: RateCurve<T>,
+    /// Discount curve of the quote currency.
+    quote: RateCurve<T>,
+    /// Spot rate, quote currency per unit of base currency.
+    spot: f64,
+    /// Cross-currency basis spread (decimal, continuously compounded).
+    basis_spread: f64,
+}
+
+impl<T: TermStructure> FxCurve<T> {
+    /// Creates an FX curve from the two currencies' rate curves and spot,
+    /// with no cross-currency basis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::InvalidValue` if spot is not positive and
+    /// finite.
+    pub fn new(base: RateCurve<T>, quote: RateCurve<T>, spot: f64) -> CurveResult<Self> {
+        if !(spot.is_finite() && spot > 0.0) {
+            return Err(CurveError::invalid_value(format!(
+                "FX spot must be positive, got {spot}"
+            )));
+        }
+        Ok(Self {
+            base,
+            quote,
+            spot,
+            basis_spread: 0.0,
+        })
+    }
+
+    /// Sets the cross-currency basis spread (decimal, continuously
+    /// compounded) added to base currency rates.
+    #[must_use]
+    pub fn with_basis_spread(mut self, spread: f64) -> Self {
+        self.basis_spread = spread;
+        self
+    }
+
+    /// Returns the base currency rate curve.
+    #[must_use]
+    pub fn base_curve(&self) -> &RateCurve<T> {
+        &self.base
+    }
+
+    /// Returns the quote currency rate curve.
+    #[must_use]
+    pub fn quote_curve(&self) -> &RateCurve<T> {
+        &self.quote
+    }
+
+    /// Returns the spot rate.
+    #[must_use]
+    pub fn spot(&self) -> f64 {
+        self.spot
+    }
+
+    /// Returns the cross-currency basis spread.
+    #[must_use]
+    pub fn basis_spread(&self) -> f64 {
+        self.basis_spread
+    }
+
+    /// Returns the outright FX forward for delivery on `date`.
+    ///
+    /// Dates on or before the curves' reference date return spot.
+    pub fn forward_rate(&self, date: Date) -> CurveResult<f64> {
+        let df_base = self.base.discount_factor(date)?;
+        let df_quote = self.quote.discount_factor(date)?;
+        if df_quote <= 0.0 {
+            return Err(CurveError::math_error(
+                "quote currency discount factor is not positive",
+            ));
+        }
+        let t = self.quote.inner().date_to_tenor(date).max(0.0);
+        Ok(self.spot * df_base * (-self.basis_spread * t).exp() / df_quote)
+    }
+
+    /// Returns the forward points for delivery on `date`: outright forward
+    /// less spot, in quote currency units.
+    ///
+    /// Screen points are this scaled by the pair's pip factor (10,000 for
+    /// most pairs, 100 for JPY crosses).
+    pub fn forward_points(&self, date: Date) -> CurveResult<f64> {
+        Ok(self.forward_rate(date)? - self.spot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::DiscreteCurve;
+    use crate::{InterpolationMethod, ValueType};
+    use convex_core::daycounts::DayCountConvention;
+
+    fn flat_curve(today: Date, rate: f64) -> RateCurve<DiscreteCurve> {
+        RateCurve::new(
+            DiscreteCurve::new(
+                today,
+                vec![0.0, 1.0, 5.0, 10.0],
+                vec![rate; 4],
+                ValueType::continuous_zero(DayCountConvention::Act365Fixed),
+                InterpolationMethod::Linear,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_equal_curves_without_basis_forward_is_spot() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        let fx = FxCurve::new(flat_curve(today, 0.04), flat_curve(today, 0.04), 1.085).unwrap();
+
+        for months in [0, 1, 3, 6, 12, 24, 60, 120] {
+            let date = today.add_months(months).unwrap();
+            assert!((fx.forward_rate(date).unwrap() - 1.085).abs() < 1e-12);
+            assert!(fx.forward_points(date).unwrap().abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_covered_interest_parity_with_basis() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        // EURUSD: EUR at 3%, USD at 5%.
+        let fx = FxCurve::new(flat_curve(today, 0.03), flat_curve(today, 0.05), 1.085).unwrap();
+        let date = today.add_days(365);
+
+        // The higher-yielding quote currency trades at a forward discount,
+        // so EURUSD forward points are positive.
+        let forward = fx.forward_rate(date).unwrap();
+        assert!((forward - 1.085 * (0.02_f64).exp()).abs() < 1e-12);
+        assert!(fx.forward_points(date).unwrap() > 0.02);
+
+        // A negative EUR basis lowers its effective rate, widening the points.
+        let with_basis = fx.with_basis_spread(-0.0015);
+        let adjusted = with_basis.forward_rate(date).unwrap();
+        assert!((adjusted - 1.085 * (0.0215_f64).exp()).abs() < 1e-12);
+        assert_eq!(with_basis.forward_rate(today).unwrap(), 1.085);
+    }
+
+    #[test]
+    fn test_spot_must_be_positive() {
+        let today = Date::from_ymd(2024, 1, 2).unwrap();
+        for spot in [0.0, -1.0, f64::NAN] {
+            assert!(FxCurve::new(flat_curve(today, 0.04), flat_curve(today, 0.04), spot).is_err());
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Inflation curve wrapper providing index ratio and real rate semantics.
+//!
+//! `InflationCurve<T>` combines a nominal rate curve with a curve of zero
+//! breakeven inflation rates, optionally with monthly seasonality, and
+//! projects the inflation index from them.
+
+use convex_core::types::{Compounding, Date};
+
+use crate::error::{CurveError, CurveResult};
+use crate::term_structure::TermStructure;
+use crate::wrappers::RateCurve;
+
+/// Projected inflation index and real rates from nominal and breakeven
+/// curves.
+///
+/// The breakeven curve holds zero breakeven inflation rates: read with
+/// continuous compounding, `b(t)` projects the index ratio
+///
+/// ```text
+/// I(T) / I(0) = exp(b(t)·t) · S(T)
+/// ```
+///
+/// where `S(T)` is the cumulative seasonal factor (1 without seasonality).
+/// Real rates follow by Fisher: the continuously compounded nominal zero
+/// rate less `ln(I(T)/I(0)) / t`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_curves::{InflationCurve, RateCurve};
+///
+/// let cpi = InflationCurve::new(RateCurve::new(nominal), RateCurve::new(breakevens))
+///     .with_seasonality(us_cpi_seasonals)?;
+///
+/// let ratio = cpi.index_ratio(payment_date)?;
+/// let real = cpi.real_rate(maturity)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct InflationCurve<T: TermStructure> {
+    /// Nominal discount curve.
+    nominal: RateCurve<T>,
+    /// Zero breakeven inflation curve.
+    breakevens: RateCurve<T>,
+    /// Month-on-month seasonal adjustments, January first, summing to zero.
+    seasonality: Option<[f64; 12]>,
+}
+
+impl<T: TermStructure> InflationCurve<T> {
+    /// Creates an inflation curve from a nominal curve and a zero breakeven
+    /// curve, with no seasonality.
+    pub fn new(nominal: RateCurve<T>, breakevens: RateCurve<T>) -> Self {
+        Self {
+            nominal,
+            breakevens,
+            seasonality: None,
+        }
+    }
+
+    /// Applies monthly seasonality to the projected index.
+    ///
+    /// `adjustments[m]` is the seasonal deviation (decimal, log terms) of
+    /// month-on-month inflation in calendar month `m + 1` from its trend.
+    /// The adjustments are de-meaned so that a full year nets to zero:
+    /// seasonality moves the index within the year but leaves the
+    /// breakeven-implied ratio on each anniversary of the reference date
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CurveError::InvalidValue` if any adjustment is not finite.
+    pub fn with_seasonality(mut self, adjustments: [f64; 12]) -> CurveResult<Self> {
+        if adjustments.iter().any(|a| !a.is_finite()) {
+            return Err(CurveError::invalid_value(
+                "seasonal adjustments must be finite",
+            ));
+        }
+        let mean = adjustments.iter().sum::<f64>() / 12.0;
+        self.seasonality = Some(adjustments.map(|a| a - mean));
+        Ok(self)
+    }
+
+    /// Returns the nominal rate curve.
+    #[must_use]
+    pub fn nominal(&self) -> &RateCurve<T> {
+        &self.nominal
+    }
+
+    /// Returns the breakeven inflation curve.
+    #[must_use]
+    pub fn breakevens(&self) -> &RateCurve<T> {
+        &self.breakevens
+    }
+
+    /// Returns the de-meaned seasonal adjustments, if any.
+    #[must_use]
+    pub fn seasonality(&self) -> Option<&[f64; 12]> {
+        self.seasonality.as_ref()
+    }
+
+    /// Returns the reference date.
+    #[must_use]
+    pub fn reference_date(&self) -> Date {
+        self.nominal.reference_date()
+    }
+
+    /// Returns the projected index ratio `I(date) / I(reference date)`.
+    pub fn index_ratio(&self, date: Date) -> CurveResult<f64> {
+        let t = self.nominal.inner().date_to_tenor(date);
+        if t <= 0.0 {
+            return Ok(1.0);
+        }
+        let breakeven = self.breakevens.zero_rate(date, Compounding::Continuous)?;
+        Ok((breakeven * t).exp() * self.seasonal_factor(date))
+    }
+
+    /// Returns the continuously compounded real zero rate to `date`.
+    pub fn real_rate(&self, date: Date) -> CurveResult<f64> {
+        let t = self.nominal.inner().date_to_tenor(date);
+        if t <= 0.0 {
+            return Ok(0.0);
+        }
+        let nominal = self.nominal.zero_rate(date, Compounding::Continuous)?;
+        Ok(nominal - self.index_ratio(date)?.ln() / t)
+    }
+
+    /// Returns the real discount factor to `date`: the nominal discount
+    /// factor of a cash flow indexed from the reference date.
+    pub fn real_discount_factor(&self, date: Date) -> CurveResult<f64> {
+        Ok(self.nominal.discount_factor(date)? * self.index_ratio(date)?)
+    }
+
+    /// Cumulative seasonal factor from the reference month to the month of
+    /// `date`.
+    fn seasonal_factor(&self, date: Date) -> f64 {
+        let Some(adjustments) = &self.seasonality else {
+            return 1.0;
+        };
+        let reference = self.reference_date();
+        // Whole years net to zero, so only the months past the last
+        // anniversary count.
+        let months = ((date.month() + 12 - reference.month()) % 12) as usize;
+        let start = reference.month() as usize % 12;
+        let log_factor: f64 = (0..months).map(|k| adjustments[(start + k) % 12]).sum();
+        log_factor.exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::DiscreteCurve;
+    use crate::{InterpolationMethod, ValueType};
+    use convex_core::daycounts::DayCountConvention;
+
+    fn flat_curve(today: Date, rate: f64) -> RateCurve<DiscreteCurve> {
+        RateCurve::new(
+            DiscreteCurve::new(
+                today,
+                vec![0.0, 1.0, 5.0, 30.0],
+                vec![rate; 4],
+                ValueType::continuous_zero(DayCountConvention::Act365Fixed),
+                InterpolationMethod::Linear,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_flat_breakeven_index_ratio_is_exponential() {
+        let today = Date::from_ymd(2024, 1, 15).unwrap();
+        let cpi = InflationCurve::new(flat_curve(today, 0.045), flat_curve(today, 0.025));
+
+        let mut previous = 1.0;
+        for months in 0..=120 {
+            let date = today.add_months(months).unwrap();
+            let t = today.days_between(&date) as f64 / 365.0;
+            let ratio = cpi.index_ratio(date).unwrap();
+            assert!((ratio - (0.025 * t).exp()).abs() < 1e-12);
+            assert!(ratio >= previous);
+            previous = ratio;
+
+            if t > 0.0 {
+                assert!((cpi.real_rate(date).unwrap() - 0.02).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_seasonality_shapes_the_year_but_nets_to_zero() {
+        let today = Date::from_ymd(2024, 1, 15).unwrap();
+        // Heavier inflation in spring, lighter in the second half.
+        let mut seasonals = [0.0; 12];
+        seasonals[2] = 0.002;
+        seasonals[3] = 0.002;
+        seasonals[10] = -0.002;
+        seasonals[11] = -0.002;
+        let plain = InflationCurve::new(flat_curve(today, 0.04), flat_curve(today, 0.025));
+        let seasonal = plain.clone().with_seasonality(seasonals).unwrap();
+
+        let ratio = |curve: &InflationCurve<DiscreteCurve>, y, m| {
+            curve
+                .index_ratio(Date::from_ymd(y, m, 15).unwrap())
+                .unwrap()
+        };
+        // Through April the index runs ahead of trend by the spring bumps.
+        let ahead = ratio(&seasonal, 2024, 5) / ratio(&plain, 2024, 5);
+        assert!((ahead - 0.004_f64.exp()).abs() < 1e-12);
+        // A full year later it is back on the breakeven path.
+        assert!((ratio(&seasonal, 2025, 1) - ratio(&plain, 2025, 1)).abs() < 1e-12);
+        assert!((ratio(&seasonal, 2026, 5) / ratio(&plain, 2026, 5) - ahead).abs() < 1e-12);
+
+        assert!(plain.with_seasonality([f64::NAN; 12]).is_err());
+    }
+
+    #[test]
+    fn test_real_discount_factor() {
+        let today = Date::from_ymd(2024, 1, 15).unwrap();
+        let cpi = InflationCurve::new(flat_curve(today, 0.045), flat_curve(today, 0.025));
+        let date = today.add_days(3650);
+        let t: f64 = 3650.0 / 365.0;
+        let real_df = cpi.real_discount_factor(date).unwrap();
+        assert!((real_df - (-0.02 * t).exp()).abs() < 1e-12);
+    }
+}
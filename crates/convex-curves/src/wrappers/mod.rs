@@ -1,11 +1,16 @@
 //! Domain-specific wrappers over [`TermStructure`]: [`RateCurve`] for rate
-//! semantics, [`CreditCurve`] for credit. [`RateCurveDyn`] is the object-safe
+//! semantics, [`CreditCurve`] for credit, [`FxCurve`] for FX forwards and
+//! [`InflationCurve`] for index ratios. [`RateCurveDyn`] is the object-safe
 //! flavour for trait-object dispatch.
 
 mod credit_curve;
+mod fx_curve;
+mod inflation_curve;
 mod rate_curve;
 
 pub use credit_curve::CreditCurve;
+pub use fx_curve::FxCurve;
+pub use inflation_curve::InflationCurve;
 pub use rate_curve::RateCurve;
 
 use convex_core::types::{Compounding, Date};
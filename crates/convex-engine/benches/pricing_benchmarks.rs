@@ -180,6 +180,7 @@ fn create_test_quotes(count: usize) -> Vec<BondQuoteOutput> {
             source: "bench".to_string(),
             is_stale: false,
             quality: 100,
+            override_id: None,
         })
         .collect()
 }
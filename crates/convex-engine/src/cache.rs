@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::time::{Duration, Instant};
 
 use dashmap::DashMap;
@@ -70,3 +72,192 @@ impl Default for QuoteCache {
         Self::new(Duration::from_secs(300)) // 5 minutes
     }
 }
+
+/// Recency order of keys, with O(1) touch, remove and pop.
+///
+/// A doubly-linked list threaded through a slot vector; the map gives each
+/// key's slot. Freed slots are reused.
+pub(crate) struct LruOrder<K> {
+    slots: Vec<LruSlot<K>>,
+    index: HashMap<K, usize>,
+    /// Most recently used
+    head: Option<usize>,
+    /// Least recently used
+    tail: Option<usize>,
+    free: Vec<usize>,
+}
+
+struct LruSlot<K> {
+    key: Option<K>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl<K: Hash + Eq + Clone> LruOrder<K> {
+    /// Create an empty order.
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            index: HashMap::new(),
+            head: None,
+            tail: None,
+            free: Vec::new(),
+        }
+    }
+
+    /// Number of tracked keys.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Mark `key` as most recently used, adding it if new.
+    pub fn touch(&mut self, key: &K) {
+        let slot = match self.index.get(key) {
+            Some(&slot) => {
+                self.unlink(slot);
+                slot
+            }
+            None => {
+                let entry = LruSlot {
+                    key: Some(key.clone()),
+                    prev: None,
+                    next: None,
+                };
+                let slot = match self.free.pop() {
+                    Some(slot) => {
+                        self.slots[slot] = entry;
+                        slot
+                    }
+                    None => {
+                        self.slots.push(entry);
+                        self.slots.len() - 1
+                    }
+                };
+                self.index.insert(key.clone(), slot);
+                slot
+            }
+        };
+        self.push_front(slot);
+    }
+
+    /// Stop tracking `key`.
+    pub fn remove(&mut self, key: &K) {
+        if let Some(slot) = self.index.remove(key) {
+            self.unlink(slot);
+            self.slots[slot].key = None;
+            self.free.push(slot);
+        }
+    }
+
+    /// Remove and return the least recently used key.
+    pub fn pop_lru(&mut self) -> Option<K> {
+        let slot = self.tail?;
+        self.unlink(slot);
+        let key = self.slots[slot].key.take()?;
+        self.index.remove(&key);
+        self.free.push(slot);
+        Some(key)
+    }
+
+    /// Stop tracking every key.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let (prev, next) = (self.slots[slot].prev, self.slots[slot].next);
+        match prev {
+            Some(p) => self.slots[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.slots[n].prev = prev,
+            None => self.tail = prev,
+        }
+        self.slots[slot].prev = None;
+        self.slots[slot].next = None;
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        self.slots[slot].next = self.head;
+        if let Some(h) = self.head {
+            self.slots[h].prev = Some(slot);
+        }
+        self.head = Some(slot);
+        if self.tail.is_none() {
+            self.tail = Some(slot);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> Default for LruOrder<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(order: &mut LruOrder<u32>) -> Vec<u32> {
+        std::iter::from_fn(|| order.pop_lru()).collect()
+    }
+
+    #[test]
+    fn test_lru_order_pops_least_recent_first() {
+        let mut order = LruOrder::new();
+        for key in [1, 2, 3] {
+            order.touch(&key);
+        }
+        order.touch(&1);
+
+        assert_eq!(order.len(), 3);
+        assert_eq!(drain(&mut order), vec![2, 3, 1]);
+        assert_eq!(order.len(), 0);
+        assert_eq!(order.pop_lru(), None);
+    }
+
+    #[test]
+    fn test_lru_order_remove_unlinks_any_position() {
+        let mut order = LruOrder::new();
+        for key in [1, 2, 3, 4] {
+            order.touch(&key);
+        }
+        order.remove(&1); // tail
+        order.remove(&4); // head
+        order.remove(&2); // middle
+        order.remove(&9); // untracked
+
+        assert_eq!(order.len(), 1);
+        assert_eq!(drain(&mut order), vec![3]);
+    }
+
+    #[test]
+    fn test_lru_order_reuses_freed_slots() {
+        let mut order = LruOrder::new();
+        for key in [1, 2, 3] {
+            order.touch(&key);
+        }
+        order.remove(&2);
+        order.pop_lru();
+        order.touch(&4);
+        order.touch(&5);
+
+        assert_eq!(order.slots.len(), 3);
+        assert_eq!(drain(&mut order), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_lru_order_clear() {
+        let mut order = LruOrder::new();
+        order.touch(&1);
+        order.touch(&2);
+        order.clear();
+
+        assert_eq!(order.len(), 0);
+        assert_eq!(order.pop_lru(), None);
+        order.touch(&3);
+        assert_eq!(drain(&mut order), vec![3]);
+    }
+}
@@ -25,20 +25,42 @@
 //!
 //! // Check if this shard owns a node
 //! if graph.owns_node(&node_id) {
-//!     graph.add_node(node_id, deps);
+//!     graph.add_node(node_id, deps)?;
 //! }
 //! ```
+//!
+//! [`CalculationGraph::assign_shard`] routes a node under the configured
+//! strategy, and [`CalculationGraph::get_nodes_to_calculate`] only returns
+//! nodes this shard owns, so each worker process recalculates its partition.
+//!
+//! ## Invalidation hooks
+//!
+//! Callers that push results out (e.g. streaming reprices to clients) can
+//! register an [`InvalidationHook`]. It runs synchronously inside
+//! [`CalculationGraph::invalidate`], so hooks should hand the node IDs off
+//! rather than recalculate in place.
+//!
+//! ## Cycles
+//!
+//! The graph must stay acyclic: invalidation and recalculation walk it to a
+//! fixed point. [`CalculationGraph::add_node`] rejects dependencies that
+//! would close a cycle, and [`CalculationGraph::validate`] checks the whole
+//! graph.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Instant;
 
 use dashmap::{DashMap, DashSet};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::cache::LruOrder;
+use crate::error::EngineError;
 use crate::ports::config::{NodeConfig, UpdateFrequency};
 use convex_core::ids::*;
-use convex_core::Date;
+use convex_core::{Currency, Date};
 
 /// Node identifier in the calculation graph.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -148,6 +170,26 @@ impl std::fmt::Display for NodeId {
     }
 }
 
+impl NodeId {
+    /// Returns true for input nodes, whose cached value is market data rather
+    /// than the result of a calculation.
+    ///
+    /// Input values cannot be recalculated, so they are never evicted from the
+    /// cache.
+    pub fn is_input(&self) -> bool {
+        matches!(
+            self,
+            NodeId::Quote { .. }
+                | NodeId::CurveInput { .. }
+                | NodeId::VolSurface { .. }
+                | NodeId::FxRate { .. }
+                | NodeId::IndexFixing { .. }
+                | NodeId::InflationFixing { .. }
+                | NodeId::Config { .. }
+        )
+    }
+}
+
 /// Node value (result of calculation).
 #[derive(Debug, Clone)]
 pub enum NodeValue {
@@ -213,6 +255,8 @@ pub enum NodeValue {
         modified_duration: Option<Decimal>,
         /// DV01 (from mid price)
         dv01: Option<Decimal>,
+        /// Manual override the price came from, if any
+        override_id: Option<String>,
     },
 
     /// ETF iNAV
@@ -260,14 +304,18 @@ pub enum ShardStrategy {
 }
 
 /// Assignment specification for manual/explicit sharding.
+///
+/// The maps are global: every worker carries the same assignment and only
+/// its own `shard_id` differs, so listed and hashed keys route identically
+/// everywhere and each node has exactly one owner.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ShardAssignment {
-    /// Currencies this shard handles.
-    pub currencies: Option<Vec<String>>,
+    /// Shard for each listed currency (matched case-insensitively).
+    pub currencies: Option<HashMap<String, u32>>,
     /// Issuer types this shard handles.
     pub issuer_types: Option<Vec<String>>,
-    /// Explicit instrument IDs this shard handles.
-    pub instrument_ids: Option<Vec<String>>,
+    /// Shard for each listed instrument ID.
+    pub instrument_ids: Option<HashMap<String, u32>>,
 }
 
 /// Configuration for sharded calculation graph.
@@ -311,7 +359,10 @@ impl ShardConfig {
     }
 
     /// Create a shard config for currency-based sharding.
-    pub fn by_currency(shard_id: u32, total_shards: u32, currencies: Vec<String>) -> Self {
+    ///
+    /// `currencies` maps currency codes to shards and must be the same on
+    /// every worker; unlisted currencies are hashed.
+    pub fn by_currency(shard_id: u32, total_shards: u32, currencies: HashMap<String, u32>) -> Self {
         Self {
             shard_id,
             total_shards,
@@ -345,24 +396,33 @@ impl ShardConfig {
         self.shard_for_key(key) == self.shard_id
     }
 
-    /// Check if this shard owns a given currency (for ByCurrency strategy).
-    pub fn owns_currency(&self, currency: &str) -> bool {
+    /// Compute which shard owns a given currency (for ByCurrency strategy).
+    ///
+    /// Listed currencies go to their mapped shard, others by hash.
+    pub fn shard_for_currency(&self, currency: &str) -> u32 {
         if self.total_shards == 1 {
-            return true;
+            return 0;
         }
-        match &self.assignment {
-            Some(assignment) => assignment
-                .currencies
-                .as_ref()
-                .map(|cs| cs.iter().any(|c| c.eq_ignore_ascii_case(currency)))
-                .unwrap_or(false),
-            None => {
-                // Fall back to hash-based
-                self.owns_key(currency)
-            }
+        let listed = self
+            .assignment
+            .as_ref()
+            .and_then(|a| a.currencies.as_ref())
+            .and_then(|cs| {
+                cs.iter()
+                    .find(|(c, _)| c.eq_ignore_ascii_case(currency))
+                    .map(|(_, shard)| *shard)
+            });
+        match listed {
+            Some(shard) => shard % self.total_shards,
+            None => self.shard_for_key(&currency.to_ascii_uppercase()),
         }
     }
 
+    /// Check if this shard owns a given currency (for ByCurrency strategy).
+    pub fn owns_currency(&self, currency: &str) -> bool {
+        self.shard_for_currency(currency) == self.shard_id
+    }
+
     /// Simple string hash function (DJB2 variant).
     fn hash_string(s: &str) -> u32 {
         let mut hash: u32 = 5381;
@@ -384,6 +444,10 @@ pub struct CachedValue {
     pub calculated_at: Instant,
 }
 
+/// Callback run after an invalidation with every node it affects: the
+/// invalidated node first, then its transitive dependents.
+pub type InvalidationHook = Arc<dyn Fn(&[NodeId]) + Send + Sync>;
+
 /// The calculation graph manages dependencies and memoization.
 ///
 /// Supports sharding for large universes (>10K bonds) where the graph
@@ -395,13 +459,17 @@ pub struct CalculationGraph {
     /// Dependents: node -> nodes that depend on it
     dependents: DashMap<NodeId, Vec<NodeId>>,
 
+    /// Held while edges change, so a cycle check and the insert it guards
+    /// see the same graph
+    topology: parking_lot::Mutex<()>,
+
     /// Node configurations
     configs: DashMap<NodeId, NodeConfig>,
 
     /// Memoized values with revision tracking
     cache: DashMap<NodeId, CachedValue>,
 
-    /// Dirty nodes pending recalculation
+    /// Dirty nodes pending recalculation (only nodes this shard owns)
     dirty: DashSet<NodeId>,
 
     /// Last calculation time per node (for throttling)
@@ -415,6 +483,21 @@ pub struct CalculationGraph {
 
     /// Shard configuration for distributed deployments
     shard_config: ShardConfig,
+
+    /// Max cached derived values (None = unbounded)
+    cache_capacity: Option<usize>,
+
+    /// Recency order of cached derived values (for LRU eviction)
+    cache_order: parking_lot::Mutex<LruOrder<NodeId>>,
+
+    /// Derived values evicted since they were last read
+    evicted: DashSet<NodeId>,
+
+    /// Callbacks run on every invalidation
+    invalidation_hooks: parking_lot::RwLock<Vec<InvalidationHook>>,
+
+    /// Currency per node (for currency-based sharding)
+    node_currencies: DashMap<NodeId, Currency>,
 }
 
 impl CalculationGraph {
@@ -428,6 +511,7 @@ impl CalculationGraph {
         Self {
             dependencies: DashMap::new(),
             dependents: DashMap::new(),
+            topology: parking_lot::Mutex::new(()),
             configs: DashMap::new(),
             cache: DashMap::new(),
             dirty: DashSet::new(),
@@ -435,9 +519,35 @@ impl CalculationGraph {
             throttle_pending: DashSet::new(),
             current_revision: AtomicU64::new(0),
             shard_config,
+            cache_capacity: None,
+            cache_order: parking_lot::Mutex::new(LruOrder::new()),
+            evicted: DashSet::new(),
+            invalidation_hooks: parking_lot::RwLock::new(Vec::new()),
+            node_currencies: DashMap::new(),
         }
     }
 
+    /// Bound the number of cached derived values.
+    ///
+    /// When the bound is exceeded the least-recently-used derived values are
+    /// evicted. An evicted node is not recalculated until it is next read:
+    /// the read misses and marks it dirty. Input values (quotes, fixings,
+    /// ...) are never evicted.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = Some(capacity);
+        self
+    }
+
+    /// Get the cache capacity (None = unbounded).
+    pub fn cache_capacity(&self) -> Option<usize> {
+        self.cache_capacity
+    }
+
+    /// Get the number of cached values.
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
     /// Get the shard configuration.
     pub fn shard_config(&self) -> &ShardConfig {
         &self.shard_config
@@ -467,9 +577,71 @@ impl CalculationGraph {
             return true;
         }
 
-        // Extract the key for sharding
-        let key = self.node_shard_key(node_id);
-        self.shard_config.owns_key(&key)
+        self.assign_shard(node_id) == self.shard_config.shard_id
+    }
+
+    /// Shard that processes a node under the configured strategy.
+    ///
+    /// - `HashBased` (and `ByIssuerType`, which has no issuer data on the
+    ///   node): hash of the node's identifier.
+    /// - `ByCurrency`: the node's currency (see
+    ///   [`set_node_currency`](Self::set_node_currency)); nodes without one
+    ///   fall back to the identifier hash.
+    /// - `Manual`: identifiers listed in the assignment; others fall back to
+    ///   the identifier hash.
+    ///
+    /// The assignment maps and the hash are the same on every worker, so a
+    /// node routes to the same shard everywhere and has exactly one owner.
+    /// Mapped shards outside `0..total_shards` wrap around.
+    pub fn assign_shard(&self, node_id: &NodeId) -> u32 {
+        let config = &self.shard_config;
+        if config.is_single_shard() {
+            return 0;
+        }
+
+        match config.strategy {
+            ShardStrategy::ByCurrency => match self.node_currency(node_id) {
+                Some(currency) => config.shard_for_currency(currency.code()),
+                None => config.shard_for_key(&self.node_shard_key(node_id)),
+            },
+            ShardStrategy::Manual => {
+                let key = self.node_shard_key(node_id);
+                let listed = config
+                    .assignment
+                    .as_ref()
+                    .and_then(|a| a.instrument_ids.as_ref())
+                    .and_then(|ids| ids.get(&key).copied());
+                match listed {
+                    Some(shard) => shard % config.total_shards,
+                    None => config.shard_for_key(&key),
+                }
+            }
+            ShardStrategy::HashBased | ShardStrategy::ByIssuerType => {
+                config.shard_for_key(&self.node_shard_key(node_id))
+            }
+        }
+    }
+
+    /// Set the currency a node is routed by under currency-based sharding.
+    ///
+    /// Curves and curve inputs whose ID starts with a currency code (e.g.
+    /// `USD.SOFR`) and FX rates (by base currency) need not be set.
+    pub fn set_node_currency(&self, node_id: NodeId, currency: Currency) {
+        self.node_currencies.insert(node_id, currency);
+    }
+
+    /// The currency a node is routed by, if known.
+    fn node_currency(&self, node_id: &NodeId) -> Option<Currency> {
+        if let Some(currency) = self.node_currencies.get(node_id) {
+            return Some(*currency);
+        }
+        match node_id {
+            NodeId::Curve { curve_id } | NodeId::CurveInput { curve_id, .. } => {
+                curve_id.as_str().get(..3).and_then(Currency::from_code)
+            }
+            NodeId::FxRate { pair } => Some(pair.base),
+            _ => None,
+        }
     }
 
     /// Get the shard key for a node.
@@ -492,27 +664,135 @@ impl CalculationGraph {
         }
     }
 
-    /// Compute which shard would own a given node.
+    /// Compute which shard would own a given node (see
+    /// [`assign_shard`](Self::assign_shard)).
     pub fn shard_for_node(&self, node_id: &NodeId) -> u32 {
-        let key = self.node_shard_key(node_id);
-        self.shard_config.shard_for_key(&key)
+        self.assign_shard(node_id)
     }
 
     /// Add a node with its dependencies.
-    pub fn add_node(&self, node_id: NodeId, deps: Vec<NodeId>) {
+    ///
+    /// Adding a node that already exists replaces its dependency set: edges to
+    /// dependencies no longer listed are dropped and no duplicate edges are
+    /// created. Returns `true` if the node was newly added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError::DependencyCycle`] naming the cycle if a
+    /// dependency already depends, directly or transitively, on `node_id`.
+    /// The graph is left unchanged.
+    pub fn add_node(&self, node_id: NodeId, mut deps: Vec<NodeId>) -> Result<bool, EngineError> {
+        let mut seen = std::collections::HashSet::new();
+        deps.retain(|d| seen.insert(d.clone()));
+
+        let _topology = self.topology.lock();
+        for dep in &deps {
+            if let Some(path) = self.dependency_path(dep, &node_id) {
+                let mut cycle = vec![node_id.clone()];
+                cycle.extend(path);
+                return Err(cycle_error(&cycle));
+            }
+        }
+
         // Store dependencies
-        self.dependencies.insert(node_id.clone(), deps.clone());
+        let previous = self.dependencies.insert(node_id.clone(), deps.clone());
+
+        // Drop reverse edges for dependencies that were removed
+        if let Some(old_deps) = &previous {
+            for dep in old_deps.iter().filter(|d| !deps.contains(d)) {
+                if let Some(mut dependents) = self.dependents.get_mut(dep) {
+                    dependents.retain(|n| n != &node_id);
+                }
+            }
+        }
 
         // Update reverse dependencies
         for dep in &deps {
-            self.dependents
-                .entry(dep.clone())
-                .or_default()
-                .push(node_id.clone());
+            let mut dependents = self.dependents.entry(dep.clone()).or_default();
+            if !dependents.contains(&node_id) {
+                dependents.push(node_id.clone());
+            }
         }
 
         // Mark as dirty initially
-        self.dirty.insert(node_id);
+        self.mark_dirty(&node_id);
+
+        Ok(previous.is_none())
+    }
+
+    /// Dependency chain from `from` to `to` (both included), if `from`
+    /// depends on `to` directly or transitively.
+    fn dependency_path(&self, from: &NodeId, to: &NodeId) -> Option<Vec<NodeId>> {
+        let mut parents: std::collections::HashMap<NodeId, NodeId> =
+            std::collections::HashMap::new();
+        let mut queue = std::collections::VecDeque::from([from.clone()]);
+        let mut seen: HashSet<NodeId> = HashSet::from([from.clone()]);
+        while let Some(node) = queue.pop_front() {
+            if &node == to {
+                let mut path = vec![node];
+                while let Some(parent) = parents.get(path.last().unwrap()) {
+                    path.push(parent.clone());
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if let Some(deps) = self.dependencies.get(&node) {
+                for dep in deps.iter() {
+                    if seen.insert(dep.clone()) {
+                        parents.insert(dep.clone(), node.clone());
+                        queue.push_back(dep.clone());
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Check that the graph has no dependency cycles.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError::DependencyCycle`] naming one cycle if any exist.
+    pub fn validate(&self) -> Result<(), EngineError> {
+        let nodes: Vec<NodeId> = self.dependencies.iter().map(|e| e.key().clone()).collect();
+        let mut done: HashSet<NodeId> = HashSet::new();
+        for start in nodes {
+            if done.contains(&start) {
+                continue;
+            }
+            // Iterative DFS; `path` holds the nodes on the current branch and
+            // the index of the next dependency to visit from each.
+            let mut path: Vec<(NodeId, usize)> = vec![(start, 0)];
+            let mut on_path: HashSet<NodeId> = path.iter().map(|(n, _)| n.clone()).collect();
+            while let Some((node, next)) = path.last_mut() {
+                let dep = self
+                    .dependencies
+                    .get(node)
+                    .and_then(|deps| deps.get(*next).cloned());
+                *next += 1;
+                match dep {
+                    Some(dep) if on_path.contains(&dep) => {
+                        let from = path.iter().position(|(n, _)| n == &dep).unwrap_or(0);
+                        let mut cycle: Vec<NodeId> =
+                            path[from..].iter().map(|(n, _)| n.clone()).collect();
+                        cycle.push(dep);
+                        return Err(cycle_error(&cycle));
+                    }
+                    Some(dep) => {
+                        if !done.contains(&dep) {
+                            on_path.insert(dep.clone());
+                            path.push((dep, 0));
+                        }
+                    }
+                    None => {
+                        let (node, _) = path.pop().expect("path is non-empty");
+                        on_path.remove(&node);
+                        done.insert(node);
+                    }
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Set node configuration.
@@ -526,15 +806,64 @@ impl CalculationGraph {
     }
 
     /// Mark a node as dirty (input changed).
+    ///
+    /// The node and its transitive dependents are marked, except those owned
+    /// by other shards: their own workers recalculate them.
     pub fn invalidate(&self, node_id: &NodeId) {
         self.bump_revision();
-        self.dirty.insert(node_id.clone());
 
-        // Propagate to dependents
-        self.propagate_dirty(node_id);
+        // Snapshot the hooks so none run, and no traversal happens, while
+        // the hook list is locked.
+        let hooks: Vec<InvalidationHook> = self.invalidation_hooks.read().clone();
+        if hooks.is_empty() && !self.is_sharded() {
+            self.dirty.insert(node_id.clone());
+            self.propagate_dirty(node_id);
+            return;
+        }
+
+        // Hooks need every affected node, including ones already dirty, and
+        // other shards' nodes must be walked through without being marked,
+        // so collect the whole walk.
+        let affected = self.affected_nodes(node_id);
+        for node in &affected {
+            self.mark_dirty(node);
+        }
+        for hook in &hooks {
+            hook(&affected);
+        }
+    }
+
+    /// Register a callback to run on every invalidation.
+    pub fn add_invalidation_hook(&self, hook: InvalidationHook) {
+        self.invalidation_hooks.write().push(hook);
+    }
+
+    /// The node and all its transitive dependents, each once.
+    fn affected_nodes(&self, node_id: &NodeId) -> Vec<NodeId> {
+        let mut affected = vec![node_id.clone()];
+        let mut seen: HashSet<NodeId> = affected.iter().cloned().collect();
+        let mut next = 0;
+        while next < affected.len() {
+            if let Some(deps) = self.dependents.get(&affected[next]) {
+                for dependent in deps.iter() {
+                    if seen.insert(dependent.clone()) {
+                        affected.push(dependent.clone());
+                    }
+                }
+            }
+            next += 1;
+        }
+        affected
     }
 
-    /// Propagate dirty flag to all dependents.
+    /// Mark a node dirty if this shard owns it.
+    fn mark_dirty(&self, node_id: &NodeId) {
+        if self.owns_node(node_id) {
+            self.dirty.insert(node_id.clone());
+        }
+    }
+
+    /// Propagate dirty flag to all dependents (single shard only).
     fn propagate_dirty(&self, node_id: &NodeId) {
         if let Some(deps) = self.dependents.get(node_id) {
             for dependent in deps.iter() {
@@ -557,8 +886,21 @@ impl CalculationGraph {
     }
 
     /// Get cached value for a node.
+    ///
+    /// A miss on an evicted value marks the node dirty so it is recalculated.
     pub fn get_cached(&self, node_id: &NodeId) -> Option<CachedValue> {
-        self.cache.get(node_id).map(|c| c.clone())
+        let cached = self.cache.get(node_id).map(|c| c.clone());
+        if cached.is_some() {
+            self.touch(node_id);
+        } else if self.evicted.remove(node_id).is_some() {
+            self.mark_dirty(node_id);
+        }
+        cached
+    }
+
+    /// Get the nodes that currently have a cached value.
+    pub fn cached_nodes(&self) -> Vec<NodeId> {
+        self.cache.iter().map(|e| e.key().clone()).collect()
     }
 
     /// Update cache for a node.
@@ -569,11 +911,40 @@ impl CalculationGraph {
             calculated_at: Instant::now(),
         };
         self.cache.insert(node_id.clone(), cached);
+        self.evicted.remove(node_id);
+        self.touch(node_id);
         self.dirty.remove(node_id);
         self.last_calc_time.insert(node_id.clone(), Instant::now());
+
+        self.evict_lru();
+    }
+
+    /// Record a cache access for LRU ordering.
+    fn touch(&self, node_id: &NodeId) {
+        if self.cache_capacity.is_some() && !node_id.is_input() {
+            self.cache_order.lock().touch(node_id);
+        }
+    }
+
+    /// Evict least-recently-used derived values beyond the cache capacity.
+    fn evict_lru(&self) {
+        let Some(capacity) = self.cache_capacity else {
+            return;
+        };
+
+        let mut order = self.cache_order.lock();
+        while order.len() > capacity {
+            let Some(node_id) = order.pop_lru() else {
+                break;
+            };
+            self.cache.remove(&node_id);
+            self.last_calc_time.remove(&node_id);
+            self.evicted.insert(node_id);
+        }
     }
 
-    /// Get nodes that should be recalculated now (respecting frequency).
+    /// Get nodes that should be recalculated now (respecting frequency),
+    /// limited to the nodes this shard owns.
     pub fn get_nodes_to_calculate(&self) -> Vec<NodeId> {
         let now = Instant::now();
         let mut to_calculate = Vec::new();
@@ -612,6 +983,46 @@ impl CalculationGraph {
         to_calculate
     }
 
+    /// Order `dirty` so every node comes after the nodes it depends on.
+    ///
+    /// Dependencies are followed transitively, including through nodes not in
+    /// `dirty`, so a curve is ordered before a bond priced off it and the bond
+    /// before its portfolio. Nodes with no ordering constraint between them
+    /// keep their relative order in `dirty` (e.g. priority order from
+    /// [`get_nodes_to_calculate`](Self::get_nodes_to_calculate)).
+    pub fn topological_order(&self, dirty: &[NodeId]) -> Vec<NodeId> {
+        let wanted: HashSet<&NodeId> = dirty.iter().collect();
+        let mut visited: HashSet<NodeId> = HashSet::new();
+        let mut order = Vec::with_capacity(dirty.len());
+
+        for root in dirty {
+            if !visited.insert(root.clone()) {
+                continue;
+            }
+            // Iterative post-order DFS over dependencies
+            let mut stack: Vec<(NodeId, Vec<NodeId>)> =
+                vec![(root.clone(), self.get_dependencies(root))];
+            while let Some((_, pending)) = stack.last_mut() {
+                match pending.pop() {
+                    Some(dep) => {
+                        if visited.insert(dep.clone()) {
+                            let deps = self.get_dependencies(&dep);
+                            stack.push((dep, deps));
+                        }
+                    }
+                    None => {
+                        let (node, _) = stack.pop().expect("stack is non-empty");
+                        if wanted.contains(&node) {
+                            order.push(node);
+                        }
+                    }
+                }
+            }
+        }
+
+        order
+    }
+
     /// Get dependencies of a node.
     pub fn get_dependencies(&self, node_id: &NodeId) -> Vec<NodeId> {
         self.dependencies
@@ -640,16 +1051,27 @@ impl CalculationGraph {
 
     /// Clear all nodes and cache.
     pub fn clear(&self) {
+        let topology = self.topology.lock();
         self.dependencies.clear();
         self.dependents.clear();
+        drop(topology);
         self.configs.clear();
         self.cache.clear();
         self.dirty.clear();
         self.last_calc_time.clear();
         self.throttle_pending.clear();
+        self.cache_order.lock().clear();
+        self.evicted.clear();
+        self.node_currencies.clear();
     }
 }
 
+/// Error describing `cycle` as a chain of "depends on" edges.
+fn cycle_error(cycle: &[NodeId]) -> EngineError {
+    let chain: Vec<String> = cycle.iter().map(ToString::to_string).collect();
+    EngineError::DependencyCycle(chain.join(" -> "))
+}
+
 impl Default for CalculationGraph {
     fn default() -> Self {
         Self::new()
@@ -675,8 +1097,10 @@ mod tests {
         };
 
         // Add nodes with dependencies
-        graph.add_node(curve_node.clone(), vec![]);
-        graph.add_node(bond_node.clone(), vec![curve_node.clone()]);
+        graph.add_node(curve_node.clone(), vec![]).unwrap();
+        graph
+            .add_node(bond_node.clone(), vec![curve_node.clone()])
+            .unwrap();
 
         // Initially dirty
         assert!(graph.is_dirty(&curve_node));
@@ -694,6 +1118,233 @@ mod tests {
         assert!(graph.is_dirty(&bond_node));
     }
 
+    #[test]
+    fn test_invalidation_hook_sees_dependents() {
+        use std::sync::Mutex;
+
+        let graph = CalculationGraph::new();
+        let quote = NodeId::Quote {
+            instrument_id: InstrumentId::new("US912810TD00"),
+        };
+        let bond = NodeId::BondPrice {
+            instrument_id: InstrumentId::new("US912810TD00"),
+        };
+        graph.add_node(bond.clone(), vec![quote.clone()]).unwrap();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        graph.add_invalidation_hook(Arc::new(move |nodes: &[NodeId]| {
+            sink.lock().unwrap().push(nodes.to_vec());
+        }));
+
+        // Reported even though the bond is still dirty from registration.
+        graph.invalidate(&quote);
+        graph.invalidate(&quote);
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0], vec![quote.clone(), bond.clone()]);
+        assert_eq!(seen[1], seen[0]);
+    }
+
+    #[test]
+    fn test_add_node_is_idempotent() {
+        let graph = CalculationGraph::new();
+
+        let discount = NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR"),
+        };
+        let benchmark = NodeId::Curve {
+            curve_id: CurveId::new("USD.GOVT"),
+        };
+        let bond = NodeId::BondPrice {
+            instrument_id: InstrumentId::new("US912810TD00"),
+        };
+
+        assert!(graph
+            .add_node(bond.clone(), vec![discount.clone(), benchmark.clone()])
+            .unwrap());
+
+        // Re-adding drops the benchmark and lists the discount curve twice
+        assert!(!graph
+            .add_node(bond.clone(), vec![discount.clone(), discount.clone()])
+            .unwrap());
+
+        assert_eq!(graph.get_dependencies(&bond), vec![discount.clone()]);
+        assert_eq!(graph.get_dependents(&discount), vec![bond.clone()]);
+        assert!(graph.get_dependents(&benchmark).is_empty());
+    }
+
+    #[test]
+    fn test_add_node_rejects_cycle() {
+        let graph = CalculationGraph::new();
+
+        let a = NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR"),
+        };
+        let b = NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR.SPREAD"),
+        };
+
+        graph.add_node(a.clone(), vec![b.clone()]).unwrap();
+        let err = graph.add_node(b.clone(), vec![a.clone()]).unwrap_err();
+
+        assert!(matches!(err, EngineError::DependencyCycle(_)));
+        assert_eq!(
+            err.to_string(),
+            "dependency cycle: Curve(USD.SOFR.SPREAD) -> Curve(USD.SOFR) -> Curve(USD.SOFR.SPREAD)"
+        );
+        // The rejected edge was not added
+        assert!(graph.get_dependencies(&b).is_empty());
+        assert!(graph.get_dependents(&a).is_empty());
+        assert!(graph.validate().is_ok());
+
+        // Self-dependencies and longer cycles are caught too
+        assert!(graph.add_node(a.clone(), vec![a.clone()]).is_err());
+        let portfolio = NodeId::Portfolio {
+            portfolio_id: PortfolioId::new("CORE"),
+        };
+        graph.add_node(portfolio.clone(), vec![a.clone()]).unwrap();
+        let err = graph.add_node(b.clone(), vec![portfolio]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "dependency cycle: Curve(USD.SOFR.SPREAD) -> Portfolio(CORE) -> Curve(USD.SOFR) -> Curve(USD.SOFR.SPREAD)"
+        );
+    }
+
+    #[test]
+    fn test_validate_finds_cycle() {
+        let graph = CalculationGraph::new();
+        let a = NodeId::Curve {
+            curve_id: CurveId::new("A"),
+        };
+        let b = NodeId::Curve {
+            curve_id: CurveId::new("B"),
+        };
+        graph.add_node(a.clone(), vec![b.clone()]).unwrap();
+        assert!(graph.validate().is_ok());
+
+        // Bypass add_node's check to corrupt the graph
+        graph.dependencies.insert(b.clone(), vec![a.clone()]);
+        let err = graph.validate().unwrap_err();
+        assert!(matches!(err, EngineError::DependencyCycle(_)));
+    }
+
+    #[test]
+    fn test_concurrent_add_node_never_closes_cycle() {
+        let curve = |i: usize| NodeId::Curve {
+            curve_id: CurveId::new(format!("C{i}")),
+        };
+        // C0 -> C1 -> ... -> C{n}, long enough that checking a new edge
+        // out of the far end overlaps the opposing insert.
+        let n = 2_000;
+        for _ in 0..20 {
+            let graph = Arc::new(CalculationGraph::new());
+            for i in 0..n {
+                graph.add_node(curve(i), vec![curve(i + 1)]).unwrap();
+            }
+            let start = Arc::new(std::sync::Barrier::new(2));
+            let (head, tail, extra) = (curve(0), curve(n), curve(n + 1));
+            let handles: Vec<_> = [(extra.clone(), head), (tail, extra)]
+                .into_iter()
+                .map(|(node, dep)| {
+                    let graph = Arc::clone(&graph);
+                    let start = Arc::clone(&start);
+                    std::thread::spawn(move || {
+                        start.wait();
+                        graph.add_node(node, vec![dep]).is_ok()
+                    })
+                })
+                .collect();
+            let added = handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .filter(|ok| *ok)
+                .count();
+
+            // The two edges close a cycle, so exactly one goes in
+            assert_eq!(added, 1);
+            assert!(graph.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_topological_order_puts_curve_before_bond() {
+        let graph = CalculationGraph::new();
+
+        let curve = NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR"),
+        };
+        let bond = NodeId::BondPrice {
+            instrument_id: InstrumentId::new("US912810TD00"),
+        };
+        let portfolio = NodeId::Portfolio {
+            portfolio_id: PortfolioId::new("CORE"),
+        };
+        graph.add_node(curve.clone(), vec![]).unwrap();
+        graph.add_node(bond.clone(), vec![curve.clone()]).unwrap();
+        graph
+            .add_node(portfolio.clone(), vec![bond.clone()])
+            .unwrap();
+
+        // Priority order would put the bond ahead of its curve
+        graph.set_node_config(
+            bond.clone(),
+            NodeConfig {
+                priority: 10,
+                ..NodeConfig::default()
+            },
+        );
+        let dirty = graph.get_nodes_to_calculate();
+        assert_eq!(dirty[0], bond);
+
+        let order = graph.topological_order(&dirty);
+        assert_eq!(order, vec![curve.clone(), bond.clone(), portfolio.clone()]);
+
+        // Ordering holds through nodes outside the dirty set
+        assert_eq!(
+            graph.topological_order(&[portfolio.clone(), curve.clone()]),
+            vec![curve, portfolio]
+        );
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let graph = CalculationGraph::new().with_cache_capacity(2);
+        let bond = |id: &str| NodeId::BondPrice {
+            instrument_id: InstrumentId::new(id),
+        };
+        let quote = NodeId::Quote {
+            instrument_id: InstrumentId::new("A"),
+        };
+
+        graph.update_cache(&quote, NodeValue::Empty);
+        graph.update_cache(&bond("A"), NodeValue::Empty);
+        graph.update_cache(&bond("B"), NodeValue::Empty);
+
+        // Touch A so B becomes the least recently used
+        assert!(graph.get_cached(&bond("A")).is_some());
+        graph.update_cache(&bond("C"), NodeValue::Empty);
+
+        // Eviction leaves B clean; the missed read schedules it
+        assert!(!graph.is_dirty(&bond("B")));
+        assert!(graph.get_cached(&bond("B")).is_none());
+        assert!(
+            graph.is_dirty(&bond("B")),
+            "evicted node must be recalculated once read"
+        );
+        assert!(graph.get_cached(&bond("A")).is_some());
+        assert!(graph.get_cached(&bond("C")).is_some());
+
+        // Input values are never evicted
+        assert!(graph.get_cached(&quote).is_some());
+
+        for i in 0..50 {
+            graph.update_cache(&bond(&format!("X{i}")), NodeValue::Empty);
+        }
+        assert_eq!(graph.cache_len(), 3); // capacity + the quote
+    }
+
     // ============ Sharding Tests ============
 
     #[test]
@@ -782,13 +1433,175 @@ mod tests {
         assert_eq!(graph.owns_node(&node), shard == 0);
     }
 
+    #[test]
+    fn test_assign_shard_partitions_nodes() {
+        let shards: Vec<_> = (0..2)
+            .map(|i| CalculationGraph::with_sharding(ShardConfig::hash_based(i, 2)))
+            .collect();
+
+        let mut nodes = Vec::new();
+        for i in 0..50 {
+            let instrument_id = InstrumentId::new(format!("BOND_{i}"));
+            nodes.push(NodeId::Quote {
+                instrument_id: instrument_id.clone(),
+            });
+            nodes.push(NodeId::BondPrice { instrument_id });
+        }
+        nodes.push(NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR"),
+        });
+        nodes.push(NodeId::Portfolio {
+            portfolio_id: PortfolioId::new("CORE"),
+        });
+
+        let mut per_shard = [0; 2];
+        for node in &nodes {
+            let owners: Vec<u32> = shards
+                .iter()
+                .filter(|g| g.owns_node(node))
+                .map(|g| g.shard_id())
+                .collect();
+            assert_eq!(owners.len(), 1, "exactly one shard should own {node}");
+
+            // Stable: every worker, on every call, routes the node the same way
+            let shard = shards[0].assign_shard(node);
+            assert_eq!(shards[1].assign_shard(node), shard);
+            assert_eq!(shards[0].assign_shard(node), shard);
+            assert_eq!(owners[0], shard);
+            per_shard[shard as usize] += 1;
+        }
+        assert!(per_shard.iter().all(|n| *n > 0));
+
+        // Each worker only picks up its own dirty nodes
+        for graph in &shards {
+            for node in &nodes {
+                graph.add_node(node.clone(), vec![]).unwrap();
+            }
+            let to_calculate = graph.get_nodes_to_calculate();
+            assert_eq!(to_calculate.len(), per_shard[graph.shard_id() as usize]);
+            assert!(to_calculate.iter().all(|n| graph.owns_node(n)));
+        }
+    }
+
+    #[test]
+    fn test_sharded_graph_only_marks_owned_nodes_dirty() {
+        let graph = CalculationGraph::with_sharding(ShardConfig::hash_based(0, 2));
+        let curve = NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR"),
+        };
+        let bonds: Vec<NodeId> = (0..20)
+            .map(|i| NodeId::BondPrice {
+                instrument_id: InstrumentId::new(format!("BOND_{i}")),
+            })
+            .collect();
+        graph.add_node(curve.clone(), vec![]).unwrap();
+        for bond in &bonds {
+            graph.add_node(bond.clone(), vec![curve.clone()]).unwrap();
+        }
+        let owned: HashSet<NodeId> = bonds
+            .iter()
+            .filter(|n| graph.owns_node(n))
+            .cloned()
+            .collect();
+        assert!(!owned.is_empty() && owned.len() < bonds.len());
+
+        for node in graph.get_nodes_to_calculate() {
+            graph.update_cache(&node, NodeValue::Empty);
+        }
+        assert!(graph.get_dirty_nodes().is_empty());
+
+        // A curve move reaches this shard's bonds, wherever the curve lives,
+        // and leaves nothing behind once they are recalculated.
+        graph.invalidate(&curve);
+        let dirty: HashSet<NodeId> = graph
+            .get_dirty_nodes()
+            .into_iter()
+            .filter(|n| n != &curve)
+            .collect();
+        assert_eq!(dirty, owned);
+        for node in graph.get_nodes_to_calculate() {
+            graph.update_cache(&node, NodeValue::Empty);
+        }
+        assert!(graph.get_dirty_nodes().is_empty());
+    }
+
+    fn currency_map(pairs: &[(&str, u32)]) -> HashMap<String, u32> {
+        pairs.iter().map(|(c, s)| (c.to_string(), *s)).collect()
+    }
+
+    #[test]
+    fn test_assign_shard_by_currency() {
+        // USD and EUR both hash to shard 1; the global map must still win on
+        // every worker so each node has exactly one owner.
+        let hash = ShardConfig::hash_based(0, 2);
+        assert_eq!(hash.shard_for_key("USD"), 1);
+        assert_eq!(hash.shard_for_key("EUR"), 1);
+
+        let map = currency_map(&[("USD", 0), ("EUR", 1)]);
+        let workers: Vec<CalculationGraph> = (0..2)
+            .map(|i| CalculationGraph::with_sharding(ShardConfig::by_currency(i, 2, map.clone())))
+            .collect();
+
+        let sofr = NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR"),
+        };
+        let estr = NodeId::Curve {
+            curve_id: CurveId::new("EUR_ESTR"),
+        };
+        let treasury = NodeId::BondPrice {
+            instrument_id: InstrumentId::new("US912810TD00"),
+        };
+        let bund = NodeId::BondPrice {
+            instrument_id: InstrumentId::new("DE0001102580"),
+        };
+        for graph in &workers {
+            graph.set_node_currency(treasury.clone(), Currency::USD);
+            graph.set_node_currency(bund.clone(), Currency::EUR);
+        }
+
+        for (node, expected) in [(&sofr, 0), (&treasury, 0), (&estr, 1), (&bund, 1)] {
+            let owners: Vec<u32> = workers
+                .iter()
+                .filter(|g| g.owns_node(node))
+                .map(|g| g.shard_id())
+                .collect();
+            assert_eq!(owners, vec![expected], "{node} should have one owner");
+            for graph in &workers {
+                assert_eq!(graph.assign_shard(node), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unlisted_currency_has_one_owner() {
+        let map = currency_map(&[("USD", 0), ("EUR", 1), ("GBP", 2)]);
+        let workers: Vec<CalculationGraph> = (0..3)
+            .map(|i| CalculationGraph::with_sharding(ShardConfig::by_currency(i, 3, map.clone())))
+            .collect();
+
+        let jgb = NodeId::BondPrice {
+            instrument_id: InstrumentId::new("JP1103551J81"),
+        };
+        for graph in &workers {
+            graph.set_node_currency(jgb.clone(), Currency::JPY);
+        }
+
+        let expected = ShardConfig::hash_based(0, 3).shard_for_key("JPY");
+        for graph in &workers {
+            assert_eq!(graph.assign_shard(&jgb), expected);
+        }
+        let owners = workers.iter().filter(|g| g.owns_node(&jgb)).count();
+        assert_eq!(owners, 1);
+    }
+
     #[test]
     fn test_currency_based_sharding() {
-        let usd_config = ShardConfig::by_currency(0, 2, vec!["USD".to_string(), "CAD".to_string()]);
-        let eur_config = ShardConfig::by_currency(1, 2, vec!["EUR".to_string(), "GBP".to_string()]);
+        let map = currency_map(&[("USD", 0), ("CAD", 0), ("EUR", 1), ("GBP", 1)]);
+        let usd_config = ShardConfig::by_currency(0, 2, map.clone());
+        let eur_config = ShardConfig::by_currency(1, 2, map);
 
         assert!(usd_config.owns_currency("USD"));
-        assert!(usd_config.owns_currency("CAD"));
+        assert!(usd_config.owns_currency("cad"));
         assert!(!usd_config.owns_currency("EUR"));
 
         assert!(eur_config.owns_currency("EUR"));
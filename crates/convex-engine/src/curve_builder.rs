@@ -6,12 +6,13 @@ use dashmap::DashMap;
 use tracing::{info, warn};
 
 use crate::ports::market_data::MarketDataProvider;
+use crate::ports::storage::CurveStore;
 use convex_core::daycounts::DayCountConvention;
 use convex_core::ids::CurveId;
 use convex_core::Date;
 use convex_curves::{
     Compounding, CurveError, CurveResult, DiscreteCurve, ExtrapolationMethod, InterpolationMethod,
-    RateCurveDyn, ValueType,
+    RateCurveDyn, ValueConverter, ValueType,
 };
 
 use crate::calc_graph::{CalculationGraph, NodeId};
@@ -190,26 +191,11 @@ impl RateCurveDyn for BuiltCurve {
                 // r_simple = (exp(r_cc * t) - 1) / t
                 Ok((cc_rate * t).exp_m1() / t)
             }
-            Compounding::Annual => {
-                // r_annual = exp(r_cc) - 1
-                Ok(cc_rate.exp() - 1.0)
-            }
-            Compounding::SemiAnnual => {
-                // r_semi = 2 * (exp(r_cc / 2) - 1)
-                Ok(2.0 * ((cc_rate / 2.0).exp() - 1.0))
-            }
-            Compounding::Quarterly => {
-                // r_quarterly = 4 * (exp(r_cc / 4) - 1)
-                Ok(4.0 * ((cc_rate / 4.0).exp() - 1.0))
-            }
-            Compounding::Monthly => {
-                // r_monthly = 12 * (exp(r_cc / 12) - 1)
-                Ok(12.0 * ((cc_rate / 12.0).exp() - 1.0))
-            }
-            Compounding::Daily => {
-                // r_daily = 365 * (exp(r_cc / 365) - 1)
-                Ok(365.0 * ((cc_rate / 365.0).exp() - 1.0))
-            }
+            periodic => Ok(ValueConverter::convert_compounding(
+                cc_rate,
+                Compounding::Continuous,
+                periodic,
+            )),
         }
     }
 
@@ -420,6 +406,47 @@ impl CurveBuilder {
         Ok(built)
     }
 
+    /// Reconstruct a curve as of a historical date from stored snapshots.
+    ///
+    /// Takes the store's curve at midnight UTC on `date` (see
+    /// [`CurveStore::curve_at`]): snapshots bracketing it are linearly
+    /// interpolated in discount factors, and dates outside the stored range
+    /// take the nearest snapshot. The result is not cached, so live curves
+    /// are left untouched.
+    pub async fn curve_as_of(
+        &self,
+        store: &dyn CurveStore,
+        curve_id: &CurveId,
+        date: Date,
+    ) -> Result<BuiltCurve, EngineError> {
+        let as_of = date
+            .as_naive_date()
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc()
+            .timestamp_millis();
+
+        let snapshot = store.curve_at(curve_id, as_of).await?.ok_or_else(|| {
+            EngineError::CurveBuildError(format!("No snapshots of curve {}", curve_id))
+        })?;
+
+        let mut built = BuiltCurve {
+            curve_id: curve_id.clone(),
+            reference_date: date,
+            points: snapshot
+                .points
+                .iter()
+                .map(|(tenor_days, rate)| (*tenor_days as f64 / 365.0, *rate))
+                .collect(),
+            built_at: snapshot.as_of,
+            inputs_hash: snapshot.inputs_hash,
+            extrapolation: self.extrapolation,
+            inner: None,
+        };
+        built.rebuild_inner();
+        Ok(built)
+    }
+
     /// Delete a curve from the cache.
     pub fn delete(&self, curve_id: &CurveId) -> bool {
         self.curves.remove(curve_id).is_some()
@@ -440,3 +467,89 @@ impl CurveBuilder {
         self.curves.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::storage::CurveSnapshot;
+    use convex_ext_file::{
+        EmptyCurveInputSource, EmptyEtfQuoteSource, EmptyFxRateSource, EmptyIndexFixingSource,
+        EmptyInflationFixingSource, EmptyQuoteSource, EmptyVolatilitySource,
+    };
+
+    fn create_test_builder() -> CurveBuilder {
+        let market_data = Arc::new(MarketDataProvider {
+            quotes: Arc::new(EmptyQuoteSource),
+            curve_inputs: Arc::new(EmptyCurveInputSource),
+            index_fixings: Arc::new(EmptyIndexFixingSource),
+            volatility: Arc::new(EmptyVolatilitySource),
+            fx_rates: Arc::new(EmptyFxRateSource),
+            inflation_fixings: Arc::new(EmptyInflationFixingSource),
+            etf_quotes: Arc::new(EmptyEtfQuoteSource),
+        });
+        CurveBuilder::new(market_data, Arc::new(CalculationGraph::new()))
+    }
+
+    fn midnight_millis(date: Date) -> i64 {
+        date.as_naive_date()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    }
+
+    fn snapshot(date: Date, points: Vec<(u32, f64)>) -> CurveSnapshot {
+        CurveSnapshot {
+            curve_id: CurveId::new("USD_GOVT"),
+            as_of: midnight_millis(date),
+            points,
+            inputs_hash: String::new(),
+            build_duration_ms: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_curve_as_of_interpolates_between_snapshots() {
+        let storage = convex_ext_redb::create_memory_storage().unwrap();
+        let curve_id = CurveId::new("USD_GOVT");
+        let monday = Date::from_ymd(2025, 3, 3).unwrap();
+        let next_monday = monday.add_days(7);
+
+        let first = snapshot(monday, vec![(365, 0.04), (3650, 0.045)]);
+        let second = snapshot(next_monday, vec![(365, 0.05), (3650, 0.048)]);
+        storage.curves.save_snapshot(&first).await.unwrap();
+        storage.curves.save_snapshot(&second).await.unwrap();
+
+        // Midweek curve is the average of the two in discount-factor terms.
+        let midweek = (first.as_of + second.as_of) / 2;
+        let mid = CurveSnapshot::interpolate_between(&first, &second, midweek);
+        for days in [365, 3650] {
+            let expected = (first.discount_factor(days) + second.discount_factor(days)) / 2.0;
+            assert!((mid.discount_factor(days) - expected).abs() < 1e-12);
+        }
+
+        let thursday = monday.add_days(3);
+        let curve = create_test_builder()
+            .curve_as_of(storage.curves.as_ref(), &curve_id, thursday)
+            .await
+            .unwrap();
+        assert_eq!(curve.reference_date, thursday);
+
+        let df0 = first.discount_factor(365);
+        let expected = df0 + 3.0 / 7.0 * (second.discount_factor(365) - df0);
+        assert!((curve.discount_factor(1.0).unwrap() - expected).abs() < 1e-9);
+
+        // Dates past the stored range take the latest snapshot.
+        let later = create_test_builder()
+            .curve_as_of(storage.curves.as_ref(), &curve_id, next_monday.add_days(1))
+            .await
+            .unwrap();
+        let expected = second.discount_factor(365);
+        assert!((later.discount_factor(1.0).unwrap() - expected).abs() < 1e-9);
+
+        assert!(create_test_builder()
+            .curve_as_of(storage.curves.as_ref(), &CurveId::new("EUR_GOVT"), thursday)
+            .await
+            .is_err());
+    }
+}
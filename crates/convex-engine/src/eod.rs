@@ -0,0 +1,266 @@
+//! End-of-day snapshots of curves and bond analytics.
+//!
+//! [`EodSnapshotter`] persists the engine's state for a business date: every
+//! built curve as a [`CurveSnapshot`] in the curve store, and every cached
+//! bond price as a [`BondSnapshot`] in the bond store. These are the
+//! historical series that [`CurveBuilder::curve_as_of`] and historical VaR
+//! read back. [`ReactiveEngine::with_eod_snapshots`] runs it daily at a
+//! configured time, after that time's recalculation.
+//!
+//! [`ReactiveEngine::with_eod_snapshots`]: crate::reactive::ReactiveEngine::with_eod_snapshots
+
+use std::sync::Arc;
+
+use tracing::{error, info};
+
+use convex_core::ids::InstrumentId;
+use convex_core::Date;
+
+use crate::calc_graph::{CalculationGraph, NodeId, NodeValue};
+use crate::curve_builder::CurveBuilder;
+use crate::error::EngineError;
+use crate::ports::storage::{BondSnapshot, CurveSnapshot, StorageAdapter};
+use crate::scheduler::EodAction;
+
+/// Timestamp (ms) that end-of-day snapshots for `date` are stored under:
+/// midnight UTC, the instant [`CurveBuilder::curve_as_of`] looks up.
+pub fn business_date_millis(date: Date) -> i64 {
+    date.as_naive_date()
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc()
+        .timestamp_millis()
+}
+
+/// Builds a bond snapshot from a cached bond price, or `None` for any other
+/// node value.
+fn bond_snapshot(
+    instrument_id: InstrumentId,
+    business_date: Date,
+    value: &NodeValue,
+) -> Option<BondSnapshot> {
+    match value {
+        NodeValue::BondPrice {
+            clean_price_bid,
+            clean_price_mid,
+            clean_price_ask,
+            accrued_interest,
+            ytm_bid,
+            ytm_mid,
+            ytm_ask,
+            z_spread_mid,
+            modified_duration,
+            dv01,
+            override_id,
+        } => Some(BondSnapshot {
+            instrument_id,
+            as_of: business_date_millis(business_date),
+            business_date,
+            clean_price_bid: *clean_price_bid,
+            clean_price_mid: *clean_price_mid,
+            clean_price_ask: *clean_price_ask,
+            accrued_interest: *accrued_interest,
+            ytm_bid: *ytm_bid,
+            ytm_mid: *ytm_mid,
+            ytm_ask: *ytm_ask,
+            z_spread_mid: *z_spread_mid,
+            modified_duration: *modified_duration,
+            dv01: *dv01,
+            override_id: override_id.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// What an end-of-day snapshot wrote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EodSnapshotSummary {
+    /// Business date
+    pub business_date: Date,
+    /// Timestamp the snapshots are stored under (ms)
+    pub as_of: i64,
+    /// Curve snapshots saved
+    pub curves: usize,
+    /// Bond snapshots saved
+    pub bonds: usize,
+}
+
+/// Persists end-of-day curve snapshots and bond analytics.
+pub struct EodSnapshotter {
+    curve_builder: Arc<CurveBuilder>,
+    calc_graph: Arc<CalculationGraph>,
+    storage: Arc<StorageAdapter>,
+}
+
+impl EodSnapshotter {
+    /// Create a snapshotter over the engine's curves, calculation graph and
+    /// storage.
+    pub fn new(
+        curve_builder: Arc<CurveBuilder>,
+        calc_graph: Arc<CalculationGraph>,
+        storage: Arc<StorageAdapter>,
+    ) -> Self {
+        Self {
+            curve_builder,
+            calc_graph,
+            storage,
+        }
+    }
+
+    /// Snapshot all built curves and cached bond prices for `business_date`.
+    ///
+    /// Both are saved under [`business_date_millis`], replacing any earlier
+    /// snapshot of the same curve or bond and date.
+    pub async fn snapshot(&self, business_date: Date) -> Result<EodSnapshotSummary, EngineError> {
+        let as_of = business_date_millis(business_date);
+
+        let mut curves = 0;
+        for curve_id in self.curve_builder.list() {
+            let Some(curve) = self.curve_builder.get(&curve_id) else {
+                continue;
+            };
+            let snapshot = CurveSnapshot {
+                curve_id,
+                as_of,
+                points: curve.to_points(),
+                inputs_hash: curve.inputs_hash.clone(),
+                build_duration_ms: 0,
+            };
+            self.storage.curves.save_snapshot(&snapshot).await?;
+            curves += 1;
+        }
+
+        let mut bonds = 0;
+        for node_id in self.calc_graph.cached_nodes() {
+            let NodeId::BondPrice { instrument_id } = &node_id else {
+                continue;
+            };
+            let Some(cached) = self.calc_graph.get_cached(&node_id) else {
+                continue;
+            };
+            let Some(snapshot) = bond_snapshot(instrument_id.clone(), business_date, &cached.value)
+            else {
+                continue;
+            };
+            self.storage.bonds.save_snapshot(&snapshot).await?;
+            bonds += 1;
+        }
+
+        info!(
+            "EOD snapshot for {}: {} curves, {} bonds",
+            business_date, curves, bonds
+        );
+        Ok(EodSnapshotSummary {
+            business_date,
+            as_of,
+            curves,
+            bonds,
+        })
+    }
+
+    /// An [`EodScheduler`](crate::scheduler::EodScheduler) action that runs
+    /// [`snapshot`](Self::snapshot) for the trigger's business date.
+    ///
+    /// The snapshot reads whatever is cached when it runs, so register it
+    /// through the scheduler rather than calling it as the trigger fires.
+    pub fn action(self: Arc<Self>) -> EodAction {
+        Arc::new(move |business_date| {
+            let snapshotter = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = snapshotter.snapshot(business_date).await {
+                    error!("EOD snapshot for {} failed: {}", business_date, e);
+                }
+            });
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use convex_core::ids::CurveId;
+    use convex_ext_file::{
+        EmptyCurveInputSource, EmptyEtfQuoteSource, EmptyFxRateSource, EmptyIndexFixingSource,
+        EmptyInflationFixingSource, EmptyQuoteSource, EmptyVolatilitySource,
+    };
+    use rust_decimal_macros::dec;
+
+    use crate::ports::market_data::MarketDataProvider;
+
+    #[tokio::test]
+    async fn test_eod_snapshot_is_retrievable_and_replaced_on_rerun() {
+        let calc_graph = Arc::new(CalculationGraph::new());
+        let market_data = Arc::new(MarketDataProvider {
+            quotes: Arc::new(EmptyQuoteSource),
+            curve_inputs: Arc::new(EmptyCurveInputSource),
+            index_fixings: Arc::new(EmptyIndexFixingSource),
+            volatility: Arc::new(EmptyVolatilitySource),
+            fx_rates: Arc::new(EmptyFxRateSource),
+            inflation_fixings: Arc::new(EmptyInflationFixingSource),
+            etf_quotes: Arc::new(EmptyEtfQuoteSource),
+        });
+        let curve_builder = Arc::new(CurveBuilder::new(market_data, calc_graph.clone()));
+        let storage = Arc::new(convex_ext_redb::create_memory_storage().unwrap());
+
+        let business_date = Date::from_ymd(2025, 3, 14).unwrap();
+        let curve_id = CurveId::new("USD_GOVT");
+        let curve = curve_builder
+            .create_from_points(
+                curve_id.clone(),
+                business_date,
+                vec![(1.0, 0.04), (5.0, 0.042), (10.0, 0.045)],
+            )
+            .unwrap();
+        let instrument_id = InstrumentId::new("US912828Z229");
+        let bond_node = NodeId::BondPrice {
+            instrument_id: instrument_id.clone(),
+        };
+        let bond_price = |clean_mid| NodeValue::BondPrice {
+            clean_price_bid: Some(dec!(99.5)),
+            clean_price_mid: Some(clean_mid),
+            clean_price_ask: Some(dec!(100)),
+            accrued_interest: Some(dec!(0.4)),
+            ytm_bid: None,
+            ytm_mid: Some(dec!(0.0431)),
+            ytm_ask: None,
+            z_spread_mid: Some(dec!(12.5)),
+            modified_duration: Some(dec!(4.6)),
+            dv01: Some(dec!(0.046)),
+            override_id: None,
+        };
+        calc_graph.update_cache(&bond_node, bond_price(dec!(99.75)));
+
+        let snapshotter = EodSnapshotter::new(curve_builder, calc_graph.clone(), storage.clone());
+        let summary = snapshotter.snapshot(business_date).await.unwrap();
+        assert_eq!((summary.curves, summary.bonds), (1, 1));
+
+        let as_of = business_date_millis(business_date);
+        let snapshot = storage
+            .curves
+            .get_snapshot(&curve_id, as_of)
+            .await
+            .unwrap()
+            .expect("EOD snapshot saved the curve");
+        assert_eq!(snapshot.points, curve.to_points());
+
+        let bond = storage
+            .bonds
+            .get_snapshot(&instrument_id, as_of)
+            .await
+            .unwrap()
+            .expect("EOD snapshot saved the bond");
+        assert_eq!(bond.business_date, business_date);
+        assert_eq!(bond.clean_price_mid, Some(dec!(99.75)));
+
+        // A rerun for the same date replaces the bond's snapshot.
+        calc_graph.update_cache(&bond_node, bond_price(dec!(99.8)));
+        snapshotter.snapshot(business_date).await.unwrap();
+        let bonds = storage
+            .bonds
+            .list_snapshots(&instrument_id, i64::MIN, i64::MAX)
+            .await
+            .unwrap();
+        assert_eq!(bonds.len(), 1);
+        assert_eq!(bonds[0].clean_price_mid, Some(dec!(99.8)));
+    }
+}
@@ -21,6 +21,10 @@ pub enum EngineError {
     #[error("pricing error: {0}")]
     PricingError(String),
 
+    /// Adding a dependency would close a cycle in the calculation graph
+    #[error("dependency cycle: {0}")]
+    DependencyCycle(String),
+
     /// Internal error
     #[error("internal error: {0}")]
     Internal(String),
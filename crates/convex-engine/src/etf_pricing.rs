@@ -294,6 +294,7 @@ mod tests {
                 source: "test".to_string(),
                 is_stale: false,
                 quality: 100,
+                override_id: None,
             },
             BondQuoteOutput {
                 instrument_id: InstrumentId::new("US037833DV24"),
@@ -345,6 +346,7 @@ mod tests {
                 source: "test".to_string(),
                 is_stale: false,
                 quality: 100,
+                override_id: None,
             },
         ]
     }
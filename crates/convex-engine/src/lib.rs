@@ -42,6 +42,7 @@
 pub mod builder;
 pub mod calc_graph;
 pub mod curve_builder;
+pub mod eod;
 pub mod error;
 pub mod etf_pricing;
 pub mod market_data_listener;
@@ -56,19 +57,24 @@ mod context;
 // Re-exports
 pub use builder::PricingEngineBuilder;
 pub use calc_graph::{
-    CalculationGraph, NodeId, NodeValue, ShardAssignment, ShardConfig, ShardStrategy,
+    CalculationGraph, InvalidationHook, NodeId, NodeValue, ShardAssignment, ShardConfig,
+    ShardStrategy,
 };
 pub use curve_builder::{BuiltCurve, CurveBuilder};
+pub use eod::{EodSnapshotSummary, EodSnapshotter};
 pub use error::EngineError;
 pub use etf_pricing::EtfPricer;
 pub use market_data_listener::{
     CurveInputUpdate, CurveUpdate, FxRateUpdate, IndexFixingUpdate, InflationFixingUpdate,
     MarketDataListener, MarketDataPublisher, MarketDataUpdate, QuoteUpdate, VolSurfaceUpdate,
 };
-pub use portfolio_analytics::{Portfolio, PortfolioAnalyzer, Position};
+pub use portfolio_analytics::{Portfolio, PortfolioAggregates, PortfolioAnalyzer, Position};
 pub use pricing_router::{BatchPricingResult, PricingRouter};
 pub use reactive::{ReactiveEngine, ReactiveEngineBuilder};
-pub use scheduler::{EodScheduler, IntervalScheduler, NodeUpdate, ThrottleManager, UpdateSource};
+pub use scheduler::{
+    EodAction, EodScheduler, EodTrigger, IntervalScheduler, NodeUpdate, ThrottleManager,
+    ThrottleStats, UpdateSource,
+};
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -111,7 +117,6 @@ pub struct PricingEngine {
     reference_data: Arc<ReferenceDataProvider>,
 
     /// Storage adapter
-    #[allow(dead_code)]
     storage: Arc<StorageAdapter>,
 
     /// Output publisher
@@ -133,7 +138,11 @@ impl PricingEngine {
     ) -> Self {
         let (shutdown_tx, _) = broadcast::channel(1);
 
-        let calc_graph = Arc::new(CalculationGraph::new());
+        let calc_graph = match config.max_cached_values {
+            Some(capacity) => CalculationGraph::new().with_cache_capacity(capacity),
+            None => CalculationGraph::new(),
+        };
+        let calc_graph = Arc::new(calc_graph);
         let curve_builder = Arc::new(CurveBuilder::new(market_data.clone(), calc_graph.clone()));
         let pricing_router = Arc::new(PricingRouter::new());
         let etf_pricer = Arc::new(EtfPricer::new());
@@ -179,8 +188,9 @@ impl PricingEngine {
             loop {
                 tokio::select! {
                     _ = ticker.tick() => {
-                        // Get dirty nodes that need recalculation
-                        let dirty_nodes = calc_graph.get_nodes_to_calculate();
+                        // Get dirty nodes that need recalculation, upstream first
+                        let dirty_nodes =
+                            calc_graph.topological_order(&calc_graph.get_nodes_to_calculate());
 
                         if !dirty_nodes.is_empty() {
                             debug!("Processing {} dirty nodes", dirty_nodes.len());
@@ -227,7 +237,9 @@ impl PricingEngine {
     ///
     /// This is useful for testing or on-demand calculations.
     pub fn process_dirty_nodes(&self) -> usize {
-        let dirty_nodes = self.calc_graph.get_nodes_to_calculate();
+        let dirty_nodes = self
+            .calc_graph
+            .topological_order(&self.calc_graph.get_nodes_to_calculate());
         let count = dirty_nodes.len();
 
         for node_id in dirty_nodes {
@@ -246,6 +258,16 @@ impl PricingEngine {
         Ok(())
     }
 
+    /// Create an end-of-day snapshotter over this engine's curves, cached
+    /// analytics and storage.
+    pub fn eod_snapshotter(&self) -> EodSnapshotter {
+        EodSnapshotter::new(
+            self.curve_builder.clone(),
+            self.calc_graph.clone(),
+            self.storage.clone(),
+        )
+    }
+
     /// Get the calculation graph.
     pub fn calc_graph(&self) -> &Arc<CalculationGraph> {
         &self.calc_graph
@@ -281,6 +303,58 @@ impl PricingEngine {
         &self.reference_data
     }
 
+    /// Price a bond, applying its active manual override from storage.
+    ///
+    /// See [`PricingRouter::price_with_override`] for how the override is
+    /// applied.
+    pub async fn price_bond(
+        &self,
+        input: pricing_router::PricingInput,
+    ) -> Result<crate::ports::output::BondQuoteOutput, EngineError> {
+        let price_override = self
+            .storage
+            .overrides
+            .get(&input.bond.instrument_id)
+            .await?;
+        let now = chrono::Utc::now().timestamp_millis();
+        self.pricing_router
+            .price_with_override(input, price_override.as_ref(), now)
+    }
+
+    /// Price a batch of bonds, applying each bond's active manual override.
+    ///
+    /// Active overrides are loaded from storage once for the whole batch;
+    /// results are returned in input order. With `parallel` the bonds are
+    /// priced on the rayon pool.
+    pub async fn price_bonds(
+        &self,
+        inputs: Vec<pricing_router::PricingInput>,
+        parallel: bool,
+    ) -> Result<Vec<Result<crate::ports::output::BondQuoteOutput, EngineError>>, EngineError> {
+        use rayon::prelude::*;
+
+        let overrides: std::collections::HashMap<_, _> = self
+            .storage
+            .overrides
+            .get_active()
+            .await?
+            .into_iter()
+            .map(|o| (o.instrument_id.clone(), o))
+            .collect();
+        let now = chrono::Utc::now().timestamp_millis();
+        let price = |input: pricing_router::PricingInput| {
+            let price_override = overrides.get(&input.bond.instrument_id);
+            self.pricing_router
+                .price_with_override(input, price_override, now)
+        };
+
+        Ok(if parallel {
+            inputs.into_par_iter().map(price).collect()
+        } else {
+            inputs.into_iter().map(price).collect()
+        })
+    }
+
     // =========================================================================
     // REACTIVE MARKET DATA HANDLERS
     // =========================================================================
@@ -420,14 +494,19 @@ impl PricingEngine {
     /// Register a bond for reactive pricing.
     ///
     /// This sets up the node in the calc graph with dependencies on
-    /// the relevant curves and quote.
+    /// the relevant curves and quote. Registering a bond again replaces its
+    /// dependencies and config without duplicating edges.
+    ///
+    /// Returns `true` if the bond was newly registered, or
+    /// [`EngineError::DependencyCycle`] if its dependencies would close a
+    /// cycle in the calc graph.
     pub fn register_bond(
         &self,
         instrument_id: convex_core::ids::InstrumentId,
         discount_curve: convex_core::ids::CurveId,
         benchmark_curve: Option<convex_core::ids::CurveId>,
         config: crate::ports::config::NodeConfig,
-    ) {
+    ) -> Result<bool, EngineError> {
         let bond_node = NodeId::BondPrice {
             instrument_id: instrument_id.clone(),
         };
@@ -448,11 +527,13 @@ impl PricingEngine {
             });
         }
 
-        // Add node with dependencies
-        self.calc_graph.add_node(bond_node.clone(), deps);
+        // Add (or replace) node with dependencies
+        let added = self.calc_graph.add_node(bond_node.clone(), deps)?;
 
         // Set config
         self.calc_graph.set_node_config(bond_node, config);
+
+        Ok(added)
     }
 
     /// Register an ETF for iNAV calculations.
@@ -463,7 +544,9 @@ impl PricingEngine {
 
         // ETF iNAV depends on its constituent bond prices
         // In practice, we'd look up the holdings and add dependencies
-        self.calc_graph.add_node(node_id.clone(), vec![]);
+        self.calc_graph
+            .add_node(node_id.clone(), vec![])
+            .expect("a node without dependencies cannot close a cycle");
 
         // Use iNAV config (15 second interval)
         self.calc_graph
@@ -476,7 +559,9 @@ impl PricingEngine {
             etf_id: etf_id.clone(),
         };
 
-        self.calc_graph.add_node(node_id.clone(), vec![]);
+        self.calc_graph
+            .add_node(node_id.clone(), vec![])
+            .expect("a node without dependencies cannot close a cycle");
 
         // Use NAV config (end of day)
         self.calc_graph
@@ -484,16 +569,29 @@ impl PricingEngine {
     }
 
     /// Register a portfolio for analytics.
-    pub fn register_portfolio(&self, portfolio_id: convex_core::ids::PortfolioId) {
+    ///
+    /// The portfolio node depends on the price node of each holding, so a
+    /// single bond repricing marks the portfolio dirty. Registering again
+    /// replaces the holdings.
+    pub fn register_portfolio(&self, portfolio: Portfolio) -> Result<(), EngineError> {
         let node_id = NodeId::Portfolio {
-            portfolio_id: portfolio_id.clone(),
+            portfolio_id: portfolio.portfolio_id.clone(),
         };
+        let deps = portfolio
+            .positions
+            .iter()
+            .map(|position| NodeId::BondPrice {
+                instrument_id: position.instrument_id.clone(),
+            })
+            .collect();
 
-        self.calc_graph.add_node(node_id.clone(), vec![]);
+        self.calc_graph.add_node(node_id.clone(), deps)?;
 
         // Use portfolio config (5 second throttle)
         self.calc_graph
             .set_node_config(node_id, crate::ports::config::NodeConfig::portfolio());
+
+        Ok(())
     }
 
     /// Create a reactive engine from this pricing engine.
@@ -507,6 +605,7 @@ impl PricingEngine {
             self.pricing_router.clone(),
             self.reference_data.clone(),
         )
+        .with_override_store(self.storage.overrides.clone())
     }
 }
 
@@ -514,3 +613,228 @@ impl PricingEngine {
 /// `convex_engine::ports::*` paths keep working while adapters depend only on
 /// the lightweight ports crate (not the whole engine).
 pub use convex_ports as ports;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::reference_data::{BondReferenceData, BondType, IssuerType};
+    use crate::ports::storage::PriceOverride;
+    use convex_core::ids::InstrumentId;
+    use convex_core::types::Date;
+    use convex_ext_file::{
+        create_empty_output, EmptyBondReferenceSource, EmptyCurveInputSource,
+        EmptyEtfHoldingsSource, EmptyEtfQuoteSource, EmptyFxRateSource, EmptyIndexFixingSource,
+        EmptyInflationFixingSource, EmptyIssuerReferenceSource, EmptyQuoteSource,
+        EmptyRatingSource, EmptyVolatilitySource,
+    };
+    use rust_decimal_macros::dec;
+
+    fn create_test_engine(storage: StorageAdapter) -> PricingEngine {
+        let market_data = MarketDataProvider {
+            quotes: Arc::new(EmptyQuoteSource),
+            curve_inputs: Arc::new(EmptyCurveInputSource),
+            index_fixings: Arc::new(EmptyIndexFixingSource),
+            volatility: Arc::new(EmptyVolatilitySource),
+            fx_rates: Arc::new(EmptyFxRateSource),
+            inflation_fixings: Arc::new(EmptyInflationFixingSource),
+            etf_quotes: Arc::new(EmptyEtfQuoteSource),
+        };
+        let reference_data = ReferenceDataProvider {
+            bonds: Arc::new(EmptyBondReferenceSource),
+            issuers: Arc::new(EmptyIssuerReferenceSource),
+            ratings: Arc::new(EmptyRatingSource),
+            etf_holdings: Arc::new(EmptyEtfHoldingsSource),
+        };
+        PricingEngineBuilder::new()
+            .with_config(EngineConfig::default())
+            .with_market_data(Arc::new(market_data))
+            .with_reference_data(Arc::new(reference_data))
+            .with_storage(Arc::new(storage))
+            .with_output(Arc::new(create_empty_output()))
+            .build()
+            .unwrap()
+    }
+
+    fn create_test_bond() -> BondReferenceData {
+        BondReferenceData {
+            instrument_id: InstrumentId::new("UST_2030"),
+            isin: None,
+            cusip: Some("912828Z22".to_string()),
+            sedol: None,
+            bbgid: None,
+            description: "US Treasury 2.5% 2030".to_string(),
+            currency: convex_core::Currency::USD,
+            issue_date: Date::from_ymd(2020, 5, 15).unwrap(),
+            maturity_date: Date::from_ymd(2030, 5, 15).unwrap(),
+            coupon_rate: Some(dec!(0.025)),
+            frequency: 2,
+            day_count: "30/360".to_string(),
+            face_value: dec!(100),
+            bond_type: BondType::FixedBullet,
+            issuer_type: IssuerType::Sovereign,
+            issuer_id: "US_GOVT".to_string(),
+            issuer_name: "United States Treasury".to_string(),
+            seniority: "Senior".to_string(),
+            is_callable: false,
+            call_schedule: vec![],
+            is_putable: false,
+            is_sinkable: false,
+            floating_terms: None,
+            inflation_index: None,
+            inflation_base_index: None,
+            has_deflation_floor: false,
+            country_of_risk: "US".to_string(),
+            sector: "Government".to_string(),
+            amount_outstanding: None,
+            first_coupon_date: None,
+            last_updated: 0,
+            source: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_register_bond_twice_keeps_latest_config_and_dependencies() {
+        use crate::ports::config::{NodeConfig, UpdateFrequency};
+        use convex_core::ids::CurveId;
+
+        let engine = create_test_engine(convex_ext_redb::create_memory_storage().unwrap());
+        let instrument_id = InstrumentId::new("UST_2030");
+        let bond = NodeId::BondPrice {
+            instrument_id: instrument_id.clone(),
+        };
+        let quote = NodeId::Quote {
+            instrument_id: instrument_id.clone(),
+        };
+        let sofr = NodeId::Curve {
+            curve_id: CurveId::new("USD.SOFR"),
+        };
+        let govt = NodeId::Curve {
+            curve_id: CurveId::new("USD.GOVT"),
+        };
+
+        assert!(engine
+            .register_bond(
+                instrument_id.clone(),
+                CurveId::new("USD.SOFR"),
+                Some(CurveId::new("USD.GOVT")),
+                NodeConfig::bond_price_liquid(),
+            )
+            .unwrap());
+        assert!(!engine
+            .register_bond(
+                instrument_id.clone(),
+                CurveId::new("USD.SOFR"),
+                None,
+                NodeConfig::bond_price_illiquid(),
+            )
+            .unwrap());
+
+        let graph = engine.calc_graph();
+        assert_eq!(
+            graph.get_dependencies(&bond),
+            vec![sofr.clone(), quote.clone()]
+        );
+        assert_eq!(graph.get_dependents(&sofr), vec![bond.clone()]);
+        assert_eq!(graph.get_dependents(&quote), vec![bond.clone()]);
+        assert!(graph.get_dependents(&govt).is_empty());
+
+        let config = graph.get_node_config(&bond).unwrap();
+        assert_eq!(config.priority, 50);
+        assert!(matches!(
+            config.frequency,
+            UpdateFrequency::Throttled { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stored_override_reaches_bond_quote() {
+        let storage = convex_ext_redb::create_memory_storage().unwrap();
+        let now = chrono::Utc::now().timestamp_millis();
+        let mark = PriceOverride {
+            instrument_id: InstrumentId::new("UST_2030"),
+            price: Some(97.25),
+            yield_value: None,
+            spread: None,
+            reason: "Dealer run".to_string(),
+            created_by: "trader".to_string(),
+            created_at: now - 60_000,
+            expires_at: Some(now + 3_600_000),
+            is_approved: true,
+            approved_by: Some("risk".to_string()),
+            approved_at: Some(now - 30_000),
+        };
+        storage.overrides.save(&mark).await.unwrap();
+        let engine = create_test_engine(storage);
+
+        let settlement = Date::from_ymd(2025, 1, 15).unwrap();
+        let input = || {
+            pricing_router::PricingInput::with_mid_price(
+                create_test_bond(),
+                settlement,
+                Some(dec!(95.50)),
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+        let quote = engine.price_bond(input()).await.unwrap();
+        let model = engine.pricing_router().price(&input()).unwrap();
+
+        assert_eq!(quote.override_id, Some(mark.override_id()));
+        assert_eq!(quote.clean_price_mid, Some(dec!(97.25)));
+        // Yield is rederived from the mark, not the 95.50 feed price.
+        assert!(quote.ytm_mid.unwrap() < model.ytm_mid.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_price_bonds_applies_stored_overrides() {
+        let storage = convex_ext_redb::create_memory_storage().unwrap();
+        let now = chrono::Utc::now().timestamp_millis();
+        let mark = PriceOverride {
+            instrument_id: InstrumentId::new("UST_2030"),
+            price: Some(97.25),
+            yield_value: None,
+            spread: None,
+            reason: "Dealer run".to_string(),
+            created_by: "trader".to_string(),
+            created_at: now - 60_000,
+            expires_at: Some(now + 3_600_000),
+            is_approved: true,
+            approved_by: Some("risk".to_string()),
+            approved_at: Some(now - 30_000),
+        };
+        storage.overrides.save(&mark).await.unwrap();
+        let engine = create_test_engine(storage);
+
+        let settlement = Date::from_ymd(2025, 1, 15).unwrap();
+        let mut other = create_test_bond();
+        other.instrument_id = InstrumentId::new("UST_2030_B");
+        let inputs = || -> Vec<_> {
+            [create_test_bond(), other.clone()]
+                .into_iter()
+                .map(|bond| {
+                    pricing_router::PricingInput::with_mid_price(
+                        bond,
+                        settlement,
+                        Some(dec!(95.50)),
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                })
+                .collect()
+        };
+
+        for parallel in [false, true] {
+            let quotes = engine.price_bonds(inputs(), parallel).await.unwrap();
+            let marked = quotes[0].as_ref().unwrap();
+            let unmarked = quotes[1].as_ref().unwrap();
+            assert_eq!(marked.override_id, Some(mark.override_id()));
+            assert_eq!(marked.clean_price_mid, Some(dec!(97.25)));
+            assert_eq!(unmarked.override_id, None);
+            assert_eq!(unmarked.clean_price_mid, Some(dec!(95.50)));
+        }
+    }
+}
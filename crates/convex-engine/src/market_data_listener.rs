@@ -248,13 +248,13 @@ impl MarketDataListener {
         // Invalidate the quote node (propagates to dependent bond prices)
         self.calc_graph.invalidate(&node_id);
 
-        // Check if dependent bond price should be calculated (respecting throttle)
+        // Check if dependent bond price should be calculated (respecting throttle).
+        // The processing loop recalculates it and records the recalculation.
         let bond_node = NodeId::BondPrice {
             instrument_id: update.instrument_id.clone(),
         };
 
         if self.throttle_manager.should_calculate(&bond_node) {
-            self.throttle_manager.mark_calculated(&bond_node);
             self.notify_update(bond_node, UpdateSource::Immediate);
         } else {
             self.throttle_manager.schedule(bond_node);
@@ -41,6 +41,99 @@ pub struct Portfolio {
     pub positions: Vec<Position>,
 }
 
+/// Running market-value-weighted totals of a portfolio's priced positions.
+///
+/// Built by [`PortfolioAnalyzer::aggregate`]; turned into analytics by
+/// [`PortfolioAnalyzer::output`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PortfolioAggregates {
+    /// Total market value
+    pub market_value: Decimal,
+    /// Sum of modified duration x market value
+    pub weighted_duration: Decimal,
+    /// Sum of convexity x market value
+    pub weighted_convexity: Decimal,
+    /// Sum of yield x market value
+    pub weighted_yield: Decimal,
+    /// Sum of Z-spread x market value
+    pub weighted_spread: Decimal,
+    /// Total DV01
+    pub dv01: Decimal,
+    /// Positions with a price
+    pub priced_count: u32,
+    /// Sum of key rate duration x market value, by tenor
+    pub krd_totals: HashMap<String, Decimal>,
+    /// Market value by sector
+    pub sector_values: HashMap<String, Decimal>,
+    /// Market value by rating
+    pub rating_values: HashMap<String, Decimal>,
+}
+
+impl PortfolioAggregates {
+    /// Add (`sign` = 1) or remove (`sign` = -1) a position's contribution
+    /// under `quote`. Positions without a dirty price contribute nothing.
+    fn apply(&mut self, position: &Position, quote: &BondQuoteOutput, sign: Decimal) {
+        let Some(dirty_price) = quote.dirty_price_mid() else {
+            return;
+        };
+
+        // Calculate market value
+        let mv = position.notional * dirty_price / Decimal::from(100);
+        self.market_value += sign * mv;
+        if sign.is_sign_negative() {
+            self.priced_count = self.priced_count.saturating_sub(1);
+        } else {
+            self.priced_count += 1;
+        }
+
+        // Aggregate weighted metrics
+        if let Some(dur) = quote.modified_duration {
+            self.weighted_duration += sign * dur * mv;
+        }
+        if let Some(conv) = quote.convexity {
+            self.weighted_convexity += sign * conv * mv;
+        }
+        if let Some(ytm) = quote.ytm_mid {
+            self.weighted_yield += sign * ytm * mv;
+        }
+        if let Some(z_spread) = quote.z_spread_mid {
+            self.weighted_spread += sign * z_spread * mv;
+        }
+
+        // Aggregate DV01 (scale by notional)
+        if let Some(dv01) = quote.dv01 {
+            // DV01 is typically per $100 face, scale to position
+            self.dv01 += sign * dv01 * position.notional / Decimal::from(100);
+        }
+
+        // Aggregate key rate durations
+        if let Some(ref krds) = quote.key_rate_durations {
+            for (tenor, krd) in krds {
+                add_bucket(&mut self.krd_totals, tenor, sign * krd * mv);
+            }
+        }
+
+        // Sector breakdown
+        if let Some(ref sector) = position.sector {
+            add_bucket(&mut self.sector_values, sector, sign * mv);
+        }
+
+        // Rating breakdown
+        if let Some(ref rating) = position.rating {
+            add_bucket(&mut self.rating_values, rating, sign * mv);
+        }
+    }
+}
+
+/// Add to a bucket, dropping it once its last contribution is backed out.
+fn add_bucket(buckets: &mut HashMap<String, Decimal>, key: &str, amount: Decimal) {
+    let total = buckets.entry(key.to_string()).or_insert(Decimal::ZERO);
+    *total += amount;
+    if amount.is_sign_negative() && total.is_zero() {
+        buckets.remove(key);
+    }
+}
+
 /// Portfolio analytics calculator.
 pub struct PortfolioAnalyzer {
     /// Minimum position coverage required (0.0 - 1.0)
@@ -72,72 +165,29 @@ impl PortfolioAnalyzer {
         portfolio: &Portfolio,
         bond_prices: &[BondQuoteOutput],
     ) -> Result<PortfolioAnalyticsOutput, EngineError> {
+        let aggregates = self.aggregate(portfolio, bond_prices);
+        self.output(portfolio, &aggregates)
+    }
+
+    /// Sum position-level contributions into running portfolio totals.
+    ///
+    /// Keep the result to update it with [`update_holding`](Self::update_holding)
+    /// as single bonds reprice, rather than re-summing the whole portfolio.
+    pub fn aggregate(
+        &self,
+        portfolio: &Portfolio,
+        bond_prices: &[BondQuoteOutput],
+    ) -> PortfolioAggregates {
         // Build price lookup
         let price_map: HashMap<_, _> = bond_prices
             .iter()
             .map(|q| (q.instrument_id.clone(), q))
             .collect();
 
-        let mut total_market_value = Decimal::ZERO;
-        let mut weighted_duration = Decimal::ZERO;
-        let mut weighted_convexity = Decimal::ZERO;
-        let mut weighted_yield = Decimal::ZERO;
-        let mut weighted_spread = Decimal::ZERO;
-        let mut total_dv01 = Decimal::ZERO;
-        let mut priced_count = 0u32;
-
-        // Key rate duration aggregation
-        let mut krd_totals: HashMap<String, Decimal> = HashMap::new();
-
-        // Sector and rating breakdowns
-        let mut sector_values: HashMap<String, Decimal> = HashMap::new();
-        let mut rating_values: HashMap<String, Decimal> = HashMap::new();
-
+        let mut aggregates = PortfolioAggregates::default();
         for position in &portfolio.positions {
             if let Some(quote) = price_map.get(&position.instrument_id) {
-                if let Some(dirty_price) = quote.dirty_price_mid() {
-                    // Calculate market value
-                    let mv = position.notional * dirty_price / Decimal::from(100);
-                    total_market_value += mv;
-                    priced_count += 1;
-
-                    // Aggregate weighted metrics
-                    if let Some(dur) = quote.modified_duration {
-                        weighted_duration += dur * mv;
-                    }
-                    if let Some(conv) = quote.convexity {
-                        weighted_convexity += conv * mv;
-                    }
-                    if let Some(ytm) = quote.ytm_mid {
-                        weighted_yield += ytm * mv;
-                    }
-                    if let Some(z_spread) = quote.z_spread_mid {
-                        weighted_spread += z_spread * mv;
-                    }
-
-                    // Aggregate DV01 (scale by notional)
-                    if let Some(dv01) = quote.dv01 {
-                        // DV01 is typically per $100 face, scale to position
-                        total_dv01 += dv01 * position.notional / Decimal::from(100);
-                    }
-
-                    // Aggregate key rate durations
-                    if let Some(ref krds) = quote.key_rate_durations {
-                        for (tenor, krd) in krds {
-                            *krd_totals.entry(tenor.clone()).or_insert(Decimal::ZERO) += krd * mv;
-                        }
-                    }
-
-                    // Sector breakdown
-                    if let Some(ref sector) = position.sector {
-                        *sector_values.entry(sector.clone()).or_insert(Decimal::ZERO) += mv;
-                    }
-
-                    // Rating breakdown
-                    if let Some(ref rating) = position.rating {
-                        *rating_values.entry(rating.clone()).or_insert(Decimal::ZERO) += mv;
-                    }
-                }
+                aggregates.apply(position, quote, Decimal::ONE);
             } else {
                 debug!(
                     "No price for position {} in portfolio {}",
@@ -145,6 +195,44 @@ impl PortfolioAnalyzer {
                 );
             }
         }
+        aggregates
+    }
+
+    /// Replace one holding's contribution to `aggregates`.
+    ///
+    /// Every position in `instrument_id` has its contribution under `old`
+    /// backed out and its contribution under `new` added; `None` means the
+    /// bond was (or is now) unpriced. The other positions are not revisited.
+    pub fn update_holding(
+        &self,
+        aggregates: &mut PortfolioAggregates,
+        portfolio: &Portfolio,
+        instrument_id: &InstrumentId,
+        old: Option<&BondQuoteOutput>,
+        new: Option<&BondQuoteOutput>,
+    ) {
+        for position in portfolio
+            .positions
+            .iter()
+            .filter(|p| &p.instrument_id == instrument_id)
+        {
+            if let Some(old) = old {
+                aggregates.apply(position, old, Decimal::NEGATIVE_ONE);
+            }
+            if let Some(new) = new {
+                aggregates.apply(position, new, Decimal::ONE);
+            }
+        }
+    }
+
+    /// Portfolio analytics from running totals.
+    pub fn output(
+        &self,
+        portfolio: &Portfolio,
+        aggregates: &PortfolioAggregates,
+    ) -> Result<PortfolioAnalyticsOutput, EngineError> {
+        let total_market_value = aggregates.market_value;
+        let priced_count = aggregates.priced_count;
 
         // Check coverage
         let num_positions = portfolio.positions.len();
@@ -166,10 +254,10 @@ impl PortfolioAnalyzer {
         // Normalize weighted metrics
         let (duration, convexity, yield_value, spread) = if total_market_value > Decimal::ZERO {
             (
-                weighted_duration / total_market_value,
-                weighted_convexity / total_market_value,
-                weighted_yield / total_market_value,
-                weighted_spread / total_market_value,
+                aggregates.weighted_duration / total_market_value,
+                aggregates.weighted_convexity / total_market_value,
+                aggregates.weighted_yield / total_market_value,
+                aggregates.weighted_spread / total_market_value,
             )
         } else {
             (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO, Decimal::ZERO)
@@ -177,9 +265,10 @@ impl PortfolioAnalyzer {
 
         // Normalize key rate durations
         let key_rate_durations: Vec<(String, Decimal)> = if total_market_value > Decimal::ZERO {
-            let mut krds: Vec<_> = krd_totals
-                .into_iter()
-                .map(|(tenor, total)| (tenor, total / total_market_value))
+            let mut krds: Vec<_> = aggregates
+                .krd_totals
+                .iter()
+                .map(|(tenor, total)| (tenor.clone(), total / total_market_value))
                 .collect();
             // Sort by tenor
             krds.sort_by(|(a, _), (b, _)| {
@@ -203,18 +292,20 @@ impl PortfolioAnalyzer {
 
         // Calculate percentage breakdowns
         let sector_breakdown: Vec<(String, Decimal)> = if total_market_value > Decimal::ZERO {
-            sector_values
-                .into_iter()
-                .map(|(sector, mv)| (sector, mv / total_market_value))
+            aggregates
+                .sector_values
+                .iter()
+                .map(|(sector, mv)| (sector.clone(), mv / total_market_value))
                 .collect()
         } else {
             Vec::new()
         };
 
         let rating_breakdown: Vec<(String, Decimal)> = if total_market_value > Decimal::ZERO {
-            rating_values
-                .into_iter()
-                .map(|(rating, mv)| (rating, mv / total_market_value))
+            aggregates
+                .rating_values
+                .iter()
+                .map(|(rating, mv)| (rating.clone(), mv / total_market_value))
                 .collect()
         } else {
             Vec::new()
@@ -248,7 +339,7 @@ impl PortfolioAnalyzer {
             yield_value,
             spread,
 
-            dv01: total_dv01,
+            dv01: aggregates.dv01,
             key_rate_durations,
 
             sector_breakdown,
@@ -420,6 +511,7 @@ mod tests {
                 source: "test".to_string(),
                 is_stale: false,
                 quality: 100,
+                override_id: None,
             },
             BondQuoteOutput {
                 instrument_id: InstrumentId::new("BOND_B"),
@@ -475,6 +567,7 @@ mod tests {
                 source: "test".to_string(),
                 is_stale: false,
                 quality: 100,
+                override_id: None,
             },
             BondQuoteOutput {
                 instrument_id: InstrumentId::new("BOND_C"),
@@ -529,6 +622,7 @@ mod tests {
                 source: "test".to_string(),
                 is_stale: false,
                 quality: 100,
+                override_id: None,
             },
         ]
     }
@@ -572,6 +666,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_update_holding_matches_full_recompute() {
+        let analyzer = PortfolioAnalyzer::new();
+        let portfolio = create_test_portfolio();
+        let quotes = create_test_quotes();
+        let mut aggregates = analyzer.aggregate(&portfolio, &quotes);
+
+        // BOND_B reprices: lower price, longer duration
+        let mut repriced = quotes.clone();
+        repriced[1].clean_price_mid = Some(dec!(97.50));
+        repriced[1].modified_duration = Some(dec!(7.4));
+        repriced[1].ytm_mid = Some(dec!(0.0505));
+        analyzer.update_holding(
+            &mut aggregates,
+            &portfolio,
+            &InstrumentId::new("BOND_B"),
+            Some(&quotes[1]),
+            Some(&repriced[1]),
+        );
+
+        let incremental = analyzer.output(&portfolio, &aggregates).unwrap();
+        let full = analyzer.calculate(&portfolio, &repriced).unwrap();
+
+        // 1M * 100% + 2M * 98.5% + 0.5M * 99%
+        assert_eq!(incremental.market_value, dec!(3465000));
+        assert_eq!(incremental.market_value, full.market_value);
+        assert_eq!(incremental.duration, full.duration);
+        assert_eq!(incremental.convexity, full.convexity);
+        assert_eq!(incremental.yield_value, full.yield_value);
+        assert_eq!(incremental.dv01, full.dv01);
+        assert_eq!(incremental.num_positions, 3);
+        assert_eq!(incremental.key_rate_durations, full.key_rate_durations);
+        let sorted = |mut v: Vec<(String, Decimal)>| {
+            v.sort();
+            v
+        };
+        assert_eq!(
+            sorted(incremental.sector_breakdown),
+            sorted(full.sector_breakdown)
+        );
+        // Weighted duration: (5.0 * 1.0M + 7.4 * 1.97M + 3.0 * 0.495M) / 3.465M
+        let expected =
+            (dec!(5.0) * dec!(1000000) + dec!(7.4) * dec!(1970000) + dec!(3.0) * dec!(495000))
+                / dec!(3465000);
+        assert_eq!(incremental.duration, expected);
+
+        // Losing the price drops the holding and its sector bucket
+        analyzer.update_holding(
+            &mut aggregates,
+            &portfolio,
+            &InstrumentId::new("BOND_B"),
+            Some(&repriced[1]),
+            None,
+        );
+        assert_eq!(aggregates.priced_count, 2);
+        assert!(!aggregates.sector_values.contains_key("Healthcare"));
+        assert_eq!(
+            aggregates,
+            analyzer.aggregate(&portfolio, &[quotes[0].clone(), quotes[2].clone()])
+        );
+    }
+
     #[test]
     fn test_duration_contribution() {
         let analyzer = PortfolioAnalyzer::new();
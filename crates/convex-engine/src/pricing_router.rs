@@ -26,13 +26,13 @@ use convex_analytics::spreads::{
     OASCalculator, ParParAssetSwap, ZSpreadCalculator,
 };
 use convex_analytics::yields::YieldSolver;
-use convex_bonds::traits::BondCashFlow;
+use convex_bonds::traits::{BondAnalytics, BondCashFlow};
 use convex_core::types::Price;
 use convex_curves::curves::{ForwardCurve, ZeroCurve, ZeroCurveBuilder};
 
 use crate::ports::output::BondQuoteOutput;
 use crate::ports::reference_data::{BondReferenceData, BondType};
-use crate::ports::storage::BidAskSpreadConfig;
+use crate::ports::storage::{BidAskSpreadConfig, PriceOverride};
 
 use crate::curve_builder::BuiltCurve;
 use crate::error::EngineError;
@@ -152,8 +152,7 @@ impl PricingInput {
             let spread_bps = config.effective_bid_spread_bps();
             // Bid price is lower than mid (widening in price terms)
             // price_change = mid * spread_bps / 10000
-            let adjustment =
-                mid * Decimal::from_f64_retain(spread_bps / 10000.0).unwrap_or_default();
+            let adjustment = mid * Decimal::from_f64(spread_bps / 10000.0).unwrap_or_default();
             Some(mid - adjustment)
         } else {
             self.market_price_bid
@@ -180,8 +179,7 @@ impl PricingInput {
         if let (Some(mid), Some(config)) = (self.market_price_mid, &self.bid_ask_config) {
             let spread_bps = config.effective_ask_spread_bps();
             // Ask price is higher than mid (widening in price terms)
-            let adjustment =
-                mid * Decimal::from_f64_retain(spread_bps / 10000.0).unwrap_or_default();
+            let adjustment = mid * Decimal::from_f64(spread_bps / 10000.0).unwrap_or_default();
             Some(mid + adjustment)
         } else {
             self.market_price_ask
@@ -233,13 +231,7 @@ impl PricingRouter {
             .coupon_rate
             .ok_or_else(|| EngineError::PricingError("Missing coupon rate".to_string()))?;
 
-        let frequency = match ref_data.frequency {
-            1 => Frequency::Annual,
-            2 => Frequency::SemiAnnual,
-            4 => Frequency::Quarterly,
-            12 => Frequency::Monthly,
-            _ => Frequency::SemiAnnual, // Default
-        };
+        let frequency = self.get_frequency(ref_data);
 
         let day_count = self.parse_day_count(&ref_data.day_count)?;
 
@@ -314,13 +306,8 @@ impl PricingRouter {
         };
 
         // Get frequency for FRN
-        let frequency = match ref_data.frequency {
-            1 => Frequency::Annual,
-            2 => Frequency::SemiAnnual,
-            4 => Frequency::Quarterly,
-            12 => Frequency::Monthly,
-            _ => Frequency::Quarterly, // Default for FRNs
-        };
+        let frequency = Frequency::try_from_periods_per_year(ref_data.frequency)
+            .unwrap_or(Frequency::Quarterly); // Default for FRNs
 
         // Get day count
         let day_count = self.parse_day_count(&ref_data.day_count)?;
@@ -365,15 +352,9 @@ impl PricingRouter {
         }))
     }
 
-    /// Get frequency enum from reference data.
+    /// Get frequency enum from reference data (semi-annual if unsupported).
     fn get_frequency(&self, ref_data: &BondReferenceData) -> Frequency {
-        match ref_data.frequency {
-            1 => Frequency::Annual,
-            2 => Frequency::SemiAnnual,
-            4 => Frequency::Quarterly,
-            12 => Frequency::Monthly,
-            _ => Frequency::SemiAnnual,
-        }
+        Frequency::try_from_periods_per_year(ref_data.frequency).unwrap_or(Frequency::SemiAnnual)
     }
 
     /// Price a bond.
@@ -394,6 +375,80 @@ impl PricingRouter {
         }
     }
 
+    /// Price a bond, applying a manual override if one is active at `now`.
+    ///
+    /// Expired, unapproved or other instruments' overrides are ignored. An
+    /// active override fixes the mid clean price: its price if set, else the
+    /// price at its yield (decimal), else the price at its Z-spread (bps) off
+    /// the discount curve. The bond is then repriced from that mark, so
+    /// yields, spreads and risk are all consistent with it, and the quote is
+    /// tagged with its [`PriceOverride::override_id`].
+    pub fn price_with_override(
+        &self,
+        mut input: PricingInput,
+        price_override: Option<&PriceOverride>,
+        now: i64,
+    ) -> Result<BondQuoteOutput, EngineError> {
+        let Some(o) = price_override
+            .filter(|o| o.instrument_id == input.bond.instrument_id && o.is_active(now))
+        else {
+            return self.price(&input);
+        };
+
+        let clean_price = self.override_clean_price(&input, o)?;
+        input.market_price_bid = None;
+        input.market_price_mid = Some(clean_price);
+        input.market_price_ask = None;
+
+        let mut output = self.price(&input)?;
+        info!(
+            "Applied override {} to {} ({})",
+            o.override_id(),
+            input.bond.instrument_id,
+            o.reason
+        );
+        output.override_id = Some(o.override_id());
+        Ok(output)
+    }
+
+    /// Mid clean price implied by an override's price, yield or spread.
+    fn override_clean_price(
+        &self,
+        input: &PricingInput,
+        o: &PriceOverride,
+    ) -> Result<Decimal, EngineError> {
+        let to_decimal = |v: f64| {
+            Decimal::from_f64(v).ok_or_else(|| {
+                EngineError::PricingError(format!("Override value not finite: {}", v))
+            })
+        };
+        if let Some(price) = o.price {
+            return to_decimal(price);
+        }
+
+        let bond = self.to_fixed_rate_bond(&input.bond)?;
+        let settlement = input.settlement_date;
+        if let Some(ytm) = o.yield_value {
+            let clean = bond
+                .clean_price_from_yield(settlement, ytm, self.get_frequency(&input.bond))
+                .map_err(|e| EngineError::PricingError(format!("Override yield: {}", e)))?;
+            return to_decimal(clean);
+        }
+        if let Some(spread_bps) = o.spread {
+            let curve = input.discount_curve.as_ref().ok_or_else(|| {
+                EngineError::PricingError("Spread override needs a discount curve".to_string())
+            })?;
+            let dirty = ZSpreadCalculator::new(curve)
+                .try_price_with_spread(&bond, spread_bps / 10_000.0, settlement)
+                .map_err(|e| EngineError::PricingError(format!("Override spread: {}", e)))?;
+            return to_decimal(dirty).map(|d| d - bond.accrued_interest(settlement));
+        }
+        Err(EngineError::PricingError(format!(
+            "Override {} sets no price, yield or spread",
+            o.override_id()
+        )))
+    }
+
     /// Calculate Z-spread if discount curve is provided.
     fn calculate_z_spread(
         &self,
@@ -1221,6 +1276,7 @@ impl PricingRouter {
             source: "convex-engine".to_string(),
             is_stale: false,
             quality: if ytm_mid.is_some() { 100 } else { 50 },
+            override_id: None,
         })
     }
 
@@ -1513,6 +1569,7 @@ impl PricingRouter {
             source: "engine".to_string(),
             is_stale: false,
             quality,
+            override_id: None,
         })
     }
 
@@ -1676,6 +1733,92 @@ mod tests {
         built
     }
 
+    #[test]
+    fn test_active_override_replaces_model_price() {
+        let router = PricingRouter::new();
+        let settlement = Date::from_ymd(2025, 1, 15).unwrap();
+        let input = || {
+            PricingInput::with_mid_price(
+                create_test_bond(),
+                settlement,
+                Some(dec!(95.50)),
+                Some(create_test_curve(settlement)),
+                None,
+                None,
+                None,
+            )
+        };
+        let now = 1_700_000_000_000;
+        let mark = PriceOverride {
+            instrument_id: InstrumentId::new("TEST001"),
+            price: Some(97.25),
+            yield_value: None,
+            spread: None,
+            reason: "Dealer run".to_string(),
+            created_by: "trader".to_string(),
+            created_at: now - 60_000,
+            expires_at: Some(now + 3_600_000),
+            is_approved: true,
+            approved_by: Some("risk".to_string()),
+            approved_at: Some(now - 30_000),
+        };
+
+        let model = router.price(&input()).unwrap();
+        let overridden = router
+            .price_with_override(input(), Some(&mark), now)
+            .unwrap();
+        assert_eq!(model.clean_price_mid, Some(dec!(95.50)));
+        assert!(!model.is_overridden());
+        assert_eq!(overridden.clean_price_mid, Some(dec!(97.25)));
+        assert_eq!(overridden.override_id, Some(mark.override_id()));
+
+        // Yield, spread and dirty price are rederived from the mark.
+        let marked = router
+            .price(&PricingInput::with_mid_price(
+                create_test_bond(),
+                settlement,
+                Some(dec!(97.25)),
+                Some(create_test_curve(settlement)),
+                None,
+                None,
+                None,
+            ))
+            .unwrap();
+        assert!(overridden.ytm_mid < model.ytm_mid);
+        assert_eq!(overridden.ytm_mid, marked.ytm_mid);
+        assert_eq!(overridden.z_spread_mid, marked.z_spread_mid);
+        assert_eq!(overridden.dirty_price_mid(), marked.dirty_price_mid());
+
+        // A yield-only override prices the bond at that yield.
+        let yield_mark = PriceOverride {
+            price: None,
+            yield_value: Some(0.05),
+            ..mark.clone()
+        };
+        let at_yield = router
+            .price_with_override(input(), Some(&yield_mark), now)
+            .unwrap();
+        let ytm = at_yield.ytm_mid.unwrap().to_f64().unwrap();
+        assert!((ytm - 0.05).abs() < 1e-6, "ytm {ytm}");
+
+        // Expired and unapproved overrides leave the model price in place.
+        let expired = PriceOverride {
+            expires_at: Some(now - 1),
+            ..mark.clone()
+        };
+        let unapproved = PriceOverride {
+            is_approved: false,
+            ..mark.clone()
+        };
+        for ignored in [&expired, &unapproved] {
+            let output = router
+                .price_with_override(input(), Some(ignored), now)
+                .unwrap();
+            assert_eq!(output.clean_price_mid, Some(dec!(95.50)));
+            assert!(!output.is_overridden());
+        }
+    }
+
     #[test]
     fn test_select_model() {
         let router = PricingRouter::new();
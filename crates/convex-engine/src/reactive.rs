@@ -8,29 +8,49 @@
 //! - Node update broadcasting
 //! - **Actual calculation execution** via PricingRouter
 
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
 
 use crate::ports::config::{NodeConfig, UpdateFrequency};
 use crate::ports::reference_data::ReferenceDataProvider;
+use crate::ports::storage::{OverrideStore, PriceOverride, StorageAdapter};
 use convex_core::ids::*;
 use convex_core::Date;
 
 use crate::calc_graph::{CalculationGraph, NodeId, NodeValue};
 use crate::curve_builder::{BuiltCurve, CurveBuilder};
+use crate::eod::EodSnapshotter;
+use crate::error::EngineError;
 use crate::market_data_listener::{MarketDataListener, MarketDataPublisher};
+use crate::portfolio_analytics::{Portfolio, PortfolioAggregates, PortfolioAnalyzer};
+use crate::ports::output::BondQuoteOutput;
 use crate::pricing_router::{PricingInput, PricingRouter};
 use crate::scheduler::{
-    CronScheduler, EodScheduler, IntervalScheduler, NodeUpdate, ThrottleManager, UpdateSource,
+    CronScheduler, EodScheduler, EodTrigger, IntervalScheduler, NodeUpdate, ThrottleManager,
+    UpdateSource,
 };
 
 // =============================================================================
 // REACTIVE ENGINE
 // =============================================================================
 
+/// How often a running engine reloads active price overrides from its store.
+pub const OVERRIDE_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A registered portfolio and its running aggregates, kept between
+/// recalculations so a repriced holding updates them incrementally.
+struct PortfolioState {
+    portfolio: Portfolio,
+    aggregates: PortfolioAggregates,
+    /// Each holding's quote as last applied, keyed with when its bond price
+    /// was cached.
+    applied: HashMap<InstrumentId, (Instant, BondQuoteOutput)>,
+}
+
 /// Reactive pricing engine that coordinates all components.
 pub struct ReactiveEngine {
     /// Calculation graph
@@ -50,6 +70,15 @@ pub struct ReactiveEngine {
     bond_cache:
         Arc<dashmap::DashMap<InstrumentId, crate::ports::reference_data::BondReferenceData>>,
 
+    /// Store of manual price overrides, if any
+    override_store: Option<Arc<dyn OverrideStore>>,
+
+    /// Active overrides loaded from the store, for sync access in calc loop
+    overrides: Arc<dashmap::DashMap<InstrumentId, PriceOverride>>,
+
+    /// Registered portfolios, for sync access in calc loop
+    portfolios: Arc<dashmap::DashMap<PortfolioId, PortfolioState>>,
+
     /// Interval scheduler
     interval_scheduler: Arc<IntervalScheduler>,
 
@@ -105,6 +134,9 @@ impl ReactiveEngine {
             pricing_router,
             reference_data,
             bond_cache,
+            override_store: None,
+            overrides: Arc::new(dashmap::DashMap::new()),
+            portfolios: Arc::new(dashmap::DashMap::new()),
             interval_scheduler,
             eod_scheduler,
             cron_scheduler,
@@ -116,6 +148,70 @@ impl ReactiveEngine {
         }
     }
 
+    /// Apply manual price overrides from `store` to bond pricing.
+    ///
+    /// Active overrides are reloaded every [`OVERRIDE_REFRESH_INTERVAL`]
+    /// while the engine runs, or on demand with
+    /// [`refresh_overrides`](Self::refresh_overrides).
+    pub fn with_override_store(mut self, store: Arc<dyn OverrideStore>) -> Self {
+        self.override_store = Some(store);
+        self
+    }
+
+    /// Snapshot curves and bond analytics to `storage` daily at `time`
+    /// ("HH:MM:SS", local), once that time's recalculation has run.
+    pub fn with_eod_snapshots(self, storage: Arc<StorageAdapter>, time: &str) -> Self {
+        let snapshotter = Arc::new(EodSnapshotter::new(
+            self.curve_builder.clone(),
+            self.calc_graph.clone(),
+            storage,
+        ));
+        self.register_eod_action(time, snapshotter.action());
+        self
+    }
+
+    /// Reload active overrides from the store and reprice affected bonds.
+    ///
+    /// Bonds whose override was added, changed or removed are invalidated.
+    /// Returns the number of active overrides; 0 without a store.
+    pub async fn refresh_overrides(&self) -> Result<usize, EngineError> {
+        match &self.override_store {
+            Some(store) => Self::load_overrides(store, &self.overrides, &self.calc_graph).await,
+            None => Ok(0),
+        }
+    }
+
+    async fn load_overrides(
+        store: &Arc<dyn OverrideStore>,
+        overrides: &dashmap::DashMap<InstrumentId, PriceOverride>,
+        calc_graph: &CalculationGraph,
+    ) -> Result<usize, EngineError> {
+        let active = store.get_active().await?;
+        let mut changed: Vec<InstrumentId> = overrides
+            .iter()
+            .filter(|entry| !active.iter().any(|o| o.instrument_id == *entry.key()))
+            .map(|entry| entry.key().clone())
+            .collect();
+        for id in &changed {
+            overrides.remove(id);
+        }
+        for o in &active {
+            // Compare whole overrides: an edit can keep the creation time.
+            let unchanged = overrides
+                .get(&o.instrument_id)
+                .is_some_and(|current| *current == *o);
+            if !unchanged {
+                changed.push(o.instrument_id.clone());
+                overrides.insert(o.instrument_id.clone(), o.clone());
+            }
+        }
+        for instrument_id in changed {
+            debug!("Override changed for {}", instrument_id);
+            calc_graph.invalidate(&NodeId::BondPrice { instrument_id });
+        }
+        Ok(active.len())
+    }
+
     /// Set the settlement date for pricing.
     pub fn set_settlement_date(&self, date: Date) {
         *self.settlement_date.write() = date;
@@ -129,8 +225,15 @@ impl ReactiveEngine {
     /// Cache bond reference data for reactive pricing.
     ///
     /// This should be called when registering a bond to ensure
-    /// the reference data is available for sync calculations.
+    /// the reference data is available for sync calculations. The bond's
+    /// price node is routed by its currency under currency-based sharding.
     pub fn cache_bond_reference(&self, bond: crate::ports::reference_data::BondReferenceData) {
+        self.calc_graph.set_node_currency(
+            NodeId::BondPrice {
+                instrument_id: bond.instrument_id.clone(),
+            },
+            bond.currency,
+        );
         self.bond_cache.insert(bond.instrument_id.clone(), bond);
     }
 
@@ -200,6 +303,9 @@ impl ReactiveEngine {
         let curve_builder = self.curve_builder.clone();
         let pricing_router = self.pricing_router.clone();
         let bond_cache = self.bond_cache.clone();
+        let override_store = self.override_store.clone();
+        let overrides = self.overrides.clone();
+        let portfolios = self.portfolios.clone();
         let throttle_manager = self.throttle_manager.clone();
         let node_update_tx = self.node_update_tx.clone();
         let settlement_date = *self.settlement_date.read();
@@ -217,9 +323,11 @@ impl ReactiveEngine {
 
         // Forward EOD scheduler updates
         let eod_scheduler = self.eod_scheduler.clone();
+        let mut eod_rx = eod_scheduler.read().subscribe_triggers();
+        let eod_scheduler_clone = eod_scheduler.clone();
         let tx = self.node_update_tx.clone();
         tokio::spawn(async move {
-            let mut rx = eod_scheduler.read().subscribe();
+            let mut rx = eod_scheduler_clone.read().subscribe();
             while let Ok(update) = rx.recv().await {
                 let _ = tx.send(update);
             }
@@ -248,16 +356,39 @@ impl ReactiveEngine {
         // Main processing loop with actual calculation execution
         tokio::spawn(async move {
             let mut ticker = tokio::time::interval(Duration::from_millis(50));
+            let mut override_ticker = tokio::time::interval(OVERRIDE_REFRESH_INTERVAL);
+            let mut pending_eod: Vec<EodTrigger> = Vec::new();
 
             loop {
                 tokio::select! {
+                    _ = override_ticker.tick(), if override_store.is_some() => {
+                        if let Some(store) = &override_store {
+                            if let Err(e) =
+                                Self::load_overrides(store, &overrides, &calc_graph).await
+                            {
+                                warn!("Failed to refresh price overrides: {}", e);
+                            }
+                        }
+                    }
+                    Ok(trigger) = eod_rx.recv() => {
+                        pending_eod.push(trigger);
+                    }
                     _ = ticker.tick() => {
-                        // Process dirty nodes that are ready
-                        let nodes_to_calc = calc_graph.get_nodes_to_calculate();
+                        // Process dirty nodes that are ready, upstream first so
+                        // curves rebuild before the bonds priced off them. At
+                        // an EOD time every dirty node is brought up to date,
+                        // whatever its frequency, before the EOD actions run.
+                        let eod_due = !pending_eod.is_empty();
+                        let ready = if eod_due {
+                            calc_graph.get_dirty_nodes()
+                        } else {
+                            calc_graph.get_nodes_to_calculate()
+                        };
+                        let nodes_to_calc = calc_graph.topological_order(&ready);
 
                         for node_id in nodes_to_calc {
                             // Check throttle
-                            if throttle_manager.should_calculate(&node_id) {
+                            if eod_due || throttle_manager.should_calculate(&node_id) {
                                 debug!("Processing node: {}", node_id);
 
                                 // Execute actual calculation based on node type
@@ -267,6 +398,8 @@ impl ReactiveEngine {
                                     &curve_builder,
                                     &pricing_router,
                                     &bond_cache,
+                                    &overrides,
+                                    &portfolios,
                                     settlement_date,
                                 );
 
@@ -282,6 +415,10 @@ impl ReactiveEngine {
                                 });
                             }
                         }
+
+                        for trigger in pending_eod.drain(..) {
+                            eod_scheduler.read().run_actions(&trigger);
+                        }
                     }
                     _ = shutdown_rx.recv() => {
                         info!("Processing loop shutting down");
@@ -293,6 +430,7 @@ impl ReactiveEngine {
     }
 
     /// Calculate a node's value based on its type.
+    #[allow(clippy::too_many_arguments)]
     fn calculate_node(
         node_id: &NodeId,
         calc_graph: &Arc<CalculationGraph>,
@@ -301,6 +439,8 @@ impl ReactiveEngine {
         bond_cache: &Arc<
             dashmap::DashMap<InstrumentId, crate::ports::reference_data::BondReferenceData>,
         >,
+        overrides: &dashmap::DashMap<InstrumentId, PriceOverride>,
+        portfolios: &dashmap::DashMap<PortfolioId, PortfolioState>,
         settlement_date: Date,
     ) -> NodeValue {
         match node_id {
@@ -310,6 +450,7 @@ impl ReactiveEngine {
                 curve_builder,
                 pricing_router,
                 bond_cache,
+                overrides,
                 settlement_date,
             ),
             NodeId::Curve { curve_id } => Self::calculate_curve(curve_id, curve_builder),
@@ -324,10 +465,8 @@ impl ReactiveEngine {
                 debug!("ETF NAV calculation not yet fully implemented");
                 NodeValue::Empty
             }
-            NodeId::Portfolio { portfolio_id: _ } => {
-                // Portfolio aggregation
-                debug!("Portfolio calculation not yet fully implemented");
-                NodeValue::Empty
+            NodeId::Portfolio { portfolio_id } => {
+                Self::calculate_portfolio(portfolio_id, calc_graph, portfolios, settlement_date)
             }
             // Input nodes don't need calculation - their values are set directly
             NodeId::Quote { .. }
@@ -347,7 +486,7 @@ impl ReactiveEngine {
         }
     }
 
-    /// Calculate bond price using PricingRouter.
+    /// Calculate bond price using PricingRouter, applying any active override.
     fn calculate_bond_price(
         instrument_id: &InstrumentId,
         calc_graph: &Arc<CalculationGraph>,
@@ -356,6 +495,7 @@ impl ReactiveEngine {
         bond_cache: &Arc<
             dashmap::DashMap<InstrumentId, crate::ports::reference_data::BondReferenceData>,
         >,
+        overrides: &dashmap::DashMap<InstrumentId, PriceOverride>,
         settlement_date: Date,
     ) -> NodeValue {
         // Get bond reference data from local cache
@@ -405,7 +545,9 @@ impl ReactiveEngine {
         };
 
         // Execute pricing
-        match pricing_router.price(&input) {
+        let price_override = overrides.get(instrument_id).map(|o| o.clone());
+        let now = chrono::Utc::now().timestamp_millis();
+        match pricing_router.price_with_override(input, price_override.as_ref(), now) {
             Ok(output) => {
                 debug!(
                     "Priced bond {}: clean={:?}, ytm={:?}",
@@ -422,6 +564,7 @@ impl ReactiveEngine {
                     z_spread_mid: output.z_spread_mid,
                     modified_duration: output.modified_duration,
                     dv01: output.dv01,
+                    override_id: output.override_id,
                 }
             }
             Err(e) => {
@@ -431,6 +574,74 @@ impl ReactiveEngine {
         }
     }
 
+    /// Update a portfolio's aggregates from its holdings' bond prices.
+    ///
+    /// Only holdings whose price node was recalculated since the last run are
+    /// revisited: their old contribution is backed out and the new one added.
+    fn calculate_portfolio(
+        portfolio_id: &PortfolioId,
+        calc_graph: &Arc<CalculationGraph>,
+        portfolios: &dashmap::DashMap<PortfolioId, PortfolioState>,
+        settlement_date: Date,
+    ) -> NodeValue {
+        let Some(mut state) = portfolios.get_mut(portfolio_id) else {
+            warn!("Portfolio {} is not registered", portfolio_id);
+            return NodeValue::Empty;
+        };
+        let state = &mut *state;
+        let analyzer = PortfolioAnalyzer::new();
+
+        let mut seen = HashSet::new();
+        let instruments: Vec<_> = state
+            .portfolio
+            .positions
+            .iter()
+            .map(|p| p.instrument_id.clone())
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+
+        for instrument_id in instruments {
+            let cached = calc_graph.get_cached(&NodeId::BondPrice {
+                instrument_id: instrument_id.clone(),
+            });
+            let calculated_at = cached.as_ref().map(|c| c.calculated_at);
+            if calculated_at == state.applied.get(&instrument_id).map(|(at, _)| *at) {
+                continue;
+            }
+
+            let new = cached.and_then(|c| {
+                holding_quote(
+                    &instrument_id,
+                    &c.value,
+                    state.portfolio.currency,
+                    settlement_date,
+                )
+            });
+            let old = state.applied.remove(&instrument_id).map(|(_, q)| q);
+            analyzer.update_holding(
+                &mut state.aggregates,
+                &state.portfolio,
+                &instrument_id,
+                old.as_ref(),
+                new.as_ref(),
+            );
+            if let (Some(at), Some(quote)) = (calculated_at, new) {
+                state.applied.insert(instrument_id, (at, quote));
+            }
+        }
+
+        match analyzer.output(&state.portfolio, &state.aggregates) {
+            Ok(output) => NodeValue::Portfolio {
+                market_value: output.market_value,
+                duration: output.duration,
+            },
+            Err(e) => {
+                error!("Failed to aggregate portfolio {}: {}", portfolio_id, e);
+                NodeValue::Empty
+            }
+        }
+    }
+
     /// Look up discount curve based on bond reference data.
     ///
     /// Uses currency and issuer type to determine the appropriate curve:
@@ -596,8 +807,31 @@ impl ReactiveEngine {
         }
     }
 
+    /// Register an action to run daily at an EOD time ("HH:MM:SS", local),
+    /// after the processing loop has recalculated every dirty node.
+    pub fn register_eod_action(&self, time: &str, action: crate::scheduler::EodAction) {
+        self.eod_scheduler.read().register_action(time, action);
+    }
+
+    /// Run an EOD time now for `business_date`, as if it had been reached:
+    /// its nodes are recalculated, then its actions run.
+    pub fn trigger_eod(&self, time: &str, business_date: Date) {
+        self.eod_scheduler.read().trigger(time, business_date);
+    }
+
     /// Register a bond for reactive pricing.
+    ///
+    /// If the bond's reference data is cached, its node is routed by the
+    /// bond's currency under currency-based sharding.
     pub fn register_bond(&self, instrument_id: InstrumentId, config: NodeConfig) {
+        if let Some(bond) = self.bond_cache.get(&instrument_id) {
+            self.calc_graph.set_node_currency(
+                NodeId::BondPrice {
+                    instrument_id: instrument_id.clone(),
+                },
+                bond.currency,
+            );
+        }
         let node_id = NodeId::BondPrice { instrument_id };
         self.register_node(node_id, config);
     }
@@ -615,9 +849,33 @@ impl ReactiveEngine {
     }
 
     /// Register a portfolio for analytics.
-    pub fn register_portfolio(&self, portfolio_id: PortfolioId) {
-        let node_id = NodeId::Portfolio { portfolio_id };
+    ///
+    /// The portfolio node depends on the price node of each holding, so a
+    /// repriced bond marks it dirty and the recalculation updates only that
+    /// holding's contribution. Registering again replaces the holdings.
+    pub fn register_portfolio(&self, portfolio: Portfolio) -> Result<(), EngineError> {
+        let node_id = NodeId::Portfolio {
+            portfolio_id: portfolio.portfolio_id.clone(),
+        };
+        let deps = portfolio
+            .positions
+            .iter()
+            .map(|position| NodeId::BondPrice {
+                instrument_id: position.instrument_id.clone(),
+            })
+            .collect();
+        self.calc_graph.add_node(node_id.clone(), deps)?;
+
+        self.portfolios.insert(
+            portfolio.portfolio_id.clone(),
+            PortfolioState {
+                portfolio,
+                aggregates: PortfolioAggregates::default(),
+                applied: HashMap::new(),
+            },
+        );
         self.register_node(node_id, NodeConfig::portfolio());
+        Ok(())
     }
 
     /// Force recalculation of a node (for on-demand).
@@ -648,6 +906,85 @@ impl ReactiveEngine {
     }
 }
 
+/// A holding's bond price node value as the quote portfolio analytics
+/// aggregate. `None` unless the node holds a bond price.
+fn holding_quote(
+    instrument_id: &InstrumentId,
+    value: &NodeValue,
+    currency: convex_core::Currency,
+    settlement_date: Date,
+) -> Option<BondQuoteOutput> {
+    let NodeValue::BondPrice {
+        clean_price_bid,
+        clean_price_mid,
+        clean_price_ask,
+        accrued_interest,
+        ytm_bid,
+        ytm_mid,
+        ytm_ask,
+        z_spread_mid,
+        modified_duration,
+        dv01,
+        override_id,
+    } = value
+    else {
+        return None;
+    };
+
+    Some(BondQuoteOutput {
+        instrument_id: instrument_id.clone(),
+        isin: None,
+        currency,
+        settlement_date,
+        clean_price_bid: *clean_price_bid,
+        clean_price_mid: *clean_price_mid,
+        clean_price_ask: *clean_price_ask,
+        accrued_interest: *accrued_interest,
+        ytm_bid: *ytm_bid,
+        ytm_mid: *ytm_mid,
+        ytm_ask: *ytm_ask,
+        ytw: None,
+        ytc: None,
+        z_spread_bid: None,
+        z_spread_mid: *z_spread_mid,
+        z_spread_ask: None,
+        i_spread_bid: None,
+        i_spread_mid: None,
+        i_spread_ask: None,
+        g_spread_bid: None,
+        g_spread_mid: None,
+        g_spread_ask: None,
+        asw_bid: None,
+        asw_mid: None,
+        asw_ask: None,
+        oas_bid: None,
+        oas_mid: None,
+        oas_ask: None,
+        discount_margin_bid: None,
+        discount_margin_mid: None,
+        discount_margin_ask: None,
+        simple_margin_bid: None,
+        simple_margin_mid: None,
+        simple_margin_ask: None,
+        modified_duration: *modified_duration,
+        macaulay_duration: None,
+        effective_duration: None,
+        spread_duration: None,
+        convexity: None,
+        effective_convexity: None,
+        dv01: *dv01,
+        pv01: None,
+        key_rate_durations: None,
+        cs01: None,
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        pricing_spec: "reactive".to_string(),
+        source: "calc_graph".to_string(),
+        is_stale: false,
+        quality: 100,
+        override_id: override_id.clone(),
+    })
+}
+
 // =============================================================================
 // REACTIVE ENGINE BUILDER
 // =============================================================================
@@ -725,16 +1062,22 @@ impl Default for ReactiveEngineBuilder {
 mod tests {
     use super::*;
     use crate::ports::market_data::MarketDataProvider;
+    use crate::ports::reference_data::{BondReferenceData, BondType, IssuerType};
+    use convex_core::Currency;
     use convex_ext_file::{
         EmptyBondReferenceSource, EmptyCurveInputSource, EmptyEtfHoldingsSource,
         EmptyEtfQuoteSource, EmptyFxRateSource, EmptyIndexFixingSource, EmptyInflationFixingSource,
         EmptyIssuerReferenceSource, EmptyQuoteSource, EmptyRatingSource, EmptyVolatilitySource,
     };
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
     use std::sync::Arc;
 
     fn create_test_engine() -> ReactiveEngine {
-        let calc_graph = Arc::new(CalculationGraph::new());
+        create_test_engine_with_graph(Arc::new(CalculationGraph::new()))
+    }
 
+    fn create_test_engine_with_graph(calc_graph: Arc<CalculationGraph>) -> ReactiveEngine {
         let market_data = Arc::new(MarketDataProvider {
             quotes: Arc::new(EmptyQuoteSource),
             curve_inputs: Arc::new(EmptyCurveInputSource),
@@ -758,6 +1101,43 @@ mod tests {
         ReactiveEngine::new(calc_graph, curve_builder, pricing_router, reference_data)
     }
 
+    fn create_test_bond_ref(instrument_id: &InstrumentId) -> BondReferenceData {
+        BondReferenceData {
+            instrument_id: instrument_id.clone(),
+            isin: Some("US912810TD00".to_string()),
+            cusip: Some("912810TD0".to_string()),
+            sedol: None,
+            bbgid: None,
+            description: "Test Treasury Bond".to_string(),
+            currency: Currency::USD,
+            issue_date: Date::from_ymd(2020, 1, 15).unwrap(),
+            maturity_date: Date::from_ymd(2030, 1, 15).unwrap(),
+            coupon_rate: Some(dec!(0.025)), // 2.5%
+            frequency: 2,                   // Semi-annual
+            day_count: "ACT/ACT".to_string(),
+            face_value: dec!(100),
+            bond_type: BondType::FixedBullet,
+            issuer_type: IssuerType::Sovereign,
+            issuer_id: "US_TREASURY".to_string(),
+            issuer_name: "US Treasury".to_string(),
+            seniority: "Senior".to_string(),
+            is_callable: false,
+            call_schedule: vec![],
+            is_putable: false,
+            is_sinkable: false,
+            floating_terms: None,
+            inflation_index: None,
+            inflation_base_index: None,
+            has_deflation_floor: false,
+            country_of_risk: "US".to_string(),
+            sector: "Government".to_string(),
+            amount_outstanding: None,
+            first_coupon_date: None,
+            last_updated: 0,
+            source: "test".to_string(),
+        }
+    }
+
     #[test]
     fn test_reactive_engine_creation() {
         let engine = create_test_engine();
@@ -776,6 +1156,49 @@ mod tests {
         assert!(engine.calc_graph().get_node_config(&node_id).is_some());
     }
 
+    #[test]
+    fn test_registered_bonds_shard_by_currency() {
+        use crate::calc_graph::ShardConfig;
+
+        // Each ID hashes to the other currency's shard, so only the bond
+        // currency routes them correctly.
+        let hash = ShardConfig::hash_based(0, 2);
+        let treasury = InstrumentId::new("US91282CJL54");
+        let bund = InstrumentId::new("DE0001102606");
+        assert_eq!(hash.shard_for_key(treasury.as_str()), 1);
+        assert_eq!(hash.shard_for_key(bund.as_str()), 0);
+
+        let map = HashMap::from([("USD".to_string(), 0), ("EUR".to_string(), 1)]);
+        let workers: Vec<ReactiveEngine> = (0..2)
+            .map(|i| {
+                let graph =
+                    CalculationGraph::with_sharding(ShardConfig::by_currency(i, 2, map.clone()));
+                create_test_engine_with_graph(Arc::new(graph))
+            })
+            .collect();
+
+        for engine in &workers {
+            engine.cache_bond_reference(create_test_bond_ref(&treasury));
+            let mut bund_ref = create_test_bond_ref(&bund);
+            bund_ref.currency = Currency::EUR;
+            engine.cache_bond_reference(bund_ref);
+            engine.register_bond(treasury.clone(), NodeConfig::bond_price_liquid());
+            engine.register_bond(bund.clone(), NodeConfig::bond_price_liquid());
+        }
+
+        for (id, expected) in [(&treasury, 0), (&bund, 1)] {
+            let node = NodeId::BondPrice {
+                instrument_id: id.clone(),
+            };
+            let owners: Vec<u32> = workers
+                .iter()
+                .filter(|e| e.calc_graph().owns_node(&node))
+                .map(|e| e.calc_graph().shard_id())
+                .collect();
+            assert_eq!(owners, vec![expected], "{node}");
+        }
+    }
+
     #[tokio::test]
     async fn test_register_etf_inav() {
         let engine = create_test_engine();
@@ -789,49 +1212,111 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_market_data_update_triggers_repricing() {
-        use crate::ports::reference_data::{BondReferenceData, BondType, IssuerType};
-        use convex_core::Currency;
-        use rust_decimal_macros::dec;
+    async fn test_stored_override_prices_bond_node() {
+        let storage = convex_ext_redb::create_memory_storage().unwrap();
+        let engine = create_test_engine().with_override_store(storage.overrides.clone());
+        let instrument_id = InstrumentId::new("TEST_BOND_001");
+        engine.cache_bond_reference(create_test_bond_ref(&instrument_id));
+        engine.register_bond(instrument_id.clone(), NodeConfig::bond_price_liquid());
 
-        let engine = create_test_engine();
+        let now = chrono::Utc::now().timestamp_millis();
+        let mark = PriceOverride {
+            instrument_id: instrument_id.clone(),
+            price: Some(97.25),
+            yield_value: None,
+            spread: None,
+            reason: "Dealer run".to_string(),
+            created_by: "trader".to_string(),
+            created_at: now - 60_000,
+            expires_at: None,
+            is_approved: true,
+            approved_by: Some("risk".to_string()),
+            approved_at: Some(now - 30_000),
+        };
+        storage.overrides.save(&mark).await.unwrap();
+        assert_eq!(engine.refresh_overrides().await.unwrap(), 1);
+
+        let bond_node = NodeId::BondPrice {
+            instrument_id: instrument_id.clone(),
+        };
+        assert!(engine.calc_graph().get_dirty_nodes().contains(&bond_node));
+
+        let result = ReactiveEngine::calculate_bond_price(
+            &instrument_id,
+            engine.calc_graph(),
+            &engine.curve_builder,
+            &engine.pricing_router,
+            &engine.bond_cache,
+            &engine.overrides,
+            engine.settlement_date(),
+        );
+        match result {
+            NodeValue::BondPrice {
+                clean_price_mid,
+                ytm_mid,
+                override_id,
+                ..
+            } => {
+                assert_eq!(clean_price_mid, Some(dec!(97.25)));
+                assert!(ytm_mid.is_some());
+                assert_eq!(override_id, Some(mark.override_id()));
+            }
+            other => panic!("expected BondPrice, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_edited_override_reprices_bond_node() {
+        let storage = convex_ext_redb::create_memory_storage().unwrap();
+        let engine = create_test_engine().with_override_store(storage.overrides.clone());
         let instrument_id = InstrumentId::new("TEST_BOND_001");
+        engine.register_bond(instrument_id.clone(), NodeConfig::bond_price_liquid());
+        let bond_node = NodeId::BondPrice {
+            instrument_id: instrument_id.clone(),
+        };
 
-        // Create and cache bond reference data
-        let bond_ref = BondReferenceData {
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut mark = PriceOverride {
             instrument_id: instrument_id.clone(),
-            isin: Some("US912810TD00".to_string()),
-            cusip: Some("912810TD0".to_string()),
-            sedol: None,
-            bbgid: None,
-            description: "Test Treasury Bond".to_string(),
-            currency: Currency::USD,
-            issue_date: Date::from_ymd(2020, 1, 15).unwrap(),
-            maturity_date: Date::from_ymd(2030, 1, 15).unwrap(),
-            coupon_rate: Some(dec!(0.025)), // 2.5%
-            frequency: 2,                   // Semi-annual
-            day_count: "ACT/ACT".to_string(),
-            face_value: dec!(100),
-            bond_type: BondType::FixedBullet,
-            issuer_type: IssuerType::Sovereign,
-            issuer_id: "US_TREASURY".to_string(),
-            issuer_name: "US Treasury".to_string(),
-            seniority: "Senior".to_string(),
-            is_callable: false,
-            call_schedule: vec![],
-            is_putable: false,
-            is_sinkable: false,
-            floating_terms: None,
-            inflation_index: None,
-            inflation_base_index: None,
-            has_deflation_floor: false,
-            country_of_risk: "US".to_string(),
-            sector: "Government".to_string(),
-            amount_outstanding: None,
-            first_coupon_date: None,
-            last_updated: 0,
-            source: "test".to_string(),
+            price: Some(97.25),
+            yield_value: None,
+            spread: None,
+            reason: "Dealer run".to_string(),
+            created_by: "trader".to_string(),
+            created_at: now - 60_000,
+            expires_at: None,
+            is_approved: true,
+            approved_by: Some("risk".to_string()),
+            approved_at: Some(now - 30_000),
         };
+        storage.overrides.save(&mark).await.unwrap();
+        engine.refresh_overrides().await.unwrap();
+        engine
+            .calc_graph()
+            .update_cache(&bond_node, NodeValue::Empty);
+
+        // Reloading the same override leaves the bond alone.
+        engine.refresh_overrides().await.unwrap();
+        assert!(!engine.calc_graph().is_dirty(&bond_node));
+
+        // Editing the price in place keeps the creation time.
+        mark.price = Some(96.5);
+        storage.overrides.save(&mark).await.unwrap();
+        engine.refresh_overrides().await.unwrap();
+        assert!(engine.calc_graph().is_dirty(&bond_node));
+        assert_eq!(
+            engine.overrides.get(&instrument_id).unwrap().price,
+            Some(96.5)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_market_data_update_triggers_repricing() {
+        let engine = create_test_engine();
+        let instrument_id = InstrumentId::new("TEST_BOND_001");
+
+        // Create and cache bond reference data
+        let bond_ref = create_test_bond_ref(&instrument_id);
 
         engine.cache_bond_reference(bond_ref);
 
@@ -881,6 +1366,7 @@ mod tests {
             &curve_builder,
             &pricing_router,
             &bond_cache,
+            &engine.overrides,
             settlement_date,
         );
 
@@ -916,6 +1402,88 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_throttled_quote_storm_counts_each_recalculation_once() {
+        use crate::market_data_listener::QuoteUpdate;
+
+        let engine = create_test_engine();
+        let instrument_id = InstrumentId::new("STORM_TEST");
+        engine.cache_bond_reference(create_test_bond_ref(&instrument_id));
+
+        let quote_node = NodeId::Quote {
+            instrument_id: instrument_id.clone(),
+        };
+        let bond_node = NodeId::BondPrice {
+            instrument_id: instrument_id.clone(),
+        };
+        let interval = Duration::from_millis(300);
+        engine
+            .calc_graph()
+            .add_node(bond_node.clone(), vec![quote_node])
+            .unwrap();
+        engine.register_bond(
+            instrument_id.clone(),
+            NodeConfig {
+                frequency: UpdateFrequency::Throttled { interval },
+                ..NodeConfig::bond_price_liquid()
+            },
+        );
+        engine.start().await;
+
+        let publish = |mid: i64| {
+            engine.market_data_publisher().publish_quote(QuoteUpdate {
+                instrument_id: instrument_id.clone(),
+                bid: None,
+                ask: None,
+                mid: Some(rust_decimal::Decimal::from(mid)),
+                timestamp: 0,
+            })
+        };
+        let clean_mid = || match engine.calc_graph().get_cached(&bond_node).map(|c| c.value) {
+            Some(NodeValue::BondPrice {
+                clean_price_mid, ..
+            }) => clean_price_mid,
+            _ => None,
+        };
+        let recalcs = || {
+            engine
+                .throttle_manager()
+                .get_stats(&bond_node)
+                .unwrap()
+                .recalcs
+        };
+        let wait_for_recalcs = |n: u64| async move {
+            for _ in 0..100 {
+                if recalcs() >= n {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        };
+
+        // First quote: one recalculation by the processing loop.
+        publish(99).unwrap();
+        wait_for_recalcs(1).await;
+        let stats = engine.throttle_manager().get_stats(&bond_node).unwrap();
+        assert_eq!(stats.recalcs, 1);
+        assert_eq!(clean_mid(), Some(dec!(99)));
+
+        // A storm inside the window coalesces into one more recalculation,
+        // which sees the last quote.
+        for mid in 100..200 {
+            publish(mid).unwrap();
+        }
+        wait_for_recalcs(2).await;
+        tokio::time::sleep(interval).await;
+        let stats = engine.throttle_manager().get_stats(&bond_node).unwrap();
+        assert_eq!(stats.recalcs, 2);
+        assert_eq!(stats.coalesced, 99);
+        assert_eq!(clean_mid(), Some(dec!(199)));
+        assert!(engine.throttle_manager().get_pending().is_empty());
+
+        engine.stop();
+    }
+
     #[tokio::test]
     async fn test_quote_update_propagates_to_bond_price() {
         use rust_decimal_macros::dec;
@@ -934,7 +1502,8 @@ mod tests {
         // Register bond node with dependency on quote
         engine
             .calc_graph()
-            .add_node(bond_node.clone(), vec![quote_node.clone()]);
+            .add_node(bond_node.clone(), vec![quote_node.clone()])
+            .unwrap();
 
         // Nodes start dirty - mark as clean first by updating cache
         engine
@@ -990,7 +1559,8 @@ mod tests {
         // Bond depends on curve
         engine
             .calc_graph()
-            .add_node(bond_node.clone(), vec![curve_node.clone()]);
+            .add_node(bond_node.clone(), vec![curve_node.clone()])
+            .unwrap();
 
         // Nodes start dirty - mark as clean first by updating cache
         engine
@@ -1018,4 +1588,196 @@ mod tests {
             "Bond should be dirty after curve update"
         );
     }
+
+    fn bond_price_value(clean_mid: Decimal, modified_duration: Decimal) -> NodeValue {
+        NodeValue::BondPrice {
+            clean_price_bid: None,
+            clean_price_mid: Some(clean_mid),
+            clean_price_ask: None,
+            accrued_interest: Some(dec!(1.0)),
+            ytm_bid: None,
+            ytm_mid: Some(dec!(0.05)),
+            ytm_ask: None,
+            z_spread_mid: None,
+            modified_duration: Some(modified_duration),
+            dv01: None,
+            override_id: None,
+        }
+    }
+
+    #[test]
+    fn test_holding_price_change_updates_portfolio() {
+        let engine = create_test_engine();
+        let settlement = Date::from_ymd(2025, 1, 15).unwrap();
+        let bonds = [
+            InstrumentId::new("PF_BOND_A"),
+            InstrumentId::new("PF_BOND_B"),
+        ];
+
+        for id in &bonds {
+            engine
+                .calc_graph()
+                .add_node(
+                    NodeId::BondPrice {
+                        instrument_id: id.clone(),
+                    },
+                    vec![NodeId::Quote {
+                        instrument_id: id.clone(),
+                    }],
+                )
+                .unwrap();
+        }
+
+        let portfolio = Portfolio {
+            portfolio_id: PortfolioId::new("PF_TEST"),
+            name: "Test".to_string(),
+            currency: Currency::USD,
+            positions: bonds
+                .iter()
+                .map(|id| crate::portfolio_analytics::Position {
+                    instrument_id: id.clone(),
+                    notional: dec!(1_000_000),
+                    sector: None,
+                    rating: None,
+                })
+                .collect(),
+        };
+        let portfolio_node = NodeId::Portfolio {
+            portfolio_id: portfolio.portfolio_id.clone(),
+        };
+        engine.register_portfolio(portfolio.clone()).unwrap();
+
+        let graph = engine.calc_graph();
+        let price_node = |id: &InstrumentId| NodeId::BondPrice {
+            instrument_id: id.clone(),
+        };
+        graph.update_cache(&price_node(&bonds[0]), bond_price_value(dec!(99), dec!(4)));
+        graph.update_cache(&price_node(&bonds[1]), bond_price_value(dec!(101), dec!(8)));
+
+        // Recompute the portfolio from scratch over the cached holding prices.
+        let full = || {
+            let quotes: Vec<_> = bonds
+                .iter()
+                .filter_map(|id| {
+                    let cached = graph.get_cached(&price_node(id))?;
+                    holding_quote(id, &cached.value, Currency::USD, settlement)
+                })
+                .collect();
+            PortfolioAnalyzer::new()
+                .calculate(&portfolio, &quotes)
+                .unwrap()
+        };
+        let assert_matches_full = |value: NodeValue| {
+            let expected = full();
+            match value {
+                NodeValue::Portfolio {
+                    market_value,
+                    duration,
+                } => {
+                    assert_eq!(market_value, expected.market_value);
+                    assert_eq!(duration, expected.duration);
+                }
+                other => panic!("expected portfolio value, got {:?}", other),
+            }
+        };
+
+        let value = ReactiveEngine::calculate_portfolio(
+            &portfolio.portfolio_id,
+            graph,
+            &engine.portfolios,
+            settlement,
+        );
+        graph.update_cache(&portfolio_node, value.clone());
+        assert_matches_full(value);
+        assert!(!graph.is_dirty(&portfolio_node));
+
+        // A new quote on one holding reaches the portfolio through its price.
+        graph.invalidate(&NodeId::Quote {
+            instrument_id: bonds[1].clone(),
+        });
+        assert!(graph.is_dirty(&portfolio_node));
+
+        graph.update_cache(&price_node(&bonds[1]), bond_price_value(dec!(95), dec!(7)));
+        let value = ReactiveEngine::calculate_portfolio(
+            &portfolio.portfolio_id,
+            graph,
+            &engine.portfolios,
+            settlement,
+        );
+        let NodeValue::Portfolio { market_value, .. } = &value else {
+            panic!("expected portfolio value");
+        };
+        // (99 + 1) + (95 + 1) percent of 1mm each.
+        assert_eq!(*market_value, dec!(1_960_000));
+        assert_matches_full(value);
+    }
+
+    #[tokio::test]
+    async fn test_eod_snapshot_runs_after_recalculation() {
+        use crate::market_data_listener::QuoteUpdate;
+
+        let storage = Arc::new(convex_ext_redb::create_memory_storage().unwrap());
+        let engine = create_test_engine().with_eod_snapshots(storage.clone(), "16:00:00");
+        let instrument_id = InstrumentId::new("EOD_TEST");
+        engine.cache_bond_reference(create_test_bond_ref(&instrument_id));
+
+        let bond_node = NodeId::BondPrice {
+            instrument_id: instrument_id.clone(),
+        };
+        engine
+            .calc_graph()
+            .add_node(
+                bond_node.clone(),
+                vec![NodeId::Quote {
+                    instrument_id: instrument_id.clone(),
+                }],
+            )
+            .unwrap();
+        engine.register_bond(
+            instrument_id.clone(),
+            NodeConfig {
+                frequency: UpdateFrequency::EndOfDay {
+                    time: "16:00:00".to_string(),
+                },
+                ..NodeConfig::bond_price_liquid()
+            },
+        );
+        engine.start().await;
+
+        engine
+            .market_data_publisher()
+            .publish_quote(QuoteUpdate {
+                instrument_id: instrument_id.clone(),
+                bid: None,
+                ask: None,
+                mid: Some(dec!(99)),
+                timestamp: 0,
+            })
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        // End-of-day nodes wait for their time.
+        assert!(engine.calc_graph().is_dirty(&bond_node));
+
+        let business_date = Date::from_ymd(2025, 3, 14).unwrap();
+        engine.trigger_eod("16:00:00", business_date);
+
+        let as_of = crate::eod::business_date_millis(business_date);
+        let mut snapshot = None;
+        for _ in 0..100 {
+            snapshot = storage
+                .bonds
+                .get_snapshot(&instrument_id, as_of)
+                .await
+                .unwrap();
+            if snapshot.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        let snapshot = snapshot.expect("EOD snapshot saved the bond");
+        assert_eq!(snapshot.business_date, business_date);
+        assert_eq!(snapshot.clean_price_mid, Some(dec!(99)));
+
+        engine.stop();
+    }
 }
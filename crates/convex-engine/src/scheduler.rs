@@ -14,6 +14,8 @@ use tokio::sync::broadcast;
 use tokio::time::{interval, Instant};
 use tracing::{debug, info};
 
+use convex_core::Date;
+
 use crate::calc_graph::{CalculationGraph, NodeId};
 
 // =============================================================================
@@ -179,20 +181,43 @@ impl IntervalScheduler {
 // EOD SCHEDULER
 // =============================================================================
 
+/// Action run at an EOD time, with the business date.
+pub type EodAction = Arc<dyn Fn(Date) + Send + Sync>;
+
+/// An EOD time being reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EodTrigger {
+    /// EOD time ("HH:MM:SS")
+    pub time: String,
+    /// Local business date
+    pub business_date: Date,
+}
+
 /// Manages end-of-day calculations.
 ///
 /// Nodes registered with the EOD scheduler will be recalculated
 /// once per day at a specified time (e.g., 4:00 PM for official NAV).
+/// Actions registered for a time, such as the
+/// [`EodSnapshotter`](crate::eod::EodSnapshotter), run once those nodes
+/// are recalculated: the scheduler only invalidates them and publishes an
+/// [`EodTrigger`], and the calculation loop calls
+/// [`run_actions`](Self::run_actions) after its next pass.
 pub struct EodScheduler {
     /// Nodes grouped by EOD time
     eod_nodes: DashMap<String, Vec<NodeId>>,
 
+    /// Actions grouped by EOD time
+    actions: Arc<DashMap<String, Vec<EodAction>>>,
+
     /// Calculation graph reference
     calc_graph: Arc<CalculationGraph>,
 
     /// Update notification channel
     update_tx: broadcast::Sender<NodeUpdate>,
 
+    /// EOD trigger channel
+    trigger_tx: broadcast::Sender<EodTrigger>,
+
     /// Shutdown signal
     shutdown_tx: Option<broadcast::Sender<()>>,
 }
@@ -201,10 +226,13 @@ impl EodScheduler {
     /// Create a new EOD scheduler.
     pub fn new(calc_graph: Arc<CalculationGraph>) -> Self {
         let (update_tx, _) = broadcast::channel(1000);
+        let (trigger_tx, _) = broadcast::channel(16);
         Self {
             eod_nodes: DashMap::new(),
+            actions: Arc::new(DashMap::new()),
             calc_graph,
             update_tx,
+            trigger_tx,
             shutdown_tx: None,
         }
     }
@@ -214,6 +242,11 @@ impl EodScheduler {
         self.update_tx.subscribe()
     }
 
+    /// Subscribe to EOD triggers.
+    pub fn subscribe_triggers(&self) -> broadcast::Receiver<EodTrigger> {
+        self.trigger_tx.subscribe()
+    }
+
     /// Register a node for EOD updates at specified time.
     ///
     /// # Arguments
@@ -228,6 +261,37 @@ impl EodScheduler {
         info!("Registered node {} for EOD at {}", node_id, time);
     }
 
+    /// Register an action to run daily at the specified time, after the
+    /// nodes due then are recalculated.
+    ///
+    /// # Arguments
+    /// * `time` - Time string in "HH:MM:SS" format (local timezone)
+    /// * `action` - Called with the local business date
+    pub fn register_action(&self, time: &str, action: EodAction) {
+        self.actions
+            .entry(time.to_string())
+            .or_default()
+            .push(action);
+
+        info!("Registered EOD action at {}", time);
+    }
+
+    /// Run the actions registered for a trigger's time.
+    ///
+    /// Call once the nodes invalidated by the trigger are recalculated.
+    pub fn run_actions(&self, trigger: &EodTrigger) {
+        if let Some(actions) = self.actions.get(&trigger.time) {
+            info!(
+                "Running {} EOD actions for {}",
+                actions.len(),
+                trigger.business_date
+            );
+            for action in actions.iter() {
+                action(trigger.business_date);
+            }
+        }
+    }
+
     /// Unregister a node from EOD updates.
     pub fn unregister(&self, node_id: &NodeId) {
         for mut entry in self.eod_nodes.iter_mut() {
@@ -239,7 +303,9 @@ impl EodScheduler {
     pub fn start(&mut self) {
         let calc_graph = self.calc_graph.clone();
         let eod_nodes = self.eod_nodes.clone();
+        let actions = self.actions.clone();
         let update_tx = self.update_tx.clone();
+        let trigger_tx = self.trigger_tx.clone();
 
         let (shutdown_tx, mut shutdown_rx) = broadcast::channel::<()>(1);
         self.shutdown_tx = Some(shutdown_tx);
@@ -254,25 +320,15 @@ impl EodScheduler {
                         let now = Local::now();
                         let current_time = format!("{:02}:{:02}:00", now.hour(), now.minute());
 
-                        // Check if any EOD time matches
-                        if let Some(nodes) = eod_nodes.get(&current_time) {
-                            info!("EOD trigger at {}", current_time);
-                            let timestamp = chrono::Utc::now().timestamp();
-
-                            for node_id in nodes.iter() {
-                                debug!("EOD scheduler processing: {}", node_id);
-
-                                // Mark as dirty to trigger recalculation
-                                calc_graph.invalidate(node_id);
-
-                                // Notify subscribers
-                                let _ = update_tx.send(NodeUpdate {
-                                    node_id: node_id.clone(),
-                                    timestamp,
-                                    source: UpdateSource::EndOfDay,
-                                });
-                            }
-                        }
+                        Self::fire(
+                            &calc_graph,
+                            &eod_nodes,
+                            &actions,
+                            &update_tx,
+                            &trigger_tx,
+                            current_time,
+                            Date::from(now.date_naive()),
+                        );
                     }
                     _ = shutdown_rx.recv() => {
                         info!("EOD scheduler shutting down");
@@ -285,6 +341,57 @@ impl EodScheduler {
         info!("EOD scheduler started");
     }
 
+    /// Fire an EOD time now for `business_date`, as the timer does when the
+    /// time is reached: its nodes are invalidated and an [`EodTrigger`] is
+    /// published so the calculation loop recalculates, then runs actions.
+    pub fn trigger(&self, time: &str, business_date: Date) {
+        Self::fire(
+            &self.calc_graph,
+            &self.eod_nodes,
+            &self.actions,
+            &self.update_tx,
+            &self.trigger_tx,
+            time.to_string(),
+            business_date,
+        );
+    }
+
+    fn fire(
+        calc_graph: &CalculationGraph,
+        eod_nodes: &DashMap<String, Vec<NodeId>>,
+        actions: &DashMap<String, Vec<EodAction>>,
+        update_tx: &broadcast::Sender<NodeUpdate>,
+        trigger_tx: &broadcast::Sender<EodTrigger>,
+        time: String,
+        business_date: Date,
+    ) {
+        if let Some(nodes) = eod_nodes.get(&time) {
+            info!("EOD trigger at {}", time);
+            let timestamp = chrono::Utc::now().timestamp();
+
+            for node_id in nodes.iter() {
+                debug!("EOD scheduler processing: {}", node_id);
+
+                // Mark as dirty to trigger recalculation
+                calc_graph.invalidate(node_id);
+
+                // Notify subscribers
+                let _ = update_tx.send(NodeUpdate {
+                    node_id: node_id.clone(),
+                    timestamp,
+                    source: UpdateSource::EndOfDay,
+                });
+            }
+        }
+
+        if eod_nodes.contains_key(&time) || actions.contains_key(&time) {
+            let _ = trigger_tx.send(EodTrigger {
+                time,
+                business_date,
+            });
+        }
+    }
+
     /// Stop the EOD scheduler.
     pub fn stop(&self) {
         if let Some(ref tx) = self.shutdown_tx {
@@ -294,7 +401,15 @@ impl EodScheduler {
 
     /// Get all registered EOD times.
     pub fn get_times(&self) -> Vec<String> {
-        self.eod_nodes.iter().map(|e| e.key().clone()).collect()
+        let mut times: Vec<String> = self
+            .eod_nodes
+            .iter()
+            .map(|e| e.key().clone())
+            .chain(self.actions.iter().map(|e| e.key().clone()))
+            .collect();
+        times.sort();
+        times.dedup();
+        times
     }
 
     /// Get nodes for a specific EOD time.
@@ -334,20 +449,57 @@ impl EodScheduler {
 // THROTTLE MANAGER
 // =============================================================================
 
+/// Recalculation counters for a throttled node.
+#[derive(Debug, Clone, Copy)]
+pub struct ThrottleStats {
+    /// Recalculations the processing loop actually ran.
+    pub recalcs: u64,
+    /// Invalidations folded into an already pending recalculation.
+    pub coalesced: u64,
+    /// When the node was registered.
+    pub since: Instant,
+}
+
+impl ThrottleStats {
+    fn new() -> Self {
+        Self {
+            recalcs: 0,
+            coalesced: 0,
+            since: Instant::now(),
+        }
+    }
+
+    /// Effective recalculations per second since registration.
+    pub fn recalc_rate(&self) -> f64 {
+        let elapsed = self.since.elapsed().as_secs_f64();
+        if elapsed > 0.0 {
+            self.recalcs as f64 / elapsed
+        } else {
+            0.0
+        }
+    }
+}
+
 /// Manages throttled (debounced) calculations.
 ///
 /// Nodes with throttled frequency will be recalculated at most once
-/// per interval, even if their inputs change more frequently.
+/// per interval, even if their inputs change more frequently. Invalidations
+/// arriving inside the interval coalesce: the first schedules a single
+/// recalculation for when the interval expires and the rest are absorbed
+/// into it, so the recalculation reads whatever inputs are latest by then.
 pub struct ThrottleManager {
     /// Last calculation time per node
-    last_calc_time: DashMap<NodeId, Instant>,
+    last_calc_time: Arc<DashMap<NodeId, Instant>>,
 
     /// Pending throttled nodes (waiting for interval)
-    pending: DashSet<NodeId>,
+    pending: Arc<DashSet<NodeId>>,
 
     /// Throttle intervals per node
     intervals: DashMap<NodeId, Duration>,
 
+    /// Recalculation counters per registered node
+    stats: Arc<DashMap<NodeId, ThrottleStats>>,
+
     /// Calculation graph reference
     calc_graph: Arc<CalculationGraph>,
 
@@ -360,9 +512,10 @@ impl ThrottleManager {
     pub fn new(calc_graph: Arc<CalculationGraph>) -> Self {
         let (update_tx, _) = broadcast::channel(1000);
         Self {
-            last_calc_time: DashMap::new(),
-            pending: DashSet::new(),
+            last_calc_time: Arc::new(DashMap::new()),
+            pending: Arc::new(DashSet::new()),
             intervals: DashMap::new(),
+            stats: Arc::new(DashMap::new()),
             calc_graph,
             update_tx,
         }
@@ -376,6 +529,7 @@ impl ThrottleManager {
     /// Register a node for throttled updates.
     pub fn register(&self, node_id: NodeId, interval: Duration) {
         self.intervals.insert(node_id.clone(), interval);
+        self.stats.insert(node_id.clone(), ThrottleStats::new());
         debug!(
             "Registered node {} for throttled updates (interval: {:?})",
             node_id, interval
@@ -387,6 +541,7 @@ impl ThrottleManager {
         self.intervals.remove(node_id);
         self.pending.remove(node_id);
         self.last_calc_time.remove(node_id);
+        self.stats.remove(node_id);
     }
 
     /// Check if a node should be calculated now (respecting throttle).
@@ -403,51 +558,65 @@ impl ThrottleManager {
     }
 
     /// Mark a node as calculated.
+    ///
+    /// Call once per real recomputation, after it completes: this starts the
+    /// throttle window and is the only place recalculations are counted.
     pub fn mark_calculated(&self, node_id: &NodeId) {
         self.last_calc_time.insert(node_id.clone(), Instant::now());
+        if let Some(mut stats) = self.stats.get_mut(node_id) {
+            stats.recalcs += 1;
+        }
         self.pending.remove(node_id);
     }
 
     /// Schedule a throttled calculation for a node.
     ///
     /// If the throttle interval hasn't passed, the node is added to pending
-    /// and will be calculated when the interval expires.
+    /// and invalidated when the interval expires, for the processing loop to
+    /// recalculate. Further calls while it is pending are coalesced into that
+    /// one calculation.
     pub fn schedule(&self, node_id: NodeId) {
         if !self.should_calculate(&node_id) {
-            // Add to pending if not already
-            if self.pending.insert(node_id.clone()) {
-                // Spawn task to trigger after interval
-                let calc_graph = self.calc_graph.clone();
-                let update_tx = self.update_tx.clone();
-                let pending = self.pending.clone();
-                let last_calc_time = self.last_calc_time.clone();
-
-                if let Some(interval) = self.intervals.get(&node_id).map(|i| *i) {
-                    let node_id_clone = node_id.clone();
-                    tokio::spawn(async move {
-                        tokio::time::sleep(interval).await;
-
-                        if pending.remove(&node_id_clone).is_some() {
-                            debug!("Throttle expired, triggering: {}", node_id_clone);
-
-                            // Mark as dirty
-                            calc_graph.invalidate(&node_id_clone);
-                            last_calc_time.insert(node_id_clone.clone(), Instant::now());
-
-                            // Notify
-                            let _ = update_tx.send(NodeUpdate {
-                                node_id: node_id_clone,
-                                timestamp: chrono::Utc::now().timestamp(),
-                                source: UpdateSource::Throttled,
-                            });
-                        }
-                    });
+            if !self.pending.insert(node_id.clone()) {
+                if let Some(mut stats) = self.stats.get_mut(&node_id) {
+                    stats.coalesced += 1;
                 }
+                return;
             }
-        } else {
-            // Can calculate immediately
-            self.mark_calculated(&node_id);
 
+            // Spawn task to trigger when the interval since the last
+            // calculation expires
+            let calc_graph = self.calc_graph.clone();
+            let update_tx = self.update_tx.clone();
+            let pending = self.pending.clone();
+
+            if let Some(interval) = self.intervals.get(&node_id).map(|i| *i) {
+                let elapsed = self
+                    .last_calc_time
+                    .get(&node_id)
+                    .map(|t| t.elapsed())
+                    .unwrap_or(interval);
+                let wait = interval.saturating_sub(elapsed);
+                tokio::spawn(async move {
+                    tokio::time::sleep(wait).await;
+
+                    if pending.remove(&node_id).is_some() {
+                        debug!("Throttle expired, triggering: {}", node_id);
+
+                        // Mark as dirty
+                        calc_graph.invalidate(&node_id);
+
+                        // Notify
+                        let _ = update_tx.send(NodeUpdate {
+                            node_id,
+                            timestamp: chrono::Utc::now().timestamp(),
+                            source: UpdateSource::Throttled,
+                        });
+                    }
+                });
+            }
+        } else {
+            // Can calculate immediately; the processing loop picks it up
             let _ = self.update_tx.send(NodeUpdate {
                 node_id,
                 timestamp: chrono::Utc::now().timestamp(),
@@ -465,6 +634,16 @@ impl ThrottleManager {
     pub fn get_interval(&self, node_id: &NodeId) -> Option<Duration> {
         self.intervals.get(node_id).map(|i| *i)
     }
+
+    /// Get the recalculation counters for a registered node.
+    pub fn get_stats(&self, node_id: &NodeId) -> Option<ThrottleStats> {
+        self.stats.get(node_id).map(|s| *s)
+    }
+
+    /// Get the effective recalculation rate (per second) for a registered node.
+    pub fn recalc_rate(&self, node_id: &NodeId) -> Option<f64> {
+        self.get_stats(node_id).map(|s| s.recalc_rate())
+    }
 }
 
 // =============================================================================
@@ -687,6 +866,27 @@ mod tests {
         assert_eq!(nodes[0], node_id);
     }
 
+    #[test]
+    fn test_eod_run_actions_only_for_trigger_time() {
+        let calc_graph = Arc::new(CalculationGraph::new());
+        let scheduler = EodScheduler::new(calc_graph);
+
+        let ran = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        for time in ["16:00:00", "17:30:00"] {
+            let ran = ran.clone();
+            scheduler.register_action(time, Arc::new(move |date| ran.lock().push((time, date))));
+        }
+
+        let business_date = Date::from_ymd(2025, 3, 14).unwrap();
+        scheduler.run_actions(&EodTrigger {
+            time: "17:30:00".to_string(),
+            business_date,
+        });
+
+        assert_eq!(*ran.lock(), vec![("17:30:00", business_date)]);
+        assert_eq!(scheduler.get_times(), vec!["16:00:00", "17:30:00"]);
+    }
+
     #[test]
     fn test_throttle_manager() {
         let calc_graph = Arc::new(CalculationGraph::new());
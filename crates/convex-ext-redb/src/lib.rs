@@ -22,13 +22,14 @@ use convex_core::ids::{CurveId, InstrumentId};
 use convex_ports::error::TraitError;
 use convex_ports::reference_data::BondReferenceData;
 use convex_ports::storage::{
-    AuditEntry, AuditFilter, AuditStore, BondFilter, BondPricingConfig, BondStore, ConfigStore,
-    ConfigVersion, CurveConfig, CurveSnapshot, CurveStore, OverrideAudit, OverrideStore, Page,
-    Pagination, PriceOverride, StorageAdapter,
+    AuditEntry, AuditFilter, AuditStore, BondFilter, BondPricingConfig, BondSnapshot, BondStore,
+    ConfigStore, ConfigVersion, CurveConfig, CurveSnapshot, CurveStore, OverrideAudit,
+    OverrideStore, Page, Pagination, PriceOverride, StorageAdapter,
 };
 
 // Table definitions
 const BONDS: TableDefinition<&str, &[u8]> = TableDefinition::new("bonds");
+const BOND_SNAPSHOTS: TableDefinition<(&str, i64), &[u8]> = TableDefinition::new("bond_snapshots");
 const CURVE_CONFIGS: TableDefinition<&str, &[u8]> = TableDefinition::new("curve_configs");
 const CURVE_SNAPSHOTS: TableDefinition<(&str, i64), &[u8]> =
     TableDefinition::new("curve_snapshots");
@@ -228,6 +229,89 @@ impl BondStore for RedbBondStore {
             .await?;
         Ok(page.items)
     }
+
+    async fn save_snapshot(&self, snapshot: &BondSnapshot) -> Result<(), TraitError> {
+        let write_txn = self
+            .db
+            .begin_write()
+            .map_err(|e| TraitError::DatabaseError(e.to_string()))?;
+        {
+            let mut table = write_txn
+                .open_table(BOND_SNAPSHOTS)
+                .map_err(|e| TraitError::DatabaseError(e.to_string()))?;
+
+            let bytes = serde_json::to_vec(snapshot)
+                .map_err(|e| TraitError::SerializationError(e.to_string()))?;
+
+            table
+                .insert(
+                    (snapshot.instrument_id.as_str(), snapshot.as_of),
+                    bytes.as_slice(),
+                )
+                .map_err(|e| TraitError::DatabaseError(e.to_string()))?;
+        }
+        write_txn
+            .commit()
+            .map_err(|e| TraitError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn get_snapshot(
+        &self,
+        id: &InstrumentId,
+        as_of: i64,
+    ) -> Result<Option<BondSnapshot>, TraitError> {
+        let read_txn = self
+            .db
+            .begin_read()
+            .map_err(|e| TraitError::DatabaseError(e.to_string()))?;
+
+        let table = match read_txn.open_table(BOND_SNAPSHOTS) {
+            Ok(t) => t,
+            Err(redb::TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(e) => return Err(TraitError::DatabaseError(e.to_string())),
+        };
+
+        match table.get((id.as_str(), as_of)) {
+            Ok(Some(data)) => {
+                let snapshot: BondSnapshot = serde_json::from_slice(data.value())
+                    .map_err(|e| TraitError::ParseError(e.to_string()))?;
+                Ok(Some(snapshot))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(TraitError::DatabaseError(e.to_string())),
+        }
+    }
+
+    async fn list_snapshots(
+        &self,
+        id: &InstrumentId,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<BondSnapshot>, TraitError> {
+        let read_txn = self
+            .db
+            .begin_read()
+            .map_err(|e| TraitError::DatabaseError(e.to_string()))?;
+
+        let table = match read_txn.open_table(BOND_SNAPSHOTS) {
+            Ok(t) => t,
+            Err(redb::TableError::TableDoesNotExist(_)) => return Ok(vec![]),
+            Err(e) => return Err(TraitError::DatabaseError(e.to_string())),
+        };
+
+        let mut snapshots = Vec::new();
+        for result in table
+            .range((id.as_str(), from)..=(id.as_str(), to))
+            .map_err(|e| TraitError::DatabaseError(e.to_string()))?
+        {
+            let (_, value) = result.map_err(|e| TraitError::DatabaseError(e.to_string()))?;
+            let snapshot: BondSnapshot = serde_json::from_slice(value.value())
+                .map_err(|e| TraitError::ParseError(e.to_string()))?;
+            snapshots.push(snapshot);
+        }
+        Ok(snapshots)
+    }
 }
 
 /// Redb-based curve store.
@@ -685,8 +769,7 @@ impl OverrideStore for RedbOverrideStore {
             let override_: PriceOverride = serde_json::from_slice(value.value())
                 .map_err(|e| TraitError::ParseError(e.to_string()))?;
 
-            // Check if active (approved and not expired)
-            if override_.is_approved && override_.expires_at.map(|e| e > now).unwrap_or(true) {
+            if override_.is_active(now) {
                 overrides.push(override_);
             }
         }
@@ -885,3 +968,66 @@ pub fn create_memory_storage() -> Result<StorageAdapter, TraitError> {
 
     create_redb_storage(&db_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(as_of: i64, points: Vec<(u32, f64)>) -> CurveSnapshot {
+        CurveSnapshot {
+            curve_id: CurveId::new("USD_GOVT"),
+            as_of,
+            points,
+            inputs_hash: as_of.to_string(),
+            build_duration_ms: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_curve_at_interpolates_between_stored_snapshots() {
+        let storage = create_memory_storage().unwrap();
+        let id = CurveId::new("USD_GOVT");
+        assert!(storage.curves.curve_at(&id, 0).await.unwrap().is_none());
+
+        let day = 86_400_000;
+        let first = snapshot(10 * day, vec![(365, 0.04), (3650, 0.045)]);
+        let second = snapshot(14 * day, vec![(365, 0.05), (1825, 0.047), (3650, 0.048)]);
+        storage.curves.save_snapshot(&first).await.unwrap();
+        storage.curves.save_snapshot(&second).await.unwrap();
+
+        // A quarter of the way through, discount factors are a quarter of
+        // the way from the first snapshot's to the second's.
+        let curve = storage
+            .curves
+            .curve_at(&id, 11 * day)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(curve.as_of, 11 * day);
+        assert_eq!(curve.points.len(), 3);
+        for days in [365, 1825, 3650] {
+            let expected = 0.75 * first.discount_factor(days) + 0.25 * second.discount_factor(days);
+            assert!((curve.discount_factor(days) - expected).abs() < 1e-12);
+        }
+
+        // On a snapshot, that snapshot; outside the range, the nearest one.
+        let exact = storage
+            .curves
+            .curve_at(&id, 14 * day)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(exact.points, second.points);
+        let before = storage.curves.curve_at(&id, day).await.unwrap().unwrap();
+        assert_eq!(before.as_of, first.as_of);
+        assert_eq!(before.points, first.points);
+        let after = storage
+            .curves
+            .curve_at(&id, 30 * day)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(after.as_of, second.as_of);
+        assert_eq!(after.points, second.points);
+    }
+}
@@ -266,9 +266,10 @@ fn build_zero_coupon(spec: ZeroCouponSpec) -> Handle {
         CoreComp::Quarterly => ZcbComp::Quarterly,
         CoreComp::Monthly => ZcbComp::Monthly,
         CoreComp::Continuous => ZcbComp::Continuous,
-        // Simple/Daily aren't on the bond builder; nearest fallback.
+        // Simple/Daily/other periodic counts aren't on the bond builder;
+        // nearest fallback.
         CoreComp::Simple => ZcbComp::Annual,
-        CoreComp::Daily => ZcbComp::Continuous,
+        CoreComp::Daily | CoreComp::Periodic(_) => ZcbComp::Continuous,
     };
 
     let mut builder = ZeroCouponBond::builder()
@@ -148,6 +148,27 @@ pub fn solve_linear_system(a: &DMatrix<f64>, b: &DVector<f64>) -> MathResult<DVe
     Ok(x)
 }
 
+/// Returns the 2-norm condition number of a matrix.
+///
+/// The ratio of the largest to the smallest singular value. Rectangular
+/// matrices use their `min(rows, cols)` singular values, so an
+/// overdetermined system with full column rank has a finite condition
+/// number. A rank-deficient or empty matrix returns `f64::INFINITY`.
+#[must_use]
+pub fn condition_number(matrix: &DMatrix<f64>) -> f64 {
+    if matrix.is_empty() {
+        return f64::INFINITY;
+    }
+    let singular_values = matrix.singular_values();
+    let max = singular_values.max();
+    let min = singular_values.min();
+    if min <= max * f64::EPSILON {
+        f64::INFINITY
+    } else {
+        max / min
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +219,19 @@ mod tests {
         assert_relative_eq!(x[0], 2.0, epsilon = 1e-10);
         assert_relative_eq!(x[1], 1.0, epsilon = 1e-10);
     }
+
+    #[test]
+    fn test_condition_number() {
+        let identity = DMatrix::<f64>::identity(3, 3);
+        assert_relative_eq!(condition_number(&identity), 1.0, epsilon = 1e-12);
+
+        let diagonal = DMatrix::from_row_slice(2, 2, &[4.0, 0.0, 0.0, 0.5]);
+        assert_relative_eq!(condition_number(&diagonal), 8.0, epsilon = 1e-12);
+
+        // Nearly parallel rows are badly conditioned; parallel ones singular.
+        let near = DMatrix::from_row_slice(2, 2, &[1.0, 1.0, 1.0, 1.0 + 1e-9]);
+        assert!(condition_number(&near) > 1e9);
+        let singular = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+        assert!(condition_number(&singular).is_infinite());
+    }
 }
@@ -0,0 +1,163 @@
+//! December 2025 sample data for demo mode.
+//!
+//! Mirrors `demo/data/corporate-bonds.json` and the FRED Treasury par
+//! yields of 29 December 2025, embedded so the server works without any
+//! prior `create_bond` / `create_curve` calls (and without the `demo/`
+//! directory, which the container image does not ship).
+//!
+//! The Boeing 7.5% 2025 bond used for Bloomberg YAS validation is included
+//! too, quoted at its own (2020) reference date.
+
+use convex::{Currency, DayCountConvention, Frequency};
+use convex_portfolio::{CreditRating, Sector};
+
+use crate::server::{BondSpec, CurveSpec, DateInput};
+
+/// As-of date of the sample data (2025-12-30).
+pub const DEMO_AS_OF: DateInput = date(2025, 12, 30);
+
+/// Sample bond: `(id, coupon %, maturity, issue, sector, S&P rating, mid clean price)`.
+type SampleBond = (
+    &'static str,
+    f64,
+    DateInput,
+    DateInput,
+    Sector,
+    CreditRating,
+    f64,
+);
+
+const BONDS: [SampleBond; 5] = [
+    (
+        "AAPL-5.0-2030",
+        5.0,
+        date(2030, 2, 15),
+        date(2020, 2, 15),
+        Sector::Corporate,
+        CreditRating::AAPlus,
+        102.5625,
+    ),
+    (
+        "MSFT-4.5-2032",
+        4.5,
+        date(2032, 6, 15),
+        date(2022, 6, 15),
+        Sector::Corporate,
+        CreditRating::AAA,
+        99.8125,
+    ),
+    (
+        "JPM-5.25-2035",
+        5.25,
+        date(2035, 3, 15),
+        date(2024, 3, 15),
+        Sector::Financial,
+        CreditRating::AMinus,
+        101.375,
+    ),
+    (
+        "VZ-6.0-2040",
+        6.0,
+        date(2040, 9, 15),
+        date(2020, 9, 15),
+        Sector::Corporate,
+        CreditRating::BBBPlus,
+        105.6875,
+    ),
+    (
+        "XOM-4.75-2028",
+        4.75,
+        date(2028, 4, 15),
+        date(2023, 4, 15),
+        Sector::Corporate,
+        CreditRating::AAMinus,
+        101.1875,
+    ),
+];
+
+/// Treasury curve pillars (years) and yields (%), 1M through 30Y.
+const CURVE_TENORS: [f64; 11] = [0.0833, 0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 20.0, 30.0];
+const CURVE_RATES_PCT: [f64; 11] = [
+    3.69, 3.68, 3.59, 3.48, 3.45, 3.51, 3.67, 3.88, 4.12, 4.75, 4.80,
+];
+
+/// Id of the YAS reference bond: Boeing 7.5% 06/15/2025 (CUSIP 097023AH7).
+pub const BOEING_ID: &str = "BA-7.5-2025";
+
+/// Settlement date of the Boeing reference quote.
+pub const BOEING_SETTLEMENT: DateInput = date(2020, 4, 29);
+
+/// Boeing reference clean price on [`BOEING_SETTLEMENT`].
+pub const BOEING_PRICE: f64 = 110.503;
+
+const fn date(year: i32, month: u32, day: u32) -> DateInput {
+    DateInput { year, month, day }
+}
+
+/// A bond from the sample universe.
+#[derive(Debug, Clone)]
+pub struct DemoBond {
+    /// Bond spec (US corporate conventions, face 100).
+    pub spec: BondSpec,
+    /// Sector classification. Industry sectors in the source data
+    /// (Technology, Energy, ...) map to `Corporate`.
+    pub sector: Sector,
+    /// S&P rating, where the source data carries one.
+    pub rating: Option<CreditRating>,
+    /// Date the price is quoted for.
+    pub as_of: DateInput,
+    /// Mid clean price per 100 on the as-of date.
+    pub mid_price: f64,
+}
+
+fn us_corporate(coupon: f64, maturity: &DateInput, issue: &DateInput) -> BondSpec {
+    BondSpec {
+        coupon_rate_pct: coupon,
+        maturity: maturity.clone(),
+        issue_date: issue.clone(),
+        frequency: Frequency::SemiAnnual,
+        day_count: DayCountConvention::Thirty360US,
+        currency: Currency::USD,
+        face_value: 100.0,
+        make_whole_spread_bps: None,
+    }
+}
+
+/// Looks up a sample bond by id (e.g. `"AAPL-5.0-2030"`).
+pub fn demo_bond(id: &str) -> Option<DemoBond> {
+    if id == BOEING_ID {
+        return Some(DemoBond {
+            spec: us_corporate(7.5, &date(2025, 6, 15), &date(1995, 6, 15)),
+            sector: Sector::Corporate,
+            rating: None,
+            as_of: BOEING_SETTLEMENT,
+            mid_price: BOEING_PRICE,
+        });
+    }
+    BONDS.iter().find(|(bond_id, ..)| *bond_id == id).map(
+        |(_, coupon, maturity, issue, sector, rating, price)| DemoBond {
+            spec: us_corporate(*coupon, maturity, issue),
+            sector: *sector,
+            rating: Some(*rating),
+            as_of: DEMO_AS_OF,
+            mid_price: *price,
+        },
+    )
+}
+
+/// Ids of the sample bonds.
+pub fn demo_bond_ids() -> impl Iterator<Item = &'static str> {
+    BONDS
+        .iter()
+        .map(|(id, ..)| *id)
+        .chain(std::iter::once(BOEING_ID))
+}
+
+/// Treasury zero curve referenced at [`DEMO_AS_OF`].
+pub fn demo_curve() -> CurveSpec {
+    CurveSpec {
+        reference_date: DEMO_AS_OF,
+        tenors_years: CURVE_TENORS.to_vec(),
+        zero_rates_pct: CURVE_RATES_PCT.to_vec(),
+    }
+}
@@ -7,6 +7,7 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
+pub mod demo;
 pub mod error;
 pub mod server;
 
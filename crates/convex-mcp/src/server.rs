@@ -19,13 +19,24 @@ use convex::{
     aggregate_risk_profiles, barbell_futures, cash_bond_pair, compare_hedges,
     compute_callable_position_risk, compute_position_risk, duration_futures, interest_rate_swap,
     key_rate_futures, narrate, position_contributions, price_callable_from_mark, price_from_mark,
-    yield_to_maturity, Bond, BondFuture, CallSchedule, CallableBond, ComparisonReport, Compounding,
-    Constraints, Currency, Date, DayCountConvention, Deposit, DiscreteCurve, FixedRateBond,
-    FloatingRateNote, Frequency, GlobalFitter, HedgeProposal, ISpreadCalculator, InstrumentSet,
-    InterpolationMethod, Mark, Ois, PositionContribution, RateCurve, RateCurveDyn, RiskProfile,
+    yield_to_maturity, AnalyticsError, Bond, BondFuture, CallSchedule, CallableBond,
+    ComparisonReport, Compounding, Constraints, Currency, Date, DayCountConvention, Deposit,
+    DiscreteCurve, FixedRateBond, FloatingRateNote, Frequency, GSpreadCalculator, GlobalFitter,
+    HedgeProposal, ISpreadCalculator, InstrumentSet, InterpolationMethod, Mark, OASCalculator, Ois,
+    PositionContribution, Price, PriceKind, RateCurve, RateCurveDyn, RiskProfile, Spread,
     SpreadType, Swap, ValueType, Yield, ZSpreadCalculator, ZeroCouponBond, ADVISOR_KEY_RATE_TENORS,
 };
+use convex_analytics::risk::{KeyRateDuration, KeyRateDurations};
+use convex_analytics::spreads::{GovernmentCurve, ParParAssetSwap, Sovereign};
+use convex_bonds::types::BondIdentifiers;
+use convex_portfolio::{
+    bucket_by_sector, dv01_contributions, historical_scenarios, run_stress_scenario,
+    stress_scenarios, weighted_modified_duration, weighted_oas, weighted_ytm, AnalyticsConfig,
+    Classification, CreditRating, Holding, HoldingAnalytics, Portfolio, PortfolioError,
+    RateScenario, RatingInfo, Sector, SectorInfo, SpreadScenario, StressScenario, TenorShift,
+};
 
+use crate::demo;
 use crate::error::McpToolError;
 use crate::{SERVER_NAME, SERVER_VERSION};
 
@@ -657,6 +668,249 @@ pub struct BootstrapCurveParams {
     pub store_as: Option<String>,
 }
 
+/// One position in an `analyze_portfolio` request.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PortfolioHoldingInput {
+    /// Bond id — created earlier via `create_bond`, or one of the demo bonds
+    /// (`AAPL-5.0-2030`, `MSFT-4.5-2032`, `JPM-5.25-2035`, `VZ-6.0-2040`,
+    /// `XOM-4.75-2028`).
+    pub id: String,
+    /// Face amount held.
+    pub quantity: f64,
+    /// Clean price per 100 face.
+    pub price: f64,
+    /// Sector name or code (e.g. `"Corporate"`, `"FIN"`). Demo bonds carry
+    /// their own; other bonds default to `Other`.
+    #[serde(default)]
+    pub sector: Option<String>,
+    /// Credit rating (e.g. `"BBB+"`, `"Baa1"`). Demo bonds carry their S&P
+    /// rating; other bonds are unrated unless given.
+    #[serde(default)]
+    pub rating: Option<String>,
+}
+
+/// `analyze_portfolio` parameters.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AnalyzePortfolioParams {
+    /// Positions to analyze.
+    pub holdings: Vec<PortfolioHoldingInput>,
+    /// Settlement date as ISO-8601. Defaults to the demo as-of date (2025-12-30).
+    #[serde(default)]
+    pub settlement: Option<String>,
+    /// Discount curve. Defaults to the December 2025 Treasury demo curve.
+    #[serde(default)]
+    pub curve: Option<CurveRef>,
+    /// Number of top DV01 contributors to return. Defaults to 5.
+    #[serde(default)]
+    pub top_n: Option<usize>,
+}
+
+/// Predefined stress scenario.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NamedScenario {
+    /// Parallel shift up 100bp.
+    #[serde(rename = "rates_up_100")]
+    RatesUp100,
+    /// Parallel shift down 100bp.
+    #[serde(rename = "rates_down_100")]
+    RatesDown100,
+    /// Parallel shift up 50bp.
+    #[serde(rename = "rates_up_50")]
+    RatesUp50,
+    /// Parallel shift down 50bp.
+    #[serde(rename = "rates_down_50")]
+    RatesDown50,
+    /// 2s10s steepens 50bp: 2Y -25bp, 10Y +25bp.
+    #[serde(rename = "steepening_50")]
+    Steepening50,
+    /// 2s10s flattens 50bp: 2Y +25bp, 10Y -25bp.
+    #[serde(rename = "flattening_50")]
+    Flattening50,
+    /// Spreads +50bp across the board.
+    #[serde(rename = "spreads_widen_50")]
+    SpreadsWiden50,
+    /// Spreads -25bp across the board.
+    #[serde(rename = "spreads_tighten_25")]
+    SpreadsTighten25,
+    /// Flight to quality: rates -50bp, spreads +100bp.
+    RiskOff,
+    /// Risk appetite: rates +50bp, spreads -25bp.
+    RiskOn,
+    /// Sep-Dec 2008: Treasuries rally ~150bp, IG +150-450bp, HY +900-2000bp.
+    #[serde(rename = "gfc_2008")]
+    Gfc2008,
+    /// May-Sep 2013: 10Y +130bp bear steepener, IG +10-25bp, HY +60-120bp.
+    #[serde(rename = "taper_tantrum_2013")]
+    TaperTantrum2013,
+    /// Feb-Mar 2020: front end -150bp, 10Y -80bp, IG +100-260bp, HY +550-1100bp.
+    #[serde(rename = "covid_2020")]
+    Covid2020,
+    /// Jan-Oct 2022: 2Y +375bp, 10Y +250bp, IG +40-90bp, HY +200-500bp.
+    #[serde(rename = "rate_shock_2022")]
+    RateShock2022,
+}
+
+impl NamedScenario {
+    /// The `convex-portfolio` scenario definition.
+    pub fn scenario(self) -> StressScenario {
+        match self {
+            Self::RatesUp100 => stress_scenarios::rates_up_100(),
+            Self::RatesDown100 => stress_scenarios::rates_down_100(),
+            Self::RatesUp50 => stress_scenarios::rates_up_50(),
+            Self::RatesDown50 => stress_scenarios::rates_down_50(),
+            Self::Steepening50 => stress_scenarios::steepening_50(),
+            Self::Flattening50 => stress_scenarios::flattening_50(),
+            Self::SpreadsWiden50 => stress_scenarios::spreads_widen_50(),
+            Self::SpreadsTighten25 => stress_scenarios::spreads_tighten_25(),
+            Self::RiskOff => stress_scenarios::risk_off(),
+            Self::RiskOn => stress_scenarios::risk_on(),
+            Self::Gfc2008 => historical_scenarios::gfc_2008(),
+            Self::TaperTantrum2013 => historical_scenarios::taper_tantrum_2013(),
+            Self::Covid2020 => historical_scenarios::covid_2020(),
+            Self::RateShock2022 => historical_scenarios::rate_shock_2022(),
+        }
+    }
+}
+
+/// Rate shift at one tenor.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct TenorShiftInput {
+    /// Tenor in years.
+    pub tenor_years: f64,
+    /// Shift in bps (positive = rates up).
+    pub shift_bps: f64,
+}
+
+/// User-defined stress scenario. Give at most one of `parallel_bps` /
+/// `key_rate_bps` and at most one of `spread_bps` / `spread_by_rating_bps`.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct CustomScenario {
+    /// Label for the output. Defaults to `"Custom"`.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Parallel rate shift in bps (positive = rates up).
+    #[serde(default)]
+    pub parallel_bps: Option<f64>,
+    /// Rate shifts by tenor, linearly interpolated between tenors and flat
+    /// beyond the ends.
+    #[serde(default)]
+    pub key_rate_bps: Vec<TenorShiftInput>,
+    /// Uniform spread shift in bps (positive = wider).
+    #[serde(default)]
+    pub spread_bps: Option<f64>,
+    /// Spread shifts in bps keyed by rating notch (`"BBB+"`) or bucket
+    /// (`"BBB"`, `"CCC & Below"`). Unrated or unlisted holdings get 0bp.
+    #[serde(default)]
+    pub spread_by_rating_bps: HashMap<String, f64>,
+}
+
+/// A named scenario (string) or a custom one (object).
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ScenarioInput {
+    /// One of the predefined scenarios, e.g. `"rates_up_100"` or `"gfc_2008"`.
+    Named(NamedScenario),
+    /// Custom rate and spread shifts.
+    Custom(CustomScenario),
+}
+
+impl ScenarioInput {
+    /// Resolves to a `convex-portfolio` scenario.
+    pub fn to_scenario(&self) -> Result<StressScenario, McpToolError> {
+        let custom = match self {
+            Self::Named(named) => return Ok(named.scenario()),
+            Self::Custom(custom) => custom,
+        };
+        let mut scenario = StressScenario::new(custom.name.as_deref().unwrap_or("Custom"));
+        match (custom.parallel_bps, custom.key_rate_bps.is_empty()) {
+            (Some(_), false) => {
+                return Err(McpToolError::InvalidInput(
+                    "give parallel_bps or key_rate_bps, not both".into(),
+                ))
+            }
+            (Some(bps), true) => {
+                scenario = scenario.with_rate_scenario(RateScenario::parallel(bps));
+            }
+            (None, false) => {
+                let shifts = custom
+                    .key_rate_bps
+                    .iter()
+                    .map(|s| TenorShift::new(s.tenor_years, s.shift_bps))
+                    .collect();
+                scenario = scenario.with_rate_scenario(RateScenario::KeyRateShifts(shifts));
+            }
+            (None, true) => {}
+        }
+        match (custom.spread_bps, custom.spread_by_rating_bps.is_empty()) {
+            (Some(_), false) => {
+                return Err(McpToolError::InvalidInput(
+                    "give spread_bps or spread_by_rating_bps, not both".into(),
+                ))
+            }
+            (Some(bps), true) => {
+                scenario = scenario.with_spread_scenario(SpreadScenario::uniform(bps));
+            }
+            (None, false) => {
+                scenario = scenario.with_spread_scenario(SpreadScenario::ByRating(
+                    custom.spread_by_rating_bps.clone(),
+                ));
+            }
+            (None, true) => {}
+        }
+        if !scenario.has_rate_scenario() && !scenario.has_spread_scenario() {
+            return Err(McpToolError::InvalidInput(
+                "custom scenario has no rate or spread shift".into(),
+            ));
+        }
+        Ok(scenario)
+    }
+}
+
+/// `bond_spreads` parameters.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BondSpreadsParams {
+    /// Bond — a stored id, a demo id (e.g. `"AAPL-5.0-2030"`, `"BA-7.5-2025"`)
+    /// or an inline spec.
+    pub bond: BondRef,
+    /// Clean price per 100 face. Defaults to the demo price for demo bonds.
+    #[serde(default)]
+    pub clean_price_per_100: Option<f64>,
+    /// Settlement date as ISO-8601. Defaults to the demo bond's quote date,
+    /// otherwise 2025-12-30.
+    #[serde(default)]
+    pub settlement: Option<String>,
+    /// Government curve for G-spread, Z-spread and OAS. Defaults to the
+    /// December 2025 Treasury demo curve.
+    #[serde(default)]
+    pub government_curve: Option<CurveRef>,
+    /// Swap curve for I-spread and par-par asset swap spread. Both are null
+    /// without it.
+    #[serde(default)]
+    pub swap_curve: Option<CurveRef>,
+    /// Annual normal volatility (decimal, `0.01` = 1%) for the HW1F OAS of a
+    /// callable bond. OAS is null without it.
+    #[serde(default)]
+    pub volatility: Option<f64>,
+}
+
+/// `stress_test` parameters.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct StressTestParams {
+    /// Positions to stress, as in `analyze_portfolio`. One entry stresses a
+    /// single bond.
+    pub holdings: Vec<PortfolioHoldingInput>,
+    /// Named scenario (e.g. `"rates_up_100"`, `"covid_2020"`) or a custom
+    /// scenario object.
+    pub scenario: ScenarioInput,
+    /// Settlement date as ISO-8601. Defaults to the demo as-of date (2025-12-30).
+    #[serde(default)]
+    pub settlement: Option<String>,
+    /// Discount curve. Defaults to the December 2025 Treasury demo curve.
+    #[serde(default)]
+    pub curve: Option<CurveRef>,
+}
+
 // Tool output types. Field names carry units; missing_docs is allowed.
 
 /// Output of `create_bond` / `create_curve` — confirms the registered id.
@@ -766,6 +1020,119 @@ pub struct ListOutput<T> {
     pub items: Vec<T>,
 }
 
+/// Per-holding line of `analyze_portfolio`.
+#[derive(Debug, Serialize)]
+#[allow(missing_docs)]
+pub struct PortfolioHoldingOutput {
+    pub id: String,
+    pub sector: String,
+    pub rating: Option<String>,
+    pub quantity: f64,
+    pub clean_price_per_100: f64,
+    pub market_value: f64,
+    pub ytm_pct: f64,
+    pub modified_duration_years: f64,
+    /// Z-spread to the curve; the bonds carry no optionality, so this is
+    /// also their OAS.
+    pub oas_bps: f64,
+    pub dv01: f64,
+}
+
+/// Sector bucket of `analyze_portfolio`, largest weight first.
+#[derive(Debug, Serialize)]
+#[allow(missing_docs)]
+pub struct SectorBucketOutput {
+    pub sector: String,
+    pub count: usize,
+    pub market_value: f64,
+    pub weight_pct: f64,
+    pub avg_duration_years: Option<f64>,
+}
+
+/// DV01 contributor of `analyze_portfolio`.
+#[derive(Debug, Serialize)]
+#[allow(missing_docs)]
+pub struct RiskContributorOutput {
+    pub id: String,
+    pub weight_pct: f64,
+    pub dv01: f64,
+    pub contribution_pct: f64,
+}
+
+/// Output of `analyze_portfolio`. Weighted figures are market-value weighted.
+#[derive(Debug, Serialize)]
+#[allow(missing_docs)]
+pub struct AnalyzePortfolioOutput {
+    pub settlement: Date,
+    pub curve_id: Option<String>,
+    pub nav: f64,
+    pub weighted_modified_duration_years: Option<f64>,
+    pub weighted_ytm_pct: Option<f64>,
+    pub weighted_oas_bps: Option<f64>,
+    pub total_dv01: f64,
+    pub sectors: Vec<SectorBucketOutput>,
+    pub top_risk_contributors: Vec<RiskContributorOutput>,
+    pub holdings: Vec<PortfolioHoldingOutput>,
+}
+
+/// Output of `bond_spreads`. A spread is null when it cannot be computed
+/// from the inputs given; `notes` says why.
+#[derive(Debug, Serialize)]
+#[allow(missing_docs)]
+pub struct BondSpreadsOutput {
+    pub bond_id: Option<String>,
+    pub settlement: Date,
+    pub government_curve_id: Option<String>,
+    pub swap_curve_id: Option<String>,
+    pub clean_price_per_100: f64,
+    pub ytm_pct: f64,
+    pub g_spread_bps: Option<f64>,
+    pub i_spread_bps: Option<f64>,
+    pub z_spread_bps: Option<f64>,
+    pub asw_bps: Option<f64>,
+    pub oas_bps: Option<f64>,
+    pub notes: Vec<String>,
+}
+
+/// Per-holding line of `stress_test`. Prices are repriced at the shocked yield.
+#[derive(Debug, Serialize)]
+#[allow(missing_docs)]
+pub struct HoldingStressOutput {
+    pub id: String,
+    pub rate_shift_bps: f64,
+    pub spread_shift_bps: f64,
+    pub base_clean_price_per_100: f64,
+    pub stressed_clean_price_per_100: f64,
+    pub base_ytm_pct: f64,
+    pub stressed_ytm_pct: f64,
+    pub base_modified_duration_years: f64,
+    pub stressed_modified_duration_years: f64,
+    pub repriced_pnl: f64,
+}
+
+/// Output of `stress_test`. `pnl` and the impact percentages are the
+/// duration-convexity estimate on NAV; the weighted figures are market-value
+/// weighted before and after the shock.
+#[derive(Debug, Serialize)]
+#[allow(missing_docs)]
+pub struct StressTestOutput {
+    pub scenario: String,
+    pub description: Option<String>,
+    pub settlement: Date,
+    pub curve_id: Option<String>,
+    pub initial_value: f64,
+    pub stressed_value: f64,
+    pub pnl: f64,
+    pub pnl_pct: f64,
+    pub rate_impact_pct: Option<f64>,
+    pub spread_impact_pct: Option<f64>,
+    pub base_weighted_modified_duration_years: Option<f64>,
+    pub stressed_weighted_modified_duration_years: Option<f64>,
+    pub base_weighted_ytm_pct: Option<f64>,
+    pub stressed_weighted_ytm_pct: Option<f64>,
+    pub holdings: Vec<HoldingStressOutput>,
+}
+
 // ============================================================================
 // Tool Implementations
 // ============================================================================
@@ -1506,6 +1873,602 @@ impl ConvexMcpServer {
         let text = narrate(&params.comparison);
         Self::json_result(&NarrationOutput { text })
     }
+
+    #[tool(
+        description = "All spreads for a bond in one call: G-spread over the government \
+        benchmark yield (the curve's semi-annual par yields, interpolated to maturity), \
+        Z-spread over the government curve, I-spread and par-par asset swap spread over the swap curve, and \
+        HW1F OAS for callables. A spread that cannot be computed (no swap curve, no \
+        volatility, curve dated after settlement, solver failure) comes back null with an \
+        explanatory entry in `notes` instead of failing the call. Demo bond ids, including \
+        the YAS reference bond BA-7.5-2025 (Boeing 7.5% 06/15/2025 at 110.503 for \
+        2020-04-29), supply their own price and settlement."
+    )]
+    pub async fn bond_spreads(
+        &self,
+        Parameters(params): Parameters<BondSpreadsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let (bond, bond_id, demo) = self.resolve_bond_or_demo(&params.bond)?;
+        let fixed = bond.fixed().ok_or_else(|| {
+            McpToolError::InvalidInput(format!(
+                "bond_spreads requires Fixed/Callable, got {}",
+                bond.type_name()
+            ))
+        })?;
+        let settlement = match (&params.settlement, &demo) {
+            (Some(s), _) => Date::parse(s)
+                .map_err(|e| McpToolError::InvalidInput(format!("settlement '{s}': {e}")))?,
+            (None, Some(d)) => d.as_of.to_date()?,
+            (None, None) => demo::DEMO_AS_OF.to_date()?,
+        };
+        let clean = match (params.clean_price_per_100, &demo) {
+            (Some(p), _) => p,
+            (None, Some(d)) => d.mid_price,
+            (None, None) => {
+                return Err(McpToolError::InvalidInput(
+                    "clean_price_per_100 is required for non-demo bonds".into(),
+                )
+                .into())
+            }
+        };
+        let clean_dec = finite_decimal(clean, "clean_price_per_100")?;
+        let dirty = clean_dec + fixed.accrued_interest(settlement);
+
+        let ytm = yield_to_maturity(fixed, settlement, clean_dec, fixed.frequency())
+            .map_err(McpToolError::from)?;
+        let compounding = Compounding::from(fixed.frequency());
+        let bond_yield = Yield::new(
+            Decimal::from_f64_retain(ytm.yield_value).unwrap_or(Decimal::ZERO),
+            compounding,
+        );
+
+        let (government, government_curve_id) = match &params.government_curve {
+            Some(r) => {
+                let (curve, id) = self.resolve_curve(r)?;
+                (Some(curve), id)
+            }
+            None => (
+                Some(build_curve(&demo::demo_curve())?),
+                Some(DEMO_CURVE_ID.to_string()),
+            ),
+        };
+        let (swap, swap_curve_id) = match &params.swap_curve {
+            Some(r) => {
+                let (curve, id) = self.resolve_curve(r)?;
+                (Some(curve), id)
+            }
+            None => (None, None),
+        };
+
+        let mut notes = Vec::new();
+        let government = usable_curve(
+            government.as_ref(),
+            "government_curve",
+            settlement,
+            &["g_spread", "z_spread", "oas"],
+            &mut notes,
+        );
+        let swap = usable_curve(
+            swap.as_ref(),
+            "swap_curve",
+            settlement,
+            &["i_spread", "asw"],
+            &mut notes,
+        );
+
+        let mut bps = |field: &str, result: Result<Spread, AnalyticsError>| match result {
+            Ok(spread) => spread.as_bps().to_f64(),
+            Err(e) => {
+                notes.push(format!("{field}: {e}"));
+                None
+            }
+        };
+        let (g_spread_bps, z_spread_bps) = match government {
+            Some(curve) => (
+                bps(
+                    "g_spread",
+                    government_benchmarks(curve, fixed.currency()).and_then(|benchmarks| {
+                        GSpreadCalculator::new(&benchmarks).calculate(fixed, bond_yield, settlement)
+                    }),
+                ),
+                bps(
+                    "z_spread",
+                    ZSpreadCalculator::new(curve).calculate(fixed, dirty, settlement),
+                ),
+            ),
+            None => (None, None),
+        };
+        let (i_spread_bps, asw_bps) = match swap {
+            Some(curve) => (
+                bps(
+                    "i_spread",
+                    ISpreadCalculator::new(curve).calculate(fixed, bond_yield, settlement),
+                ),
+                bps(
+                    "asw",
+                    ParParAssetSwap::new(curve).calculate(
+                        fixed,
+                        Price::new(clean_dec, fixed.currency()),
+                        settlement,
+                    ),
+                ),
+            ),
+            None => (None, None),
+        };
+        let oas_bps = match (&bond, government, params.volatility) {
+            (StoredBond::Callable(callable), Some(curve), Some(vol)) => bps(
+                "oas",
+                OASCalculator::default_hull_white(vol)
+                    .calculate(callable, dirty, curve, settlement),
+            ),
+            (StoredBond::Callable(_), Some(_), None) => {
+                notes.push("oas: callable bond needs `volatility`".into());
+                None
+            }
+            (StoredBond::Callable(_), None, _) => None,
+            _ => {
+                notes.push("oas: no embedded options; the Z-spread applies".into());
+                None
+            }
+        };
+
+        Self::json_result(&BondSpreadsOutput {
+            bond_id,
+            settlement,
+            government_curve_id,
+            swap_curve_id,
+            clean_price_per_100: clean,
+            ytm_pct: ytm.yield_value * 100.0,
+            g_spread_bps,
+            i_spread_bps,
+            z_spread_bps,
+            asw_bps,
+            oas_bps,
+            notes,
+        })
+    }
+
+    #[tool(
+        description = "Portfolio analytics for a list of holdings (bond id, face quantity, \
+        clean price): NAV, market-value-weighted modified duration / YTM / OAS, sector \
+        buckets and top DV01 contributors. Demo mode needs no setup: the December 2025 \
+        sample bonds (AAPL-5.0-2030, MSFT-4.5-2032, JPM-5.25-2035, VZ-6.0-2040, \
+        XOM-4.75-2028) resolve by id, and settlement / curve default to 2025-12-30 and \
+        that day's Treasury curve. OAS is the Z-spread, as no optionality is modelled."
+    )]
+    pub async fn analyze_portfolio(
+        &self,
+        Parameters(params): Parameters<AnalyzePortfolioParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let (settlement, curve, curve_id) =
+            self.portfolio_market(params.settlement.as_deref(), params.curve.as_ref())?;
+        let priced = self.price_holdings(&params.holdings, settlement, &curve, &curve_id)?;
+        let portfolio = build_portfolio(priced.iter().map(|p| p.holding.clone()), settlement)?;
+
+        let config = AnalyticsConfig::default();
+        let holdings = &portfolio.holdings;
+        let contributions = dv01_contributions(holdings, &config);
+        let sectors = bucket_by_sector(holdings, &config)
+            .sorted_by_weight()
+            .into_iter()
+            .map(|(sector, m)| SectorBucketOutput {
+                sector: sector.name().to_string(),
+                count: m.count,
+                market_value: m.market_value.to_f64().unwrap_or(f64::NAN),
+                weight_pct: m.weight_pct,
+                avg_duration_years: m.avg_duration,
+            })
+            .collect();
+        let top_risk_contributors = contributions
+            .top_contributors(params.top_n.unwrap_or(5))
+            .into_iter()
+            .map(|c| RiskContributorOutput {
+                id: c.id.clone(),
+                weight_pct: c.weight * 100.0,
+                dv01: c.contribution,
+                contribution_pct: c.contribution_pct,
+            })
+            .collect();
+
+        Self::json_result(&AnalyzePortfolioOutput {
+            settlement,
+            curve_id,
+            nav: portfolio.nav().to_f64().unwrap_or(f64::NAN),
+            weighted_modified_duration_years: weighted_modified_duration(holdings, &config),
+            weighted_ytm_pct: weighted_ytm(holdings, &config).map(|y| y * 100.0),
+            weighted_oas_bps: weighted_oas(holdings, &config),
+            total_dv01: contributions.total_dv01.to_f64().unwrap_or(f64::NAN),
+            sectors,
+            top_risk_contributors,
+            holdings: priced.into_iter().map(|p| p.line).collect(),
+        })
+    }
+
+    #[tool(
+        description = "Stress a bond or portfolio under a named or custom scenario and \
+        return the P&L and post-shock analytics. Named scenarios: rates_up_100, \
+        rates_down_100, rates_up_50, rates_down_50 (parallel); steepening_50, \
+        flattening_50 (2Y / 10Y ±25bp); spreads_widen_50, spreads_tighten_25 (uniform); \
+        risk_off (rates -50bp, spreads +100bp), risk_on (rates +50bp, spreads -25bp); \
+        historical replays gfc_2008, taper_tantrum_2013, covid_2020, rate_shock_2022 \
+        (key-rate curve move plus spreads by rating). Custom: an object with \
+        `parallel_bps` or `key_rate_bps`, and `spread_bps` or `spread_by_rating_bps`. \
+        Holdings and defaults are as in analyze_portfolio, so the demo bonds need no \
+        setup; pass one holding to stress a single bond. Portfolio P&L is the \
+        duration-convexity estimate; per-holding figures reprice each bond at its \
+        shocked yield."
+    )]
+    pub async fn stress_test(
+        &self,
+        Parameters(params): Parameters<StressTestParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let scenario = params.scenario.to_scenario()?;
+        let (settlement, curve, curve_id) =
+            self.portfolio_market(params.settlement.as_deref(), params.curve.as_ref())?;
+        let priced = self.price_holdings(&params.holdings, settlement, &curve, &curve_id)?;
+        let portfolio = build_portfolio(priced.iter().map(|p| p.holding.clone()), settlement)?;
+        let config = AnalyticsConfig::default();
+        let result = run_stress_scenario(&portfolio, &scenario, &config);
+
+        let curve_id_str = curve_id.as_deref().unwrap_or("<inline>");
+        let mut stressed_holdings = Vec::with_capacity(priced.len());
+        let mut lines = Vec::with_capacity(priced.len());
+        for p in &priced {
+            let base = &p.holding;
+            let rate_shift_bps = scenario.rate_scenario.as_ref().map_or(0.0, |rs| {
+                rs.shift_at_tenor(base.analytics.years_to_maturity.unwrap_or(0.0))
+            });
+            let spread_shift_bps = scenario
+                .spread_scenario
+                .as_ref()
+                .map_or(0.0, |ss| ss.shift_for(base));
+            let stressed_ytm =
+                p.line.ytm_pct / 100.0 + (rate_shift_bps + spread_shift_bps) / 10_000.0;
+            let mark = Mark::Yield {
+                value: finite_decimal(stressed_ytm, "stressed yield")?,
+                frequency: p.bond.frequency(),
+            };
+            let risk = compute_position_risk(
+                &p.bond,
+                settlement,
+                &mark,
+                dec!(100),
+                &curve,
+                curve_id_str,
+                None,
+                Some(&[]),
+                Some(base.id.clone()),
+            )
+            .map_err(McpToolError::from)?;
+
+            let mut stressed = base.clone();
+            stressed.market_price = finite_decimal(risk.clean_price_per_100, "stressed price")?;
+            stressed.analytics = stressed
+                .analytics
+                .with_ytm(risk.ytm_decimal)
+                .with_modified_duration(risk.modified_duration_years)
+                .with_convexity(risk.convexity)
+                .with_dv01(risk.dv01);
+            lines.push(HoldingStressOutput {
+                id: base.id.clone(),
+                rate_shift_bps,
+                spread_shift_bps,
+                base_clean_price_per_100: p.line.clean_price_per_100,
+                stressed_clean_price_per_100: risk.clean_price_per_100,
+                base_ytm_pct: p.line.ytm_pct,
+                stressed_ytm_pct: risk.ytm_decimal * 100.0,
+                base_modified_duration_years: p.line.modified_duration_years,
+                stressed_modified_duration_years: risk.modified_duration_years,
+                repriced_pnl: (risk.clean_price_per_100 - p.line.clean_price_per_100)
+                    * p.line.quantity
+                    / 100.0,
+            });
+            stressed_holdings.push(stressed);
+        }
+        let stressed = build_portfolio(stressed_holdings.into_iter(), settlement)?;
+
+        Self::json_result(&StressTestOutput {
+            scenario: scenario.name.clone(),
+            description: scenario.description.clone(),
+            settlement,
+            curve_id,
+            initial_value: result.initial_value,
+            stressed_value: result.stressed_value,
+            pnl: result.pnl,
+            pnl_pct: result.pnl_pct,
+            rate_impact_pct: result.rate_impact,
+            spread_impact_pct: result.spread_impact,
+            base_weighted_modified_duration_years: weighted_modified_duration(
+                &portfolio.holdings,
+                &config,
+            ),
+            stressed_weighted_modified_duration_years: weighted_modified_duration(
+                &stressed.holdings,
+                &config,
+            ),
+            base_weighted_ytm_pct: weighted_ytm(&portfolio.holdings, &config).map(|y| y * 100.0),
+            stressed_weighted_ytm_pct: weighted_ytm(&stressed.holdings, &config).map(|y| y * 100.0),
+            holdings: lines,
+        })
+    }
+}
+
+/// Id reported for the demo Treasury curve.
+const DEMO_CURVE_ID: &str = "DEMO.UST.2025-12-30";
+
+/// Returns the curve if it can value flows from `settlement`; otherwise
+/// notes why each dependent spread is missing.
+/// Government benchmark yields off a government zero curve: the semi-annual
+/// par yield at each pillar from six months out, standing in for on-the-run
+/// issues trading near par.
+fn government_benchmarks(
+    curve: &StoredCurve,
+    currency: Currency,
+) -> Result<GovernmentCurve, AnalyticsError> {
+    let sovereign = match currency {
+        Currency::EUR => Sovereign::Germany,
+        Currency::GBP => Sovereign::UK,
+        Currency::JPY => Sovereign::Japan,
+        _ => Sovereign::UST,
+    };
+    let df = |years: f64| {
+        curve
+            .discount_factor_at_tenor(years)
+            .map_err(|e| AnalyticsError::CurveError(e.to_string()))
+    };
+
+    let mut benchmarks = GovernmentCurve::new(sovereign, curve.reference_date());
+    for &years in curve.inner().tenors().iter().filter(|&&t| t >= 0.5) {
+        let periods = (years * 2.0).round() as u32;
+        let mut annuity = 0.0;
+        for k in 0..periods {
+            annuity += 0.5 * df(years - f64::from(k) / 2.0)?;
+        }
+        benchmarks = benchmarks.with_point(years, (1.0 - df(years)?) / annuity);
+    }
+    Ok(benchmarks)
+}
+
+fn usable_curve<'a>(
+    curve: Option<&'a StoredCurve>,
+    name: &str,
+    settlement: Date,
+    fields: &[&str],
+    notes: &mut Vec<String>,
+) -> Option<&'a StoredCurve> {
+    let reason = match curve {
+        Some(c) if c.reference_date() <= settlement => return Some(c),
+        Some(c) => format!(
+            "{name} is dated {}, after settlement {settlement}",
+            c.reference_date()
+        ),
+        None => format!("no {name} given"),
+    };
+    notes.extend(fields.iter().map(|f| format!("{f}: {reason}")));
+    None
+}
+
+/// A holding priced for the portfolio tools, with the bond kept for repricing.
+struct PricedHolding {
+    bond: FixedRateBond,
+    holding: Holding,
+    line: PortfolioHoldingOutput,
+}
+
+fn build_portfolio(
+    holdings: impl Iterator<Item = Holding>,
+    as_of: Date,
+) -> Result<Portfolio, McpToolError> {
+    holdings
+        .fold(Portfolio::builder("mcp").as_of_date(as_of), |b, h| {
+            b.add_holding(h)
+        })
+        .build()
+        .map_err(portfolio_error)
+}
+
+fn portfolio_error(e: PortfolioError) -> McpToolError {
+    McpToolError::InvalidInput(e.to_string())
+}
+
+impl ConvexMcpServer {
+    /// Settlement and curve for the portfolio tools; both default to the
+    /// December 2025 demo set.
+    fn portfolio_market(
+        &self,
+        settlement: Option<&str>,
+        curve: Option<&CurveRef>,
+    ) -> Result<(Date, StoredCurve, Option<String>), McpToolError> {
+        let settlement = match settlement {
+            Some(s) => Date::parse(s)
+                .map_err(|e| McpToolError::InvalidInput(format!("settlement '{s}': {e}")))?,
+            None => demo::DEMO_AS_OF.to_date()?,
+        };
+        let (curve, curve_id) = match curve {
+            Some(r) => self.resolve_curve(r)?,
+            None => (
+                build_curve(&demo::demo_curve())?,
+                Some(DEMO_CURVE_ID.to_string()),
+            ),
+        };
+        Ok((settlement, curve, curve_id))
+    }
+
+    /// Prices each input at its clean price. Analytics are per 100 face;
+    /// the portfolio scales them by par. OAS is the Z-spread.
+    fn price_holdings(
+        &self,
+        inputs: &[PortfolioHoldingInput],
+        settlement: Date,
+        curve: &StoredCurve,
+        curve_id: &Option<String>,
+    ) -> Result<Vec<PricedHolding>, McpToolError> {
+        if inputs.is_empty() {
+            return Err(McpToolError::InvalidInput("holdings list is empty".into()));
+        }
+        let curve_id_str = curve_id.as_deref().unwrap_or("<inline>");
+        inputs
+            .iter()
+            .map(|input| {
+                let (bond, classification) = self.resolve_portfolio_bond(input)?;
+                let fixed = bond.fixed().cloned().ok_or_else(|| {
+                    McpToolError::InvalidInput(format!(
+                        "portfolio tools require Fixed/Callable bonds, '{}' is {}",
+                        input.id,
+                        bond.type_name()
+                    ))
+                })?;
+                let price = finite_decimal(input.price, "price")?;
+                let quantity = finite_decimal(input.quantity, "quantity")?;
+                let mark = Mark::Price {
+                    value: price,
+                    kind: PriceKind::Clean,
+                };
+                let risk = compute_position_risk(
+                    &fixed,
+                    settlement,
+                    &mark,
+                    dec!(100),
+                    curve,
+                    curve_id_str,
+                    None,
+                    None,
+                    Some(input.id.clone()),
+                )?;
+                let dirty = finite_decimal(risk.dirty_price_per_100, "dirty price")?;
+                let oas_bps = ZSpreadCalculator::new(curve)
+                    .calculate(&fixed, dirty, settlement)?
+                    .as_bps()
+                    .to_f64()
+                    .unwrap_or(f64::NAN);
+                let years_to_maturity = fixed
+                    .maturity()
+                    .map_or(0.0, |m| settlement.days_between(&m) as f64 / 365.25);
+
+                // Key rate durations from the per-100 partial DV01s, so
+                // curve-shape scenarios can be applied to the holding.
+                let krds = risk
+                    .key_rate_buckets
+                    .iter()
+                    .map(|b| KeyRateDuration {
+                        tenor: b.tenor_years,
+                        duration: (b.partial_dv01 / (risk.dirty_price_per_100 * 1e-4)).into(),
+                    })
+                    .collect();
+                let mut analytics = HoldingAnalytics::new()
+                    .with_ytm(risk.ytm_decimal)
+                    .with_modified_duration(risk.modified_duration_years)
+                    .with_convexity(risk.convexity)
+                    .with_oas(oas_bps)
+                    .with_dv01(risk.dv01)
+                    .with_years_to_maturity(years_to_maturity);
+                analytics.key_rate_durations = Some(KeyRateDurations::new(krds));
+
+                let holding = Holding::builder()
+                    .id(&input.id)
+                    .identifiers(BondIdentifiers::new().with_ticker(&input.id))
+                    .par_amount(quantity)
+                    .market_price(price)
+                    .accrued_interest(finite_decimal(risk.accrued_per_100, "accrued")?)
+                    .analytics(analytics)
+                    .classification(classification)
+                    .build()
+                    .map_err(portfolio_error)?;
+
+                let line = PortfolioHoldingOutput {
+                    id: input.id.clone(),
+                    sector: holding
+                        .classification
+                        .sector
+                        .composite
+                        .unwrap_or_default()
+                        .name()
+                        .to_string(),
+                    rating: holding
+                        .classification
+                        .rating
+                        .composite
+                        .map(|r| r.to_string()),
+                    quantity: input.quantity,
+                    clean_price_per_100: input.price,
+                    market_value: holding.market_value().to_f64().unwrap_or(f64::NAN),
+                    ytm_pct: risk.ytm_decimal * 100.0,
+                    modified_duration_years: risk.modified_duration_years,
+                    oas_bps,
+                    dv01: holding
+                        .total_dv01()
+                        .and_then(|d| d.to_f64())
+                        .unwrap_or(f64::NAN),
+                };
+                Ok(PricedHolding {
+                    bond: fixed,
+                    holding,
+                    line,
+                })
+            })
+            .collect()
+    }
+
+    /// `resolve_bond`, falling back to the demo bonds for ids not created
+    /// in this session.
+    fn resolve_bond_or_demo(
+        &self,
+        r: &BondRef,
+    ) -> Result<(StoredBond, Option<String>, Option<demo::DemoBond>), McpToolError> {
+        if let BondRef::Id(id) = r {
+            if self.get_bond(id).is_none() {
+                if let Some(demo) = demo::demo_bond(id) {
+                    return Ok((build_bond(id, &demo.spec)?, Some(id.clone()), Some(demo)));
+                }
+            }
+        }
+        let (bond, id) = self.resolve_bond(r)?;
+        Ok((bond, id, None))
+    }
+
+    /// Session bonds first, then the demo universe. Demo bonds bring their
+    /// own sector and rating; explicit `sector` / `rating` inputs override them.
+    fn resolve_portfolio_bond(
+        &self,
+        input: &PortfolioHoldingInput,
+    ) -> Result<(StoredBond, Classification), McpToolError> {
+        let (bond, default_sector, default_rating) = match self.get_bond(&input.id) {
+            Some(bond) => (bond, Sector::Other, None),
+            None => {
+                let demo = demo::demo_bond(&input.id).ok_or_else(|| {
+                    McpToolError::InvalidInput(format!(
+                        "bond '{}' not found; create it first or use a demo bond ({})",
+                        input.id,
+                        demo::demo_bond_ids().collect::<Vec<_>>().join(", ")
+                    ))
+                })?;
+                (build_bond(&input.id, &demo.spec)?, demo.sector, demo.rating)
+            }
+        };
+        let sector = match &input.sector {
+            Some(s) => Sector::all()
+                .iter()
+                .copied()
+                .find(|sec| {
+                    sec.name().eq_ignore_ascii_case(s) || sec.code().eq_ignore_ascii_case(s)
+                })
+                .ok_or_else(|| McpToolError::InvalidInput(format!("unknown sector '{s}'")))?,
+            None => default_sector,
+        };
+        let rating = match &input.rating {
+            Some(r) => Some(
+                CreditRating::parse(r)
+                    .ok_or_else(|| McpToolError::InvalidInput(format!("unknown rating '{r}'")))?,
+            ),
+            None => default_rating,
+        };
+        let mut classification =
+            Classification::new().with_sector(SectorInfo::from_composite(sector));
+        if let Some(rating) = rating {
+            classification = classification.with_rating(RatingInfo::from_composite(rating));
+        }
+        Ok((bond, classification))
+    }
 }
 
 #[tool_handler]
@@ -1516,11 +2479,13 @@ impl ServerHandler for ConvexMcpServer {
             .with_server_info(
                 Implementation::new(SERVER_NAME, SERVER_VERSION)
                     .with_title("Convex Fixed Income Analytics")
+                    .with_description(format!("Convex {}", convex::build_info()))
                     .with_website_url("https://github.com/sujitn/convex"),
             )
             .with_instructions(
                 "Convex MCP Server — fixed income analytics. Create bonds and curves, \
-                 then call price_bond or compute_spread.",
+                 then call price_bond or compute_spread. bond_spreads and \
+                 analyze_portfolio run on the December 2025 demo bonds with no setup.",
             )
     }
 }
@@ -2742,4 +3707,281 @@ mod tests {
              got qty_a={qty_a}, qty_b={qty_b}"
         );
     }
+
+    fn demo_holding(id: &str, quantity: f64, price: f64) -> PortfolioHoldingInput {
+        PortfolioHoldingInput {
+            id: id.into(),
+            quantity,
+            price,
+            sector: None,
+            rating: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn analyze_portfolio_on_demo_data() {
+        // No create calls: ids, settlement and curve all come from the demo set.
+        let server = ConvexMcpServer::new();
+        let out = server
+            .analyze_portfolio(Parameters(AnalyzePortfolioParams {
+                holdings: vec![
+                    demo_holding("AAPL-5.0-2030", 5_000_000.0, 102.5625),
+                    demo_holding("JPM-5.25-2035", 3_000_000.0, 101.375),
+                ],
+                settlement: None,
+                curve: None,
+                top_n: Some(1),
+            }))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&response_text(out)).unwrap();
+
+        assert_eq!(json["curve_id"], DEMO_CURVE_ID);
+        let holdings = json["holdings"].as_array().unwrap();
+        assert_eq!(holdings.len(), 2);
+
+        let market_value: f64 = holdings
+            .iter()
+            .map(|h| h["market_value"].as_f64().unwrap())
+            .sum();
+        let nav = json["nav"].as_f64().unwrap();
+        assert!((market_value - (5_128_125.0 + 3_041_250.0)).abs() < 1e-6);
+        // 30/360 accrued from the last coupon: 135 days at 5%, 105 at 5.25%.
+        assert!(
+            (nav - market_value - (93_750.0 + 45_937.5)).abs() < 1e-6,
+            "{nav}"
+        );
+
+        // Weighted figures sit between the two holdings.
+        let field = |i: usize, k: &str| holdings[i][k].as_f64().unwrap();
+        for (weighted, per_holding) in [
+            (
+                "weighted_modified_duration_years",
+                "modified_duration_years",
+            ),
+            ("weighted_ytm_pct", "ytm_pct"),
+            ("weighted_oas_bps", "oas_bps"),
+        ] {
+            let w = json[weighted].as_f64().unwrap();
+            let (a, b) = (field(0, per_holding), field(1, per_holding));
+            assert!(
+                w > a.min(b) && w < a.max(b),
+                "{weighted}: {w} not in [{a}, {b}]"
+            );
+        }
+        assert!(json["weighted_oas_bps"].as_f64().unwrap() > 0.0);
+
+        let sectors = json["sectors"].as_array().unwrap();
+        assert_eq!(sectors.len(), 2);
+        assert_eq!(sectors[0]["sector"], "Corporate");
+        assert_eq!(sectors[1]["sector"], "Financial");
+
+        let top = json["top_risk_contributors"].as_array().unwrap();
+        assert_eq!(top.len(), 1);
+        let total_dv01 = json["total_dv01"].as_f64().unwrap();
+        let largest = field(0, "dv01").max(field(1, "dv01"));
+        assert!((top[0]["dv01"].as_f64().unwrap() - largest).abs() < 1e-9);
+        assert!((field(0, "dv01") + field(1, "dv01") - total_dv01).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn analyze_portfolio_unknown_id_errors() {
+        let server = ConvexMcpServer::new();
+        let err = server
+            .analyze_portfolio(Parameters(AnalyzePortfolioParams {
+                holdings: vec![demo_holding("NOPE-1.0-2030", 1_000_000.0, 100.0)],
+                settlement: None,
+                curve: None,
+                top_n: None,
+            }))
+            .await;
+        assert!(err.is_err());
+    }
+
+    fn boeing_spreads_params() -> BondSpreadsParams {
+        BondSpreadsParams {
+            bond: BondRef::Id(demo::BOEING_ID.into()),
+            clean_price_per_100: None,
+            settlement: None,
+            government_curve: None,
+            swap_curve: None,
+            volatility: None,
+        }
+    }
+
+    fn boeing_flat_curve(rate_pct: f64) -> CurveRef {
+        CurveRef::Spec(CurveSpec {
+            reference_date: demo::BOEING_SETTLEMENT,
+            ..flat_curve_spec(rate_pct)
+        })
+    }
+
+    #[tokio::test]
+    async fn bond_spreads_boeing_without_curves_returns_nulls_with_notes() {
+        let server = ConvexMcpServer::new();
+        let out = server
+            .bond_spreads(Parameters(boeing_spreads_params()))
+            .await
+            .unwrap();
+        let v: serde_json::Value = serde_json::from_str(&response_text(out)).unwrap();
+
+        assert_eq!(v["settlement"], "2020-04-29");
+        // Street yield that reprices the 113.2947 dirty (134 days accrued).
+        assert!((v["ytm_pct"].as_f64().unwrap() - 5.141768).abs() < 1e-4);
+        for field in [
+            "g_spread_bps",
+            "i_spread_bps",
+            "z_spread_bps",
+            "asw_bps",
+            "oas_bps",
+        ] {
+            assert!(v[field].is_null(), "{field} should be null");
+        }
+        // The December 2025 demo curve postdates the 2020 settlement.
+        let notes = v["notes"].as_array().unwrap();
+        assert!(notes.iter().any(|n| n
+            .as_str()
+            .unwrap()
+            .starts_with("z_spread: government_curve is dated")));
+        assert!(notes.iter().any(|n| n == "asw: no swap_curve given"));
+    }
+
+    #[tokio::test]
+    async fn bond_spreads_boeing_with_curves() {
+        let server = ConvexMcpServer::new();
+        let out = server
+            .bond_spreads(Parameters(BondSpreadsParams {
+                government_curve: Some(boeing_flat_curve(0.5)),
+                ..boeing_spreads_params()
+            }))
+            .await
+            .unwrap();
+        let v: serde_json::Value = serde_json::from_str(&response_text(out)).unwrap();
+        let g = v["g_spread_bps"].as_f64().unwrap();
+        let z = v["z_spread_bps"].as_f64().unwrap();
+        // ~5.14% yield over a flat 0.5% curve.
+        assert!((g - 464.0).abs() < 10.0, "g_spread {g}");
+        assert!((z - g).abs() < 15.0, "z_spread {z} vs g_spread {g}");
+        assert!(v["i_spread_bps"].is_null());
+        assert!(v["asw_bps"].is_null());
+        assert!(v["oas_bps"].is_null());
+
+        let out = server
+            .bond_spreads(Parameters(BondSpreadsParams {
+                government_curve: Some(boeing_flat_curve(0.5)),
+                swap_curve: Some(boeing_flat_curve(0.7)),
+                ..boeing_spreads_params()
+            }))
+            .await
+            .unwrap();
+        let v: serde_json::Value = serde_json::from_str(&response_text(out)).unwrap();
+        let i = v["i_spread_bps"].as_f64().unwrap();
+        assert!((g - i - 20.0).abs() < 1.0, "i_spread {i} vs g_spread {g}");
+        assert!(v["asw_bps"].as_f64().unwrap() > 0.0);
+        assert_eq!(v["notes"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn government_benchmarks_are_par_yields() {
+        let curve = build_curve(&flat_curve_spec(3.0)).unwrap();
+        let benchmarks = government_benchmarks(&curve, Currency::USD).unwrap();
+
+        // On a flat curve every par yield is the semi-annual equivalent of
+        // the continuous zero rate.
+        let expected = 2.0 * ((0.03_f64 / 2.0).exp() - 1.0);
+        for years in [0.5, 2.0, 7.0, 30.0] {
+            let y = benchmarks
+                .interpolated_yield(years)
+                .value()
+                .to_f64()
+                .unwrap();
+            assert!((y - expected).abs() < 1e-12, "{years}y: {y}");
+        }
+    }
+
+    #[tokio::test]
+    async fn stress_test_parallel_up_100_loses_on_demo_bond() {
+        let server = ConvexMcpServer::new();
+        // Scenario as the assistant would send it.
+        let scenario: ScenarioInput = serde_json::from_str(r#""rates_up_100""#).unwrap();
+        let out = server
+            .stress_test(Parameters(StressTestParams {
+                holdings: vec![demo_holding("JPM-5.25-2035", 1_000_000.0, 101.375)],
+                scenario,
+                settlement: None,
+                curve: None,
+            }))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&response_text(out)).unwrap();
+
+        assert_eq!(json["scenario"], "Rates +100bp");
+        let pnl = json["pnl"].as_f64().unwrap();
+        assert!(pnl < 0.0, "{pnl}");
+
+        // The repriced loss agrees with the duration-convexity estimate.
+        let line = &json["holdings"][0];
+        assert_eq!(line["rate_shift_bps"], 100.0);
+        let repriced = line["repriced_pnl"].as_f64().unwrap();
+        assert!(repriced < 0.0 && (repriced - pnl).abs() < 0.01 * pnl.abs());
+        assert!(
+            line["stressed_ytm_pct"].as_f64().unwrap() - line["base_ytm_pct"].as_f64().unwrap()
+                > 0.99
+        );
+        assert!(
+            json["stressed_weighted_modified_duration_years"]
+                .as_f64()
+                .unwrap()
+                < json["base_weighted_modified_duration_years"]
+                    .as_f64()
+                    .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn stress_test_custom_and_historical_scenarios() {
+        let server = ConvexMcpServer::new();
+        let holdings = vec![
+            demo_holding("AAPL-5.0-2030", 1_000_000.0, 102.5625),
+            demo_holding("VZ-6.0-2040", 1_000_000.0, 105.6875),
+        ];
+        let run = |scenario: &str| {
+            let params = StressTestParams {
+                holdings: holdings.clone(),
+                scenario: serde_json::from_str(scenario).unwrap(),
+                settlement: None,
+                curve: None,
+            };
+            let server = server.clone();
+            async move {
+                let out = server.stress_test(Parameters(params)).await?;
+                Ok::<_, McpError>(
+                    serde_json::from_str::<serde_json::Value>(&response_text(out)).unwrap(),
+                )
+            }
+        };
+
+        // BBB widening hits only VZ (BBB+).
+        let custom = run(r#"{"spread_by_rating_bps": {"BBB": 100}}"#)
+            .await
+            .unwrap();
+        assert_eq!(custom["scenario"], "Custom");
+        assert_eq!(custom["holdings"][0]["spread_shift_bps"], 0.0);
+        assert_eq!(custom["holdings"][1]["spread_shift_bps"], 100.0);
+        assert!(custom["pnl"].as_f64().unwrap() < 0.0);
+
+        // The 2008 replay rallies Treasuries further than AA-A spreads widen.
+        let gfc = run(r#""gfc_2008""#).await.unwrap();
+        let aapl = &gfc["holdings"][0];
+        assert!(aapl["rate_shift_bps"].as_f64().unwrap() < -100.0);
+        assert_eq!(aapl["spread_shift_bps"], 250.0);
+        assert!(gfc["rate_impact_pct"].as_f64().unwrap() > 0.0);
+
+        assert!(run(
+            r#"{"parallel_bps": 50, "key_rate_bps": [{"tenor_years": 2, "shift_bps": 10}]}"#
+        )
+        .await
+        .is_err());
+        assert!(run(r#"{"name": "Nothing"}"#).await.is_err());
+    }
 }
@@ -0,0 +1,191 @@
+//! Weighted-average coupon (WAC) and weighted-average maturity (WAM).
+//!
+//! Standard pool and fund reporting measures. Weights follow the configured
+//! [`WeightingMethod`] (market value by default, or par).
+
+use crate::analytics::parallel::maybe_parallel_fold;
+use crate::types::{AnalyticsConfig, Holding, WeightingMethod};
+use convex_core::types::Date;
+use rust_decimal::Decimal;
+
+/// Calculates the weighted-average coupon.
+///
+/// ## Formula
+///
+/// ```text
+/// WAC = Σ(w_i × c_i) / Σ(w_i)
+/// ```
+///
+/// Holdings without a coupon rate are excluded. Perpetuals are included,
+/// since their coupon is well defined.
+///
+/// # Returns
+///
+/// Returns `None` if no holdings have coupon data.
+#[must_use]
+pub fn weighted_average_coupon(holdings: &[Holding], config: &AnalyticsConfig) -> Option<f64> {
+    weighted_metric(holdings, config, |h| h.analytics.coupon_rate)
+}
+
+/// Calculates the weighted-average maturity in years as of `as_of`.
+///
+/// Each holding's remaining term is `(maturity_date - as_of) / 365`. When
+/// no maturity date is set, the pre-computed `years_to_maturity` is used
+/// instead. Holdings are excluded when:
+///
+/// - neither is available (perpetuals, or missing data), or
+/// - the remaining term is zero or negative (already matured).
+///
+/// # Returns
+///
+/// Returns `None` if no holdings have a positive remaining term.
+#[must_use]
+pub fn weighted_average_maturity(
+    holdings: &[Holding],
+    as_of: Date,
+    config: &AnalyticsConfig,
+) -> Option<f64> {
+    weighted_metric(holdings, config, |h| {
+        let years = match h.analytics.maturity_date {
+            Some(maturity) => as_of.days_between(&maturity) as f64 / 365.0,
+            None => h.analytics.years_to_maturity?,
+        };
+        (years > 0.0).then_some(years)
+    })
+}
+
+/// Internal helper to calculate weighted average of any metric.
+fn weighted_metric<F>(holdings: &[Holding], config: &AnalyticsConfig, get_value: F) -> Option<f64>
+where
+    F: Fn(&Holding) -> Option<f64> + Sync,
+{
+    let (sum_weighted, sum_weights) = maybe_parallel_fold(
+        holdings,
+        config,
+        (0.0_f64, 0.0_f64),
+        |(sum_w, sum_wt), h| {
+            if let Some(value) = get_value(h) {
+                let weight = weight_for_holding(h, config.weighting);
+                (sum_w + value * weight, sum_wt + weight)
+            } else {
+                (sum_w, sum_wt)
+            }
+        },
+        |(a, b), (c, d)| (a + c, b + d),
+    );
+
+    if sum_weights > 0.0 {
+        Some(sum_weighted / sum_weights)
+    } else {
+        None
+    }
+}
+
+/// Returns the weight for a holding as f64.
+fn weight_for_holding(holding: &Holding, method: WeightingMethod) -> f64 {
+    decimal_to_f64(holding.weight_value(method))
+}
+
+/// Converts Decimal to f64.
+fn decimal_to_f64(d: Decimal) -> f64 {
+    use rust_decimal::prelude::ToPrimitive;
+    d.to_f64().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::HoldingAnalytics;
+    use convex_bonds::types::BondIdentifiers;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn create_holding(
+        id: &str,
+        par: Decimal,
+        price: Decimal,
+        analytics: HoldingAnalytics,
+    ) -> Holding {
+        Holding::builder()
+            .id(id)
+            .identifiers(BondIdentifiers::new().with_ticker(format!("TST{id}")))
+            .par_amount(par)
+            .market_price(price)
+            .analytics(analytics)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_wac_is_market_value_weighted() {
+        let holdings = vec![
+            // MV 900k
+            create_holding(
+                "BOND1",
+                dec!(1_000_000),
+                dec!(90),
+                HoldingAnalytics::new().with_coupon_rate(0.03),
+            ),
+            // MV 550k
+            create_holding(
+                "BOND2",
+                dec!(500_000),
+                dec!(110),
+                HoldingAnalytics::new().with_coupon_rate(0.07),
+            ),
+        ];
+
+        let config = AnalyticsConfig::default();
+        let wac = weighted_average_coupon(&holdings, &config).unwrap();
+        let expected = (900_000.0 * 0.03 + 550_000.0 * 0.07) / 1_450_000.0;
+        assert!((wac - expected).abs() < 1e-12);
+
+        // Par weighting: (1M × 3% + 0.5M × 7%) / 1.5M
+        let par = AnalyticsConfig::default().with_weighting(WeightingMethod::ParValue);
+        let wac_par = weighted_average_coupon(&holdings, &par).unwrap();
+        assert!((wac_par - 0.065 / 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_wam_excludes_perpetuals_and_matured() {
+        let as_of = date(2025, 1, 15);
+        let holdings = vec![
+            create_holding(
+                "TWO_YEAR",
+                dec!(1_000_000),
+                dec!(100),
+                HoldingAnalytics::new().with_maturity_date(date(2027, 1, 15)),
+            ),
+            // No date: falls back to the pre-computed term.
+            create_holding(
+                "TEN_YEAR",
+                dec!(1_000_000),
+                dec!(100),
+                HoldingAnalytics::new().with_years_to_maturity(10.0),
+            ),
+            create_holding(
+                "PERPETUAL",
+                dec!(1_000_000),
+                dec!(100),
+                HoldingAnalytics::new().with_coupon_rate(0.06),
+            ),
+            create_holding(
+                "MATURED",
+                dec!(1_000_000),
+                dec!(100),
+                HoldingAnalytics::new().with_maturity_date(date(2025, 1, 1)),
+            ),
+        ];
+
+        let config = AnalyticsConfig::default();
+        let wam = weighted_average_maturity(&holdings, as_of, &config).unwrap();
+        let two_year = 730.0 / 365.0;
+        assert!((wam - (two_year + 10.0) / 2.0).abs() < 1e-12);
+
+        assert!(weighted_average_maturity(&holdings[2..], as_of, &config).is_none());
+        assert!(weighted_average_coupon(&holdings[..2], &config).is_none());
+    }
+}
@@ -5,9 +5,12 @@
 //! - Liquidity score distribution
 //! - Days to liquidate estimation
 //! - Liquidity stress testing
+//!
+//! Liquidity scores come from the [`LiquidityModel`] in [`AnalyticsConfig`];
+//! by default that is each holding's provided `liquidity_score`.
 
 use crate::analytics::parallel::maybe_parallel_fold;
-use crate::types::{AnalyticsConfig, Holding, WeightingMethod};
+use crate::types::{AnalyticsConfig, CreditRating, Holding, LiquidityModel, WeightingMethod};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -122,7 +125,7 @@ pub fn calculate_liquidity_metrics(
         .count();
     let score_coverage = holdings
         .iter()
-        .filter(|h| h.analytics.liquidity_score.is_some())
+        .filter(|h| holding_liquidity_score(h, &config.liquidity_model).is_some())
         .count();
 
     // Calculate liquidity buckets
@@ -133,8 +136,7 @@ pub fn calculate_liquidity_metrics(
         let highly_liquid: f64 = holdings
             .iter()
             .filter(|h| {
-                h.analytics
-                    .liquidity_score
+                holding_liquidity_score(h, &config.liquidity_model)
                     .map(|s| s >= 70.0)
                     .unwrap_or(false)
             })
@@ -146,8 +148,7 @@ pub fn calculate_liquidity_metrics(
         let moderately_liquid: f64 = holdings
             .iter()
             .filter(|h| {
-                h.analytics
-                    .liquidity_score
+                holding_liquidity_score(h, &config.liquidity_model)
                     .map(|s| (30.0..70.0).contains(&s))
                     .unwrap_or(false)
             })
@@ -159,8 +160,7 @@ pub fn calculate_liquidity_metrics(
         let illiquid: f64 = holdings
             .iter()
             .filter(|h| {
-                h.analytics
-                    .liquidity_score
+                holding_liquidity_score(h, &config.liquidity_model)
                     .map(|s| s < 30.0)
                     .unwrap_or(false)
             })
@@ -186,6 +186,62 @@ pub fn calculate_liquidity_metrics(
     }
 }
 
+/// Scores a holding's liquidity (0-100) under a liquidity model.
+///
+/// Returns the weighted average of the model components the holding has
+/// data for, or `None` if it has none with a positive weight.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_portfolio::analytics::holding_liquidity_score;
+/// use convex_portfolio::LiquidityModel;
+///
+/// let model = LiquidityModel::default().with_bid_ask_weight(1.0);
+/// let score = holding_liquidity_score(&holding, &model);
+/// ```
+#[must_use]
+pub fn holding_liquidity_score(holding: &Holding, model: &LiquidityModel) -> Option<f64> {
+    let analytics = &holding.analytics;
+    let bid_ask = analytics
+        .bid_ask_spread
+        .filter(|_| model.bid_ask_cap_bps > 0.0)
+        .map(|spread| 100.0 * (1.0 - (spread / model.bid_ask_cap_bps).clamp(0.0, 1.0)));
+    let size = analytics
+        .issue_size
+        .filter(|_| model.benchmark_issue_size > 0.0)
+        .map(|size| 100.0 * (size / model.benchmark_issue_size).clamp(0.0, 1.0));
+    let age = analytics
+        .years_since_issue
+        .filter(|_| model.seasoning_years > 0.0)
+        .map(|years| 100.0 * (1.0 - (years / model.seasoning_years).clamp(0.0, 1.0)));
+    let rating = holding
+        .classification
+        .rating
+        .composite
+        .filter(|r| *r != CreditRating::NotRated)
+        .map(|r| 100.0 * f64::from(CreditRating::D.score() - r.score()) / 21.0);
+
+    let components = [
+        (analytics.liquidity_score, model.provided_score_weight),
+        (bid_ask, model.bid_ask_weight),
+        (size, model.issuance_size_weight),
+        (age, model.age_weight),
+        (rating, model.rating_weight),
+    ];
+    let (sum_weighted, sum_weights) = components
+        .iter()
+        .filter(|(_, weight)| *weight > 0.0)
+        .filter_map(|(score, weight)| score.map(|s| (s * weight, *weight)))
+        .fold((0.0, 0.0), |(a, b), (c, d)| (a + c, b + d));
+
+    if sum_weights > 0.0 {
+        Some(sum_weighted / sum_weights)
+    } else {
+        None
+    }
+}
+
 /// Calculates weighted average bid-ask spread.
 ///
 /// # Returns
@@ -231,7 +287,7 @@ pub fn weighted_liquidity_score(holdings: &[Holding], config: &AnalyticsConfig)
         config,
         (0.0, 0.0),
         |(sum_w, sum_wt), h| {
-            if let Some(score) = h.analytics.liquidity_score {
+            if let Some(score) = holding_liquidity_score(h, &config.liquidity_model) {
                 let weight = match config.weighting {
                     WeightingMethod::MarketValue => h.market_value().to_f64().unwrap_or(0.0),
                     WeightingMethod::ParValue => h.par_amount.to_f64().unwrap_or(0.0),
@@ -340,13 +396,15 @@ pub struct BucketInfo {
 #[must_use]
 pub fn liquidity_distribution(
     holdings: &[Holding],
-    _config: &AnalyticsConfig,
+    config: &AnalyticsConfig,
 ) -> LiquidityDistribution {
     let mut by_bucket: HashMap<LiquidityBucket, Vec<&Holding>> = HashMap::new();
 
     for h in holdings {
-        let bucket =
-            LiquidityBucket::classify(h.analytics.liquidity_score, h.analytics.bid_ask_spread);
+        let bucket = LiquidityBucket::classify(
+            holding_liquidity_score(h, &config.liquidity_model),
+            h.analytics.bid_ask_spread,
+        );
         by_bucket.entry(bucket).or_default().push(h);
     }
 
@@ -371,7 +429,7 @@ pub fn liquidity_distribution(
             // Calculate averages for this bucket
             let scores: Vec<f64> = group
                 .iter()
-                .filter_map(|h| h.analytics.liquidity_score)
+                .filter_map(|h| holding_liquidity_score(h, &config.liquidity_model))
                 .collect();
             let avg_score = if !scores.is_empty() {
                 Some(scores.iter().sum::<f64>() / scores.len() as f64)
@@ -426,7 +484,7 @@ pub fn liquidity_distribution(
 pub fn estimate_days_to_liquidate(
     holdings: &[Holding],
     max_participation_rate: f64,
-    _config: &AnalyticsConfig,
+    config: &AnalyticsConfig,
 ) -> DaysToLiquidate {
     if holdings.is_empty() || max_participation_rate <= 0.0 {
         return DaysToLiquidate {
@@ -447,7 +505,7 @@ pub fn estimate_days_to_liquidate(
     for h in holdings {
         let mv = h.market_value().to_f64().unwrap_or(0.0);
 
-        if let Some(score) = h.analytics.liquidity_score {
+        if let Some(score) = holding_liquidity_score(h, &config.liquidity_model) {
             // Estimate ADV based on liquidity score
             // Assume liquidity score 100 = 10M ADV, score 0 = 100K ADV (logarithmic)
             let estimated_adv = 100_000.0 * (10.0_f64.powf(score / 50.0));
@@ -698,4 +756,72 @@ mod tests {
         // Illiquid > 15% → has concerns
         assert!(metrics.has_liquidity_concerns());
     }
+
+    #[test]
+    fn test_liquidity_model_bid_ask_weight() {
+        // Same provided score; only the bid-ask spread differs.
+        let holdings = vec![
+            create_holding_with_liquidity("TIGHT", dec!(500_000), Some(50.0), Some(2.0)),
+            create_holding_with_liquidity("WIDE", dec!(500_000), Some(50.0), Some(60.0)),
+        ];
+
+        // Default model: provided scores as-is.
+        let config = AnalyticsConfig::default();
+        let model = &config.liquidity_model;
+        assert_eq!(holding_liquidity_score(&holdings[0], model), Some(50.0));
+        assert_eq!(holding_liquidity_score(&holdings[1], model), Some(50.0));
+        let base = calculate_liquidity_metrics(&holdings, &config);
+        assert!((base.moderately_liquid_pct - 100.0).abs() < 1e-9);
+
+        // Equal weight on bid-ask: tight (98) up, wide (40) down.
+        let config = AnalyticsConfig::default()
+            .with_liquidity_model(LiquidityModel::default().with_bid_ask_weight(1.0));
+        let model = &config.liquidity_model;
+        let tight = holding_liquidity_score(&holdings[0], model).unwrap();
+        let wide = holding_liquidity_score(&holdings[1], model).unwrap();
+        assert!((tight - 74.0).abs() < 1e-9);
+        assert!((wide - 45.0).abs() < 1e-9);
+
+        // More bid-ask weight widens the gap further.
+        let heavier = LiquidityModel::default().with_bid_ask_weight(3.0);
+        let gap = holding_liquidity_score(&holdings[0], &heavier).unwrap()
+            - holding_liquidity_score(&holdings[1], &heavier).unwrap();
+        assert!(gap > tight - wide);
+
+        let metrics = calculate_liquidity_metrics(&holdings, &config);
+        assert!((metrics.highly_liquid_pct - 50.0).abs() < 1e-9);
+        let dist = liquidity_distribution(&holdings, &config);
+        assert_eq!(dist.by_bucket[&LiquidityBucket::HighlyLiquid].count, 1);
+        assert_eq!(dist.by_bucket[&LiquidityBucket::ModeratelyLiquid].count, 1);
+    }
+
+    #[test]
+    fn test_liquidity_model_size_age_rating() {
+        use crate::types::{Classification, RatingInfo};
+
+        let model = LiquidityModel::default()
+            .with_provided_score_weight(0.0)
+            .with_issuance_size_weight(1.0)
+            .with_age_weight(1.0)
+            .with_rating_weight(1.0);
+        let holding = HoldingBuilder::new()
+            .id("H1")
+            .identifiers(BondIdentifiers::from_isin_str("US912828Z229").unwrap())
+            .par_amount(dec!(1_000_000))
+            .market_price(dec!(100))
+            .classification(
+                Classification::new().with_rating(RatingInfo::from_composite(CreditRating::AAA)),
+            )
+            .analytics(
+                HoldingAnalytics::new()
+                    .with_issue_size(500_000_000.0)
+                    .with_years_since_issue(2.5),
+            )
+            .build()
+            .unwrap();
+
+        // Size 50, age 75, rating 100.
+        let score = holding_liquidity_score(&holding, &model).unwrap();
+        assert!((score - 75.0).abs() < 1e-9);
+    }
 }
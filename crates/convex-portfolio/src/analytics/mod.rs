@@ -3,6 +3,7 @@
 //! This module provides aggregated analytics for portfolios, including:
 //! - NAV and component breakdown
 //! - Weighted yield metrics (YTM, YTW, YTC)
+//! - Weighted-average coupon and maturity (WAC, WAM)
 //! - Risk aggregation (duration, DV01, convexity)
 //! - Spread analytics (Z-spread, OAS, etc.)
 //! - Key rate duration profiles
@@ -11,6 +12,7 @@
 //! All functions are pure - they take holdings and configuration as input
 //! and return computed results. No caching, no I/O, no side effects.
 
+mod averages;
 mod credit;
 mod key_rates;
 mod liquidity;
@@ -21,6 +23,7 @@ mod spreads;
 mod summary;
 mod yields;
 
+pub use averages::*;
 pub use credit::*;
 pub use key_rates::*;
 pub use liquidity::*;
@@ -161,6 +161,79 @@ pub fn weighted_effective_convexity(holdings: &[Holding], config: &AnalyticsConf
     weighted_metric(holdings, config, |h| h.analytics.effective_convexity)
 }
 
+/// Negative convexity exposure of a portfolio.
+///
+/// Callables, MBS and other holdings with short optionality lose value
+/// faster than they gain it as rates fall. This summarizes how much of
+/// the portfolio carries that profile alongside its option-adjusted duration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NegativeConvexityExposure {
+    /// IDs of holdings whose effective convexity is below the threshold.
+    pub holding_ids: Vec<String>,
+
+    /// Weight of the flagged holdings (percentage of the portfolio).
+    pub weight: f64,
+
+    /// Weighted average effective (option-adjusted) duration of the portfolio.
+    pub effective_duration: Option<f64>,
+}
+
+impl NegativeConvexityExposure {
+    /// Returns the number of flagged holdings.
+    #[must_use]
+    pub fn holdings_count(&self) -> usize {
+        self.holding_ids.len()
+    }
+}
+
+/// Identifies holdings with effective convexity below `threshold`.
+///
+/// Holdings without effective convexity data are never flagged. The
+/// flagged weight is expressed as a percentage of the total portfolio
+/// weight under the configured weighting method (typically market value).
+///
+/// # Example
+///
+/// ```ignore
+/// let exposure = negative_convexity_exposure(&portfolio.holdings, 0.0, &config);
+/// println!("Negatively convex: {:.1}%", exposure.weight);
+/// ```
+#[must_use]
+pub fn negative_convexity_exposure(
+    holdings: &[Holding],
+    threshold: f64,
+    config: &AnalyticsConfig,
+) -> NegativeConvexityExposure {
+    let total_weight: f64 = holdings
+        .iter()
+        .map(|h| weight_for_holding(h, config.weighting))
+        .sum();
+
+    let mut flagged_weight = 0.0;
+    let mut holding_ids = Vec::new();
+    for h in holdings {
+        if h.analytics
+            .effective_convexity
+            .is_some_and(|convexity| convexity < threshold)
+        {
+            flagged_weight += weight_for_holding(h, config.weighting);
+            holding_ids.push(h.id.clone());
+        }
+    }
+
+    let weight = if total_weight > 0.0 {
+        flagged_weight / total_weight * 100.0
+    } else {
+        0.0
+    };
+
+    NegativeConvexityExposure {
+        holding_ids,
+        weight,
+        effective_duration: weighted_effective_duration(holdings, config),
+    }
+}
+
 /// Calculates total portfolio DV01.
 ///
 /// ## Formula
@@ -449,6 +522,51 @@ mod tests {
         assert!((best - 5.25).abs() < 0.01);
     }
 
+    #[test]
+    fn test_negative_convexity_exposure() {
+        let bullet = Holding::builder()
+            .id("BULLET")
+            .identifiers(BondIdentifiers::new().with_ticker("BULL001"))
+            .par_amount(dec!(3_000_000))
+            .market_price(dec!(100))
+            .analytics(
+                HoldingAnalytics::new()
+                    .with_effective_duration(6.0)
+                    .with_effective_convexity(45.0),
+            )
+            .build()
+            .unwrap();
+
+        let callable = Holding::builder()
+            .id("CALLABLE")
+            .identifiers(BondIdentifiers::new().with_ticker("CALL001"))
+            .par_amount(dec!(1_000_000))
+            .market_price(dec!(100))
+            .analytics(
+                HoldingAnalytics::new()
+                    .with_effective_duration(2.0)
+                    .with_effective_convexity(-1.5),
+            )
+            .build()
+            .unwrap();
+
+        let holdings = vec![bullet, callable];
+        let config = AnalyticsConfig::default();
+        let exposure = negative_convexity_exposure(&holdings, 0.0, &config);
+
+        assert_eq!(exposure.holding_ids, vec!["CALLABLE".to_string()]);
+        assert_eq!(exposure.holdings_count(), 1);
+        // Callable is 1M of 4M market value
+        assert!((exposure.weight - 25.0).abs() < 1e-9);
+        // (3M × 6.0 + 1M × 2.0) / 4M = 5.0
+        assert!((exposure.effective_duration.unwrap() - 5.0).abs() < 1e-9);
+
+        // Nothing falls below a deeply negative threshold
+        let none = negative_convexity_exposure(&holdings, -10.0, &config);
+        assert!(none.holding_ids.is_empty());
+        assert!(none.weight.abs() < 1e-12);
+    }
+
     #[test]
     fn test_risk_metrics() {
         let holdings = vec![
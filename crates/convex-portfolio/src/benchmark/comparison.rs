@@ -4,7 +4,10 @@
 //! a portfolio and its benchmark.
 
 use super::tracking::{active_weights, ActiveWeights};
-use crate::analytics::{calculate_risk_metrics, calculate_spread_metrics, calculate_yield_metrics};
+use crate::analytics::{
+    aggregate_key_rate_profile, calculate_risk_metrics, calculate_spread_metrics,
+    calculate_yield_metrics, weighted_modified_duration,
+};
 use crate::bucketing::{bucket_by_rating, bucket_by_sector};
 use crate::types::{AnalyticsConfig, Holding, RatingBucket, Sector};
 use serde::{Deserialize, Serialize};
@@ -336,6 +339,104 @@ pub fn benchmark_comparison(
     }
 }
 
+/// Active key rate duration at one tenor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRateBet {
+    /// Tenor in years.
+    pub tenor: f64,
+
+    /// Portfolio key rate duration.
+    pub portfolio: f64,
+
+    /// Benchmark key rate duration.
+    pub benchmark: f64,
+
+    /// Active key rate duration (portfolio - benchmark).
+    pub active: f64,
+}
+
+/// Key rate profile of a portfolio relative to its benchmark.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRateActiveExposure {
+    /// Active key rate durations, one per standard tenor.
+    pub by_tenor: Vec<KeyRateBet>,
+
+    /// Sum of the active key rate durations.
+    pub total_active: f64,
+
+    /// Modified duration difference (portfolio - benchmark), for
+    /// reconciliation against `total_active`.
+    pub duration_difference: Option<f64>,
+}
+
+impl KeyRateActiveExposure {
+    /// Gets the active key rate duration at a specific tenor.
+    #[must_use]
+    pub fn at_tenor(&self, tenor: f64) -> Option<f64> {
+        self.by_tenor
+            .iter()
+            .find(|bet| (bet.tenor - tenor).abs() < 0.001)
+            .map(|bet| bet.active)
+    }
+}
+
+/// Calculates per-tenor active key rate durations (portfolio minus
+/// benchmark).
+///
+/// Each side is aggregated with [`aggregate_key_rate_profile`] on the
+/// standard tenors. Curve positioning shows up here even when the total
+/// durations match: a barbell against a bullet benchmark is long the wings
+/// and short the belly.
+///
+/// Returns `None` if either side has no key rate duration data.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_portfolio::benchmark::key_rate_active_exposure;
+///
+/// let exposure = key_rate_active_exposure(&portfolio, &benchmark, &config).unwrap();
+/// for bet in &exposure.by_tenor {
+///     println!("{:>5}Y: {:+.3}", bet.tenor, bet.active);
+/// }
+/// ```
+#[must_use]
+pub fn key_rate_active_exposure(
+    portfolio: &[Holding],
+    benchmark: &[Holding],
+    config: &AnalyticsConfig,
+) -> Option<KeyRateActiveExposure> {
+    let port_profile = aggregate_key_rate_profile(portfolio, config, None)?;
+    let bench_profile = aggregate_key_rate_profile(benchmark, config, None)?;
+
+    let by_tenor: Vec<KeyRateBet> = port_profile
+        .durations
+        .iter()
+        .zip(&bench_profile.durations)
+        .map(|(p, b)| KeyRateBet {
+            tenor: p.tenor,
+            portfolio: p.duration.as_f64(),
+            benchmark: b.duration.as_f64(),
+            active: p.duration.as_f64() - b.duration.as_f64(),
+        })
+        .collect();
+    let total_active = by_tenor.iter().map(|bet| bet.active).sum();
+
+    let duration_difference = match (
+        weighted_modified_duration(portfolio, config),
+        weighted_modified_duration(benchmark, config),
+    ) {
+        (Some(p), Some(b)) => Some(p - b),
+        _ => None,
+    };
+
+    Some(KeyRateActiveExposure {
+        by_tenor,
+        total_active,
+        duration_difference,
+    })
+}
+
 /// Calculates sector-level comparison.
 fn calculate_sector_comparison(
     portfolio: &[Holding],
@@ -476,6 +577,7 @@ mod tests {
     use crate::types::{
         Classification, CreditRating, HoldingAnalytics, HoldingBuilder, RatingInfo, SectorInfo,
     };
+    use convex_analytics::risk::{Duration, KeyRateDuration, KeyRateDurations};
     use convex_bonds::types::BondIdentifiers;
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
@@ -803,4 +905,67 @@ mod tests {
         // Duration ratio = 6 / 4 = 1.5
         assert!((comparison.duration.ratio.unwrap() - 1.5).abs() < 0.01);
     }
+
+    fn create_krd_holding(id: &str, krds: &[(f64, f64)]) -> Holding {
+        let durations = krds
+            .iter()
+            .map(|&(tenor, duration)| KeyRateDuration {
+                tenor,
+                duration: Duration::from(duration),
+            })
+            .collect();
+        let mut analytics = HoldingAnalytics::new()
+            .with_modified_duration(krds.iter().map(|(_, d)| d).sum::<f64>());
+        analytics.key_rate_durations = Some(KeyRateDurations::new(durations));
+
+        HoldingBuilder::new()
+            .id(id)
+            .identifiers(BondIdentifiers::from_isin_str("US912828Z229").unwrap())
+            .par_amount(dec!(1_000_000))
+            .market_price(dec!(100))
+            .analytics(analytics)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_key_rate_active_exposure_barbell_vs_bullet() {
+        // Equal-weight 2Y/10Y barbell against a 5Y bullet of the same duration.
+        let portfolio = vec![
+            create_krd_holding("2Y", &[(2.0, 1.9), (5.0, 0.0), (10.0, 0.0)]),
+            create_krd_holding("10Y", &[(2.0, 0.1), (5.0, 0.3), (10.0, 6.7)]),
+        ];
+        let benchmark = vec![create_krd_holding(
+            "5Y",
+            &[(2.0, 0.2), (5.0, 4.3), (10.0, 0.0)],
+        )];
+        let config = AnalyticsConfig::default();
+
+        let exposure = key_rate_active_exposure(&portfolio, &benchmark, &config).unwrap();
+
+        // Wings long, belly short.
+        assert!((exposure.at_tenor(2.0).unwrap() - 0.8).abs() < 1e-9);
+        assert!((exposure.at_tenor(5.0).unwrap() + 4.15).abs() < 1e-9);
+        assert!((exposure.at_tenor(10.0).unwrap() - 3.35).abs() < 1e-9);
+
+        // Tenors with no data on either side carry no bet.
+        assert_eq!(exposure.at_tenor(30.0), Some(0.0));
+
+        // Total active KRD reconciles to the modified duration difference.
+        let diff = exposure.duration_difference.unwrap();
+        assert!(diff.abs() < 1e-9);
+        assert!((exposure.total_active - diff).abs() < 1e-9);
+
+        // No KRD data on the benchmark: no comparison.
+        let bare = vec![create_test_holding(
+            "B",
+            dec!(100),
+            4.5,
+            100.0,
+            0.05,
+            None,
+            None,
+        )];
+        assert!(key_rate_active_exposure(&portfolio, &bare, &config).is_none());
+    }
 }
@@ -4,6 +4,7 @@
 //! - Active weights by holding and sector
 //! - Tracking error estimation
 //! - Duration and spread differences
+//! - Active key rate duration (curve) bets
 //! - Contribution to tracking error
 //!
 //! # Example
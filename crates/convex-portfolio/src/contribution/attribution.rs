@@ -5,7 +5,10 @@
 //! - Treasury return = -(duration × Δyield) + convexity adjustment
 //! - Spread return = -(spread duration × Δspread)
 //! - Residual = total - income - treasury - spread
+//!
+//! and Brinson-style sector allocation/selection against a benchmark.
 
+use crate::bucketing::{bucket_by_sector, SectorDistribution};
 use crate::types::{AnalyticsConfig, Holding, Sector};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -329,6 +332,179 @@ pub fn estimate_spread_returns(
         .collect()
 }
 
+/// Brinson attribution effects for one sector.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrinsonSector {
+    /// Portfolio weight (0-1).
+    pub portfolio_weight: f64,
+
+    /// Benchmark weight (0-1).
+    pub benchmark_weight: f64,
+
+    /// Market-value weighted portfolio return within the sector.
+    pub portfolio_return: f64,
+
+    /// Market-value weighted benchmark return within the sector.
+    pub benchmark_return: f64,
+
+    /// Allocation effect = (wp - wb) × Rb.
+    pub allocation: f64,
+
+    /// Selection effect = wb × (Rp - Rb).
+    pub selection: f64,
+
+    /// Interaction effect = (wp - wb) × (Rp - Rb).
+    pub interaction: f64,
+}
+
+impl BrinsonSector {
+    /// Sector contribution to active return (allocation + selection + interaction).
+    #[must_use]
+    pub fn active_return(&self) -> f64 {
+        self.allocation + self.selection + self.interaction
+    }
+}
+
+/// Brinson-Hood-Beebower attribution of active return to sector
+/// allocation and security selection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrinsonResult {
+    /// Effects by sector.
+    pub by_sector: HashMap<Sector, BrinsonSector>,
+
+    /// Effects for holdings without a sector.
+    pub unclassified: BrinsonSector,
+
+    /// Total portfolio return.
+    pub portfolio_return: f64,
+
+    /// Total benchmark return.
+    pub benchmark_return: f64,
+
+    /// Total allocation effect.
+    pub allocation: f64,
+
+    /// Total selection effect.
+    pub selection: f64,
+
+    /// Total interaction effect.
+    pub interaction: f64,
+}
+
+impl BrinsonResult {
+    /// Active return (portfolio - benchmark).
+    #[must_use]
+    pub fn active_return(&self) -> f64 {
+        self.portfolio_return - self.benchmark_return
+    }
+}
+
+/// Decomposes active return into Brinson sector allocation, selection and
+/// interaction effects.
+///
+/// Sector weights come from [`bucket_by_sector`]; sector returns are the
+/// market-value weighted returns of the holdings in each sector. For a
+/// sector held on only one side, the missing return is taken as equal to
+/// the other side's, so the whole active weight shows up as allocation.
+/// The effects sum to the total active return.
+///
+/// # Arguments
+///
+/// * `portfolio` - Portfolio holdings
+/// * `benchmark` - Benchmark holdings
+/// * `returns` - Period total return by holding ID (as decimal); holdings
+///   without an entry earn zero
+/// * `config` - Analytics configuration
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_portfolio::contribution::brinson_attribution;
+///
+/// let result = brinson_attribution(&portfolio, &benchmark, &returns, &config);
+/// println!("Allocation: {:.2}bp", result.allocation * 10_000.0);
+/// println!("Selection:  {:.2}bp", result.selection * 10_000.0);
+/// ```
+#[must_use]
+pub fn brinson_attribution(
+    portfolio: &[Holding],
+    benchmark: &[Holding],
+    returns: &HashMap<String, f64>,
+    config: &AnalyticsConfig,
+) -> BrinsonResult {
+    let port_dist = bucket_by_sector(portfolio, config);
+    let bench_dist = bucket_by_sector(benchmark, config);
+    let port_returns = sector_returns(portfolio, returns);
+    let bench_returns = sector_returns(benchmark, returns);
+
+    let mut sectors: std::collections::HashSet<Option<Sector>> =
+        port_returns.keys().copied().collect();
+    sectors.extend(bench_returns.keys().copied());
+
+    let mut result = BrinsonResult::default();
+    for sector in sectors {
+        let wp = sector_weight(&port_dist, sector);
+        let wb = sector_weight(&bench_dist, sector);
+        let (rp, rb) = match (port_returns.get(&sector), bench_returns.get(&sector)) {
+            (Some(&rp), Some(&rb)) => (rp, rb),
+            (Some(&rp), None) => (rp, rp),
+            (None, Some(&rb)) => (rb, rb),
+            (None, None) => continue,
+        };
+
+        let effects = BrinsonSector {
+            portfolio_weight: wp,
+            benchmark_weight: wb,
+            portfolio_return: rp,
+            benchmark_return: rb,
+            allocation: (wp - wb) * rb,
+            selection: wb * (rp - rb),
+            interaction: (wp - wb) * (rp - rb),
+        };
+        result.portfolio_return += wp * rp;
+        result.benchmark_return += wb * rb;
+        result.allocation += effects.allocation;
+        result.selection += effects.selection;
+        result.interaction += effects.interaction;
+
+        match sector {
+            Some(sector) => {
+                result.by_sector.insert(sector, effects);
+            }
+            None => result.unclassified = effects,
+        }
+    }
+
+    result
+}
+
+/// Market-value weighted return of each sector's holdings.
+fn sector_returns(
+    holdings: &[Holding],
+    returns: &HashMap<String, f64>,
+) -> HashMap<Option<Sector>, f64> {
+    let mut sums: HashMap<Option<Sector>, (f64, f64)> = HashMap::new();
+    for h in holdings {
+        let mv: f64 = h.market_value().try_into().unwrap_or(0.0);
+        let r = returns.get(&h.id).copied().unwrap_or(0.0);
+        let entry = sums.entry(h.classification.sector.composite).or_default();
+        entry.0 += mv * r;
+        entry.1 += mv;
+    }
+    sums.into_iter()
+        .map(|(sector, (weighted, mv))| (sector, if mv > 0.0 { weighted / mv } else { 0.0 }))
+        .collect()
+}
+
+/// Sector weight (0-1) from a sector distribution.
+fn sector_weight(distribution: &SectorDistribution, sector: Option<Sector>) -> f64 {
+    let metrics = match sector {
+        Some(sector) => distribution.get(sector),
+        None => Some(&distribution.unclassified),
+    };
+    metrics.map_or(0.0, |m| m.weight_pct / 100.0)
+}
+
 /// Helper to aggregate attribution by sector.
 fn aggregate_attribution_by_sector(
     holdings: &[Holding],
@@ -582,4 +758,61 @@ mod tests {
         // Expected total = (1/3 × 1.5%) + (2/3 × 2.5%) ≈ 2.17%
         assert!((attr.portfolio.total_return - 2.17).abs() < 0.1);
     }
+
+    #[test]
+    fn test_brinson_attribution_reconciles() {
+        // Portfolio overweights corporates (60/40 vs 40/60) and picks
+        // better government bonds.
+        let portfolio = vec![
+            create_test_holding("G1", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Government)),
+            create_test_holding("G2", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Government)),
+            create_test_holding("C1", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Corporate)),
+            create_test_holding("C2", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Corporate)),
+            create_test_holding("C3", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Corporate)),
+        ];
+        let benchmark = vec![
+            create_test_holding("G3", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Government)),
+            create_test_holding("G4", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Government)),
+            create_test_holding("G5", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Government)),
+            create_test_holding("C1", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Corporate)),
+            create_test_holding("C4", dec!(100), 5.0, 0.0, 0.0, Some(Sector::Corporate)),
+        ];
+        let returns: HashMap<String, f64> = [
+            ("G1", 0.012),
+            ("G2", 0.010),
+            ("G3", 0.008),
+            ("G4", 0.009),
+            ("G5", 0.007),
+            ("C1", 0.020),
+            ("C2", 0.015),
+            ("C3", 0.017),
+            ("C4", 0.022),
+        ]
+        .into_iter()
+        .map(|(id, r)| (id.to_string(), r))
+        .collect();
+        let config = AnalyticsConfig::default();
+
+        let result = brinson_attribution(&portfolio, &benchmark, &returns, &config);
+
+        let total = result.allocation + result.selection + result.interaction;
+        assert!((total - result.active_return()).abs() < 1e-9);
+        let by_sector: f64 = result
+            .by_sector
+            .values()
+            .map(BrinsonSector::active_return)
+            .sum();
+        assert!((by_sector - result.active_return()).abs() < 1e-9);
+
+        // Overweight in the higher-returning sector adds allocation.
+        let corp = &result.by_sector[&Sector::Corporate];
+        assert!((corp.portfolio_weight - 0.6).abs() < 1e-12);
+        assert!((corp.benchmark_weight - 0.4).abs() < 1e-12);
+        assert!((corp.allocation - 0.2 * 0.021).abs() < 1e-12);
+
+        // Better government picks add selection: 0.6 × (1.1% - 0.8%).
+        let govt = &result.by_sector[&Sector::Government];
+        assert!((govt.selection - 0.6 * 0.003).abs() < 1e-12);
+        assert!(govt.selection > 0.0);
+    }
 }
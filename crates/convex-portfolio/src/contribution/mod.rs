@@ -5,6 +5,7 @@
 //! - DV01 contribution by holding
 //! - Spread contribution by holding
 //! - Aggregated contributions by sector, rating, etc.
+//! - Brinson allocation/selection attribution against a benchmark
 //!
 //! # Example
 //!
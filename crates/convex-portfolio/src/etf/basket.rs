@@ -5,9 +5,11 @@
 //! - Tracking difference from benchmark
 //! - Cash component calculations
 //! - Creation unit analysis
+//! - Lot-size constrained basket optimization
 
+use crate::error::{PortfolioError, PortfolioResult};
 use crate::types::{AnalyticsConfig, Holding};
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -220,6 +222,233 @@ pub fn build_creation_basket(
     }
 }
 
+/// Constraints for [`optimize_creation_basket`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasketConstraints {
+    /// Creation unit size (number of ETF shares per creation unit).
+    pub creation_unit_size: Decimal,
+
+    /// Value of one creation unit (securities plus cash).
+    pub unit_value: Decimal,
+
+    /// Par increment in which bonds can be delivered.
+    pub lot_size: Decimal,
+
+    /// Smallest par amount of any bond included in the basket.
+    pub min_piece: Decimal,
+
+    /// Largest cash component allowed, as a % of unit value.
+    pub max_cash_pct: f64,
+}
+
+/// Result of [`optimize_creation_basket`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizedBasket {
+    /// The basket, with the residual cash as its cash component.
+    pub basket: CreationBasket,
+
+    /// Unit value not delivered in bonds.
+    pub residual_cash: Decimal,
+
+    /// Root-sum-square deviation of basket weights from the targets (%).
+    pub tracking_deviation_pct: f64,
+}
+
+/// Builds a creation basket of deliverable bond quantities that tracks
+/// target weights as closely as lot sizes allow.
+///
+/// Each bond starts at its target par rounded down to a whole lot (or zero
+/// if that is below the minimum piece). Lots are then added greedily, each
+/// time to the bond whose extra lot most reduces the squared deviation from
+/// the target weights, until no affordable lot improves the fit. Whatever
+/// is left of the unit value becomes the cash component.
+///
+/// # Arguments
+///
+/// * `holdings` - Candidate bonds, priced at their market value per unit par
+/// * `target_weights` - Target weight (%) by holding ID; bonds not listed are excluded
+/// * `constraints` - Unit size, lot size, minimum piece and cash limit
+///
+/// # Errors
+///
+/// Returns `PortfolioError::InvalidPortfolio` if the constraints are not
+/// positive, the target weights sum to more than 100 or the residual cash
+/// exceeds `max_cash_pct`, and `PortfolioError::InvalidHolding` if a target
+/// weight is negative or a targeted holding has no price.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_portfolio::etf::{optimize_creation_basket, BasketConstraints};
+///
+/// let constraints = BasketConstraints {
+///     creation_unit_size: dec!(50_000),
+///     unit_value: dec!(5_000_000),
+///     lot_size: dec!(1_000),
+///     min_piece: dec!(2_000),
+///     max_cash_pct: 2.0,
+/// };
+/// let result = optimize_creation_basket(&holdings, &target_weights, &constraints)?;
+/// println!("Cash: {} ({:.3}% deviation)", result.residual_cash, result.tracking_deviation_pct);
+/// ```
+pub fn optimize_creation_basket(
+    holdings: &[Holding],
+    target_weights: &HashMap<String, f64>,
+    constraints: &BasketConstraints,
+) -> PortfolioResult<OptimizedBasket> {
+    let BasketConstraints {
+        unit_value,
+        lot_size,
+        min_piece,
+        ..
+    } = *constraints;
+    if unit_value <= Decimal::ZERO || lot_size <= Decimal::ZERO || min_piece < Decimal::ZERO {
+        return Err(PortfolioError::invalid_portfolio(
+            "basket unit value and lot size must be positive",
+        ));
+    }
+    // A negative weight or an overweight total would leave negative cash,
+    // which the max_cash_pct check below does not catch.
+    let mut weights: Vec<_> = target_weights.iter().collect();
+    weights.sort_by(|a, b| a.0.cmp(b.0));
+    for (id, &weight) in &weights {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(PortfolioError::invalid_holding(
+                id.as_str(),
+                format!("target weight must be non-negative, got {weight}"),
+            ));
+        }
+    }
+    let total_weight: f64 = target_weights.values().sum();
+    if total_weight > 100.0 + 1e-9 {
+        return Err(PortfolioError::invalid_portfolio(format!(
+            "target weights sum to {total_weight}%, above 100%"
+        )));
+    }
+    let unit_f64 = unit_value.to_f64().unwrap_or(0.0);
+
+    // Candidates: (holding, price per unit par, target weight as a fraction).
+    let mut candidates = Vec::new();
+    for h in holdings {
+        let Some(&target) = target_weights.get(&h.id) else {
+            continue;
+        };
+        if h.par_amount.is_zero() || h.market_value() <= Decimal::ZERO {
+            return Err(PortfolioError::invalid_holding(
+                &h.id,
+                "cannot size a basket position without a price",
+            ));
+        }
+        candidates.push((h, h.market_value() / h.par_amount, target / 100.0));
+    }
+
+    // Round each target down to whole lots.
+    let mut quantities: Vec<Decimal> = candidates
+        .iter()
+        .map(|(_, unit_price, target)| {
+            let ideal = Decimal::from_f64(target * unit_f64).unwrap_or_default() / unit_price;
+            let lots = (ideal / lot_size).floor() * lot_size;
+            if lots < min_piece {
+                Decimal::ZERO
+            } else {
+                lots
+            }
+        })
+        .collect();
+    let mut cash = unit_value
+        - quantities
+            .iter()
+            .zip(&candidates)
+            .map(|(q, (_, unit_price, _))| q * unit_price)
+            .sum::<Decimal>();
+
+    // Greedy top-up: add the lot that most reduces squared deviation.
+    loop {
+        let mut best: Option<(usize, Decimal, f64)> = None;
+        for (i, (_, unit_price, target)) in candidates.iter().enumerate() {
+            let step = if quantities[i].is_zero() {
+                min_piece.max(lot_size)
+            } else {
+                lot_size
+            };
+            let cost = step * unit_price;
+            if cost > cash {
+                continue;
+            }
+            let weight = (quantities[i] * unit_price).to_f64().unwrap_or(0.0) / unit_f64;
+            let added = cost.to_f64().unwrap_or(0.0) / unit_f64;
+            // Change in squared deviation for the bond; cash (target 0) falls
+            // by the same amount.
+            let cash_weight = cash.to_f64().unwrap_or(0.0) / unit_f64;
+            let gain = (weight - target).powi(2) - (weight + added - target).powi(2)
+                + cash_weight.powi(2)
+                - (cash_weight - added).powi(2);
+            if gain > 0.0 && best.is_none_or(|(_, _, g)| gain > g) {
+                best = Some((i, step, gain));
+            }
+        }
+        let Some((i, step, _)) = best else {
+            break;
+        };
+        quantities[i] += step;
+        cash -= step * candidates[i].1;
+    }
+
+    let cash_pct = cash.to_f64().unwrap_or(0.0) / unit_f64 * 100.0;
+    if cash_pct > constraints.max_cash_pct {
+        return Err(PortfolioError::invalid_portfolio(format!(
+            "residual cash {cash_pct:.4}% exceeds the {:.4}% limit",
+            constraints.max_cash_pct
+        )));
+    }
+
+    let mut deviation_sq = (cash_pct / 100.0).powi(2);
+    let mut components = Vec::new();
+    for ((h, unit_price, target), quantity) in candidates.iter().zip(quantities) {
+        let market_value = quantity * unit_price;
+        let weight = market_value.to_f64().unwrap_or(0.0) / unit_f64;
+        deviation_sq += (weight - target).powi(2);
+        if quantity.is_zero() {
+            continue;
+        }
+        components.push(BasketComponent {
+            holding_id: h.id.clone(),
+            security_id: h
+                .identifiers
+                .isin()
+                .map(|i| i.to_string())
+                .unwrap_or_default(),
+            quantity,
+            price: h.market_price,
+            market_value,
+            weight_pct: weight * 100.0,
+            is_substitution: false,
+        });
+    }
+    components.sort_by(|a, b| {
+        b.weight_pct
+            .partial_cmp(&a.weight_pct)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let securities_value = unit_value - cash;
+    Ok(OptimizedBasket {
+        basket: CreationBasket {
+            creation_unit_size: constraints.creation_unit_size,
+            security_count: components.len(),
+            components,
+            securities_value,
+            cash_component: cash,
+            total_value: unit_value,
+            nav_per_cu: unit_f64,
+            estimated_cost_bps: None,
+            substitution_count: 0,
+        },
+        residual_cash: cash,
+        tracking_deviation_pct: deviation_sq.sqrt() * 100.0,
+    })
+}
+
 /// Analyzes differences between creation basket and target portfolio.
 ///
 /// # Arguments
@@ -568,4 +797,100 @@ mod tests {
         // Cash % = 5,000 / 50,000 × 100 = 10%
         assert!((basket.cash_pct() - 10.0).abs() < 0.1);
     }
+
+    #[test]
+    fn test_optimize_creation_basket_lot_sizes_leave_cash() {
+        let holdings = vec![
+            create_test_holding("H1", dec!(1_000_000), dec!(100)),
+            create_test_holding("H2", dec!(1_000_000), dec!(97.3)),
+            create_test_holding("UNLISTED", dec!(1_000_000), dec!(101)),
+        ];
+        let targets = HashMap::from([("H1".to_string(), 50.0), ("H2".to_string(), 50.0)]);
+        let mut constraints = BasketConstraints {
+            creation_unit_size: dec!(50_000),
+            unit_value: dec!(1_000_000),
+            lot_size: dec!(10_000),
+            min_piece: dec!(20_000),
+            max_cash_pct: 1.0,
+        };
+
+        let result = optimize_creation_basket(&holdings, &targets, &constraints).unwrap();
+        let basket = &result.basket;
+
+        // H1 fills its target exactly; H2's ideal 513,875 par rounds to
+        // 510,000 and the next lot (9,730) no longer fits.
+        assert_eq!(basket.security_count, 2);
+        let h2 = basket
+            .components
+            .iter()
+            .find(|c| c.holding_id == "H2")
+            .unwrap();
+        assert_eq!(h2.quantity, dec!(510_000));
+        assert_eq!(result.residual_cash, dec!(3_770));
+        assert_eq!(basket.cash_component, result.residual_cash);
+        assert_eq!(
+            basket.securities_value + basket.cash_component,
+            dec!(1_000_000)
+        );
+        assert!(result.tracking_deviation_pct > 0.0 && result.tracking_deviation_pct < 1.0);
+
+        // A tighter cash limit cannot be met with these lots.
+        constraints.max_cash_pct = 0.1;
+        assert!(optimize_creation_basket(&holdings, &targets, &constraints).is_err());
+    }
+
+    #[test]
+    fn test_optimize_creation_basket_tops_up_underweight() {
+        // 1,000,000 over three equal targets: 330,000 each after rounding,
+        // leaving room for one more lot.
+        let holdings = vec![
+            create_test_holding("A", dec!(1_000_000), dec!(100)),
+            create_test_holding("B", dec!(1_000_000), dec!(100)),
+            create_test_holding("C", dec!(1_000_000), dec!(100)),
+        ];
+        let targets: HashMap<String, f64> = ["A", "B", "C"]
+            .iter()
+            .map(|id| (id.to_string(), 100.0 / 3.0))
+            .collect();
+        let constraints = BasketConstraints {
+            creation_unit_size: dec!(50_000),
+            unit_value: dec!(1_000_000),
+            lot_size: dec!(10_000),
+            min_piece: dec!(10_000),
+            max_cash_pct: 0.5,
+        };
+
+        let result = optimize_creation_basket(&holdings, &targets, &constraints).unwrap();
+        let total_par: Decimal = result.basket.components.iter().map(|c| c.quantity).sum();
+        assert_eq!(total_par, dec!(1_000_000));
+        assert!(result.residual_cash.is_zero());
+    }
+
+    #[test]
+    fn test_optimize_creation_basket_rejects_invalid_weights() {
+        let holdings = vec![
+            create_test_holding("A", dec!(1_000_000), dec!(100)),
+            create_test_holding("B", dec!(1_000_000), dec!(100)),
+        ];
+        let constraints = BasketConstraints {
+            creation_unit_size: dec!(50_000),
+            unit_value: dec!(1_000_000),
+            lot_size: dec!(10_000),
+            min_piece: dec!(10_000),
+            max_cash_pct: 5.0,
+        };
+
+        // A short leg would fund an overweight long leg with negative cash.
+        let negative = HashMap::from([("A".to_string(), 110.0), ("B".to_string(), -10.0)]);
+        assert!(matches!(
+            optimize_creation_basket(&holdings, &negative, &constraints),
+            Err(PortfolioError::InvalidHolding { id, .. }) if id == "B"
+        ));
+
+        let overweight = HashMap::from([("A".to_string(), 60.0), ("B".to_string(), 60.0)]);
+        assert!(matches!(
+            optimize_creation_basket(&holdings, &overweight, &constraints),
+            Err(PortfolioError::InvalidPortfolio { .. })
+        ));
+    }
 }
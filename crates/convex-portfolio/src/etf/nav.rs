@@ -190,6 +190,10 @@ pub fn premium_discount(nav_per_share: f64, market_price: f64) -> Option<f64> {
     }
 }
 
+/// Trailing window (observations) used for the rolling statistics in
+/// [`PremiumDiscountStats`], about one trading month.
+pub const PREMIUM_DISCOUNT_ROLLING_WINDOW: usize = 20;
+
 /// Historical premium/discount tracking point.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PremiumDiscountPoint {
@@ -235,6 +239,15 @@ pub struct PremiumDiscountStats {
 
     /// Days at par (within 0.05% threshold).
     pub days_at_par: usize,
+
+    /// Mean premium/discount (%) over the trailing
+    /// [`PREMIUM_DISCOUNT_ROLLING_WINDOW`] observations before the latest.
+    #[serde(default)]
+    pub rolling_mean: f64,
+
+    /// Standard deviation of premium/discount over the same trailing window.
+    #[serde(default)]
+    pub rolling_std: f64,
 }
 
 impl PremiumDiscountStats {
@@ -281,6 +294,8 @@ pub fn calculate_premium_discount_stats(history: &[PremiumDiscountPoint]) -> Pre
             days_at_premium: 0,
             days_at_discount: 0,
             days_at_par: 0,
+            rolling_mean: 0.0,
+            rolling_std: 0.0,
         };
     }
 
@@ -314,6 +329,9 @@ pub fn calculate_premium_discount_stats(history: &[PremiumDiscountPoint]) -> Pre
     let days_at_discount = values.iter().filter(|&&v| v < -PAR_THRESHOLD).count();
     let days_at_par = values.iter().filter(|&&v| v.abs() <= PAR_THRESHOLD).count();
 
+    let (rolling_mean, rolling_std) =
+        mean_and_std(trailing_window(&values, PREMIUM_DISCOUNT_ROLLING_WINDOW));
+
     PremiumDiscountStats {
         count,
         average,
@@ -324,7 +342,63 @@ pub fn calculate_premium_discount_stats(history: &[PremiumDiscountPoint]) -> Pre
         days_at_premium,
         days_at_discount,
         days_at_par,
+        rolling_mean,
+        rolling_std,
+    }
+}
+
+/// Z-score of the latest premium/discount against its trailing distribution.
+///
+/// The last point in `history` is the current observation; the mean and
+/// standard deviation come from the `window` points before it, or from all
+/// earlier points if there are fewer. Large magnitudes flag a dislocation
+/// between the ETF price and its NAV.
+///
+/// Returns 0.0 if there are fewer than two trailing points or they show no
+/// dispersion.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use convex_portfolio::etf::premium_discount_zscore;
+///
+/// if premium_discount_zscore(&history, 20).abs() > 3.0 {
+///     println!("Premium/discount dislocated from its recent range");
+/// }
+/// ```
+#[must_use]
+pub fn premium_discount_zscore(history: &[PremiumDiscountPoint], window: usize) -> f64 {
+    let values: Vec<f64> = history.iter().map(|p| p.premium_discount_pct).collect();
+    let Some(&current) = values.last() else {
+        return 0.0;
+    };
+    let trailing = trailing_window(&values, window);
+    if trailing.len() < 2 {
+        return 0.0;
+    }
+    let (mean, std_dev) = mean_and_std(trailing);
+    if std_dev > 0.0 {
+        (current - mean) / std_dev
+    } else {
+        0.0
+    }
+}
+
+/// Up to `window` values immediately before the last one.
+fn trailing_window(values: &[f64], window: usize) -> &[f64] {
+    let end = values.len().saturating_sub(1);
+    &values[end.saturating_sub(window)..end]
+}
+
+/// Mean and population standard deviation, zero for an empty slice.
+fn mean_and_std(values: &[f64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (0.0, 0.0);
     }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance.sqrt())
 }
 
 #[cfg(test)]
@@ -499,4 +573,56 @@ mod tests {
         assert!((stats.pct_at_premium() - 50.0).abs() < 0.01);
         assert!((stats.pct_at_discount() - 50.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_premium_discount_stats_without_rolling_fields_deserialize() {
+        // Stats serialized before the rolling window was added.
+        let json = r#"{
+            "count": 3, "average": 0.1, "median": 0.1, "std_dev": 0.05,
+            "max_premium": 0.15, "max_discount": -0.05,
+            "days_at_premium": 2, "days_at_discount": 1, "days_at_par": 0
+        }"#;
+        let stats: PremiumDiscountStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.rolling_mean, 0.0);
+        assert_eq!(stats.rolling_std, 0.0);
+    }
+
+    #[test]
+    fn test_premium_discount_zscore_flags_outlier() {
+        // Stable history oscillating ±0.05% around a 0.10% premium, then a
+        // 1.5% premium today.
+        let mut history: Vec<PremiumDiscountPoint> = (0..30)
+            .map(|i| {
+                let pct = if i % 2 == 0 { 0.15 } else { 0.05 };
+                PremiumDiscountPoint {
+                    date: Date::from_ymd(2025, 1, 1).unwrap().add_days(i),
+                    nav: 100.0,
+                    market_price: 100.0 + pct,
+                    premium_discount_pct: pct,
+                }
+            })
+            .collect();
+        history.push(PremiumDiscountPoint {
+            date: Date::from_ymd(2025, 1, 31).unwrap(),
+            nav: 100.0,
+            market_price: 101.5,
+            premium_discount_pct: 1.5,
+        });
+
+        // Trailing mean 0.10, std 0.05: z = (1.5 - 0.1) / 0.05 = 28.
+        let z = premium_discount_zscore(&history, 20);
+        assert!((z - 28.0).abs() < 1e-9, "z = {z}");
+
+        // A window longer than the history uses every earlier point.
+        assert!((premium_discount_zscore(&history, 500) - 28.0).abs() < 1e-9);
+
+        // The last stable point is unremarkable.
+        assert!(premium_discount_zscore(&history[..30], 20).abs() < 1.5);
+        assert_eq!(premium_discount_zscore(&history[..1], 20), 0.0);
+
+        let stats = calculate_premium_discount_stats(&history);
+        assert!((stats.rolling_mean - 0.10).abs() < 1e-12);
+        assert!((stats.rolling_std - 0.05).abs() < 1e-12);
+    }
 }
@@ -5,11 +5,19 @@
 //! - Distribution yield
 //! - Compliance metrics for fund reporting
 
-use crate::types::{AnalyticsConfig, Holding};
-use rust_decimal::prelude::ToPrimitive;
+use crate::error::{PortfolioError, PortfolioResult};
+use crate::types::{AnalyticsConfig, Holding, Sector};
+use convex_bonds::conventions::{BondConventions, ConventionRegistry, InstrumentType, Market};
+use convex_bonds::traits::{Bond, BondAnalytics};
+use convex_bonds::FixedRateBond;
+use convex_core::types::Frequency;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+/// Length of the SEC yield measurement period in days.
+const SEC_YIELD_PERIOD_DAYS: i64 = 30;
+
 /// SEC 30-day yield calculation result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecYield {
@@ -50,6 +58,153 @@ impl SecYield {
     }
 }
 
+/// How the income numerator of the SEC yield is derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SecYieldMethod {
+    /// Uses `net_investment_income` as supplied.
+    #[default]
+    Simplified,
+    /// Form N-1A standardized method: income is rebuilt from the holdings,
+    /// each earning its yield to maturity (or to the next call, for premium
+    /// callables) on its market value, less expenses accrued at the expense
+    /// ratio.
+    StandardizedN1A,
+}
+
+/// A debt holding as seen by the standardized SEC yield calculation.
+#[derive(Debug, Clone)]
+pub struct SecYieldHolding {
+    /// Par amount held.
+    pub par_amount: Decimal,
+
+    /// Clean price per 100 par at period start.
+    pub clean_price: Decimal,
+
+    /// Annual coupon rate (e.g. 0.05 for 5%).
+    pub coupon_rate: f64,
+
+    /// Maturity date (redeemed at par).
+    pub maturity: convex_core::types::Date,
+
+    /// Next call date and call price per 100 par, if callable.
+    pub next_call: Option<(convex_core::types::Date, Decimal)>,
+
+    /// Market conventions (day count, coupon frequency, calendar) of the
+    /// obligation.
+    pub conventions: BondConventions,
+}
+
+impl SecYieldHolding {
+    /// Builds from a portfolio holding, using its pre-calculated coupon rate
+    /// and maturity date. Conventions are looked up for the holding's market
+    /// (its country, else its currency) and sector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the coupon rate or maturity date is missing, or
+    /// no conventions are known for the holding's market.
+    pub fn from_holding(holding: &Holding) -> PortfolioResult<Self> {
+        let missing = |what: &str| PortfolioError::invalid_holding(&holding.id, what);
+        Ok(Self {
+            par_amount: holding.par_amount,
+            clean_price: holding.market_price,
+            coupon_rate: holding
+                .analytics
+                .coupon_rate
+                .ok_or_else(|| missing("missing coupon rate"))?,
+            maturity: holding
+                .analytics
+                .maturity_date
+                .ok_or_else(|| missing("missing maturity date"))?,
+            next_call: None,
+            conventions: holding_conventions(holding)
+                .ok_or_else(|| missing("no bond conventions for its market"))?,
+        })
+    }
+
+    /// Sets the next call date and price.
+    #[must_use]
+    pub fn with_next_call(mut self, date: convex_core::types::Date, price: Decimal) -> Self {
+        self.next_call = Some((date, price));
+        self
+    }
+
+    /// Sets the market conventions.
+    #[must_use]
+    pub fn with_conventions(mut self, conventions: BondConventions) -> Self {
+        self.conventions = conventions;
+        self
+    }
+
+    /// Standardized income over `days` days from `start`.
+    ///
+    /// Per Form N-1A, each obligation earns its yield on its market value
+    /// including accrued interest: `YTM × MV / 360 × days`. The yield is the
+    /// semi-annual yield, on the obligation's own conventions, to the next
+    /// call for a bond priced above its call price, otherwise to maturity.
+    fn period_income(&self, start: convex_core::types::Date, days: i64) -> PortfolioResult<f64> {
+        let (redemption_date, redemption_price) = match self.next_call {
+            Some((date, call_price)) if date > start && self.clean_price > call_price => {
+                (date, call_price)
+            }
+            _ => (self.maturity, Decimal::ONE_HUNDRED),
+        };
+        let days_to_redemption = start.days_between(&redemption_date);
+        if days_to_redemption <= 0 {
+            return Ok(0.0);
+        }
+
+        let invalid = |reason: String| PortfolioError::invalid_portfolio(reason);
+        let years = i32::try_from(days_to_redemption / 365 + 1).unwrap_or(i32::MAX);
+        let issue = redemption_date
+            .add_years(-years)
+            .map_err(|e| invalid(format!("SEC yield holding: {e}")))?;
+        let coupon_rate = Decimal::from_f64(self.coupon_rate)
+            .ok_or_else(|| invalid(format!("invalid coupon rate {}", self.coupon_rate)))?;
+        let bond = FixedRateBond::builder()
+            .cusip_unchecked("SECYIELD0")
+            .coupon_rate(coupon_rate)
+            .maturity(redemption_date)
+            .issue_date(issue)
+            .redemption_value(redemption_price)
+            .with_conventions(&self.conventions)
+            .build()
+            .map_err(|e| invalid(format!("SEC yield holding: {e}")))?;
+        let ytm = bond
+            .yield_to_maturity(start, self.clean_price, Frequency::SemiAnnual)
+            .map_err(|e| invalid(format!("SEC yield holding due {redemption_date}: {e}")))?;
+
+        let accrued = bond.accrued_interest(start);
+        let market_value = ((self.clean_price + accrued) / Decimal::ONE_HUNDRED * self.par_amount)
+            .to_f64()
+            .unwrap_or(0.0);
+        Ok(ytm.yield_value * market_value / 360.0 * days.min(days_to_redemption) as f64)
+    }
+}
+
+/// Registered conventions for a holding's market and sector. The market is
+/// the holding's country, else its currency's (the first two letters of an
+/// ISO 4217 code are the country, `EU` for the euro).
+fn holding_conventions(holding: &Holding) -> Option<BondConventions> {
+    let market = holding
+        .classification
+        .country
+        .as_deref()
+        .and_then(Market::from_country_code)
+        .or_else(|| Market::from_country_code(&holding.currency.code()[..2]))?;
+    let instrument_type = match holding.classification.sector.composite {
+        Some(Sector::Government) => InstrumentType::GovernmentBond,
+        Some(Sector::Agency) => InstrumentType::Agency,
+        Some(Sector::Municipal) => InstrumentType::Municipal,
+        _ => InstrumentType::CorporateIG,
+    };
+    let registry = ConventionRegistry::global();
+    registry
+        .get_by_market(market, instrument_type)
+        .or_else(|| registry.get_by_market(market, InstrumentType::GovernmentBond))
+        .cloned()
+}
+
 /// SEC 30-day yield input data.
 #[derive(Debug, Clone)]
 pub struct SecYieldInput {
@@ -68,8 +223,17 @@ pub struct SecYieldInput {
     /// Fee waivers during the period.
     pub fee_waivers: Option<Decimal>,
 
-    /// As-of date.
+    /// As-of date (end of the 30-day period).
     pub as_of_date: convex_core::types::Date,
+
+    /// Income method.
+    pub method: SecYieldMethod,
+
+    /// Debt holdings (standardized method only).
+    pub holdings: Vec<SecYieldHolding>,
+
+    /// Annual net expense ratio (standardized method only, e.g. 0.0015).
+    pub expense_ratio: Option<f64>,
 }
 
 /// Calculates SEC 30-day yield.
@@ -88,6 +252,13 @@ pub struct SecYieldInput {
 ///
 /// The formula compounds the 30-day return to annualize it (^6 for semi-annual compounding).
 ///
+/// With [`SecYieldMethod::Simplified`], `a - b` is the supplied
+/// `net_investment_income`. With [`SecYieldMethod::StandardizedN1A`], `a` is
+/// the sum over `holdings` of `YTM × MV / 360 × 30`, with yields and market
+/// values (including accrued interest) taken at the start of the 30 days
+/// ending `as_of_date`, and `b` is the expense ratio accrued on `c × d` for
+/// the period.
+///
 /// # Arguments
 ///
 /// * `input` - SEC yield calculation inputs
@@ -96,6 +267,11 @@ pub struct SecYieldInput {
 ///
 /// SEC yield result with both subsidized and unsubsidized yields.
 ///
+/// # Errors
+///
+/// With [`SecYieldMethod::StandardizedN1A`], returns an error if a holding's
+/// bond cannot be built or its yield does not solve.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -108,19 +284,36 @@ pub struct SecYieldInput {
 ///     gross_expenses: Some(dec!(10_000)),
 ///     fee_waivers: Some(dec!(2_000)),
 ///     as_of_date: Date::from_ymd(2025, 1, 15).unwrap(),
+///     method: SecYieldMethod::Simplified,
+///     holdings: Vec::new(),
+///     expense_ratio: None,
 /// };
 ///
-/// let result = calculate_sec_yield(&input);
+/// let result = calculate_sec_yield(&input)?;
 /// println!("SEC 30-Day Yield: {:.2}%", result.sec_30_day_yield * 100.0);
 /// ```
-#[must_use]
-pub fn calculate_sec_yield(input: &SecYieldInput) -> SecYield {
+pub fn calculate_sec_yield(input: &SecYieldInput) -> PortfolioResult<SecYield> {
     let shares = input.avg_shares_outstanding.to_f64().unwrap_or(1.0);
     let price = input.max_offering_price.to_f64().unwrap_or(1.0);
-    let net_income = input.net_investment_income.to_f64().unwrap_or(0.0);
-
     // SEC yield formula: 2 × ((income / (shares × price)) + 1)^6 - 1
     let denominator = shares * price;
+
+    let (gross_income, accrued_expenses) = match input.method {
+        SecYieldMethod::Simplified => (input.net_investment_income.to_f64().unwrap_or(0.0), 0.0),
+        SecYieldMethod::StandardizedN1A => {
+            let start = input.as_of_date.add_days(-SEC_YIELD_PERIOD_DAYS);
+            let income = input
+                .holdings
+                .iter()
+                .map(|h| h.period_income(start, SEC_YIELD_PERIOD_DAYS))
+                .sum::<PortfolioResult<f64>>()?;
+            let expenses =
+                input.expense_ratio.unwrap_or(0.0) * denominator * SEC_YIELD_PERIOD_DAYS as f64
+                    / 360.0;
+            (income, expenses)
+        }
+    };
+    let net_income = gross_income - accrued_expenses;
     let yield_30_day = if denominator > 0.0 {
         let ratio = net_income / denominator;
         // Compound to annual (6 periods of 2 months = 1 year)
@@ -149,17 +342,26 @@ pub fn calculate_sec_yield(input: &SecYieldInput) -> SecYield {
             None
         };
 
-    SecYield {
+    let (interest_income, accrued_expenses) = match input.method {
+        // Already netted in input
+        SecYieldMethod::Simplified => (input.net_investment_income, Decimal::ZERO),
+        SecYieldMethod::StandardizedN1A => (
+            Decimal::from_f64(gross_income).unwrap_or_default(),
+            Decimal::from_f64(accrued_expenses).unwrap_or_default(),
+        ),
+    };
+
+    Ok(SecYield {
         sec_30_day_yield: yield_30_day,
         unsubsidized_yield,
         dividend_income: Decimal::ZERO, // Would need breakdown
-        interest_income: input.net_investment_income,
-        total_income: input.net_investment_income,
-        accrued_expenses: Decimal::ZERO, // Already netted in input
+        interest_income,
+        total_income: interest_income,
+        accrued_expenses,
         avg_shares: input.avg_shares_outstanding,
         max_offering_price: input.max_offering_price,
         as_of_date: input.as_of_date,
-    }
+    })
 }
 
 /// Distribution yield calculation result.
@@ -508,8 +710,9 @@ pub fn run_compliance_checks(
 mod tests {
     use super::*;
     use crate::types::{Classification, HoldingAnalytics, HoldingBuilder, SectorInfo};
+    use convex_bonds::conventions::{uk_gilt, us_corporate};
     use convex_bonds::types::BondIdentifiers;
-    use convex_core::types::Date;
+    use convex_core::types::{Currency, Date};
     use rust_decimal_macros::dec;
 
     #[test]
@@ -521,9 +724,12 @@ mod tests {
             gross_expenses: None,
             fee_waivers: None,
             as_of_date: Date::from_ymd(2025, 1, 15).unwrap(),
+            method: SecYieldMethod::Simplified,
+            holdings: Vec::new(),
+            expense_ratio: None,
         };
 
-        let result = calculate_sec_yield(&input);
+        let result = calculate_sec_yield(&input).unwrap();
 
         // income / (shares × price) = 50,000 / (1,000,000 × 25) = 0.002
         // SEC yield = 2 × ((1 + 0.002)^6 - 1) ≈ 0.0241 or 2.41%
@@ -540,9 +746,12 @@ mod tests {
             gross_expenses: Some(dec!(10_000)),
             fee_waivers: Some(dec!(2_000)),
             as_of_date: Date::from_ymd(2025, 1, 15).unwrap(),
+            method: SecYieldMethod::Simplified,
+            holdings: Vec::new(),
+            expense_ratio: None,
         };
 
-        let result = calculate_sec_yield(&input);
+        let result = calculate_sec_yield(&input).unwrap();
 
         assert!(result.sec_30_day_yield > 0.0);
         assert!(result.unsubsidized_yield.is_some());
@@ -550,6 +759,151 @@ mod tests {
         assert!(result.unsubsidized_yield.unwrap() < result.sec_30_day_yield);
     }
 
+    #[test]
+    fn test_standardized_sec_yield_n1a_example() {
+        // Period 15 Jan - 14 Feb 2025. The fund holds $10mm of a 6% 30/360
+        // semi-annual bond due 15 Jan 2030, priced at 108 on a coupon date
+        // (no accrued), and nothing else.
+        let as_of = Date::from_ymd(2025, 2, 14).unwrap();
+        let bond = SecYieldHolding {
+            par_amount: dec!(10_000_000),
+            clean_price: dec!(108),
+            coupon_rate: 0.06,
+            maturity: Date::from_ymd(2030, 1, 15).unwrap(),
+            next_call: None,
+            conventions: us_corporate::investment_grade(),
+        };
+        let input = SecYieldInput {
+            net_investment_income: Decimal::ZERO,
+            avg_shares_outstanding: dec!(432_000),
+            max_offering_price: dec!(25.00),
+            gross_expenses: None,
+            fee_waivers: None,
+            as_of_date: as_of,
+            method: SecYieldMethod::StandardizedN1A,
+            holdings: vec![bond.clone()],
+            expense_ratio: Some(0.0015),
+        };
+        let result = calculate_sec_yield(&input).unwrap();
+
+        // YTM at 108 is 4.209022%; income = YTM × MV / 360 × 30
+        //   = 0.04209022 × 10,800,000 / 12 = 37,881.20
+        // expenses = 0.15% × 10,800,000 / 12 = 1,350.00
+        // SEC yield = 2 × ((36,531.20 / (432,000 × 25) + 1)^6 − 1)
+        let income = result.interest_income.to_f64().unwrap();
+        assert!((income - 37_881.20).abs() < 0.05, "{income}");
+        assert!((result.accrued_expenses.to_f64().unwrap() - 1_350.0).abs() < 1e-6);
+        let expected = 2.0 * ((36_531.20 / 10_800_000.0 + 1.0_f64).powi(6) - 1.0);
+        assert!((result.sec_30_day_yield - expected).abs() < 1e-8);
+
+        // A premium bond callable at par next year earns its (negative)
+        // yield to call, -1.886961%.
+        let to_call = calculate_sec_yield(&SecYieldInput {
+            holdings: vec![bond.with_next_call(Date::from_ymd(2026, 1, 15).unwrap(), dec!(100))],
+            ..input
+        })
+        .unwrap();
+        let income = to_call.interest_income.to_f64().unwrap();
+        assert!((income + 16_982.65).abs() < 0.05, "{income}");
+    }
+
+    fn premium_holding() -> SecYieldHolding {
+        // $10mm of a 6% 30/360 bond due 15 Jan 2030 at 108, on a coupon date.
+        SecYieldHolding {
+            par_amount: dec!(10_000_000),
+            clean_price: dec!(108),
+            coupon_rate: 0.06,
+            maturity: Date::from_ymd(2030, 1, 15).unwrap(),
+            next_call: None,
+            conventions: us_corporate::investment_grade(),
+        }
+    }
+
+    #[test]
+    fn test_standardized_below_simplified_for_premium_bond() {
+        // The simplified method takes coupon income at face, 6% × 10mm / 12;
+        // the standardized one amortizes the premium through the yield.
+        let simplified = SecYieldInput {
+            net_investment_income: dec!(50_000),
+            avg_shares_outstanding: dec!(432_000),
+            max_offering_price: dec!(25.00),
+            gross_expenses: None,
+            fee_waivers: None,
+            as_of_date: Date::from_ymd(2025, 2, 14).unwrap(),
+            method: SecYieldMethod::Simplified,
+            holdings: Vec::new(),
+            expense_ratio: None,
+        };
+        let standardized = SecYieldInput {
+            method: SecYieldMethod::StandardizedN1A,
+            holdings: vec![premium_holding()],
+            ..simplified.clone()
+        };
+
+        let simplified = calculate_sec_yield(&simplified).unwrap();
+        let standardized = calculate_sec_yield(&standardized).unwrap();
+        assert!(standardized.interest_income < simplified.interest_income);
+        assert!(standardized.sec_30_day_yield < simplified.sec_30_day_yield);
+    }
+
+    #[test]
+    fn test_standardized_sec_yield_propagates_holding_errors() {
+        let input = SecYieldInput {
+            net_investment_income: Decimal::ZERO,
+            avg_shares_outstanding: dec!(432_000),
+            max_offering_price: dec!(25.00),
+            gross_expenses: None,
+            fee_waivers: None,
+            as_of_date: Date::from_ymd(2025, 2, 14).unwrap(),
+            method: SecYieldMethod::StandardizedN1A,
+            holdings: vec![SecYieldHolding {
+                clean_price: dec!(-5),
+                ..premium_holding()
+            }],
+            expense_ratio: None,
+        };
+        assert!(calculate_sec_yield(&input).is_err());
+    }
+
+    #[test]
+    fn test_sec_holding_conventions_follow_market_and_sector() {
+        let holding = |currency, sector| {
+            HoldingBuilder::new()
+                .id("GILT")
+                .identifiers(BondIdentifiers::from_isin_str("GB00BM8Z2S21").unwrap())
+                .par_amount(dec!(1_000_000))
+                .market_price(dec!(98))
+                .currency(currency)
+                .classification(
+                    Classification::new().with_sector(SectorInfo::from_composite(sector)),
+                )
+                .analytics(
+                    HoldingAnalytics::new()
+                        .with_coupon_rate(0.04)
+                        .with_maturity_date(Date::from_ymd(2032, 3, 7).unwrap()),
+                )
+                .build()
+                .unwrap()
+        };
+
+        let gilt = holding(Currency::GBP, Sector::Government);
+        let sec = SecYieldHolding::from_holding(&gilt).unwrap();
+        let expected = uk_gilt::conventional();
+        assert_eq!(sec.conventions.day_count(), expected.day_count());
+        assert_eq!(sec.conventions.ex_dividend_days(), expected.ex_dividend_days());
+
+        let corporate = holding(Currency::USD, Sector::Corporate);
+        let sec = SecYieldHolding::from_holding(&corporate).unwrap();
+        assert_eq!(
+            sec.conventions.day_count(),
+            us_corporate::investment_grade().day_count()
+        );
+
+        let mut no_coupon = gilt;
+        no_coupon.analytics.coupon_rate = None;
+        assert!(SecYieldHolding::from_holding(&no_coupon).is_err());
+    }
+
     #[test]
     fn test_calculate_distribution_yield_monthly() {
         let distributions: Vec<(Date, Decimal)> = (1..=12)
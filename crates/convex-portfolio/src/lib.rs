@@ -90,6 +90,7 @@ pub use types::{
     Holding,
     HoldingAnalytics,
     HoldingBuilder,
+    LiquidityModel,
     // Maturity
     MaturityBucket,
     RatingBucket,
@@ -126,15 +127,19 @@ pub use analytics::{
     cs01_per_share,
     dv01_per_share,
     estimate_days_to_liquidate,
+    holding_liquidity_score,
     liquidity_distribution,
     // Parallel utilities
     maybe_parallel_filter_map,
     maybe_parallel_fold,
     maybe_parallel_map,
+    negative_convexity_exposure,
     partial_dv01s,
     total_cs01,
     total_dv01,
     weighted_asw,
+    weighted_average_coupon,
+    weighted_average_maturity,
     weighted_best_duration,
     weighted_best_spread,
     weighted_best_yield,
@@ -163,6 +168,7 @@ pub use analytics::{
     LiquidityMetrics,
     MigrationRisk,
     NavBreakdown,
+    NegativeConvexityExposure,
     PortfolioAnalytics,
     QualityTiers,
     RisingStarRisk,
@@ -197,6 +203,8 @@ pub use bucketing::{
 pub use stress::{
     // Impact calculations
     best_case,
+    // Historical scenarios
+    historical as historical_scenarios,
     key_rate_shift_impact,
     parallel_shift_impact,
     run_stress_scenario,
@@ -205,8 +213,12 @@ pub use stress::{
     // Standard scenarios
     standard as stress_scenarios,
     summarize_results,
+    // Volatility stress
+    weighted_oas_stress,
     worst_case,
     // Scenarios
+    HoldingOasStress,
+    OasStressResult,
     RateScenario,
     SpreadScenario,
     StressResult,
@@ -218,6 +230,7 @@ pub use stress::{
 // Re-export contribution analysis types and functions
 pub use contribution::{
     // Return attribution
+    brinson_attribution,
     calculate_attribution,
     // Risk contribution
     cs01_contributions,
@@ -229,6 +242,8 @@ pub use contribution::{
     spread_contributions,
     AggregatedAttribution,
     AttributionInput,
+    BrinsonResult,
+    BrinsonSector,
     BucketContribution,
     Cs01Contributions,
     DurationContributions,
@@ -248,11 +263,14 @@ pub use benchmark::{
     benchmark_comparison,
     duration_difference_by_sector,
     estimate_tracking_error,
+    key_rate_active_exposure,
     spread_difference_by_sector,
     ActiveWeight,
     ActiveWeights,
     BenchmarkComparison,
     DurationComparison,
+    KeyRateActiveExposure,
+    KeyRateBet,
     RatingComparison,
     RiskComparison,
     SectorComparison,
@@ -275,10 +293,13 @@ pub use etf::{
     calculate_premium_discount_stats,
     calculate_sec_yield,
     estimate_yield_from_holdings,
+    optimize_creation_basket,
     premium_discount,
+    premium_discount_zscore,
     run_compliance_checks,
     BasketAnalysis,
     BasketComponent,
+    BasketConstraints,
     BasketFlowSummary,
     ComplianceCheck,
     ComplianceSeverity,
@@ -286,10 +307,14 @@ pub use etf::{
     DistributionYield,
     EtfNavMetrics,
     ExpenseMetrics,
+    OptimizedBasket,
     PremiumDiscountPoint,
     PremiumDiscountStats,
     SecYield,
+    SecYieldHolding,
     SecYieldInput,
+    SecYieldMethod,
+    PREMIUM_DISCOUNT_ROLLING_WINDOW,
 };
 
 /// Prelude module for convenient imports.
@@ -311,7 +336,7 @@ pub mod prelude {
     pub use crate::types::{CashPosition, Holding, HoldingAnalytics, HoldingBuilder};
 
     // Config types
-    pub use crate::types::{AnalyticsConfig, WeightingMethod};
+    pub use crate::types::{AnalyticsConfig, LiquidityModel, WeightingMethod};
 
     // Maturity
     pub use crate::types::MaturityBucket;
@@ -3,7 +3,7 @@
 //! Calculates the price/value impact of stress scenarios using
 //! duration and convexity approximations.
 
-use super::scenarios::{RateScenario, SpreadScenario, StressScenario};
+use super::scenarios::{rating_shift, sector_shift, RateScenario, SpreadScenario, StressScenario};
 use crate::analytics::{aggregate_key_rate_profile, weighted_best_duration, weighted_convexity};
 use crate::types::{AnalyticsConfig, Holding};
 use crate::{maybe_parallel_fold, Portfolio};
@@ -188,8 +188,14 @@ fn weighted_spread_duration(holdings: &[Holding], config: &AnalyticsConfig) -> O
 
 /// Calculates spread shock impact with per-holding shifts by rating.
 ///
-/// Each holding receives a spread shock based on its credit rating.
+/// Each holding receives a spread shock based on its credit rating. Keys
+/// are notches (e.g. "BBB+"); a holding whose notch has no entry uses its
+/// rating bucket label (e.g. "BBB", "CCC & Below") instead.
 /// Holdings without a rating or spread duration use the default (0bp).
+///
+/// The result is a percentage of the whole portfolio: every positively
+/// weighted holding counts in the denominator, shocked or not, so a shock
+/// to part of the book is diluted by the rest.
 #[must_use]
 pub fn spread_shock_by_rating(
     holdings: &[Holding],
@@ -206,17 +212,11 @@ pub fn spread_shock_by_rating(
                 return (sum_i, sum_w);
             }
 
-            // Get the spread shift for this holding's rating
-            let shift_bps = h
-                .classification
-                .rating
-                .composite
-                .and_then(|r| rating_shifts.get(&r.to_string()))
-                .copied()
-                .unwrap_or(0.0);
+            let shift_bps = rating_shift(rating_shifts, h);
 
+            // Unshocked holdings still count towards the portfolio weight
             if shift_bps == 0.0 {
-                return (sum_i, sum_w);
+                return (sum_i, sum_w + weight);
             }
 
             // Use spread duration, fall back to modified duration
@@ -245,6 +245,9 @@ pub fn spread_shock_by_rating(
 ///
 /// Each holding receives a spread shock based on its sector classification.
 /// Holdings without a sector or spread duration use the default (0bp).
+///
+/// As with [`spread_shock_by_rating`], the result is a percentage of the
+/// whole portfolio, with unshocked holdings in the denominator.
 #[must_use]
 pub fn spread_shock_by_sector(
     holdings: &[Holding],
@@ -261,17 +264,11 @@ pub fn spread_shock_by_sector(
                 return (sum_i, sum_w);
             }
 
-            // Get the spread shift for this holding's sector
-            let shift_bps = h
-                .classification
-                .sector
-                .composite
-                .and_then(|s| sector_shifts.get(&s.to_string()))
-                .copied()
-                .unwrap_or(0.0);
+            let shift_bps = sector_shift(sector_shifts, h);
 
+            // Unshocked holdings still count towards the portfolio weight
             if shift_bps == 0.0 {
-                return (sum_i, sum_w);
+                return (sum_i, sum_w + weight);
             }
 
             // Use spread duration, fall back to modified duration
@@ -769,4 +766,146 @@ mod tests {
         assert!(impact < 0.0); // Spread widening = loss
         assert!((impact - (-5.625)).abs() < 0.1);
     }
+
+    #[test]
+    fn test_partial_spread_shock_is_diluted_by_unshocked_holdings() {
+        use crate::types::{Classification, Sector, SectorInfo};
+        use std::collections::HashMap;
+
+        let holding = |id: &str, sector: Sector, par: rust_decimal::Decimal| {
+            Holding::builder()
+                .id(id)
+                .identifiers(BondIdentifiers::new().with_ticker(id))
+                .par_amount(par)
+                .market_price(dec!(100))
+                .analytics(HoldingAnalytics::new().with_modified_duration(5.0))
+                .classification(
+                    Classification::new().with_sector(SectorInfo::from_composite(sector)),
+                )
+                .build()
+                .unwrap()
+        };
+        // A quarter of the book is financials; the rest is not shocked.
+        let holdings = vec![
+            holding("FIN", Sector::Financial, dec!(1_000_000)),
+            holding("GOVT", Sector::Government, dec!(3_000_000)),
+        ];
+        let shifts = HashMap::from([("Financial".to_string(), 100.0)]);
+        let config = AnalyticsConfig::default();
+
+        // -(5 × 1%) on 25% of the weight.
+        let impact = spread_shock_by_sector(&holdings, &shifts, &config).unwrap();
+        assert!((impact + 1.25).abs() < 1e-9, "{impact}");
+
+        // With nothing shocked the portfolio is flat, not undefined.
+        let none = HashMap::from([("Utility".to_string(), 100.0)]);
+        assert_eq!(spread_shock_by_sector(&holdings, &none, &config), Some(0.0));
+    }
+
+    #[test]
+    fn test_rate_shock_2022_long_duration() {
+        use crate::stress::historical;
+        use crate::types::{Classification, CreditRating, RatingInfo};
+
+        // 30Y A- corporate: duration 17 concentrated at the long end.
+        let krds = [(10.0, 2.0), (20.0, 5.0), (30.0, 10.0)]
+            .iter()
+            .map(|&(tenor, d)| KeyRateDuration {
+                tenor,
+                duration: Duration::from(d),
+            })
+            .collect();
+        let mut analytics = HoldingAnalytics::new()
+            .with_modified_duration(17.0)
+            .with_convexity(400.0);
+        analytics.spread_duration = Some(15.0);
+        analytics.key_rate_durations = Some(KeyRateDurations::new(krds));
+        let holding = Holding::builder()
+            .id("LONG")
+            .identifiers(BondIdentifiers::new().with_ticker("LONG30"))
+            .par_amount(dec!(1_000_000))
+            .market_price(dec!(100))
+            .analytics(analytics)
+            .classification(
+                Classification::new().with_rating(RatingInfo::from_composite(CreditRating::AMinus)),
+            )
+            .build()
+            .unwrap();
+        let portfolio = Portfolio::builder("Long Duration")
+            .as_of_date(Date::from_ymd(2025, 1, 15).unwrap())
+            .add_holding(holding)
+            .build()
+            .unwrap();
+        let config = AnalyticsConfig::default();
+
+        let result = run_stress_scenario(&portfolio, &historical::rate_shock_2022(), &config);
+
+        // Rates: -(2 × 2.50% + 5 × 2.40% + 10 × 2.30%) = -40%.
+        assert!((result.rate_impact.unwrap() + 40.0).abs() < 1e-9);
+        // Spreads: A- falls back to the A bucket, -(15 × 0.70%) = -10.5%.
+        assert!((result.spread_impact.unwrap() + 10.5).abs() < 1e-9);
+        assert!(result.is_loss());
+        assert!(result.pnl_pct < -45.0);
+    }
+
+    #[test]
+    fn test_custom_twist_with_high_yield_widening() {
+        use crate::types::{Classification, CreditRating, RatingBucket, RatingInfo};
+        use convex_curves::Tenor;
+
+        let holding = |id: &str, rating: CreditRating, krds: [f64; 3], spread_dur: f64| {
+            let krds = [2.0, 10.0, 30.0]
+                .iter()
+                .zip(krds)
+                .map(|(&tenor, d)| KeyRateDuration {
+                    tenor,
+                    duration: Duration::from(d),
+                })
+                .collect();
+            let mut analytics = HoldingAnalytics::new().with_modified_duration(spread_dur);
+            analytics.spread_duration = Some(spread_dur);
+            analytics.key_rate_durations = Some(KeyRateDurations::new(krds));
+            Holding::builder()
+                .id(id)
+                .identifiers(BondIdentifiers::new().with_ticker(id))
+                .par_amount(dec!(1_000_000))
+                .market_price(dec!(100))
+                .analytics(analytics)
+                .classification(
+                    Classification::new().with_rating(RatingInfo::from_composite(rating)),
+                )
+                .build()
+                .unwrap()
+        };
+
+        // Short IG bond and long HY bond, equally weighted.
+        let portfolio = Portfolio::builder("Twist")
+            .as_of_date(Date::from_ymd(2025, 1, 15).unwrap())
+            .add_holding(holding("IG2", CreditRating::A, [1.8, 0.0, 0.0], 1.8))
+            .add_holding(holding("HY30", CreditRating::B, [0.0, 3.0, 9.0], 12.0))
+            .build()
+            .unwrap();
+        let config = AnalyticsConfig::default();
+
+        // Short end up, long end down; only high yield widens.
+        let scenario = StressScenario::from_vectors(
+            &[(Tenor::Y30, -50.0), (Tenor::Y2, 50.0)],
+            &[
+                (RatingBucket::BB, 150.0),
+                (RatingBucket::B, 300.0),
+                (RatingBucket::CCC, 500.0),
+            ],
+        );
+        let result = run_stress_scenario(&portfolio, &scenario, &config);
+
+        // 10Y interpolates between the 2Y and 30Y shifts.
+        let shift_10y = 50.0 - 100.0 * 8.0 / 28.0;
+        let expected_rate = -(0.9 * 50.0 + 1.5 * shift_10y + 4.5 * -50.0) / 100.0;
+        assert!((result.rate_impact.unwrap() - expected_rate).abs() < 1e-9);
+        assert!(result.rate_impact.unwrap() > 0.0);
+
+        // HY: -(12 × 3%) = -36%, diluted by the unshocked IG half.
+        assert!((result.spread_impact.unwrap() + 18.0).abs() < 1e-9);
+        assert!((result.pnl_pct - (expected_rate - 18.0)).abs() < 1e-9);
+    }
 }
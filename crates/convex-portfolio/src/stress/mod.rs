@@ -4,13 +4,17 @@
 //! - Scenario definitions (parallel shifts, key rate shocks, spread shocks)
 //! - Impact calculations based on duration and convexity
 //! - Multi-scenario analysis
+//! - Historical replays (2008, 2013, 2020, 2022)
 //!
 //! All calculations are based on pre-calculated analytics from holdings.
 //! No curve repricing is performed - impacts are approximated using
-//! duration and convexity.
+//! duration and convexity. The exception is the volatility stress, which
+//! reprices callable holdings on an OAS model.
 
 mod impact;
 mod scenarios;
+mod volatility;
 
 pub use impact::*;
 pub use scenarios::*;
+pub use volatility::*;
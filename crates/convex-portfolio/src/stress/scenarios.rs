@@ -5,10 +5,14 @@
 //! - Key rate shifts (twist, steepening, flattening)
 //! - Credit spread shocks
 //! - Combined scenarios
+//! - Historical replays ([`historical`])
 
+use convex_curves::Tenor;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::types::{Holding, RatingBucket};
+
 /// A rate shift at a specific tenor (in basis points).
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct TenorShift {
@@ -245,6 +249,20 @@ impl SpreadScenario {
         Self::BySector(shifts.iter().map(|(s, v)| (s.to_string(), *v)).collect())
     }
 
+    /// Spread shift (bps) applied to a holding.
+    ///
+    /// Rating shifts are keyed by notch (e.g. "BBB+"), falling back to the
+    /// rating bucket label (e.g. "BBB"); sector shifts by sector name.
+    /// Holdings without a matching entry get 0bp.
+    #[must_use]
+    pub fn shift_for(&self, holding: &Holding) -> f64 {
+        match self {
+            Self::Uniform(shift) => *shift,
+            Self::ByRating(shifts) => rating_shift(shifts, holding),
+            Self::BySector(shifts) => sector_shift(shifts, holding),
+        }
+    }
+
     /// Returns the scenario name.
     #[must_use]
     pub fn name(&self) -> &'static str {
@@ -256,6 +274,32 @@ impl SpreadScenario {
     }
 }
 
+/// Shift for a holding's rating notch, falling back to its rating bucket.
+pub(crate) fn rating_shift(shifts: &HashMap<String, f64>, holding: &Holding) -> f64 {
+    holding
+        .classification
+        .rating
+        .composite
+        .and_then(|r| {
+            shifts
+                .get(&r.to_string())
+                .or_else(|| shifts.get(r.bucket().label()))
+        })
+        .copied()
+        .unwrap_or(0.0)
+}
+
+/// Shift for a holding's sector.
+pub(crate) fn sector_shift(shifts: &HashMap<String, f64>, holding: &Holding) -> f64 {
+    holding
+        .classification
+        .sector
+        .composite
+        .and_then(|s| shifts.get(&s.to_string()))
+        .copied()
+        .unwrap_or(0.0)
+}
+
 /// A complete stress scenario combining rate and spread shocks.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StressScenario {
@@ -284,6 +328,47 @@ impl StressScenario {
         }
     }
 
+    /// Creates a custom scenario from user-supplied shift vectors.
+    ///
+    /// `rate_shifts` become key rate shifts (interpolated between the given
+    /// tenors, flat beyond them) and `spread_shifts` are applied to holdings
+    /// in the matching rating bucket; other holdings get no spread shock.
+    /// An empty vector leaves that component out. Shifts are in basis points.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Twist around 5Y with high yield widening
+    /// let scenario = StressScenario::from_vectors(
+    ///     &[(Tenor::Y2, 50.0), (Tenor::Y30, -50.0)],
+    ///     &[(RatingBucket::BB, 150.0), (RatingBucket::B, 250.0)],
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_vectors(
+        rate_shifts: &[(Tenor, f64)],
+        spread_shifts: &[(RatingBucket, f64)],
+    ) -> Self {
+        let mut scenario = Self::new("Custom");
+        if !rate_shifts.is_empty() {
+            let mut shifts: Vec<TenorShift> = rate_shifts
+                .iter()
+                .map(|(tenor, bps)| TenorShift::new(tenor.years(), *bps))
+                .collect();
+            shifts.sort_by(|a, b| a.tenor.total_cmp(&b.tenor));
+            scenario.rate_scenario = Some(RateScenario::KeyRateShifts(shifts));
+        }
+        if !spread_shifts.is_empty() {
+            scenario.spread_scenario = Some(SpreadScenario::ByRating(
+                spread_shifts
+                    .iter()
+                    .map(|(bucket, bps)| (bucket.label().to_string(), *bps))
+                    .collect(),
+            ));
+        }
+        scenario
+    }
+
     /// Sets the description.
     #[must_use]
     pub fn with_description(mut self, desc: impl Into<String>) -> Self {
@@ -422,6 +507,141 @@ pub mod standard {
     }
 }
 
+/// Historical stress replays.
+///
+/// Each scenario approximates the Treasury curve and credit spread moves of
+/// a past episode, peak to trough, as key rate shifts (3M, 2Y, 5Y, 10Y,
+/// 30Y) and spread shifts by rating bucket. The moves are rounded and
+/// meant for risk committee "what if it happened again" runs, not exact
+/// reconstruction.
+pub mod historical {
+    use super::*;
+
+    /// Key rate tenors used by the historical curve moves.
+    const TENORS: [f64; 5] = [0.25, 2.0, 5.0, 10.0, 30.0];
+
+    fn curve(shifts_bps: [f64; 5]) -> RateScenario {
+        RateScenario::KeyRateShifts(
+            TENORS
+                .iter()
+                .zip(shifts_bps)
+                .map(|(&tenor, shift)| TenorShift::new(tenor, shift))
+                .collect(),
+        )
+    }
+
+    /// Spread shifts for AAA, AA, A, BBB, BB, B and CCC & below.
+    fn spreads(shifts_bps: [f64; 7]) -> SpreadScenario {
+        let buckets = [
+            RatingBucket::AAA,
+            RatingBucket::AA,
+            RatingBucket::A,
+            RatingBucket::BBB,
+            RatingBucket::BB,
+            RatingBucket::B,
+            RatingBucket::CCC,
+        ];
+        SpreadScenario::ByRating(
+            buckets
+                .iter()
+                .zip(shifts_bps)
+                .map(|(bucket, shift)| (bucket.label().to_string(), shift))
+                .collect(),
+        )
+    }
+
+    /// 2008 global financial crisis (Sep-Dec 2008).
+    ///
+    /// Flight to quality with a collapse in credit:
+    ///
+    /// | Tenor | 3M | 2Y | 5Y | 10Y | 30Y |
+    /// |-------|----|----|----|-----|-----|
+    /// | bp | -150 | -150 | -140 | -150 | -110 |
+    ///
+    /// | Rating | AAA | AA | A | BBB | BB | B | CCC |
+    /// |--------|-----|----|---|-----|----|---|-----|
+    /// | bp | +150 | +250 | +350 | +450 | +900 | +1200 | +2000 |
+    #[must_use]
+    pub fn gfc_2008() -> StressScenario {
+        StressScenario::new("2008 GFC")
+            .with_description(
+                "Sep-Dec 2008: Treasuries rally ~150bp, IG spreads +150-450bp, HY +900-2000bp",
+            )
+            .with_rate_scenario(curve([-150.0, -150.0, -140.0, -150.0, -110.0]))
+            .with_spread_scenario(spreads([150.0, 250.0, 350.0, 450.0, 900.0, 1200.0, 2000.0]))
+    }
+
+    /// 2013 taper tantrum (May-Sep 2013).
+    ///
+    /// Bear steepening on Fed tapering talk, with modest credit widening:
+    ///
+    /// | Tenor | 3M | 2Y | 5Y | 10Y | 30Y |
+    /// |-------|----|----|----|-----|-----|
+    /// | bp | 0 | +20 | +100 | +130 | +100 |
+    ///
+    /// | Rating | AAA | AA | A | BBB | BB | B | CCC |
+    /// |--------|-----|----|---|-----|----|---|-----|
+    /// | bp | +10 | +15 | +20 | +25 | +60 | +80 | +120 |
+    #[must_use]
+    pub fn taper_tantrum_2013() -> StressScenario {
+        StressScenario::new("2013 Taper Tantrum")
+            .with_description("May-Sep 2013: 10Y +130bp bear steepener, IG +10-25bp, HY +60-120bp")
+            .with_rate_scenario(curve([0.0, 20.0, 100.0, 130.0, 100.0]))
+            .with_spread_scenario(spreads([10.0, 15.0, 20.0, 25.0, 60.0, 80.0, 120.0]))
+    }
+
+    /// March 2020 COVID shock (late Feb to 23 Mar 2020).
+    ///
+    /// Emergency cuts pull the front end down while credit gaps wider:
+    ///
+    /// | Tenor | 3M | 2Y | 5Y | 10Y | 30Y |
+    /// |-------|----|----|----|-----|-----|
+    /// | bp | -150 | -130 | -110 | -80 | -60 |
+    ///
+    /// | Rating | AAA | AA | A | BBB | BB | B | CCC |
+    /// |--------|-----|----|---|-----|----|---|-----|
+    /// | bp | +100 | +150 | +200 | +260 | +550 | +700 | +1100 |
+    #[must_use]
+    pub fn covid_2020() -> StressScenario {
+        StressScenario::new("March 2020 COVID")
+            .with_description(
+                "Feb-Mar 2020: front end -150bp, 10Y -80bp, IG +100-260bp, HY +550-1100bp",
+            )
+            .with_rate_scenario(curve([-150.0, -130.0, -110.0, -80.0, -60.0]))
+            .with_spread_scenario(spreads([100.0, 150.0, 200.0, 260.0, 550.0, 700.0, 1100.0]))
+    }
+
+    /// 2022 rate shock (Jan-Oct 2022).
+    ///
+    /// The hiking cycle lifts the whole curve, front end most:
+    ///
+    /// | Tenor | 3M | 2Y | 5Y | 10Y | 30Y |
+    /// |-------|----|----|----|-----|-----|
+    /// | bp | +400 | +375 | +300 | +250 | +230 |
+    ///
+    /// | Rating | AAA | AA | A | BBB | BB | B | CCC |
+    /// |--------|-----|----|---|-----|----|---|-----|
+    /// | bp | +40 | +50 | +70 | +90 | +200 | +260 | +500 |
+    #[must_use]
+    pub fn rate_shock_2022() -> StressScenario {
+        StressScenario::new("2022 Rate Shock")
+            .with_description("Jan-Oct 2022: 2Y +375bp, 10Y +250bp, IG +40-90bp, HY +200-500bp")
+            .with_rate_scenario(curve([400.0, 375.0, 300.0, 250.0, 230.0]))
+            .with_spread_scenario(spreads([40.0, 50.0, 70.0, 90.0, 200.0, 260.0, 500.0]))
+    }
+
+    /// Returns all historical scenarios, oldest first.
+    #[must_use]
+    pub fn all() -> Vec<StressScenario> {
+        vec![
+            gfc_2008(),
+            taper_tantrum_2013(),
+            covid_2020(),
+            rate_shock_2022(),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -481,6 +701,34 @@ mod tests {
         assert_eq!(scenario.name(), "Uniform Spread Shock");
     }
 
+    #[test]
+    fn test_spread_shift_for_holding() {
+        use crate::types::{Classification, CreditRating, RatingInfo};
+        use convex_bonds::types::BondIdentifiers;
+        use rust_decimal_macros::dec;
+
+        let holding = Holding::builder()
+            .id("BBB_PLUS")
+            .identifiers(BondIdentifiers::new().with_ticker("BBB_PLUS"))
+            .par_amount(dec!(1_000_000))
+            .market_price(dec!(100))
+            .classification(
+                Classification::new()
+                    .with_rating(RatingInfo::from_composite(CreditRating::BBBPlus)),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(SpreadScenario::uniform(50.0).shift_for(&holding), 50.0);
+        // Notch first, then bucket, then nothing.
+        let notch = SpreadScenario::by_rating(&[("BBB+", 80.0), ("BBB", 60.0)]);
+        assert_eq!(notch.shift_for(&holding), 80.0);
+        let bucket = SpreadScenario::by_rating(&[("BBB", 60.0)]);
+        assert_eq!(bucket.shift_for(&holding), 60.0);
+        let other = SpreadScenario::by_sector(&[("Financial", 100.0)]);
+        assert_eq!(other.shift_for(&holding), 0.0);
+    }
+
     #[test]
     fn test_stress_scenario_builder() {
         let scenario = StressScenario::new("Test Scenario")
@@ -517,4 +765,26 @@ mod tests {
         assert_eq!(ts.shift_bps, 100.0);
         assert!((ts.shift_decimal() - 0.01).abs() < 0.0001);
     }
+
+    #[test]
+    fn test_historical_scenarios() {
+        let scenarios = historical::all();
+        assert_eq!(scenarios.len(), 4);
+        assert!(scenarios
+            .iter()
+            .all(|s| s.has_rate_scenario() && s.has_spread_scenario()));
+
+        // 2022 bear flattener: front end up more than the long end.
+        let shock = historical::rate_shock_2022();
+        let rates = shock.rate_scenario.as_ref().unwrap();
+        assert_eq!(rates.shift_at_tenor(2.0), 375.0);
+        assert!(rates.shift_at_tenor(2.0) > rates.shift_at_tenor(30.0));
+
+        // GFC spreads keyed by rating bucket, HY wider than IG.
+        let Some(SpreadScenario::ByRating(spreads)) = historical::gfc_2008().spread_scenario else {
+            panic!("expected rating-based spreads");
+        };
+        assert_eq!(spreads["BBB"], 450.0);
+        assert!(spreads["CCC & Below"] > spreads["BB"]);
+    }
 }
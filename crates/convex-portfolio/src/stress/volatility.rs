@@ -0,0 +1,306 @@
+//! Volatility stress on option-adjusted spreads.
+//!
+//! Unlike the other stress calculations this one reprices: callable
+//! holdings are run through a Hull-White OAS model at the base and shocked
+//! volatility, holding their market prices fixed. Higher volatility makes
+//! the embedded calls more valuable, so the OAS implied by the same price
+//! falls and the effective duration shortens. Concentrations of negative
+//! convexity show up as large moves in the weighted figures.
+
+use std::collections::HashMap;
+
+use convex_analytics::spreads::OASCalculator;
+use convex_bonds::instruments::CallableBond;
+use convex_curves::RateCurveDyn;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{PortfolioError, PortfolioResult};
+use crate::types::{AnalyticsConfig, Holding};
+use crate::Portfolio;
+
+/// OAS and effective duration of one holding before and after the shock.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldingOasStress {
+    /// Holding ID.
+    pub id: String,
+
+    /// True if the holding was repriced as a callable.
+    pub callable: bool,
+
+    /// OAS at the base volatility (bps).
+    pub base_oas: Option<f64>,
+
+    /// OAS at the shocked volatility (bps).
+    pub stressed_oas: Option<f64>,
+
+    /// Effective duration at the base volatility.
+    pub base_duration: Option<f64>,
+
+    /// Effective duration at the shocked volatility.
+    pub stressed_duration: Option<f64>,
+}
+
+/// Portfolio OAS and duration under a volatility shock.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OasStressResult {
+    /// Absolute volatility shock applied (e.g. 0.005 for +0.5 points).
+    pub vol_shock: f64,
+
+    /// Weighted OAS at the base volatility (bps).
+    pub base_oas: Option<f64>,
+
+    /// Weighted OAS at the shocked volatility (bps).
+    pub stressed_oas: Option<f64>,
+
+    /// Weighted effective duration at the base volatility.
+    pub base_duration: Option<f64>,
+
+    /// Weighted effective duration at the shocked volatility.
+    pub stressed_duration: Option<f64>,
+
+    /// Per-holding detail, in portfolio order.
+    pub holdings: Vec<HoldingOasStress>,
+}
+
+impl OasStressResult {
+    /// Change in weighted OAS (bps).
+    #[must_use]
+    pub fn oas_change(&self) -> Option<f64> {
+        Some(self.stressed_oas? - self.base_oas?)
+    }
+
+    /// Change in weighted effective duration.
+    #[must_use]
+    pub fn duration_change(&self) -> Option<f64> {
+        Some(self.stressed_duration? - self.base_duration?)
+    }
+}
+
+/// Recomputes OAS and effective duration of callable holdings under a
+/// volatility shock and reports the weighted portfolio figures.
+///
+/// Holdings whose ID appears in `callables` are priced on a Hull-White tree
+/// (3% mean reversion, 200 steps) at `volatility` and `volatility +
+/// vol_shock`, solving for OAS at the holding's dirty price as of the
+/// portfolio date. Every other holding passes through with its
+/// pre-calculated OAS and best duration on both sides.
+///
+/// # Arguments
+///
+/// * `portfolio` - Portfolio to stress
+/// * `callables` - Callable bond definitions keyed by holding ID
+/// * `curve` - Discount curve for the OAS model
+/// * `volatility` - Base short-rate volatility (e.g. 0.01)
+/// * `vol_shock` - Absolute change in volatility (e.g. 0.005)
+/// * `config` - Analytics configuration (weighting)
+///
+/// # Errors
+///
+/// Returns `PortfolioError::InvalidHolding` if a callable holding cannot
+/// be priced, or `PortfolioError::InvalidPortfolio` if the shocked
+/// volatility is not positive.
+pub fn weighted_oas_stress(
+    portfolio: &Portfolio,
+    callables: &HashMap<String, CallableBond>,
+    curve: &dyn RateCurveDyn,
+    volatility: f64,
+    vol_shock: f64,
+    config: &AnalyticsConfig,
+) -> PortfolioResult<OasStressResult> {
+    let stressed_vol = volatility + vol_shock;
+    if volatility <= 0.0 || stressed_vol <= 0.0 {
+        return Err(PortfolioError::invalid_portfolio(format!(
+            "volatility must stay positive under the shock ({volatility} + {vol_shock})"
+        )));
+    }
+
+    let base_calc = OASCalculator::default_hull_white(volatility);
+    let stressed_calc = OASCalculator::default_hull_white(stressed_vol);
+    let settlement = portfolio.as_of_date;
+
+    let holdings = portfolio
+        .holdings
+        .iter()
+        .map(|h| match callables.get(&h.id) {
+            Some(bond) => {
+                let (base_oas, base_duration) =
+                    callable_oas_and_duration(&base_calc, h, bond, curve, settlement)?;
+                let (stressed_oas, stressed_duration) =
+                    callable_oas_and_duration(&stressed_calc, h, bond, curve, settlement)?;
+                Ok(HoldingOasStress {
+                    id: h.id.clone(),
+                    callable: true,
+                    base_oas: Some(base_oas),
+                    stressed_oas: Some(stressed_oas),
+                    base_duration: Some(base_duration),
+                    stressed_duration: Some(stressed_duration),
+                })
+            }
+            None => Ok(HoldingOasStress {
+                id: h.id.clone(),
+                callable: false,
+                base_oas: h.analytics.oas,
+                stressed_oas: h.analytics.oas,
+                base_duration: h.analytics.best_duration(),
+                stressed_duration: h.analytics.best_duration(),
+            }),
+        })
+        .collect::<PortfolioResult<Vec<_>>>()?;
+
+    let weights: Vec<f64> = portfolio
+        .holdings
+        .iter()
+        .map(|h| weight_for_holding(h, config))
+        .collect();
+    let weighted = |get: fn(&HoldingOasStress) -> Option<f64>| {
+        weighted_average(holdings.iter().map(get).zip(weights.iter().copied()))
+    };
+
+    Ok(OasStressResult {
+        vol_shock,
+        base_oas: weighted(|s| s.base_oas),
+        stressed_oas: weighted(|s| s.stressed_oas),
+        base_duration: weighted(|s| s.base_duration),
+        stressed_duration: weighted(|s| s.stressed_duration),
+        holdings,
+    })
+}
+
+/// Solves OAS at the holding's dirty price, then effective duration at it.
+fn callable_oas_and_duration(
+    calc: &OASCalculator,
+    holding: &Holding,
+    bond: &CallableBond,
+    curve: &dyn RateCurveDyn,
+    settlement: convex_core::types::Date,
+) -> PortfolioResult<(f64, f64)> {
+    let oas_bps = calc
+        .calculate(bond, holding.dirty_price(), curve, settlement)
+        .map_err(|e| PortfolioError::invalid_holding(&holding.id, format!("OAS: {e}")))?
+        .as_bps()
+        .to_f64()
+        .unwrap_or(0.0);
+    let duration = calc
+        .effective_duration(bond, curve, oas_bps / 10_000.0, settlement)
+        .map_err(|e| {
+            PortfolioError::invalid_holding(&holding.id, format!("effective duration: {e}"))
+        })?;
+    Ok((oas_bps, duration))
+}
+
+/// Weighted average over the values that are present.
+fn weighted_average(values: impl Iterator<Item = (Option<f64>, f64)>) -> Option<f64> {
+    let (sum_weighted, sum_weights) = values
+        .filter_map(|(value, weight)| value.map(|v| (v * weight, weight)))
+        .fold((0.0, 0.0), |(a, b), (c, d)| (a + c, b + d));
+    (sum_weights > 0.0).then(|| sum_weighted / sum_weights)
+}
+
+/// Returns the weight for a holding as f64.
+fn weight_for_holding(holding: &Holding, config: &AnalyticsConfig) -> f64 {
+    holding
+        .weight_value(config.weighting)
+        .to_f64()
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::HoldingAnalytics;
+    use convex_bonds::instruments::FixedRateBond;
+    use convex_bonds::types::{BondIdentifiers, CallEntry, CallSchedule, CallType};
+    use convex_core::types::Date;
+    use convex_curves::curves::DiscountCurveBuilder;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    fn flat_curve(rate: f64) -> impl RateCurveDyn {
+        let mut builder = DiscountCurveBuilder::new(date(2024, 1, 17));
+        for t in [0.0001, 0.25, 0.5, 1.0, 2.0, 5.0, 10.0] {
+            builder = builder.add_zero_rate(t, rate);
+        }
+        builder.with_extrapolation().build().unwrap()
+    }
+
+    fn callable() -> CallableBond {
+        let base = FixedRateBond::builder()
+            .cusip_unchecked("123456789")
+            .coupon_percent(5.0)
+            .maturity(date(2031, 1, 15))
+            .issue_date(date(2021, 1, 15))
+            .us_corporate()
+            .build()
+            .unwrap();
+        let schedule = CallSchedule::new(CallType::American)
+            .with_entry(CallEntry::new(date(2025, 1, 15), 100.0));
+        CallableBond::new(base, schedule)
+    }
+
+    fn holding(id: &str, price: Decimal, analytics: HoldingAnalytics) -> Holding {
+        Holding::builder()
+            .id(id)
+            .identifiers(BondIdentifiers::new().with_ticker(id))
+            .par_amount(dec!(1_000_000))
+            .market_price(price)
+            .analytics(analytics)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_vol_shock_shortens_duration() {
+        let portfolio = Portfolio::builder("Test")
+            .as_of_date(date(2024, 1, 17))
+            .add_holding(holding(
+                "BULLET",
+                dec!(100),
+                HoldingAnalytics::new()
+                    .with_oas(40.0)
+                    .with_effective_duration(4.5),
+            ))
+            // Near the money: OAS close to zero at the base volatility.
+            .add_holding(holding("CALLABLE", dec!(95), HoldingAnalytics::new()))
+            .build()
+            .unwrap();
+        let callables = HashMap::from([("CALLABLE".to_string(), callable())]);
+        let curve = flat_curve(0.05);
+        let config = AnalyticsConfig::default();
+
+        let result =
+            weighted_oas_stress(&portfolio, &callables, &curve, 0.01, 0.01, &config).unwrap();
+
+        let bullet = &result.holdings[0];
+        assert!(!bullet.callable);
+        assert_eq!(bullet.base_oas, bullet.stressed_oas);
+        assert_eq!(bullet.base_duration, Some(4.5));
+        assert_eq!(bullet.stressed_duration, Some(4.5));
+
+        // Same price, richer call: OAS falls and duration shortens.
+        let call = &result.holdings[1];
+        assert!(call.callable);
+        assert!(call.stressed_oas.unwrap() < call.base_oas.unwrap());
+        assert!(call.stressed_duration.unwrap() < call.base_duration.unwrap());
+
+        assert!(result.duration_change().unwrap() < 0.0);
+        assert!(result.oas_change().unwrap() < 0.0);
+    }
+
+    #[test]
+    fn test_non_positive_volatility_rejected() {
+        let portfolio = Portfolio::builder("Test")
+            .as_of_date(date(2024, 1, 17))
+            .build()
+            .unwrap();
+        let curve = flat_curve(0.05);
+        let config = AnalyticsConfig::default();
+        assert!(
+            weighted_oas_stress(&portfolio, &HashMap::new(), &curve, 0.01, -0.02, &config).is_err()
+        );
+    }
+}
@@ -25,6 +25,10 @@ pub struct AnalyticsConfig {
     /// Key rate tenors to use for KRD aggregation.
     /// If None, uses the standard tenors from convex-analytics.
     pub key_rate_tenors: Option<Vec<f64>>,
+
+    /// Model used to score holding liquidity.
+    #[serde(default)]
+    pub liquidity_model: LiquidityModel,
 }
 
 impl Default for AnalyticsConfig {
@@ -35,6 +39,7 @@ impl Default for AnalyticsConfig {
             weighting: WeightingMethod::MarketValue,
             include_incomplete: true,
             key_rate_tenors: None,
+            liquidity_model: LiquidityModel::default(),
         }
     }
 }
@@ -90,6 +95,13 @@ impl AnalyticsConfig {
         self
     }
 
+    /// Sets the liquidity model.
+    #[must_use]
+    pub fn with_liquidity_model(mut self, model: LiquidityModel) -> Self {
+        self.liquidity_model = model;
+        self
+    }
+
     /// Returns true if parallel processing should be used for the given count.
     #[must_use]
     pub fn should_parallelize(&self, count: usize) -> bool {
@@ -97,6 +109,98 @@ impl AnalyticsConfig {
     }
 }
 
+/// Weights and scales for scoring holding liquidity (0-100).
+///
+/// A holding's score is the weighted average of whichever component scores
+/// it has data for:
+///
+/// - provided: the holding's own `liquidity_score`
+/// - bid-ask: 100 at zero spread, falling linearly to 0 at `bid_ask_cap_bps`
+/// - issuance size: 100 at or above `benchmark_issue_size`, pro rata below
+/// - age: 100 at issue, falling linearly to 0 at `seasoning_years`
+/// - rating: 100 for AAA down to 0 for D
+///
+/// The default puts all weight on the provided score, i.e. the caller's
+/// scores are used as-is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LiquidityModel {
+    /// Weight on the provided liquidity score.
+    pub provided_score_weight: f64,
+
+    /// Weight on the bid-ask spread component.
+    pub bid_ask_weight: f64,
+
+    /// Weight on the issuance size component.
+    pub issuance_size_weight: f64,
+
+    /// Weight on the age (seasoning) component.
+    pub age_weight: f64,
+
+    /// Weight on the credit rating component.
+    pub rating_weight: f64,
+
+    /// Bid-ask spread (bps) at which the bid-ask component reaches 0.
+    pub bid_ask_cap_bps: f64,
+
+    /// Issue size at which the size component reaches 100.
+    pub benchmark_issue_size: f64,
+
+    /// Age (years) at which the age component reaches 0.
+    pub seasoning_years: f64,
+}
+
+impl Default for LiquidityModel {
+    fn default() -> Self {
+        Self {
+            provided_score_weight: 1.0,
+            bid_ask_weight: 0.0,
+            issuance_size_weight: 0.0,
+            age_weight: 0.0,
+            rating_weight: 0.0,
+            bid_ask_cap_bps: 100.0,
+            benchmark_issue_size: 1_000_000_000.0,
+            seasoning_years: 10.0,
+        }
+    }
+}
+
+impl LiquidityModel {
+    /// Sets the weight on the provided liquidity score.
+    #[must_use]
+    pub fn with_provided_score_weight(mut self, weight: f64) -> Self {
+        self.provided_score_weight = weight;
+        self
+    }
+
+    /// Sets the weight on the bid-ask spread component.
+    #[must_use]
+    pub fn with_bid_ask_weight(mut self, weight: f64) -> Self {
+        self.bid_ask_weight = weight;
+        self
+    }
+
+    /// Sets the weight on the issuance size component.
+    #[must_use]
+    pub fn with_issuance_size_weight(mut self, weight: f64) -> Self {
+        self.issuance_size_weight = weight;
+        self
+    }
+
+    /// Sets the weight on the age component.
+    #[must_use]
+    pub fn with_age_weight(mut self, weight: f64) -> Self {
+        self.age_weight = weight;
+        self
+    }
+
+    /// Sets the weight on the credit rating component.
+    #[must_use]
+    pub fn with_rating_weight(mut self, weight: f64) -> Self {
+        self.rating_weight = weight;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,6 +213,7 @@ mod tests {
         assert_eq!(config.weighting, WeightingMethod::MarketValue);
         assert!(config.include_incomplete);
         assert!(config.key_rate_tenors.is_none());
+        assert_eq!(config.liquidity_model, LiquidityModel::default());
     }
 
     #[test]
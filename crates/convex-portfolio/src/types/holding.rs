@@ -3,7 +3,7 @@
 use super::{Classification, WeightingMethod};
 use convex_analytics::risk::KeyRateDurations;
 use convex_bonds::types::BondIdentifiers;
-use convex_core::types::Currency;
+use convex_core::types::{Currency, Date};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -100,11 +100,20 @@ pub struct HoldingAnalytics {
     /// Liquidity score (0-100, higher is more liquid).
     pub liquidity_score: Option<f64>,
 
+    /// Amount issued, in the holding's currency.
+    pub issue_size: Option<f64>,
+
+    /// Years since issue (seasoning).
+    pub years_since_issue: Option<f64>,
+
     // =========================================================================
     // MATURITY
     // =========================================================================
     /// Years to maturity.
     pub years_to_maturity: Option<f64>,
+
+    /// Final maturity date (`None` for perpetuals or when unknown).
+    pub maturity_date: Option<Date>,
 }
 
 impl HoldingAnalytics {
@@ -149,6 +158,13 @@ impl HoldingAnalytics {
         self
     }
 
+    /// Sets the effective convexity.
+    #[must_use]
+    pub fn with_effective_convexity(mut self, convexity: f64) -> Self {
+        self.effective_convexity = Some(convexity);
+        self
+    }
+
     /// Sets the DV01.
     #[must_use]
     pub fn with_dv01(mut self, dv01: f64) -> Self {
@@ -177,6 +193,34 @@ impl HoldingAnalytics {
         self
     }
 
+    /// Sets the maturity date.
+    #[must_use]
+    pub fn with_maturity_date(mut self, date: Date) -> Self {
+        self.maturity_date = Some(date);
+        self
+    }
+
+    /// Sets the coupon rate (as decimal).
+    #[must_use]
+    pub fn with_coupon_rate(mut self, coupon: f64) -> Self {
+        self.coupon_rate = Some(coupon);
+        self
+    }
+
+    /// Sets the issue size.
+    #[must_use]
+    pub fn with_issue_size(mut self, size: f64) -> Self {
+        self.issue_size = Some(size);
+        self
+    }
+
+    /// Sets the years since issue.
+    #[must_use]
+    pub fn with_years_since_issue(mut self, years: f64) -> Self {
+        self.years_since_issue = Some(years);
+        self
+    }
+
     /// Returns the best available duration measure.
     /// Prefers effective duration for callable bonds.
     #[must_use]
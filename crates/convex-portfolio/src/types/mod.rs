@@ -21,7 +21,7 @@ pub use classification::{
     Classification, CreditRating, RatingBucket, RatingInfo, Sector, SectorInfo, Seniority,
     SeniorityInfo,
 };
-pub use config::AnalyticsConfig;
+pub use config::{AnalyticsConfig, LiquidityModel};
 pub use holding::{Holding, HoldingAnalytics, HoldingBuilder};
 pub use maturity::MaturityBucket;
 pub use weighting::WeightingMethod;
@@ -217,6 +217,13 @@ pub struct EngineConfig {
 
     /// Max concurrent calculations
     pub max_concurrent_calcs: usize,
+
+    /// Max derived node values kept in the calculation cache (`None` = unbounded).
+    ///
+    /// Least-recently-used values are evicted first; an evicted node is
+    /// simply recalculated on the next cycle.
+    #[serde(default)]
+    pub max_cached_values: Option<usize>,
 }
 
 impl Default for EngineConfig {
@@ -230,6 +237,7 @@ impl Default for EngineConfig {
             metrics_enabled: true,
             metrics_prefix: "convex".to_string(),
             max_concurrent_calcs: 1000,
+            max_cached_values: None,
         }
     }
 }
@@ -138,6 +138,9 @@ pub struct BondQuoteOutput {
     pub is_stale: bool,
     /// Quality indicator (0-100)
     pub quality: u8,
+    /// Manual override applied to this quote, if any
+    #[serde(default)]
+    pub override_id: Option<String>,
 }
 
 impl BondQuoteOutput {
@@ -168,6 +171,11 @@ impl BondQuoteOutput {
         }
     }
 
+    /// Whether a manual override replaced model values in this quote.
+    pub fn is_overridden(&self) -> bool {
+        self.override_id.is_some()
+    }
+
     /// Get price for specified side.
     pub fn clean_price_for_side(&self, side: crate::storage::QuoteSide) -> Option<Decimal> {
         use crate::storage::QuoteSide;
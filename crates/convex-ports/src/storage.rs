@@ -1,7 +1,7 @@
 //! Storage traits for persistence.
 //!
 //! These traits define interfaces for storage backends:
-//! - [`BondStore`]: Bond reference data storage
+//! - [`BondStore`]: Bond reference data and analytics snapshot storage
 //! - [`CurveStore`]: Curve config and snapshot storage
 //! - [`ConfigStore`]: Pricing configuration storage
 //! - [`OverrideStore`]: Price override storage with audit
@@ -518,6 +518,40 @@ impl BondFilter {
     }
 }
 
+/// Bond analytics snapshot (a bond's analytics at a point in time).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BondSnapshot {
+    /// Instrument identifier
+    pub instrument_id: InstrumentId,
+    /// As-of timestamp
+    pub as_of: i64,
+    /// Business date
+    pub business_date: convex_core::Date,
+    /// Clean price (bid)
+    pub clean_price_bid: Option<rust_decimal::Decimal>,
+    /// Clean price (mid)
+    pub clean_price_mid: Option<rust_decimal::Decimal>,
+    /// Clean price (ask)
+    pub clean_price_ask: Option<rust_decimal::Decimal>,
+    /// Accrued interest
+    pub accrued_interest: Option<rust_decimal::Decimal>,
+    /// YTM (bid)
+    pub ytm_bid: Option<rust_decimal::Decimal>,
+    /// YTM (mid)
+    pub ytm_mid: Option<rust_decimal::Decimal>,
+    /// YTM (ask)
+    pub ytm_ask: Option<rust_decimal::Decimal>,
+    /// Z-spread (mid, bps)
+    pub z_spread_mid: Option<rust_decimal::Decimal>,
+    /// Modified duration (mid)
+    pub modified_duration: Option<rust_decimal::Decimal>,
+    /// DV01 (mid)
+    pub dv01: Option<rust_decimal::Decimal>,
+    /// Manual override the price came from, if any
+    #[serde(default)]
+    pub override_id: Option<String>,
+}
+
 /// Bond reference data storage.
 #[async_trait]
 pub trait BondStore: Send + Sync {
@@ -549,6 +583,26 @@ pub trait BondStore: Send + Sync {
     /// Search bonds by text query.
     async fn search(&self, query: &str, limit: usize)
         -> Result<Vec<BondReferenceData>, TraitError>;
+
+    // Snapshot methods
+
+    /// Save bond snapshot, replacing any for the same bond and time.
+    async fn save_snapshot(&self, snapshot: &BondSnapshot) -> Result<(), TraitError>;
+
+    /// Get bond snapshot at a specific time.
+    async fn get_snapshot(
+        &self,
+        id: &InstrumentId,
+        as_of: i64,
+    ) -> Result<Option<BondSnapshot>, TraitError>;
+
+    /// List snapshots in time range.
+    async fn list_snapshots(
+        &self,
+        id: &InstrumentId,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<BondSnapshot>, TraitError>;
 }
 
 // =============================================================================
@@ -591,6 +645,83 @@ pub struct CurveSnapshot {
     pub build_duration_ms: u64,
 }
 
+impl CurveSnapshot {
+    /// Zero rate at a tenor (in days), linearly interpolated between points
+    /// and held flat beyond the first and last points.
+    pub fn zero_rate(&self, tenor_days: u32) -> f64 {
+        let Some(&(first_days, first_rate)) = self.points.first() else {
+            return 0.0;
+        };
+        if tenor_days <= first_days {
+            return first_rate;
+        }
+        for window in self.points.windows(2) {
+            let (d0, r0) = window[0];
+            let (d1, r1) = window[1];
+            if tenor_days <= d1 {
+                let weight = f64::from(tenor_days - d0) / f64::from(d1 - d0);
+                return r0 + weight * (r1 - r0);
+            }
+        }
+        self.points.last().map(|(_, r)| *r).unwrap_or(first_rate)
+    }
+
+    /// Discount factor at a tenor (in days), from the continuously
+    /// compounded zero rate on an Act/365 basis.
+    pub fn discount_factor(&self, tenor_days: u32) -> f64 {
+        (-self.zero_rate(tenor_days) * f64::from(tenor_days) / 365.0).exp()
+    }
+
+    /// Time-interpolates between two snapshots of the same curve.
+    ///
+    /// Discount factors are interpolated linearly in `as_of` on the union of
+    /// both snapshots' tenors and converted back to zero rates. `as_of` is
+    /// clamped to `[earlier.as_of, later.as_of]`.
+    pub fn interpolate_between(earlier: &Self, later: &Self, as_of: i64) -> Self {
+        let span = later.as_of - earlier.as_of;
+        let as_of = if span <= 0 {
+            earlier.as_of
+        } else {
+            as_of.clamp(earlier.as_of, later.as_of)
+        };
+        let weight = if span <= 0 {
+            0.0
+        } else {
+            (as_of - earlier.as_of) as f64 / span as f64
+        };
+
+        let mut tenors: Vec<u32> = earlier
+            .points
+            .iter()
+            .chain(later.points.iter())
+            .map(|(days, _)| *days)
+            .collect();
+        tenors.sort_unstable();
+        tenors.dedup();
+
+        let points = tenors
+            .into_iter()
+            .map(|days| {
+                if days == 0 {
+                    let r0 = earlier.zero_rate(0);
+                    return (0, r0 + weight * (later.zero_rate(0) - r0));
+                }
+                let df0 = earlier.discount_factor(days);
+                let df = df0 + weight * (later.discount_factor(days) - df0);
+                (days, -df.ln() * 365.0 / f64::from(days))
+            })
+            .collect();
+
+        Self {
+            curve_id: earlier.curve_id.clone(),
+            as_of,
+            points,
+            inputs_hash: format!("{}|{}", earlier.inputs_hash, later.inputs_hash),
+            build_duration_ms: 0,
+        }
+    }
+}
+
 /// Curve storage (configs and snapshots).
 #[async_trait]
 pub trait CurveStore: Send + Sync {
@@ -633,6 +764,37 @@ pub trait CurveStore: Send + Sync {
 
     /// Delete snapshots before a timestamp.
     async fn delete_snapshots_before(&self, id: &CurveId, before: i64) -> Result<u64, TraitError>;
+
+    /// Curve at an arbitrary instant.
+    ///
+    /// Time-interpolates the snapshots bracketing `as_of` (see
+    /// [`CurveSnapshot::interpolate_between`]). Before the earliest or after
+    /// the latest snapshot the nearest one is returned unchanged, flat in
+    /// time, as is a snapshot taken exactly at `as_of`. Returns `None` if
+    /// the curve has no snapshots.
+    async fn curve_at(
+        &self,
+        id: &CurveId,
+        as_of: i64,
+    ) -> Result<Option<CurveSnapshot>, TraitError> {
+        let snapshots = self.list_snapshots(id, i64::MIN, i64::MAX).await?;
+        let earlier = snapshots
+            .iter()
+            .filter(|s| s.as_of <= as_of)
+            .max_by_key(|s| s.as_of);
+        let later = snapshots
+            .iter()
+            .filter(|s| s.as_of >= as_of)
+            .min_by_key(|s| s.as_of);
+
+        Ok(match (earlier, later) {
+            (Some(earlier), Some(later)) if earlier.as_of < later.as_of => {
+                Some(CurveSnapshot::interpolate_between(earlier, later, as_of))
+            }
+            (Some(nearest), _) | (None, Some(nearest)) => Some(nearest.clone()),
+            (None, None) => None,
+        })
+    }
 }
 
 // =============================================================================
@@ -721,7 +883,7 @@ pub trait ConfigStore: Send + Sync {
 // =============================================================================
 
 /// Price override entry.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PriceOverride {
     /// Instrument ID
     pub instrument_id: InstrumentId,
@@ -747,6 +909,21 @@ pub struct PriceOverride {
     pub approved_at: Option<i64>,
 }
 
+impl PriceOverride {
+    /// Whether the override applies at `now`: approved and not yet expired.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.is_approved && self.expires_at.map(|e| e > now).unwrap_or(true)
+    }
+
+    /// Identifier of this override: the instrument and its creation time.
+    ///
+    /// The store keeps one override per instrument, so the pair picks out
+    /// the version that was applied.
+    pub fn override_id(&self) -> String {
+        format!("{}@{}", self.instrument_id, self.created_at)
+    }
+}
+
 /// Override audit entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverrideAudit {
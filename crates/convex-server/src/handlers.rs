@@ -73,6 +73,7 @@ use convex_portfolio::{
     RatingBucket,
     RatingInfo,
     SecYieldInput,
+    SecYieldMethod,
     Sector,
     SpreadContributions,
     SpreadScenario,
@@ -104,13 +105,18 @@ pub struct AppState {
 pub struct HealthResponse {
     status: String,
     version: String,
+    git_commit: String,
+    build_timestamp: String,
 }
 
 /// Health check handler.
 pub async fn health() -> Json<HealthResponse> {
+    let build = convex_core::build_info();
     Json(HealthResponse {
         status: "ok".to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: build.git_commit.to_string(),
+        build_timestamp: build.build_timestamp.to_string(),
     })
 }
 
@@ -201,9 +207,8 @@ pub async fn get_bond_quote(
         None, // volatility
     );
 
-    // Price the bond
-    let router = state.engine.pricing_router();
-    match router.price(&input) {
+    // Price the bond, applying any active manual override
+    match state.engine.price_bond(input).await {
         Ok(quote) => {
             // Publish to WebSocket subscribers
             state.ws_state.publish_bond_quote(quote.clone());
@@ -346,9 +351,8 @@ pub async fn price_single_bond(
         Some(Decimal::from_f64(0.10).unwrap()), // 10% volatility for OAS on callables
     );
 
-    // Price the bond
-    let router = state.engine.pricing_router();
-    match router.price(&input) {
+    // Price the bond, applying any active manual override
+    match state.engine.price_bond(input).await {
         Ok(quote) => {
             // Publish to WebSocket subscribers
             state.ws_state.publish_bond_quote(quote.clone());
@@ -552,6 +556,8 @@ pub struct BootstrapCurveRequest {
     pub interpolation: Option<String>,
     /// Calibration method: "GlobalFit", "Piecewise" (default: GlobalFit)
     pub method: Option<String>,
+    /// Repricing tolerance in basis points (default: 0.1)
+    pub tolerance_bps: Option<f64>,
 }
 
 /// Response from curve bootstrapping.
@@ -565,6 +571,27 @@ pub struct BootstrapCurveResponse {
     pub points: Vec<(f64, f64)>,
     /// Calibration statistics
     pub calibration: CalibrationStats,
+    /// Repricing check for each input instrument, in request order
+    pub instruments: Vec<InstrumentRepricing>,
+    /// Whether every input instrument reprices within tolerance
+    pub all_within_tolerance: bool,
+}
+
+/// Repricing check for one input instrument.
+#[derive(Debug, Serialize)]
+pub struct InstrumentRepricing {
+    /// Instrument type as given in the request
+    #[serde(rename = "type")]
+    pub instrument_type: String,
+    /// Description from the request, or the instrument's own
+    pub description: Option<String>,
+    /// Market quote
+    pub quote: f64,
+    /// Pricing error on the calibrated curve in basis points (None if the
+    /// instrument was not used)
+    pub residual_bps: Option<f64>,
+    /// Whether the residual is within the tolerance
+    pub within_tolerance: bool,
 }
 
 /// Calibration statistics.
@@ -580,7 +607,7 @@ pub struct CalibrationStats {
     pub rms_error_bps: f64,
     /// Max error in basis points
     pub max_error_bps: f64,
-    /// Per-instrument residuals in basis points
+    /// Per-instrument residuals in basis points, in maturity order
     pub residuals_bps: Vec<f64>,
     /// Method used
     pub method: String,
@@ -597,6 +624,7 @@ pub async fn bootstrap_curve(
         CalibrationResult, Deposit, Fra, GlobalFitter, InstrumentSet, Ois, PiecewiseBootstrapper,
         Swap,
     };
+    use convex_curves::RateCurve;
 
     // Parse reference date
     let reference_date = match &request.reference_date {
@@ -614,12 +642,14 @@ pub async fn bootstrap_curve(
         None => Date::today(),
     };
 
-    // Build instrument set
+    // Build instrument set, remembering which input each instrument came from
     let mut instruments = InstrumentSet::new();
+    let mut used = Vec::with_capacity(request.instruments.len());
     let dc = DayCountConvention::Act360;
     let swap_dc = DayCountConvention::Thirty360US;
 
     for inst in &request.instruments {
+        let before = instruments.len();
         match inst.instrument_type.to_uppercase().as_str() {
             "DEPOSIT" => {
                 if let Some(tenor) = inst.tenor {
@@ -665,6 +695,7 @@ pub async fn bootstrap_curve(
                 // Skip unknown instrument types
             }
         }
+        used.push((instruments.len() > before).then_some(before));
     }
 
     if instruments.is_empty() {
@@ -713,6 +744,32 @@ pub async fn bootstrap_curve(
         .map(|(&t, &v)| (t, v))
         .collect();
 
+    // Reprice each input on the calibrated curve
+    let tolerance_bps = request.tolerance_bps.unwrap_or(0.1);
+    let calibrated = RateCurve::new(result.curve.clone());
+    let repricing: Vec<InstrumentRepricing> = request
+        .instruments
+        .iter()
+        .zip(used)
+        .map(|(input, slot)| {
+            let instrument = slot.map(|i| &instruments.instruments()[i]);
+            let residual_bps = instrument
+                .and_then(|inst| inst.pricing_error(&calibrated).ok())
+                .map(|e| e * 10_000.0);
+            InstrumentRepricing {
+                instrument_type: input.instrument_type.clone(),
+                description: input
+                    .description
+                    .clone()
+                    .or_else(|| instrument.map(|inst| inst.description())),
+                quote: input.quote,
+                residual_bps,
+                within_tolerance: residual_bps.is_some_and(|r| r.abs() <= tolerance_bps),
+            }
+        })
+        .collect();
+    let all_within_tolerance = repricing.iter().all(|r| r.within_tolerance);
+
     // Store the curve in the engine
     let curve_id = CurveId::new(&request.curve_id);
     let _ = state.engine.curve_builder().create_from_points(
@@ -739,6 +796,8 @@ pub async fn bootstrap_curve(
             residuals_bps: result.errors_bps(),
             method: method.to_string(),
         },
+        instruments: repricing,
+        all_within_tolerance,
     };
 
     (
@@ -977,14 +1036,23 @@ pub async fn batch_price(
         })
         .collect();
 
-    let router = state.engine.pricing_router();
+    let instrument_ids: Vec<String> = inputs
+        .iter()
+        .map(|inp| inp.bond.instrument_id.as_str().to_string())
+        .collect();
     let start = Instant::now();
 
-    // Execute pricing (parallel or sequential)
-    let results = if request.parallel {
-        router.price_batch_parallel(&inputs)
-    } else {
-        router.price_batch(&inputs)
+    // Execute pricing (parallel or sequential), applying active manual overrides
+    let results = match state.engine.price_bonds(inputs, request.parallel).await {
+        Ok(results) => results,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Pricing failed: {}", e)
+                })),
+            );
+        }
     };
 
     let elapsed = start.elapsed();
@@ -1002,9 +1070,9 @@ pub async fn batch_price(
                 quotes.push(quote);
             }
             Err(e) => errors.push(PricingError {
-                instrument_id: inputs
+                instrument_id: instrument_ids
                     .get(i)
-                    .map(|inp| inp.bond.instrument_id.as_str().to_string())
+                    .cloned()
                     .unwrap_or_else(|| format!("index_{}", i)),
                 error: e.to_string(),
             }),
@@ -1036,6 +1104,257 @@ pub async fn batch_price(
     )
 }
 
+// =============================================================================
+// BATCH BOND ANALYTICS
+// =============================================================================
+
+/// Request for batch YAS-style analytics.
+#[derive(Debug, Deserialize)]
+pub struct BatchAnalyticsRequest {
+    /// Settlement date shared by all bonds (YYYY-MM-DD)
+    pub settlement_date: String,
+    /// Zero curve for G-spread and Z-spread
+    pub curve: AnalyticsCurveInput,
+    /// Bonds to analyze, with their prices
+    pub bonds: Vec<BondAnalyticsItem>,
+}
+
+/// Inline zero curve for batch analytics.
+#[derive(Debug, Deserialize)]
+pub struct AnalyticsCurveInput {
+    /// Reference date (YYYY-MM-DD); defaults to the settlement date
+    pub reference_date: Option<String>,
+    /// Curve points (tenor in years, continuously compounded zero rate)
+    pub points: Vec<CurvePointInput>,
+}
+
+/// A fixed-rate bond and its price.
+#[derive(Debug, Deserialize)]
+pub struct BondAnalyticsItem {
+    /// Caller's identifier, echoed in the result
+    pub id: String,
+    /// Annual coupon rate as decimal (e.g., 0.05 for 5%)
+    pub coupon_rate: Decimal,
+    /// Issue date (YYYY-MM-DD)
+    pub issue_date: String,
+    /// Maturity date (YYYY-MM-DD)
+    pub maturity_date: String,
+    /// Coupons per year
+    #[serde(default = "default_coupon_frequency")]
+    pub frequency: u32,
+    /// Day count convention (e.g., "30/360", "ACT/ACT")
+    #[serde(default = "default_day_count")]
+    pub day_count: String,
+    /// Clean price per 100 face
+    pub clean_price: Decimal,
+}
+
+fn default_coupon_frequency() -> u32 {
+    2
+}
+
+fn default_day_count() -> String {
+    "30/360".to_string()
+}
+
+/// Analytics for one bond.
+#[derive(Debug, Serialize)]
+pub struct BondAnalyticsOutput {
+    /// Street convention yield to maturity (%)
+    pub ytm: Decimal,
+    /// Current yield (%)
+    pub current_yield: Decimal,
+    /// G-spread (bps)
+    pub g_spread_bps: Decimal,
+    /// Z-spread (bps)
+    pub z_spread_bps: Decimal,
+    /// Benchmark spread (bps)
+    pub benchmark_spread_bps: Decimal,
+    /// Benchmark tenor used for the benchmark spread
+    pub benchmark_tenor: String,
+    /// Modified duration (years)
+    pub modified_duration: Decimal,
+    /// Convexity
+    pub convexity: Decimal,
+    /// DV01 per 100 face
+    pub dv01: Decimal,
+    /// Accrued interest per 100 face
+    pub accrued_interest: Decimal,
+    /// Dirty price per 100 face
+    pub dirty_price: Decimal,
+}
+
+/// Result for one bond, in request order: analytics or an error.
+#[derive(Debug, Serialize)]
+pub struct BondAnalyticsResult {
+    /// Identifier from the request
+    pub id: String,
+    /// Analytics, when the bond could be analyzed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analytics: Option<BondAnalyticsOutput>,
+    /// Error message, when it could not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response for batch analytics.
+#[derive(Debug, Serialize)]
+pub struct BatchAnalyticsResponse {
+    /// One result per requested bond, in request order
+    pub results: Vec<BondAnalyticsResult>,
+    /// Statistics
+    pub stats: BatchStats,
+}
+
+/// Batch bond analytics handler.
+///
+/// Bonds that fail to build or analyze come back as per-item errors; only a
+/// bad settlement date or curve fails the whole request.
+pub async fn batch_analytics(Json(request): Json<BatchAnalyticsRequest>) -> impl IntoResponse {
+    use convex_analytics::yas::BatchYASCalculator;
+    use convex_bonds::prelude::Bond;
+    use convex_curves::curves::DiscountCurveBuilder;
+    use std::time::Instant;
+
+    let settlement_date = match parse_date(&request.settlement_date) {
+        Ok(d) => d,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": e })),
+            );
+        }
+    };
+    let curve_date = match request.curve.reference_date.as_deref().map(parse_date) {
+        None => settlement_date,
+        Some(Ok(d)) => d,
+        Some(Err(e)) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": e })),
+            );
+        }
+    };
+    let curve = match request
+        .curve
+        .points
+        .iter()
+        .fold(DiscountCurveBuilder::new(curve_date), |b, p| {
+            b.add_zero_rate(p.tenor, p.rate)
+        })
+        .with_extrapolation()
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": format!("Invalid curve: {}", e) })),
+            );
+        }
+    };
+
+    let start = Instant::now();
+
+    // Build what we can; the rest keep their error in place.
+    let mut results: Vec<BondAnalyticsResult> = Vec::with_capacity(request.bonds.len());
+    let mut bonds: Vec<Arc<dyn Bond + Send + Sync>> = Vec::new();
+    let mut prices = Vec::new();
+    let mut slots = Vec::new();
+    for item in &request.bonds {
+        let error = match build_analytics_bond(item) {
+            Ok(bond) => {
+                slots.push(results.len());
+                bonds.push(Arc::new(bond));
+                prices.push(item.clean_price);
+                None
+            }
+            Err(e) => Some(e),
+        };
+        results.push(BondAnalyticsResult {
+            id: item.id.clone(),
+            analytics: None,
+            error,
+        });
+    }
+
+    let analyzed = BatchYASCalculator::new(&curve).analyze_batch(
+        &bonds,
+        settlement_date.as_naive_date(),
+        &prices,
+    );
+    for (slot, outcome) in slots.into_iter().zip(analyzed) {
+        match outcome {
+            Ok(yas) => {
+                results[slot].analytics = Some(BondAnalyticsOutput {
+                    ytm: yas.ytm,
+                    current_yield: yas.current_yield,
+                    g_spread_bps: yas.g_spread.as_bps(),
+                    z_spread_bps: yas.z_spread.as_bps(),
+                    benchmark_spread_bps: yas.benchmark_spread.as_bps(),
+                    benchmark_tenor: yas.benchmark_tenor.clone(),
+                    modified_duration: yas.modified_duration(),
+                    convexity: yas.convexity(),
+                    dv01: yas.dv01(),
+                    accrued_interest: yas.invoice.accrued_interest,
+                    dirty_price: yas.invoice.dirty_price,
+                })
+            }
+            Err(e) => results[slot].error = Some(e.to_string()),
+        }
+    }
+
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+    let total = results.len();
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+    let bonds_per_second = if elapsed_ms > 0 {
+        total as f64 / (elapsed_ms as f64 / 1000.0)
+    } else {
+        total as f64
+    };
+
+    let response = BatchAnalyticsResponse {
+        results,
+        stats: BatchStats {
+            total,
+            succeeded: total - failed,
+            failed,
+            elapsed_ms,
+            bonds_per_second,
+        },
+    };
+
+    (
+        StatusCode::OK,
+        Json(serde_json::to_value(response).unwrap()),
+    )
+}
+
+/// Builds the fixed-rate bond for a batch analytics item.
+fn build_analytics_bond(item: &BondAnalyticsItem) -> Result<convex_bonds::FixedRateBond, String> {
+    use convex_core::daycounts::DayCountConvention;
+    use convex_core::types::Frequency;
+    use std::str::FromStr;
+
+    let issue_date = parse_date(&item.issue_date)?;
+    let maturity_date = parse_date(&item.maturity_date)?;
+    let frequency = Frequency::try_from_periods_per_year(item.frequency)
+        .ok_or_else(|| format!("Unsupported coupon frequency: {}", item.frequency))?;
+    let day_count = DayCountConvention::from_str(&item.day_count)
+        .map_err(|_| format!("Unknown day count: {}", item.day_count))?;
+
+    convex_bonds::FixedRateBond::builder()
+        .identifiers(BondIdentifiers::new().with_ticker(&item.id))
+        .issue_date(issue_date)
+        .maturity(maturity_date)
+        .coupon_rate(item.coupon_rate)
+        .face_value(Decimal::ONE_HUNDRED)
+        .frequency(frequency)
+        .day_count(day_count)
+        .build()
+        .map_err(|e| format!("Failed to build bond: {}", e))
+}
+
 // =============================================================================
 // ETF iNAV
 // =============================================================================
@@ -5174,9 +5493,21 @@ pub async fn calculate_sec_yield_handler(
         gross_expenses: request.gross_expenses,
         fee_waivers: request.fee_waivers,
         as_of_date,
+        method: SecYieldMethod::Simplified,
+        holdings: Vec::new(),
+        expense_ratio: None,
     };
 
-    let result = calculate_sec_yield(&input);
+    let result = match calculate_sec_yield(&input) {
+        Ok(r) => r,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            )
+                .into_response();
+        }
+    };
 
     let response = SecYieldResponse {
         etf_id: request.etf_id,
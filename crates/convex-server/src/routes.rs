@@ -52,6 +52,7 @@ pub fn create_router_with_stores(
         bond_store,
         portfolio_store,
     });
+    websocket::connect_reprice_stream(&state);
 
     Router::new()
         // Health
@@ -104,6 +105,8 @@ pub fn create_router_with_stores(
         )
         // Batch Pricing
         .route("/api/v1/batch/price", post(handlers::batch_price))
+        // Batch Analytics
+        .route("/api/v1/analytics/batch", post(handlers::batch_analytics))
         // ETF iNAV
         .route("/api/v1/etf/inav", post(handlers::calculate_inav))
         .route(
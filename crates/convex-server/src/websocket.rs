@@ -4,6 +4,9 @@
 //! - Subscribing to individual bond quotes
 //! - Subscribing to ETF iNAV updates
 //! - Subscribing to portfolio analytics updates
+//! - Subscribing to bond reprice events: `{"subscribe": ["bonds", ["US912828Z229"]]}`
+//!   (and the matching `{"unsubscribe": ...}`), pushed whenever the engine
+//!   invalidates a bond's price node and it is recomputed
 //! - Heartbeat/ping-pong for connection health
 
 use std::collections::HashSet;
@@ -13,14 +16,18 @@ use std::time::Duration;
 use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::State;
 use axum::response::IntoResponse;
+use dashmap::DashSet;
 use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 use tokio::time::interval;
 use tracing::{debug, error, info, warn};
 
+use convex_bonds::conventions::{ConventionKey, ConventionRegistry, InstrumentType, Market};
 use convex_core::ids::{EtfId, InstrumentId, PortfolioId};
+use convex_core::Date;
 use convex_engine::ports::output::{BondQuoteOutput, EtfQuoteOutput, PortfolioAnalyticsOutput};
+use convex_engine::ports::reference_data::{BondReferenceData, BondReferenceSource, IssuerType};
 
 use crate::handlers::AppState;
 
@@ -53,6 +60,27 @@ pub enum ClientMessage {
     Ping { timestamp: i64 },
 }
 
+/// Maximum reprice subscriptions per connection.
+pub const MAX_REPRICE_SUBSCRIPTIONS: usize = 1_000;
+
+/// Topic of a stream subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamTopic {
+    /// Bond reprice events
+    Bonds,
+}
+
+/// Inbound stream subscription, e.g. `{"subscribe": ["bonds", ["US912828Z229"]]}`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamRequest {
+    /// Subscribe to a topic for the given IDs
+    Subscribe(StreamTopic, Vec<String>),
+    /// Unsubscribe from a topic for the given IDs
+    Unsubscribe(StreamTopic, Vec<String>),
+}
+
 /// Outbound WebSocket message to client.
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -75,6 +103,8 @@ pub enum ServerMessage {
     },
     /// Bond quote update
     BondQuote(Box<BondQuoteOutput>),
+    /// Bond repriced after its price node was invalidated
+    Reprice(Box<BondQuoteOutput>),
     /// ETF iNAV update
     EtfQuote(Box<EtfQuoteOutput>),
     /// Portfolio analytics update
@@ -105,6 +135,8 @@ pub enum ServerMessage {
 pub enum BroadcastUpdate {
     /// Bond quote update
     BondQuote(Box<BondQuoteOutput>),
+    /// Bond repriced after its price node was invalidated
+    Reprice(Box<BondQuoteOutput>),
     /// ETF iNAV update
     EtfQuote(Box<EtfQuoteOutput>),
     /// Portfolio analytics update
@@ -141,6 +173,8 @@ pub struct WebSocketState {
     pub connection_count: std::sync::atomic::AtomicUsize,
     /// Session ID counter
     session_counter: std::sync::atomic::AtomicU64,
+    /// Reprice subscriber count per bond, across connections
+    reprice_interest: dashmap::DashMap<InstrumentId, usize>,
 }
 
 impl WebSocketState {
@@ -151,6 +185,7 @@ impl WebSocketState {
             broadcast_tx,
             connection_count: std::sync::atomic::AtomicUsize::new(0),
             session_counter: std::sync::atomic::AtomicU64::new(0),
+            reprice_interest: dashmap::DashMap::new(),
         }
     }
 
@@ -169,6 +204,29 @@ impl WebSocketState {
             .send(BroadcastUpdate::BondQuote(Box::new(quote)));
     }
 
+    /// Publish a bond reprice event.
+    pub fn publish_reprice(&self, quote: BondQuoteOutput) {
+        let _ = self
+            .broadcast_tx
+            .send(BroadcastUpdate::Reprice(Box::new(quote)));
+    }
+
+    /// Whether any connection is subscribed to reprices of a bond.
+    pub fn has_reprice_subscribers(&self, id: &InstrumentId) -> bool {
+        self.reprice_interest.contains_key(id)
+    }
+
+    fn add_reprice_interest(&self, id: &InstrumentId) {
+        *self.reprice_interest.entry(id.clone()).or_insert(0) += 1;
+    }
+
+    fn remove_reprice_interest(&self, id: &InstrumentId) {
+        self.reprice_interest.remove_if_mut(id, |_, count| {
+            *count -= 1;
+            *count == 0
+        });
+    }
+
     /// Publish an ETF quote update.
     pub fn publish_etf_quote(&self, quote: EtfQuoteOutput) {
         let _ = self
@@ -318,6 +376,140 @@ pub fn connect_reactive_engine(
     });
 }
 
+// =============================================================================
+// REPRICE STREAM
+// =============================================================================
+
+/// Stream bond reprices to `{"subscribe": ["bonds", ...]}` clients.
+///
+/// Registers an invalidation hook on the engine's calculation graph that
+/// records every affected bond in a pending set and wakes a background task.
+/// Repeated invalidations of a bond before the task runs coalesce into one
+/// reprice, and the wake-up channel holds a single signal, so a burst of
+/// ticks cannot queue unbounded work. The task drains the set, reprices
+/// bonds that have subscribers from their cached quote, stores the result in
+/// the graph and publishes a reprice event. Does nothing outside a Tokio
+/// runtime.
+pub fn connect_reprice_stream(state: &Arc<AppState>) {
+    let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+        warn!("No Tokio runtime; bond reprice streaming disabled");
+        return;
+    };
+
+    let pending: Arc<DashSet<InstrumentId>> = Arc::new(DashSet::new());
+    let (wake_tx, mut wake_rx) = tokio::sync::mpsc::channel::<()>(1);
+    let dirty = pending.clone();
+    state
+        .engine
+        .calc_graph()
+        .add_invalidation_hook(Arc::new(move |nodes: &[EngineNodeId]| {
+            let mut marked = false;
+            for node in nodes {
+                if let EngineNodeId::BondPrice { instrument_id } = node {
+                    marked |= dirty.insert(instrument_id.clone());
+                }
+            }
+            if marked {
+                // A full channel already holds a wake-up covering this batch.
+                let _ = wake_tx.try_send(());
+            }
+        }));
+
+    let state = Arc::downgrade(state);
+    runtime.spawn(async move {
+        while wake_rx.recv().await.is_some() {
+            let Some(state) = state.upgrade() else {
+                break;
+            };
+            let batch: Vec<InstrumentId> = pending.iter().map(|id| id.clone()).collect();
+            for instrument_id in batch {
+                pending.remove(&instrument_id);
+                if state.ws_state.has_reprice_subscribers(&instrument_id) {
+                    reprice_bond(&state, &instrument_id).await;
+                }
+            }
+        }
+    });
+}
+
+/// Settlement date for a trade in `bond` on `trade_date`, from the
+/// settlement rules and calendar of its market and issuer type.
+fn settlement_for(bond: &BondReferenceData, trade_date: Date) -> Date {
+    let market = Market::from_country_code(&bond.country_of_risk).unwrap_or(Market::US);
+    let instrument_type = match bond.issuer_type {
+        IssuerType::Sovereign => InstrumentType::GovernmentBond,
+        IssuerType::Agency | IssuerType::Supranational => InstrumentType::Agency,
+        IssuerType::Municipal => InstrumentType::Municipal,
+        IssuerType::CorporateHY => InstrumentType::CorporateHY,
+        IssuerType::CorporateIG | IssuerType::Financial => InstrumentType::CorporateIG,
+    };
+    ConventionRegistry::global().settlement_date(
+        trade_date,
+        &ConventionKey::new(market, instrument_type),
+        None,
+    )
+}
+
+/// Reprice one bond from its cached quote and publish the result.
+async fn reprice_bond(state: &AppState, instrument_id: &InstrumentId) {
+    use convex_engine::pricing_router::PricingInput;
+    use convex_engine::NodeValue;
+
+    let bond = match state.bond_store.get_by_id(instrument_id).await {
+        Ok(Some(bond)) => Some(bond),
+        _ => state
+            .engine
+            .reference_data()
+            .bonds
+            .get_by_id(instrument_id)
+            .await
+            .ok()
+            .flatten(),
+    };
+    let Some(bond) = bond else {
+        warn!("No reference data to reprice {}", instrument_id);
+        return;
+    };
+
+    let calc_graph = state.engine.calc_graph();
+    let quote_node = EngineNodeId::Quote {
+        instrument_id: instrument_id.clone(),
+    };
+    let mid = calc_graph
+        .get_cached(&quote_node)
+        .and_then(|cv| match cv.value {
+            NodeValue::Quote { mid, .. } => mid,
+            _ => None,
+        });
+
+    let settlement = settlement_for(&bond, Date::today());
+    let input = PricingInput::with_mid_price(bond, settlement, mid, None, None, None, None);
+    match state.engine.price_bond(input).await {
+        Ok(quote) => {
+            calc_graph.update_cache(
+                &EngineNodeId::BondPrice {
+                    instrument_id: instrument_id.clone(),
+                },
+                NodeValue::BondPrice {
+                    clean_price_bid: quote.clean_price_bid,
+                    clean_price_mid: quote.clean_price_mid,
+                    clean_price_ask: quote.clean_price_ask,
+                    accrued_interest: quote.accrued_interest,
+                    ytm_bid: quote.ytm_bid,
+                    ytm_mid: quote.ytm_mid,
+                    ytm_ask: quote.ytm_ask,
+                    z_spread_mid: quote.z_spread_mid,
+                    modified_duration: quote.modified_duration,
+                    dv01: quote.dv01,
+                    override_id: quote.override_id.clone(),
+                },
+            );
+            state.ws_state.publish_reprice(quote);
+        }
+        Err(e) => warn!("Failed to reprice {}: {}", instrument_id, e),
+    }
+}
+
 // =============================================================================
 // CLIENT SESSION
 // =============================================================================
@@ -333,6 +525,8 @@ struct ClientSession {
     etf_subscriptions: HashSet<EtfId>,
     /// Subscribed portfolio IDs
     portfolio_subscriptions: HashSet<PortfolioId>,
+    /// Bonds subscribed to reprice events
+    reprice_subscriptions: HashSet<InstrumentId>,
 }
 
 impl ClientSession {
@@ -343,6 +537,7 @@ impl ClientSession {
             subscribe_all_bonds: false,
             etf_subscriptions: HashSet::new(),
             portfolio_subscriptions: HashSet::new(),
+            reprice_subscriptions: HashSet::new(),
         }
     }
 
@@ -418,7 +613,7 @@ async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
             Some(msg) = receiver.next() => {
                 match msg {
                     Ok(Message::Text(text)) => {
-                        if let Err(e) = handle_client_message(&text, &mut session, &mut sender).await {
+                        if let Err(e) = handle_client_message(&text, &state, &mut session, &mut sender).await {
                             warn!("Error handling client message: {}", e);
                             // Send error message to client
                             let error_msg = ServerMessage::Error {
@@ -433,7 +628,7 @@ async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
                     Ok(Message::Binary(data)) => {
                         // Try to parse as JSON
                         if let Ok(text) = String::from_utf8(data) {
-                            if let Err(e) = handle_client_message(&text, &mut session, &mut sender).await {
+                            if let Err(e) = handle_client_message(&text, &state, &mut session, &mut sender).await {
                                 warn!("Error handling binary message: {}", e);
                             }
                         }
@@ -479,6 +674,9 @@ async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
     }
 
     // Cleanup
+    for id in &session.reprice_subscriptions {
+        ws_state.remove_reprice_interest(id);
+    }
     ws_state
         .connection_count
         .fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
@@ -488,9 +686,17 @@ async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
 /// Handle a client message.
 async fn handle_client_message(
     text: &str,
+    state: &AppState,
     session: &mut ClientSession,
     sender: &mut futures::stream::SplitSink<WebSocket, Message>,
 ) -> Result<(), String> {
+    if let Ok(request) = serde_json::from_str::<StreamRequest>(text) {
+        let response = handle_stream_request(request, state, session);
+        return send_message(sender, &response)
+            .await
+            .map_err(|e| e.to_string());
+    }
+
     let msg: ClientMessage =
         serde_json::from_str(text).map_err(|e| format!("Invalid message: {}", e))?;
 
@@ -598,6 +804,83 @@ async fn handle_client_message(
         .map_err(|e| e.to_string())
 }
 
+/// Apply a stream (un)subscription and build the reply.
+fn handle_stream_request(
+    request: StreamRequest,
+    state: &AppState,
+    session: &mut ClientSession,
+) -> ServerMessage {
+    match request {
+        StreamRequest::Subscribe(StreamTopic::Bonds, instrument_ids) => {
+            let new_ids: HashSet<InstrumentId> = instrument_ids
+                .iter()
+                .map(InstrumentId::new)
+                .filter(|id| !session.reprice_subscriptions.contains(id))
+                .collect();
+            if session.reprice_subscriptions.len() + new_ids.len() > MAX_REPRICE_SUBSCRIPTIONS {
+                return ServerMessage::Error {
+                    code: "SUBSCRIPTION_LIMIT".to_string(),
+                    message: format!(
+                        "At most {} bond reprice subscriptions per connection ({} active, {} requested)",
+                        MAX_REPRICE_SUBSCRIPTIONS,
+                        session.reprice_subscriptions.len(),
+                        new_ids.len()
+                    ),
+                };
+            }
+            let added = new_ids.len();
+            for id in new_ids {
+                link_quote_to_price_node(state, &id);
+                state.ws_state.add_reprice_interest(&id);
+                session.reprice_subscriptions.insert(id);
+            }
+            debug!(
+                "Session {} subscribed to reprices of {} bonds",
+                session.session_id,
+                session.reprice_subscriptions.len()
+            );
+            ServerMessage::Subscribed {
+                subscription_type: "bond_reprice".to_string(),
+                count: added,
+            }
+        }
+        StreamRequest::Unsubscribe(StreamTopic::Bonds, instrument_ids) => {
+            let mut removed = 0;
+            for id in &instrument_ids {
+                let id = InstrumentId::new(id);
+                if session.reprice_subscriptions.remove(&id) {
+                    state.ws_state.remove_reprice_interest(&id);
+                    removed += 1;
+                }
+            }
+            ServerMessage::Unsubscribed {
+                subscription_type: "bond_reprice".to_string(),
+                count: removed,
+            }
+        }
+    }
+}
+
+/// Make sure a bond's price node exists, depending at least on its quote, so
+/// quote updates invalidate it. Bonds registered with the engine keep their
+/// curve dependencies.
+fn link_quote_to_price_node(state: &AppState, instrument_id: &InstrumentId) {
+    let calc_graph = state.engine.calc_graph();
+    let price_node = EngineNodeId::BondPrice {
+        instrument_id: instrument_id.clone(),
+    };
+    if calc_graph.get_dependencies(&price_node).is_empty() {
+        if let Err(e) = calc_graph.add_node(
+            price_node,
+            vec![EngineNodeId::Quote {
+                instrument_id: instrument_id.clone(),
+            }],
+        ) {
+            warn!("Cannot link quote for {}: {}", instrument_id, e);
+        }
+    }
+}
+
 /// Filter a broadcast update for a specific client session.
 fn filter_update(session: &ClientSession, update: &BroadcastUpdate) -> Option<ServerMessage> {
     match update {
@@ -608,6 +891,13 @@ fn filter_update(session: &ClientSession, update: &BroadcastUpdate) -> Option<Se
                 None
             }
         }
+        BroadcastUpdate::Reprice(quote) => {
+            if session.reprice_subscriptions.contains(&quote.instrument_id) {
+                Some(ServerMessage::Reprice(quote.clone()))
+            } else {
+                None
+            }
+        }
         BroadcastUpdate::EtfQuote(quote) => {
             if session.is_subscribed_to_etf(&quote.etf_id) {
                 Some(ServerMessage::EtfQuote(quote.clone()))
@@ -735,6 +1025,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stream_request_subscribe_bonds() {
+        let json = r#"{"subscribe":["bonds",["US912828Z229","US037833DV24"]]}"#;
+        match serde_json::from_str::<StreamRequest>(json).unwrap() {
+            StreamRequest::Subscribe(StreamTopic::Bonds, ids) => {
+                assert_eq!(ids, ["US912828Z229", "US037833DV24"]);
+            }
+            other => panic!("Wrong request: {:?}", other),
+        }
+
+        let json = r#"{"unsubscribe":["bonds",["US912828Z229"]]}"#;
+        assert!(matches!(
+            serde_json::from_str::<StreamRequest>(json).unwrap(),
+            StreamRequest::Unsubscribe(StreamTopic::Bonds, _)
+        ));
+
+        // Typed client messages are not stream requests
+        let json = r#"{"type":"subscribe_all_bonds"}"#;
+        assert!(serde_json::from_str::<StreamRequest>(json).is_err());
+    }
+
     #[test]
     fn test_client_message_subscribe_all_bonds() {
         let json = r#"{"type":"subscribe_all_bonds"}"#;
@@ -867,6 +1178,7 @@ mod tests {
             source: "test".to_string(),
             is_stale: false,
             quality: 100,
+            override_id: None,
         };
 
         state.publish_bond_quote(quote.clone());
@@ -880,4 +1192,65 @@ mod tests {
             _ => panic!("Wrong update type"),
         }
     }
+
+    fn bond_ref(issuer_type: IssuerType, country: &str) -> BondReferenceData {
+        use convex_core::Currency;
+        use convex_engine::ports::reference_data::BondType;
+
+        BondReferenceData {
+            instrument_id: InstrumentId::new("US912810TD00"),
+            isin: None,
+            cusip: None,
+            sedol: None,
+            bbgid: None,
+            description: "Test bond".to_string(),
+            currency: Currency::USD,
+            issue_date: Date::from_ymd(2020, 1, 15).unwrap(),
+            maturity_date: Date::from_ymd(2030, 1, 15).unwrap(),
+            coupon_rate: Some(dec!(0.025)),
+            frequency: 2,
+            day_count: "ACT/ACT".to_string(),
+            face_value: dec!(100),
+            bond_type: BondType::FixedBullet,
+            issuer_type,
+            issuer_id: "ISSUER".to_string(),
+            issuer_name: "Issuer".to_string(),
+            seniority: "Senior".to_string(),
+            is_callable: false,
+            call_schedule: vec![],
+            is_putable: false,
+            is_sinkable: false,
+            floating_terms: None,
+            inflation_index: None,
+            inflation_base_index: None,
+            has_deflation_floor: false,
+            country_of_risk: country.to_string(),
+            sector: "Test".to_string(),
+            amount_outstanding: None,
+            first_coupon_date: None,
+            last_updated: 0,
+            source: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_reprice_settlement_follows_bond_rules() {
+        // Friday before MLK day.
+        let trade = Date::from_ymd(2025, 1, 17).unwrap();
+
+        // Treasuries settle T+1, corporates T+2, both on the US calendar.
+        assert_eq!(
+            settlement_for(&bond_ref(IssuerType::Sovereign, "US"), trade),
+            Date::from_ymd(2025, 1, 21).unwrap()
+        );
+        assert_eq!(
+            settlement_for(&bond_ref(IssuerType::CorporateIG, "US"), trade),
+            Date::from_ymd(2025, 1, 22).unwrap()
+        );
+        // Gilts settle T+1 on the UK calendar, which has no MLK holiday.
+        assert_eq!(
+            settlement_for(&bond_ref(IssuerType::Sovereign, "GB"), trade),
+            Date::from_ymd(2025, 1, 20).unwrap()
+        );
+    }
 }
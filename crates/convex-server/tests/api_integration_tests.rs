@@ -172,6 +172,7 @@ fn create_test_quote(id: &str, clean_price: Decimal) -> BondQuoteOutput {
         source: "test".to_string(),
         is_stale: false,
         quality: 100,
+        override_id: None,
     }
 }
 
@@ -214,6 +215,8 @@ async fn test_health_endpoint() {
 
     assert_eq!(json["status"], "ok");
     assert!(json["version"].is_string());
+    assert!(json["git_commit"].is_string());
+    assert!(json["build_timestamp"].is_string());
 }
 
 // =============================================================================
@@ -298,6 +301,74 @@ async fn test_batch_price_invalid_date() {
     assert!(json["error"].is_string());
 }
 
+// =============================================================================
+// BATCH ANALYTICS TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_batch_analytics_partial_failure() {
+    let engine = create_test_engine();
+    let app = create_router(engine);
+
+    let request_body = json!({
+        "settlement_date": "2020-04-29",
+        "curve": {
+            "points": [
+                { "tenor": 0.25, "rate": 0.005 },
+                { "tenor": 1.0, "rate": 0.008 },
+                { "tenor": 5.0, "rate": 0.02 },
+                { "tenor": 10.0, "rate": 0.025 }
+            ]
+        },
+        "bonds": [
+            {
+                "id": "BA-2025",
+                "coupon_rate": 0.075,
+                "issue_date": "1995-06-15",
+                "maturity_date": "2025-06-15",
+                "clean_price": 110.503
+            },
+            {
+                "id": "BAD-DATE",
+                "coupon_rate": 0.05,
+                "issue_date": "2020-01-15",
+                "maturity_date": "2030-13-45",
+                "clean_price": 100.0
+            },
+            {
+                "id": "UST-2030",
+                "coupon_rate": 0.015,
+                "issue_date": "2020-02-15",
+                "maturity_date": "2030-02-15",
+                "day_count": "ACT/ACT",
+                "clean_price": 104.25
+            }
+        ]
+    });
+
+    let (status, json) = post_json(app, "/api/v1/analytics/batch", request_body).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["stats"]["total"], 3);
+    assert_eq!(json["stats"]["succeeded"], 2);
+    assert_eq!(json["stats"]["failed"], 1);
+
+    let results = json["results"].as_array().unwrap();
+    let ids: Vec<&str> = results.iter().map(|r| r["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["BA-2025", "BAD-DATE", "UST-2030"]);
+
+    let boeing = &results[0];
+    assert!(boeing.get("error").is_none());
+    let ytm = boeing["analytics"]["ytm"].as_f64().unwrap();
+    assert!(ytm > 4.0 && ytm < 6.0, "ytm {ytm}");
+
+    let bad = &results[1];
+    assert!(bad.get("analytics").is_none());
+    assert!(bad["error"].as_str().unwrap().contains("2030-13-45"));
+
+    assert!(results[2]["analytics"]["z_spread_bps"].is_number());
+}
+
 // =============================================================================
 // ETF iNAV TESTS
 // =============================================================================
@@ -983,6 +1054,45 @@ async fn test_query_zero_rate_with_compounding() {
     );
 }
 
+#[tokio::test]
+async fn test_bootstrap_swap_curve_reprices_inputs() {
+    let engine = create_test_engine();
+    let app = create_router(engine);
+
+    let request_body = json!({
+        "curve_id": "USD_SWAP_BOOT",
+        "reference_date": "2025-01-15",
+        "method": "Piecewise",
+        "instruments": [
+            { "type": "Deposit", "tenor": 0.5, "quote": 0.0430 },
+            { "type": "Swap", "tenor": 2.0, "quote": 0.0410 },
+            { "type": "Swap", "tenor": 5.0, "quote": 0.0395 },
+            { "type": "Swap", "tenor": 10.0, "quote": 0.0405 },
+            { "type": "Cap", "tenor": 3.0, "quote": 0.04 }
+        ]
+    });
+
+    let (status, json) = post_json(app, "/api/v1/curves/bootstrap", request_body).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let instruments = json["instruments"].as_array().unwrap();
+    assert_eq!(instruments.len(), 5);
+    for inst in &instruments[..4] {
+        let residual = inst["residual_bps"].as_f64().unwrap();
+        assert!(
+            residual.abs() < 0.1,
+            "{} residual {} bps",
+            inst["type"],
+            residual
+        );
+        assert_eq!(inst["within_tolerance"], true);
+    }
+    // Unknown instrument types are skipped, so they cannot reprice.
+    assert!(instruments[4]["residual_bps"].is_null());
+    assert_eq!(instruments[4]["within_tolerance"], false);
+    assert_eq!(json["all_within_tolerance"], false);
+}
+
 // =============================================================================
 // SINGLE BOND QUOTE TESTS
 // =============================================================================
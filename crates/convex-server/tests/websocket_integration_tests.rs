@@ -732,6 +732,143 @@ async fn test_websocket_unknown_message_type() {
     assert_eq!(msg["type"], "error");
 }
 
+// =============================================================================
+// REPRICE STREAM TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_websocket_reprice_on_quote_update() {
+    let (addr, engine) = start_test_server().await;
+    let ws_url = format!("ws://{}/ws", addr);
+
+    // Register the bond so the server can reprice it
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("http://{}/api/v1/bonds", addr))
+        .json(&json!({
+            "instrument_id": "US912828Z229",
+            "isin": "US912828Z229",
+            "cusip": "912828Z22",
+            "description": "Treasury 2.5% 2030",
+            "currency": "USD",
+            "issue_date": "2020-01-15",
+            "maturity_date": "2030-01-15",
+            "coupon_rate": "0.025",
+            "frequency": 2,
+            "day_count": "ACT/ACT",
+            "face_value": "100",
+            "bond_type": "FixedBullet",
+            "issuer_type": "Sovereign",
+            "issuer_id": "US_TREASURY",
+            "issuer_name": "US Treasury",
+            "seniority": "Senior",
+            "is_callable": false,
+            "call_schedule": [],
+            "is_putable": false,
+            "is_sinkable": false,
+            "has_deflation_floor": false,
+            "country_of_risk": "US",
+            "sector": "Sovereign",
+            "last_updated": 0,
+            "source": "test"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let (ws_stream, _) = connect_async(&ws_url).await.expect("Failed to connect");
+    let (mut write, mut read) = ws_stream.split();
+    let _ = recv_json(&mut read).await;
+
+    let subscribe_msg = json!({ "subscribe": ["bonds", ["US912828Z229"]] });
+    write
+        .send(Message::Text(subscribe_msg.to_string().into()))
+        .await
+        .unwrap();
+    let msg = recv_json_skip_heartbeats(&mut read)
+        .await
+        .expect("Should receive subscribed message");
+    assert_eq!(msg["type"], "subscribed");
+    assert_eq!(msg["subscription_type"], "bond_reprice");
+    assert_eq!(msg["count"], 1);
+
+    engine.on_quote_update(
+        &convex_core::ids::InstrumentId::new("US912828Z229"),
+        Some(rust_decimal_macros::dec!(98.25)),
+        Some(rust_decimal_macros::dec!(98.75)),
+    );
+
+    let msg = recv_json_skip_heartbeats(&mut read)
+        .await
+        .expect("Should receive reprice message");
+    assert_eq!(msg["type"], "reprice");
+    assert_eq!(msg["instrument_id"], "US912828Z229");
+    assert_eq!(msg["clean_price_mid"].as_f64(), Some(98.5));
+
+    // Exactly one frame per invalidation
+    let next = timeout(
+        Duration::from_millis(300),
+        recv_json_skip_heartbeats(&mut read),
+    )
+    .await;
+    assert!(next.is_err() || next.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_websocket_reprice_subscription_limit() {
+    let (addr, _engine) = start_test_server().await;
+    let ws_url = format!("ws://{}/ws", addr);
+
+    let (ws_stream, _) = connect_async(&ws_url).await.expect("Failed to connect");
+    let (mut write, mut read) = ws_stream.split();
+    let _ = recv_json(&mut read).await;
+
+    let ids: Vec<String> = (0..=convex_server::websocket::MAX_REPRICE_SUBSCRIPTIONS)
+        .map(|i| format!("BOND{i}"))
+        .collect();
+    let subscribe_msg = json!({ "subscribe": ["bonds", ids] });
+    write
+        .send(Message::Text(subscribe_msg.to_string().into()))
+        .await
+        .unwrap();
+
+    let msg = recv_json_skip_heartbeats(&mut read)
+        .await
+        .expect("Should receive error message");
+    assert_eq!(msg["type"], "error");
+    assert_eq!(msg["code"], "SUBSCRIPTION_LIMIT");
+
+    let unsubscribe_msg = json!({ "unsubscribe": ["bonds", ["BOND0"]] });
+    write
+        .send(Message::Text(unsubscribe_msg.to_string().into()))
+        .await
+        .unwrap();
+    let msg = recv_json_skip_heartbeats(&mut read)
+        .await
+        .expect("Should receive unsubscribed message");
+    assert_eq!(msg["type"], "unsubscribed");
+    assert_eq!(msg["subscription_type"], "bond_reprice");
+    assert_eq!(msg["count"], 0);
+
+    // Counts report the IDs actually added, not the IDs requested
+    for (ids, added) in [
+        (json!(["BOND0", "BOND0"]), 1),
+        (json!(["BOND0", "BOND1"]), 1),
+    ] {
+        let subscribe_msg = json!({ "subscribe": ["bonds", ids] });
+        write
+            .send(Message::Text(subscribe_msg.to_string().into()))
+            .await
+            .unwrap();
+        let msg = recv_json_skip_heartbeats(&mut read)
+            .await
+            .expect("Should receive subscribed message");
+        assert_eq!(msg["type"], "subscribed");
+        assert_eq!(msg["count"], added);
+    }
+}
+
 // =============================================================================
 // STATUS ENDPOINT TESTS
 // =============================================================================
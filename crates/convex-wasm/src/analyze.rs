@@ -1,19 +1,30 @@
-//! Public WASM analytics surface: analyze_bond, get_cash_flows, calculate_accrued, calculate_simple_metrics.
+//! Public WASM analytics surface: analyze_bond, get_cash_flows, get_cash_flows_with_pv,
+//! calculate_accrued, calculate_simple_metrics.
 
 use wasm_bindgen::prelude::*;
 
-use convex_analytics::spreads::OASCalculator;
+use std::sync::Arc;
+
+use convex_analytics::spreads::{DiscountMarginCalculator, OASCalculator};
 use convex_analytics::yas::YASCalculator;
-use convex_bonds::instruments::CallableBond;
-use convex_bonds::traits::{Bond, EmbeddedOptionBond, FixedCouponBond};
+use convex_bonds::instruments::{CallableBond, CallableFloatingRateNote};
+use convex_bonds::traits::{Bond, BondCashFlow, EmbeddedOptionBond, FixedCouponBond};
 use convex_bonds::types::{CallEntry, CallSchedule, CallType};
+use convex_curves::curves::ForwardCurve;
+use convex_curves::RateCurveDyn;
 
 use crate::bond::{
     calculate_convention_yield, convert_yas_result, create_bond, create_curve,
-    create_discount_curve, get_yield_rules,
+    create_discount_curve, create_frn, get_yield_rules,
+};
+use crate::convert::{
+    date_to_naive, decimal_to_f64, f64_to_decimal, format_cash_flow_type, parse_date,
 };
-use crate::convert::{date_to_naive, decimal_to_f64, f64_to_decimal, parse_date};
-use crate::dto::{AnalysisResult, BondParams, CashFlowEntry, CurvePoint};
+use crate::dto::{
+    AnalysisResult, BondParams, CashFlowEntry, CashFlowPvEntry, CashFlowPvResult, CurvePoint,
+    Warning,
+};
+use crate::error::{ErrorCode, WasmError};
 
 /// Calculate bond analytics given price and yield curve.
 ///
@@ -28,54 +39,57 @@ fn analyze_bond_impl(params: JsValue, clean_price: f64, curve_points: JsValue) -
     let bond_params: BondParams = match serde_wasm_bindgen::from_value(params) {
         Ok(p) => p,
         Err(e) => {
-            return AnalysisResult {
-                error: Some(format!("Failed to parse bond parameters: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse bond parameters: {:?}", e),
+            )
+            .into()
         }
     };
 
     let points: Vec<CurvePoint> = match serde_wasm_bindgen::from_value(curve_points) {
         Ok(p) => p,
         Err(e) => {
-            return AnalysisResult {
-                error: Some(format!("Failed to parse curve points: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse curve points: {:?}", e),
+            )
+            .into()
         }
     };
 
-    let bond = match create_bond(&bond_params) {
+    analyze_parsed(&bond_params, clean_price, &points)
+}
+
+/// `analyze_bond` on already-deserialized inputs.
+fn analyze_parsed(
+    bond_params: &BondParams,
+    clean_price: f64,
+    points: &[CurvePoint],
+) -> AnalysisResult {
+    if bond_params.rate_index.is_some() {
+        return match analyze_frn(bond_params, clean_price, points) {
+            Ok(result) => result,
+            Err(e) => e.into(),
+        };
+    }
+
+    let bond = match create_bond(bond_params) {
         Ok(b) => b,
-        Err(e) => {
-            return AnalysisResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let settlement = match parse_date(&bond_params.settlement_date) {
         Ok(d) => d,
-        Err(e) => {
-            return AnalysisResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
-    let curve = match create_curve(settlement, &points) {
+    let curve = match create_curve(settlement, points) {
         Ok(c) => c,
-        Err(e) => {
-            return AnalysisResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
-    let yield_rules = get_yield_rules(&bond_params);
+    let yield_rules = get_yield_rules(bond_params);
 
     let calculator = YASCalculator::new(&curve);
     let settlement_naive = date_to_naive(settlement);
@@ -84,14 +98,15 @@ fn analyze_bond_impl(params: JsValue, clean_price: f64, curve_points: JsValue) -
     {
         Ok(result) => result,
         Err(e) => {
-            return AnalysisResult {
-                error: Some(format!("Analysis failed: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::AnalysisFailed,
+                format!("Analysis failed: {:?}", e),
+            )
+            .into()
         }
     };
 
-    let mut result = convert_yas_result(&yas_result, &bond, settlement, &yield_rules, &bond_params);
+    let mut result = convert_yas_result(&yas_result, &bond, settlement, &yield_rules, bond_params);
 
     // Convention-aware YTM via StandardYieldEngine — same engine the bond was priced with.
     if let Some(convention_ytm) =
@@ -104,17 +119,15 @@ fn analyze_bond_impl(params: JsValue, clean_price: f64, curve_points: JsValue) -
         if !call_entries.is_empty() {
             // Parse all call dates up front: a single bad date should fail the whole call,
             // not silently flag the bond callable with a partial / empty schedule.
-            let parsed: Result<Vec<(convex_core::types::Date, f64)>, String> = call_entries
+            let parsed: Result<Vec<(convex_core::types::Date, f64)>, WasmError> = call_entries
                 .iter()
                 .map(|entry| parse_date(&entry.date).map(|d| (d, entry.price)))
                 .collect();
             let parsed = match parsed {
                 Ok(v) => v,
                 Err(e) => {
-                    return AnalysisResult {
-                        error: Some(format!("Invalid call schedule entry: {}", e)),
-                        ..Default::default()
-                    }
+                    return WasmError::new(e.code, format!("Invalid call schedule entry: {}", e))
+                        .into()
                 }
             };
 
@@ -154,7 +167,7 @@ fn analyze_bond_impl(params: JsValue, clean_price: f64, curve_points: JsValue) -
             let dirty_price_f64 = clean_price + accrued;
             let dirty_price = f64_to_decimal(dirty_price_f64);
 
-            match create_discount_curve(settlement, &points) {
+            match create_discount_curve(settlement, points) {
                 Ok(discount_curve) => {
                     match oas_calc.calculate(&callable, dirty_price, &discount_curve, settlement) {
                         Ok(oas) => {
@@ -186,10 +199,15 @@ fn analyze_bond_impl(params: JsValue, clean_price: f64, curve_points: JsValue) -
                                 result.option_value = Some(opt_val);
                             }
                         }
-                        Err(_e) => {
+                        Err(e) => {
                             // OAS calc failed (model price can't bracket market price);
-                            // fall back to Z-spread so the UI gets a sensible spread.
+                            // fall back to Z-spread so the UI gets a sensible spread,
+                            // but flag it so the UI can say so.
                             result.oas = result.z_spread;
+                            result.warnings.push(Warning {
+                                code: ErrorCode::OasNoConverge,
+                                message: format!("OAS did not converge, showing Z-spread: {:?}", e),
+                            });
                         }
                     }
                 }
@@ -205,6 +223,82 @@ fn analyze_bond_impl(params: JsValue, clean_price: f64, curve_points: JsValue) -
     result
 }
 
+/// `analyze_bond` for floaters: discount margin off the curve's forwards and,
+/// with a call schedule, the margin to the worst workout.
+fn analyze_frn(
+    bond_params: &BondParams,
+    clean_price: f64,
+    points: &[CurvePoint],
+) -> Result<AnalysisResult, WasmError> {
+    let frn = create_frn(bond_params)?;
+    let settlement = parse_date(&bond_params.settlement_date)?;
+
+    // Project and discount off the same curve; forwards span one coupon period.
+    let discount_curve: Arc<dyn RateCurveDyn> =
+        Arc::new(create_discount_curve(settlement, points)?);
+    let forward_curve =
+        ForwardCurve::from_months(discount_curve.clone(), frn.frequency().months_per_period());
+    let dm_calc = DiscountMarginCalculator::new(&forward_curve, discount_curve.as_ref());
+
+    let accrued = decimal_to_f64(frn.accrued_interest(settlement));
+    let dirty_price = f64_to_decimal(clean_price + accrued);
+
+    let discount_margin = dm_calc
+        .calculate(&frn, dirty_price, settlement)
+        .map_err(|e| {
+            WasmError::new(
+                ErrorCode::AnalysisFailed,
+                format!("Discount margin failed: {:?}", e),
+            )
+        })?;
+
+    let maturity = frn.maturity_date();
+    let days_to_maturity = settlement.days_between(&maturity);
+    let mut result = AnalysisResult {
+        clean_price: Some(clean_price),
+        dirty_price: Some(clean_price + accrued),
+        accrued_interest: Some(accrued),
+        discount_margin: Some(decimal_to_f64(discount_margin.as_bps())),
+        days_to_maturity: Some(days_to_maturity),
+        years_to_maturity: Some(days_to_maturity as f64 / 365.0),
+        is_callable: Some(false),
+        ..Default::default()
+    };
+
+    let call_entries = bond_params.call_schedule.as_deref().unwrap_or_default();
+    if !call_entries.is_empty() {
+        // Calls on the listed dates only, so each entry is one candidate workout.
+        let mut call_schedule = CallSchedule::new(CallType::Bermudan);
+        for entry in call_entries {
+            let call_date = parse_date(&entry.date).map_err(|e| {
+                WasmError::new(e.code, format!("Invalid call schedule entry: {}", e))
+            })?;
+            call_schedule = call_schedule.with_entry(CallEntry::new(call_date, entry.price));
+        }
+        let callable = CallableFloatingRateNote::new(frn, call_schedule);
+
+        let (margin_to_worst, workout_date) = dm_calc
+            .discount_margin_to_worst(&callable, dirty_price, settlement)
+            .map_err(|e| {
+                WasmError::new(
+                    ErrorCode::AnalysisFailed,
+                    format!("Margin to worst failed: {:?}", e),
+                )
+            })?;
+
+        result.is_callable = Some(true);
+        result.margin_to_worst = Some(decimal_to_f64(margin_to_worst.as_bps()));
+        result.workout_date = Some(format!("{}", workout_date));
+        result.workout_price = if workout_date == maturity {
+            Some(100.0)
+        } else {
+            callable.call_price_on(workout_date)
+        };
+    }
+
+    Ok(result)
+}
+
 /// Get bond cash flows.
 ///
 /// Returns all future cash flows from settlement date.
@@ -230,30 +324,105 @@ fn get_cash_flows_impl(params: JsValue) -> Vec<CashFlowEntry> {
         Err(_) => return vec![],
     };
 
-    let face = decimal_to_f64(bond.face_value());
-    bond.cash_flows(settlement)
+    cash_flow_entries(&bond.cash_flows(settlement))
+}
+
+/// Wire entries for a bond's cash flows, typed from each flow's metadata.
+fn cash_flow_entries(flows: &[BondCashFlow]) -> Vec<CashFlowEntry> {
+    let Some(final_date) = flows.iter().map(|cf| cf.date).max() else {
+        return vec![];
+    };
+    flows
+        .iter()
+        .map(|cf| CashFlowEntry {
+            date: format!("{}", cf.date),
+            amount: decimal_to_f64(cf.amount),
+            cf_type: format_cash_flow_type(cf, final_date),
+        })
+        .collect()
+}
+
+/// Get bond cash flows with discount factors and present values.
+///
+/// Discounts each future cash flow on the curve from settlement; the present
+/// values sum to the curve-implied dirty price (per 100 face).
+#[wasm_bindgen]
+pub fn get_cash_flows_with_pv(params: JsValue, curve_points: JsValue) -> JsValue {
+    let result = get_cash_flows_with_pv_impl(params, curve_points);
+    serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+}
+
+fn get_cash_flows_with_pv_impl(params: JsValue, curve_points: JsValue) -> CashFlowPvResult {
+    let bond_params: BondParams = match serde_wasm_bindgen::from_value(params) {
+        Ok(p) => p,
+        Err(e) => {
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse bond parameters: {:?}", e),
+            )
+            .into()
+        }
+    };
+
+    let points: Vec<CurvePoint> = match serde_wasm_bindgen::from_value(curve_points) {
+        Ok(p) => p,
+        Err(e) => {
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse curve points: {:?}", e),
+            )
+            .into()
+        }
+    };
+
+    match cash_flows_with_pv(&bond_params, &points) {
+        Ok(result) => result,
+        Err(e) => e.into(),
+    }
+}
+
+/// `get_cash_flows_with_pv` on already-deserialized inputs.
+fn cash_flows_with_pv(
+    bond_params: &BondParams,
+    points: &[CurvePoint],
+) -> Result<CashFlowPvResult, WasmError> {
+    let bond = create_bond(bond_params)?;
+    let settlement = parse_date(&bond_params.settlement_date)?;
+    // Referenced at settlement, so DF(0) = 1 and no forward adjustment is needed.
+    let curve = create_discount_curve(settlement, points)?;
+
+    let flows = bond.cash_flows(settlement);
+    let final_date = flows.iter().map(|cf| cf.date).max().unwrap_or(settlement);
+    let cash_flows = flows
         .iter()
+        .filter(|cf| cf.date > settlement)
         .map(|cf| {
+            let t = settlement.days_between(&cf.date) as f64 / 365.0;
+            let discount_factor = RateCurveDyn::discount_factor(&curve, t).map_err(|e| {
+                WasmError::new(
+                    ErrorCode::CurveBuild,
+                    format!("Failed to discount cash flow on {}: {:?}", cf.date, e),
+                )
+            })?;
             let amount = decimal_to_f64(cf.amount);
-            // Classify against the bond's face value, not a hardcoded 100, so face_value != 100
-            // (e.g. 1000 for institutional notional) doesn't misclassify maturities.
-            let cf_type = if cf.is_principal() && amount >= face / 2.0 {
-                if amount > face {
-                    "coupon_and_principal"
-                } else {
-                    "principal"
-                }
-            } else {
-                "coupon"
-            };
-
-            CashFlowEntry {
+            Ok(CashFlowPvEntry {
                 date: format!("{}", cf.date),
                 amount,
-                cf_type: cf_type.to_string(),
-            }
+                cf_type: format_cash_flow_type(cf, final_date),
+                discount_factor,
+                present_value: amount * discount_factor,
+            })
         })
-        .collect()
+        .collect::<Result<Vec<_>, WasmError>>()?;
+
+    let face = decimal_to_f64(bond.face_value());
+    let total_pv: f64 = cash_flows.iter().map(|cf| cf.present_value).sum();
+
+    Ok(CashFlowPvResult {
+        cash_flows,
+        dirty_price: Some(total_pv / face * 100.0),
+        ..Default::default()
+    })
 }
 
 /// Calculate accrued interest.
@@ -267,17 +436,18 @@ pub fn calculate_accrued(params: JsValue) -> JsValue {
             accrued_interest: Some(v),
             ..Default::default()
         },
-        Err(e) => AnalysisResult {
-            error: Some(e),
-            ..Default::default()
-        },
+        Err(e) => e.into(),
     };
     serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
 }
 
-fn calculate_accrued_impl(params: JsValue) -> Result<f64, String> {
-    let bond_params: BondParams = serde_wasm_bindgen::from_value(params)
-        .map_err(|e| format!("Failed to parse bond parameters: {:?}", e))?;
+fn calculate_accrued_impl(params: JsValue) -> Result<f64, WasmError> {
+    let bond_params: BondParams = serde_wasm_bindgen::from_value(params).map_err(|e| {
+        WasmError::new(
+            ErrorCode::ParseInput,
+            format!("Failed to parse bond parameters: {:?}", e),
+        )
+    })?;
 
     let bond = create_bond(&bond_params)?;
 
@@ -298,31 +468,22 @@ fn calculate_simple_metrics_impl(params: JsValue, clean_price: f64) -> AnalysisR
     let bond_params: BondParams = match serde_wasm_bindgen::from_value(params) {
         Ok(p) => p,
         Err(e) => {
-            return AnalysisResult {
-                error: Some(format!("Failed to parse bond parameters: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse bond parameters: {:?}", e),
+            )
+            .into()
         }
     };
 
     let bond = match create_bond(&bond_params) {
         Ok(b) => b,
-        Err(e) => {
-            return AnalysisResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let settlement = match parse_date(&bond_params.settlement_date) {
         Ok(d) => d,
-        Err(e) => {
-            return AnalysisResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let accrued = decimal_to_f64(bond.accrued_interest(settlement));
@@ -357,3 +518,232 @@ fn calculate_simple_metrics_impl(params: JsValue, clean_price: f64) -> AnalysisR
         ..Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::CurvePoint;
+
+    fn params(settlement_date: &str) -> BondParams {
+        BondParams {
+            coupon_rate: 5.0,
+            maturity_date: "2030-06-15".to_string(),
+            issue_date: "2020-06-15".to_string(),
+            settlement_date: settlement_date.to_string(),
+            face_value: Some(100.0),
+            frequency: Some(2),
+            day_count: Some("30/360".to_string()),
+            currency: Some("USD".to_string()),
+            first_coupon_date: None,
+            call_schedule: None,
+            volatility: None,
+            market: None,
+            instrument_type: None,
+            yield_convention: None,
+            compounding: None,
+            settlement_days: None,
+            ex_dividend_days: None,
+            use_business_days: None,
+            rate_index: None,
+            quoted_margin: None,
+        }
+    }
+
+    fn curve() -> Vec<CurvePoint> {
+        vec![
+            CurvePoint {
+                date: "2025-06-15".to_string(),
+                rate: 4.0,
+            },
+            CurvePoint {
+                date: "2030-06-15".to_string(),
+                rate: 4.5,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_bad_date_is_parse_date() {
+        let result = analyze_parsed(&params("2024/06/15"), 100.0, &curve());
+        assert_eq!(result.error_code, Some(ErrorCode::ParseDate));
+        assert!(result.error.unwrap().contains("2024/06/15"));
+        assert!(result.clean_price.is_none());
+    }
+
+    #[test]
+    fn test_empty_curve_is_curve_empty() {
+        let result = analyze_parsed(&params("2024-06-15"), 100.0, &[]);
+        assert_eq!(result.error_code, Some(ErrorCode::CurveEmpty));
+        assert!(result.error.is_some());
+    }
+
+    #[test]
+    fn test_cash_flow_pvs_sum_to_dirty_price() {
+        use convex_analytics::spreads::ZSpreadCalculator;
+
+        // Settles after the (business-day adjusted) June 2024 coupon.
+        let bond_params = params("2024-06-20");
+        let result = cash_flows_with_pv(&bond_params, &curve()).unwrap();
+        assert_eq!(result.cash_flows.len(), 12);
+
+        let last = result.cash_flows.last().unwrap();
+        assert_eq!(last.cf_type, "coupon_and_principal");
+        assert!(result.cash_flows[..11]
+            .iter()
+            .all(|cf| cf.cf_type == "coupon"));
+        assert!(result
+            .cash_flows
+            .windows(2)
+            .all(|w| w[1].discount_factor < w[0].discount_factor));
+
+        // Same curve through the Z-spread pricer at zero spread.
+        let bond = create_bond(&bond_params).unwrap();
+        let settlement = parse_date(&bond_params.settlement_date).unwrap();
+        let curve = create_discount_curve(settlement, &curve()).unwrap();
+        let dirty = ZSpreadCalculator::new(&curve).price_with_spread(&bond, 0.0, settlement);
+
+        let total: f64 = result.cash_flows.iter().map(|cf| cf.present_value).sum();
+        assert!((total - dirty).abs() < 1e-9, "{total} vs {dirty}");
+        assert!((result.dirty_price.unwrap() - dirty).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_amortizing_principal_is_typed() {
+        use convex_bonds::instruments::{SinkingFundBond, SinkingFundPayment, SinkingFundSchedule};
+        use convex_core::types::Date;
+
+        let date = |y, m, d| Date::from_ymd(y, m, d).unwrap();
+        let base = params("2025-06-20");
+        let schedule = SinkingFundSchedule::new()
+            .with_payment(SinkingFundPayment::new(date(2026, 6, 15), 5.0))
+            .with_payment(SinkingFundPayment::new(date(2027, 6, 15), 30.0))
+            .with_payment(SinkingFundPayment::new(date(2028, 6, 15), 30.0));
+        let bond = SinkingFundBond::new(create_bond(&base).unwrap(), schedule);
+
+        let entries = cash_flow_entries(&bond.cash_flows(date(2025, 6, 20)));
+        let types_on = |d: &str| -> Vec<&str> {
+            entries
+                .iter()
+                .filter(|e| e.date == d)
+                .map(|e| e.cf_type.as_str())
+                .collect()
+        };
+
+        // Sink dates carry a partial paydown, however small, plus the coupon.
+        for d in ["2026-06-15", "2027-06-15", "2028-06-15"] {
+            assert_eq!(types_on(d), vec!["amortization", "coupon"], "{d}");
+        }
+        assert_eq!(types_on("2025-12-15"), vec!["coupon"]);
+
+        // The residual 35% is redeemed at maturity.
+        let last = entries.last().unwrap();
+        assert_eq!(last.cf_type, "principal");
+        assert!((last.amount - 35.0).abs() < 1e-9);
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|e| e.cf_type == "amortization")
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_callable_par_floater_margin_to_worst() {
+        use crate::dto::CallScheduleEntry;
+
+        // SOFR + 100bp at 5% current coupon, priced at par on a reset date.
+        let bond_params = BondParams {
+            coupon_rate: 5.0,
+            maturity_date: "2029-06-15".to_string(),
+            issue_date: "2024-06-15".to_string(),
+            frequency: Some(4),
+            day_count: Some("ACT/360".to_string()),
+            call_schedule: Some(vec![
+                CallScheduleEntry {
+                    date: "2026-06-15".to_string(),
+                    price: 100.0,
+                },
+                CallScheduleEntry {
+                    date: "2027-06-15".to_string(),
+                    price: 100.0,
+                },
+            ]),
+            rate_index: Some("SOFR".to_string()),
+            quoted_margin: Some(100.0),
+            ..params("2024-06-15")
+        };
+        let flat = vec![
+            CurvePoint {
+                date: "2025-06-15".to_string(),
+                rate: 4.0,
+            },
+            CurvePoint {
+                date: "2030-06-15".to_string(),
+                rate: 4.0,
+            },
+        ];
+        let result = analyze_parsed(&bond_params, 100.0, &flat);
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert_eq!(result.is_callable, Some(true));
+
+        let dm = result.discount_margin.unwrap();
+        let mtw = result.margin_to_worst.unwrap();
+        assert!(mtw <= dm);
+
+        // Margin to the first call, solved directly.
+        let frn = create_frn(&bond_params).unwrap();
+        let settlement = parse_date(&bond_params.settlement_date).unwrap();
+        let discount: Arc<dyn RateCurveDyn> =
+            Arc::new(create_discount_curve(settlement, &flat).unwrap());
+        let forward = ForwardCurve::from_months(discount.clone(), 3);
+        let dm_first_call = DiscountMarginCalculator::new(&forward, discount.as_ref())
+            .calculate_to_workout(
+                &frn,
+                f64_to_decimal(100.0),
+                settlement,
+                parse_date("2026-06-15").unwrap(),
+                100.0,
+            )
+            .unwrap();
+        let dm_first_call = decimal_to_f64(dm_first_call.as_bps());
+
+        // At par every workout earns the quoted margin, so the worst is the
+        // first call's margin.
+        assert!(
+            (mtw - dm_first_call).abs() < 0.01,
+            "{mtw} vs {dm_first_call}"
+        );
+        assert!((mtw - 100.0).abs() < 1.0, "{mtw}");
+    }
+
+    #[test]
+    fn test_oas_failure_is_a_warning() {
+        use crate::dto::CallScheduleEntry;
+
+        // Callable at par next month but priced at 120: no OAS reaches that
+        // price, while the bullet Z-spread still solves.
+        let bond_params = BondParams {
+            call_schedule: Some(vec![CallScheduleEntry {
+                date: "2024-07-15".to_string(),
+                price: 100.0,
+            }]),
+            ..params("2024-06-15")
+        };
+        let result = analyze_parsed(&bond_params, 120.0, &curve());
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert!(result.error_code.is_none());
+        assert!(result.ytm.is_some());
+        assert_eq!(result.oas, result.z_spread);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code, ErrorCode::OasNoConverge);
+    }
+
+    #[test]
+    fn test_error_code_wire_format() {
+        let json = serde_json::to_string(&ErrorCode::OasNoConverge).unwrap();
+        assert_eq!(json, "\"OAS_NO_CONVERGE\"");
+        let code: ErrorCode = serde_json::from_str("\"CURVE_EMPTY\"").unwrap();
+        assert_eq!(code, ErrorCode::CurveEmpty);
+    }
+}
@@ -1,9 +1,11 @@
 //! Bond / curve construction and YAS-result conversion shared by analytics and pricing modules.
 
 use convex_analytics::yas::YASResult;
+use convex_bonds::cashflows::SettlementCalculator;
 use convex_bonds::conventions::{ConventionKey, ConventionRegistry};
+use convex_bonds::instruments::FloatingRateNote;
 use convex_bonds::pricing::{StandardYieldEngine, YieldEngine};
-use convex_bonds::traits::Bond;
+use convex_bonds::traits::{Bond, FixedCouponBond};
 use convex_bonds::types::{
     CompoundingMethod, DayType, ExDivAccruedMethod, ExDividendRules, SettlementRules,
     YieldCalculationRules, YieldConvention,
@@ -18,11 +20,12 @@ use convex_curves::{
 use crate::convert::{
     decimal_to_f64, f64_to_decimal, format_compounding, format_instrument_type, format_market_name,
     format_yield_convention, log, parse_compounding, parse_currency, parse_date, parse_day_count,
-    parse_frequency, parse_instrument_type, parse_market, parse_yield_convention,
+    parse_frequency, parse_instrument_type, parse_market, parse_rate_index, parse_yield_convention,
 };
 use crate::dto::{AnalysisResult, BondParams, CurvePoint};
+use crate::error::{ErrorCode, WasmError};
 
-pub(crate) fn create_bond(params: &BondParams) -> Result<FixedRateBond, String> {
+pub(crate) fn create_bond(params: &BondParams) -> Result<FixedRateBond, WasmError> {
     let issue_date = parse_date(&params.issue_date)?;
     let maturity_date = parse_date(&params.maturity_date)?;
 
@@ -57,17 +60,72 @@ pub(crate) fn create_bond(params: &BondParams) -> Result<FixedRateBond, String>
         builder = builder.first_coupon_date(fc);
     }
 
-    builder
+    // Negative accrued inside the ex-dividend window (UK gilts) is applied by the
+    // bond itself, so YAS, convention yield and plain accrued all agree, whether
+    // the window counts business or calendar days.
+    if let Some(ex_div) = get_yield_rules(params).ex_dividend_rules {
+        if ex_div.can_have_negative_accrued() {
+            builder = builder
+                .ex_dividend_days(ex_div.days)
+                .ex_dividend_day_type(ex_div.day_type);
+        }
+    }
+
+    builder.build().map_err(|e| {
+        WasmError::new(
+            ErrorCode::BondBuild,
+            format!("Failed to create bond: {:?}", e),
+        )
+    })
+}
+
+/// Build an FRN from parameters with `rate_index` set. The current index
+/// fixing is backed out of `coupon_rate` so accrued interest and the
+/// in-progress coupon use the coupon the holder is actually earning.
+pub(crate) fn create_frn(params: &BondParams) -> Result<FloatingRateNote, WasmError> {
+    let issue_date = parse_date(&params.issue_date)?;
+    let maturity_date = parse_date(&params.maturity_date)?;
+
+    let index = parse_rate_index(params.rate_index.as_deref().unwrap_or("SOFR"))?;
+    let margin_bps = params.quoted_margin.unwrap_or(0.0);
+    let face = f64_to_decimal(params.face_value.unwrap_or(100.0));
+    let frequency = parse_frequency(params.frequency.unwrap_or(4));
+    let day_count = parse_day_count(params.day_count.as_deref().unwrap_or("ACT/360"));
+    let currency = parse_currency(params.currency.as_deref().unwrap_or("USD"));
+
+    let mut frn = FloatingRateNote::builder()
+        .identifiers(BondIdentifiers::new())
+        .index(index)
+        .spread_decimal(f64_to_decimal(margin_bps / 10_000.0))
+        .issue_date(issue_date)
+        .maturity(maturity_date)
+        .face_value(face)
+        .frequency(frequency)
+        .day_count(day_count)
+        .currency(currency)
         .build()
-        .map_err(|e| format!("Failed to create bond: {:?}", e))
+        .map_err(|e| {
+            WasmError::new(
+                ErrorCode::BondBuild,
+                format!("Failed to create FRN: {:?}", e),
+            )
+        })?;
+
+    frn.set_current_rate(f64_to_decimal(
+        (params.coupon_rate - margin_bps / 100.0) / 100.0,
+    ));
+    Ok(frn)
 }
 
 pub(crate) fn create_curve(
     reference_date: Date,
     points: &[CurvePoint],
-) -> Result<ZeroCurve, String> {
+) -> Result<ZeroCurve, WasmError> {
     if points.is_empty() {
-        return Err("Curve must have at least one point".to_string());
+        return Err(WasmError::new(
+            ErrorCode::CurveEmpty,
+            "Curve must have at least one point",
+        ));
     }
 
     let mut builder = ZeroCurveBuilder::new()
@@ -81,18 +139,24 @@ pub(crate) fn create_curve(
         builder = builder.add_rate(date, rate);
     }
 
-    builder
-        .build()
-        .map_err(|e| format!("Failed to create curve: {:?}", e))
+    builder.build().map_err(|e| {
+        WasmError::new(
+            ErrorCode::CurveBuild,
+            format!("Failed to create curve: {:?}", e),
+        )
+    })
 }
 
 /// Create a DiscountCurve for OAS calculations (implements the Curve trait).
 pub(crate) fn create_discount_curve(
     reference_date: Date,
     points: &[CurvePoint],
-) -> Result<DiscountCurve, String> {
+) -> Result<DiscountCurve, WasmError> {
     if points.is_empty() {
-        return Err("Curve must have at least one point".to_string());
+        return Err(WasmError::new(
+            ErrorCode::CurveEmpty,
+            "Curve must have at least one point",
+        ));
     }
 
     let mut builder = DiscountCurveBuilder::new(reference_date);
@@ -122,10 +186,12 @@ pub(crate) fn create_discount_curve(
         builder = builder.add_pillar(t, df);
     }
 
-    builder
-        .with_extrapolation()
-        .build()
-        .map_err(|e| format!("Failed to create discount curve: {:?}", e))
+    builder.with_extrapolation().build().map_err(|e| {
+        WasmError::new(
+            ErrorCode::CurveBuild,
+            format!("Failed to create discount curve: {:?}", e),
+        )
+    })
 }
 
 /// Get yield calculation rules from parameters, using registry if market/type specified.
@@ -261,15 +327,21 @@ pub(crate) fn convert_yas_result(
         .as_ref()
         .map(|s| format_instrument_type(parse_instrument_type(s)));
 
-    let is_ex_dividend = if let Some(ref ex_rules) = rules.ex_dividend_rules {
-        if let Some(next_coupon) = bond.next_coupon_date(settlement) {
-            let days_to_coupon = settlement.days_between(&next_coupon);
-            days_to_coupon <= ex_rules.days as i64
-        } else {
-            false
-        }
-    } else {
-        false
+    // Report the window the bond applied to accrued, so the flag never
+    // disagrees with the accrued and dirty price above. Windows that leave
+    // accrued alone (the Italian BTP record date) are not carried by the
+    // bond and come from the convention rules instead.
+    let (ex_dividend_days, is_ex_dividend) = match bond.ex_dividend_days() {
+        Some(days) => (Some(days), bond.is_ex_dividend(settlement)),
+        None => match &rules.ex_dividend_rules {
+            Some(ex_rules) => (
+                Some(ex_rules.days),
+                bond.next_coupon_date(settlement).is_some_and(|next| {
+                    SettlementCalculator::is_ex_dividend(settlement, next, ex_rules)
+                }),
+            ),
+            None => (None, false),
+        },
     };
 
     AnalysisResult {
@@ -301,6 +373,8 @@ pub(crate) fn convert_yas_result(
             .as_ref()
             .map(|s| decimal_to_f64(s.as_bps())),
         oas: None,
+        discount_margin: None,
+        margin_to_worst: None,
 
         effective_duration: None,
         effective_convexity: None,
@@ -315,10 +389,12 @@ pub(crate) fn convert_yas_result(
         yield_convention: Some(format_yield_convention(rules.convention)),
         compounding_method: Some(format_compounding(rules.compounding)),
         settlement_days: Some(rules.settlement_rules.days),
-        ex_dividend_days: rules.ex_dividend_rules.as_ref().map(|r| r.days),
+        ex_dividend_days,
         is_ex_dividend: Some(is_ex_dividend),
 
         error: None,
+        error_code: None,
+        warnings: Vec::new(),
     }
 }
 
@@ -339,16 +415,7 @@ mod tests {
             frequency: Some(2),
             day_count: Some("30/360".to_string()),
             currency: Some("USD".to_string()),
-            first_coupon_date: None,
-            call_schedule: None,
-            volatility: None,
-            market: None,
-            instrument_type: None,
-            yield_convention: None,
-            compounding: None,
-            settlement_days: None,
-            ex_dividend_days: None,
-            use_business_days: None,
+            ..Default::default()
         };
 
         let bond = create_bond(&params).unwrap();
@@ -356,6 +423,172 @@ mod tests {
         assert_eq!(decimal_to_f64(bond.coupon_rate()), 0.05);
     }
 
+    #[test]
+    fn test_gilt_in_ex_dividend_period_has_negative_accrued() {
+        use convex_analytics::yas::YASCalculator;
+
+        // 4.25% gilt paying 7 Jun / 7 Dec: the June 2025 coupon goes ex on
+        // 29 May, 7 business days earlier, so 2 Jun settles ex-dividend.
+        let params = BondParams {
+            coupon_rate: 4.25,
+            maturity_date: "2032-06-07".to_string(),
+            issue_date: "2022-06-07".to_string(),
+            settlement_date: "2025-06-02".to_string(),
+            face_value: Some(100.0),
+            frequency: Some(2),
+            day_count: Some("ACT/ACT".to_string()),
+            currency: Some("GBP".to_string()),
+            market: Some("UK".to_string()),
+            instrument_type: Some("GovernmentBond".to_string()),
+            ..Default::default()
+        };
+        let bond = create_bond(&params).unwrap();
+        let settlement = Date::from_ymd(2025, 6, 2).unwrap();
+        let curve = create_curve(
+            settlement,
+            &[
+                CurvePoint {
+                    date: "2026-06-02".to_string(),
+                    rate: 4.0,
+                },
+                CurvePoint {
+                    date: "2035-06-02".to_string(),
+                    rate: 4.5,
+                },
+            ],
+        )
+        .unwrap();
+
+        let yas = YASCalculator::new(&curve)
+            .analyze(&bond, settlement.into(), f64_to_decimal(98.0))
+            .unwrap();
+        let result =
+            convert_yas_result(&yas, &bond, settlement, &get_yield_rules(&params), &params);
+        assert_eq!(result.is_ex_dividend, Some(true));
+
+        let accrued = result.accrued_interest.unwrap();
+        assert!(accrued < 0.0, "accrued {accrued}");
+        assert!(result.dirty_price.unwrap() < result.clean_price.unwrap());
+        // Rebate of the 5 days left in the 182-day period.
+        let rebate = 2.125 * 5.0 / 182.0;
+        assert!((accrued + rebate).abs() < 1e-9, "{accrued} vs -{rebate}");
+
+        // Cum-dividend a week earlier accrues normally.
+        let cum = Date::from_ymd(2025, 5, 23).unwrap();
+        assert!(!bond.is_ex_dividend(cum));
+        assert!(decimal_to_f64(bond.accrued_interest(cum)) > 0.0);
+    }
+
+    #[test]
+    fn test_btp_record_date_window_is_reported() {
+        use convex_analytics::yas::YASCalculator;
+
+        // BTP paying 1 Feb / 1 Aug: the record-date window does not touch
+        // accrued, so the bond carries no window and the Italian convention
+        // rules flag 31 Jul as ex-dividend.
+        let params = BondParams {
+            coupon_rate: 3.85,
+            maturity_date: "2034-08-01".to_string(),
+            issue_date: "2024-02-01".to_string(),
+            settlement_date: "2025-07-31".to_string(),
+            face_value: Some(100.0),
+            frequency: Some(2),
+            day_count: Some("ACT/ACT".to_string()),
+            currency: Some("EUR".to_string()),
+            market: Some("Italy".to_string()),
+            instrument_type: Some("GovernmentBond".to_string()),
+            ..Default::default()
+        };
+        let rules = get_yield_rules(&params);
+        assert!(rules.ex_dividend_rules.unwrap().uses_record_date());
+        let bond = create_bond(&params).unwrap();
+        assert_eq!(bond.ex_dividend_days(), None);
+
+        let settlement = Date::from_ymd(2025, 7, 31).unwrap();
+        let curve = create_curve(
+            settlement,
+            &[
+                CurvePoint {
+                    date: "2026-07-31".to_string(),
+                    rate: 3.0,
+                },
+                CurvePoint {
+                    date: "2035-07-31".to_string(),
+                    rate: 3.8,
+                },
+            ],
+        )
+        .unwrap();
+        let analyze = |settlement: Date| {
+            let yas = YASCalculator::new(&curve)
+                .analyze(&bond, settlement.into(), f64_to_decimal(101.0))
+                .unwrap();
+            convert_yas_result(&yas, &bond, settlement, &rules, &params)
+        };
+
+        let result = analyze(settlement);
+        assert_eq!(result.is_ex_dividend, Some(true));
+        assert_eq!(result.ex_dividend_days, Some(2));
+        assert!(result.accrued_interest.unwrap() > 0.0);
+
+        let cum = analyze(Date::from_ymd(2025, 7, 25).unwrap());
+        assert_eq!(cum.is_ex_dividend, Some(false));
+    }
+
+    #[test]
+    fn test_calendar_day_ex_dividend_window_is_applied() {
+        use convex_analytics::yas::YASCalculator;
+
+        // Same gilt with a 7 calendar-day window: the June coupon goes ex on
+        // 31 May, so 2 Jun settles ex-dividend with the same rebate.
+        let params = BondParams {
+            coupon_rate: 4.25,
+            maturity_date: "2032-06-07".to_string(),
+            issue_date: "2022-06-07".to_string(),
+            settlement_date: "2025-06-02".to_string(),
+            face_value: Some(100.0),
+            frequency: Some(2),
+            day_count: Some("ACT/ACT".to_string()),
+            currency: Some("GBP".to_string()),
+            ex_dividend_days: Some(7),
+            use_business_days: Some(false),
+            ..Default::default()
+        };
+        let bond = create_bond(&params).unwrap();
+        let settlement = Date::from_ymd(2025, 6, 2).unwrap();
+        let curve = create_curve(
+            settlement,
+            &[
+                CurvePoint {
+                    date: "2026-06-02".to_string(),
+                    rate: 4.0,
+                },
+                CurvePoint {
+                    date: "2035-06-02".to_string(),
+                    rate: 4.5,
+                },
+            ],
+        )
+        .unwrap();
+
+        let yas = YASCalculator::new(&curve)
+            .analyze(&bond, settlement.into(), f64_to_decimal(98.0))
+            .unwrap();
+        let result =
+            convert_yas_result(&yas, &bond, settlement, &get_yield_rules(&params), &params);
+        assert_eq!(result.is_ex_dividend, Some(true));
+        assert_eq!(result.ex_dividend_days, Some(7));
+        let accrued = result.accrued_interest.unwrap();
+        let rebate = 2.125 * 5.0 / 182.0;
+        assert!((accrued + rebate).abs() < 1e-9, "{accrued} vs -{rebate}");
+
+        // 30 May is eight calendar days out: still cum-dividend, although a
+        // 7 business-day window would already have gone ex.
+        let cum = Date::from_ymd(2025, 5, 30).unwrap();
+        assert!(!bond.is_ex_dividend(cum));
+        assert!(decimal_to_f64(bond.accrued_interest(cum)) > 0.0);
+    }
+
     #[test]
     fn test_create_curve() {
         let reference = Date::from_ymd(2024, 6, 15).unwrap();
@@ -1,13 +1,16 @@
-//! UI dropdown helpers: list available conventions, fetch defaults for a market/instrument pair.
+//! UI dropdown helpers: list available conventions, fetch defaults for a market/instrument pair,
+//! and compute calendar-aware settlement dates.
 
 use wasm_bindgen::prelude::*;
 
-use convex_bonds::conventions::{ConventionKey, ConventionRegistry};
+use convex_bonds::conventions::{ConventionKey, ConventionRegistry, InstrumentType};
+use convex_core::types::Date;
 
 use crate::convert::{
-    format_compounding, format_yield_convention, parse_instrument_type, parse_market,
+    format_compounding, format_yield_convention, parse_date, parse_instrument_type, parse_market,
 };
-use crate::dto::{ConventionOption, ConventionOptions, DefaultConventions};
+use crate::dto::{ConventionOption, ConventionOptions, DefaultConventions, SettlementDateResult};
+use crate::error::WasmError;
 
 /// Get available convention options for UI dropdowns.
 #[wasm_bindgen]
@@ -207,3 +210,55 @@ pub fn get_default_conventions(market: String, instrument_type: String) -> JsVal
 
     serde_wasm_bindgen::to_value(&defaults).unwrap_or(JsValue::NULL)
 }
+
+/// Calculate the settlement date for a trade in the given market.
+///
+/// Rolls T+N over the market's holiday calendar, honoring the registry's
+/// business-day vs calendar-day rule. `settlement_days` overrides the market's
+/// default T+N when provided.
+#[wasm_bindgen]
+pub fn settlement_date(
+    trade_date: String,
+    market: String,
+    settlement_days: Option<u32>,
+) -> JsValue {
+    let result = match settlement_date_impl(&trade_date, &market, settlement_days) {
+        Ok(date) => SettlementDateResult {
+            settlement_date: Some(format!("{}", date)),
+            ..Default::default()
+        },
+        Err(e) => e.into(),
+    };
+    serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+}
+
+fn settlement_date_impl(
+    trade_date: &str,
+    market: &str,
+    settlement_days: Option<u32>,
+) -> Result<Date, WasmError> {
+    let trade_date = parse_date(trade_date)?;
+    let key = ConventionKey::new(parse_market(market), InstrumentType::GovernmentBond);
+    Ok(ConventionRegistry::global().settlement_date(trade_date, &key, settlement_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settlement_date_rolls_over_us_holiday() {
+        // Wednesday before Thanksgiving 2025, T+2 business days -> Monday
+        let settle = settlement_date_impl("2025-11-26", "US", Some(2)).unwrap();
+        assert_eq!(settle, Date::from_ymd(2025, 12, 1).unwrap());
+
+        // Default US Treasury T+1 from Friday before MLK day -> Tuesday
+        let settle = settlement_date_impl("2025-01-17", "US", None).unwrap();
+        assert_eq!(settle, Date::from_ymd(2025, 1, 21).unwrap());
+    }
+
+    #[test]
+    fn test_settlement_date_invalid_trade_date() {
+        assert!(settlement_date_impl("2025/01/17", "US", None).is_err());
+    }
+}
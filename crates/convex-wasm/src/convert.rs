@@ -4,36 +4,49 @@ use std::str::FromStr;
 
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
 use convex_bonds::conventions::{InstrumentType, Market};
-use convex_bonds::types::{CompoundingMethod, YieldConvention};
+use convex_bonds::traits::{BondCashFlow, CashFlowType};
+use convex_bonds::types::{CompoundingMethod, RateIndex, YieldConvention};
 use convex_core::daycounts::DayCountConvention;
 use convex_core::types::{Currency, Date, Frequency};
 
+use crate::error::{ErrorCode, WasmError};
+
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     pub(crate) fn log(s: &str);
 }
 
-pub(crate) fn parse_date(s: &str) -> Result<Date, String> {
+/// Native builds (tests) have no console to log to.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn log(_s: &str) {}
+
+pub(crate) fn parse_date(s: &str) -> Result<Date, WasmError> {
+    let invalid = |message: String| WasmError::new(ErrorCode::ParseDate, message);
     let parts: Vec<&str> = s.split('-').collect();
     if parts.len() != 3 {
-        return Err(format!("Invalid date format: {}. Expected YYYY-MM-DD", s));
+        return Err(invalid(format!(
+            "Invalid date format: {}. Expected YYYY-MM-DD",
+            s
+        )));
     }
 
     let year: i32 = parts[0]
         .parse()
-        .map_err(|_| format!("Invalid year: {}", parts[0]))?;
+        .map_err(|_| invalid(format!("Invalid year: {}", parts[0])))?;
     let month: u32 = parts[1]
         .parse()
-        .map_err(|_| format!("Invalid month: {}", parts[1]))?;
+        .map_err(|_| invalid(format!("Invalid month: {}", parts[1])))?;
     let day: u32 = parts[2]
         .parse()
-        .map_err(|_| format!("Invalid day: {}", parts[2]))?;
+        .map_err(|_| invalid(format!("Invalid day: {}", parts[2])))?;
 
-    Date::from_ymd(year, month, day).map_err(|e| format!("Invalid date {}: {:?}", s, e))
+    Date::from_ymd(year, month, day).map_err(|e| invalid(format!("Invalid date {}: {:?}", s, e)))
 }
 
 pub(crate) fn date_to_naive(date: Date) -> chrono::NaiveDate {
@@ -48,13 +61,30 @@ pub(crate) fn parse_day_count(s: &str) -> DayCountConvention {
 }
 
 pub(crate) fn parse_frequency(f: u32) -> Frequency {
-    match f {
-        1 => Frequency::Annual,
-        2 => Frequency::SemiAnnual,
-        4 => Frequency::Quarterly,
-        12 => Frequency::Monthly,
-        _ => Frequency::SemiAnnual,
-    }
+    Frequency::try_from_periods_per_year(f).unwrap_or(Frequency::SemiAnnual)
+}
+
+pub(crate) fn parse_rate_index(s: &str) -> Result<RateIndex, WasmError> {
+    let index = match s.to_uppercase().replace(['_', '-', ' '], "").as_str() {
+        "SOFR" => RateIndex::Sofr,
+        "ESTR" | "€STR" => RateIndex::Estr,
+        "SONIA" => RateIndex::Sonia,
+        "TONAR" | "TONA" => RateIndex::Tonar,
+        "SARON" => RateIndex::Saron,
+        "CORRA" => RateIndex::Corra,
+        "EURIBOR1M" => RateIndex::Euribor1M,
+        "EURIBOR3M" | "EURIBOR" => RateIndex::Euribor3M,
+        "EURIBOR6M" => RateIndex::Euribor6M,
+        "EURIBOR12M" => RateIndex::Euribor12M,
+        "TIBOR3M" | "TIBOR" => RateIndex::Tibor3M,
+        _ => {
+            return Err(WasmError::new(
+                ErrorCode::ParseRateIndex,
+                format!("Unknown rate index: {}", s),
+            ))
+        }
+    };
+    Ok(index)
 }
 
 pub(crate) fn parse_currency(s: &str) -> Currency {
@@ -225,6 +255,20 @@ pub(crate) fn format_yield_convention(conv: YieldConvention) -> String {
     }
 }
 
+/// Wire label for a cash flow's `cf_type`.
+///
+/// Principal-only flows paid before `final_date` (sinking fund or
+/// amortization paydowns) are reported as "amortization".
+pub(crate) fn format_cash_flow_type(cf: &BondCashFlow, final_date: Date) -> String {
+    match cf.flow_type {
+        CashFlowType::Coupon => "coupon".to_string(),
+        CashFlowType::Principal if cf.date < final_date => "amortization".to_string(),
+        CashFlowType::Principal => "principal".to_string(),
+        CashFlowType::CouponAndPrincipal => "coupon_and_principal".to_string(),
+        CashFlowType::Fee => "fee".to_string(),
+    }
+}
+
 pub(crate) fn format_compounding(method: CompoundingMethod) -> String {
     match method {
         CompoundingMethod::Periodic { frequency: 1 } => "Annual".to_string(),
@@ -255,6 +299,19 @@ mod tests {
         assert!(parse_date("2024/06/15").is_err());
     }
 
+    #[test]
+    fn test_parse_rate_index() {
+        assert_eq!(parse_rate_index("sofr").unwrap(), RateIndex::Sofr);
+        assert_eq!(
+            parse_rate_index("Euribor-6M").unwrap(),
+            RateIndex::Euribor6M
+        );
+
+        let err = parse_rate_index("LIBOR3M").unwrap_err();
+        assert_eq!(err.code, ErrorCode::ParseRateIndex);
+        assert!(err.message.contains("LIBOR3M"));
+    }
+
     #[test]
     fn test_parse_day_count() {
         // US 30/360
@@ -297,5 +354,7 @@ mod tests {
         assert!(matches!(parse_frequency(1), Frequency::Annual));
         assert!(matches!(parse_frequency(2), Frequency::SemiAnnual));
         assert!(matches!(parse_frequency(4), Frequency::Quarterly));
+        assert!(matches!(parse_frequency(6), Frequency::CustomMonths(s) if s.months() == 2));
+        assert!(matches!(parse_frequency(5), Frequency::SemiAnnual));
     }
 }
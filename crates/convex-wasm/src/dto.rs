@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::ErrorCode;
+
 /// Call schedule entry for callable bonds.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CallScheduleEntry {
@@ -12,7 +14,7 @@ pub struct CallScheduleEntry {
 }
 
 /// Bond parameters for creating a fixed coupon bond.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BondParams {
     /// Coupon rate as percentage (e.g., 5.0 for 5%)
     pub coupon_rate: f64,
@@ -58,6 +60,14 @@ pub struct BondParams {
     pub ex_dividend_days: Option<u32>,
     /// Whether this market uses business days for settlement
     pub use_business_days: Option<bool>,
+
+    // === Floating rate parameters ===
+    /// Reference index: "SOFR", "SONIA", "ESTR", "EURIBOR3M", etc. When set the
+    /// bond is analyzed as an FRN and `coupon_rate` is the current period's
+    /// coupon (index fixing plus quoted margin).
+    pub rate_index: Option<String>,
+    /// Quoted margin over the index in basis points (default 0)
+    pub quoted_margin: Option<f64>,
 }
 
 /// Analysis results returned from bond calculations.
@@ -93,6 +103,8 @@ pub struct AnalysisResult {
     pub z_spread: Option<f64>,
     pub asw_spread: Option<f64>,
     pub oas: Option<f64>, // Option-Adjusted Spread (for callable bonds)
+    pub discount_margin: Option<f64>, // FRN discount margin to maturity
+    pub margin_to_worst: Option<f64>, // Callable FRN discount margin to the worst workout
 
     // OAS-related metrics (for callable bonds)
     pub effective_duration: Option<f64>,
@@ -115,6 +127,17 @@ pub struct AnalysisResult {
 
     // Error message if calculation failed
     pub error: Option<String>,
+    // Machine-readable category of `error`
+    pub error_code: Option<ErrorCode>,
+    // Non-fatal issues; the rest of the result is still valid
+    pub warnings: Vec<Warning>,
+}
+
+/// A non-fatal issue reported alongside a successful result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Warning {
+    pub code: ErrorCode,
+    pub message: String,
 }
 
 /// Cash flow entry.
@@ -125,6 +148,28 @@ pub struct CashFlowEntry {
     pub cf_type: String, // "coupon", "principal", "coupon_and_principal"
 }
 
+/// Cash flow entry with its discount factor and present value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashFlowPvEntry {
+    pub date: String,
+    pub amount: f64,
+    pub cf_type: String,
+    /// Discount factor from settlement to the payment date
+    pub discount_factor: f64,
+    /// amount × discount_factor
+    pub present_value: f64,
+}
+
+/// Discounted cash flow schedule.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CashFlowPvResult {
+    pub cash_flows: Vec<CashFlowPvEntry>,
+    /// Sum of the present values as a percentage of face
+    pub dirty_price: Option<f64>,
+    pub error: Option<String>,
+    pub error_code: Option<ErrorCode>,
+}
+
 /// Curve point for yield curve construction.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurvePoint {
@@ -141,6 +186,16 @@ pub struct PriceFromYieldResult {
     pub dirty_price: Option<f64>,
     pub accrued_interest: Option<f64>,
     pub error: Option<String>,
+    pub error_code: Option<ErrorCode>,
+}
+
+/// Result from a settlement date calculation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SettlementDateResult {
+    /// Settlement date as "YYYY-MM-DD"
+    pub settlement_date: Option<String>,
+    pub error: Option<String>,
+    pub error_code: Option<ErrorCode>,
 }
 
 /// Available convention options for UI dropdowns.
@@ -0,0 +1,94 @@
+//! Machine-readable error codes returned alongside the human-readable message.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dto::{AnalysisResult, CashFlowPvResult, PriceFromYieldResult, SettlementDateResult};
+
+/// Failure category, serialized as a short upper-case string (e.g. `"PARSE_DATE"`)
+/// so the front end can branch on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// Bond parameters or curve points could not be deserialized.
+    ParseInput,
+    /// A date was not a valid "YYYY-MM-DD" string.
+    ParseDate,
+    /// A benchmark tenor string was not recognized.
+    ParseTenor,
+    /// A floating-rate index name was not recognized.
+    ParseRateIndex,
+    /// The curve has no points.
+    CurveEmpty,
+    /// The curve could not be built or queried from its points.
+    CurveBuild,
+    /// The bond could not be built from its parameters.
+    BondBuild,
+    /// The YAS analysis failed.
+    AnalysisFailed,
+    /// The OAS solver did not converge; the Z-spread is reported instead.
+    OasNoConverge,
+}
+
+/// An error code and its message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl WasmError {
+    pub(crate) fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for WasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<WasmError> for AnalysisResult {
+    fn from(e: WasmError) -> Self {
+        Self {
+            error: Some(e.message),
+            error_code: Some(e.code),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<WasmError> for PriceFromYieldResult {
+    fn from(e: WasmError) -> Self {
+        Self {
+            error: Some(e.message),
+            error_code: Some(e.code),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<WasmError> for SettlementDateResult {
+    fn from(e: WasmError) -> Self {
+        Self {
+            error: Some(e.message),
+            error_code: Some(e.code),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<WasmError> for CashFlowPvResult {
+    fn from(e: WasmError) -> Self {
+        Self {
+            error: Some(e.message),
+            error_code: Some(e.code),
+            ..Default::default()
+        }
+    }
+}
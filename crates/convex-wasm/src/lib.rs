@@ -4,14 +4,16 @@
 //! Bloomberg YAS-equivalent bond analytics in web browsers. The public
 //! `#[wasm_bindgen]` surface is split across submodules by responsibility:
 //!
-//! - [`analyze`] — `analyze_bond`, `get_cash_flows`, `calculate_accrued`,
-//!   `calculate_simple_metrics`
+//! - [`analyze`] — `analyze_bond`, `get_cash_flows`, `get_cash_flows_with_pv`,
+//!   `calculate_accrued`, `calculate_simple_metrics`
 //! - [`pricing`] — `price_from_yield`, `price_from_spread`,
 //!   `price_from_g_spread`, `price_from_benchmark_spread`
-//! - [`conventions`] — `get_convention_options`, `get_default_conventions`
+//! - [`conventions`] — `get_convention_options`, `get_default_conventions`,
+//!   `settlement_date`
 //!
 //! The non-public modules ([`dto`], [`convert`], [`bond`]) hold the wire
-//! types, parser/formatter helpers, and shared bond/curve construction.
+//! types, parser/formatter helpers, and shared bond/curve construction;
+//! [`error`] holds the `error_code` values reported next to each message.
 
 use wasm_bindgen::prelude::*;
 
@@ -20,14 +22,20 @@ pub mod bond;
 pub mod conventions;
 pub mod convert;
 pub mod dto;
+pub mod error;
 pub mod pricing;
 
-pub use analyze::{analyze_bond, calculate_accrued, calculate_simple_metrics, get_cash_flows};
-pub use conventions::{get_convention_options, get_default_conventions};
+pub use analyze::{
+    analyze_bond, calculate_accrued, calculate_simple_metrics, get_cash_flows,
+    get_cash_flows_with_pv,
+};
+pub use conventions::{get_convention_options, get_default_conventions, settlement_date};
 pub use dto::{
-    AnalysisResult, BondParams, CallScheduleEntry, CashFlowEntry, ConventionOption,
-    ConventionOptions, CurvePoint, DefaultConventions, PriceFromYieldResult,
+    AnalysisResult, BondParams, CallScheduleEntry, CashFlowEntry, CashFlowPvEntry,
+    CashFlowPvResult, ConventionOption, ConventionOptions, CurvePoint, DefaultConventions,
+    PriceFromYieldResult, SettlementDateResult,
 };
+pub use error::{ErrorCode, WasmError};
 pub use pricing::{
     price_from_benchmark_spread, price_from_g_spread, price_from_spread, price_from_yield,
 };
@@ -9,6 +9,7 @@ use convex_bonds::traits::Bond;
 use crate::bond::{create_bond, create_curve, create_discount_curve, get_yield_rules};
 use crate::convert::{decimal_to_f64, parse_date, parse_tenor_to_years};
 use crate::dto::{BondParams, CurvePoint, PriceFromYieldResult};
+use crate::error::{ErrorCode, WasmError};
 
 /// Calculate clean price from target yield.
 ///
@@ -27,31 +28,22 @@ fn price_from_yield_impl(
     let bond_params: BondParams = match serde_wasm_bindgen::from_value(params) {
         Ok(p) => p,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to parse bond parameters: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse bond parameters: {:?}", e),
+            )
+            .into()
         }
     };
 
     let bond = match create_bond(&bond_params) {
         Ok(b) => b,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let settlement = match parse_date(&bond_params.settlement_date) {
         Ok(d) => d,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let cash_flows = bond.cash_flows(settlement);
@@ -71,6 +63,7 @@ fn price_from_yield_impl(
         dirty_price: Some(dirty_price),
         accrued_interest: Some(decimal_to_f64(accrued)),
         error: None,
+        error_code: None,
     }
 }
 
@@ -95,51 +88,38 @@ fn price_from_spread_impl(
     let bond_params: BondParams = match serde_wasm_bindgen::from_value(params) {
         Ok(p) => p,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to parse bond parameters: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse bond parameters: {:?}", e),
+            )
+            .into()
         }
     };
 
     let points: Vec<CurvePoint> = match serde_wasm_bindgen::from_value(curve_points) {
         Ok(p) => p,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to parse curve points: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse curve points: {:?}", e),
+            )
+            .into()
         }
     };
 
     let bond = match create_bond(&bond_params) {
         Ok(b) => b,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let settlement = match parse_date(&bond_params.settlement_date) {
         Ok(d) => d,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let curve = match create_discount_curve(settlement, &points) {
         Ok(c) => c,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let accrued = bond.accrued_interest(settlement);
@@ -155,6 +135,7 @@ fn price_from_spread_impl(
         dirty_price: Some(dirty_price),
         accrued_interest: Some(decimal_to_f64(accrued)),
         error: None,
+        error_code: None,
     }
 }
 
@@ -180,70 +161,53 @@ fn price_from_g_spread_impl(
     let bond_params: BondParams = match serde_wasm_bindgen::from_value(params) {
         Ok(p) => p,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to parse bond parameters: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse bond parameters: {:?}", e),
+            )
+            .into()
         }
     };
 
     let points: Vec<CurvePoint> = match serde_wasm_bindgen::from_value(curve_points) {
         Ok(p) => p,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to parse curve points: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse curve points: {:?}", e),
+            )
+            .into()
         }
     };
 
     let bond = match create_bond(&bond_params) {
         Ok(b) => b,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let settlement = match parse_date(&bond_params.settlement_date) {
         Ok(d) => d,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let curve = match create_curve(settlement, &points) {
         Ok(c) => c,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let maturity = match bond.maturity() {
         Some(m) => m,
-        None => {
-            return PriceFromYieldResult {
-                error: Some("Bond has no maturity date".to_string()),
-                ..Default::default()
-            }
-        }
+        None => return WasmError::new(ErrorCode::BondBuild, "Bond has no maturity date").into(),
     };
 
     let benchmark_rate = match curve.zero_rate(maturity, convex_curves::Compounding::SemiAnnual) {
         Ok(r) => r,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to get benchmark rate: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::CurveBuild,
+                format!("Failed to get benchmark rate: {:?}", e),
+            )
+            .into()
         }
     };
 
@@ -267,6 +231,7 @@ fn price_from_g_spread_impl(
         dirty_price: Some(dirty_price),
         accrued_interest: Some(decimal_to_f64(accrued)),
         error: None,
+        error_code: None,
     }
 }
 
@@ -299,61 +264,43 @@ fn price_from_benchmark_spread_impl(
     let bond_params: BondParams = match serde_wasm_bindgen::from_value(params) {
         Ok(p) => p,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to parse bond parameters: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse bond parameters: {:?}", e),
+            )
+            .into()
         }
     };
 
     let points: Vec<CurvePoint> = match serde_wasm_bindgen::from_value(curve_points) {
         Ok(p) => p,
         Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(format!("Failed to parse curve points: {:?}", e)),
-                ..Default::default()
-            }
+            return WasmError::new(
+                ErrorCode::ParseInput,
+                format!("Failed to parse curve points: {:?}", e),
+            )
+            .into()
         }
     };
 
     let bond = match create_bond(&bond_params) {
         Ok(b) => b,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let settlement = match parse_date(&bond_params.settlement_date) {
         Ok(d) => d,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let curve = match create_curve(settlement, &points) {
         Ok(c) => c,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return e.into(),
     };
 
     let tenor_years = match parse_tenor_to_years(&benchmark_tenor) {
         Ok(t) => t,
-        Err(e) => {
-            return PriceFromYieldResult {
-                error: Some(e),
-                ..Default::default()
-            }
-        }
+        Err(e) => return WasmError::new(ErrorCode::ParseTenor, e).into(),
     };
 
     let benchmark_days = (tenor_years * 365.25) as i64;
@@ -363,10 +310,11 @@ fn price_from_benchmark_spread_impl(
         match curve.zero_rate(benchmark_date, convex_curves::Compounding::SemiAnnual) {
             Ok(r) => r,
             Err(e) => {
-                return PriceFromYieldResult {
-                    error: Some(format!("Failed to get benchmark rate: {:?}", e)),
-                    ..Default::default()
-                }
+                return WasmError::new(
+                    ErrorCode::CurveBuild,
+                    format!("Failed to get benchmark rate: {:?}", e),
+                )
+                .into()
             }
         };
 
@@ -390,5 +338,6 @@ fn price_from_benchmark_spread_impl(
         dirty_price: Some(dirty_price),
         accrued_interest: Some(decimal_to_f64(accrued)),
         error: None,
+        error_code: None,
     }
 }
@@ -6,6 +6,7 @@
 
 #![warn(missing_docs)]
 
+pub use convex_core::build_info::{build_info, BuildInfo};
 pub use convex_core::daycounts::DayCountConvention;
 pub use convex_core::error::{ConvexError, ConvexResult};
 pub use convex_core::types::{
@@ -28,8 +29,8 @@ pub use convex_bonds::{BondError, BondResult};
 
 pub use convex_analytics::error::{AnalyticsError, AnalyticsResult};
 pub use convex_analytics::functions::{
-    clean_price_from_yield, convexity, dirty_price_from_yield, dv01, macaulay_duration,
-    modified_duration, yield_to_maturity,
+    average_life, clean_price_from_yield, convexity, dirty_price_from_yield, dv01,
+    macaulay_duration, modified_duration, yield_to_average_life, yield_to_maturity,
 };
 pub use convex_analytics::pricing::{price_callable_from_mark, price_from_mark, PricingResult};
 pub use convex_analytics::risk::{